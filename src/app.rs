@@ -1,44 +1,148 @@
 #![allow(dead_code)]
 
 use crate::core::actions::Action;
+use crate::models::archive_passwords::ArchivePasswords;
+use crate::models::frecency::FrecencyStore;
+use crate::models::jobs::{Job, JobLog, JobStatus};
+use crate::models::open_rules::OpenRules;
 use crate::models::operation::{
-    ConflictResolution, FlattenedEntryKind, FlattenedFile, OperationProgress, OperationState,
-    OperationType, PendingOperation,
+    ConflictResolution, CopyOption, CopyOptions, FlattenedEntryKind, FlattenedFile, MoveStrategy,
+    OperationProgress, OperationState, OperationType, PendingOperation, SymlinkPolicy,
 };
 use crate::models::panel_state::{SortBy, SortOrder};
+use crate::models::remote::{
+    is_remote_url, remember_host, RemoteHost, RemoteProtocol, MAX_REMOTE_HOSTS,
+};
+use crate::models::session::{self, PanelSession, Session, SessionSide, SESSION_VERSION};
 use crate::models::{FileEntry, PanelState, PanelTabs};
+use crate::system::compare::CompareOutcome;
+use crate::system::disk_usage::UsageTree;
+use crate::system::diskspace::{self, DiskSpace};
+use crate::system::event_loop::Waker;
+use crate::system::filesystem::{ChunkedCopy, LazyMetadata, CHUNKED_COPY_THRESHOLD};
+use crate::system::git::DirectoryStatus;
+use crate::system::listing_cache;
+use crate::system::privilege::{self, ElevatedOp, ElevatedRequest};
+use crate::system::remote::{
+    join_remote, remote_file_name, remote_parent, RemoteSession, RemoteTransferRequest,
+    TransferDirection,
+};
+use crate::system::sync::{SyncItem, SyncSummary};
 use crate::system::{
-    create_archive, detect_archive_format, extract_archive, list_entries, list_extract_conflicts,
-    supports_password, ArchiveCreateRequest, ArchiveEntry, ArchiveExtractRequest, ArchiveFormat,
-    ArchiveProgressEvent, ArchiveSummary, FileSystem, ImeStatus,
+    create_archive, default_archive_presets, delete_entries, detect_archive_format,
+    extract_archive, list_entries, list_extract_conflicts, resolve_compression_threads,
+    supports_entry_delete, supports_name_encoding, supports_password, zip_name_sample,
+    ArchiveControl, ArchiveCreateRequest, ArchiveDeleteRequest, ArchiveEntry,
+    ArchiveExtractRequest, ArchiveFormat, ArchivePreset, ArchiveProgressEvent, ArchiveSummary,
+    CompressionLevel, FileSystem, ImeStatus, ZipNameEncoding,
 };
+use crate::ui::highlight::HighlightRules;
+use crate::ui::theme::{ColorDepth, ColorMode};
 use crate::ui::{
-    create_default_menus, ActivePanel, DialogKind, I18n, InputPurpose, Language, LayoutManager,
-    LayoutMode, Menu, MenuState, MessageKey, TextKey, ThemeManager,
+    create_default_menus, ActivePanel, DialogKind, DiskUsageItem, I18n, InputPurpose, Language,
+    LayoutManager, LayoutMode, Menu, MenuState, MessageKey, MountPointItem, TextKey, ThemeManager,
 };
+use crate::utils::collation::NameCollation;
 use crate::utils::error::{BokslDirError, Result};
+use crate::utils::logging;
+use crate::utils::scope_filter::ScopeFilter;
+use log::LevelFilter;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Component, Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
-use std::time::Instant;
-
+use std::time::{Duration, Instant, SystemTime};
+
+mod archive_encoding;
+mod attributes;
+mod auto_refresh;
+mod bookmarks;
+mod breadcrumb;
+mod bulk_rename;
+mod busy_files;
+mod command_palette;
+mod compare;
+mod confirmations;
+mod context_menu;
 mod controllers;
+mod dialog_outcome;
 mod dialogs;
+mod disk_usage;
+mod elevation;
+mod error_report;
+mod event_loop;
+mod file_finder;
+mod file_types;
+#[cfg(test)]
+pub(crate) mod fixture;
+mod follow_focus;
+mod free_space;
+mod fuzzy_list;
+mod git;
+mod hide_patterns;
+mod info_line;
+mod jobs;
+mod jump_list;
+mod macros;
+mod messages;
+mod mounted;
+mod name_check;
 mod navigation;
+mod new_file;
+mod open_file;
+mod operation_scope;
 mod operations;
+mod other_panel;
+mod panel_sync;
+mod path_line;
+mod plugins;
+mod remote;
+mod run_executable;
+mod selection_stats;
+mod siblings;
+mod sync_browsing;
+mod tabs;
 mod text_edit;
 
+use dialog_outcome::DialogContinuation;
+pub use dialog_outcome::DialogOutcome;
+pub use macros::load_script;
+use macros::MacroState;
+pub use messages::{Command, Msg};
+pub use plugins::PluginColumnsView;
+use plugins::PluginHost;
+pub use text_edit::TextEdit;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PersistedPanelHistory {
     entries: Vec<PathBuf>,
     index: usize,
+    /// `entries`와 같은 순서의 마지막 방문 시각 (UNIX 초, 0이면 기록 없음)
+    #[serde(default)]
+    times: Vec<u64>,
+    /// 고정한 경로
+    #[serde(default)]
+    pinned: Vec<PathBuf>,
+}
+
+impl PersistedPanelHistory {
+    fn capture(panel: &PanelState) -> Self {
+        Self {
+            entries: panel.history_entries.clone(),
+            index: panel.history_index,
+            times: panel
+                .history_entries
+                .iter()
+                .map(|path| panel.history_visited_at(path).unwrap_or(0))
+                .collect(),
+            pinned: panel.history_pins.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +155,12 @@ struct PersistedHistoriesState {
 struct PersistedBookmark {
     name: String,
     path: PathBuf,
+    /// 이동 단축키 숫자 (`'` + 숫자)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hotkey: Option<u8>,
+    /// 소속 그룹 (북마크 목록에서 접기/펼치기 단위)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +171,254 @@ struct PersistedAppState {
     language: String,
     history: PersistedHistoriesState,
     bookmarks: Vec<PersistedBookmark>,
+    #[serde(default)]
+    sort: PersistedSortOptions,
+    #[serde(default)]
+    color_mode: ColorMode,
+    #[serde(default)]
+    copy: PersistedCopyOptions,
+    #[serde(default)]
+    confirm: ConfirmPolicy,
+    #[serde(default)]
+    auto_refresh: AutoRefreshIntervals,
+    /// 목록에서 늘 숨길 글로브 패턴 (`/`로 끝나면 디렉토리만)
+    #[serde(default)]
+    hide_patterns: Vec<String>,
+    /// 압축 생성 프리셋 (압축 창에서 숫자 키로 적용)
+    #[serde(default = "default_archive_presets")]
+    archive_presets: Vec<ArchivePreset>,
+    #[serde(default)]
+    remote: PersistedRemote,
+    #[serde(default = "default_git_status")]
+    git_status: bool,
+    /// 패널 아래 `ls -l` 형식 정보 줄 표시
+    #[serde(default)]
+    info_line: bool,
+    /// 상태바 위 전체 경로 줄 표시
+    #[serde(default)]
+    path_line: bool,
+    /// 패널 종류 컬럼 표시
+    #[serde(default)]
+    type_column: bool,
+    #[serde(default)]
+    frecency: FrecencyStore,
+    /// 북마크 목록에서 접어 둔 그룹
+    #[serde(default)]
+    bookmark_collapsed_groups: Vec<String>,
+    /// 패널당 최대 탭 수
+    #[serde(default = "default_max_tabs_per_panel")]
+    max_tabs_per_panel: usize,
+    /// 로그 파일 기록 수준 (off, error, warn, info, debug, trace)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    log_level: Option<String>,
+    /// 초당 최대 화면 갱신 횟수
+    #[serde(default = "default_frame_rate")]
+    frame_rate: u32,
+    /// 입력이 없을 때 상태(IME, 디스크 용량, 테마 파일 등)를 확인하는 주기
+    #[serde(default = "default_idle_tick_ms")]
+    idle_tick_ms: u64,
+    /// tar.zst/7z 압축 스레드 수 (0이면 CPU 수)
+    #[serde(default)]
+    compression_threads: usize,
+    /// 다른 프로세스가 쓰는 파일의 자동 재시도 횟수 (0이면 바로 묻기)
+    #[serde(default = "default_busy_retry_attempts")]
+    busy_retry_attempts: u32,
+    /// 세션 중 기억한 압축 비밀번호를 압축 파일별로 쓸지 (끄면 마지막 비밀번호를 모두에 시도)
+    #[serde(default = "default_true")]
+    archive_password_per_archive: bool,
+    /// UTF-8 표시가 없는 ZIP 항목 이름 인코딩 기본값
+    #[serde(default)]
+    zip_name_encoding: ZipNameEncoding,
+    /// 목록을 이름만 먼저 보여주고 메타데이터는 나중에 채울지 (auto: 네트워크 파일시스템만)
+    #[serde(default)]
+    lazy_metadata: LazyMetadata,
+    /// 켤 플러그인 이름 (빌드에 포함된 것만, 예: `media-duration`)
+    #[serde(default)]
+    plugins: Vec<String>,
+    /// 종료 시점의 양쪽 패널 탭 (다음 실행 시 복원)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tabs: Option<PersistedTabs>,
+}
+
+/// 종료 시점 탭 배치 (이름 있는 세션과 같은 형식에서 이름만 제외)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PersistedTabs {
+    #[serde(default)]
+    active_panel: SessionSide,
+    #[serde(default)]
+    left: PanelSession,
+    #[serde(default)]
+    right: PanelSession,
+}
+
+impl PersistedTabs {
+    fn from_session(session: Session) -> Self {
+        Self {
+            active_panel: session.active_panel,
+            left: session.left,
+            right: session.right,
+        }
+    }
+
+    fn into_session(self) -> Session {
+        Session {
+            version: SESSION_VERSION,
+            name: String::new(),
+            active_panel: self.active_panel,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct PersistedSortOptions {
+    #[serde(default)]
+    natural: bool,
+    #[serde(default)]
+    locale: bool,
+    #[serde(default = "default_dirs_first")]
+    dirs_first: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct PersistedCopyOptions {
+    #[serde(default)]
+    verify: bool,
+    #[serde(default)]
+    preserve_timestamps: bool,
+    #[serde(default = "default_preserve_permissions")]
+    preserve_permissions: bool,
+    #[serde(default)]
+    preserve_xattrs: bool,
+    #[serde(default)]
+    update_only: bool,
+    #[serde(default)]
+    symlinks: SymlinkPolicy,
+}
+
+/// 작업별 확인 여부 (설정 파일 `[confirm]`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfirmPolicy {
+    /// 휴지통으로 삭제 (d)
+    #[serde(default = "default_true")]
+    pub trash: bool,
+    /// 영구 삭제 (D)
+    #[serde(default = "default_true")]
+    pub permanent_delete: bool,
+    /// 복사/이동/압축 해제 대상이 이미 있을 때 (끄면 덮어쓰기)
+    #[serde(default = "default_true")]
+    pub overwrite: bool,
+    /// 백그라운드 작업이 진행 중일 때 종료
+    #[serde(default = "default_true")]
+    pub quit_with_jobs: bool,
+    /// 쉘 명령 실행 (!)
+    #[serde(default)]
+    pub shell_command: bool,
+}
+
+impl Default for ConfirmPolicy {
+    fn default() -> Self {
+        Self {
+            trash: true,
+            permanent_delete: true,
+            overwrite: true,
+            quit_with_jobs: true,
+            shell_command: false,
+        }
+    }
+}
+
+/// 패널별 자동 새로고침 주기 (설정 파일 `[auto_refresh]`, 초 단위, 0이면 끔)
+///
+/// inotify가 동작하지 않는 NFS 등에서 다운로드/로그 디렉토리를 지켜볼 때 사용합니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoRefreshIntervals {
+    #[serde(default)]
+    pub left: u64,
+    #[serde(default)]
+    pub right: u64,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 원격 접속 관리자에 저장된 접속 대상 (최근 접속 순)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PersistedRemote {
+    #[serde(default)]
+    hosts: Vec<RemoteHost>,
+}
+
+impl From<CopyOptions> for PersistedCopyOptions {
+    fn from(options: CopyOptions) -> Self {
+        Self {
+            verify: options.verify,
+            preserve_timestamps: options.preserve_timestamps,
+            preserve_permissions: options.preserve_permissions,
+            preserve_xattrs: options.preserve_xattrs,
+            update_only: options.update_only,
+            symlinks: options.symlinks,
+        }
+    }
+}
+
+impl From<PersistedCopyOptions> for CopyOptions {
+    fn from(options: PersistedCopyOptions) -> Self {
+        Self {
+            verify: options.verify,
+            preserve_timestamps: options.preserve_timestamps,
+            preserve_permissions: options.preserve_permissions,
+            preserve_xattrs: options.preserve_xattrs,
+            update_only: options.update_only,
+            symlinks: options.symlinks,
+        }
+    }
+}
+
+impl Default for PersistedCopyOptions {
+    fn default() -> Self {
+        CopyOptions::default().into()
+    }
+}
+
+fn default_preserve_permissions() -> bool {
+    true
+}
+
+impl Default for PersistedSortOptions {
+    fn default() -> Self {
+        Self {
+            natural: false,
+            locale: false,
+            dirs_first: default_dirs_first(),
+        }
+    }
+}
+
+fn default_dirs_first() -> bool {
+    true
+}
+
+fn default_git_status() -> bool {
+    true
+}
+
+fn default_max_tabs_per_panel() -> usize {
+    App::DEFAULT_MAX_TABS_PER_PANEL
+}
+
+fn default_frame_rate() -> u32 {
+    App::DEFAULT_FRAME_RATE
+}
+
+fn default_idle_tick_ms() -> u64 {
+    App::DEFAULT_IDLE_TICK_MS
+}
+
+fn default_busy_retry_attempts() -> u32 {
+    App::DEFAULT_BUSY_RETRY_ATTEMPTS
 }
 
 fn default_language_code() -> String {
@@ -79,10 +437,65 @@ pub struct TerminalCommandRequest {
     pub working_dir: PathBuf,
 }
 
+/// 실행 파일 직접 실행 요청 (셸을 거치지 않음)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutableRunRequest {
+    pub program: PathBuf,
+    pub args: Vec<String>,
+    /// 덮어쓸 환경 변수 (입력 앞부분의 `NAME=value`)
+    pub env: Vec<(String, String)>,
+    pub working_dir: PathBuf,
+    /// true면 TUI를 유지한 채 출력을 모아 보여줌, false면 TUI를 잠시 멈추고 터미널에서 실행
+    pub capture: bool,
+}
+
+/// 실행 파일 실행 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutableRunOutcome {
+    /// 종료 코드 (시그널로 끝나면 None)
+    pub exit_code: Option<i32>,
+    /// 모은 출력 (stdout 뒤에 stderr, 캡처 모드에서만 Some)
+    pub output: Option<String>,
+}
+
+/// 시작 옵션 (커맨드라인 인자 기반)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StartupOptions {
+    /// 좌측 패널 시작 경로
+    pub left_path: Option<PathBuf>,
+    /// 우측 패널 시작 경로
+    pub right_path: Option<PathBuf>,
+    /// 시작 테마 이름 (저장된 테마보다 우선)
+    pub theme: Option<String>,
+    /// 설정 파일 읽기/쓰기 비활성화
+    pub no_persist: bool,
+}
+
+/// 쉘 연동 모드 종료 출력 대상 (`--choose-dir` / `--choose-files`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChooseOutputs {
+    /// 종료 시 마지막 활성 디렉토리를 기록할 파일
+    pub dir_file: Option<PathBuf>,
+    /// 종료 시 선택된 파일 경로 목록을 기록할 파일 (줄 단위)
+    pub files_file: Option<PathBuf>,
+}
+
+impl ChooseOutputs {
+    pub fn is_empty(&self) -> bool {
+        self.dir_file.is_none() && self.files_file.is_none()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ArchiveWorkerKind {
     Compress,
     Extract,
+    /// 압축 파일 안 항목 삭제 (압축 파일 다시 쓰기)
+    Delete,
+    /// 원격 → 로컬 전송
+    Download,
+    /// 로컬 → 원격 전송
+    Upload,
 }
 
 #[derive(Debug)]
@@ -91,10 +504,118 @@ struct ArchiveWorkerState {
     progress_rx: Receiver<ArchiveProgressEvent>,
     join_handle:
         Option<JoinHandle<std::result::Result<ArchiveSummary, crate::utils::error::BokslDirError>>>,
-    cancel_flag: Arc<AtomicBool>,
+    control: Arc<ArchiveControl>,
     progress: OperationProgress,
 }
 
+/// 디스크 사용량 스캔 스레드 → 메인 루프 이벤트
+#[derive(Debug)]
+enum DiskUsageEvent {
+    Progress { files: u64, bytes: u64 },
+    Finished(Result<UsageTree>),
+}
+
+#[derive(Debug)]
+struct DiskUsageState {
+    started: Instant,
+    /// 스캔 완료 전에는 None
+    tree: Option<UsageTree>,
+    /// 현재 표시 중인 디렉토리 노드
+    current: usize,
+    events: Option<Receiver<DiskUsageEvent>>,
+    cancel: Arc<std::sync::atomic::AtomicBool>,
+}
+
+/// 파일 색인 스레드 → 메인 루프 이벤트
+#[derive(Debug)]
+enum FileFinderEvent {
+    Batch(Vec<String>),
+    Finished(Result<crate::system::file_index::IndexSummary>),
+}
+
+#[derive(Debug)]
+struct FileFinderState {
+    /// 색인 시작 경로 (항목은 이 경로 기준 상대 경로)
+    root: PathBuf,
+    started: Instant,
+    events: Option<Receiver<FileFinderEvent>>,
+    cancel: Arc<std::sync::atomic::AtomicBool>,
+}
+
+/// 파일 비교 스레드 → 메인 루프 이벤트
+#[derive(Debug)]
+enum FileCompareEvent {
+    Progress { checked: u64, total: u64 },
+    Finished(Result<CompareOutcome>),
+}
+
+#[derive(Debug)]
+struct FileCompareState {
+    name: String,
+    started: Instant,
+    events: Receiver<FileCompareEvent>,
+    cancel: Arc<std::sync::atomic::AtomicBool>,
+}
+
+/// 패널 동기화 스레드 → 메인 루프 이벤트 (비교 후 실행)
+#[derive(Debug)]
+enum PanelSyncEvent {
+    Compared(u64),
+    Planned(Result<Vec<SyncItem>>),
+    Progress { done: usize, total: usize },
+    Finished(Result<SyncSummary>),
+}
+
+#[derive(Debug)]
+struct PanelSyncState {
+    left: PathBuf,
+    right: PathBuf,
+    started: Instant,
+    /// 비교/실행 중에만 Some (계획 검토 중이면 None)
+    events: Option<Receiver<PanelSyncEvent>>,
+    cancel: Arc<std::sync::atomic::AtomicBool>,
+}
+
+/// 편집기로 이름 바꾸기 중인 목록 (편집기를 닫으면 줄 순서대로 적용)
+#[derive(Debug)]
+struct BulkRename {
+    dir: PathBuf,
+    /// 임시 파일에 쓴 원래 이름 (한 줄에 하나)
+    names: Vec<String>,
+    list_path: PathBuf,
+}
+
+/// 오류와 함께 끝난 작업의 전체 오류 보고서 (결과 창에서 보기/저장)
+#[derive(Debug)]
+struct ErrorReport {
+    operation: String,
+    sources: Vec<PathBuf>,
+    /// 대상 디렉토리 (Delete는 None)
+    dest_dir: Option<PathBuf>,
+    finished_at: SystemTime,
+    succeeded: usize,
+    errors: Vec<(SystemTime, String)>,
+}
+
+/// git 상태 조회 스레드 → 메인 루프 결과 (조회 시점의 패널/경로 포함)
+#[derive(Debug)]
+struct GitStatusResult {
+    panel: PanelSlot,
+    path: PathBuf,
+    status: Option<DirectoryStatus>,
+}
+
+/// 새 이름을 받는 작업 (이름 검사에 걸리면 고친 이름으로 다시 실행)
+#[derive(Debug, Clone)]
+enum NameTarget {
+    /// 새 디렉토리 (`parent` 아래)
+    NewDirectory { parent: PathBuf },
+    /// 이름 변경 (`original`의 이름을 바꿈)
+    Rename { original: PathBuf },
+    /// 새 파일 (현재 디렉토리, `edit`이면 만든 뒤 편집기로 열기)
+    NewFile { edit: bool },
+}
+
 #[derive(Debug, Clone)]
 enum ArchiveFlowContext {
     CreatePending {
@@ -103,6 +624,10 @@ enum ArchiveFlowContext {
     ExtractPending {
         archive_path: PathBuf,
         format: ArchiveFormat,
+        /// 압축 보기에서 고른 항목만 풀 때 (비어 있으면 전체)
+        only_entries: Vec<String>,
+        /// 압축 보기를 열 때 입력한 비밀번호
+        password: Option<String>,
     },
     ExtractNeedsPassword {
         request: ArchiveExtractRequest,
@@ -126,12 +651,33 @@ enum ArchiveFlowContext {
     },
 }
 
+/// 원격 패널 관련 다이얼로그 흐름 상태
+#[derive(Debug, Clone)]
+enum RemoteFlowContext {
+    /// 복사/이동 대상 입력 대기
+    Transfer {
+        direction: TransferDirection,
+        remove_source: bool,
+        sources: Vec<PathBuf>,
+    },
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum PanelSlot {
     Left,
     Right,
 }
 
+impl PanelSlot {
+    /// 반대쪽 패널
+    fn other(self) -> Self {
+        match self {
+            PanelSlot::Left => PanelSlot::Right,
+            PanelSlot::Right => PanelSlot::Left,
+        }
+    }
+}
+
 impl From<ActivePanel> for PanelSlot {
     fn from(value: ActivePanel) -> Self {
         match value {
@@ -151,6 +697,29 @@ struct ArchivePanelView {
     password: Option<String>,
 }
 
+/// 원격 패널 탐색 상태 (SFTP/FTP 세션이 연결된 패널)
+///
+/// 목록/생성/이름 변경/삭제는 패널에 마운트한 `RemoteFs`가 처리하고, 여기서는 접속 정보와
+/// 업로드/다운로드에 쓰는 세션을 보관합니다.
+#[derive(Debug, Clone)]
+struct RemotePanelView {
+    panel: PanelSlot,
+    host: RemoteHost,
+    /// 원격 현재 디렉토리 (절대 경로)
+    current_dir: String,
+    /// 접속 전 로컬 경로 (접속 해제 시 복귀)
+    local_dir: PathBuf,
+    session: Arc<Mutex<RemoteSession>>,
+}
+
+/// 항목별 컨텍스트 메뉴 (포커스된 항목에 적용 가능한 동작 목록)
+pub struct ContextMenu {
+    /// 메뉴를 연 패널
+    pub panel: ActivePanel,
+    pub menu: Menu,
+    pub state: MenuState,
+}
+
 /// 파일 크기 표시 형식
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SizeFormat {
@@ -177,6 +746,8 @@ pub struct App {
     pub menus: Vec<Menu>,
     /// 메뉴 상태
     pub menu_state: MenuState,
+    /// 열려 있는 항목 컨텍스트 메뉴
+    pub context_menu: Option<ContextMenu>,
     /// 테마 관리자
     pub theme_manager: ThemeManager,
     /// 현재 UI 언어
@@ -186,14 +757,55 @@ pub struct App {
     pub dialog: Option<DialogKind>,
     /// 대기 중인 파일 작업
     pub pending_operation: Option<PendingOperation>,
+    /// 열린 다이얼로그의 결과를 받아 이어서 실행할 작업
+    dialog_continuation: Option<DialogContinuation>,
+    /// 진행 중인 대용량 파일 분할 복사
+    chunked_copy: Option<ChunkedCopy>,
     /// 진행 중인 압축 작업 워커
     archive_worker: Option<ArchiveWorkerState>,
+    /// 디스크 사용량 분석 상태 (스캔 중이거나 결과 탐색 중)
+    disk_usage: Option<DiskUsageState>,
+    file_finder: Option<FileFinderState>,
+    /// 진행 중인 패널 간 파일 비교
+    file_compare: Option<FileCompareState>,
+    /// 두 패널 동기화 (비교 중, 계획 검토 중, 실행 중)
+    panel_sync: Option<PanelSyncState>,
+    /// 디렉토리 방문 빈도/최근성 기록 (점프 목록, 설정 저장)
+    frecency: FrecencyStore,
+    /// 패널 목록에 git 상태 표시 여부 (설정 저장)
+    git_status_enabled: bool,
+    /// 패널 아래에 포커스 항목의 `ls -l` 형식 정보 줄 표시 (설정 저장)
+    show_info_line: bool,
+    /// 상태바 위에 포커스 항목의 전체 경로 줄 표시 (설정 저장)
+    show_path_line: bool,
+    /// 포커스 항목 이름의 가로 스크롤 (항목 경로, 건너뛴 글자 수, 포커스가 바뀌면 무시)
+    name_scroll: Option<(PathBuf, usize)>,
+    /// 제목 경로 조각 고르기 모드에서 고른 조각 (활성 패널, None이면 모드 꺼짐)
+    breadcrumb: Option<usize>,
+    /// 패널에 매직 넘버로 판별한 파일 종류 컬럼 표시 (설정 저장)
+    show_type_column: bool,
+    /// 동기 탐색 모드 (활성 패널의 디렉토리 이동을 반대 패널에도 반영)
+    sync_browsing: bool,
+    /// 포커스한 디렉토리를 반대 패널에 표시하는 모드
+    follow_focus: bool,
+    /// 따라갈 디렉토리와 포커스가 머물기 시작한 시각
+    follow_focus_pending: Option<(PathBuf, Instant)>,
+    /// git 상태 조회 결과 채널 (워커 스레드가 송신)
+    git_status_events: (mpsc::Sender<GitStatusResult>, Receiver<GitStatusResult>),
+    /// git 상태를 조회 중인 패널
+    git_status_pending: Vec<PanelSlot>,
     /// 압축 관련 다이얼로그 흐름 상태
     archive_flow: Option<ArchiveFlowContext>,
     /// 압축 패널 탐색 상태 (활성 패널 기준)
     archive_panel_view: Option<ArchivePanelView>,
     /// 압축 내부 복사용 임시 디렉토리 (작업 종료/취소 시 정리)
     archive_copy_temp_dir: Option<PathBuf>,
+    /// 원격 패널 탐색 상태
+    remote_panel_view: Option<RemotePanelView>,
+    /// 원격 관련 다이얼로그 흐름 상태
+    remote_flow: Option<RemoteFlowContext>,
+    /// 저장된 원격 접속 대상 (최근 접속 순)
+    remote_hosts: Vec<RemoteHost>,
     // Phase 4: Vim 스타일 키 시퀀스
     /// 대기 중인 키 (예: 'g' for 'gg')
     pub pending_key: Option<char>,
@@ -205,24 +817,107 @@ pub struct App {
     pub icon_mode: crate::ui::components::panel::IconMode,
     /// 파일 크기 표시 형식
     pub size_format: SizeFormat,
+    /// 이름 비교 방식 (자연 정렬 / 로캘 인식, 전체 패널 공통)
+    sort_collation: NameCollation,
+    /// 디렉토리 우선 정렬 여부 (전체 패널 공통)
+    dirs_first: bool,
+    /// 복사 옵션 기본값 (복사 대상 다이얼로그의 초기 체크 상태)
+    copy_options: CopyOptions,
+    /// 작업별 확인 여부 (설정 파일 `[confirm]`)
+    confirm_policy: ConfirmPolicy,
+    /// 패널별 자동 새로고침 주기 (설정 파일 `[auto_refresh]`)
+    auto_refresh: AutoRefreshIntervals,
+    /// 패널별 마지막 자동 새로고침 시각 (왼쪽, 오른쪽)
+    auto_refresh_last: (Instant, Instant),
+    /// 목록에서 숨길 글로브 패턴 (설정 파일 `hide_patterns`)
+    hide_patterns: Vec<String>,
+    /// 패턴으로 숨긴 항목을 잠시 보이는 중 (저장하지 않음)
+    hide_patterns_revealed: bool,
+    /// 압축 생성 프리셋 (설정 파일 `[[archive_presets]]`)
+    archive_presets: Vec<ArchivePreset>,
+    /// 전역 파일 하이라이트 규칙 (`highlight.toml`, 없으면 테마 규칙 사용)
+    highlight_override: Option<HighlightRules>,
+    /// 파일에서 Enter를 눌렀을 때의 동작 규칙 (`open.toml`, 없으면 내장 규칙)
+    open_rules: OpenRules,
+    /// 마지막 사용자 테마 파일 변경 확인 시각
+    last_theme_poll: Instant,
+    /// 활성 패널 경로의 디스크 용량 (상태바 표시용, 주기적으로 갱신)
+    disk_space: Option<(PathBuf, DiskSpace)>,
+    /// 마지막 디스크 용량 조회 시각
+    last_disk_space_poll: Instant,
+    /// 색상 출력 모드 설정 (auto/truecolor/256/16)
+    color_mode: ColorMode,
+    /// 실제 출력 색상 깊이 (RGB 테마 색상 변환 기준)
+    color_depth: ColorDepth,
     /// 현재 IME 상태
     pub ime_status: ImeStatus,
     /// 기본 터미널 에디터 명령 (런타임 프리셋/환경변수 기반)
     default_terminal_editor: String,
-    /// 메인 루프에서 처리할 터미널 에디터 실행 요청
-    pending_terminal_editor_request: Option<TerminalEditorRequest>,
-    /// 메인 루프에서 처리할 터미널 명령 실행 요청
-    pending_terminal_command_request: Option<TerminalCommandRequest>,
+    /// 메인 루프가 실행할 외부 작업 요청 (에디터/명령/실행 파일/관리자 권한 재시도)
+    commands: Vec<Command>,
+    /// 매크로 레지스터와 녹화/재생 상태
+    macros: MacroState,
+    pending_bulk_rename: Option<BulkRename>,
+    /// 새 디렉토리를 만든 뒤 바로 들어가기 (입력 창에서 Ctrl+E, 세션 동안 유지)
+    mkdir_enter_created: bool,
+    /// "끝나면 종료"를 고른 뒤 진행 중인 작업이 모두 끝나기를 기다리는 중
+    quit_after_jobs: bool,
+    /// 최근 끝난 백그라운드 작업 기록
+    jobs: JobLog,
     /// 전역 북마크 목록
     bookmarks: Vec<PersistedBookmark>,
+    bookmark_collapsed_groups: Vec<String>,
+    /// 패널당 최대 탭 수 (설정 파일 `max_tabs_per_panel`)
+    max_tabs_per_panel: usize,
+    /// 로그 기록 수준 (설정 파일 `log_level`, 잘못된 값은 무시)
+    log_level: Option<LevelFilter>,
+    /// 초당 최대 화면 갱신 횟수 (설정 파일 `frame_rate`)
+    frame_rate: u32,
+    /// 유휴 상태 확인 주기 (설정 파일 `idle_tick_ms`)
+    idle_tick_ms: u64,
+    /// 압축 스레드 수 (설정 파일 `compression_threads`, 0이면 CPU 수)
+    compression_threads: usize,
+    /// 사용 중인 파일 자동 재시도 횟수 (설정 파일 `busy_retry_attempts`)
+    busy_retry_attempts: u32,
+    /// 세션 동안 기억한 압축 비밀번호 (메모리에만 보관)
+    archive_passwords: ArchivePasswords,
+    /// 현재 창 아래에 쌓인 창과 그 확인 작업 (위 창을 닫으면 차례로 복귀)
+    dialog_stack: Vec<(DialogKind, Option<DialogContinuation>)>,
+    /// 마지막으로 오류와 함께 끝난 작업 보고서 (결과 창을 닫으면 비움)
+    error_report: Option<ErrorReport>,
+    /// ZIP 항목 이름 인코딩 기본값 (설정 파일 `zip_name_encoding`)
+    zip_name_encoding: ZipNameEncoding,
+    /// 압축 보기에서 압축 파일별로 고른 이름 인코딩 (세션 동안만)
+    archive_name_encodings: HashMap<PathBuf, ZipNameEncoding>,
+    /// 켜진 플러그인과 훅 호출 상태 (설정 파일 `plugins`)
+    plugins: PluginHost,
+    /// 백그라운드 작업이 끝났을 때 메인 루프 깨우기
+    waker: Waker,
     /// 테스트에서 설정 저장 경로를 격리하기 위한 override
     state_store_override: Option<PathBuf>,
+    /// 설정 파일 읽기/쓰기 비활성화 (`--no-persist`)
+    persist_disabled: bool,
+    /// 쉘 연동 모드 종료 출력 대상
+    choose_outputs: ChooseOutputs,
+    /// 종료 출력 기록 실패 메시지 (터미널 복구 후 stderr로 출력)
+    choose_output_error: Option<String>,
 }
 
 impl App {
-    const MAX_TABS_PER_PANEL: usize = 5;
+    const DEFAULT_MAX_TABS_PER_PANEL: usize = 9;
+    const MAX_TABS_PER_PANEL_LIMIT: usize = 20;
+    const DEFAULT_FRAME_RATE: u32 = 60;
+    const FRAME_RATE_RANGE: (u32, u32) = (5, 240);
+    const DEFAULT_IDLE_TICK_MS: u64 = 250;
+    const IDLE_TICK_MS_RANGE: (u64, u64) = (20, 5000);
+    const COMPRESSION_THREADS_LIMIT: usize = 64;
+    const DEFAULT_BUSY_RETRY_ATTEMPTS: u32 = 3;
+    const BUSY_RETRY_ATTEMPTS_LIMIT: u32 = 10;
     const APP_STATE_VERSION: u32 = 1;
     const FALLBACK_TERMINAL_EDITOR: &'static str = "vi";
+    const THEME_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+    const DISK_SPACE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+    const FOLLOW_FOCUS_DELAY: std::time::Duration = std::time::Duration::from_millis(200);
 
     fn resolve_default_terminal_editor_from_env() -> String {
         for key in ["VISUAL", "EDITOR"] {
@@ -241,6 +936,18 @@ impl App {
     }
 
     pub fn new_with_startup_path(startup_path: Option<PathBuf>) -> Result<Self> {
+        Self::new_with_options(StartupOptions {
+            left_path: startup_path.clone(),
+            right_path: startup_path,
+            ..StartupOptions::default()
+        })
+    }
+
+    /// 시작 옵션으로 앱 생성
+    ///
+    /// - 유효한 시작 경로가 하나라도 있으면 저장된 히스토리 복원을 건너뜀
+    /// - 유효하지 않은 패널 경로는 현재 작업 디렉토리로 대체
+    pub fn new_with_options(options: StartupOptions) -> Result<Self> {
         let current_dir = env::current_dir().unwrap_or_else(|_| {
             #[cfg(unix)]
             {
@@ -255,16 +962,19 @@ impl App {
                 std::path::PathBuf::from(".")
             }
         });
-        let startup_path = Self::normalize_startup_path(startup_path);
-        let initial_dir = startup_path.clone().unwrap_or(current_dir);
+        let left_path = Self::normalize_startup_path(options.left_path);
+        let right_path = Self::normalize_startup_path(options.right_path);
+        let has_startup_path = left_path.is_some() || right_path.is_some();
+        let left_dir = left_path.unwrap_or_else(|| current_dir.clone());
+        let right_dir = right_path.unwrap_or(current_dir);
 
-        let filesystem = FileSystem::new();
+        let filesystem = FileSystem::with_listing_cache(listing_cache::DEFAULT_MAX_BYTES);
 
         // 패널 상태 초기화 및 파일 목록 로드
-        let mut left_panel = PanelState::new(initial_dir.clone());
+        let mut left_panel = PanelState::new(left_dir);
         left_panel.refresh(&filesystem)?;
 
-        let mut right_panel = PanelState::new(initial_dir);
+        let mut right_panel = PanelState::new(right_dir);
         right_panel.refresh(&filesystem)?;
 
         let mut app = Self {
@@ -276,28 +986,94 @@ impl App {
             language: Language::English,
             menus: create_default_menus(Language::English),
             menu_state: MenuState::new(),
+            context_menu: None,
             theme_manager: ThemeManager::new(),
             dialog: None,
             pending_operation: None,
+            dialog_continuation: None,
+            chunked_copy: None,
             archive_worker: None,
+            disk_usage: None,
+            panel_sync: None,
+            file_finder: None,
+            file_compare: None,
+            frecency: FrecencyStore::default(),
+            git_status_enabled: true,
+            show_info_line: false,
+            show_path_line: false,
+            name_scroll: None,
+            breadcrumb: None,
+            show_type_column: false,
+            sync_browsing: false,
+            follow_focus: false,
+            follow_focus_pending: None,
+            git_status_events: mpsc::channel(),
+            git_status_pending: Vec::new(),
             archive_flow: None,
             archive_panel_view: None,
             archive_copy_temp_dir: None,
+            remote_panel_view: None,
+            remote_flow: None,
+            remote_hosts: Vec::new(),
             pending_key: None,
             pending_key_time: None,
             toast_message: None,
             icon_mode: crate::ui::components::panel::IconMode::default(),
             size_format: SizeFormat::default(),
+            sort_collation: NameCollation::default(),
+            dirs_first: true,
+            copy_options: CopyOptions::default(),
+            confirm_policy: ConfirmPolicy::default(),
+            auto_refresh: AutoRefreshIntervals::default(),
+            auto_refresh_last: (Instant::now(), Instant::now()),
+            hide_patterns: Vec::new(),
+            hide_patterns_revealed: false,
+            archive_presets: default_archive_presets(),
+            highlight_override: None,
+            open_rules: OpenRules::default(),
+            last_theme_poll: Instant::now(),
+            disk_space: None,
+            last_disk_space_poll: Instant::now(),
+            color_mode: ColorMode::Auto,
+            color_depth: ColorMode::Auto.resolve(),
             ime_status: Self::initial_ime_status(),
             default_terminal_editor: Self::resolve_default_terminal_editor_from_env(),
-            pending_terminal_editor_request: None,
-            pending_terminal_command_request: None,
+            commands: Vec::new(),
+            macros: MacroState::default(),
+            pending_bulk_rename: None,
+            mkdir_enter_created: false,
+            quit_after_jobs: false,
+            jobs: JobLog::default(),
             bookmarks: Vec::new(),
+            bookmark_collapsed_groups: Vec::new(),
+            max_tabs_per_panel: Self::DEFAULT_MAX_TABS_PER_PANEL,
+            frame_rate: Self::DEFAULT_FRAME_RATE,
+            idle_tick_ms: Self::DEFAULT_IDLE_TICK_MS,
+            compression_threads: 0,
+            busy_retry_attempts: Self::DEFAULT_BUSY_RETRY_ATTEMPTS,
+            archive_passwords: ArchivePasswords::default(),
+            dialog_stack: Vec::new(),
+            error_report: None,
+            zip_name_encoding: ZipNameEncoding::default(),
+            archive_name_encodings: HashMap::new(),
+            plugins: PluginHost::default(),
+            waker: Waker::default(),
+            log_level: None,
             state_store_override: None,
+            persist_disabled: false,
+            choose_outputs: ChooseOutputs::default(),
+            choose_output_error: None,
         };
-        if startup_path.is_none() {
+        app.persist_disabled = options.no_persist;
+        app.load_highlight_rules();
+        app.load_open_rules();
+        app.load_custom_themes();
+        if !has_startup_path {
             app.load_persisted_state();
         }
+        if let Some(theme) = options.theme.as_deref() {
+            let _ = app.theme_manager.switch_theme(theme);
+        }
         Ok(app)
     }
 
@@ -352,41 +1128,174 @@ impl App {
             layout: LayoutManager::new(),
             left_tabs: PanelTabs::new(PanelState::new(current_dir.clone())),
             right_tabs: PanelTabs::new(PanelState::new(current_dir)),
-            filesystem: FileSystem::new(),
+            filesystem: FileSystem::with_listing_cache(listing_cache::DEFAULT_MAX_BYTES),
             language: Language::English,
             menus: create_default_menus(Language::English),
             menu_state: MenuState::new(),
+            context_menu: None,
             theme_manager: ThemeManager::new(),
             dialog: None,
             pending_operation: None,
+            dialog_continuation: None,
+            chunked_copy: None,
             archive_worker: None,
+            disk_usage: None,
+            panel_sync: None,
+            file_finder: None,
+            file_compare: None,
+            frecency: FrecencyStore::default(),
+            git_status_enabled: true,
+            show_info_line: false,
+            show_path_line: false,
+            name_scroll: None,
+            breadcrumb: None,
+            show_type_column: false,
+            sync_browsing: false,
+            follow_focus: false,
+            follow_focus_pending: None,
+            git_status_events: mpsc::channel(),
+            git_status_pending: Vec::new(),
             archive_flow: None,
             archive_panel_view: None,
             archive_copy_temp_dir: None,
+            remote_panel_view: None,
+            remote_flow: None,
+            remote_hosts: Vec::new(),
             pending_key: None,
             pending_key_time: None,
             toast_message: None,
             icon_mode: crate::ui::components::panel::IconMode::default(),
             size_format: SizeFormat::default(),
+            sort_collation: NameCollation::default(),
+            dirs_first: true,
+            copy_options: CopyOptions::default(),
+            confirm_policy: ConfirmPolicy::default(),
+            auto_refresh: AutoRefreshIntervals::default(),
+            auto_refresh_last: (Instant::now(), Instant::now()),
+            hide_patterns: Vec::new(),
+            hide_patterns_revealed: false,
+            archive_presets: default_archive_presets(),
+            highlight_override: None,
+            open_rules: OpenRules::default(),
+            last_theme_poll: Instant::now(),
+            disk_space: None,
+            last_disk_space_poll: Instant::now(),
+            color_mode: ColorMode::Auto,
+            color_depth: ColorDepth::TrueColor,
             ime_status: ImeStatus::Unknown,
             default_terminal_editor: Self::FALLBACK_TERMINAL_EDITOR.to_string(),
-            pending_terminal_editor_request: None,
-            pending_terminal_command_request: None,
+            commands: Vec::new(),
+            macros: MacroState::default(),
+            pending_bulk_rename: None,
+            mkdir_enter_created: false,
+            quit_after_jobs: false,
+            jobs: JobLog::default(),
             bookmarks: Vec::new(),
+            bookmark_collapsed_groups: Vec::new(),
+            max_tabs_per_panel: Self::DEFAULT_MAX_TABS_PER_PANEL,
+            frame_rate: Self::DEFAULT_FRAME_RATE,
+            idle_tick_ms: Self::DEFAULT_IDLE_TICK_MS,
+            compression_threads: 0,
+            busy_retry_attempts: Self::DEFAULT_BUSY_RETRY_ATTEMPTS,
+            archive_passwords: ArchivePasswords::default(),
+            dialog_stack: Vec::new(),
+            error_report: None,
+            zip_name_encoding: ZipNameEncoding::default(),
+            archive_name_encodings: HashMap::new(),
+            plugins: PluginHost::default(),
+            waker: Waker::default(),
+            log_level: None,
             state_store_override: Some(state_store_override),
+            persist_disabled: false,
+            choose_outputs: ChooseOutputs::default(),
+            choose_output_error: None,
         }
     }
 
     /// 종료
     pub fn quit(&mut self) {
         let _ = self.save_persisted_state();
+        if let Err(error) = self.write_choose_outputs() {
+            self.choose_output_error = Some(error.to_string());
+        }
         self.should_quit = true;
     }
 
+    /// 쉘 연동 모드 종료 출력 대상 설정
+    pub fn set_choose_outputs(&mut self, outputs: ChooseOutputs) {
+        self.choose_outputs = outputs;
+    }
+
+    /// 종료 출력 기록 실패 메시지
+    pub fn choose_output_error(&self) -> Option<&str> {
+        self.choose_output_error.as_deref()
+    }
+
+    /// 종료 시점의 마지막 활성 디렉토리 (압축 패널 탐색 중이면 압축 파일 위치)
+    pub(crate) fn chosen_directory(&self) -> PathBuf {
+        if let Some(view) = self.active_remote_view() {
+            return view.local_dir.clone();
+        }
+        if self.is_active_panel_archive_view() {
+            if let Some(view) = &self.archive_panel_view {
+                return view.base_dir.clone();
+            }
+        }
+        self.active_path().to_path_buf()
+    }
+
+    /// 종료 시점의 선택 파일 목록 (선택 없으면 커서 항목, 압축 내부 항목은 제외)
+    pub(crate) fn chosen_files(&self) -> Vec<PathBuf> {
+        if !self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            return Vec::new();
+        }
+        self.get_operation_sources()
+    }
+
+    fn write_choose_outputs(&self) -> std::io::Result<()> {
+        if let Some(path) = &self.choose_outputs.dir_file {
+            let dir = self.chosen_directory();
+            fs::write(path, dir.to_string_lossy().as_bytes())?;
+        }
+        if let Some(path) = &self.choose_outputs.files_file {
+            let mut data = String::new();
+            for file in self.chosen_files() {
+                data.push_str(&file.to_string_lossy());
+                data.push('\n');
+            }
+            fs::write(path, data)?;
+        }
+        Ok(())
+    }
+
     fn state_store_path(&self) -> Option<PathBuf> {
+        if self.persist_disabled {
+            return None;
+        }
+        self.settings_file_path()
+    }
+
+    /// 설정 파일 경로 (영속화 비활성 여부와 무관)
+    fn settings_file_path(&self) -> Option<PathBuf> {
         if let Some(path) = &self.state_store_override {
             return Some(path.clone());
         }
+        Self::default_settings_file_path()
+    }
+
+    /// 기본 설정 파일 경로 (`BOKSLDIR_SETTINGS_FILE` 또는 `~/.boksldir/settings.toml`)
+    /// 로그 파일 경로 (기본 설정 파일과 같은 위치의 boksldir.log)
+    pub fn default_log_file_path() -> Option<PathBuf> {
+        let settings = Self::default_settings_file_path()?;
+        Some(settings.parent()?.join(logging::LOG_FILE_NAME))
+    }
+
+    /// 설정 파일의 로그 수준 (`--log-level`이 없을 때 적용)
+    pub fn configured_log_level(&self) -> Option<LevelFilter> {
+        self.log_level
+    }
+
+    pub fn default_settings_file_path() -> Option<PathBuf> {
         if let Ok(custom) = env::var("BOKSLDIR_SETTINGS_FILE") {
             let trimmed = custom.trim();
             if !trimmed.is_empty() {
@@ -398,6 +1307,105 @@ impl App {
             .map(|home| home.join(".boksldir").join("settings.toml"))
     }
 
+    /// 사용자 테마 디렉토리 (설정 파일과 같은 위치의 themes/)
+    pub fn themes_dir_for(settings_file: &Path) -> Option<PathBuf> {
+        Some(settings_file.parent()?.join("themes"))
+    }
+
+    /// 사용자 테마 로드 및 핫 리로드 감시 시작
+    pub fn load_custom_themes(&mut self) {
+        if let Some(dir) = self
+            .settings_file_path()
+            .and_then(|path| Self::themes_dir_for(&path))
+        {
+            self.theme_manager.load_custom_themes(&dir);
+        }
+    }
+
+    /// 사용자 테마 파일 변경 감지 (메인 루프에서 주기적으로 호출)
+    pub fn poll_theme_files(&mut self) {
+        if self.last_theme_poll.elapsed() < Self::THEME_POLL_INTERVAL {
+            return;
+        }
+        self.last_theme_poll = Instant::now();
+        if self.theme_manager.reload_if_changed() {
+            let name = self.current_theme_name().to_string();
+            self.set_toast(&format!("Theme reloaded: {}", name));
+        }
+    }
+
+    /// 활성 패널 디스크 용량 갱신 (경로가 바뀌었거나 주기가 지나면 다시 조회)
+    ///
+    /// 원격/마운트된 백엔드 패널은 조회하지 않습니다. 메인 루프에서 호출합니다.
+    pub fn poll_disk_space(&mut self) {
+        if !self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            self.disk_space = None;
+            return;
+        }
+        let panel = self.active_panel_state();
+        let path = panel.current_path.clone();
+        let same_path = self
+            .disk_space
+            .as_ref()
+            .is_some_and(|(cached, _)| *cached == path);
+        if same_path && self.last_disk_space_poll.elapsed() < Self::DISK_SPACE_POLL_INTERVAL {
+            return;
+        }
+        self.last_disk_space_poll = Instant::now();
+        self.disk_space = diskspace::disk_space(&path).map(|space| (path, space));
+    }
+
+    /// 활성 패널 경로의 디스크 용량 (아직 조회 전이거나 경로가 바뀌었으면 None)
+    pub fn active_disk_space(&self) -> Option<DiskSpace> {
+        self.disk_space
+            .as_ref()
+            .filter(|(path, _)| *path == self.active_panel_state().current_path)
+            .map(|(_, space)| *space)
+    }
+
+    /// 전역 하이라이트 규칙 파일 (설정 파일과 같은 위치의 highlight.toml)
+    fn highlight_rules_path(&self) -> Option<PathBuf> {
+        let settings = self.settings_file_path()?;
+        Some(settings.parent()?.join("highlight.toml"))
+    }
+
+    /// 전역 하이라이트 규칙 로드 (파일이 없거나 잘못되면 테마 규칙 사용)
+    pub fn load_highlight_rules(&mut self) {
+        self.highlight_override = self
+            .highlight_rules_path()
+            .filter(|path| path.is_file())
+            .and_then(|path| HighlightRules::from_file(&path).ok());
+    }
+
+    /// Enter 동작 규칙 파일 경로 (설정 파일과 같은 위치의 open.toml)
+    fn open_rules_path(&self) -> Option<PathBuf> {
+        let settings = self.settings_file_path()?;
+        Some(settings.parent()?.join("open.toml"))
+    }
+
+    /// Enter 동작 규칙 로드 (파일이 없거나 잘못되면 내장 규칙 사용)
+    pub fn load_open_rules(&mut self) {
+        self.open_rules = self
+            .open_rules_path()
+            .filter(|path| path.is_file())
+            .and_then(|path| OpenRules::from_file(&path).ok())
+            .unwrap_or_default();
+    }
+
+    /// 현재 적용할 파일 하이라이트 규칙
+    pub fn highlight_rules(&self) -> &HighlightRules {
+        self.highlight_override
+            .as_ref()
+            .unwrap_or(&self.theme_manager.current().highlight)
+    }
+
+    /// 이름 있는 세션 저장 디렉토리 (설정 파일과 같은 위치의 sessions/)
+    fn sessions_dir(&self) -> Option<PathBuf> {
+        let settings = self.state_store_path()?;
+        let parent = settings.parent()?;
+        Some(parent.join("sessions"))
+    }
+
     fn encode_app_state(&self) -> std::result::Result<String, toml::ser::Error> {
         let left = self.left_tabs.active();
         let right = self.right_tabs.active();
@@ -406,16 +1414,43 @@ impl App {
             theme: self.current_theme_name().to_string(),
             language: self.language.code().to_string(),
             history: PersistedHistoriesState {
-                left: PersistedPanelHistory {
-                    entries: left.history_entries.clone(),
-                    index: left.history_index,
-                },
-                right: PersistedPanelHistory {
-                    entries: right.history_entries.clone(),
-                    index: right.history_index,
-                },
+                left: PersistedPanelHistory::capture(left),
+                right: PersistedPanelHistory::capture(right),
             },
             bookmarks: self.bookmarks.clone(),
+            sort: PersistedSortOptions {
+                natural: self.sort_collation.natural,
+                locale: self.sort_collation.locale,
+                dirs_first: self.dirs_first,
+            },
+            color_mode: self.color_mode,
+            copy: self.copy_options.into(),
+            confirm: self.confirm_policy,
+            auto_refresh: self.auto_refresh,
+            hide_patterns: self.hide_patterns.clone(),
+            archive_presets: self.archive_presets.clone(),
+            remote: PersistedRemote {
+                hosts: self.remote_hosts.clone(),
+            },
+            git_status: self.git_status_enabled,
+            info_line: self.show_info_line,
+            path_line: self.show_path_line,
+            type_column: self.show_type_column,
+            frecency: self.frecency.clone(),
+            bookmark_collapsed_groups: self.bookmark_collapsed_groups.clone(),
+            max_tabs_per_panel: self.max_tabs_per_panel,
+            frame_rate: self.frame_rate,
+            idle_tick_ms: self.idle_tick_ms,
+            compression_threads: self.compression_threads,
+            busy_retry_attempts: self.busy_retry_attempts,
+            archive_password_per_archive: self.archive_passwords.per_archive(),
+            zip_name_encoding: self.zip_name_encoding,
+            lazy_metadata: self.filesystem.lazy_metadata(),
+            plugins: self.plugins.enabled_names().to_vec(),
+            log_level: self
+                .log_level
+                .map(|level| level.as_str().to_ascii_lowercase()),
+            tabs: Some(PersistedTabs::from_session(self.capture_session(""))),
         };
         toml::to_string_pretty(&payload)
     }
@@ -441,36 +1476,88 @@ impl App {
         let data = self
             .encode_app_state()
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-        fs::write(path, data)
+        fs::write(&path, data).inspect_err(|e| {
+            log::warn!("failed to save state to {}: {}", path.display(), e);
+        })
     }
 
     fn load_persisted_state(&mut self) {
         let Some(path) = self.state_store_path() else {
             return;
         };
-        let Ok(data) = fs::read_to_string(path) else {
+        let Ok(data) = fs::read_to_string(&path) else {
             return;
         };
         let Some(state) = Self::decode_app_state(&data) else {
+            log::warn!("ignoring unreadable state file {}", path.display());
             return;
         };
 
-        self.apply_loaded_history(
-            ActivePanel::Left,
-            state.history.left.entries,
-            state.history.left.index,
-        );
-        self.apply_loaded_history(
-            ActivePanel::Right,
-            state.history.right.entries,
-            state.history.right.index,
-        );
+        self.apply_loaded_history(ActivePanel::Left, state.history.left);
+        self.apply_loaded_history(ActivePanel::Right, state.history.right);
         self.bookmarks = state.bookmarks;
+        self.bookmark_collapsed_groups = state.bookmark_collapsed_groups;
+        self.log_level = state.log_level.as_deref().and_then(logging::parse_level);
+        self.max_tabs_per_panel = state
+            .max_tabs_per_panel
+            .clamp(1, Self::MAX_TABS_PER_PANEL_LIMIT);
+        self.frame_rate = state
+            .frame_rate
+            .clamp(Self::FRAME_RATE_RANGE.0, Self::FRAME_RATE_RANGE.1);
+        self.idle_tick_ms = state
+            .idle_tick_ms
+            .clamp(Self::IDLE_TICK_MS_RANGE.0, Self::IDLE_TICK_MS_RANGE.1);
+        self.compression_threads = state
+            .compression_threads
+            .min(Self::COMPRESSION_THREADS_LIMIT);
+        self.busy_retry_attempts = state
+            .busy_retry_attempts
+            .min(Self::BUSY_RETRY_ATTEMPTS_LIMIT);
+        self.archive_passwords
+            .set_per_archive(state.archive_password_per_archive);
+        self.zip_name_encoding = state.zip_name_encoding;
+        self.filesystem.set_lazy_metadata(state.lazy_metadata);
+        self.load_plugins(state.plugins);
+        self.apply_sort_preferences(
+            NameCollation {
+                natural: state.sort.natural,
+                locale: state.sort.locale,
+            },
+            state.sort.dirs_first,
+        );
+        self.hide_patterns = state.hide_patterns;
+        self.apply_hide_patterns();
+        // 정렬/탭 수/숨김 패턴 설정을 적용한 뒤 탭 복원 (활성 탭은 위에서 불러온 히스토리를 이어받음)
+        if let Some(tabs) = state.tabs {
+            self.restore_session(&tabs.into_session());
+        }
+        self.copy_options = state.copy.into();
+        self.confirm_policy = state.confirm;
+        self.auto_refresh = state.auto_refresh;
+        self.archive_presets = state.archive_presets;
+        self.remote_hosts = state.remote.hosts;
+        self.remote_hosts.truncate(MAX_REMOTE_HOSTS);
+        self.git_status_enabled = state.git_status;
+        self.show_info_line = state.info_line;
+        self.show_path_line = state.path_line;
+        self.show_type_column = state.type_column;
+        self.frecency = state.frecency;
         let _ = self.theme_manager.switch_theme(&state.theme);
+        self.set_color_mode(state.color_mode);
         self.language = Language::from_code(&state.language);
         self.rebuild_localized_ui();
     }
 
+    /// 색상 출력 모드 설정 (깊이 재계산)
+    pub fn set_color_mode(&mut self, mode: ColorMode) {
+        self.color_mode = mode;
+        self.color_depth = mode.resolve();
+    }
+
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
     fn current_theme_name(&self) -> &str {
         self.theme_manager.current_name()
     }
@@ -495,42 +1582,39 @@ impl App {
         self.menus = create_default_menus(self.language);
     }
 
-    fn apply_loaded_history(
-        &mut self,
-        panel_kind: ActivePanel,
-        entries: Vec<PathBuf>,
-        index: usize,
-    ) {
+    fn apply_loaded_history(&mut self, panel_kind: ActivePanel, history: PersistedPanelHistory) {
         let fallback = match panel_kind {
             ActivePanel::Left => self.left_tabs.active().current_path.clone(),
             ActivePanel::Right => self.right_tabs.active().current_path.clone(),
         };
 
-        let mut valid_entries: Vec<PathBuf> = entries
-            .into_iter()
-            .filter(|p| p.exists() && p.is_dir())
-            .collect();
+        let mut visits = HashMap::new();
+        let mut valid_entries: Vec<PathBuf> = Vec::new();
+        for (idx, path) in history.entries.into_iter().enumerate() {
+            if !(path.exists() && path.is_dir()) {
+                continue;
+            }
+            if let Some(&time) = history.times.get(idx).filter(|time| **time > 0) {
+                visits.insert(path.clone(), time);
+            }
+            valid_entries.push(path);
+        }
         if valid_entries.is_empty() {
             valid_entries.push(fallback.clone());
         }
 
-        let clamped_index = index.min(valid_entries.len().saturating_sub(1));
+        let clamped_index = history.index.min(valid_entries.len().saturating_sub(1));
         let restore_path = valid_entries[clamped_index].clone();
 
-        match panel_kind {
-            ActivePanel::Left => {
-                let panel = self.left_tabs.active_mut();
-                panel.history_entries = valid_entries;
-                panel.history_index = clamped_index;
-                let _ = panel.change_directory(restore_path, &self.filesystem);
-            }
-            ActivePanel::Right => {
-                let panel = self.right_tabs.active_mut();
-                panel.history_entries = valid_entries;
-                panel.history_index = clamped_index;
-                let _ = panel.change_directory(restore_path, &self.filesystem);
-            }
-        }
+        let panel = match panel_kind {
+            ActivePanel::Left => self.left_tabs.active_mut(),
+            ActivePanel::Right => self.right_tabs.active_mut(),
+        };
+        panel.history_entries = valid_entries;
+        panel.history_index = clamped_index;
+        panel.history_visits = visits;
+        panel.history_pins = history.pinned;
+        let _ = panel.change_directory(restore_path, &self.filesystem);
     }
 
     /// 종료 상태 확인
@@ -558,30 +1642,22 @@ impl App {
     /// 활성 패널에 새 탭 생성
     pub fn new_tab_active_panel(&mut self) {
         let i18n = I18n::new(self.language);
-        match self.active_panel() {
-            ActivePanel::Left => {
-                if self.left_tabs.len() >= Self::MAX_TABS_PER_PANEL {
-                    self.set_toast(i18n.msg(MessageKey::MaxTabsPerPanel));
-                    return;
-                }
-                let from = self.left_tabs.active().clone();
-                let idx = self.left_tabs.create_tab(&from);
-                self.set_toast(
-                    &i18n.fmt(MessageKey::TabCreated, &[("index", (idx + 1).to_string())]),
-                );
-            }
-            ActivePanel::Right => {
-                if self.right_tabs.len() >= Self::MAX_TABS_PER_PANEL {
-                    self.set_toast(i18n.msg(MessageKey::MaxTabsPerPanel));
-                    return;
-                }
-                let from = self.right_tabs.active().clone();
-                let idx = self.right_tabs.create_tab(&from);
-                self.set_toast(
-                    &i18n.fmt(MessageKey::TabCreated, &[("index", (idx + 1).to_string())]),
-                );
-            }
+        let max_tabs = self.max_tabs_per_panel;
+        let tabs = match self.active_panel() {
+            ActivePanel::Left => &mut self.left_tabs,
+            ActivePanel::Right => &mut self.right_tabs,
+        };
+        if tabs.len() >= max_tabs {
+            self.set_toast(&i18n.fmt(
+                MessageKey::MaxTabsPerPanel,
+                &[("count", max_tabs.to_string())],
+            ));
+            return;
         }
+        let mut from = tabs.active().clone();
+        from.tab_title = None;
+        let idx = tabs.create_tab(&from);
+        self.set_toast(&i18n.fmt(MessageKey::TabCreated, &[("index", (idx + 1).to_string())]));
     }
 
     /// 활성 패널의 현재 탭 닫기
@@ -743,7 +1819,7 @@ impl Default for App {
         // Default는 에러를 무시하고 기본값 사용
         Self::new().unwrap_or_else(|_| {
             let current_dir = std::path::PathBuf::from(".");
-            let filesystem = FileSystem::new();
+            let filesystem = FileSystem::with_listing_cache(listing_cache::DEFAULT_MAX_BYTES);
 
             Self {
                 should_quit: false,
@@ -754,24 +1830,83 @@ impl Default for App {
                 language: Language::English,
                 menus: create_default_menus(Language::English),
                 menu_state: MenuState::new(),
+                context_menu: None,
                 theme_manager: ThemeManager::new(),
                 dialog: None,
                 pending_operation: None,
+                dialog_continuation: None,
+                chunked_copy: None,
                 archive_worker: None,
+                disk_usage: None,
+                panel_sync: None,
+                file_finder: None,
+                file_compare: None,
+                frecency: FrecencyStore::default(),
+                git_status_enabled: true,
+                show_info_line: false,
+                show_path_line: false,
+                name_scroll: None,
+                breadcrumb: None,
+                show_type_column: false,
+                sync_browsing: false,
+                follow_focus: false,
+                follow_focus_pending: None,
+                git_status_events: mpsc::channel(),
+                git_status_pending: Vec::new(),
                 archive_flow: None,
                 archive_panel_view: None,
                 archive_copy_temp_dir: None,
+                remote_panel_view: None,
+                remote_flow: None,
+                remote_hosts: Vec::new(),
                 pending_key: None,
                 pending_key_time: None,
                 toast_message: None,
                 icon_mode: crate::ui::components::panel::IconMode::default(),
                 size_format: SizeFormat::default(),
+                sort_collation: NameCollation::default(),
+                dirs_first: true,
+                copy_options: CopyOptions::default(),
+                confirm_policy: ConfirmPolicy::default(),
+                auto_refresh: AutoRefreshIntervals::default(),
+                auto_refresh_last: (Instant::now(), Instant::now()),
+                hide_patterns: Vec::new(),
+                hide_patterns_revealed: false,
+                archive_presets: default_archive_presets(),
+                highlight_override: None,
+                open_rules: OpenRules::default(),
+                last_theme_poll: Instant::now(),
+                disk_space: None,
+                last_disk_space_poll: Instant::now(),
+                color_mode: ColorMode::Auto,
+                color_depth: ColorMode::Auto.resolve(),
                 ime_status: ImeStatus::Unknown,
                 default_terminal_editor: Self::FALLBACK_TERMINAL_EDITOR.to_string(),
-                pending_terminal_editor_request: None,
-                pending_terminal_command_request: None,
+                commands: Vec::new(),
+                macros: MacroState::default(),
+                pending_bulk_rename: None,
+                mkdir_enter_created: false,
+                quit_after_jobs: false,
+                jobs: JobLog::default(),
                 bookmarks: Vec::new(),
+                bookmark_collapsed_groups: Vec::new(),
+                max_tabs_per_panel: Self::DEFAULT_MAX_TABS_PER_PANEL,
+                frame_rate: Self::DEFAULT_FRAME_RATE,
+                idle_tick_ms: Self::DEFAULT_IDLE_TICK_MS,
+                compression_threads: 0,
+                busy_retry_attempts: Self::DEFAULT_BUSY_RETRY_ATTEMPTS,
+                archive_passwords: ArchivePasswords::default(),
+                dialog_stack: Vec::new(),
+                error_report: None,
+                zip_name_encoding: ZipNameEncoding::default(),
+                archive_name_encodings: HashMap::new(),
+                plugins: PluginHost::default(),
+                waker: Waker::default(),
+                log_level: None,
                 state_store_override: None,
+                persist_disabled: false,
+                choose_outputs: ChooseOutputs::default(),
+                choose_output_error: None,
             }
         })
     }
@@ -0,0 +1,75 @@
+use super::*;
+use crate::ui::{FuzzyListItem, FuzzyListPurpose};
+
+impl App {
+    // === ZIP 항목 이름 인코딩 (옛 압축 프로그램의 CP949 이름) ===
+
+    /// 압축 파일 항목 이름 인코딩 (압축 보기에서 고른 인코딩, 없으면 설정 파일 `zip_name_encoding`)
+    pub(super) fn archive_name_encoding(&self, archive_path: &Path) -> ZipNameEncoding {
+        self.archive_name_encodings
+            .get(archive_path)
+            .copied()
+            .unwrap_or(self.zip_name_encoding)
+    }
+
+    /// 이름 인코딩 고르기 (압축 보기에서, 인코딩마다 ASCII가 아닌 첫 이름을 미리 보여줌)
+    pub fn show_archive_encoding_list(&mut self) {
+        let Some(archive_path) = self
+            .archive_panel_view
+            .as_ref()
+            .filter(|_| self.is_active_panel_archive_view())
+            .map(|view| view.archive_path.clone())
+        else {
+            self.set_toast("Name encoding can be changed in the archive view");
+            return;
+        };
+        if !detect_archive_format(&archive_path).is_some_and(supports_name_encoding) {
+            self.set_toast("Only zip archives need a name encoding");
+            return;
+        }
+        let sample = zip_name_sample(&archive_path);
+        let current = self.archive_name_encoding(&archive_path);
+        let items = ZipNameEncoding::ALL
+            .into_iter()
+            .map(|encoding| {
+                let preview = sample
+                    .as_deref()
+                    .map(|raw| encoding.decode(raw))
+                    .unwrap_or_default();
+                FuzzyListItem {
+                    label: encoding.label().to_string(),
+                    detail: if encoding == current {
+                        format!("* {}", preview)
+                    } else {
+                        preview
+                    },
+                }
+            })
+            .collect();
+        self.dialog = Some(DialogKind::fuzzy_list(
+            "Archive name encoding",
+            FuzzyListPurpose::ArchiveNameEncoding,
+            items,
+        ));
+    }
+
+    /// 고른 인코딩을 이 압축 파일에 기억하고 압축 보기 다시 읽기
+    pub(super) fn apply_archive_name_encoding(&mut self, label: &str) {
+        let Some(encoding) = ZipNameEncoding::ALL
+            .into_iter()
+            .find(|encoding| encoding.label() == label)
+        else {
+            return;
+        };
+        let Some(archive_path) = self
+            .archive_panel_view
+            .as_ref()
+            .map(|view| view.archive_path.clone())
+        else {
+            return;
+        };
+        self.archive_name_encodings.insert(archive_path, encoding);
+        self.reload_archive_view();
+        self.set_toast(&format!("Name encoding: {}", encoding.label()));
+    }
+}
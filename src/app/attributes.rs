@@ -0,0 +1,193 @@
+use super::*;
+use crate::system::attributes::{self, Attribute, FileAttributes, QUARANTINE_XATTR};
+
+/// 속성 다이얼로그에 표시하는 확장 속성 값 최대 길이
+const XATTR_VALUE_MAX_CHARS: usize = 48;
+
+impl App {
+    // === 속성 다이얼로그의 읽기 전용 / 숨김 / 시스템 전환 ===
+
+    /// 속성 다이얼로그에 표시할 속성 (압축/원격 보기 등 로컬 파일이 아니면 None)
+    pub(super) fn properties_attributes(&self, path: &Path) -> Option<FileAttributes> {
+        if !self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            return None;
+        }
+        attributes::read_attributes(path).ok()
+    }
+
+    /// 속성 다이얼로그에 표시할 확장 속성 (로컬 파일이 아니거나 읽지 못하면 빈 목록)
+    pub(super) fn properties_xattrs(&self, path: &Path) -> Vec<(String, String)> {
+        if !self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            return Vec::new();
+        }
+        attributes::list_xattrs(path)
+            .map(|attrs| {
+                attrs
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let shown = attributes::format_xattr_value(&value, XATTR_VALUE_MAX_CHARS);
+                        (name, shown)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 속성 다이얼로그의 확장 속성 선택 이동
+    pub fn properties_xattr_move(&mut self, down: bool) {
+        if let Some(DialogKind::Properties {
+            xattrs,
+            xattr_index,
+            ..
+        }) = &mut self.dialog
+        {
+            if xattrs.is_empty() {
+                return;
+            }
+            *xattr_index = if down {
+                (*xattr_index + 1).min(xattrs.len() - 1)
+            } else {
+                xattr_index.saturating_sub(1)
+            };
+        }
+    }
+
+    /// macOS 격리 속성(`com.apple.quarantine`)을 작업 대상에서 제거하고 다이얼로그 갱신
+    pub fn remove_properties_quarantine(&mut self) {
+        let Some(DialogKind::Properties { path, xattrs, .. }) = &self.dialog else {
+            return;
+        };
+        if !cfg!(target_os = "macos") {
+            self.set_toast("Quarantine attribute is only available on macOS");
+            return;
+        }
+        if !xattrs.iter().any(|(name, _)| name == QUARANTINE_XATTR) {
+            return;
+        }
+        let shown = PathBuf::from(path);
+        let mut sources = self.get_operation_sources();
+        if sources.is_empty() {
+            sources.push(shown);
+        }
+
+        let mut errors = Vec::new();
+        let mut removed = 0usize;
+        for source in &sources {
+            let has_quarantine = attributes::list_xattrs(source)
+                .map(|attrs| attrs.iter().any(|(name, _)| name == QUARANTINE_XATTR))
+                .unwrap_or(false);
+            if !has_quarantine {
+                continue;
+            }
+            match attributes::remove_xattr(source, QUARANTINE_XATTR) {
+                Ok(()) => removed += 1,
+                Err(e) => errors.push(format!("{}: {}", display_file_name(source), e)),
+            }
+        }
+
+        self.show_properties();
+        if !errors.is_empty() {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error("Remove quarantine", None, &errors.join("\n"), ""),
+            ));
+            return;
+        }
+        let i18n = I18n::new(self.language);
+        self.set_toast(&i18n.fmt(
+            MessageKey::QuarantineRemoved,
+            &[("count", removed.to_string())],
+        ));
+    }
+
+    /// 속성 전환을 작업 대상(선택 항목 또는 포커스 항목)에 적용하고 다이얼로그 갱신
+    ///
+    /// 새 값은 다이얼로그에 표시된 항목 기준으로 결정하며, 실패는 모아서 한 번에 보여줍니다.
+    pub fn toggle_properties_attribute(&mut self, attribute: Attribute) {
+        let Some(DialogKind::Properties {
+            path,
+            attributes: Some(current),
+            ..
+        }) = &self.dialog
+        else {
+            return;
+        };
+        let Some(enabled) = current.get(attribute).map(|value| !value) else {
+            return;
+        };
+        let shown = PathBuf::from(path);
+
+        let mut sources = self.get_operation_sources();
+        if sources.is_empty() {
+            sources.push(shown.clone());
+        }
+        let was_selected = !self.active_panel_state().selected_items.is_empty();
+
+        let mut errors = Vec::new();
+        let mut updated = Vec::new();
+        for source in &sources {
+            match attributes::set_attribute(source, attribute, enabled) {
+                Ok(new_path) => updated.push((source.clone(), new_path)),
+                Err(e) => errors.push(format!("{}: {}", display_file_name(source), e)),
+            }
+        }
+
+        // 숨김으로 바꾼 항목이 목록에서 사라지지 않도록 대상 패널에서는 숨김 파일 표시
+        if attribute == Attribute::Hidden && enabled {
+            self.active_panel_state_mut().show_hidden = true;
+        }
+        self.refresh_both_panels();
+
+        let shown_now = updated
+            .iter()
+            .find(|(old, _)| *old == shown)
+            .map(|(_, new)| new.clone())
+            .unwrap_or(shown);
+        if was_selected {
+            self.reselect_paths(updated.iter().map(|(_, new)| new));
+        }
+        let focused = shown_now
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .is_some_and(|name| self.focus_active_entry_by_name(&name));
+        if focused {
+            self.show_properties();
+        } else {
+            self.close_dialog();
+        }
+
+        if !errors.is_empty() {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error("Attributes", None, &errors.join("\n"), ""),
+            ));
+            return;
+        }
+        let i18n = I18n::new(self.language);
+        self.set_toast(&i18n.fmt(
+            MessageKey::AttributesUpdated,
+            &[("count", updated.len().to_string())],
+        ));
+    }
+
+    /// 새로고침으로 지워진 선택을 경로 기준으로 복원
+    fn reselect_paths<'a>(&mut self, paths: impl Iterator<Item = &'a PathBuf>) {
+        let paths: Vec<&PathBuf> = paths.collect();
+        let panel = self.active_panel_state_mut();
+        let indices: Vec<usize> = panel
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| paths.contains(&&entry.path))
+            .map(|(index, _)| index)
+            .collect();
+        panel.select_indices(indices);
+    }
+}
+
+/// 오류 목록에 표시할 항목 이름 (이름이 없으면 전체 경로)
+fn display_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
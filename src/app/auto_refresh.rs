@@ -0,0 +1,116 @@
+use super::*;
+
+/// 입력할 수 있는 최대 주기 (하루)
+const MAX_AUTO_REFRESH_SECS: u64 = 24 * 60 * 60;
+
+impl App {
+    // === 패널별 자동 새로고침 (설정 파일 `[auto_refresh]`) ===
+
+    /// 패널의 자동 새로고침 주기 (끄면 None)
+    pub fn auto_refresh_interval(&self, side: ActivePanel) -> Option<Duration> {
+        let secs = match side {
+            ActivePanel::Left => self.auto_refresh.left,
+            ActivePanel::Right => self.auto_refresh.right,
+        };
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
+
+    fn auto_refresh_last_mut(&mut self, side: ActivePanel) -> &mut Instant {
+        match side {
+            ActivePanel::Left => &mut self.auto_refresh_last.0,
+            ActivePanel::Right => &mut self.auto_refresh_last.1,
+        }
+    }
+
+    fn auto_refresh_due(&self, side: ActivePanel) -> Option<(Instant, Duration)> {
+        let interval = self.auto_refresh_interval(side)?;
+        let last = match side {
+            ActivePanel::Left => self.auto_refresh_last.0,
+            ActivePanel::Right => self.auto_refresh_last.1,
+        };
+        Some((last, interval))
+    }
+
+    /// 다음 새로고침까지 남은 초 (패널 제목 표시용, 끄면 None)
+    pub fn auto_refresh_remaining(&self, side: ActivePanel, now: Instant) -> Option<u64> {
+        let (last, interval) = self.auto_refresh_due(side)?;
+        let remaining = (last + interval).saturating_duration_since(now);
+        Some(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0))
+    }
+
+    /// 주기가 지난 패널 새로고침 (메인 루프에서 호출, 목록을 읽는 중이면 이번 주기는 건너뜀)
+    pub fn poll_auto_refresh(&mut self, now: Instant) {
+        for side in [ActivePanel::Left, ActivePanel::Right] {
+            let Some((last, interval)) = self.auto_refresh_due(side) else {
+                continue;
+            };
+            if now < last + interval {
+                continue;
+            }
+            *self.auto_refresh_last_mut(side) = now;
+            let panel = match side {
+                ActivePanel::Left => self.left_tabs.active_mut(),
+                ActivePanel::Right => self.right_tabs.active_mut(),
+            };
+            if !panel.is_loading() {
+                let _ = panel.refresh(&self.filesystem);
+            }
+        }
+    }
+
+    /// 남은 초 표시가 바뀌는 가장 이른 시각 (자동 새로고침을 켠 패널이 없으면 None)
+    pub(super) fn auto_refresh_deadline(&self, now: Instant) -> Option<Instant> {
+        [ActivePanel::Left, ActivePanel::Right]
+            .into_iter()
+            .filter_map(|side| self.auto_refresh_due(side))
+            .map(|(last, interval)| {
+                let elapsed = now.saturating_duration_since(last).as_secs();
+                last + Duration::from_secs(elapsed + 1).min(interval)
+            })
+            .min()
+    }
+
+    /// 활성 패널 자동 새로고침 주기 입력 시작
+    pub(super) fn start_auto_refresh_input(&mut self) {
+        let current = self
+            .auto_refresh_interval(self.active_panel())
+            .map_or_else(String::new, |interval| interval.as_secs().to_string());
+        self.open_auto_refresh_input(current);
+    }
+
+    fn open_auto_refresh_input(&mut self, initial: String) {
+        self.dialog = Some(DialogKind::auto_refresh_input(initial));
+        self.on_dialog_text(|app, value| app.confirm_auto_refresh_input(&value));
+    }
+
+    /// 입력한 주기 적용 (비우거나 0이면 끔, 설정 저장)
+    fn confirm_auto_refresh_input(&mut self, input: &str) {
+        let value = input.trim();
+        let value = value.strip_suffix('s').unwrap_or(value).trim();
+        let secs = if value.is_empty() {
+            0
+        } else {
+            match value.parse::<u64>() {
+                Ok(secs) => secs.min(MAX_AUTO_REFRESH_SECS),
+                Err(_) => {
+                    // 입력 창을 다시 열어 고칠 수 있게 함
+                    self.open_auto_refresh_input(input.to_string());
+                    self.set_toast("Enter the interval in seconds");
+                    return;
+                }
+            }
+        };
+        let side = self.active_panel();
+        match side {
+            ActivePanel::Left => self.auto_refresh.left = secs,
+            ActivePanel::Right => self.auto_refresh.right = secs,
+        }
+        *self.auto_refresh_last_mut(side) = Instant::now();
+        if secs == 0 {
+            self.set_toast("Auto refresh: off");
+        } else {
+            self.set_toast(&format!("Auto refresh: every {}s", secs));
+        }
+        let _ = self.save_persisted_state();
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+
+/// 내보내기 파일 형식 버전
+const BOOKMARK_FILE_VERSION: u32 = 1;
+/// 내보내기/가져오기 기본 파일 이름 (홈 디렉토리 기준)
+const DEFAULT_BOOKMARK_FILE: &str = "~/boksldir-bookmarks.toml";
+
+/// 다른 기기와 주고받는 북마크 파일 (TOML)
+#[derive(Debug, Serialize, Deserialize)]
+struct BookmarkFile {
+    version: u32,
+    #[serde(default)]
+    bookmarks: Vec<PersistedBookmark>,
+}
+
+impl App {
+    // === 북마크 그룹 / 내보내기·가져오기 ===
+
+    /// 그룹 접기/펼치기 (머리글 선택 유지, 설정에 저장)
+    pub(super) fn toggle_bookmark_group(&mut self, group: &str) {
+        if let Some(pos) = self
+            .bookmark_collapsed_groups
+            .iter()
+            .position(|name| name == group)
+        {
+            self.bookmark_collapsed_groups.remove(pos);
+        } else {
+            self.bookmark_collapsed_groups.push(group.to_string());
+        }
+        let _ = self.save_persisted_state();
+        let mut dialog = self.bookmark_list_dialog(0);
+        dialog.select_bookmark_group(group);
+        self.dialog = Some(dialog);
+    }
+
+    /// 북마크 소속 그룹 변경 (비우면 그룹 해제)
+    pub fn confirm_bookmark_group(&mut self, value: String, bookmark_index: usize) {
+        if bookmark_index >= self.bookmarks.len() {
+            self.close_dialog();
+            return;
+        }
+
+        let trimmed = value.trim();
+        let group = (!trimmed.is_empty()).then(|| trimmed.to_string());
+        let removed = group.is_none();
+        self.bookmarks[bookmark_index].group = group;
+        // 더 이상 쓰지 않는 그룹의 접힘 상태 정리
+        let bookmarks = &self.bookmarks;
+        self.bookmark_collapsed_groups
+            .retain(|name| bookmarks.iter().any(|b| b.group.as_ref() == Some(name)));
+        let _ = self.save_persisted_state();
+        self.return_to_bookmark_list(bookmark_index);
+        self.set_toast(if removed {
+            "Bookmark removed from group"
+        } else {
+            "Bookmark group changed"
+        });
+    }
+
+    /// 북마크 내보내기 파일 경로 입력 시작
+    pub fn start_bookmark_export(&mut self) {
+        if self.bookmarks.is_empty() {
+            self.dialog = Some(DialogKind::message("Bookmarks", "No bookmarks."));
+            return;
+        }
+        self.open_bookmark_file_input(true);
+    }
+
+    /// 북마크 가져오기 파일 경로 입력 시작
+    pub fn start_bookmark_import(&mut self) {
+        self.open_bookmark_file_input(false);
+    }
+
+    fn open_bookmark_file_input(&mut self, export: bool) {
+        let base_path = self.active_panel_state().current_path.clone();
+        self.dialog = Some(DialogKind::bookmark_file_input(
+            export,
+            DEFAULT_BOOKMARK_FILE,
+            base_path.clone(),
+        ));
+        self.on_dialog_text(move |app, value| {
+            let path = app.resolve_input_path(&value, &base_path);
+            if export {
+                app.export_bookmarks(&path);
+            } else {
+                app.import_bookmarks(&path);
+            }
+        });
+    }
+
+    /// 전체 북마크(그룹/단축키 포함)를 TOML 파일로 저장
+    pub(super) fn export_bookmarks(&mut self, path: &Path) {
+        let file = BookmarkFile {
+            version: BOOKMARK_FILE_VERSION,
+            bookmarks: self.bookmarks.clone(),
+        };
+        let result = toml::to_string_pretty(&file)
+            .map_err(|err| err.to_string())
+            .and_then(|text| fs::write(path, text).map_err(|err| err.to_string()));
+        match result {
+            Ok(()) => {
+                self.set_toast(&format!("Bookmarks exported: {}", self.bookmarks.len()));
+            }
+            Err(err) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Export bookmarks",
+                        Some(path),
+                        &err,
+                        "Check the file path and write permission.",
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// TOML 파일의 북마크 병합 (이미 있는 경로는 건너뜀, 겹치는 이름/단축키는 조정)
+    pub(super) fn import_bookmarks(&mut self, path: &Path) {
+        let parsed = fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|text| toml::from_str::<BookmarkFile>(&text).map_err(|err| err.to_string()));
+        let file = match parsed {
+            Ok(file) => file,
+            Err(err) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Import bookmarks",
+                        Some(path),
+                        &err,
+                        "Check that the file is a bookmark export (TOML).",
+                    ),
+                ));
+                return;
+            }
+        };
+
+        let total = file.bookmarks.len();
+        let mut added = 0;
+        for mut bookmark in file.bookmarks {
+            if self.bookmarks.iter().any(|b| b.path == bookmark.path) {
+                continue;
+            }
+            bookmark.name = self.make_unique_bookmark_name(&bookmark.name, None);
+            if bookmark.hotkey.is_some_and(|digit| {
+                digit > 9 || self.bookmarks.iter().any(|b| b.hotkey == Some(digit))
+            }) {
+                bookmark.hotkey = None;
+            }
+            bookmark.group = bookmark
+                .group
+                .map(|group| group.trim().to_string())
+                .filter(|group| !group.is_empty());
+            self.bookmarks.push(bookmark);
+            added += 1;
+        }
+        let _ = self.save_persisted_state();
+        self.close_dialog();
+        self.set_toast(&format!("Bookmarks imported: {}/{}", added, total));
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+use crate::ui::components::panel::Panel;
+use crate::utils::path_display::{self, Crumb};
+
+impl App {
+    // === 패널 제목 경로 조각 (gc 고르기 모드 / 제목 클릭) ===
+
+    /// 제목 경로 조각 고르기 모드 중
+    pub fn is_breadcrumb_active(&self) -> bool {
+        self.breadcrumb.is_some()
+    }
+
+    /// 패널 제목에 강조할 경로 조각 (활성 패널에서 고르기 모드일 때만)
+    pub fn breadcrumb_focus_for(&self, side: ActivePanel) -> Option<usize> {
+        self.breadcrumb.filter(|_| self.active_panel() == side)
+    }
+
+    /// 고르기 모드 시작 (gc, 상위 디렉토리 조각부터)
+    pub(super) fn start_breadcrumbs(&mut self) {
+        if !self.require_local_panel() {
+            return;
+        }
+        let count = self.active_crumbs().len();
+        self.breadcrumb = Some(count.saturating_sub(2));
+    }
+
+    /// 고른 조각을 왼쪽/오른쪽으로 옮기기
+    pub fn breadcrumb_move(&mut self, forward: bool) {
+        let last = self.active_crumbs().len().saturating_sub(1);
+        if let Some(index) = self.breadcrumb.as_mut() {
+            *index = if forward {
+                (*index + 1).min(last)
+            } else {
+                index.saturating_sub(1)
+            };
+        }
+    }
+
+    /// 고르기 모드 끝내기
+    pub fn close_breadcrumbs(&mut self) {
+        self.breadcrumb = None;
+    }
+
+    /// 고른 조각의 디렉토리로 이동하고 모드 끝내기
+    pub fn confirm_breadcrumb(&mut self) {
+        if let Some(index) = self.breadcrumb.take() {
+            self.jump_to_crumb(index);
+        }
+    }
+
+    /// 패널 제목 줄 클릭 (조각 위면 그 디렉토리로 이동, 처리했으면 true)
+    pub fn click_panel_title(&mut self, column: u16, row: u16) -> bool {
+        let Some(side) = [ActivePanel::Left, ActivePanel::Right]
+            .into_iter()
+            .find(|side| {
+                let area = self.panel_area(*side);
+                row == area.y && column >= area.x && column < area.x + area.width
+            })
+        else {
+            return false;
+        };
+        let panel_state = match side {
+            ActivePanel::Left => self.left_active_panel_state(),
+            ActivePanel::Right => self.right_active_panel_state(),
+        };
+        if !self.is_local_panel_view(PanelSlot::from(side)) {
+            return false;
+        }
+        let title = panel_state.current_path.to_string_lossy();
+        let git_summary = panel_state.git_repo.as_ref().map(|repo| repo.label());
+        let index = Panel::new()
+            .title(&title)
+            .tab_count(self.panel_tab_count(side))
+            .tab_label(panel_state.tab_title.as_deref())
+            .git_summary(git_summary.as_deref())
+            .auto_refresh(self.auto_refresh_remaining(side, Instant::now()))
+            .show_hidden(panel_state.show_hidden)
+            .breadcrumb_focus(self.breadcrumb_focus_for(side))
+            .breadcrumb_at(self.panel_area(side), column);
+        let Some(index) = index else {
+            return false;
+        };
+        self.breadcrumb = None;
+        self.layout.set_active_panel(side);
+        self.jump_to_crumb(index);
+        true
+    }
+
+    fn active_crumbs(&self) -> Vec<Crumb> {
+        path_display::breadcrumbs(&self.active_panel_state().current_path)
+    }
+
+    /// 조각 디렉토리로 이동 (그 아래 조각 이름에 포커스, 마지막 조각이면 그대로)
+    fn jump_to_crumb(&mut self, index: usize) {
+        let crumbs = self.active_crumbs();
+        if index + 1 >= crumbs.len() {
+            return;
+        }
+        let focus = crumbs[index + 1].label.clone();
+        self.change_active_dir(crumbs[index].path.clone(), true, Some(&focus));
+    }
+}
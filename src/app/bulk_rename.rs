@@ -0,0 +1,223 @@
+use super::*;
+use crate::utils::file_name::{self, NameRules};
+use crate::utils::formatter::pluralize;
+use crate::utils::temp_name;
+use std::collections::HashSet;
+use std::io::Write;
+
+impl App {
+    // === 편집기로 여러 이름 바꾸기 (한 줄에 이름 하나, 저장하면 줄 순서대로 적용) ===
+
+    /// 선택 항목(없으면 커서 항목) 이름을 임시 파일에 써서 터미널 에디터로 열기
+    pub fn start_rename_via_editor(&mut self) {
+        if !self.require_local_panel() {
+            return;
+        }
+        // 선택 항목은 패널에 보이는 순서대로
+        let panel = self.active_panel_state();
+        let mut sources: Vec<PathBuf> = panel
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| panel.selected_items.contains(index))
+            .map(|(_, entry)| entry.path.clone())
+            .collect();
+        if sources.is_empty() {
+            sources = self.get_operation_sources();
+        }
+        if sources.is_empty() {
+            self.set_toast("No items to rename");
+            return;
+        }
+        let dir = self.active_panel_state().current_path.clone();
+        let mut names = Vec::with_capacity(sources.len());
+        for source in &sources {
+            match source.file_name().and_then(|name| name.to_str()) {
+                Some(name) if !name.contains('\n') => names.push(name.to_string()),
+                _ => {
+                    self.show_bulk_rename_error(
+                        &format!("'{}' can't be edited as one line", source.display()),
+                        "Rename it with r instead.",
+                    );
+                    return;
+                }
+            }
+        }
+
+        let mut text = names.join("\n");
+        text.push('\n');
+        // 다른 사용자가 미리 만들어 둘 수 없게 새 파일(0600)로만 씀
+        let temp_dir = std::env::temp_dir();
+        let written = temp_name::create_new_file(&temp_dir, "boksldir-rename-", ".txt").and_then(
+            |(path, mut file)| match file.write_all(text.as_bytes()) {
+                Ok(()) => Ok(path),
+                Err(e) => {
+                    let _ = fs::remove_file(&path);
+                    Err(e)
+                }
+            },
+        );
+        let list_path = match written {
+            Ok(path) => path,
+            Err(e) => {
+                self.show_bulk_rename_error(
+                    &format!("Failed to write a list in {}: {}", temp_dir.display(), e),
+                    "Check the temporary directory.",
+                );
+                return;
+            }
+        };
+        self.send_command(Command::EditFile(TerminalEditorRequest {
+            editor_command: self.default_terminal_editor.clone(),
+            target_path: list_path.clone(),
+        }));
+        self.pending_bulk_rename = Some(BulkRename {
+            dir,
+            names,
+            list_path,
+        });
+    }
+
+    /// 편집기를 닫은 뒤 이름 목록 적용 (이름 바꾸기 목록이 아니면 false)
+    pub(super) fn finish_bulk_rename(
+        &mut self,
+        request: &TerminalEditorRequest,
+        result: &std::result::Result<(), String>,
+    ) -> bool {
+        let Some(bulk) = self
+            .pending_bulk_rename
+            .take_if(|bulk| bulk.list_path == request.target_path)
+        else {
+            return false;
+        };
+        let edited = fs::read_to_string(&bulk.list_path);
+        let _ = fs::remove_file(&bulk.list_path);
+        if let Err(reason) = result {
+            self.show_bulk_rename_error(reason, "Check editor command.");
+            return true;
+        }
+        let edited = match edited {
+            Ok(edited) => edited,
+            Err(e) => {
+                self.show_bulk_rename_error(&e.to_string(), "Save the list before closing.");
+                return true;
+            }
+        };
+
+        let mut new_names: Vec<&str> = edited
+            .lines()
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect();
+        while new_names.last().is_some_and(|name| name.is_empty()) {
+            new_names.pop();
+        }
+        if new_names.len() != bulk.names.len() {
+            self.show_bulk_rename_error(
+                &format!(
+                    "Expected {} names but the list has {}",
+                    bulk.names.len(),
+                    new_names.len()
+                ),
+                "Keep one line per item in the same order.",
+            );
+            return true;
+        }
+
+        let changes: Vec<(&str, &str)> = bulk
+            .names
+            .iter()
+            .map(String::as_str)
+            .zip(new_names)
+            .filter(|(old, new)| old != new)
+            .collect();
+        if changes.is_empty() {
+            self.set_toast("No names changed");
+            return true;
+        }
+        if let Err(problem) = self.check_bulk_rename(&bulk.dir, &changes) {
+            self.show_bulk_rename_error(&problem, "Fix the list and try again.");
+            return true;
+        }
+
+        let errors = self.apply_bulk_rename(&bulk.dir, &changes);
+        self.refresh_both_panels();
+        self.active_panel_state_mut().deselect_all();
+        if errors.is_empty() {
+            self.set_toast(&format!(
+                "Renamed {}",
+                pluralize(changes.len(), "item", "items")
+            ));
+        } else {
+            self.show_bulk_rename_error(&errors.join("\n"), "Check write permission.");
+        }
+        true
+    }
+
+    /// 새 이름 검사 (쓸 수 없는 이름, 목록 안 중복, 바꾸지 않는 기존 항목과 충돌)
+    fn check_bulk_rename(
+        &self,
+        dir: &Path,
+        changes: &[(&str, &str)],
+    ) -> std::result::Result<(), String> {
+        let rules = NameRules::for_filesystem(diskspace::filesystem_type(dir).as_deref());
+        let fold = self.filesystem.is_case_insensitive(dir);
+        let key = |name: &str| {
+            if fold {
+                name.to_lowercase()
+            } else {
+                name.to_string()
+            }
+        };
+        let renamed: HashSet<String> = changes.iter().map(|(old, _)| key(old)).collect();
+        let mut taken = HashSet::new();
+        for (_, new) in changes {
+            if new.trim().is_empty() {
+                return Err("Name cannot be empty".to_string());
+            }
+            if let Err(problem) = file_name::validate(new, dir, rules) {
+                return Err(format!("'{}': {}", new, problem));
+            }
+            if !taken.insert(key(new)) {
+                return Err(format!("'{}' appears more than once", new));
+            }
+            if fs::symlink_metadata(dir.join(new)).is_ok() && !renamed.contains(&key(new)) {
+                return Err(format!("'{}' already exists", new));
+            }
+        }
+        Ok(())
+    }
+
+    /// 임시 이름을 거쳐 적용 (이름을 맞바꾸거나 돌려 써도 겹치지 않게)
+    fn apply_bulk_rename(&self, dir: &Path, changes: &[(&str, &str)]) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut staged = Vec::new();
+        for (old, new) in changes {
+            // 비어 있는 이름에만 옮김 (링크 포함, 이미 있는 항목을 덮지 않음)
+            let temp = match temp_name::unused_path(dir, ".boksldir-rename-", "") {
+                Ok(temp) => temp,
+                Err(e) => {
+                    errors.push(format!("{}: {}", old, e));
+                    continue;
+                }
+            };
+            match self.filesystem.rename_path(&dir.join(old), &temp) {
+                Ok(()) => staged.push((temp, *old, *new)),
+                Err(e) => errors.push(format!("{}: {}", old, e)),
+            }
+        }
+        for (temp, old, new) in staged {
+            if let Err(e) = self.filesystem.rename_path(&temp, &dir.join(new)) {
+                errors.push(format!("{} -> {}: {}", old, new, e));
+                let _ = self.filesystem.rename_path(&temp, &dir.join(old));
+            }
+        }
+        errors
+    }
+
+    fn show_bulk_rename_error(&mut self, reason: &str, hint: &str) {
+        self.dialog = Some(DialogKind::error(
+            "Error",
+            Self::format_user_error("Rename via editor", None, reason, hint),
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+impl App {
+    // === 다른 프로세스가 쓰는 파일 (자동 재시도 → 다시 시도/건너뛰기/모두 건너뛰기) ===
+
+    /// 사용 중인 현재 항목의 재시도 시각 전이면 true (이번 틱은 건너뜀)
+    pub(super) fn is_waiting_busy_retry(pending: &PendingOperation) -> bool {
+        pending
+            .busy_retry_at
+            .is_some_and(|retry_at| Instant::now() < retry_at)
+    }
+
+    /// 자동 재시도를 다 쓴 항목이면 선택 창 표시 (표시했으면 true)
+    pub(super) fn prompt_busy_item(&mut self, pending: &PendingOperation) -> bool {
+        let Some(error) = pending.busy_error.clone() else {
+            return false;
+        };
+        let path = if pending.operation_type == OperationType::Delete {
+            pending.sources.get(pending.current_index).cloned()
+        } else {
+            pending
+                .flattened_files
+                .get(pending.current_index)
+                .map(|file| file.source.clone())
+        };
+        self.dialog = Some(DialogKind::file_in_use(path.unwrap_or_default(), error));
+        true
+    }
+
+    /// 사용 중인 파일 창: 버튼 이동
+    pub fn dialog_file_in_use_move(&mut self, forward: bool) {
+        if let Some(DialogKind::FileInUse {
+            selected_button, ..
+        }) = &mut self.dialog
+        {
+            *selected_button = if forward {
+                (*selected_button + 1) % 4
+            } else {
+                (*selected_button + 3) % 4
+            };
+        }
+    }
+
+    /// 사용 중인 파일 창 확정 (0: 다시 시도, 1: 건너뛰기, 2: 모두 건너뛰기, 3: 작업 취소)
+    pub fn confirm_file_in_use(&mut self) {
+        let Some(DialogKind::FileInUse {
+            selected_button, ..
+        }) = self.dialog
+        else {
+            return;
+        };
+        if selected_button == 3 {
+            self.close_dialog();
+            return;
+        }
+        let Some(pending) = self.pending_operation.as_mut() else {
+            self.close_dialog();
+            return;
+        };
+        pending.busy_error = None;
+        pending.busy_retry_at = None;
+        pending.busy_retries = 0;
+        match selected_button {
+            0 => {}
+            2 => {
+                pending.skip_busy_all = true;
+                pending.file_skipped();
+                pending.current_index += 1;
+            }
+            _ => {
+                pending.file_skipped();
+                pending.current_index += 1;
+            }
+        }
+        pending.state = OperationState::Processing;
+        self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+    }
+}
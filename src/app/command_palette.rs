@@ -0,0 +1,30 @@
+use super::*;
+use crate::core::actions::generate_palette_entries;
+use crate::ui::{FuzzyListItem, FuzzyListPurpose};
+
+impl App {
+    // === 명령 팔레트 (모든 액션 퍼지 검색 후 실행) ===
+
+    /// 명령 팔레트 열기 (설명 + 현재 단축키)
+    pub fn show_command_palette(&mut self) {
+        let items = generate_palette_entries(self.language())
+            .into_iter()
+            .map(|(_, label, shortcut)| FuzzyListItem {
+                label: label.to_string(),
+                detail: shortcut.to_string(),
+            })
+            .collect();
+        self.dialog = Some(DialogKind::fuzzy_list(
+            "Command palette",
+            FuzzyListPurpose::CommandPalette,
+            items,
+        ));
+    }
+
+    /// 팔레트에서 고른 액션 실행 (`index`는 팔레트 엔트리 순서)
+    pub(super) fn run_palette_command(&mut self, index: usize) {
+        if let Some((action, _, _)) = generate_palette_entries(self.language()).get(index) {
+            self.execute_action(*action);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use super::*;
+use crate::system::compare::{self, CompareOutcome};
+use crate::system::vfs::VirtualFileSystem;
+use crate::utils::formatter::{format_file_size, format_number_with_commas};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+impl App {
+    // === 패널 간 파일 내용 비교 ===
+
+    /// 포커스된 파일과 반대쪽 패널의 같은 이름 파일 비교 시작 (백그라운드)
+    pub fn start_file_compare(&mut self) {
+        let Some(entry) = self.active_panel_state().selected_entry() else {
+            self.dialog = Some(DialogKind::message("Information", "No file selected."));
+            return;
+        };
+        if entry.is_directory() {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error(
+                    "Compare files",
+                    Some(&entry.path),
+                    "Directories cannot be compared by content.",
+                    "Select a regular file and try again.",
+                ),
+            ));
+            return;
+        }
+        let name = entry.name.clone();
+        let active_path = entry.path.clone();
+        let other = self.inactive_panel_state();
+        let other_path = other.current_path.join(&name);
+        let other_fs = other.vfs(&self.filesystem);
+        if other_fs.stat(&other_path).is_err() {
+            self.dialog = Some(DialogKind::message(
+                "Compare",
+                format!("No file named '{}' in the other panel.", name),
+            ));
+            return;
+        }
+        if self.is_local_panel_view(PanelSlot::Left)
+            && self.is_local_panel_view(PanelSlot::Right)
+            && active_path == other_path
+        {
+            self.dialog = Some(DialogKind::message(
+                "Compare",
+                "Both panels point to the same file.",
+            ));
+            return;
+        }
+        let backend = |panel: &PanelState| -> Arc<dyn VirtualFileSystem> {
+            panel
+                .backend
+                .clone()
+                .unwrap_or_else(|| Arc::new(FileSystem::new()))
+        };
+        let (left_fs, right_fs) = (
+            backend(self.left_active_panel_state()),
+            backend(self.right_active_panel_state()),
+        );
+        let (left_path, right_path) = match self.layout.active_panel() {
+            ActivePanel::Left => (active_path, other_path),
+            ActivePanel::Right => (other_path, active_path),
+        };
+
+        self.cancel_file_compare();
+        let (events_tx, events_rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_for_worker = Arc::clone(&cancel);
+        let waker = self.waker.clone();
+        std::thread::spawn(move || {
+            let progress_tx = events_tx.clone();
+            let result = compare::compare_files(
+                left_fs.as_ref(),
+                &left_path,
+                right_fs.as_ref(),
+                &right_path,
+                &cancel_for_worker,
+                &mut |checked, total| {
+                    let _ = progress_tx.send(FileCompareEvent::Progress { checked, total });
+                },
+            );
+            let _ = events_tx.send(FileCompareEvent::Finished(result));
+            waker.wake();
+        });
+        self.file_compare = Some(FileCompareState {
+            name: name.clone(),
+            started: Instant::now(),
+            events: events_rx,
+            cancel,
+        });
+        self.dialog = Some(DialogKind::file_compare(name));
+    }
+
+    /// 비교 이벤트 반영 (메인 루프에서 호출)
+    pub fn poll_file_compare(&mut self) {
+        let Some(state) = self.file_compare.as_ref() else {
+            return;
+        };
+        let mut progress = None;
+        let mut finished = None;
+        while let Ok(event) = state.events.try_recv() {
+            match event {
+                FileCompareEvent::Progress { checked, total } => progress = Some((checked, total)),
+                FileCompareEvent::Finished(result) => {
+                    finished = Some(result);
+                    break;
+                }
+            }
+        }
+        if let (Some((done, size)), Some(DialogKind::FileCompare { checked, total, .. })) =
+            (progress, self.dialog.as_mut())
+        {
+            *checked = done;
+            *total = size;
+        }
+        let Some(result) = finished else {
+            return;
+        };
+        let Some(state) = self.file_compare.take() else {
+            return;
+        };
+        match result {
+            Ok(outcome) => {
+                let message = Self::file_compare_summary(&state, &outcome);
+                let verdict = if outcome.identical {
+                    "identical"
+                } else {
+                    "different"
+                };
+                self.record_job(
+                    "Compare files",
+                    format!("{}: {}", state.name, verdict),
+                    JobStatus::Succeeded,
+                    state.started.elapsed(),
+                    Vec::new(),
+                );
+                self.dialog = Some(DialogKind::message("Compare", message));
+            }
+            Err(BokslDirError::OperationCancelled) => self.close_dialog(),
+            Err(err) => {
+                self.record_job(
+                    "Compare files",
+                    state.name.clone(),
+                    JobStatus::Failed,
+                    state.started.elapsed(),
+                    vec![err.to_string()],
+                );
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Compare files",
+                        None,
+                        &err.to_string(),
+                        "Check the file path and read permission.",
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// 진행 중인 비교 중단 (다이얼로그 닫기)
+    pub fn cancel_file_compare(&mut self) {
+        if let Some(state) = self.file_compare.take() {
+            state.cancel.store(true, Ordering::Relaxed);
+            if matches!(self.dialog, Some(DialogKind::FileCompare { .. })) {
+                self.close_dialog();
+            }
+            self.set_toast("Compare cancelled");
+        }
+    }
+
+    fn file_compare_summary(state: &FileCompareState, outcome: &CompareOutcome) -> String {
+        let verdict = if outcome.identical {
+            "Identical"
+        } else {
+            "Different"
+        };
+        let mut lines = vec![
+            format!("{}: {}", verdict, state.name),
+            format!(
+                "Size: {} / {}",
+                format_file_size(outcome.left_size),
+                format_file_size(outcome.right_size)
+            ),
+        ];
+        if let Some(offset) = outcome.first_difference {
+            lines.push(format!(
+                "First difference at byte {}",
+                format_number_with_commas(offset)
+            ));
+        }
+        lines.push(format!("Method: {}", outcome.method.name()));
+        lines.push(format!(
+            "Time: {:.2}s",
+            state.started.elapsed().as_secs_f64()
+        ));
+        lines.join("\n")
+    }
+}
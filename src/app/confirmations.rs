@@ -0,0 +1,198 @@
+use super::*;
+
+impl App {
+    // === 작업별 확인 정책 (설정 파일 `[confirm]`) ===
+
+    /// 확인 정책에 따라 액션을 대신 처리했으면 true (`execute_action`에서 먼저 호출)
+    ///
+    /// 확인을 끈 삭제는 확인 창 없이 바로 실행하고, 확인을 켠 종료는 진행 중인 작업이 있으면
+    /// 기다릴지/취소하고 종료할지 먼저 묻습니다.
+    pub(super) fn apply_confirm_policy(&mut self, action: Action) -> bool {
+        match action {
+            Action::Quit => {
+                let jobs = self.running_job_names();
+                if !self.confirm_policy.quit_with_jobs || jobs.is_empty() {
+                    return false;
+                }
+                self.dialog = Some(DialogKind::quit_confirm(
+                    jobs.into_iter().map(str::to_string).collect(),
+                ));
+                true
+            }
+            Action::Delete if !self.confirm_policy.trash => {
+                self.delete_without_confirmation(true);
+                true
+            }
+            Action::PermanentDelete if !self.confirm_policy.permanent_delete => {
+                self.delete_without_confirmation(false);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// 종료 확인 다이얼로그: 버튼 이동
+    pub fn dialog_quit_confirm_move(&mut self, forward: bool) {
+        if let Some(DialogKind::QuitConfirm {
+            selected_button, ..
+        }) = &mut self.dialog
+        {
+            *selected_button = if forward {
+                (*selected_button + 1) % 3
+            } else {
+                (*selected_button + 2) % 3
+            };
+        }
+    }
+
+    /// 종료 확인 다이얼로그 확정 (0: 끝나면 종료, 1: 취소하고 종료, 2: 돌아가기)
+    pub fn confirm_quit_dialog(&mut self) {
+        let Some(DialogKind::QuitConfirm {
+            selected_button, ..
+        }) = self.dialog
+        else {
+            return;
+        };
+        match selected_button {
+            0 => {
+                self.close_quit_dialog();
+                self.quit_after_jobs = true;
+                self.set_toast("Quitting when running jobs finish");
+            }
+            1 => {
+                self.dialog = None;
+                self.cancel_running_jobs();
+                self.quit();
+            }
+            _ => self.close_quit_dialog(),
+        }
+    }
+
+    /// 종료 확인 창 닫기 (종료 취소, 진행 중인 파일 작업이 있으면 진행률 창으로 복귀)
+    pub fn close_quit_dialog(&mut self) {
+        self.quit_after_jobs = false;
+        let progress = self
+            .archive_worker
+            .as_ref()
+            .map(|worker| worker.progress.clone())
+            .or_else(|| {
+                self.pending_operation
+                    .as_ref()
+                    .filter(|pending| pending.state != OperationState::WaitingConflict)
+                    .map(|pending| pending.progress.clone())
+            });
+        self.dialog = progress.map(DialogKind::progress);
+    }
+
+    /// "끝나면 종료"를 골랐고 남은 작업이 없으면 종료 (메인 루프에서 호출)
+    pub fn poll_quit_after_jobs(&mut self) {
+        if self.quit_after_jobs && self.running_job_names().is_empty() {
+            self.quit();
+        }
+    }
+
+    /// 진행 중인 작업을 모두 중단 (압축 작업 스레드는 끝날 때까지 기다림)
+    ///
+    /// 종료 전에 호출해 작성 중이던 압축 파일/대상 파일 정리가 터미널 복원 전에 끝나게 합니다.
+    pub fn cancel_running_jobs(&mut self) {
+        if let Some(mut worker) = self.archive_worker.take() {
+            worker.control.cancel();
+            if let Some(handle) = worker.join_handle.take() {
+                let result = handle.join().map_err(|_| {
+                    crate::utils::error::BokslDirError::ArchiveCreateFailed {
+                        path: PathBuf::from("archive"),
+                        reason: "Archive worker thread panicked".to_string(),
+                    }
+                });
+                self.finish_archive_operation(
+                    worker.kind,
+                    worker.progress.active_elapsed(),
+                    result,
+                );
+            }
+        }
+        if self.pending_operation.is_some() {
+            self.cancel_operation();
+        }
+        self.cancel_file_compare();
+        self.cancel_panel_sync();
+        self.cancel_disk_usage();
+        self.cancel_file_finder();
+    }
+
+    /// 삭제 확인 창을 건너뛰고 바로 삭제 (대상이 없다는 안내 등은 그대로 표시)
+    fn delete_without_confirmation(&mut self, use_trash: bool) {
+        self.prepare_delete_pending_dialog(if use_trash { 0 } else { 1 });
+        if matches!(self.dialog, Some(DialogKind::DeleteConfirm { .. })) {
+            self.confirm_delete(use_trash);
+        }
+    }
+
+    /// 쉘 명령 실행 요청 (확인을 켰으면 먼저 묻기)
+    pub(super) fn request_terminal_command(&mut self, request: TerminalCommandRequest) {
+        if !self.confirm_policy.shell_command {
+            self.send_command(Command::RunShell(request));
+            return;
+        }
+        self.dialog = Some(DialogKind::confirm(
+            "Run command",
+            format!(
+                "Run `{}` in {}?",
+                request.command,
+                request.working_dir.display()
+            ),
+        ));
+        self.on_dialog_accepted(|app| app.send_command(Command::RunShell(request)));
+    }
+
+    /// 종료하면 중단되는 작업 이름
+    pub(super) fn running_job_names(&self) -> Vec<&'static str> {
+        let mut jobs = Vec::new();
+        if let Some(pending) = &self.pending_operation {
+            if matches!(
+                pending.state,
+                OperationState::Processing
+                    | OperationState::Paused
+                    | OperationState::WaitingConflict
+            ) {
+                // 압축/해제는 아래 작업 스레드로 표시
+                match pending.operation_type {
+                    OperationType::Copy => jobs.push("copy"),
+                    OperationType::Move => jobs.push("move"),
+                    OperationType::Delete => jobs.push("delete"),
+                    OperationType::ArchiveCompress | OperationType::ArchiveExtract => {}
+                }
+            }
+        }
+        if let Some(worker) = &self.archive_worker {
+            jobs.push(match worker.kind {
+                ArchiveWorkerKind::Compress => "archive create",
+                ArchiveWorkerKind::Extract => "archive extract",
+                ArchiveWorkerKind::Delete => "archive delete",
+                ArchiveWorkerKind::Download => "download",
+                ArchiveWorkerKind::Upload => "upload",
+            });
+        }
+        if self.file_compare.is_some() {
+            jobs.push("compare");
+        }
+        if self.is_panel_sync_running() {
+            jobs.push("synchronize");
+        }
+        if self
+            .disk_usage
+            .as_ref()
+            .is_some_and(|state| state.events.is_some())
+        {
+            jobs.push("disk usage scan");
+        }
+        if self
+            .file_finder
+            .as_ref()
+            .is_some_and(|state| state.events.is_some())
+        {
+            jobs.push("file index");
+        }
+        jobs
+    }
+}
@@ -0,0 +1,201 @@
+use super::*;
+use crate::ui::{create_context_menu, DropdownMenu};
+use ratatui::layout::Rect;
+
+impl App {
+    // === 항목 컨텍스트 메뉴 ===
+
+    /// 컨텍스트 메뉴가 열려 있는지 확인
+    pub fn is_context_menu_active(&self) -> bool {
+        self.context_menu.is_some()
+    }
+
+    /// 포커스된 항목에 적용 가능한 동작으로 컨텍스트 메뉴 열기
+    pub fn open_context_menu(&mut self) {
+        let Some(entry) = self.active_panel_state().selected_entry() else {
+            self.dialog = Some(DialogKind::message("Information", "No file selected."));
+            return;
+        };
+        let is_dir = entry.is_directory();
+        let is_executable = entry.is_executable();
+        let is_archive = !is_dir && detect_archive_format(&entry.path).is_some();
+        let panel = self.active_panel();
+        let local = self.is_local_panel_view(PanelSlot::from(panel));
+        let in_repo = local && self.active_panel_state().git_repo.is_some();
+
+        let mut open = if is_dir {
+            vec!["enter", "open_default"]
+        } else {
+            vec!["open_default", "open_terminal_editor"]
+        };
+        if is_executable && local {
+            open.push("run_executable");
+        }
+        let mut inspect = vec!["file_info"];
+        if local {
+            if is_archive {
+                inspect.extend(["archive_preview", "archive_extract"]);
+            } else {
+                inspect.push("archive_compress");
+            }
+            if !is_dir {
+                inspect.push("compare_files");
+            }
+            inspect.push("reveal_in_file_manager");
+        }
+        let git = if in_repo {
+            vec!["git_diff", "git_discard"]
+        } else {
+            Vec::new()
+        };
+        let menu = create_context_menu(
+            self.language(),
+            &[open, vec!["copy", "move", "rename", "delete"], inspect, git],
+        );
+        let mut state = MenuState::new();
+        state.open();
+        self.context_menu = Some(ContextMenu { panel, menu, state });
+    }
+
+    /// 컨텍스트 메뉴 닫기
+    pub fn close_context_menu(&mut self) {
+        self.context_menu = None;
+    }
+
+    /// 다음 항목으로 이동
+    pub fn context_menu_next(&mut self) {
+        if let Some(context) = self.context_menu.as_mut() {
+            context.state.next_item(&context.menu.items);
+        }
+    }
+
+    /// 이전 항목으로 이동
+    pub fn context_menu_prev(&mut self) {
+        if let Some(context) = self.context_menu.as_mut() {
+            context.state.prev_item(&context.menu.items);
+        }
+    }
+
+    /// 선택된 항목 실행 (메뉴를 닫은 뒤 실행)
+    pub fn confirm_context_menu(&mut self) {
+        let Some(context) = self.context_menu.take() else {
+            return;
+        };
+        let action = context
+            .menu
+            .items
+            .get(context.state.selected_item)
+            .filter(|item| !item.is_separator() && item.enabled)
+            .and_then(|item| Action::from_id(&item.id));
+        if let Some(action) = action {
+            self.execute_action(action);
+        }
+    }
+
+    /// 컨텍스트 메뉴 표시 영역 (커서 행 아래, 공간이 없으면 위)
+    pub fn context_menu_area(&self) -> Option<Rect> {
+        let context = self.context_menu.as_ref()?;
+        let (width, height) = DropdownMenu::new(&context.menu, &context.state).size();
+        let (screen_width, screen_height) = self.layout.terminal_size();
+        let panel_area = self.panel_area(context.panel);
+        let panel = match context.panel {
+            ActivePanel::Left => self.left_active_panel_state(),
+            ActivePanel::Right => self.right_active_panel_state(),
+        };
+        let has_parent = panel.current_path.parent().is_some();
+        let visible_row = if has_parent {
+            // ".." 행은 스크롤과 무관하게 첫 줄에 고정
+            panel.selected_index.saturating_sub(1 + panel.scroll_offset) + 1
+        } else {
+            panel.selected_index.saturating_sub(panel.scroll_offset)
+        };
+        // 테두리 1줄 + 헤더 2줄
+        let row = panel_area.y + 3 + visible_row as u16;
+        let width = width.min(screen_width);
+        let height = height.min(screen_height);
+        let x = (panel_area.x + 4).min(screen_width.saturating_sub(width));
+        let y = if row + 1 + height <= screen_height {
+            row + 1
+        } else {
+            row.saturating_sub(height)
+        };
+        Some(Rect::new(x, y, width, height))
+    }
+
+    /// 화면 좌표의 패널 행 (패널, 커서 인덱스)
+    pub fn panel_index_at(&self, column: u16, row: u16) -> Option<(ActivePanel, usize)> {
+        [ActivePanel::Left, ActivePanel::Right]
+            .into_iter()
+            .find_map(|side| {
+                let area = self.panel_area(side);
+                let inside = column > area.x
+                    && column + 1 < area.x + area.width
+                    && row >= area.y + 3
+                    && row + 1 < area.y + area.height;
+                if !inside {
+                    return None;
+                }
+                let panel = match side {
+                    ActivePanel::Left => self.left_active_panel_state(),
+                    ActivePanel::Right => self.right_active_panel_state(),
+                };
+                let has_parent = panel.current_path.parent().is_some();
+                let offset = (row - area.y - 3) as usize;
+                let index = if has_parent && offset == 0 {
+                    0
+                } else {
+                    let entry = panel.scroll_offset + offset - usize::from(has_parent);
+                    (entry < panel.entries.len()).then_some(entry + usize::from(has_parent))?
+                };
+                Some((side, index))
+            })
+    }
+
+    /// 우클릭한 행으로 커서를 옮기고 컨텍스트 메뉴 열기
+    pub fn open_context_menu_at(&mut self, column: u16, row: u16) {
+        self.close_context_menu();
+        let Some((side, index)) = self.panel_index_at(column, row) else {
+            return;
+        };
+        self.layout.set_active_panel(side);
+        self.active_panel_state_mut().selected_index = index;
+        if self.active_panel_state().selected_entry().is_some() {
+            self.open_context_menu();
+        }
+    }
+
+    /// 컨텍스트 메뉴 클릭 (항목이면 실행, 바깥이면 닫기)
+    pub fn click_context_menu(&mut self, column: u16, row: u16) {
+        let Some(area) = self.context_menu_area() else {
+            return;
+        };
+        let inside = column >= area.x
+            && column < area.x + area.width
+            && row > area.y
+            && row + 1 < area.y + area.height;
+        if !inside {
+            self.close_context_menu();
+            return;
+        }
+        let index = (row - area.y - 1) as usize;
+        if let Some(context) = self.context_menu.as_mut() {
+            if context
+                .menu
+                .items
+                .get(index)
+                .is_some_and(|item| !item.is_separator())
+            {
+                context.state.selected_item = index;
+                self.confirm_context_menu();
+            }
+        }
+    }
+
+    pub(super) fn panel_area(&self, side: ActivePanel) -> Rect {
+        let areas = self.layout.areas();
+        match side {
+            ActivePanel::Left => areas.left_panel,
+            ActivePanel::Right => areas.right_panel,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::super::*;
+use crate::models::operation::CopyOption;
 use crate::ui::{I18n, Language, TextKey};
 
 pub(in crate::app) fn execute(app: &mut App, action: Action) {
@@ -6,9 +7,13 @@ pub(in crate::app) fn execute(app: &mut App, action: Action) {
         Action::ShowHelp => app.show_help(),
         Action::Refresh => app.refresh_current(),
         Action::OpenMenu => app.open_menu(),
+        Action::OpenContextMenu => app.open_context_menu(),
+        Action::ShowCommandPalette => app.show_command_palette(),
+        Action::ShowPluginActions => app.show_plugin_actions(),
         Action::ThemeDark => app.switch_theme_and_save("dark"),
         Action::ThemeLight => app.switch_theme_and_save("light"),
         Action::ThemeContrast => app.switch_theme_and_save("high_contrast"),
+        Action::ShowThemeList => app.show_theme_list(),
         Action::SetLanguageEnglish => app.set_language_and_save(Language::English),
         Action::SetLanguageKorean => app.set_language_and_save(Language::Korean),
         Action::ToggleIconMode => {
@@ -18,6 +23,20 @@ pub(in crate::app) fn execute(app: &mut App, action: Action) {
                 IconMode::Ascii => IconMode::Emoji,
             };
         }
+        Action::ToggleGitStatus => app.toggle_git_status(),
+        Action::ToggleInfoLine => app.toggle_info_line(),
+        Action::TogglePathLine => app.toggle_path_line(),
+        Action::ToggleTypeColumn => app.toggle_type_column(),
+        Action::SetAutoRefresh => app.start_auto_refresh_input(),
+        Action::ToggleCopyVerify => app.toggle_copy_option(CopyOption::Verify),
+        Action::ToggleCopyPreserveTimestamps => {
+            app.toggle_copy_option(CopyOption::PreserveTimestamps)
+        }
+        Action::ToggleCopyPreservePermissions => {
+            app.toggle_copy_option(CopyOption::PreservePermissions)
+        }
+        Action::ToggleCopyPreserveXattrs => app.toggle_copy_option(CopyOption::PreserveXattrs),
+        Action::ToggleCopyUpdateOnly => app.toggle_copy_option(CopyOption::UpdateOnly),
         Action::SetDefaultEditorVi => app.set_default_editor_vi(),
         Action::SetDefaultEditorVim => app.set_default_editor_vim(),
         Action::SetDefaultEditorNano => app.set_default_editor_nano(),
@@ -7,16 +7,25 @@ pub(in crate::app) fn execute(app: &mut App, action: Action) {
         Action::Move => app.start_move(),
         Action::OpenDefaultApp => app.start_open_default_app(),
         Action::OpenTerminalEditor => app.start_open_terminal_editor(),
-        Action::RunShellCommand => app.start_run_shell_command(),
+        Action::RevealInFileManager => app.start_reveal_in_file_manager(),
+        Action::GitShowDiff => app.show_git_diff(),
+        Action::GitDiscardChanges => app.request_git_discard(),
+        Action::RunShell => app.start_run_shell_command(),
+        Action::RunExecutable => app.start_run_executable(),
         Action::Delete => app.start_delete(),
         Action::PermanentDelete => app.start_permanent_delete(),
         Action::MakeDirectory => app.start_mkdir(),
+        Action::NewFile => app.start_new_file(),
         Action::Rename => app.start_rename(),
+        Action::RenameViaEditor => app.start_rename_via_editor(),
         Action::ShowProperties => app.show_properties(),
+        Action::ShowSelectionStats => app.show_selection_stats(),
         Action::ArchiveCompress => app.start_archive_compress(),
         Action::ArchiveExtract => app.start_archive_extract(),
         Action::ArchiveExtractAuto => app.start_archive_extract_auto(),
         Action::ArchivePreview => app.start_archive_preview(),
+        Action::ForgetArchivePasswords => app.forget_archive_passwords(),
+        Action::ArchiveNameEncoding => app.show_archive_encoding_list(),
         Action::ToggleSelection => app.toggle_selection_and_move_down(),
         Action::InvertSelection => app.invert_selection(),
         Action::SelectAll => app.select_all(),
@@ -31,17 +40,37 @@ pub(in crate::app) fn execute(app: &mut App, action: Action) {
                 .set_sort_order(SortOrder::Descending);
             app.re_sort_active_panel();
         }
+        Action::ToggleNaturalSort => app.toggle_natural_sort(),
+        Action::ToggleLocaleSort => app.toggle_locale_sort(),
+        Action::ToggleDirsFirst => app.toggle_dirs_first(),
         Action::StartFilter => app.start_filter(),
         Action::ClearFilter => app.clear_filter(),
         Action::ToggleHidden => app.toggle_hidden(),
+        Action::ToggleHiddenBoth => app.toggle_hidden_both(),
+        Action::ToggleHidePatterns => app.toggle_hide_patterns_revealed(),
+        Action::EditHidePatterns => app.start_hide_patterns_input(),
+        Action::ToggleSyncBrowsing => app.toggle_sync_browsing(),
+        Action::ToggleFollowFocus => app.toggle_follow_focus(),
         Action::ShowMountPoints => app.show_mount_points(),
+        Action::AnalyzeDiskUsage => app.start_disk_usage_analysis(),
+        Action::CompareFiles => app.start_file_compare(),
+        Action::SyncPanels => app.start_panel_sync(),
         Action::GoToPath => app.start_go_to_path(),
+        Action::ConnectRemote => app.start_connect_remote(),
         Action::ShowTabList => app.show_tab_list(),
         Action::HistoryBack => app.history_back(),
         Action::HistoryForward => app.history_forward(),
         Action::ShowHistoryList => app.show_history_list(),
+        Action::ShowJumpList => app.show_jump_list(),
+        Action::ShowSiblingList => app.show_sibling_list(),
+        Action::FindFile => app.open_file_finder(),
         Action::AddBookmark => app.add_bookmark_current_dir(),
         Action::ShowBookmarkList => app.show_bookmark_list(),
+        Action::ExportBookmarks => app.start_bookmark_export(),
+        Action::ImportBookmarks => app.start_bookmark_import(),
+        Action::SaveSession => app.start_save_session(),
+        Action::ShowSessionList => app.show_session_list(),
+        Action::ShowJobList => app.show_job_list(),
         Action::SizeFormatAuto => {
             app.size_format = SizeFormat::Auto;
             let i18n = I18n::new(app.language());
@@ -0,0 +1,57 @@
+use super::*;
+
+/// 다이얼로그를 닫을 때 이어서 실행할 작업에 넘기는 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogOutcome {
+    /// 확인 창에서 OK
+    Accepted,
+    /// 입력 창에서 확정한 값
+    Text(String),
+    /// 입력 창에서 Ctrl+대체 키로 확정한 값 (`PromptInput::alternate_key`)
+    AlternateText(String),
+    /// Esc / Cancel (다른 이유로 창이 닫혀도 취소로 전달)
+    Cancelled,
+}
+
+/// 다이얼로그 결과를 받아 이어서 실행할 작업
+pub(super) type DialogContinuation = Box<dyn FnOnce(&mut App, DialogOutcome)>;
+
+impl App {
+    // === 다이얼로그 결과 전달 (창을 열 때 결과를 받을 작업을 함께 지정) ===
+
+    /// 현재 창의 결과를 `then`으로 받음 (이미 지정한 작업은 대체)
+    pub(super) fn on_dialog_outcome(
+        &mut self,
+        then: impl FnOnce(&mut App, DialogOutcome) + 'static,
+    ) {
+        self.dialog_continuation = Some(Box::new(then));
+    }
+
+    /// 현재 창에서 OK를 눌렀을 때만 `then` 실행
+    pub(super) fn on_dialog_accepted(&mut self, then: impl FnOnce(&mut App) + 'static) {
+        self.on_dialog_outcome(|app, outcome| {
+            if outcome == DialogOutcome::Accepted {
+                then(app);
+            }
+        });
+    }
+
+    /// 현재 창에서 확정한 입력 값을 `then`으로 받음
+    pub(super) fn on_dialog_text(&mut self, then: impl FnOnce(&mut App, String) + 'static) {
+        self.on_dialog_outcome(|app, outcome| {
+            if let DialogOutcome::Text(value) = outcome {
+                then(app, value);
+            }
+        });
+    }
+
+    /// 창을 닫고 결과 전달 (결과를 기다리는 작업이 없으면 false, 창은 그대로)
+    pub fn finish_dialog(&mut self, outcome: DialogOutcome) -> bool {
+        let Some(then) = self.dialog_continuation.take() else {
+            return false;
+        };
+        self.close_dialog();
+        then(self, outcome);
+        true
+    }
+}
@@ -1,101 +1,137 @@
-use super::text_edit::TextBufferEdit;
+use super::text_edit::{TextEdit, TextInputState};
 use super::*;
+use crate::ui::components::{list_dialog::ListNavigation, ListMove};
 
-impl App {
-    // === 다이얼로그 입력 처리 메서드 ===
+/// 텍스트 보기 페이지 이동 단위 (줄 수)
+const TEXT_VIEWER_PAGE: isize = 20;
 
-    /// 입력 다이얼로그: 문자 입력
-    pub fn dialog_input_char(&mut self, c: char) {
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::insert_char(value, cursor_pos, c);
+impl App {
+    // === 다이얼로그 스택 (목록/입력 창 위에 확인·오류·입력 창을 띄우고 닫으면 복귀) ===
+
+    /// 현재 창을 그대로 아래에 두고 새 창 표시 (현재 창이 없으면 그냥 표시)
+    pub(super) fn push_dialog(&mut self, dialog: DialogKind) {
+        match self.dialog.replace(dialog) {
+            Some(below) => self
+                .dialog_stack
+                .push((below, self.dialog_continuation.take())),
+            None => self.dialog_stack.clear(),
         }
-        self.update_input_completion_state();
     }
 
-    /// 입력 다이얼로그: 백스페이스
-    pub fn dialog_input_backspace(&mut self) {
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::backspace(value, cursor_pos);
-        }
-        self.update_input_completion_state();
+    /// 위에 띄운 창을 닫고 아래 창을 상태 그대로 복귀 (아래 창이 없으면 false)
+    pub(super) fn pop_dialog(&mut self) -> bool {
+        let Some((dialog, continuation)) = self.dialog_stack.pop() else {
+            return false;
+        };
+        self.dialog = Some(dialog);
+        self.dialog_continuation = continuation;
+        true
     }
 
-    /// 입력 다이얼로그: 이전 단어 삭제 (Ctrl+W)
-    pub fn dialog_input_delete_prev_word(&mut self) {
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete_prev_word(value, cursor_pos);
-        }
-        self.update_input_completion_state();
-    }
+    // === 다이얼로그 입력 처리 메서드 ===
 
-    /// 입력 다이얼로그: Delete
-    pub fn dialog_input_delete(&mut self) {
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete(value, cursor_pos);
-        }
-        self.update_input_completion_state();
+    /// 열린 다이얼로그에서 편집 중인 텍스트 입력 (글자 입력 칸이 없으면 None)
+    fn focused_text_input(&mut self) -> Option<TextInputState<'_>> {
+        let (value, cursor_pos) = match self.dialog.as_mut()? {
+            DialogKind::Input {
+                value, cursor_pos, ..
+            }
+            | DialogKind::MkdirInput {
+                value, cursor_pos, ..
+            }
+            | DialogKind::RenameInput {
+                value, cursor_pos, ..
+            }
+            | DialogKind::BookmarkRenameInput {
+                value, cursor_pos, ..
+            }
+            | DialogKind::FilterInput {
+                value, cursor_pos, ..
+            } => (value, cursor_pos),
+            DialogKind::FuzzyList {
+                query, cursor_pos, ..
+            } => (query, cursor_pos),
+            DialogKind::Help {
+                search_query,
+                search_cursor,
+                search_mode: true,
+                ..
+            } => (search_query, search_cursor),
+            DialogKind::ArchiveCreateOptions {
+                focused_field,
+                path_value,
+                path_cursor_pos,
+                use_password,
+                password_value,
+                password_cursor_pos,
+                password_confirm_value,
+                password_confirm_cursor_pos,
+                ..
+            } => match *focused_field {
+                0 => (path_value, path_cursor_pos),
+                2 if *use_password => (password_value, password_cursor_pos),
+                3 if *use_password => (password_confirm_value, password_confirm_cursor_pos),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(TextInputState::new(value, cursor_pos))
     }
 
-    /// 입력 다이얼로그: 커서 왼쪽
-    pub fn dialog_input_left(&mut self) {
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::left(value, cursor_pos);
-        }
-        self.update_input_completion_state();
+    /// 다이얼로그 텍스트 입력 편집 (입력/새 디렉토리/이름 변경/필터/도움말 검색/퍼지 목록/압축 옵션 공용)
+    pub fn dialog_text_edit(&mut self, edit: TextEdit) {
+        let Some(mut input) = self.focused_text_input() else {
+            return;
+        };
+        input.apply(edit);
+        self.after_dialog_text_edit(edit.modifies());
     }
 
-    /// 입력 다이얼로그: 커서 오른쪽
-    pub fn dialog_input_right(&mut self) {
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::right(value, cursor_pos);
-        }
-        self.update_input_completion_state();
+    /// 붙여넣기/IME 확정 문자열을 현재 다이얼로그의 입력 필드에 삽입
+    ///
+    /// 텍스트 입력 필드가 없는 다이얼로그(목록, 확인 등)에서는 무시합니다.
+    pub fn dialog_paste_text(&mut self, text: &str) {
+        let Some(mut input) = self.focused_text_input() else {
+            return;
+        };
+        input.insert_str(text);
+        self.after_dialog_text_edit(true);
     }
 
-    /// 입력 다이얼로그: Home
-    pub fn dialog_input_home(&mut self) {
-        if let Some(DialogKind::Input { cursor_pos, .. }) = &mut self.dialog {
-            TextBufferEdit::home(cursor_pos);
+    /// 편집 뒤 다이얼로그별 후속 처리 (경로 추천 갱신, 필터 즉시 적용, 검색 스크롤 초기화, 목록 다시 거르기)
+    fn after_dialog_text_edit(&mut self, modified: bool) {
+        if matches!(
+            self.dialog,
+            Some(DialogKind::Input { .. } | DialogKind::MkdirInput { .. })
+        ) {
+            self.update_input_completion_state();
+            return;
         }
-        self.update_input_completion_state();
-    }
-
-    /// 입력 다이얼로그: End
-    pub fn dialog_input_end(&mut self) {
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::end(value, cursor_pos);
+        if !modified {
+            return;
+        }
+        let mut filter_value = None;
+        match self.dialog.as_mut() {
+            Some(DialogKind::FilterInput { value, .. }) => filter_value = Some(value.clone()),
+            Some(DialogKind::Help { scroll_offset, .. }) => *scroll_offset = 0,
+            Some(dialog @ DialogKind::FuzzyList { .. }) => dialog.refilter_fuzzy_list(),
+            _ => {}
+        }
+        if let Some(value) = filter_value {
+            self.apply_live_filter(&value);
         }
-        self.update_input_completion_state();
     }
 
-    /// 입력 다이얼로그: 버튼 선택 변경 (Tab)
+    /// 입력 다이얼로그: 버튼 선택 변경 (Tab, 미리보기 버튼이 있으면 OK → Cancel → Preview)
     pub fn dialog_input_toggle_button(&mut self) {
         if let Some(DialogKind::Input {
-            selected_button, ..
+            selected_button,
+            show_preview,
+            ..
         }) = &mut self.dialog
         {
-            *selected_button = if *selected_button == 0 { 1 } else { 0 };
+            let count = if *show_preview { 3 } else { 2 };
+            *selected_button = (*selected_button + 1) % count;
         }
     }
 
@@ -111,6 +147,28 @@ impl App {
         }
     }
 
+    /// 입력 다이얼로그: 복사 옵션 체크박스 전환 (Ctrl+R/T/P/X)
+    pub fn dialog_input_toggle_copy_option(&mut self, option: CopyOption) {
+        if let Some(DialogKind::Input {
+            copy_options: Some(options),
+            ..
+        }) = &mut self.dialog
+        {
+            options.toggle(option);
+        }
+    }
+
+    /// 입력 다이얼로그: 심볼릭 링크 처리 방식 순환 (Ctrl+L)
+    pub fn dialog_input_cycle_symlink_policy(&mut self) {
+        if let Some(DialogKind::Input {
+            copy_options: Some(options),
+            ..
+        }) = &mut self.dialog
+        {
+            options.symlinks = options.symlinks.next();
+        }
+    }
+
     pub fn get_dialog_input_purpose(&self) -> Option<InputPurpose> {
         if let Some(DialogKind::Input { purpose, .. }) = &self.dialog {
             Some(*purpose)
@@ -169,218 +227,67 @@ impl App {
         }
     }
 
-    pub fn archive_create_dialog_toggle_button(&mut self) {
-        if let Some(DialogKind::ArchiveCreateOptions {
-            selected_button, ..
-        }) = &mut self.dialog
-        {
-            *selected_button = if *selected_button == 0 { 1 } else { 0 };
-        }
-    }
-
-    pub fn archive_create_dialog_char(&mut self, c: char) {
-        if let Some(DialogKind::ArchiveCreateOptions {
-            focused_field,
-            path_value,
-            path_cursor_pos,
-            use_password,
-            password_value,
-            password_cursor_pos,
-            password_confirm_value,
-            password_confirm_cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            match *focused_field {
-                0 => {
-                    TextBufferEdit::insert_char(path_value, path_cursor_pos, c);
-                }
-                2 if *use_password => {
-                    TextBufferEdit::insert_char(password_value, password_cursor_pos, c);
-                }
-                3 if *use_password => {
-                    TextBufferEdit::insert_char(
-                        password_confirm_value,
-                        password_confirm_cursor_pos,
-                        c,
-                    );
-                }
-                _ => {}
-            }
+    /// 압축 수준 바꾸기 (checkbox 줄에서 Left/Right)
+    pub fn archive_create_dialog_cycle_level(&mut self, forward: bool) {
+        if let Some(DialogKind::ArchiveCreateOptions { level, .. }) = &mut self.dialog {
+            *level = level.cycle(forward);
         }
     }
 
-    pub fn archive_create_dialog_backspace(&mut self) {
-        if let Some(DialogKind::ArchiveCreateOptions {
-            focused_field,
-            path_value,
-            path_cursor_pos,
-            use_password,
-            password_value,
-            password_cursor_pos,
-            password_confirm_value,
-            password_confirm_cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            match *focused_field {
-                0 => TextBufferEdit::backspace(path_value, path_cursor_pos),
-                2 if *use_password => {
-                    TextBufferEdit::backspace(password_value, password_cursor_pos)
-                }
-                3 if *use_password => {
-                    TextBufferEdit::backspace(password_confirm_value, password_confirm_cursor_pos)
-                }
-                _ => {}
-            }
-        }
-    }
-
-    pub fn archive_create_dialog_delete(&mut self) {
-        if let Some(DialogKind::ArchiveCreateOptions {
-            focused_field,
-            path_value,
-            path_cursor_pos,
-            use_password,
-            password_value,
-            password_cursor_pos,
-            password_confirm_value,
-            password_confirm_cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            match *focused_field {
-                0 => TextBufferEdit::delete(path_value, path_cursor_pos),
-                2 if *use_password => TextBufferEdit::delete(password_value, password_cursor_pos),
-                3 if *use_password => {
-                    TextBufferEdit::delete(password_confirm_value, password_confirm_cursor_pos)
-                }
-                _ => {}
-            }
-        }
-    }
-
-    pub fn archive_create_dialog_left(&mut self) {
-        if let Some(DialogKind::ArchiveCreateOptions {
-            focused_field,
+    /// 프리셋 적용 (숫자 키, 파일 이름 확장자/압축 수준/비밀번호 사용을 프리셋 값으로)
+    pub fn archive_create_dialog_apply_preset(&mut self, index: usize) {
+        let Some(preset) = self.archive_presets.get(index).cloned() else {
+            return;
+        };
+        let Some(DialogKind::ArchiveCreateOptions {
             path_value,
             path_cursor_pos,
             use_password,
-            password_value,
-            password_cursor_pos,
-            password_confirm_value,
-            password_confirm_cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            match *focused_field {
-                0 => TextBufferEdit::left(path_value, path_cursor_pos),
-                2 if *use_password => TextBufferEdit::left(password_value, password_cursor_pos),
-                3 if *use_password => {
-                    TextBufferEdit::left(password_confirm_value, password_confirm_cursor_pos)
-                }
-                4 => {
-                    self.archive_create_dialog_toggle_button();
-                }
-                _ => {}
-            }
-        }
-    }
-
-    pub fn archive_create_dialog_right(&mut self) {
-        if let Some(DialogKind::ArchiveCreateOptions {
             focused_field,
-            path_value,
-            path_cursor_pos,
-            use_password,
-            password_value,
-            password_cursor_pos,
-            password_confirm_value,
-            password_confirm_cursor_pos,
+            level,
             ..
         }) = &mut self.dialog
-        {
-            match *focused_field {
-                0 => TextBufferEdit::right(path_value, path_cursor_pos),
-                2 if *use_password => TextBufferEdit::right(password_value, password_cursor_pos),
-                3 if *use_password => {
-                    TextBufferEdit::right(password_confirm_value, password_confirm_cursor_pos)
-                }
-                4 => {
-                    self.archive_create_dialog_toggle_button();
-                }
-                _ => {}
-            }
-        }
-    }
-
-    pub fn archive_create_dialog_home(&mut self) {
-        if let Some(DialogKind::ArchiveCreateOptions {
-            focused_field,
-            path_cursor_pos,
-            use_password,
-            password_cursor_pos,
-            password_confirm_cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            match *focused_field {
-                0 => TextBufferEdit::home(path_cursor_pos),
-                2 if *use_password => TextBufferEdit::home(password_cursor_pos),
-                3 if *use_password => TextBufferEdit::home(password_confirm_cursor_pos),
-                _ => {}
-            }
+        else {
+            return;
+        };
+        let path = PathBuf::from(path_value.as_str());
+        let name = format!(
+            "{}.{}",
+            Self::auto_extract_base_name(&path),
+            preset.extension.trim_start_matches('.')
+        );
+        *path_value = path.with_file_name(name).to_string_lossy().to_string();
+        *path_cursor_pos = path_value.len();
+        *level = preset.level;
+        *use_password = preset.password;
+        if !*use_password && *focused_field > 2 {
+            *focused_field = 2;
         }
+        self.set_toast(&format!("Preset: {}", preset.name));
     }
 
-    pub fn archive_create_dialog_end(&mut self) {
+    pub fn archive_create_dialog_toggle_button(&mut self) {
         if let Some(DialogKind::ArchiveCreateOptions {
-            focused_field,
-            path_value,
-            path_cursor_pos,
-            use_password,
-            password_value,
-            password_cursor_pos,
-            password_confirm_value,
-            password_confirm_cursor_pos,
-            ..
+            selected_button, ..
         }) = &mut self.dialog
         {
-            match *focused_field {
-                0 => TextBufferEdit::end(path_value, path_cursor_pos),
-                2 if *use_password => TextBufferEdit::end(password_value, password_cursor_pos),
-                3 if *use_password => {
-                    TextBufferEdit::end(password_confirm_value, password_confirm_cursor_pos)
-                }
-                _ => {}
-            }
+            *selected_button = if *selected_button == 0 { 1 } else { 0 };
         }
     }
 
-    pub fn archive_create_dialog_delete_prev_word(&mut self) {
-        if let Some(DialogKind::ArchiveCreateOptions {
-            focused_field,
-            path_value,
-            path_cursor_pos,
-            use_password,
-            password_value,
-            password_cursor_pos,
-            password_confirm_value,
-            password_confirm_cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            match *focused_field {
-                0 => TextBufferEdit::delete_prev_word(path_value, path_cursor_pos),
-                2 if *use_password => {
-                    TextBufferEdit::delete_prev_word(password_value, password_cursor_pos)
-                }
-                3 if *use_password => TextBufferEdit::delete_prev_word(
-                    password_confirm_value,
-                    password_confirm_cursor_pos,
-                ),
-                _ => {}
-            }
+    /// 압축 옵션 창 ←/→ (압축 수준은 순환, 버튼 줄은 버튼 전환, 글자 입력 칸은 커서 이동)
+    pub fn archive_create_dialog_move(&mut self, forward: bool) {
+        let Some(DialogKind::ArchiveCreateOptions { focused_field, .. }) = &self.dialog else {
+            return;
+        };
+        match *focused_field {
+            1 => self.archive_create_dialog_cycle_level(forward),
+            4 => self.archive_create_dialog_toggle_button(),
+            _ => self.dialog_text_edit(if forward {
+                TextEdit::Right
+            } else {
+                TextEdit::Left
+            }),
         }
     }
 
@@ -391,12 +298,14 @@ impl App {
             password_value,
             password_confirm_value,
             base_path,
+            level,
             ..
         }) = &self.dialog
         else {
             self.close_dialog();
             return;
         };
+        let level = *level;
 
         let path_value = path_value.clone();
         let use_password = *use_password;
@@ -476,6 +385,8 @@ impl App {
             sources,
             output_path: resolved_path,
             password,
+            level,
+            threads: resolve_compression_threads(self.compression_threads),
         });
     }
 
@@ -489,32 +400,118 @@ impl App {
         }
     }
 
-    /// 확인 다이얼로그 확정 처리
+    /// 확인 다이얼로그 확정 처리 (결과를 기다리는 작업이 있으면 실행)
     pub fn confirm_confirm_dialog(&mut self) {
-        self.close_dialog();
+        if !self.finish_dialog(DialogOutcome::Accepted) {
+            self.close_dialog();
+        }
+    }
+
+    /// 텍스트 보기 스크롤 (delta 줄, 마지막 줄까지)
+    pub fn text_viewer_scroll(&mut self, delta: isize) {
+        if let Some(DialogKind::TextViewer {
+            lines,
+            scroll_offset,
+            ..
+        }) = &mut self.dialog
+        {
+            *scroll_offset = scroll_offset
+                .saturating_add_signed(delta)
+                .min(lines.len().saturating_sub(1));
+        }
+    }
+
+    pub fn text_viewer_page_down(&mut self) {
+        self.text_viewer_scroll(TEXT_VIEWER_PAGE);
+    }
+
+    pub fn text_viewer_page_up(&mut self) {
+        self.text_viewer_scroll(-TEXT_VIEWER_PAGE);
+    }
+
+    pub fn text_viewer_go_top(&mut self) {
+        self.text_viewer_scroll(isize::MIN);
+    }
+
+    pub fn text_viewer_go_bottom(&mut self) {
+        self.text_viewer_scroll(isize::MAX);
+    }
+
+    // === 선택 목록 다이얼로그 공통 (이동 / `/` 검색) ===
+
+    fn list_navigation_mut(&mut self) -> Option<&mut dyn ListNavigation> {
+        self.dialog
+            .as_mut()
+            .and_then(DialogKind::list_navigation_mut)
+    }
+
+    /// 목록 다이얼로그 선택 이동
+    pub fn list_dialog_move(&mut self, movement: ListMove) {
+        if let Some(list) = self.list_navigation_mut() {
+            list.move_selection(movement);
+        }
+    }
+
+    /// 목록 다이얼로그에서 검색어 입력 중이면 true
+    pub fn list_dialog_searching(&self) -> bool {
+        self.dialog.as_ref().is_some_and(DialogKind::list_searching)
+    }
+
+    /// 검색어 입력 시작 (검색을 쓰는 목록만)
+    pub fn list_dialog_start_search(&mut self) {
+        if let Some(list) = self.list_navigation_mut() {
+            list.start_search();
+        }
+    }
+
+    /// 검색어 편집 (`None`이면 한 글자 지움)
+    pub fn list_dialog_edit_search(&mut self, input: Option<char>) {
+        if let Some(list) = self.list_navigation_mut() {
+            list.edit_search(input);
+        }
+    }
+
+    /// 검색어 입력 종료 (`keep_filter`가 false면 검색어도 지움)
+    pub fn list_dialog_finish_search(&mut self, keep_filter: bool) {
+        if let Some(list) = self.list_navigation_mut() {
+            list.finish_search(keep_filter);
+        }
     }
 
     /// 충돌 다이얼로그: 옵션 이동
     pub fn dialog_conflict_next(&mut self) {
         if let Some(DialogKind::Conflict {
-            selected_option, ..
+            selected_option,
+            directory,
+            ..
         }) = &mut self.dialog
         {
-            *selected_option = (*selected_option + 1) % 5;
+            let order = Self::conflict_option_order(*directory);
+            let pos = order.iter().position(|o| o == selected_option).unwrap_or(0);
+            *selected_option = order[(pos + 1) % order.len()];
         }
     }
 
     /// 충돌 다이얼로그: 옵션 이동 (이전)
     pub fn dialog_conflict_prev(&mut self) {
         if let Some(DialogKind::Conflict {
-            selected_option, ..
+            selected_option,
+            directory,
+            ..
         }) = &mut self.dialog
         {
-            *selected_option = if *selected_option == 0 {
-                4
-            } else {
-                *selected_option - 1
-            };
+            let order = Self::conflict_option_order(*directory);
+            let pos = order.iter().position(|o| o == selected_option).unwrap_or(0);
+            *selected_option = order[(pos + order.len() - 1) % order.len()];
+        }
+    }
+
+    /// 충돌 다이얼로그 옵션 순서 (화면 배치 순, 디렉토리 충돌은 Merge가 맨 앞)
+    fn conflict_option_order(directory: bool) -> &'static [usize] {
+        if directory {
+            &[5, 0, 1, 2, 3, 4]
+        } else {
+            &[0, 1, 2, 3, 4]
         }
     }
 
@@ -550,6 +547,7 @@ impl App {
                 1 => ConflictResolution::Skip,
                 2 => ConflictResolution::OverwriteAll,
                 3 => ConflictResolution::SkipAll,
+                5 => ConflictResolution::Merge,
                 _ => ConflictResolution::Cancel,
             })
         } else {
@@ -0,0 +1,352 @@
+use super::*;
+use crate::system::disk_usage;
+use crate::system::vfs::VirtualFileSystem;
+use crate::utils::formatter::format_file_size;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 페이지 이동 단위 (항목 수)
+const DISK_USAGE_PAGE: usize = 12;
+
+impl App {
+    // === 디스크 사용량 분석 (ncdu 방식) ===
+
+    /// 활성 패널 경로 분석 시작 (백그라운드 스캔)
+    pub fn start_disk_usage_analysis(&mut self) {
+        if !self.require_local_panel() {
+            return;
+        }
+        self.cancel_disk_usage();
+        let root = self.active_panel_state().current_path.clone();
+        let (events_tx, events_rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_for_worker = Arc::clone(&cancel);
+        let scan_root = root.clone();
+        let waker = self.waker.clone();
+        std::thread::spawn(move || {
+            let progress_tx = events_tx.clone();
+            let result = disk_usage::scan(&scan_root, &cancel_for_worker, &mut |files, bytes| {
+                let _ = progress_tx.send(DiskUsageEvent::Progress { files, bytes });
+            });
+            let _ = events_tx.send(DiskUsageEvent::Finished(result));
+            waker.wake();
+        });
+        self.disk_usage = Some(DiskUsageState {
+            started: Instant::now(),
+            tree: None,
+            current: UsageTree::ROOT,
+            events: Some(events_rx),
+            cancel,
+        });
+        self.dialog = Some(DialogKind::disk_usage_scanning(
+            root.to_string_lossy().to_string(),
+        ));
+    }
+
+    /// 스캔 이벤트 반영 (메인 루프에서 호출)
+    pub fn poll_disk_usage(&mut self) {
+        let Some(events) = self.disk_usage.as_ref().and_then(|s| s.events.as_ref()) else {
+            return;
+        };
+        let mut progress = None;
+        let mut finished = None;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                DiskUsageEvent::Progress { files, bytes } => progress = Some((files, bytes)),
+                DiskUsageEvent::Finished(result) => {
+                    finished = Some(result);
+                    break;
+                }
+            }
+        }
+        if let (Some(progress), Some(DialogKind::DiskUsage { scanning, .. })) =
+            (progress, self.dialog.as_mut())
+        {
+            *scanning = Some(progress);
+        }
+        match finished {
+            Some(Ok(tree)) => {
+                let root = tree.node(UsageTree::ROOT);
+                let summary = format!(
+                    "{}: {} in {}",
+                    root.name,
+                    format_file_size(root.size),
+                    crate::utils::formatter::pluralize(root.file_count as usize, "file", "files")
+                );
+                let duration = self
+                    .disk_usage
+                    .as_ref()
+                    .map_or(Duration::ZERO, |state| state.started.elapsed());
+                self.record_job(
+                    "Analyze disk usage",
+                    summary,
+                    JobStatus::Succeeded,
+                    duration,
+                    Vec::new(),
+                );
+                if let Some(state) = self.disk_usage.as_mut() {
+                    state.tree = Some(tree);
+                    state.events = None;
+                }
+                if matches!(self.dialog, Some(DialogKind::DiskUsage { .. })) {
+                    self.show_disk_usage_dir(UsageTree::ROOT, None);
+                }
+            }
+            Some(Err(BokslDirError::OperationCancelled)) => self.disk_usage = None,
+            Some(Err(err)) => {
+                let duration = self
+                    .disk_usage
+                    .take()
+                    .map_or(Duration::ZERO, |state| state.started.elapsed());
+                self.record_job(
+                    "Analyze disk usage",
+                    String::new(),
+                    JobStatus::Failed,
+                    duration,
+                    vec![err.to_string()],
+                );
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Analyze disk usage",
+                        None,
+                        &err.to_string(),
+                        "Check the directory and read permission.",
+                    ),
+                ));
+            }
+            None => {}
+        }
+    }
+
+    /// 분석 다이얼로그 닫기 (스캔 중이면 중단)
+    pub fn close_disk_usage(&mut self) {
+        self.cancel_disk_usage();
+        self.close_dialog();
+    }
+
+    pub(super) fn cancel_disk_usage(&mut self) {
+        if let Some(state) = self.disk_usage.take() {
+            state.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// 디렉토리 노드 목록 표시 (focus가 있으면 해당 노드 선택)
+    fn show_disk_usage_dir(&mut self, dir: usize, focus: Option<usize>) {
+        let Some(state) = self.disk_usage.as_mut() else {
+            return;
+        };
+        let Some(tree) = state.tree.as_ref() else {
+            return;
+        };
+        state.current = dir;
+        let children = tree.sorted_children(dir);
+        let selected = focus
+            .and_then(|id| children.iter().position(|&child| child == id))
+            .unwrap_or(0);
+        let items = children
+            .iter()
+            .map(|&id| {
+                let node = tree.node(id);
+                DiskUsageItem {
+                    name: node.name.clone(),
+                    size: node.size,
+                    is_dir: node.is_dir,
+                    incomplete: node.has_error,
+                }
+            })
+            .collect();
+        let node = tree.node(dir);
+        self.dialog = Some(DialogKind::disk_usage(
+            node.path.to_string_lossy().to_string(),
+            node.size,
+            items,
+            selected,
+        ));
+    }
+
+    fn disk_usage_selected_index(&self) -> Option<usize> {
+        match &self.dialog {
+            Some(DialogKind::DiskUsage {
+                selected_index,
+                scanning: None,
+                ..
+            }) => Some(*selected_index),
+            _ => None,
+        }
+    }
+
+    /// 선택된 항목의 트리 노드
+    fn disk_usage_selected_node(&self) -> Option<usize> {
+        let index = self.disk_usage_selected_index()?;
+        let state = self.disk_usage.as_ref()?;
+        let tree = state.tree.as_ref()?;
+        tree.sorted_children(state.current).get(index).copied()
+    }
+
+    fn disk_usage_select(&mut self, select: impl FnOnce(usize, usize) -> usize) {
+        if let Some(DialogKind::DiskUsage {
+            items,
+            selected_index,
+            pending_delete: None,
+            ..
+        }) = &mut self.dialog
+        {
+            if !items.is_empty() {
+                *selected_index = select(*selected_index, items.len()).min(items.len() - 1);
+            }
+        }
+    }
+
+    pub fn disk_usage_move_down(&mut self) {
+        self.disk_usage_select(|index, _| index + 1);
+    }
+
+    pub fn disk_usage_move_up(&mut self) {
+        self.disk_usage_select(|index, _| index.saturating_sub(1));
+    }
+
+    pub fn disk_usage_page_down(&mut self) {
+        self.disk_usage_select(|index, _| index + DISK_USAGE_PAGE);
+    }
+
+    pub fn disk_usage_page_up(&mut self) {
+        self.disk_usage_select(|index, _| index.saturating_sub(DISK_USAGE_PAGE));
+    }
+
+    pub fn disk_usage_go_top(&mut self) {
+        self.disk_usage_select(|_, _| 0);
+    }
+
+    pub fn disk_usage_go_bottom(&mut self) {
+        self.disk_usage_select(|_, len| len - 1);
+    }
+
+    /// 선택한 디렉토리로 들어가기
+    pub fn disk_usage_enter(&mut self) {
+        let Some(id) = self.disk_usage_selected_node() else {
+            return;
+        };
+        let is_dir = self
+            .disk_usage
+            .as_ref()
+            .and_then(|state| state.tree.as_ref())
+            .is_some_and(|tree| tree.node(id).is_dir);
+        if is_dir {
+            self.show_disk_usage_dir(id, None);
+        }
+    }
+
+    /// 상위 디렉토리로 (분석 시작 경로 위로는 가지 않음)
+    pub fn disk_usage_go_parent(&mut self) {
+        let Some(state) = self.disk_usage.as_ref() else {
+            return;
+        };
+        let Some(parent) = state
+            .tree
+            .as_ref()
+            .and_then(|tree| tree.node(state.current).parent)
+        else {
+            return;
+        };
+        let current = state.current;
+        self.show_disk_usage_dir(parent, Some(current));
+    }
+
+    /// 활성 패널을 현재 분석 디렉토리로 이동하고 선택 항목에 포커스
+    pub fn disk_usage_go_to_panel(&mut self) {
+        let Some(state) = self.disk_usage.as_ref() else {
+            return;
+        };
+        let Some(tree) = state.tree.as_ref() else {
+            return;
+        };
+        let dir = tree.node(state.current).path.clone();
+        let focus = self
+            .disk_usage_selected_node()
+            .map(|id| tree.node(id).name.clone());
+        self.close_disk_usage();
+        self.change_active_dir(dir, true, focus.as_deref());
+    }
+
+    /// 선택 항목 삭제 확인 요청 (permanent = 영구 삭제)
+    pub fn disk_usage_request_delete(&mut self, permanent: bool) {
+        if self.disk_usage_selected_node().is_none() {
+            return;
+        }
+        if let Some(DialogKind::DiskUsage { pending_delete, .. }) = &mut self.dialog {
+            *pending_delete = Some(permanent);
+        }
+    }
+
+    pub fn disk_usage_cancel_delete(&mut self) {
+        if let Some(DialogKind::DiskUsage { pending_delete, .. }) = &mut self.dialog {
+            *pending_delete = None;
+        }
+    }
+
+    /// 삭제 실행 후 트리/패널 갱신
+    pub fn disk_usage_confirm_delete(&mut self) {
+        let permanent = match &self.dialog {
+            Some(DialogKind::DiskUsage {
+                pending_delete: Some(permanent),
+                ..
+            }) => *permanent,
+            _ => return,
+        };
+        let Some(id) = self.disk_usage_selected_node() else {
+            return;
+        };
+        let Some((path, name, size)) = self
+            .disk_usage
+            .as_ref()
+            .and_then(|state| state.tree.as_ref())
+            .map(|tree| {
+                let node = tree.node(id);
+                (node.path.clone(), node.name.clone(), node.size)
+            })
+        else {
+            return;
+        };
+        let result = if permanent {
+            self.filesystem.delete(&path)
+        } else {
+            self.filesystem.trash_items(std::slice::from_ref(&path))
+        };
+        if let Err(err) = result {
+            self.disk_usage_cancel_delete();
+            self.set_toast(&format!("Delete failed: {}", err));
+            return;
+        }
+        let selected = self.disk_usage_selected_index().unwrap_or(0);
+        let current = match self.disk_usage.as_mut() {
+            Some(DiskUsageState {
+                tree: Some(tree),
+                current,
+                ..
+            }) => {
+                tree.remove(id);
+                *current
+            }
+            _ => return,
+        };
+        self.show_disk_usage_dir(current, None);
+        if let Some(DialogKind::DiskUsage {
+            items,
+            selected_index,
+            ..
+        }) = &mut self.dialog
+        {
+            *selected_index = selected.min(items.len().saturating_sub(1));
+        }
+        self.refresh_both_panels();
+        if permanent {
+            self.set_toast(&format!("Freed {}: {}", format_file_size(size), name));
+        } else {
+            self.set_toast(&format!(
+                "Moved to trash: {} ({})",
+                name,
+                format_file_size(size)
+            ));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+/// 확인 창에 나열할 최대 명령 수
+const ELEVATED_PREVIEW_LIMIT: usize = 5;
+
+impl App {
+    // === 권한 부족 작업의 관리자 권한 재시도 (sudo) ===
+
+    /// 권한 부족으로 실패한 작업을 sudo로 다시 실행할지 묻기 (제안할 수 없으면 false)
+    pub(super) fn offer_elevated_retry(&mut self, operation: &str, ops: Vec<ElevatedOp>) -> bool {
+        if ops.is_empty() || !privilege::is_available() {
+            return false;
+        }
+        let mut message = format!(
+            "{}: permission denied for {}.\nRetry with administrator privileges (sudo)?\n",
+            operation,
+            crate::utils::formatter::pluralize(ops.len(), "item", "items")
+        );
+        for op in ops.iter().take(ELEVATED_PREVIEW_LIMIT) {
+            message.push_str(&format!("\n{}", op.describe()));
+        }
+        if ops.len() > ELEVATED_PREVIEW_LIMIT {
+            message.push_str(&format!(
+                "\n... and {} more",
+                ops.len() - ELEVATED_PREVIEW_LIMIT
+            ));
+        }
+        self.dialog = Some(DialogKind::confirm("Run as administrator", message));
+        let request = ElevatedRequest {
+            operation: operation.to_string(),
+            ops,
+        };
+        self.on_dialog_accepted(|app| app.send_command(Command::RunElevated(request)));
+        true
+    }
+
+    /// TUI를 내리고 sudo가 비밀번호를 묻기 전에 터미널에 보여줄 안내
+    pub fn elevation_terminal_prompt(&self, request: &ElevatedRequest) -> String {
+        let operation = crate::ui::localize_runtime_text(self.language, &request.operation);
+        I18n::new(self.language).fmt(
+            MessageKey::ElevationTerminalPrompt,
+            &[("operation", operation)],
+        )
+    }
+
+    /// 단일 작업 실패 시 권한 부족이면 재시도 제안 (제안했으면 true)
+    pub(super) fn offer_elevated_retry_for(
+        &mut self,
+        operation: &str,
+        error: &BokslDirError,
+        op: ElevatedOp,
+    ) -> bool {
+        error.is_permission_denied() && self.offer_elevated_retry(operation, vec![op])
+    }
+
+    /// sudo 재시도 결과 반영 (errors = 실패한 명령)
+    pub(super) fn apply_elevated_result(
+        &mut self,
+        request: &ElevatedRequest,
+        errors: Vec<String>,
+        duration: Duration,
+    ) {
+        self.refresh_both_panels();
+        let succeeded = request.ops.len().saturating_sub(errors.len());
+        let name = format!("{} (sudo)", request.operation);
+        let summary = format!(
+            "{}/{}",
+            succeeded,
+            crate::utils::formatter::pluralize(request.ops.len(), "item", "items")
+        );
+        if errors.is_empty() {
+            self.record_job(&name, summary, JobStatus::Succeeded, duration, errors);
+            self.set_toast(&format!(
+                "{} completed with sudo: {}",
+                request.operation,
+                crate::utils::formatter::pluralize(succeeded, "item", "items")
+            ));
+            return;
+        }
+        self.dialog = Some(DialogKind::error(
+            "Error",
+            format!(
+                "{} with sudo failed.\nSucceeded: {}\nFailed: {}\n\n{}",
+                request.operation,
+                succeeded,
+                errors.len(),
+                errors
+                    .iter()
+                    .take(ELEVATED_PREVIEW_LIMIT)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+        ));
+        let status = if succeeded > 0 {
+            JobStatus::CompletedWithErrors
+        } else {
+            JobStatus::Failed
+        };
+        self.record_job(&name, summary, status, duration, errors);
+    }
+}
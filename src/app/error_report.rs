@@ -0,0 +1,100 @@
+use super::*;
+use crate::utils::formatter::format_date_full;
+
+/// 결과 창에서 오류 보고서를 쓸 수 있다는 안내 줄
+pub(super) const ERROR_REPORT_HINT: &str = "v: view all errors, s: save report";
+
+impl App {
+    // === 작업 오류 보고서 (결과 창에서 v: 전체 보기, s: 파일로 저장) ===
+
+    /// 오류와 함께 끝난 작업의 보고서 보관
+    pub(super) fn keep_error_report(&mut self, pending: &PendingOperation) {
+        let dest_dir = (pending.operation_type != OperationType::Delete
+            && !pending.dest_dir.as_os_str().is_empty())
+        .then(|| pending.dest_dir.clone());
+        self.error_report = Some(ErrorReport {
+            operation: pending.operation_type.name().to_string(),
+            sources: pending.sources.clone(),
+            dest_dir,
+            finished_at: SystemTime::now(),
+            succeeded: pending.completed_count,
+            errors: pending
+                .error_times
+                .iter()
+                .copied()
+                .zip(pending.errors.iter().cloned())
+                .collect(),
+        });
+    }
+
+    /// 결과 창이 떠 있을 때의 보고서 (다른 창이면 None)
+    fn visible_error_report(&self) -> Option<&ErrorReport> {
+        if !matches!(
+            self.dialog,
+            Some(DialogKind::Error { .. } | DialogKind::Confirm { .. })
+        ) {
+            return None;
+        }
+        self.error_report.as_ref()
+    }
+
+    /// 보고서 본문 (작업/시각/경로 요약 + 시각이 붙은 전체 오류)
+    fn error_report_text(report: &ErrorReport) -> String {
+        let mut text = format!(
+            "Operation: {}\nFinished: {}\n",
+            report.operation,
+            format_date_full(report.finished_at)
+        );
+        if let Some(dest_dir) = &report.dest_dir {
+            text.push_str(&format!("Destination: {}\n", dest_dir.display()));
+        }
+        text.push_str(&format!("Sources ({}):\n", report.sources.len()));
+        for source in &report.sources {
+            text.push_str(&format!("  {}\n", source.display()));
+        }
+        text.push_str(&format!(
+            "Succeeded: {}\nFailed: {}\n\nErrors:\n",
+            report.succeeded,
+            report.errors.len()
+        ));
+        for (time, error) in &report.errors {
+            text.push_str(&format!("[{}] {}\n", format_date_full(*time), error));
+        }
+        text
+    }
+
+    /// 전체 오류를 스크롤 창으로 보기 (닫으면 결과 창으로 복귀)
+    pub fn show_error_report(&mut self) {
+        let Some(report) = self.visible_error_report() else {
+            return;
+        };
+        let text = Self::error_report_text(report);
+        self.push_dialog(DialogKind::text_viewer("Errors".to_string(), &text, false));
+    }
+
+    /// 보고서를 대상 디렉토리(안 되면 설정 디렉토리의 reports/)에 텍스트 파일로 저장
+    pub fn save_error_report(&mut self) {
+        let Some(report) = self.visible_error_report() else {
+            return;
+        };
+        let text = Self::error_report_text(report);
+        let datetime: chrono::DateTime<chrono::Local> = report.finished_at.into();
+        let file_name = format!("boksldir-errors-{}.txt", datetime.format("%Y%m%d-%H%M%S"));
+        let reports_dir = self
+            .settings_file_path()
+            .and_then(|settings| Some(settings.parent()?.join("reports")));
+        let mut last_error = None;
+        for dir in report.dest_dir.iter().cloned().chain(reports_dir) {
+            let path = dir.join(&file_name);
+            match fs::create_dir_all(&dir).and_then(|_| fs::write(&path, &text)) {
+                Ok(()) => {
+                    self.set_toast(&format!("Error report saved: {}", path.display()));
+                    return;
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        let reason = last_error.map_or_else(|| "no location".to_string(), |e| e.to_string());
+        self.set_toast(&format!("Failed to save error report: {}", reason));
+    }
+}
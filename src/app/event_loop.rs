@@ -0,0 +1,113 @@
+use super::*;
+
+impl App {
+    // === 메인 루프 일정 (화면 갱신 상한 / 다음에 깨어날 시각) ===
+
+    /// 백그라운드 작업이 끝날 때 메인 루프를 깨울 핸들 등록
+    pub fn set_waker(&mut self, waker: Waker) {
+        self.waker = waker;
+    }
+
+    /// 화면을 다시 그리는 최소 간격 (설정 파일 `frame_rate`)
+    pub fn frame_interval(&self) -> Duration {
+        Duration::from_secs(1) / self.frame_rate.max(1)
+    }
+
+    /// 입력이 없을 때 상태를 확인하는 주기 (설정 파일 `idle_tick_ms`)
+    pub fn idle_tick(&self) -> Duration {
+        Duration::from_millis(self.idle_tick_ms)
+    }
+
+    /// 메인 스레드에서 파일 단위로 처리하는 복사/이동/삭제가 진행 중 (일시정지 제외)
+    pub fn has_stepped_operation(&self) -> bool {
+        self.archive_worker.is_none()
+            && self
+                .pending_operation
+                .as_ref()
+                .is_some_and(|p| p.state == OperationState::Processing)
+    }
+
+    /// 진행 상황을 화면에 계속 보여줘야 하는 백그라운드 작업이 있음
+    pub fn has_background_progress(&self) -> bool {
+        self.archive_worker.is_some()
+            || self.file_compare.is_some()
+            || self.is_panel_sync_running()
+            || self.left_active_panel_state().is_loading()
+            || self.right_active_panel_state().is_loading()
+            || self
+                .disk_usage
+                .as_ref()
+                .is_some_and(|state| state.events.is_some())
+            || self
+                .file_finder
+                .as_ref()
+                .is_some_and(|state| state.events.is_some())
+    }
+
+    /// 파일 작업 한 단계 처리 (한 파일, 대용량 파일은 한 조각, 압축은 진행 이벤트 반영)
+    ///
+    /// 종료 확인 창을 띄운 동안에는 진행률 창으로 덮지 않습니다 (작업이 끝나면 창을 닫음).
+    pub fn process_operation_step(&mut self) {
+        let quit_dialog = self
+            .dialog
+            .take_if(|dialog| matches!(dialog, DialogKind::QuitConfirm { .. }));
+        if self.is_delete_operation() {
+            self.process_next_delete();
+        } else if self.is_archive_operation() {
+            self.process_next_archive();
+        } else {
+            self.process_next_file();
+        }
+        if let Some(DialogKind::QuitConfirm {
+            selected_button, ..
+        }) = quit_dialog
+        {
+            let jobs = self.running_job_names();
+            if matches!(self.dialog, None | Some(DialogKind::Progress { .. })) && !jobs.is_empty() {
+                self.dialog = Some(DialogKind::QuitConfirm {
+                    jobs: jobs.into_iter().map(str::to_string).collect(),
+                    selected_button,
+                });
+            }
+        }
+    }
+
+    /// 시간이 지나면 처리할 일이 생기는 가장 이른 시각 (토스트/대기 키 만료, 포커스 따라가기,
+    /// 자동 새로고침 남은 초 표시)
+    pub fn next_timer_deadline(&self) -> Option<Instant> {
+        let toast = self
+            .toast_message
+            .as_ref()
+            .map(|(_, shown)| *shown + Duration::from_secs(3));
+        let pending_key = self
+            .pending_key_time
+            .map(|pressed| pressed + Duration::from_millis(801));
+        let follow_focus = self
+            .follow_focus_pending
+            .as_ref()
+            .map(|(_, since)| *since + Self::FOLLOW_FOCUS_DELAY);
+        let auto_refresh = self.auto_refresh_deadline(Instant::now());
+        [toast, pending_key, follow_focus, auto_refresh]
+            .into_iter()
+            .flatten()
+            .min()
+    }
+
+    /// 다음 이벤트를 기다릴 최대 시간
+    ///
+    /// 파일 단위 작업은 기다리지 않고, 진행률 표시가 필요하면 프레임 간격, 그 외에는
+    /// 유휴 주기와 가장 이른 타이머 중 짧은 쪽만큼 기다립니다.
+    pub fn event_wait_timeout(&self, now: Instant, more_work: bool) -> Duration {
+        if self.has_stepped_operation() || more_work {
+            return Duration::ZERO;
+        }
+        let mut timeout = self.idle_tick();
+        if self.has_background_progress() {
+            timeout = timeout.min(self.frame_interval());
+        }
+        if let Some(deadline) = self.next_timer_deadline() {
+            timeout = timeout.min(deadline.saturating_duration_since(now));
+        }
+        timeout
+    }
+}
@@ -0,0 +1,180 @@
+use super::*;
+use crate::system::file_index;
+use crate::ui::{FuzzyListItem, FuzzyListPurpose};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 색인할 최대 파일 수 (넘으면 앞부분만 표시)
+const FILE_FINDER_MAX_FILES: usize = 100_000;
+
+impl App {
+    // === 퍼지 파일 찾기 (현재 경로 아래) ===
+
+    /// 활성 패널 경로 아래 파일 찾기 시작 (백그라운드 색인)
+    pub fn open_file_finder(&mut self) {
+        if !self.require_local_panel() {
+            return;
+        }
+        self.cancel_file_finder();
+        let root = self.active_panel_state().current_path.clone();
+        let include_hidden = self.active_panel_state().show_hidden;
+        let (events_tx, events_rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_for_worker = Arc::clone(&cancel);
+        let index_root = root.clone();
+        let waker = self.waker.clone();
+        std::thread::spawn(move || {
+            let batch_tx = events_tx.clone();
+            let result = file_index::index_files(
+                &index_root,
+                include_hidden,
+                FILE_FINDER_MAX_FILES,
+                &cancel_for_worker,
+                &mut |paths| {
+                    let _ = batch_tx.send(FileFinderEvent::Batch(paths));
+                },
+            );
+            let _ = events_tx.send(FileFinderEvent::Finished(result));
+            waker.wake();
+        });
+        self.file_finder = Some(FileFinderState {
+            root,
+            started: Instant::now(),
+            events: Some(events_rx),
+            cancel,
+        });
+        let mut dialog =
+            DialogKind::fuzzy_list("Find file", FuzzyListPurpose::FindFile, Vec::new());
+        if let DialogKind::FuzzyList { loading, .. } = &mut dialog {
+            *loading = true;
+        }
+        self.dialog = Some(dialog);
+    }
+
+    /// 색인 결과 반영 (메인 루프에서 호출)
+    pub fn poll_file_finder(&mut self) {
+        if self.file_finder.is_none() {
+            return;
+        }
+        if !matches!(
+            self.dialog,
+            Some(DialogKind::FuzzyList {
+                purpose: FuzzyListPurpose::FindFile,
+                ..
+            })
+        ) {
+            // 다이얼로그가 다른 경로로 닫힘
+            self.cancel_file_finder();
+            return;
+        }
+        let Some(events) = self.file_finder.as_ref().and_then(|s| s.events.as_ref()) else {
+            return;
+        };
+        let mut paths = Vec::new();
+        let mut finished = None;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                FileFinderEvent::Batch(batch) => paths.extend(batch),
+                FileFinderEvent::Finished(result) => {
+                    finished = Some(result);
+                    break;
+                }
+            }
+        }
+        if paths.is_empty() && finished.is_none() {
+            return;
+        }
+        let items = paths
+            .into_iter()
+            .map(|label| FuzzyListItem {
+                label,
+                detail: String::new(),
+            })
+            .collect();
+        if let Some(dialog) = self.dialog.as_mut() {
+            dialog.extend_fuzzy_list(items, finished.is_some());
+        }
+        match finished {
+            Some(Ok(summary)) => {
+                let mut duration = Duration::ZERO;
+                if let Some(state) = self.file_finder.as_mut() {
+                    state.events = None;
+                    duration = state.started.elapsed();
+                }
+                let mut result = format!(
+                    "{} indexed",
+                    crate::utils::formatter::pluralize(summary.files, "file", "files")
+                );
+                if summary.truncated {
+                    result.push_str(" (limit reached)");
+                }
+                self.record_job(
+                    "Find file",
+                    result,
+                    JobStatus::Succeeded,
+                    duration,
+                    Vec::new(),
+                );
+                if summary.truncated {
+                    self.set_toast("Too many files; showing only part of the tree");
+                }
+            }
+            Some(Err(BokslDirError::OperationCancelled)) => self.file_finder = None,
+            Some(Err(err)) => {
+                let duration = self
+                    .file_finder
+                    .take()
+                    .map_or(Duration::ZERO, |state| state.started.elapsed());
+                self.record_job(
+                    "Find file",
+                    String::new(),
+                    JobStatus::Failed,
+                    duration,
+                    vec![err.to_string()],
+                );
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Find file",
+                        None,
+                        &err.to_string(),
+                        "Check the directory and read permission.",
+                    ),
+                ));
+            }
+            None => {}
+        }
+    }
+
+    /// 색인 중이면 중단
+    pub(super) fn cancel_file_finder(&mut self) {
+        if let Some(state) = self.file_finder.take() {
+            state.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// 찾은 파일이 있는 디렉토리로 이동해 파일에 커서
+    pub(super) fn jump_to_found_file(&mut self, relative: &str) {
+        let Some(state) = self.file_finder.take() else {
+            return;
+        };
+        state.cancel.store(true, Ordering::Relaxed);
+        let path = state.root.join(relative);
+        let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+            return;
+        };
+        if !path.exists() {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error(
+                    "Find file",
+                    Some(&path),
+                    "File no longer exists.",
+                    "Search again to refresh the file list.",
+                ),
+            ));
+            return;
+        }
+        let name = name.to_string_lossy().to_string();
+        self.change_active_dir(parent.to_path_buf(), true, Some(&name));
+    }
+}
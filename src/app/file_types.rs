@@ -0,0 +1,76 @@
+use super::*;
+use crate::system::file_type;
+
+impl App {
+    // === 매직 넘버 기반 파일 종류 (종류 컬럼 / 속성 다이얼로그) ===
+
+    /// 한 번의 폴링에서 판별할 최대 파일 수 (큰 디렉토리에서도 입력이 밀리지 않도록)
+    const FILE_TYPE_BATCH: usize = 64;
+
+    /// 종류 컬럼 표시 전환 (설정 저장)
+    pub(super) fn toggle_type_column(&mut self) {
+        self.show_type_column = !self.show_type_column;
+        self.set_toast(if self.show_type_column {
+            "Type column: on"
+        } else {
+            "Type column: off"
+        });
+        let _ = self.save_persisted_state();
+    }
+
+    /// 패널 렌더링에 넘길 종류 판별 결과 (컬럼이 꺼져 있으면 None)
+    pub fn file_types_for<'a>(
+        &self,
+        panel: &'a PanelState,
+    ) -> Option<&'a HashMap<PathBuf, &'static str>> {
+        self.show_type_column.then_some(&panel.detected_types)
+    }
+
+    /// 화면에 보이는 로컬 파일의 종류를 판별해 캐시에 채움
+    ///
+    /// 한 번에 다 판별하지 못해 남은 파일이 있을 수 있으면 true (메인 루프가 곧 다시 호출)
+    pub fn poll_file_types(&mut self) -> bool {
+        if !self.show_type_column {
+            return false;
+        }
+        let (_, terminal_height) = self.layout.terminal_size();
+        let visible_rows = usize::from(terminal_height);
+        let mut budget = Self::FILE_TYPE_BATCH;
+        for slot in [PanelSlot::Left, PanelSlot::Right] {
+            if budget == 0 {
+                break;
+            }
+            if !self.is_local_panel_view(slot) {
+                continue;
+            }
+            let panel = self.panel_state_by_slot_mut(slot);
+            let start = panel.scroll_offset.min(panel.entries.len());
+            let end = (start + visible_rows).min(panel.entries.len());
+            let pending: Vec<PathBuf> = panel.entries[start..end]
+                .iter()
+                .filter(|entry| !entry.is_directory())
+                .filter(|entry| !panel.detected_types.contains_key(&entry.path))
+                .take(budget)
+                .map(|entry| entry.path.clone())
+                .collect();
+            budget -= pending.len();
+            for path in pending {
+                // 읽지 못한 파일은 빈 칸으로 남기고 다시 시도하지 않음
+                let description = file_type::detect_path(&path)
+                    .map(|kind| kind.description)
+                    .unwrap_or("");
+                panel.detected_types.insert(path, description);
+            }
+        }
+        budget == 0
+    }
+
+    /// 속성 다이얼로그의 판별 결과 ("PNG image (image/png)", 디렉토리/비로컬이면 None)
+    pub(super) fn properties_detected_type(&self, entry: &FileEntry) -> Option<String> {
+        if entry.is_directory() || !self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            return None;
+        }
+        let kind = file_type::detect_path(&entry.path).ok()?;
+        Some(format!("{} ({})", kind.description, kind.mime))
+    }
+}
@@ -0,0 +1,113 @@
+// Test fixture - 실제 파일 시스템 없이 화면 상태를 꾸미는 테스트용 App 빌더
+
+use super::*;
+use crate::models::FileType;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 픽스처 항목의 수정/생성 시각 (스냅샷에서 날짜 칸을 가릴 때 기준)
+pub(crate) fn fixture_time() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(1_767_225_600)
+}
+
+/// 픽스처 디렉토리 항목
+pub(crate) fn dir(name: &str) -> FileEntry {
+    entry(name, FileType::Directory, 0)
+}
+
+/// 픽스처 파일 항목
+pub(crate) fn file(name: &str, size: u64) -> FileEntry {
+    entry(name, FileType::File, size)
+}
+
+fn entry(name: &str, file_type: FileType, size: u64) -> FileEntry {
+    FileEntry::new(
+        name.to_string(),
+        PathBuf::from(name),
+        file_type,
+        size,
+        fixture_time(),
+        fixture_time(),
+        None,
+        name.starts_with('.'),
+    )
+}
+
+/// 패널 경로/목록, 활성 패널, 다이얼로그를 지정해 App을 만듦
+///
+/// 패널 경로는 디스크에 없어도 되고 목록은 읽지 않습니다 (항목은 주어진 순서 그대로, 경로는 패널 경로 아래로 맞춤).
+pub(crate) struct AppFixture {
+    app: App,
+}
+
+impl AppFixture {
+    /// 빈 `/work/left`, `/work/right` 패널
+    pub(crate) fn new() -> Self {
+        Self {
+            app: App::new_for_test(),
+        }
+        .left("/work/left", Vec::new())
+        .right("/work/right", Vec::new())
+    }
+
+    pub(crate) fn left(mut self, path: &str, entries: Vec<FileEntry>) -> Self {
+        fill_panel(self.app.left_active_panel_state_mut(), path, entries);
+        self
+    }
+
+    pub(crate) fn right(mut self, path: &str, entries: Vec<FileEntry>) -> Self {
+        fill_panel(self.app.right_active_panel_state_mut(), path, entries);
+        self
+    }
+
+    /// 오른쪽 패널을 활성화
+    pub(crate) fn focus_right(mut self) -> Self {
+        self.app.layout.set_active_panel(ActivePanel::Right);
+        self
+    }
+
+    /// 활성 패널 커서 위치 (".." 포함 인덱스)
+    pub(crate) fn cursor(mut self, index: usize) -> Self {
+        self.app.active_panel_state_mut().selected_index = index;
+        self
+    }
+
+    /// 활성 패널에서 선택 표시할 항목 (`entries` 인덱스)
+    pub(crate) fn select(mut self, indices: &[usize]) -> Self {
+        self.app
+            .active_panel_state_mut()
+            .selected_items
+            .extend(indices.iter().copied());
+        self.app.active_panel_state_mut().recount_entries();
+        self
+    }
+
+    /// 내장 테마로 전환
+    pub(crate) fn theme(mut self, name: &str) -> Self {
+        self.app
+            .theme_manager
+            .switch_theme(name)
+            .expect("built-in theme");
+        self
+    }
+
+    pub(crate) fn dialog(mut self, dialog: DialogKind) -> Self {
+        self.app.dialog = Some(dialog);
+        self
+    }
+
+    pub(crate) fn build(self) -> App {
+        self.app
+    }
+}
+
+fn fill_panel(panel: &mut PanelState, path: &str, mut entries: Vec<FileEntry>) {
+    let path = PathBuf::from(path);
+    for entry in &mut entries {
+        entry.path = path.join(&entry.name);
+    }
+    *panel = PanelState::new(path);
+    panel.entries = entries;
+    panel.recount_entries();
+    // git 조회를 건너뜀 (디스크에 없는 경로)
+    panel.git_status_stale = false;
+}
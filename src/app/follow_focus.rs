@@ -0,0 +1,70 @@
+use super::*;
+
+impl App {
+    // === 포커스 디렉토리 따라가기 (반대 패널에 미리보기) ===
+
+    pub fn is_follow_focus(&self) -> bool {
+        self.follow_focus
+    }
+
+    /// 포커스 디렉토리 따라가기 모드 전환 (gf)
+    pub(super) fn toggle_follow_focus(&mut self) {
+        self.follow_focus = !self.follow_focus;
+        self.follow_focus_pending = None;
+        self.set_toast(if self.follow_focus {
+            "Follow focused directory: on"
+        } else {
+            "Follow focused directory: off"
+        });
+    }
+
+    /// 매 루프마다 호출: 포커스가 잠시 머문 디렉토리를 반대 패널에 표시
+    pub fn poll_follow_focus(&mut self) {
+        self.poll_follow_focus_at(Instant::now());
+    }
+
+    /// 빠르게 스크롤하는 동안에는 반대 패널을 바꾸지 않도록
+    /// 같은 디렉토리에 `FOLLOW_FOCUS_DELAY` 이상 머문 경우에만 이동
+    pub(super) fn poll_follow_focus_at(&mut self, now: Instant) {
+        let Some(target) = self.follow_focus_target() else {
+            self.follow_focus_pending = None;
+            return;
+        };
+        if self.inactive_panel_state().current_path == target {
+            self.follow_focus_pending = None;
+            return;
+        }
+        match &self.follow_focus_pending {
+            Some((pending, since)) if *pending == target => {
+                if now.duration_since(*since) < Self::FOLLOW_FOCUS_DELAY {
+                    return;
+                }
+            }
+            _ => {
+                self.follow_focus_pending = Some((target, now));
+                return;
+            }
+        }
+        self.follow_focus_pending = None;
+        let panel = match self.active_panel() {
+            ActivePanel::Left => self.right_tabs.active_mut(),
+            ActivePanel::Right => self.left_tabs.active_mut(),
+        };
+        // 미리보기 이동은 히스토리에 남기지 않음
+        let _ = panel.change_directory(target, &self.filesystem);
+    }
+
+    /// 따라갈 디렉토리 (모드가 꺼져 있거나 압축/원격 보기 중이면 None)
+    fn follow_focus_target(&self) -> Option<PathBuf> {
+        if !self.follow_focus
+            || !self.is_local_panel_view(PanelSlot::Left)
+            || !self.is_local_panel_view(PanelSlot::Right)
+        {
+            return None;
+        }
+        self.active_panel_state()
+            .selected_entry()
+            .filter(|entry| entry.is_directory())
+            .map(|entry| entry.path.clone())
+    }
+}
@@ -0,0 +1,112 @@
+use super::*;
+use crate::ui::components::dialog::SpaceEstimate;
+use crate::utils::formatter::format_file_size;
+
+impl App {
+    // === 복사/이동 대상 여유 공간 확인 ===
+
+    /// 대상에 새로 써야 하는 작업인지 (복사, 다른 파일시스템으로의 이동)
+    fn operation_needs_space(
+        operation_type: OperationType,
+        sources: &[PathBuf],
+        dest: &Path,
+    ) -> bool {
+        match operation_type {
+            OperationType::Copy => true,
+            OperationType::Move => sources
+                .first()
+                .is_some_and(|source| !diskspace::same_filesystem(source, dest)),
+            _ => false,
+        }
+    }
+
+    /// 대상 경로 입력 창에 표시할 필요 공간 (활성 패널 항목 크기 기준, 디렉토리는 계산한 크기만)
+    pub(super) fn destination_space_estimate(
+        &self,
+        operation_type: OperationType,
+        sources: &[PathBuf],
+        dest: &Path,
+    ) -> Option<SpaceEstimate> {
+        if !Self::operation_needs_space(operation_type, sources, dest) {
+            return None;
+        }
+        let panel = self.active_panel_state();
+        let mut estimate = SpaceEstimate {
+            needed: 0,
+            partial: false,
+            available: diskspace::disk_space(dest).map(|space| space.available),
+        };
+        for entry in panel
+            .entries
+            .iter()
+            .filter(|entry| sources.contains(&entry.path))
+        {
+            if entry.is_directory() {
+                match panel.dir_sizes.get(&entry.path) {
+                    Some(size) => estimate.needed += size,
+                    None => estimate.partial = true,
+                }
+            } else {
+                estimate.needed += entry.size;
+            }
+        }
+        Some(estimate)
+    }
+
+    /// 대상 경로를 고치면 여유 공간을 그 경로 기준으로 다시 조회
+    pub(super) fn refresh_input_free_space(&mut self) {
+        let Some(DialogKind::Input {
+            value,
+            base_path,
+            free_space: Some(_),
+            ..
+        }) = &self.dialog
+        else {
+            return;
+        };
+        let dest = self.resolve_input_path(value, base_path);
+        let available = dest
+            .is_dir()
+            .then(|| diskspace::disk_space(&dest))
+            .flatten()
+            .map(|space| space.available);
+        if let Some(DialogKind::Input {
+            free_space: Some(estimate),
+            ..
+        }) = &mut self.dialog
+        {
+            estimate.available = available;
+        }
+    }
+
+    /// 평탄화한 전체 크기가 대상 여유 공간보다 크면 확인 메시지
+    pub(super) fn free_space_shortfall(
+        &self,
+        pending: &PendingOperation,
+        dest: &Path,
+        total_bytes: u64,
+    ) -> Option<String> {
+        if !Self::operation_needs_space(pending.operation_type, &pending.sources, dest) {
+            return None;
+        }
+        let available = diskspace::disk_space(dest)?.available;
+        (total_bytes > available).then(|| {
+            format!(
+                "Not enough free space: need {}, free {}.\nStart anyway?",
+                format_file_size(total_bytes),
+                format_file_size(available)
+            )
+        })
+    }
+
+    /// 여유 공간 경고를 무시하고 작업 시작 (닫으면서 돌아온 대상 경로 입력 창은 닫음)
+    pub(super) fn start_operation_despite_low_space(&mut self) {
+        let Some(mut pending) = self.pending_operation.take() else {
+            return;
+        };
+        self.dialog = None;
+        let dest = pending.dest_dir.clone();
+        self.prepare_and_start_operation(&mut pending, &dest);
+        self.pending_operation = Some(pending);
+    }
+}
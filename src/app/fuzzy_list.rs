@@ -0,0 +1,56 @@
+use super::*;
+use crate::ui::FuzzyListPurpose;
+
+impl App {
+    // === 퍼지 목록 다이얼로그 (점프 목록 등 공용) ===
+
+    /// 일치 항목 선택 이동 (끝에서 반대쪽으로 순환)
+    pub fn fuzzy_list_move(&mut self, down: bool) {
+        if let Some(DialogKind::FuzzyList {
+            matches,
+            selected_index,
+            ..
+        }) = &mut self.dialog
+        {
+            let len = matches.len();
+            if len == 0 {
+                return;
+            }
+            *selected_index = if down {
+                (*selected_index + 1) % len
+            } else {
+                (*selected_index + len - 1) % len
+            };
+        }
+    }
+
+    /// 선택 확정 (목적별 동작 실행)
+    pub fn confirm_fuzzy_list(&mut self) {
+        let Some(DialogKind::FuzzyList {
+            items,
+            matches,
+            selected_index,
+            purpose,
+            ..
+        }) = &self.dialog
+        else {
+            return;
+        };
+        let Some((index, item)) = matches
+            .get(*selected_index)
+            .and_then(|(index, _)| Some((*index, items.get(*index)?)))
+        else {
+            return;
+        };
+        let (label, purpose) = (item.label.clone(), *purpose);
+        self.close_dialog();
+        match purpose {
+            FuzzyListPurpose::JumpList => self.jump_to_frecent_dir(PathBuf::from(label)),
+            FuzzyListPurpose::Siblings => self.jump_to_sibling(&label),
+            FuzzyListPurpose::FindFile => self.jump_to_found_file(&label),
+            FuzzyListPurpose::CommandPalette => self.run_palette_command(index),
+            FuzzyListPurpose::ArchiveNameEncoding => self.apply_archive_name_encoding(&label),
+            FuzzyListPurpose::PluginAction => self.run_plugin_action(index),
+        }
+    }
+}
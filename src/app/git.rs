@@ -0,0 +1,175 @@
+use super::*;
+use crate::system::git::{self, GitStatus};
+
+impl App {
+    // === git 상태 표시 ===
+
+    /// 목록이 바뀐 패널의 git 상태 조회 시작 및 결과 반영 (메인 루프에서 호출)
+    pub fn poll_git_status(&mut self) {
+        while let Ok(result) = self.git_status_events.1.try_recv() {
+            self.git_status_pending.retain(|&slot| slot != result.panel);
+            if !self.git_status_enabled {
+                continue;
+            }
+            let local = self.is_local_panel_view(result.panel);
+            let panel = self.panel_state_by_slot_mut(result.panel);
+            if local && panel.current_path == result.path {
+                let status = result.status.unwrap_or_default();
+                panel.git_status = status.entries;
+                panel.git_repo = (!status.repo.branch.is_empty()).then_some(status.repo);
+            }
+        }
+        if !self.git_status_enabled {
+            return;
+        }
+        for slot in [PanelSlot::Left, PanelSlot::Right] {
+            if !self.is_local_panel_view(slot) {
+                let panel = self.panel_state_by_slot_mut(slot);
+                panel.git_status.clear();
+                panel.git_repo = None;
+                continue;
+            }
+            if self.git_status_pending.contains(&slot) {
+                continue;
+            }
+            let panel = self.panel_state_by_slot_mut(slot);
+            if !panel.git_status_stale {
+                continue;
+            }
+            panel.git_status_stale = false;
+            let path = panel.current_path.clone();
+            let sender = self.git_status_events.0.clone();
+            let waker = self.waker.clone();
+            self.git_status_pending.push(slot);
+            std::thread::spawn(move || {
+                let status = git::directory_status(&path);
+                let _ = sender.send(GitStatusResult {
+                    panel: slot,
+                    path,
+                    status,
+                });
+                waker.wake();
+            });
+        }
+    }
+
+    /// git 상태 표시 전환 (설정 저장)
+    pub(super) fn toggle_git_status(&mut self) {
+        self.git_status_enabled = !self.git_status_enabled;
+        let enabled = self.git_status_enabled;
+        for tabs in [&mut self.left_tabs, &mut self.right_tabs] {
+            for panel in tabs.tabs_mut() {
+                panel.git_status.clear();
+                panel.git_repo = None;
+                panel.git_status_stale = enabled;
+            }
+        }
+        self.set_toast(if enabled {
+            "Git status: on"
+        } else {
+            "Git status: off"
+        });
+        let _ = self.save_persisted_state();
+    }
+
+    // === git 파일 작업 ===
+
+    /// git 작업 대상 (포커스된 로컬 항목, 없으면 안내 후 None)
+    fn git_target_entry(&mut self) -> Option<(PathBuf, String)> {
+        if !self.require_local_panel() {
+            return None;
+        }
+        match self.active_panel_state().selected_entry() {
+            Some(entry) => Some((entry.path.clone(), entry.name.clone())),
+            None => {
+                self.dialog = Some(DialogKind::message("Information", "No file selected."));
+                None
+            }
+        }
+    }
+
+    /// 포커스된 항목의 HEAD 대비 변경 내용을 보기 창에 표시
+    pub fn show_git_diff(&mut self) {
+        let Some((path, name)) = self.git_target_entry() else {
+            return;
+        };
+        match git::diff(&path) {
+            Ok(text) if text.trim().is_empty() => {
+                self.dialog = Some(DialogKind::message("Git diff", "No changes to show."));
+            }
+            Ok(text) => {
+                self.dialog = Some(DialogKind::text_viewer(
+                    format!("Git diff: {}", name),
+                    &text,
+                    true,
+                ));
+            }
+            Err(err) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Show git diff",
+                        Some(&path),
+                        &err.to_string(),
+                        "Check that git is installed and the item is inside a repository.",
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// 포커스된 항목의 작업 트리 변경 되돌리기 확인 요청
+    pub fn request_git_discard(&mut self) {
+        let Some((path, name)) = self.git_target_entry() else {
+            return;
+        };
+        // 상태를 아는 경우에만 미리 걸러냄 (표시가 꺼져 있으면 git이 판단)
+        if self.git_status_enabled {
+            let status = self.active_panel_state().git_status.get(&path).copied();
+            let message = match status {
+                Some(GitStatus::Modified | GitStatus::Conflicted) => None,
+                Some(GitStatus::Untracked) => {
+                    Some("Untracked files have no committed version to restore.")
+                }
+                _ => Some("No unstaged changes to discard."),
+            };
+            if let Some(message) = message {
+                self.dialog = Some(DialogKind::message("Discard changes", message));
+                return;
+            }
+        }
+        self.dialog = Some(DialogKind::confirm(
+            "Discard changes",
+            format!(
+                "Discard unstaged changes to '{}'? This cannot be undone.",
+                name
+            ),
+        ));
+        self.on_dialog_accepted(move |app| app.discard_git_changes(&path));
+    }
+
+    /// 작업 트리 변경 되돌리기 실행 (확인 후)
+    pub(super) fn discard_git_changes(&mut self, path: &Path) {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        match git::discard_changes(path) {
+            Ok(()) => {
+                self.refresh_both_panels();
+                self.set_toast(&format!("Discarded changes: {}", name));
+            }
+            Err(err) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Discard changes",
+                        Some(path),
+                        &err.to_string(),
+                        "Resolve conflicts first and check the repository state.",
+                    ),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::utils::scope_filter::ScopeFilter;
+
+impl App {
+    // === 숨김 패턴 (설정 파일 `hide_patterns`, 숨김 파일 표시와 별개) ===
+
+    /// 설정한 숨김 패턴 (`*.pyc`, `.DS_Store`, `__pycache__/` 등)
+    pub fn hide_patterns(&self) -> &[String] {
+        &self.hide_patterns
+    }
+
+    /// 목록에 적용할 숨김 패턴 필터 (잠시 보이기를 켜면 빈 필터)
+    pub(super) fn hide_rules(&self) -> ScopeFilter {
+        if self.hide_patterns_revealed {
+            ScopeFilter::default()
+        } else {
+            ScopeFilter::excluding(self.hide_patterns.iter().map(String::as_str))
+        }
+    }
+
+    /// 숨김 패턴을 양쪽 패널의 모든 탭에 적용하고 바뀐 탭은 다시 읽기
+    pub(super) fn apply_hide_patterns(&mut self) {
+        let rules = self.hide_rules();
+        for tabs in [&mut self.left_tabs, &mut self.right_tabs] {
+            for panel in tabs.tabs_mut() {
+                if panel.hide_rules != rules {
+                    panel.hide_rules = rules.clone();
+                    let _ = panel.refresh(&self.filesystem);
+                }
+            }
+        }
+    }
+
+    /// 패턴으로 숨긴 항목 잠시 보이기/다시 숨기기 (저장하지 않음)
+    pub(super) fn toggle_hide_patterns_revealed(&mut self) {
+        if self.hide_patterns.is_empty() {
+            self.set_toast("No hide patterns set");
+            return;
+        }
+        self.hide_patterns_revealed = !self.hide_patterns_revealed;
+        self.apply_hide_patterns();
+        self.set_toast(if self.hide_patterns_revealed {
+            "Pattern-hidden files shown"
+        } else {
+            "Pattern-hidden files hidden"
+        });
+    }
+
+    /// 숨김 패턴 편집 다이얼로그 (공백으로 구분)
+    pub(super) fn start_hide_patterns_input(&mut self) {
+        self.dialog = Some(DialogKind::hide_patterns_input(
+            self.hide_patterns.join(" "),
+        ));
+        self.on_dialog_text(|app, value| app.confirm_hide_patterns_input(&value));
+    }
+
+    /// 입력한 숨김 패턴 적용 (다시 숨긴 상태로 돌아가고 설정 저장)
+    fn confirm_hide_patterns_input(&mut self, value: &str) {
+        self.hide_patterns = value.split_whitespace().map(str::to_string).collect();
+        self.hide_patterns_revealed = false;
+        self.apply_hide_patterns();
+        if self.hide_patterns.is_empty() {
+            self.set_toast("Hide patterns cleared");
+        } else {
+            self.set_toast(&format!("Hide patterns: {}", self.hide_patterns.len()));
+        }
+        let _ = self.save_persisted_state();
+    }
+}
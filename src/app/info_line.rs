@@ -0,0 +1,98 @@
+use super::*;
+use crate::models::file_entry::{FileType, ReparseKind};
+use crate::system::users;
+use crate::utils::formatter;
+
+impl App {
+    // === 패널 아래 `ls -l` 형식 정보 줄 ===
+
+    /// 정보 줄 표시 전환 (설정 저장)
+    pub(super) fn toggle_info_line(&mut self) {
+        self.show_info_line = !self.show_info_line;
+        self.set_toast(if self.show_info_line {
+            "Info line: on"
+        } else {
+            "Info line: off"
+        });
+        let _ = self.save_persisted_state();
+    }
+
+    /// 패널의 포커스 항목 정보 줄 (꺼져 있거나 ".."이면 None)
+    pub fn info_line_for(&self, panel: &PanelState) -> Option<String> {
+        if !self.show_info_line {
+            return None;
+        }
+        let entry = panel.selected_entry()?;
+        let size = match self.size_format {
+            _ if entry.metadata_pending => "…".to_string(),
+            SizeFormat::Auto => formatter::format_file_size(entry.size),
+            SizeFormat::Bytes => formatter::format_file_size_bytes(entry.size),
+        };
+        // 원격 uid/gid는 로컬 사용자 DB로 해석하지 않음
+        Some(long_listing(entry, &size, panel.backend.is_none()))
+    }
+}
+
+/// `ls -l` 형식 한 줄 (권한 링크수 소유자 그룹 크기 수정일 이름[ -> 대상] 및 inode)
+fn long_listing(entry: &FileEntry, size: &str, resolve_names: bool) -> String {
+    let kind = match entry.file_type {
+        FileType::Directory => 'd',
+        FileType::Symlink => 'l',
+        FileType::File | FileType::Executable => '-',
+    };
+    let permissions = entry
+        .permissions
+        .as_ref()
+        .map(|perms| formatter::format_permissions(Some(perms)))
+        .filter(|perms| perms != "-")
+        .unwrap_or_else(|| "?????????".to_string());
+    let links = entry
+        .links
+        .map(|links| links.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let (owner, group) = if resolve_names {
+        (
+            id_name(entry.owner.as_deref(), users::user_name),
+            id_name(entry.group.as_deref(), users::group_name),
+        )
+    } else {
+        (
+            entry.owner.clone().unwrap_or_else(|| "-".to_string()),
+            entry.group.clone().unwrap_or_else(|| "-".to_string()),
+        )
+    };
+
+    let modified = if entry.metadata_pending {
+        "…".to_string()
+    } else {
+        formatter::format_date(entry.modified)
+    };
+    let mut line = format!(
+        "{}{} {} {} {} {} {} {}",
+        kind, permissions, links, owner, group, size, modified, entry.name
+    );
+    if let Some(target) = &entry.link_target {
+        line.push_str(&format!(" -> {}", target.display()));
+    }
+    match entry.reparse {
+        Some(ReparseKind::Junction) => line.push_str("  [junction]"),
+        Some(ReparseKind::Other) => line.push_str("  [reparse point]"),
+        _ => {}
+    }
+    if let Some(inode) = entry.inode {
+        line.push_str(&format!("  inode {}", inode));
+    }
+    line
+}
+
+/// 숫자 uid/gid를 이름으로 변환 (찾지 못하면 숫자 그대로)
+fn id_name(id: Option<&str>, lookup: fn(u32) -> Option<String>) -> String {
+    match id {
+        Some(id) => id
+            .parse::<u32>()
+            .ok()
+            .and_then(lookup)
+            .unwrap_or_else(|| id.to_string()),
+        None => "-".to_string(),
+    }
+}
@@ -0,0 +1,88 @@
+use super::*;
+use crate::utils::formatter::format_date_full;
+use std::time::SystemTime;
+
+impl App {
+    // === 백그라운드 작업 기록 / 작업 목록 (tj) ===
+
+    /// 끝난 작업 기록 (작업 목록에 추가, 상태바 알림)
+    pub(super) fn record_job(
+        &mut self,
+        name: &str,
+        summary: String,
+        status: JobStatus,
+        duration: Duration,
+        errors: Vec<String>,
+    ) {
+        if status.is_failure() {
+            log::warn!(
+                "job {} {}: {} ({} errors)",
+                name,
+                status.label(),
+                summary,
+                errors.len()
+            );
+            for error in &errors {
+                log::warn!("  {}", error);
+            }
+        } else {
+            log::info!("job {} {}: {}", name, status.label(), summary);
+        }
+        self.jobs.push(Job {
+            name: name.to_string(),
+            summary,
+            status,
+            finished_at: SystemTime::now(),
+            duration,
+            errors,
+        });
+    }
+
+    /// 작업 목록을 연 뒤 새로 끝난 작업 수와 그중 실패 여부
+    pub fn jobs_unseen(&self) -> (usize, bool) {
+        self.jobs.unseen()
+    }
+
+    /// 최근 작업 목록 표시 (새 작업 알림 해제)
+    pub fn show_job_list(&mut self) {
+        self.jobs.mark_seen();
+        if self.jobs.is_empty() {
+            self.dialog = Some(DialogKind::message("Jobs", "No background jobs yet."));
+            return;
+        }
+        self.dialog = Some(DialogKind::job_list(self.jobs.jobs().cloned().collect()));
+    }
+
+    /// 선택한 작업의 상태/시간/오류 전체 보기
+    pub fn job_list_show_details(&mut self) {
+        let Some(DialogKind::JobList { list }) = &self.dialog else {
+            return;
+        };
+        let Some(job) = list.selected_item() else {
+            return;
+        };
+        let mut text = format!(
+            "Status: {}\nFinished: {}\nDuration: {}\nResult: {}\n",
+            job.status.label(),
+            format_date_full(job.finished_at),
+            job.duration_display(),
+            job.summary
+        );
+        if !job.errors.is_empty() {
+            text.push_str(&format!("\nErrors ({}):\n", job.errors.len()));
+            for error in &job.errors {
+                text.push_str(error);
+                text.push('\n');
+            }
+        }
+        let title = job.name.clone();
+        self.dialog = Some(DialogKind::text_viewer(title, &text, false));
+    }
+
+    /// 작업 기록 비우기
+    pub fn job_list_clear(&mut self) {
+        self.jobs.clear();
+        self.close_dialog();
+        self.set_toast("Job history cleared");
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::ui::{FuzzyListItem, FuzzyListPurpose};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+impl App {
+    // === 빈도/최근성 기반 디렉토리 점프 목록 ===
+
+    /// 로컬 디렉토리 방문 기록 (탐색 성공 시 호출)
+    pub(super) fn record_frecent_visit(&mut self, path: &Path) {
+        self.frecency.visit(path, unix_now());
+    }
+
+    /// 점프 목록 열기 (현재 디렉토리 제외, 점수순)
+    pub fn show_jump_list(&mut self) {
+        let current = self.active_panel_state().current_path.clone();
+        let items: Vec<FuzzyListItem> = self
+            .frecency
+            .ranked(unix_now())
+            .into_iter()
+            .filter(|entry| entry.path != current)
+            .map(|entry| FuzzyListItem {
+                label: entry.path.to_string_lossy().to_string(),
+                detail: String::new(),
+            })
+            .collect();
+        if items.is_empty() {
+            self.dialog = Some(DialogKind::message(
+                "Jump list",
+                "No visited directories yet.",
+            ));
+            return;
+        }
+        self.dialog = Some(DialogKind::fuzzy_list(
+            "Jump to directory",
+            FuzzyListPurpose::JumpList,
+            items,
+        ));
+    }
+
+    /// 점프 목록에서 고른 디렉토리로 이동 (사라진 경로는 목록에서 삭제)
+    pub(super) fn jump_to_frecent_dir(&mut self, path: PathBuf) {
+        if !path.is_dir() {
+            self.frecency.remove(&path);
+            let _ = self.save_persisted_state();
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error(
+                    "Jump to directory",
+                    Some(&path),
+                    "Directory no longer exists.",
+                    "It has been removed from the jump list.",
+                ),
+            ));
+            return;
+        }
+        self.change_active_dir(path, true, None);
+    }
+}
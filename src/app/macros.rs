@@ -0,0 +1,301 @@
+use super::*;
+use crate::utils::formatter::pluralize;
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// 매크로 한 단계 (스크립트 파일 한 줄)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MacroStep {
+    /// 일반 모드에서 실행한 액션 (액션 id 한 단어, 예: `go_parent`)
+    Action(Action),
+    /// 다이얼로그/메뉴에서 누른 키 (`key Enter`, `key C-d`)
+    Key(KeyCode, KeyModifiers),
+    /// 다이얼로그에 입력한 글자 (`type *.log`)
+    Text(String),
+}
+
+impl MacroStep {
+    /// 스크립트 한 줄 해석 (빈 줄과 `#` 주석은 None)
+    pub fn parse(line: &str) -> std::result::Result<Option<Self>, String> {
+        let line = line.trim_start();
+        if line.trim_end().is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+        let (word, rest) = line.split_once(' ').unwrap_or((line.trim_end(), ""));
+        let step = match word {
+            "type" => Self::Text(rest.to_string()),
+            "key" => {
+                let (code, modifiers) = parse_key(rest.trim())?;
+                Self::Key(code, modifiers)
+            }
+            id => match (Action::from_id(id), rest.trim().is_empty()) {
+                (Some(action), true) => Self::Action(action),
+                (Some(_), false) => return Err(format!("'{}' takes no argument", id)),
+                (None, _) => return Err(format!("unknown action '{}'", id)),
+            },
+        };
+        Ok(Some(step))
+    }
+}
+
+impl fmt::Display for MacroStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Action(action) => match action.id() {
+                Some(id) => f.write_str(id),
+                None => write!(f, "# {:?}", action),
+            },
+            Self::Key(code, modifiers) => write!(f, "key {}", format_key(*code, *modifiers)),
+            Self::Text(text) => write!(f, "type {}", text),
+        }
+    }
+}
+
+/// 스크립트 해석 (오류는 줄 번호와 함께)
+pub fn parse_script(text: &str) -> std::result::Result<Vec<MacroStep>, String> {
+    let mut steps = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        match MacroStep::parse(line) {
+            Ok(Some(step)) => steps.push(step),
+            Ok(None) => {}
+            Err(problem) => return Err(format!("line {}: {}", index + 1, problem)),
+        }
+    }
+    Ok(steps)
+}
+
+/// `--script` 파일 읽기
+pub fn load_script(path: &Path) -> std::result::Result<Vec<MacroStep>, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("cannot read script {}: {}", path.display(), e))?;
+    parse_script(&text).map_err(|problem| format!("{}: {}", path.display(), problem))
+}
+
+const NAMED_KEYS: &[(&str, KeyCode)] = &[
+    ("Enter", KeyCode::Enter),
+    ("Esc", KeyCode::Esc),
+    ("Tab", KeyCode::Tab),
+    ("BackTab", KeyCode::BackTab),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Insert", KeyCode::Insert),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Space", KeyCode::Char(' ')),
+];
+
+/// `C-d`, `A-x`, `S-Tab`, `Enter`, `F5`, `x` 형식 키 이름 해석
+fn parse_key(spec: &str) -> std::result::Result<(KeyCode, KeyModifiers), String> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut name = spec;
+    while let Some((prefix, rest)) = name.split_once('-').filter(|(_, rest)| !rest.is_empty()) {
+        modifiers |= match prefix {
+            "C" => KeyModifiers::CONTROL,
+            "A" => KeyModifiers::ALT,
+            "S" => KeyModifiers::SHIFT,
+            _ => break,
+        };
+        name = rest;
+    }
+    let code = if let Some(&(_, code)) = NAMED_KEYS.iter().find(|(key, _)| *key == name) {
+        code
+    } else if let Some(n) = name
+        .strip_prefix('F')
+        .and_then(|n| n.parse::<u8>().ok())
+        .filter(|n| (1..=12).contains(n))
+    {
+        KeyCode::F(n)
+    } else {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => return Err(format!("unknown key '{}'", spec)),
+        }
+    };
+    Ok((code, modifiers))
+}
+
+fn format_key(code: KeyCode, modifiers: KeyModifiers) -> String {
+    let mut text = String::new();
+    for (modifier, prefix) in [
+        (KeyModifiers::CONTROL, "C-"),
+        (KeyModifiers::ALT, "A-"),
+        (KeyModifiers::SHIFT, "S-"),
+    ] {
+        if modifiers.contains(modifier) {
+            text.push_str(prefix);
+        }
+    }
+    match NAMED_KEYS.iter().find(|(_, named)| *named == code) {
+        Some((name, _)) => text.push_str(name),
+        None => match code {
+            KeyCode::F(n) => text.push_str(&format!("F{}", n)),
+            KeyCode::Char(c) => text.push(c),
+            other => text.push_str(&format!("{:?}", other)),
+        },
+    }
+    text
+}
+
+/// 매크로 레지스터와 녹화/재생 상태
+#[derive(Debug, Default)]
+pub(super) struct MacroState {
+    /// 레지스터 문자 → 녹화한 단계 (실행 중에만 유지)
+    registers: HashMap<char, Vec<MacroStep>>,
+    /// 녹화 중인 레지스터와 지금까지의 단계
+    recording: Option<(char, Vec<MacroStep>)>,
+    /// 재생할 남은 단계
+    playback: VecDeque<MacroStep>,
+    /// 재생 중 (마지막 단계가 보낸 키를 처리할 때까지 녹화하지 않음)
+    replaying: bool,
+    /// `@@`로 다시 재생할 레지스터
+    last_played: Option<char>,
+}
+
+impl App {
+    // === 매크로 녹화/재생 (`qa` … `q`로 녹화, `@a`로 재생, `--script` 파일 실행) ===
+
+    pub fn is_recording_macro(&self) -> bool {
+        self.macros.recording.is_some()
+    }
+
+    /// 녹화 중인 레지스터 (상태바 표시용)
+    pub fn recording_macro_register(&self) -> Option<char> {
+        self.macros
+            .recording
+            .as_ref()
+            .map(|(register, _)| *register)
+    }
+
+    pub fn start_macro_recording(&mut self, register: char) {
+        self.macros.recording = Some((register, Vec::new()));
+        self.set_toast(&format!("Recording @{}", register));
+    }
+
+    pub fn stop_macro_recording(&mut self) {
+        let Some((register, steps)) = self.macros.recording.take() else {
+            return;
+        };
+        self.set_toast(&format!(
+            "Recorded @{} ({})",
+            register,
+            pluralize(steps.len(), "step", "steps")
+        ));
+        // 같은 내용을 `--script` 파일로 쓸 수 있게 로그에 남김
+        let script: Vec<String> = steps.iter().map(MacroStep::to_string).collect();
+        log::info!("recorded macro @{}:\n{}", register, script.join("\n"));
+        self.macros.registers.insert(register, steps);
+    }
+
+    fn record_macro_step(&mut self, step: MacroStep) {
+        if self.macros.replaying {
+            return;
+        }
+        let Some((_, steps)) = self.macros.recording.as_mut() else {
+            return;
+        };
+        // 이어서 입력한 글자는 한 줄로 모음
+        if let (MacroStep::Text(next), Some(MacroStep::Text(text))) = (&step, steps.last_mut()) {
+            text.push_str(next);
+            return;
+        }
+        steps.push(step);
+    }
+
+    /// 일반 모드에서 키로 실행한 액션 기록
+    pub fn record_macro_action(&mut self, action: Action) {
+        self.record_macro_step(MacroStep::Action(action));
+    }
+
+    /// 다이얼로그/메뉴에서 누른 키 기록 (글자는 입력 문자열로)
+    pub fn record_macro_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let step = match code {
+            KeyCode::Char(c) if (modifiers - KeyModifiers::SHIFT).is_empty() => {
+                MacroStep::Text(c.to_string())
+            }
+            _ => MacroStep::Key(code, modifiers),
+        };
+        self.record_macro_step(step);
+    }
+
+    /// 붙여넣은 문자열 기록
+    pub fn record_macro_text(&mut self, text: &str) {
+        self.record_macro_step(MacroStep::Text(text.to_string()));
+    }
+
+    /// 레지스터 재생 (`@`이면 마지막으로 재생한 레지스터)
+    pub fn play_macro(&mut self, register: char) {
+        let register = match register {
+            '@' => match self.macros.last_played {
+                Some(last) => last,
+                None => {
+                    self.set_toast("No macro played yet");
+                    return;
+                }
+            },
+            register => register,
+        };
+        let Some(steps) = self.macros.registers.get(&register).cloned() else {
+            self.set_toast(&format!("No macro in @{}", register));
+            return;
+        };
+        self.macros.last_played = Some(register);
+        // 녹화 중이면 재생한 단계를 그대로 이어 붙임
+        if let Some((_, recording)) = self.macros.recording.as_mut() {
+            recording.extend(steps.iter().cloned());
+        }
+        self.queue_macro_steps(steps);
+    }
+
+    /// 스크립트 단계 실행 예약
+    pub fn run_script(&mut self, steps: Vec<MacroStep>) {
+        self.queue_macro_steps(steps);
+    }
+
+    fn queue_macro_steps(&mut self, steps: Vec<MacroStep>) {
+        if steps.is_empty() {
+            return;
+        }
+        self.macros.playback.extend(steps);
+        self.macros.replaying = true;
+        self.send_command(Command::PollSoon);
+    }
+
+    /// 재생 단계를 하나씩 실행 (파일 작업과 목록 읽기가 끝날 때까지 대기)
+    pub(super) fn step_macro_playback(&mut self) {
+        if self.macros.playback.is_empty() {
+            self.macros.replaying = false;
+            return;
+        }
+        let busy = self.is_operation_processing()
+            || self.left_active_panel_state().is_loading()
+            || self.right_active_panel_state().is_loading();
+        if !busy {
+            match self.macros.playback.pop_front() {
+                // 액션 단계에서 창이 남아 있으면 앞 단계가 뜻대로 되지 않은 것 (오류 창 등)
+                Some(MacroStep::Action(_)) if self.dialog.is_some() => {
+                    self.macros.playback.clear();
+                    self.set_toast("Macro stopped: a dialog is open");
+                }
+                Some(MacroStep::Action(action)) => self.execute_action(action),
+                Some(MacroStep::Key(code, modifiers)) => {
+                    self.send_command(Command::PressKey(code, modifiers));
+                }
+                Some(MacroStep::Text(text)) => {
+                    for c in text.chars() {
+                        self.send_command(Command::PressKey(KeyCode::Char(c), KeyModifiers::NONE));
+                    }
+                }
+                None => {}
+            }
+        }
+        self.send_command(Command::PollSoon);
+    }
+}
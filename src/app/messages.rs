@@ -0,0 +1,143 @@
+use super::*;
+use crate::core::actions::MACRO_RECORD_PREFIX;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// 메인 루프 → 앱: 키 입력 밖에서 상태를 바꾸는 사건
+#[derive(Debug)]
+pub enum Msg {
+    /// 이벤트를 하나 처리할 때마다 (만료 확인, 배경 작업 결과 반영)
+    Tick(Instant),
+    /// 터미널 에디터 종료
+    EditorFinished(TerminalEditorRequest, std::result::Result<(), String>),
+    /// 터미널 명령 종료
+    ShellCommandFinished(TerminalCommandRequest, std::result::Result<(), String>),
+    /// 관리자 권한 재시도 종료 (errors = 실패한 명령)
+    ElevatedFinished {
+        request: ElevatedRequest,
+        errors: Vec<String>,
+        duration: Duration,
+    },
+    /// 실행 파일 종료
+    ExecutableFinished(
+        ExecutableRunRequest,
+        std::result::Result<ExecutableRunOutcome, String>,
+    ),
+}
+
+/// 앱 → 메인 루프: 앱 밖에서 할 일 (터미널을 잠시 넘기는 외부 프로세스 등)
+///
+/// 메인 루프는 요청 순서대로 실행하고 결과를 다시 `Msg`로 돌려줍니다.
+#[derive(Debug)]
+pub enum Command {
+    /// 배경 작업이 남아 입력을 기다리지 않고 곧 다시 Tick
+    PollSoon,
+    EditFile(TerminalEditorRequest),
+    RunShell(TerminalCommandRequest),
+    RunElevated(ElevatedRequest),
+    RunExecutable(ExecutableRunRequest),
+    /// 매크로 재생: 키 입력 처리 (녹화하지 않음)
+    PressKey(KeyCode, KeyModifiers),
+}
+
+impl App {
+    // === 메인 루프와 주고받는 사건/요청 (Msg → update → Command) ===
+
+    /// 메인 루프가 실행할 일 요청
+    pub(super) fn send_command(&mut self, command: Command) {
+        self.commands.push(command);
+    }
+
+    /// 쌓인 요청을 요청 순서대로 꺼냄
+    pub fn take_commands(&mut self) -> Vec<Command> {
+        std::mem::take(&mut self.commands)
+    }
+
+    /// 사건 반영 후 그동안 쌓인 요청 반환
+    pub fn update(&mut self, msg: Msg) -> Vec<Command> {
+        match msg {
+            Msg::Tick(now) => self.tick(now),
+            Msg::EditorFinished(request, result) => {
+                self.refresh_both_panels();
+                self.apply_terminal_editor_result(&request, result);
+            }
+            Msg::ShellCommandFinished(request, result) => {
+                self.refresh_both_panels();
+                self.apply_terminal_command_result(&request, result);
+            }
+            Msg::ElevatedFinished {
+                request,
+                errors,
+                duration,
+            } => self.apply_elevated_result(&request, errors, duration),
+            Msg::ExecutableFinished(request, result) => {
+                self.refresh_both_panels();
+                self.apply_executable_run_result(&request, result);
+            }
+        }
+        self.take_commands()
+    }
+
+    fn tick(&mut self, now: Instant) {
+        // pending 키 타임아웃 체크 (`q`만 누르고 기다리면 종료)
+        if self.pending_key.is_some() && self.is_pending_key_expired() {
+            let pending = self.pending_key;
+            self.clear_pending_key();
+            if pending == Some(MACRO_RECORD_PREFIX) {
+                self.execute_action(Action::Quit);
+            }
+        }
+
+        // 토스트 메시지 만료 체크
+        self.clear_expired_toast();
+
+        // 사용자 테마 파일 변경 감지 (핫 리로드)
+        self.poll_theme_files();
+
+        // 큰 디렉토리의 나머지 목록 반영
+        self.poll_directory_listings();
+
+        // 원격 패널 상태 정리 (탭 전환/히스토리 이동으로 벗어난 경우)
+        self.sync_remote_panel_view();
+
+        // 상태바 디스크 용량 갱신
+        self.poll_disk_space();
+
+        // 디스크 사용량 분석 스캔 진행 반영
+        self.poll_disk_usage();
+
+        // 파일 찾기 색인 결과 반영
+        self.poll_file_finder();
+
+        // 파일 비교 진행 반영
+        self.poll_file_compare();
+
+        // 패널 동기화 비교/실행 진행 반영
+        self.poll_panel_sync();
+
+        // 주기가 지난 패널 자동 새로고침
+        self.poll_auto_refresh(now);
+
+        // 목록이 바뀐 패널의 git 상태 조회
+        self.poll_git_status();
+
+        // 포커스한 디렉토리를 반대 패널에 표시
+        self.poll_follow_focus();
+
+        // 종류 컬럼에 보이는 파일 형식 판별
+        if self.poll_file_types() {
+            self.send_command(Command::PollSoon);
+        }
+
+        // 플러그인 훅 호출과 플러그인 컬럼 값 채우기
+        self.poll_plugin_hooks();
+        if self.poll_plugin_columns() {
+            self.send_command(Command::PollSoon);
+        }
+
+        // 매크로/스크립트 다음 단계 실행
+        self.step_macro_playback();
+
+        // "끝나면 종료"를 골랐으면 남은 작업 확인
+        self.poll_quit_after_jobs();
+    }
+}
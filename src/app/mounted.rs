@@ -0,0 +1,154 @@
+use super::*;
+use crate::system::vfs::VirtualFileSystem;
+
+impl App {
+    // === 마운트된 원격 백엔드 패널 (SFTP/FTP) 삭제/디렉토리 생성/이름 변경 ===
+
+    /// 활성 패널이 마운트된 백엔드면 삭제 확인 다이얼로그를 띄우고 true
+    pub(super) fn prepare_mounted_delete_dialog(&mut self, selected_button: usize) -> bool {
+        if !self.active_panel_state().is_mounted() {
+            return false;
+        }
+        let sources = self.get_operation_sources();
+        if sources.is_empty() {
+            self.dialog = Some(DialogKind::message(
+                "Information",
+                "No files selected for deletion.",
+            ));
+            return true;
+        }
+        let panel = self.active_panel_state();
+        let items: Vec<String> = sources
+            .iter()
+            .map(|path| {
+                let entry = panel.entries.iter().find(|e| &e.path == path);
+                let name = entry
+                    .map(|e| e.name.clone())
+                    .unwrap_or_else(|| path.to_string_lossy().to_string());
+                if entry.is_some_and(FileEntry::is_directory) {
+                    format!("{}/", name)
+                } else {
+                    name
+                }
+            })
+            .collect();
+        let total_size = crate::utils::formatter::pluralize(sources.len(), "item", "items");
+        self.pending_operation = Some(PendingOperation::new(
+            OperationType::Delete,
+            sources,
+            PathBuf::new(),
+        ));
+        self.dialog = Some(DialogKind::DeleteConfirm {
+            items,
+            total_size,
+            selected_button,
+        });
+        true
+    }
+
+    /// 백엔드 삭제 실행 (휴지통을 지원하지 않으면 영구 삭제만 허용)
+    pub(super) fn confirm_mounted_delete(
+        &mut self,
+        backend: &dyn VirtualFileSystem,
+        paths: &[PathBuf],
+        use_trash: bool,
+    ) {
+        if use_trash && !backend.supports_trash() {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error(
+                    "Move to trash",
+                    None,
+                    "Trash is not available on remote panels.",
+                    "Use permanent delete (D).",
+                ),
+            ));
+            return;
+        }
+        let errors: Vec<String> = paths
+            .iter()
+            .filter_map(|path| backend.delete(path).err())
+            .map(|err| err.to_string())
+            .collect();
+        self.refresh_both_panels();
+        self.active_panel_state_mut().deselect_all();
+        if errors.is_empty() {
+            self.dialog = None;
+            self.set_toast(&format!(
+                "Deleted {}.",
+                crate::utils::formatter::pluralize(paths.len(), "item", "items")
+            ));
+        } else {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error("Delete", None, &errors.join("\n"), ""),
+            ));
+        }
+    }
+
+    /// 원격 디렉토리 생성 (`parent`와 `new_path` 사이의 없는 중간 디렉토리도 함께, 성공하면 true)
+    pub(super) fn confirm_mounted_mkdir(
+        &mut self,
+        backend: &dyn VirtualFileSystem,
+        parent: &Path,
+        new_path: &Path,
+    ) -> bool {
+        let mut levels: Vec<&Path> = new_path
+            .ancestors()
+            .skip(1)
+            .take_while(|level| *level != parent)
+            .filter(|level| backend.stat(level).is_err())
+            .collect();
+        levels.reverse();
+        match levels
+            .into_iter()
+            .chain(std::iter::once(new_path))
+            .try_for_each(|level| backend.mkdir(level))
+        {
+            Ok(()) => true,
+            Err(e) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Create directory",
+                        Some(new_path),
+                        &e.to_string(),
+                        "Use a valid name and check write permission.",
+                    ),
+                ));
+                false
+            }
+        }
+    }
+
+    pub(super) fn confirm_mounted_rename(
+        &mut self,
+        backend: &dyn VirtualFileSystem,
+        new_name: &str,
+        original_path: &Path,
+    ) {
+        let parent = backend
+            .parent(original_path)
+            .unwrap_or_else(|| self.active_panel_state().current_path.clone());
+        let new_path = parent.join(new_name);
+        match backend.rename(original_path, &new_path) {
+            Ok(()) => {
+                self.refresh_both_panels();
+                self.focus_active_entry_by_name(new_name);
+                self.dialog = None;
+                self.set_toast("Rename completed");
+            }
+            Err(e) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Rename",
+                        Some(original_path),
+                        &e.to_string(),
+                        "Check duplicate names and write permission.",
+                    ),
+                ));
+            }
+        }
+    }
+}
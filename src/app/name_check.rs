@@ -0,0 +1,53 @@
+use super::*;
+use crate::utils::file_name::{self, NameRules};
+
+impl App {
+    // === 새 이름 검사 (새 파일 / 새 디렉토리 / 이름 변경) ===
+
+    /// 대상 파일시스템 규칙으로 새 이름 검사 (쓸 수 없으면 고친 이름을 제안하고 false)
+    ///
+    /// 원격 패널은 Unix 규칙, 로컬은 플랫폼과 대상 파일시스템 종류(FAT/NTFS/SMB 마운트 등)로
+    /// 규칙을 정합니다.
+    pub(super) fn check_new_name(&mut self, name: &str, parent: &Path, target: NameTarget) -> bool {
+        let rules = if self.active_panel_state().backend.is_some() {
+            NameRules::Unix
+        } else {
+            NameRules::for_filesystem(diskspace::filesystem_type(parent).as_deref())
+        };
+        let Err(problem) = file_name::validate(name, parent, rules) else {
+            return true;
+        };
+        let suggestion = file_name::sanitize(name, rules);
+        let usable = suggestion != name && file_name::validate(&suggestion, parent, rules).is_ok();
+        if !usable {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error(
+                    "Name check",
+                    Some(&parent.join(name)),
+                    &problem.to_string(),
+                    "Use a shorter name or a shallower directory.",
+                ),
+            ));
+            return false;
+        }
+        self.dialog = Some(DialogKind::confirm(
+            "Invalid name",
+            format!(
+                "'{}' can't be used here: {}.\nUse '{}' instead?",
+                name, problem, suggestion
+            ),
+        ));
+        self.on_dialog_accepted(|app| app.apply_sanitized_name(target, suggestion));
+        false
+    }
+
+    /// 제안을 받아들인 고친 이름으로 원래 작업 실행
+    pub(super) fn apply_sanitized_name(&mut self, target: NameTarget, name: String) {
+        match target {
+            NameTarget::NewDirectory { parent } => self.confirm_mkdir(name, parent),
+            NameTarget::Rename { original } => self.confirm_rename(name, original),
+            NameTarget::NewFile { edit } => self.confirm_new_file(&name, edit),
+        }
+    }
+}
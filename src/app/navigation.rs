@@ -1,5 +1,4 @@
 use super::controllers;
-use super::text_edit::TextBufferEdit;
 use super::*;
 
 impl App {
@@ -78,13 +77,21 @@ impl App {
 
     /// 액션 실행 (단일 진실 원천)
     pub fn execute_action(&mut self, action: Action) {
+        if self.block_remote_only_action(action) || self.apply_confirm_policy(action) {
+            return;
+        }
         match action {
             Action::Quit => self.quit(),
             Action::TogglePanel => self.toggle_panel(),
             Action::ToggleLayout => self.toggle_layout_mode(),
+            Action::SwapPanels => self.swap_panels(),
+            Action::SyncOtherPanel => self.sync_other_panel_to_active(),
             Action::MoveDown => self.move_selection_down(),
             Action::MoveUp => self.move_selection_up(),
             Action::GoToParent => self.go_to_parent(),
+            Action::ScrollNameLeft => self.scroll_focused_name(false),
+            Action::ScrollNameRight => self.scroll_focused_name(true),
+            Action::ShowBreadcrumbs => self.start_breadcrumbs(),
             Action::EnterSelected => self.enter_selected(),
             Action::GoToTop => self.go_to_top(),
             Action::GoToBottom => self.go_to_bottom(),
@@ -92,15 +99,35 @@ impl App {
             Action::PageDown => self.move_selection_page_down(),
             Action::TabNew => self.new_tab_active_panel(),
             Action::TabClose => self.close_tab_active_panel(),
+            Action::TabRename => self.start_tab_rename(),
+            Action::TabMoveLeft => self.move_tab_active_panel(false),
+            Action::TabMoveRight => self.move_tab_active_panel(true),
+            Action::TabCopyToOtherPanel => self.transfer_tab_to_other_panel(false),
+            Action::TabSendToOtherPanel => self.transfer_tab_to_other_panel(true),
+            Action::TabOpenInOtherPanel => self.open_focused_dir_in_other_panel_tab(),
             Action::ShowHelp
             | Action::Refresh
             | Action::OpenMenu
+            | Action::OpenContextMenu
+            | Action::ShowCommandPalette
+            | Action::ShowPluginActions
             | Action::ThemeDark
             | Action::ThemeLight
             | Action::ThemeContrast
+            | Action::ShowThemeList
             | Action::SetLanguageEnglish
             | Action::SetLanguageKorean
             | Action::ToggleIconMode
+            | Action::ToggleGitStatus
+            | Action::ToggleInfoLine
+            | Action::TogglePathLine
+            | Action::ToggleTypeColumn
+            | Action::SetAutoRefresh
+            | Action::ToggleCopyVerify
+            | Action::ToggleCopyPreserveTimestamps
+            | Action::ToggleCopyPreservePermissions
+            | Action::ToggleCopyPreserveXattrs
+            | Action::ToggleCopyUpdateOnly
             | Action::SetDefaultEditorVi
             | Action::SetDefaultEditorVim
             | Action::SetDefaultEditorNano
@@ -110,16 +137,25 @@ impl App {
             | Action::Move
             | Action::OpenDefaultApp
             | Action::OpenTerminalEditor
-            | Action::RunShellCommand
+            | Action::RevealInFileManager
+            | Action::GitShowDiff
+            | Action::GitDiscardChanges
+            | Action::RunShell
+            | Action::RunExecutable
             | Action::Delete
             | Action::PermanentDelete
             | Action::MakeDirectory
+            | Action::NewFile
             | Action::Rename
+            | Action::RenameViaEditor
             | Action::ShowProperties
+            | Action::ShowSelectionStats
             | Action::ArchiveCompress
             | Action::ArchiveExtract
             | Action::ArchiveExtractAuto
             | Action::ArchivePreview
+            | Action::ForgetArchivePasswords
+            | Action::ArchiveNameEncoding
             | Action::ToggleSelection
             | Action::InvertSelection
             | Action::SelectAll
@@ -130,17 +166,37 @@ impl App {
             | Action::SortByExt
             | Action::SortAscending
             | Action::SortDescending
+            | Action::ToggleNaturalSort
+            | Action::ToggleLocaleSort
+            | Action::ToggleDirsFirst
             | Action::StartFilter
             | Action::ClearFilter
             | Action::ToggleHidden
+            | Action::ToggleHiddenBoth
+            | Action::ToggleHidePatterns
+            | Action::EditHidePatterns
+            | Action::ToggleSyncBrowsing
+            | Action::ToggleFollowFocus
             | Action::ShowMountPoints
+            | Action::AnalyzeDiskUsage
+            | Action::CompareFiles
+            | Action::SyncPanels
             | Action::GoToPath
+            | Action::ConnectRemote
             | Action::ShowTabList
             | Action::HistoryBack
             | Action::HistoryForward
             | Action::ShowHistoryList
+            | Action::ShowJumpList
+            | Action::ShowSiblingList
+            | Action::FindFile
             | Action::AddBookmark
             | Action::ShowBookmarkList
+            | Action::ExportBookmarks
+            | Action::ImportBookmarks
+            | Action::SaveSession
+            | Action::ShowSessionList
+            | Action::ShowJobList
             | Action::SizeFormatAuto
             | Action::SizeFormatBytes => controllers::operation_controller::execute(self, action),
         }
@@ -184,7 +240,7 @@ impl App {
         }
 
         // 다중 선택 초기화 (인덱스 무효화)
-        panel.selected_items.clear();
+        panel.deselect_all();
 
         let indicator = panel.sort_indicator_localized(language);
         self.set_toast(&indicator);
@@ -220,7 +276,7 @@ impl App {
             }
         }
 
-        panel.selected_items.clear();
+        panel.deselect_all();
 
         let indicator = panel.sort_indicator_localized(language);
         self.set_toast(&indicator);
@@ -232,10 +288,85 @@ impl App {
         let language = self.language();
         let panel = self.active_panel_state_mut();
         panel.sort_entries();
-        panel.selected_items.clear();
+        panel.deselect_all();
+
+        let indicator = panel.sort_indicator_localized(language);
+        self.set_toast(&indicator);
+    }
+
+    /// 정렬 옵션(이름 비교 방식, 디렉토리 우선)을 양쪽 패널의 모든 탭에 적용하고 재정렬
+    pub(super) fn apply_sort_preferences(&mut self, collation: NameCollation, dirs_first: bool) {
+        self.sort_collation = collation;
+        self.dirs_first = dirs_first;
+        for tabs in [&mut self.left_tabs, &mut self.right_tabs] {
+            for panel in tabs.tabs_mut() {
+                if panel.collation != collation || panel.dirs_first != dirs_first {
+                    panel.collation = collation;
+                    panel.dirs_first = dirs_first;
+                    panel.sort_entries();
+                    panel.deselect_all();
+                }
+            }
+        }
+    }
+
+    fn set_sort_preferences_and_save(&mut self, collation: NameCollation, dirs_first: bool) {
+        let language = self.language();
+        let panel = self.active_panel_state();
+        let has_parent = panel.current_path.parent().is_some();
+        let focused_name = {
+            let entry_index = if has_parent {
+                panel.selected_index.saturating_sub(1)
+            } else {
+                panel.selected_index
+            };
+            panel.entries.get(entry_index).map(|e| e.name.clone())
+        };
+
+        self.apply_sort_preferences(collation, dirs_first);
 
+        let panel = self.active_panel_state_mut();
+        if let Some(name) = focused_name {
+            let offset = if has_parent { 1 } else { 0 };
+            if let Some(idx) = panel.entries.iter().position(|e| e.name == name) {
+                panel.selected_index = idx + offset;
+            }
+        }
         let indicator = panel.sort_indicator_localized(language);
         self.set_toast(&indicator);
+        self.adjust_scroll_offset();
+        let _ = self.save_persisted_state();
+    }
+
+    /// 자연 정렬 (숫자 구간 수치 비교) 전환
+    pub(super) fn toggle_natural_sort(&mut self) {
+        let mut collation = self.sort_collation;
+        collation.natural = !collation.natural;
+        self.set_sort_preferences_and_save(collation, self.dirs_first);
+    }
+
+    /// 로캘 인식 정렬 전환
+    pub(super) fn toggle_locale_sort(&mut self) {
+        let mut collation = self.sort_collation;
+        collation.locale = !collation.locale;
+        self.set_sort_preferences_and_save(collation, self.dirs_first);
+    }
+
+    /// 디렉토리 우선 / 파일과 섞어서 정렬 전환
+    pub(super) fn toggle_dirs_first(&mut self) {
+        self.set_sort_preferences_and_save(self.sort_collation, !self.dirs_first);
+    }
+
+    /// 복사 옵션 기본값 전환 (설정 저장)
+    pub(super) fn toggle_copy_option(&mut self, option: CopyOption) {
+        self.copy_options.toggle(option);
+        let state = if self.copy_options.get(option) {
+            "on"
+        } else {
+            "off"
+        };
+        self.set_toast(&format!("{}: {}", option.label(), state));
+        let _ = self.save_persisted_state();
     }
 
     // === 파일 탐색 관련 메서드 (Phase 2.3) ===
@@ -344,6 +475,11 @@ impl App {
             }
         };
 
+        if result.is_ok() && self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            let visited = self.active_panel_state().current_path.clone();
+            self.record_frecent_visit(&visited);
+        }
+
         if result.is_ok() && record_in_history {
             self.active_panel_state_mut()
                 .record_history(path_for_history);
@@ -358,7 +494,7 @@ impl App {
 
     /// 상위 디렉토리로 이동 (h / Left)
     pub fn go_to_parent(&mut self) {
-        if self.archive_view_go_parent() {
+        if self.archive_view_go_parent() || self.remote_view_go_parent() {
             return;
         }
         let panel = self.active_panel_state();
@@ -372,7 +508,9 @@ impl App {
                 .file_name()
                 .and_then(|n| n.to_str())
                 .map(|s| s.to_string());
-            let _ = self.change_active_dir(parent_path, true, current_dir_name.as_deref());
+            if self.change_active_dir(parent_path, true, current_dir_name.as_deref()) {
+                self.sync_go_to_parent();
+            }
         }
     }
 
@@ -418,9 +556,12 @@ impl App {
             .is_some_and(|t| t.elapsed().as_millis() > 800)
     }
 
-    /// 대기 키 표시 문자열 (상태바용)
+    /// 대기 키 표시 문자열 (상태바용, 대기 키가 없으면 매크로 녹화 중 표시)
     pub fn pending_key_display(&self) -> Option<String> {
-        self.pending_key.map(|k| format!("{}_", k))
+        self.pending_key.map(|k| format!("{}_", k)).or_else(|| {
+            self.recording_macro_register()
+                .map(|r| format!("rec @{}", r))
+        })
     }
 
     /// 메시지 다이얼로그 표시
@@ -540,97 +681,6 @@ impl App {
         }
     }
 
-    pub fn dialog_help_input_char(&mut self, c: char) {
-        if let Some(DialogKind::Help {
-            search_query,
-            search_cursor,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::insert_char(search_query, search_cursor, c);
-            *scroll_offset = 0;
-        }
-    }
-
-    pub fn dialog_help_backspace(&mut self) {
-        if let Some(DialogKind::Help {
-            search_query,
-            search_cursor,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::backspace(search_query, search_cursor);
-            *scroll_offset = 0;
-        }
-    }
-
-    pub fn dialog_help_delete_prev_word(&mut self) {
-        if let Some(DialogKind::Help {
-            search_query,
-            search_cursor,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete_prev_word(search_query, search_cursor);
-            *scroll_offset = 0;
-        }
-    }
-
-    pub fn dialog_help_delete(&mut self) {
-        if let Some(DialogKind::Help {
-            search_query,
-            search_cursor,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete(search_query, search_cursor);
-            *scroll_offset = 0;
-        }
-    }
-
-    pub fn dialog_help_cursor_left(&mut self) {
-        if let Some(DialogKind::Help {
-            search_query,
-            search_cursor,
-            ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::left(search_query, search_cursor);
-        }
-    }
-
-    pub fn dialog_help_cursor_right(&mut self) {
-        if let Some(DialogKind::Help {
-            search_query,
-            search_cursor,
-            ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::right(search_query, search_cursor);
-        }
-    }
-
-    pub fn dialog_help_cursor_home(&mut self) {
-        if let Some(DialogKind::Help { search_cursor, .. }) = &mut self.dialog {
-            TextBufferEdit::home(search_cursor);
-        }
-    }
-
-    pub fn dialog_help_cursor_end(&mut self) {
-        if let Some(DialogKind::Help {
-            search_query,
-            search_cursor,
-            ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::end(search_query, search_cursor);
-        }
-    }
-
     /// 최대 인덱스 계산
     pub(super) fn get_max_index(&self) -> usize {
         let panel = self.active_panel_state();
@@ -643,21 +693,10 @@ impl App {
         }
     }
 
-    /// 페이지 크기 계산 (화면에 표시되는 항목 수)
+    /// 페이지 크기 계산 (화면에 표시되는 항목 수, 정보 줄/경로 줄 제외)
     pub(super) fn get_page_size(&self) -> usize {
-        let panel = self.active_panel_state();
-        let (_, terminal_height) = self.layout.terminal_size();
-        let panel_inner_height = terminal_height.saturating_sub(4);
-        let available_height = panel_inner_height
-            .saturating_sub(2)
-            .saturating_sub(2)
-            .saturating_sub(if panel.current_path.parent().is_some() {
-                1
-            } else {
-                0
-            });
-
-        (available_height as usize).max(1)
+        let has_parent = self.active_panel_state().current_path.parent().is_some();
+        self.panel_list_height(has_parent) as usize
     }
 
     /// 스크롤 오프셋을 현재 선택 위치에 맞게 조정
@@ -682,15 +721,7 @@ impl App {
             selected
         };
 
-        // 패널 렌더링 가능 높이 계산
-        // terminal_height - menu_bar(1) - status_bar(1) - command_bar(1)
-        // - panel_borders(2) - header(1) - separator(1) - parent(1 if shown)
-        let (_, terminal_height) = self.layout.terminal_size();
-        let panel_inner_height = terminal_height.saturating_sub(4); // 메뉴/상태/커맨드바 제외
-        let available_height = panel_inner_height
-            .saturating_sub(2) // 테두리
-            .saturating_sub(2) // 헤더 + 구분선
-            .saturating_sub(if has_parent { 1 } else { 0 }); // ".." 항목
+        let available_height = self.panel_list_height(has_parent);
 
         let panel_mut = self.active_panel_state_mut();
 
@@ -705,6 +736,65 @@ impl App {
         }
     }
 
+    /// 패널에서 파일 목록이 차지하는 줄 수
+    ///
+    /// panel_height(메뉴/상태/커맨드바 제외) - 여유(1) - panel_borders(2)
+    /// - header(1) - separator(1) - parent(1 if shown) - info line(1 if shown)
+    /// - path line(1 if shown)
+    pub(super) fn panel_list_height(&self, has_parent: bool) -> u16 {
+        self.layout
+            .panel_height()
+            .saturating_sub(1)
+            .saturating_sub(2) // 테두리
+            .saturating_sub(2) // 헤더 + 구분선
+            .saturating_sub(if has_parent { 1 } else { 0 }) // ".." 항목
+            .saturating_sub(u16::from(self.show_info_line)) // 하단 정보 줄
+            .saturating_sub(u16::from(self.show_path_line)) // 상태바 위 전체 경로 줄
+            .max(1)
+    }
+
+    /// 터미널 크기가 바뀌거나 목록이 늘어난 뒤 양쪽 패널의 스크롤 보정
+    ///
+    /// 스크롤 위치는 가능한 한 그대로 두고, 커서가 화면 밖으로 밀리거나
+    /// 커진 화면 아래쪽이 비는 경우에만 최소한으로 옮깁니다.
+    pub fn keep_panels_in_view(&mut self) {
+        for panel in [ActivePanel::Left, ActivePanel::Right] {
+            let state = match panel {
+                ActivePanel::Left => self.left_active_panel_state(),
+                ActivePanel::Right => self.right_active_panel_state(),
+            };
+            let has_parent = state.current_path.parent().is_some();
+            let height = self.panel_list_height(has_parent) as usize;
+            let state = match panel {
+                ActivePanel::Left => self.left_active_panel_state_mut(),
+                ActivePanel::Right => self.right_active_panel_state_mut(),
+            };
+            if has_parent && state.selected_index == 0 {
+                state.scroll_offset = 0;
+                continue;
+            }
+            let selected = state.selected_index - usize::from(has_parent);
+            let mut scroll = state.scroll_offset;
+            if selected < scroll {
+                scroll = selected;
+            } else if selected >= scroll + height {
+                scroll = selected + 1 - height;
+            }
+            state.scroll_offset = scroll.min(state.entries.len().saturating_sub(height));
+        }
+    }
+
+    /// 큰 디렉토리의 나머지 목록과 나중에 읽은 메타데이터 반영 (메인 루프에서 호출, 보이는 탭만)
+    pub fn poll_directory_listings(&mut self) {
+        let left = self.left_active_panel_state_mut();
+        let left = left.poll_listing() | left.poll_metadata();
+        let right = self.right_active_panel_state_mut();
+        let right = right.poll_listing() | right.poll_metadata();
+        if left || right {
+            self.keep_panels_in_view();
+        }
+    }
+
     /// ".." 선택 시 상위 디렉토리로 이동 + 포커스 복원
     pub(super) fn navigate_to_parent(&mut self, current_path: &std::path::Path) {
         let current_path = Self::normalize_existing_directory_path(current_path)
@@ -715,18 +805,25 @@ impl App {
                 .file_name()
                 .and_then(|n| n.to_str())
                 .map(|s| s.to_string());
-            let _ = self.change_active_dir(parent_path, true, current_dir_name.as_deref());
+            if self.change_active_dir(parent_path, true, current_dir_name.as_deref()) {
+                self.sync_go_to_parent();
+            }
         }
     }
 
-    /// 디렉토리 항목 진입
+    /// 디렉토리 항목 진입 (동기 탐색 중이면 반대 패널도 같은 이름으로 진입)
     pub(super) fn enter_directory(&mut self, path: PathBuf) {
-        let _ = self.change_active_dir(path, true, None);
+        let name = path.file_name().map(|name| name.to_os_string());
+        if self.change_active_dir(path, true, None) {
+            if let Some(name) = name {
+                self.sync_enter_directory(&name);
+            }
+        }
     }
 
-    /// Enter 키 처리: 디렉토리 진입 / 상위 디렉토리 이동 / 압축 파일 미리보기
+    /// Enter 키 처리: 디렉토리 진입 / 상위 디렉토리 이동 / 압축 파일 미리보기 / 파일 종류별 열기
     pub fn enter_selected(&mut self) {
-        if self.archive_view_enter_selected() {
+        if self.archive_view_enter_selected() || self.remote_view_enter_selected() {
             return;
         }
         let panel = self.active_panel_state();
@@ -749,14 +846,16 @@ impl App {
             panel
                 .entries
                 .get(entry_index)
-                .map(|e| (e.is_directory(), e.path.clone()))
+                .map(|e| (e.is_directory(), e.is_executable(), e.path.clone()))
         };
 
-        if let Some((is_dir, path)) = entry_info {
+        if let Some((is_dir, executable, path)) = entry_info {
             if is_dir {
                 self.enter_directory(path);
             } else if detect_archive_format(&path).is_some() {
                 self.start_archive_preview();
+            } else if self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+                self.open_file_by_rules(path, executable);
             }
         }
     }
@@ -0,0 +1,125 @@
+use super::*;
+use crate::system::vfs::VirtualFileSystem;
+use std::io::Write as _;
+
+impl App {
+    // === 새 파일 만들기 (빈 파일 / 템플릿) ===
+
+    /// 새 파일 템플릿 디렉토리 (설정 파일과 같은 위치의 templates/)
+    pub(super) fn templates_dir(&self) -> Option<PathBuf> {
+        let settings = self.state_store_path()?;
+        Some(settings.parent()?.join("templates"))
+    }
+
+    /// 새 파일 이름 입력 시작 (A)
+    pub fn start_new_file(&mut self) {
+        if self.is_active_panel_archive_view() {
+            self.set_toast("Not available in archive panel");
+            return;
+        }
+        let parent_path = self.active_panel_state().current_path.clone();
+        self.dialog = Some(DialogKind::new_file_input(parent_path));
+        self.on_dialog_outcome(|app, outcome| match outcome {
+            DialogOutcome::Text(name) => app.confirm_new_file(&name, false),
+            DialogOutcome::AlternateText(name) => app.confirm_new_file(&name, true),
+            DialogOutcome::Accepted | DialogOutcome::Cancelled => {}
+        });
+    }
+
+    /// 확장자가 같은 첫 번째 템플릿 파일 (이름순)
+    fn template_for(&self, file_name: &str) -> Option<PathBuf> {
+        let ext = Path::new(file_name).extension()?.to_str()?.to_lowercase();
+        let mut candidates: Vec<PathBuf> = fs::read_dir(self.templates_dir()?)
+            .ok()?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .filter(|path| {
+                path.extension()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|candidate| candidate.to_lowercase() == ext)
+            })
+            .collect();
+        candidates.sort();
+        candidates.into_iter().next()
+    }
+
+    /// 새 파일 생성 확인 (`edit`이면 만든 뒤 터미널 편집기로 열기)
+    pub fn confirm_new_file(&mut self, file_name: &str, edit: bool) {
+        let file_name = file_name.trim().to_string();
+        if file_name.is_empty() {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                "Create file failed.\nReason: Name cannot be empty.\nHint: Enter at least one character.",
+            ));
+            return;
+        }
+
+        let parent_path = self.active_panel_state().current_path.clone();
+        if !self.check_new_name(&file_name, &parent_path, NameTarget::NewFile { edit }) {
+            return;
+        }
+
+        let backend = self.active_panel_state().backend.clone();
+        if edit && backend.is_some() {
+            self.set_toast("Editor is only available for local files");
+            return;
+        }
+        let new_path = parent_path.join(&file_name);
+        let template = self.template_for(&file_name);
+        let content = match template.as_ref().map(fs::read) {
+            Some(Ok(content)) => content,
+            Some(Err(e)) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Read template",
+                        template.as_deref(),
+                        &e.to_string(),
+                        "Check the templates directory.",
+                    ),
+                ));
+                return;
+            }
+            None => Vec::new(),
+        };
+
+        let result = match backend.as_deref() {
+            Some(backend) => write_new_file(backend, &new_path, &content),
+            None => write_new_file(&self.filesystem, &new_path, &content),
+        };
+        if let Err(e) = result {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error(
+                    "Create file",
+                    Some(&new_path),
+                    &e.to_string(),
+                    "Use a new name and check write permission.",
+                ),
+            ));
+            return;
+        }
+
+        self.refresh_both_panels();
+        self.focus_active_entry_by_name(&file_name);
+        self.dialog = None;
+        match template.as_deref().and_then(Path::file_name) {
+            Some(name) => self.set_toast(&format!(
+                "File '{}' created from template '{}'.",
+                file_name,
+                name.to_string_lossy()
+            )),
+            None => self.set_toast(&format!("File '{}' created.", file_name)),
+        }
+        if edit {
+            self.start_open_terminal_editor();
+        }
+    }
+}
+
+/// 새 파일 쓰기 (이미 있으면 FileExists)
+fn write_new_file(backend: &dyn VirtualFileSystem, path: &Path, content: &[u8]) -> Result<()> {
+    let mut writer = backend.open_write(path)?;
+    writer.write_all(content)?;
+    writer.commit()
+}
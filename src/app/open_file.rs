@@ -0,0 +1,56 @@
+use super::*;
+use crate::models::open_rules::{EnterAction, OpenTarget};
+use crate::system::file_type;
+use std::io::Read as _;
+
+/// 내장 보기에서 읽는 최대 크기
+const VIEWER_MAX_BYTES: u64 = 1024 * 1024;
+
+impl App {
+    // === 파일에서 Enter: 파일 종류별 동작 (open.toml) ===
+
+    /// 파일 종류 규칙에 따라 보기/편집/기본 앱/실행 중 하나로 열기
+    pub(super) fn open_file_by_rules(&mut self, path: PathBuf, executable: bool) {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let detected = file_type::detect_path(&path).ok();
+        let action = self.open_rules.action_for(&OpenTarget {
+            name: &name,
+            mime: detected.map(|detected| detected.mime),
+            executable,
+        });
+        match action {
+            EnterAction::Viewer => self.open_in_text_viewer(&path, &name),
+            EnterAction::Editor => self.start_open_terminal_editor(),
+            EnterAction::DefaultApp => self.start_open_default_app(),
+            EnterAction::Run if executable => {
+                self.open_run_executable_input(path);
+            }
+            EnterAction::Run => self.set_toast("Not an executable file"),
+            EnterAction::None => {}
+        }
+    }
+
+    /// 파일 앞부분을 내장 텍스트 보기로 열기
+    fn open_in_text_viewer(&mut self, path: &Path, name: &str) {
+        let mut bytes = Vec::new();
+        let read = fs::File::open(path)
+            .and_then(|file| file.take(VIEWER_MAX_BYTES + 1).read_to_end(&mut bytes));
+        if let Err(err) = read {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error("Open in viewer", Some(path), &err.to_string(), ""),
+            ));
+            return;
+        }
+        let truncated = bytes.len() as u64 > VIEWER_MAX_BYTES;
+        bytes.truncate(VIEWER_MAX_BYTES as usize);
+        let mut text = String::from_utf8_lossy(&bytes).into_owned();
+        if truncated {
+            text.push_str("\n… (truncated at 1 MiB)");
+        }
+        self.dialog = Some(DialogKind::text_viewer(name, &text, false));
+    }
+}
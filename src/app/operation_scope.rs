@@ -0,0 +1,156 @@
+use super::*;
+use crate::models::operation::{OperationPreview, PreviewAction};
+use crate::utils::formatter::{format_file_size, pluralize};
+
+impl App {
+    // === 복사/이동 대상 경로 입력의 범위 필터(Ctrl+F)와 미리보기(Preview 버튼) ===
+
+    /// 범위 필터 입력 열기 (대상 경로 입력 창은 보관했다가 닫으면 복귀)
+    pub fn edit_operation_scope_filter(&mut self) {
+        let Some(DialogKind::Input {
+            scope_filter: Some(filter),
+            ..
+        }) = &self.dialog
+        else {
+            return;
+        };
+        self.push_dialog(DialogKind::operation_scope_filter_input(filter.clone()));
+        self.on_dialog_text(|app, value| app.confirm_operation_scope_filter(&value));
+    }
+
+    /// 범위 필터 입력 확정 (필터 입력을 닫고 돌아온 대상 경로 입력 창에 반영)
+    fn confirm_operation_scope_filter(&mut self, value: &str) {
+        if let Some(DialogKind::Input { scope_filter, .. }) = &mut self.dialog {
+            *scope_filter = Some(value.trim().to_string());
+        }
+    }
+
+    /// 입력한 대상 경로로 실행하면 생성/덮어쓰기/건너뛰기/제거될 항목 표시
+    ///
+    /// 대상 경로 입력 창은 보관했다가 미리보기를 닫으면 그대로 되돌아가므로, 경로나 옵션을
+    /// 고친 뒤 다시 미리보거나 바로 실행할 수 있습니다.
+    pub fn preview_operation_destination(&mut self, dest_path_str: String) {
+        let (
+            Some(DialogKind::Input {
+                base_path,
+                scope_filter,
+                copy_options,
+                ..
+            }),
+            Some(pending),
+        ) = (&self.dialog, &self.pending_operation)
+        else {
+            return;
+        };
+        let filter = ScopeFilter::parse(scope_filter.as_deref().unwrap_or_default());
+        let resolved_path = self.resolve_input_path(&dest_path_str, base_path);
+        let resolved_path_str = resolved_path.to_string_lossy().to_string();
+        if let Err(error_msg) = Self::validate_operation_destination(
+            &pending.sources,
+            pending.operation_type,
+            &resolved_path,
+            &resolved_path_str,
+        ) {
+            self.set_toast(&error_msg);
+            return;
+        }
+        let (files, filtered_out) = match self.filesystem.flatten_sources_filtered(
+            &pending.sources,
+            &resolved_path,
+            &filter,
+            match copy_options {
+                Some(options) if pending.operation_type == OperationType::Copy => options.symlinks,
+                _ => pending.symlink_policy(),
+            },
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                self.set_toast(&format!("Failed to scan files: {}", e));
+                return;
+            }
+        };
+
+        let operation_type = pending.operation_type;
+        let preview = OperationPreview::build(operation_type, &files);
+        let report = preview_report(
+            operation_type,
+            &resolved_path,
+            &preview,
+            filtered_out,
+            self.confirm_policy.overwrite,
+        );
+        let title = format!("{} Preview", operation_type.name());
+        self.push_dialog(DialogKind::text_viewer(title, &report, true));
+    }
+}
+
+/// 미리보기 본문 (요약 한 줄 + 처리별 구역, 대상 항목은 대상 디렉토리 기준 상대 경로)
+fn preview_report(
+    operation_type: OperationType,
+    dest_dir: &Path,
+    preview: &OperationPreview,
+    filtered_out: usize,
+    overwrite_asks: bool,
+) -> String {
+    let mut text = format!(
+        "{} to {}\nWrites {} · create {} · overwrite {} · skip {}",
+        operation_type.name(),
+        dest_dir.display(),
+        format_file_size(preview.total_write_bytes()),
+        preview.count(PreviewAction::Create),
+        preview.count(PreviewAction::Overwrite),
+        preview.count(PreviewAction::Skip),
+    );
+    if operation_type == OperationType::Move {
+        text.push_str(&format!(
+            " · remove {}",
+            preview.count(PreviewAction::Remove)
+        ));
+    }
+    if filtered_out > 0 {
+        text.push_str(&format!(" · filtered out {}", filtered_out));
+    }
+    text.push('\n');
+
+    let sections = [
+        (PreviewAction::Create, "+", "Create"),
+        (PreviewAction::Overwrite, "~", "Overwrite"),
+        (PreviewAction::Skip, "=", "Skip"),
+        (PreviewAction::Remove, "-", "Remove from source"),
+    ];
+    for (action, marker, label) in sections {
+        let count = preview.count(action);
+        if count == 0 {
+            continue;
+        }
+        let note = if action == PreviewAction::Overwrite && overwrite_asks {
+            ", asks before each"
+        } else {
+            ""
+        };
+        text.push_str(&format!(
+            "\n@@ {} ({}, {}{})\n",
+            label,
+            pluralize(count, "item", "items"),
+            format_file_size(preview.bytes(action)),
+            note
+        ));
+        for entry in preview.entries_for(action) {
+            let path = if action == PreviewAction::Remove {
+                entry.path.as_path()
+            } else {
+                entry.path.strip_prefix(dest_dir).unwrap_or(&entry.path)
+            };
+            text.push_str(&format!(
+                "{} {}  {}\n",
+                marker,
+                path.display(),
+                format_file_size(entry.size)
+            ));
+        }
+    }
+    if preview.entries.is_empty() {
+        text.push_str("\nNothing to do\n");
+    }
+    text
+}
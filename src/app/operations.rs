@@ -1,5 +1,7 @@
-use super::text_edit::TextBufferEdit;
 use super::*;
+use crate::ui::components::{ListDialogState, PathCompletion};
+use crate::ui::{BookmarkEditField, BookmarkListItem, BookmarkListRow, HistoryListItem};
+use crate::utils::win_path;
 
 impl App {
     // === 파일 복사/이동 관련 메서드 (Phase 3.2) ===
@@ -93,24 +95,58 @@ impl App {
 
     /// 다이얼로그 닫기
     pub fn close_dialog(&mut self) {
-        self.dialog = None;
-        self.pending_operation = None;
-        self.archive_flow = None;
+        let waiting = self.dialog_continuation.take();
+        // 다른 창 위에 띄운 창이면 아래 창으로 복귀
+        if !self.pop_dialog() {
+            self.error_report = None;
+            self.dialog = None;
+            self.pending_operation = None;
+            self.archive_flow = None;
+            self.remote_flow = None;
+        }
+        // 결과를 기다리던 작업에는 취소로 전달
+        if let Some(then) = waiting {
+            then(self, DialogOutcome::Cancelled);
+        }
     }
 
     /// 진행 중인 작업 취소
     pub fn cancel_operation(&mut self) {
         if let Some(worker) = &self.archive_worker {
-            worker.cancel_flag.store(true, Ordering::Relaxed);
-            self.set_toast("Archive cancel requested...");
+            worker.control.cancel();
+            match worker.kind {
+                ArchiveWorkerKind::Compress
+                | ArchiveWorkerKind::Extract
+                | ArchiveWorkerKind::Delete => {
+                    self.set_toast("Archive cancel requested...");
+                }
+                ArchiveWorkerKind::Download | ArchiveWorkerKind::Upload => {
+                    self.set_toast("Transfer cancel requested...");
+                }
+            }
             return;
         }
 
+        if let Some(copy) = self.chunked_copy.take() {
+            copy.abort();
+        }
+
         if let Some(pending) = self.pending_operation.take() {
             // 패널 새로고침 (일부 복사된 파일 반영)
             self.refresh_both_panels();
             self.cleanup_archive_copy_temp_dir();
 
+            self.record_job(
+                pending.operation_type.name(),
+                format!(
+                    "{}/{} files",
+                    pending.progress.files_completed, pending.progress.total_files
+                ),
+                JobStatus::Cancelled,
+                pending.progress.active_elapsed(),
+                pending.errors.clone(),
+            );
+
             // 취소 토스트 표시
             self.dialog = None;
             self.set_toast(&format!(
@@ -126,6 +162,9 @@ impl App {
 
     /// 복사 시작 (y)
     pub fn start_copy(&mut self) {
+        if self.start_remote_transfer(OperationType::Copy) {
+            return;
+        }
         if self.is_active_panel_archive_view() {
             self.start_archive_copy_dialog();
             return;
@@ -135,6 +174,9 @@ impl App {
 
     /// 이동 시작 (x)
     pub fn start_move(&mut self) {
+        if self.start_remote_transfer(OperationType::Move) {
+            return;
+        }
         self.start_file_operation(OperationType::Move);
     }
 
@@ -161,7 +203,14 @@ impl App {
         };
         let suggested_path = Self::next_unique_archive_path(&base_path, &suggested);
         let initial = suggested_path.to_string_lossy().to_string();
-        self.dialog = Some(DialogKind::archive_create_options_input(initial, base_path));
+        let presets = self
+            .archive_presets
+            .iter()
+            .map(|preset| preset.name.clone())
+            .collect();
+        self.dialog = Some(DialogKind::archive_create_options_input(
+            initial, base_path, presets,
+        ));
         self.archive_flow = Some(ArchiveFlowContext::CreatePending { sources });
     }
 
@@ -217,6 +266,10 @@ impl App {
 
     /// 압축 해제 시작 (zx)
     pub fn start_archive_extract(&mut self) {
+        if self.is_active_panel_archive_view() {
+            self.start_archive_view_extract();
+            return;
+        }
         let archive_path = match self.focused_open_target() {
             Ok(path) => path,
             Err(reason) => {
@@ -243,9 +296,15 @@ impl App {
         let base_path = self.inactive_panel_state().current_path.clone();
         let initial = base_path.to_string_lossy().to_string();
         self.dialog = Some(DialogKind::archive_extract_path_input(initial, base_path));
+        let password = self
+            .archive_passwords
+            .get(&archive_path)
+            .map(str::to_string);
         self.archive_flow = Some(ArchiveFlowContext::ExtractPending {
             archive_path,
             format,
+            only_entries: Vec::new(),
+            password,
         });
         self.update_input_completion_state();
     }
@@ -334,6 +393,7 @@ impl App {
         archive_path: &Path,
         base_dir: &Path,
         password: Option<&str>,
+        name_encoding: ZipNameEncoding,
     ) -> Result<ArchiveExtractRequest> {
         if !base_dir.exists() || !base_dir.is_dir() {
             return Err(BokslDirError::ArchiveExtractFailed {
@@ -345,7 +405,7 @@ impl App {
             });
         }
 
-        let entries = list_entries(archive_path, password)?;
+        let entries = list_entries(archive_path, password, name_encoding)?;
         let single_root_dir = Self::detect_single_root_dir(&entries);
         let dest_dir = if single_root_dir.is_some() {
             base_dir.to_path_buf()
@@ -364,6 +424,8 @@ impl App {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding,
         })
     }
 
@@ -393,11 +455,21 @@ impl App {
         };
 
         let base_dir = self.active_panel_state().current_path.clone();
-        match Self::build_auto_extract_request(&archive_path, &base_dir, None) {
+        let password = self
+            .archive_passwords
+            .get(&archive_path)
+            .map(str::to_string);
+        match Self::build_auto_extract_request(
+            &archive_path,
+            &base_dir,
+            password.as_deref(),
+            self.archive_name_encoding(&archive_path),
+        ) {
             Ok(request) => {
                 self.prepare_archive_extract_request(request);
             }
-            Err(BokslDirError::ArchivePasswordRequired { .. }) if supports_password(format) => {
+            Err(err) if Self::is_archive_password_error(&err) && supports_password(format) => {
+                self.archive_passwords.forget(&archive_path);
                 self.archive_flow = Some(ArchiveFlowContext::ExtractAutoNeedsPassword {
                     archive_path,
                     base_dir,
@@ -447,14 +519,34 @@ impl App {
             &request.archive_path,
             &request.dest_dir,
             request.password.as_deref(),
+            &request.only_entries,
+            request.name_encoding,
         ) {
             Ok(conflicts) if conflicts.is_empty() => {
                 self.archive_flow = None;
                 self.start_archive_extract_worker(request);
             }
+            Ok(_) if !self.confirm_policy.overwrite => {
+                let mut request = request;
+                request.overwrite_existing = true;
+                self.archive_flow = None;
+                self.start_archive_extract_worker(request);
+            }
             Ok(conflicts) => {
                 self.show_archive_extract_conflict_dialog(request, conflicts, 0);
             }
+            Err(err)
+                if Self::is_archive_password_error(&err)
+                    && detect_archive_format(&request.archive_path)
+                        .is_some_and(supports_password) =>
+            {
+                // 기억한(또는 방금 입력한) 비밀번호가 맞지 않으면 잊고 다시 묻기
+                self.archive_passwords.forget(&request.archive_path);
+                let mut request = request;
+                request.password = None;
+                self.archive_flow = Some(ArchiveFlowContext::ExtractNeedsPassword { request });
+                self.dialog = Some(DialogKind::archive_password_input("Archive Password"));
+            }
             Err(err) => {
                 self.archive_flow = None;
                 self.dialog = Some(DialogKind::error(
@@ -490,12 +582,15 @@ impl App {
             return;
         };
 
-        match self.enter_archive_panel_view(&archive_path, None) {
+        let password = self
+            .archive_passwords
+            .get(&archive_path)
+            .map(str::to_string);
+        match self.enter_archive_panel_view(&archive_path, password.as_deref()) {
             Ok(()) => {}
             Err(err) => {
-                if supports_password(format)
-                    && matches!(err, BokslDirError::ArchivePasswordRequired { .. })
-                {
+                if supports_password(format) && Self::is_archive_password_error(&err) {
+                    self.archive_passwords.forget(&archive_path);
                     self.archive_flow = Some(ArchiveFlowContext::PreviewNeedsPassword {
                         archive_path,
                         panel: self.active_panel(),
@@ -516,6 +611,28 @@ impl App {
         }
     }
 
+    /// 비밀번호가 없거나 맞지 않아 실패 (비밀번호를 다시 물을 오류)
+    pub(super) fn is_archive_password_error(err: &BokslDirError) -> bool {
+        matches!(
+            err,
+            BokslDirError::ArchivePasswordRequired { .. }
+                | BokslDirError::ArchiveInvalidPassword { .. }
+        )
+    }
+
+    /// 세션 동안 기억한 압축 비밀번호 모두 잊기
+    pub fn forget_archive_passwords(&mut self) {
+        if self.archive_passwords.is_empty() {
+            self.set_toast("No archive passwords remembered");
+        } else {
+            let count = self.archive_passwords.clear();
+            self.set_toast(&format!(
+                "Forgot {}",
+                crate::utils::formatter::pluralize(count, "archive password", "archive passwords")
+            ));
+        }
+    }
+
     pub(super) fn panel_state_by_slot_mut(&mut self, slot: PanelSlot) -> &mut PanelState {
         match slot {
             PanelSlot::Left => self.left_tabs.active_mut(),
@@ -536,6 +653,40 @@ impl App {
             .is_some_and(|v| v.panel == PanelSlot::from(self.active_panel()))
     }
 
+    /// 로컬 디렉토리를 표시 중인 패널인지 (압축/원격 탐색 제외)
+    ///
+    /// 로컬 파일시스템을 직접 쓰는 기능은 이 확인을 거칩니다. 마운트된 백엔드만 보면
+    /// 압축 파일 내부 보기를 놓칩니다.
+    pub(super) fn is_local_panel_view(&self, slot: PanelSlot) -> bool {
+        !self.panel_state_by_slot(slot).is_mounted()
+            && !self
+                .archive_panel_view
+                .as_ref()
+                .is_some_and(|v| v.panel == slot)
+            && !self
+                .remote_panel_view
+                .as_ref()
+                .is_some_and(|v| v.panel == slot)
+    }
+
+    /// 로컬 전용 기능: 활성 패널이 로컬 디렉토리가 아니면 안내 후 false
+    pub(super) fn require_local_panel(&mut self) -> bool {
+        if self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            return true;
+        }
+        self.set_toast("Not available in archive or remote panel");
+        false
+    }
+
+    /// 양쪽 패널을 함께 쓰는 로컬 전용 기능: 한쪽이라도 로컬이 아니면 안내 후 false
+    pub(super) fn require_local_panels(&mut self) -> bool {
+        if self.is_local_panel_view(PanelSlot::Left) && self.is_local_panel_view(PanelSlot::Right) {
+            return true;
+        }
+        self.set_toast("Not available while browsing an archive or remote panel");
+        false
+    }
+
     pub(super) fn normalize_archive_entry_path(path: &str) -> String {
         let trimmed = path.replace('\\', "/");
         trimmed.trim_matches('/').to_string()
@@ -637,7 +788,8 @@ impl App {
         let panel = self.panel_state_by_slot_mut(view.panel);
         panel.current_path = PathBuf::from(display_path);
         panel.entries = entries;
-        panel.selected_items.clear();
+        panel.deselect_all();
+        panel.recount_entries();
         panel.selected_index = 0;
         panel.scroll_offset = 0;
     }
@@ -647,7 +799,11 @@ impl App {
         archive_path: &Path,
         password: Option<&str>,
     ) -> Result<()> {
-        let entries = list_entries(archive_path, password)?;
+        let entries = list_entries(
+            archive_path,
+            password,
+            self.archive_name_encoding(archive_path),
+        )?;
         let panel = PanelSlot::from(self.active_panel());
         let base_dir = archive_path
             .parent()
@@ -744,23 +900,7 @@ impl App {
             self.start_file_operation(OperationType::Copy);
             return;
         }
-        let panel = self.panel_state_by_slot(view.panel);
-        let has_parent = true;
-        let selected_entries: Vec<FileEntry> = if !panel.selected_items.is_empty() {
-            panel.selected_entries().into_iter().cloned().collect()
-        } else {
-            let idx = panel.selected_index;
-            if has_parent && idx == 0 {
-                Vec::new()
-            } else {
-                panel
-                    .entries
-                    .get(idx.saturating_sub(1))
-                    .cloned()
-                    .into_iter()
-                    .collect()
-            }
-        };
+        let selected_entries = self.archive_view_selected_entries(&view);
         if selected_entries.is_empty() {
             self.dialog = Some(DialogKind::message(
                 "Information",
@@ -780,6 +920,145 @@ impl App {
         self.update_input_completion_state();
     }
 
+    /// 압축 보기에서 고른 항목 (선택이 없으면 커서 항목, ".."은 제외)
+    fn archive_view_selected_entries(&self, view: &ArchivePanelView) -> Vec<FileEntry> {
+        let panel = self.panel_state_by_slot(view.panel);
+        if !panel.selected_items.is_empty() {
+            return panel.selected_entries().into_iter().cloned().collect();
+        }
+        match panel.selected_index {
+            0 => Vec::new(),
+            idx => panel.entries.get(idx - 1).cloned().into_iter().collect(),
+        }
+    }
+
+    /// 압축 보기에서 고른 항목만 풀기 (zx, 압축 안 경로 그대로 반대 패널 경로 아래에)
+    fn start_archive_view_extract(&mut self) {
+        let Some(view) = self.archive_panel_view.clone() else {
+            return;
+        };
+        let Some(format) = detect_archive_format(&view.archive_path) else {
+            return;
+        };
+        let only_entries: Vec<String> = self
+            .archive_view_selected_entries(&view)
+            .iter()
+            .map(|entry| entry.path.to_string_lossy().to_string())
+            .collect();
+        if only_entries.is_empty() {
+            self.dialog = Some(DialogKind::message(
+                "Information",
+                "No archive entries selected for extract.",
+            ));
+            return;
+        }
+        let base_path = self.inactive_panel_state().current_path.clone();
+        let initial = base_path.to_string_lossy().to_string();
+        self.dialog = Some(DialogKind::archive_extract_path_input(initial, base_path));
+        self.archive_flow = Some(ArchiveFlowContext::ExtractPending {
+            archive_path: view.archive_path,
+            format,
+            only_entries,
+            password: view.password,
+        });
+        self.update_input_completion_state();
+    }
+
+    /// 압축 보기에서 고른 항목 삭제 확인 (ZIP/7z만, 지울 항목 목록 표시)
+    fn prepare_archive_entry_delete(&mut self) {
+        let Some(view) = self.archive_panel_view.clone() else {
+            return;
+        };
+        if !detect_archive_format(&view.archive_path).is_some_and(supports_entry_delete) {
+            self.dialog = Some(DialogKind::error(
+                "Error",
+                Self::format_user_error(
+                    "Delete from archive",
+                    Some(&view.archive_path),
+                    "Deleting entries is not supported for this archive format",
+                    "Only zip and 7z archives can be updated in place.",
+                ),
+            ));
+            return;
+        }
+        let selected = self.archive_view_selected_entries(&view);
+        if selected.is_empty() {
+            self.dialog = Some(DialogKind::message(
+                "Information",
+                "No archive entries selected for deletion.",
+            ));
+            return;
+        }
+
+        const PREVIEW_LIMIT: usize = 10;
+        let mut lines: Vec<String> = selected
+            .iter()
+            .take(PREVIEW_LIMIT)
+            .map(|entry| {
+                let path = entry.path.to_string_lossy();
+                if entry.is_directory() {
+                    format!("  {}/", path)
+                } else {
+                    format!("  {}", path)
+                }
+            })
+            .collect();
+        if selected.len() > PREVIEW_LIMIT {
+            lines.push(format!("  ... and {} more", selected.len() - PREVIEW_LIMIT));
+        }
+        let archive_name = view
+            .archive_path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .unwrap_or_default()
+            .to_string();
+        self.dialog = Some(DialogKind::confirm(
+            "Delete from archive",
+            format!(
+                "Delete {} from {}?\n{}",
+                crate::utils::formatter::pluralize(selected.len(), "entry", "entries"),
+                archive_name,
+                lines.join("\n")
+            ),
+        ));
+        let name_encoding = self.archive_name_encoding(&view.archive_path);
+        let request = ArchiveDeleteRequest {
+            archive_path: view.archive_path,
+            entries: selected
+                .iter()
+                .map(|entry| entry.path.to_string_lossy().to_string())
+                .collect(),
+            password: view.password,
+            name_encoding,
+        };
+        self.on_dialog_accepted(|app| app.start_archive_delete_worker(request));
+    }
+
+    /// 항목을 지운 압축 파일을 다시 읽어 압축 보기 갱신 (보던 디렉토리가 비면 위로)
+    pub(super) fn reload_archive_view(&mut self) {
+        let Some(mut view) = self.archive_panel_view.clone() else {
+            return;
+        };
+        let Ok(entries) = list_entries(
+            &view.archive_path,
+            view.password.as_deref(),
+            self.archive_name_encoding(&view.archive_path),
+        ) else {
+            return;
+        };
+        view.all_entries = entries;
+        while !view.current_dir.is_empty()
+            && Self::build_archive_panel_entries(&view.all_entries, &view.current_dir).is_empty()
+        {
+            match view.current_dir.rfind('/') {
+                Some(pos) => view.current_dir.truncate(pos),
+                None => view.current_dir.clear(),
+            }
+        }
+        self.apply_archive_view_to_panel(&view);
+        self.archive_panel_view = Some(view);
+    }
+
     pub(super) fn copy_from_archive_view_to_dest(
         &mut self,
         view: &ArchivePanelView,
@@ -803,7 +1082,7 @@ impl App {
         }
 
         let (tx, _rx) = mpsc::channel();
-        let cancel = Arc::new(AtomicBool::new(false));
+        let control = Arc::new(ArchiveControl::default());
         let extract_result = extract_archive(
             &ArchiveExtractRequest {
                 archive_path: view.archive_path.clone(),
@@ -813,9 +1092,14 @@ impl App {
                 overwrite_entries: Vec::new(),
                 skip_existing_entries: Vec::new(),
                 skip_all_existing: false,
+                only_entries: selected_entries
+                    .iter()
+                    .map(|entry| entry.path.to_string_lossy().to_string())
+                    .collect(),
+                name_encoding: self.archive_name_encoding(&view.archive_path),
             },
             tx,
-            cancel,
+            control,
         );
         if let Err(err) = extract_result {
             let _ = std::fs::remove_dir_all(&temp_root);
@@ -863,7 +1147,11 @@ impl App {
         archive_path: &Path,
         password: Option<&str>,
     ) -> Result<()> {
-        let mut entries = list_entries(archive_path, password)?;
+        let mut entries = list_entries(
+            archive_path,
+            password,
+            self.archive_name_encoding(archive_path),
+        )?;
         let truncated = entries.len() > 5000;
         if truncated {
             entries.truncate(5000);
@@ -917,6 +1205,8 @@ impl App {
         let dest_dir = self.inactive_panel_state().current_path.clone();
         let dest_path = dest_dir.to_string_lossy().to_string();
 
+        let free_space = self.destination_space_estimate(operation_type, &sources, &dest_dir);
+
         // 대기 작업 저장
         self.pending_operation = Some(PendingOperation::new(
             operation_type,
@@ -927,14 +1217,23 @@ impl App {
         // 입력 다이얼로그 표시
         let title = operation_type.name();
         let prompt = format!("{} to:", title);
-        self.dialog = Some(DialogKind::operation_path_input(
-            title, prompt, dest_path, dest_dir,
-        ));
+        let mut dialog = DialogKind::operation_path_input(title, prompt, dest_path, dest_dir)
+            .with_preview()
+            .with_scope_filter("");
+        if operation_type == OperationType::Copy {
+            dialog = dialog.with_copy_options(self.copy_options);
+        }
+        if let Some(estimate) = free_space {
+            dialog = dialog.with_free_space(estimate);
+        }
+        self.dialog = Some(dialog);
         self.update_input_completion_state();
     }
 
     pub(super) fn home_dir() -> Option<PathBuf> {
-        env::var_os("HOME").map(PathBuf::from)
+        env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(PathBuf::from)
     }
 
     pub(super) fn split_path_input(value: &str) -> (&str, &str) {
@@ -1021,6 +1320,20 @@ impl App {
         value: &str,
         base_path: &Path,
     ) -> Vec<String> {
+        // Windows UNC: 공유 이름까지 입력했으면 공유 루트로 들어가는 후보 (공유 목록은 읽지 않음)
+        if cfg!(windows) {
+            if win_path::is_unc_server_only(value) {
+                return Vec::new();
+            }
+            if let Some(unc) = win_path::parse_unc(value).filter(|unc| unc.rest.is_empty()) {
+                let root = format!(r"\\{}\{}\", unc.server, unc.share);
+                return if Path::new(&root).is_dir() {
+                    vec![root]
+                } else {
+                    Vec::new()
+                };
+            }
+        }
         let (dir_path, display_prefix, partial) = self.input_parent_context(value, base_path);
 
         let mut candidates: Vec<String> = fs::read_dir(dir_path)
@@ -1064,147 +1377,132 @@ impl App {
         candidates
     }
 
-    pub(super) fn selected_input_completion(&self) -> Option<String> {
-        match &self.dialog {
-            Some(DialogKind::Input {
-                completion_candidates,
-                completion_index: Some(idx),
-                ..
-            }) => completion_candidates.get(*idx).cloned(),
+    /// 자동완성을 쓰는 입력 창의 (값, 커서, 추천) (경로 입력 / 새 디렉토리)
+    fn completion_input_mut(&mut self) -> Option<(&mut String, &mut usize, &mut PathCompletion)> {
+        match &mut self.dialog {
+            Some(
+                DialogKind::Input {
+                    value,
+                    cursor_pos,
+                    completion,
+                    ..
+                }
+                | DialogKind::MkdirInput {
+                    value,
+                    cursor_pos,
+                    completion,
+                    ..
+                },
+            ) => Some((value, cursor_pos, completion)),
             _ => None,
         }
     }
 
+    /// 선택 추천이 입력값과 달라 적용할 수 있으면 true (Tab을 추천 적용에 쓸지 판단)
+    pub fn has_input_completion_to_apply(&self) -> bool {
+        match &self.dialog {
+            Some(
+                DialogKind::Input {
+                    value, completion, ..
+                }
+                | DialogKind::MkdirInput {
+                    value, completion, ..
+                },
+            ) => completion
+                .selected()
+                .is_some_and(|candidate| candidate != value),
+            _ => false,
+        }
+    }
+
     pub(super) fn update_input_completion_state(&mut self) {
-        let (value, base_path, purpose, mask_input) = match &self.dialog {
+        let (value, base_path, use_completion) = match &self.dialog {
             Some(DialogKind::Input {
                 value,
                 base_path,
                 purpose,
                 mask_input,
                 ..
-            }) => (value.clone(), base_path.clone(), *purpose, *mask_input),
+            }) => (
+                value.clone(),
+                base_path.clone(),
+                !mask_input
+                    && match purpose {
+                        InputPurpose::ArchivePassword | InputPurpose::TerminalCommand => false,
+                        InputPurpose::Prompt(input) => input.path_completion,
+                        _ => true,
+                    },
+            ),
+            // 새 디렉토리: 현재 디렉토리 기준, 입력을 시작해야 추천 (압축/원격 패널 제외)
+            Some(DialogKind::MkdirInput {
+                value, parent_path, ..
+            }) => (
+                value.clone(),
+                parent_path.clone(),
+                !value.is_empty() && self.is_local_panel_view(PanelSlot::from(self.active_panel())),
+            ),
             _ => return,
         };
 
-        let use_completion = !mask_input
-            && !matches!(
-                purpose,
-                InputPurpose::ArchivePassword | InputPurpose::TerminalCommand
-            );
-        let completion_candidates = if use_completion {
-            self.collect_input_completion_candidates(&value, &base_path)
-        } else {
-            Vec::new()
-        };
-        let completion_index = if completion_candidates.is_empty() {
-            None
+        let completion = if use_completion && !is_remote_url(&value) {
+            PathCompletion::new(self.collect_input_completion_candidates(&value, &base_path))
         } else {
-            Some(0)
+            PathCompletion::default()
         };
-
-        if let Some(DialogKind::Input {
-            completion_candidates: candidates,
-            completion_index: selected_idx,
-            ..
-        }) = &mut self.dialog
-        {
-            *candidates = completion_candidates;
-            *selected_idx = completion_index;
+        if let Some((_, _, current)) = self.completion_input_mut() {
+            *current = completion;
         }
+        self.refresh_input_free_space();
     }
 
     /// 경로 입력 다이얼로그: 현재 선택 추천 적용
     pub fn dialog_input_apply_selected_completion(&mut self) {
-        let Some(candidate) = self.selected_input_completion() else {
+        let Some((value, cursor_pos, completion)) = self.completion_input_mut() else {
             return;
         };
-
-        if let Some(DialogKind::Input {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            if *value != candidate {
-                *value = candidate;
-                *cursor_pos = value.len();
-            }
+        let Some(candidate) = completion.selected() else {
+            return;
+        };
+        if value != candidate {
+            *value = candidate.to_string();
+            *cursor_pos = value.len();
         }
         self.update_input_completion_state();
     }
 
     /// 경로 입력 다이얼로그: 다음 추천으로 순환 + 즉시 적용
     pub fn dialog_input_cycle_completion_next(&mut self) {
-        let needs_seed = matches!(
-            &self.dialog,
-            Some(DialogKind::Input {
-                completion_candidates,
-                ..
-            }) if completion_candidates.is_empty()
-        );
-        if needs_seed {
-            self.update_input_completion_state();
-        }
-
-        if let Some(DialogKind::Input {
-            completion_candidates,
-            completion_index,
-            value,
-            cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            if completion_candidates.is_empty() {
-                return;
-            }
-            let next = completion_index
-                .map(|idx| (idx + 1) % completion_candidates.len())
-                .unwrap_or(0);
-            *completion_index = Some(next);
-            *value = completion_candidates[next].clone();
-            *cursor_pos = value.len();
-        }
+        self.cycle_input_completion(true);
     }
 
     /// 경로 입력 다이얼로그: 이전 추천으로 순환 + 즉시 적용
     pub fn dialog_input_cycle_completion_prev(&mut self) {
-        let needs_seed = matches!(
-            &self.dialog,
-            Some(DialogKind::Input {
-                completion_candidates,
-                ..
-            }) if completion_candidates.is_empty()
-        );
+        self.cycle_input_completion(false);
+    }
+
+    fn cycle_input_completion(&mut self, forward: bool) {
+        let needs_seed = self
+            .completion_input_mut()
+            .is_some_and(|(_, _, completion)| completion.is_empty());
         if needs_seed {
             self.update_input_completion_state();
         }
 
-        if let Some(DialogKind::Input {
-            completion_candidates,
-            completion_index,
-            value,
-            cursor_pos,
-            ..
-        }) = &mut self.dialog
-        {
-            if completion_candidates.is_empty() {
-                return;
+        if let Some((value, cursor_pos, completion)) = self.completion_input_mut() {
+            if let Some(candidate) = completion.cycle(forward) {
+                *value = candidate.to_string();
+                *cursor_pos = value.len();
             }
-            let prev = completion_index
-                .map(|idx| {
-                    if idx == 0 {
-                        completion_candidates.len() - 1
-                    } else {
-                        idx - 1
-                    }
-                })
-                .unwrap_or(0);
-            *completion_index = Some(prev);
-            *value = completion_candidates[prev].clone();
-            *cursor_pos = value.len();
         }
     }
 
     pub(super) fn resolve_input_path(&self, input: &str, base_path: &Path) -> PathBuf {
+        // Windows: `\\server\share`, `//server/share` UNC 경로
+        if cfg!(windows) {
+            if let Some(unc) = win_path::normalize_unc(input) {
+                return PathBuf::from(unc);
+            }
+        }
         let expanded = if input == "~" {
             Self::home_dir().unwrap_or_else(|| PathBuf::from(input))
         } else if let Some(rest) = input.strip_prefix("~/") {
@@ -1268,28 +1566,57 @@ impl App {
         pending: &mut PendingOperation,
         dest_path: &std::path::Path,
     ) {
-        let flattened: Vec<FlattenedFile> =
-            match self.filesystem.flatten_sources(&pending.sources, dest_path) {
-                Ok(files) => files,
-                Err(e) => {
-                    self.dialog = Some(DialogKind::error(
-                        "Error",
-                        format!("Failed to scan files: {}", e),
-                    ));
-                    return;
-                }
-            };
+        let (flattened, filtered_out) = match self.filesystem.flatten_sources_filtered(
+            &pending.sources,
+            dest_path,
+            &pending.scope_filter,
+            pending.symlink_policy(),
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                log::warn!("failed to scan operation sources: {}", e);
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    format!("Failed to scan files: {}", e),
+                ));
+                return;
+            }
+        };
 
         // 디렉토리는 size=0, 파일/링크는 size 누적
         let total_bytes: u64 = flattened.iter().map(|f| f.size).sum();
         let total_files = flattened.len();
 
+        // 모자라면 확인을 받고, 취소하면 대상 경로 입력 창으로 돌아감
+        if std::mem::take(&mut pending.check_free_space) {
+            if let Some(message) = self.free_space_shortfall(pending, dest_path, total_bytes) {
+                self.push_dialog(DialogKind::confirm("Not enough space", message));
+                self.on_dialog_accepted(Self::start_operation_despite_low_space);
+                return;
+            }
+        }
+
         if pending.operation_type == OperationType::Move {
             pending.set_move_cleanup_dirs(self.filesystem.collect_move_cleanup_dirs(&flattened));
         } else {
             pending.set_move_cleanup_dirs(Vec::new());
         }
 
+        if filtered_out > 0 {
+            self.set_toast(&format!("Filter skipped {} items", filtered_out));
+        }
+        pending.case_collisions = if self.filesystem.is_case_insensitive(dest_path) {
+            crate::models::operation::case_collisions(&flattened)
+        } else {
+            HashSet::new()
+        };
+        if !pending.case_collisions.is_empty() {
+            self.set_toast(&format!(
+                "Case-insensitive destination: {} collide by letter case only",
+                crate::utils::formatter::pluralize(pending.case_collisions.len(), "item", "items")
+            ));
+        }
+
         pending.set_flattened_files(flattened);
         pending.start_processing(total_bytes, total_files);
         self.dialog = Some(DialogKind::progress(pending.progress.clone()));
@@ -1318,53 +1645,86 @@ impl App {
             FlattenedEntryKind::Directory => std::fs::create_dir_all(&file_entry.dest)
                 .map(|_| 0)
                 .map_err(crate::utils::error::BokslDirError::Io),
+            FlattenedEntryKind::SymlinkFile | FlattenedEntryKind::SymlinkDirectory
+                if pending.operation_type == OperationType::Move =>
+            {
+                self.filesystem
+                    .move_symlink(&file_entry.source, &file_entry.dest)
+                    .map(|(bytes, strategy)| {
+                        if strategy == MoveStrategy::CopyDelete {
+                            pending.cross_device_moves += 1;
+                        }
+                        bytes
+                    })
+            }
+            FlattenedEntryKind::SymlinkFile | FlattenedEntryKind::SymlinkDirectory
+                if pending.copy_options.symlinks == SymlinkPolicy::Links =>
+            {
+                self.filesystem
+                    .copy_symlink(&file_entry.source, &file_entry.dest)
+            }
             FlattenedEntryKind::File | FlattenedEntryKind::SymlinkFile => {
                 match pending.operation_type {
                     OperationType::Copy => self
                         .filesystem
-                        .copy_file(&file_entry.source, &file_entry.dest),
+                        .copy_file_with_options(
+                            &file_entry.source,
+                            &file_entry.dest,
+                            &pending.copy_options,
+                        )
+                        .and_then(|bytes| self.verify_copied_file(pending, file_entry, bytes)),
                     OperationType::Move => self
                         .filesystem
-                        .move_file(&file_entry.source, &file_entry.dest),
+                        .move_file_with_strategy(&file_entry.source, &file_entry.dest)
+                        .map(|(bytes, strategy)| {
+                            if strategy == MoveStrategy::CopyDelete {
+                                pending.cross_device_moves += 1;
+                            }
+                            bytes
+                        }),
                     OperationType::Delete => unreachable!("Delete uses process_next_delete"),
                     OperationType::ArchiveCompress | OperationType::ArchiveExtract => {
                         unreachable!("Archive uses process_next_archive")
                     }
                 }
             }
+            // 링크를 따라갈 때 지나온 디렉토리를 가리키는 링크만 남음
             FlattenedEntryKind::SymlinkDirectory => {
-                let message = "Directory symlink is not supported for copy/move";
-                match pending.operation_type {
-                    OperationType::Copy => Err(crate::utils::error::BokslDirError::CopyFailed {
-                        src: file_entry.source.clone(),
-                        dest: file_entry.dest.clone(),
-                        reason: message.to_string(),
-                    }),
-                    OperationType::Move => Err(crate::utils::error::BokslDirError::MoveFailed {
-                        src: file_entry.source.clone(),
-                        dest: file_entry.dest.clone(),
-                        reason: message.to_string(),
-                    }),
-                    OperationType::Delete => unreachable!("Delete uses process_next_delete"),
-                    OperationType::ArchiveCompress | OperationType::ArchiveExtract => {
-                        unreachable!("Archive uses process_next_archive")
-                    }
-                }
+                Err(crate::utils::error::BokslDirError::CopyFailed {
+                    src: file_entry.source.clone(),
+                    dest: file_entry.dest.clone(),
+                    reason: "Directory symlink loops back into the copied tree".to_string(),
+                })
             }
         };
 
         match result {
             Ok(bytes) => pending.files_completed(bytes, 1),
             Err(e) => {
-                pending.add_error(format!("{}: {}", file_name, e));
-                pending.mark_item_failed();
-                pending.file_skipped();
+                if pending.defer_busy_item(&e, self.busy_retry_attempts) {
+                    return;
+                }
+                pending.fail_current_item(file_name, &e);
             }
         }
 
         pending.current_index += 1;
     }
 
+    /// 복사 검증 옵션이 켜져 있으면 원본/대상 해시 비교
+    fn verify_copied_file(
+        &self,
+        pending: &PendingOperation,
+        file_entry: &FlattenedFile,
+        bytes: u64,
+    ) -> Result<u64> {
+        if pending.copy_options.verify {
+            self.filesystem
+                .verify_copy(&file_entry.source, &file_entry.dest)?;
+        }
+        Ok(bytes)
+    }
+
     pub(super) fn resolve_conflict(
         &mut self,
         pending: &mut PendingOperation,
@@ -1374,9 +1734,12 @@ impl App {
         let skip_all = pending
             .conflict_resolution
             .is_some_and(|r| r == ConflictResolution::SkipAll);
-        let overwrite_all = pending
-            .conflict_resolution
-            .is_some_and(|r| r == ConflictResolution::OverwriteAll);
+        // 대소문자만 다른 앞선 항목을 덮어쓰는 경우는 항상 묻기
+        let overwrite_all = (!self.confirm_policy.overwrite
+            || pending
+                .conflict_resolution
+                .is_some_and(|r| r == ConflictResolution::OverwriteAll))
+            && !pending.case_collisions.contains(dest_path);
 
         if skip_all {
             pending.file_skipped();
@@ -1396,12 +1759,21 @@ impl App {
         true
     }
 
+    /// 선택한 최상위 디렉토리의 대상이 이미 있는 디렉토리인지 (안쪽 디렉토리는 그대로 합침)
+    fn is_directory_merge_conflict(pending: &PendingOperation, file_entry: &FlattenedFile) -> bool {
+        file_entry.entry_kind == FlattenedEntryKind::Directory
+            && file_entry.dest.parent() == Some(pending.dest_dir.as_path())
+            && file_entry.dest.is_dir()
+    }
+
     pub(super) fn should_resolve_conflict(file_entry: &FlattenedFile) -> bool {
         match file_entry.entry_kind {
             FlattenedEntryKind::Directory => file_entry.dest.exists() && !file_entry.dest.is_dir(),
-            FlattenedEntryKind::File
-            | FlattenedEntryKind::SymlinkFile
-            | FlattenedEntryKind::SymlinkDirectory => file_entry.dest.exists(),
+            FlattenedEntryKind::File => file_entry.dest.exists(),
+            // 링크를 링크로 만들 때 대상이 없는 링크가 있어도 충돌
+            FlattenedEntryKind::SymlinkFile | FlattenedEntryKind::SymlinkDirectory => {
+                std::fs::symlink_metadata(&file_entry.dest).is_ok()
+            }
         }
     }
 
@@ -1432,13 +1804,20 @@ impl App {
             return;
         };
 
-        if pending.state != OperationState::Processing {
+        if pending.state != OperationState::Processing || Self::is_waiting_busy_retry(&pending) {
             self.pending_operation = Some(pending);
             return;
         }
 
-        if pending.is_all_processed() {
-            self.finish_operation(pending);
+        if self.chunked_copy.is_some() {
+            self.continue_chunked_copy(&mut pending);
+            self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+            self.pending_operation = Some(pending);
+            return;
+        }
+
+        if pending.is_all_processed() {
+            self.finish_operation(pending);
             return;
         }
 
@@ -1452,7 +1831,7 @@ impl App {
             .to_string_lossy()
             .to_string();
 
-        pending.set_current_file(&file_name);
+        pending.set_current_file_with_size(&file_name, file_entry.size);
         self.dialog = Some(DialogKind::progress(pending.progress.clone()));
 
         if file_entry.entry_kind != FlattenedEntryKind::Directory && source == dest_path {
@@ -1464,6 +1843,43 @@ impl App {
             return;
         }
 
+        // 갱신 모드: 최신인 대상은 건너뛰고 오래된 대상은 묻지 않고 덮어쓰기
+        if pending.operation_type == OperationType::Copy
+            && pending.copy_options.update_only
+            && matches!(
+                file_entry.entry_kind,
+                FlattenedEntryKind::File | FlattenedEntryKind::SymlinkFile
+            )
+        {
+            match crate::system::filesystem::update_check(&source, &dest_path) {
+                crate::system::filesystem::UpdateCheck::UpToDate => {
+                    pending.unchanged_skipped += 1;
+                    pending.file_skipped();
+                    pending.current_index += 1;
+                    self.pending_operation = Some(pending);
+                    return;
+                }
+                crate::system::filesystem::UpdateCheck::Outdated
+                    if !pending.case_collisions.contains(&dest_path) =>
+                {
+                    Self::remove_existing_path(&dest_path);
+                }
+                _ => {}
+            }
+        }
+
+        // 선택한 디렉토리가 대상에 이미 있으면 합칠지/바꿀지/건너뛸지 먼저 묻기 (갱신 모드는 합침)
+        if self.confirm_policy.overwrite
+            && !pending.copy_options.update_only
+            && pending.conflict_resolution.is_none()
+            && Self::is_directory_merge_conflict(&pending, &file_entry)
+        {
+            pending.state = OperationState::WaitingConflict;
+            self.dialog = Some(DialogKind::directory_conflict(source, dest_path));
+            self.pending_operation = Some(pending);
+            return;
+        }
+
         if Self::should_resolve_conflict(&file_entry)
             && !self.resolve_conflict(&mut pending, &source, &dest_path)
         {
@@ -1471,16 +1887,230 @@ impl App {
             return;
         }
 
-        self.execute_single_file_operation(&mut pending, &file_entry, &file_name);
+        if !self.start_chunked_copy(&mut pending, &file_entry, &file_name) {
+            self.execute_single_file_operation(&mut pending, &file_entry, &file_name);
+        }
 
-        self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+        if !self.prompt_busy_item(&pending) {
+            self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+        }
         self.pending_operation = Some(pending);
     }
 
+    /// 대용량 파일이면 분할 복사 시작 (이동은 rename이 실패한 경우에만)
+    ///
+    /// 반환값: 분할 복사로 처리했으면 true (시작 실패도 에러로 기록하고 true)
+    fn start_chunked_copy(
+        &mut self,
+        pending: &mut PendingOperation,
+        file_entry: &FlattenedFile,
+        file_name: &str,
+    ) -> bool {
+        if file_entry.entry_kind != FlattenedEntryKind::File
+            || file_entry.size < CHUNKED_COPY_THRESHOLD
+        {
+            return false;
+        }
+        match pending.operation_type {
+            OperationType::Copy => {}
+            OperationType::Move => {
+                if let Some(parent) = file_entry.dest.parent() {
+                    let _ = std::fs::create_dir_all(parent);
+                }
+                match self
+                    .filesystem
+                    .try_rename(&file_entry.source, &file_entry.dest)
+                {
+                    Ok(true) => {
+                        pending.files_completed(file_entry.size, 1);
+                        pending.current_index += 1;
+                        return true;
+                    }
+                    // 다른 파일시스템: 아래 분할 복사 후 원본 삭제 (바이트 단위 진행률)
+                    Ok(false) => {}
+                    Err(e) => {
+                        if !pending.defer_busy_item(&e, self.busy_retry_attempts) {
+                            pending.fail_current_item(file_name, &e);
+                            pending.current_index += 1;
+                        }
+                        return true;
+                    }
+                }
+            }
+            _ => return false,
+        }
+
+        if let Some(parent) = file_entry.dest.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let options = match pending.operation_type {
+            OperationType::Move => CopyOptions::preserve_all(),
+            _ => pending.copy_options,
+        };
+        // 같은 파일시스템이면 reflink 복제로 데이터 복사 없이 완료
+        match self
+            .filesystem
+            .try_clone_file(&file_entry.source, &file_entry.dest, &options)
+            .and_then(|cloned| match cloned {
+                Some(bytes) => self
+                    .finish_copied_file(pending, &file_entry.source, &file_entry.dest, bytes)
+                    .map(Some),
+                None => Ok(None),
+            }) {
+            Ok(Some(bytes)) => {
+                if pending.operation_type == OperationType::Move {
+                    pending.cross_device_moves += 1;
+                }
+                pending.files_completed(bytes, 1);
+                pending.current_index += 1;
+                return true;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                if !pending.defer_busy_item(&e, self.busy_retry_attempts) {
+                    pending.fail_current_item(file_name, &e);
+                    pending.current_index += 1;
+                }
+                return true;
+            }
+        }
+        match ChunkedCopy::open(&file_entry.source, &file_entry.dest) {
+            Ok(copy) => {
+                pending.set_current_file_with_size(file_name, copy.total());
+                self.chunked_copy = Some(copy);
+            }
+            Err(e) => {
+                if !pending.defer_busy_item(&e, self.busy_retry_attempts) {
+                    pending.fail_current_item(file_name, &e);
+                    pending.current_index += 1;
+                }
+            }
+        }
+        true
+    }
+
+    /// 분할 복사 한 조각 진행, 완료 시 권한 복사(이동이면 원본 삭제)
+    fn continue_chunked_copy(&mut self, pending: &mut PendingOperation) {
+        let Some(copy) = self.chunked_copy.as_mut() else {
+            return;
+        };
+        let file_name = pending.progress.current_file.clone();
+        let result = match copy.step() {
+            Ok(0) => {
+                let copy = self.chunked_copy.take().unwrap_or_else(|| unreachable!());
+                let source = copy.src().to_path_buf();
+                let dest = copy.dest().to_path_buf();
+                let options = match pending.operation_type {
+                    OperationType::Move => CopyOptions::preserve_all(),
+                    _ => pending.copy_options,
+                };
+                copy.finish(&options)
+                    .and_then(|bytes| self.finish_copied_file(pending, &source, &dest, bytes))
+            }
+            Ok(bytes) => {
+                pending.progress.add_file_chunk(bytes);
+                return;
+            }
+            Err(e) => {
+                if let Some(copy) = self.chunked_copy.take() {
+                    copy.abort();
+                }
+                Err(e)
+            }
+        };
+
+        match result {
+            Ok(bytes) => {
+                if pending.operation_type == OperationType::Move {
+                    pending.cross_device_moves += 1;
+                }
+                pending.files_completed(bytes, 1)
+            }
+            Err(e) => {
+                pending.fail_current_item(&file_name, &e);
+            }
+        }
+        pending.current_index += 1;
+    }
+
+    /// 대용량 파일 복사 완료 후처리: 검증(복사) 또는 원본 삭제(이동)
+    fn finish_copied_file(
+        &self,
+        pending: &PendingOperation,
+        source: &Path,
+        dest: &Path,
+        bytes: u64,
+    ) -> crate::utils::error::Result<u64> {
+        if pending.operation_type == OperationType::Copy && pending.copy_options.verify {
+            self.filesystem.verify_copy(source, dest)?;
+        }
+        if pending.operation_type == OperationType::Move {
+            std::fs::remove_file(source).map_err(|e| {
+                crate::utils::error::BokslDirError::MoveFailed {
+                    src: source.to_path_buf(),
+                    dest: dest.to_path_buf(),
+                    reason: format!("Failed to remove source after copy: {}", e),
+                }
+            })?;
+        }
+        Ok(bytes)
+    }
+
+    /// 작업 일시정지/재개 (복사/이동/삭제/압축)
+    pub fn toggle_operation_pause(&mut self) {
+        if let Some(worker) = self.archive_worker.as_mut() {
+            let paused = !worker.control.is_paused();
+            worker.control.set_paused(paused);
+            if paused {
+                worker.progress.pause();
+            } else {
+                worker.progress.resume();
+            }
+            self.dialog = Some(DialogKind::progress(worker.progress.clone()));
+            return;
+        }
+
+        let Some(pending) = self.pending_operation.as_mut() else {
+            return;
+        };
+        match pending.state {
+            OperationState::Processing => {
+                pending.state = OperationState::Paused;
+                pending.progress.pause();
+            }
+            OperationState::Paused => {
+                pending.state = OperationState::Processing;
+                pending.progress.resume();
+            }
+            _ => return,
+        }
+        self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+    }
+
+    /// 작업이 일시정지 상태인지
+    pub fn is_operation_paused(&self) -> bool {
+        self.archive_worker
+            .as_ref()
+            .is_some_and(|w| w.control.is_paused())
+            || self
+                .pending_operation
+                .as_ref()
+                .is_some_and(|p| p.state == OperationState::Paused)
+    }
+
     /// 입력 다이얼로그에서 확인 처리
     pub fn confirm_input_dialog(&mut self, dest_path_str: String) {
+        // 결과를 받을 작업과 함께 연 입력 창이면 그 작업으로 전달
+        if self.dialog_continuation.is_some() {
+            self.finish_dialog(DialogOutcome::Text(dest_path_str));
+            return;
+        }
         let Some(DialogKind::Input {
-            purpose, base_path, ..
+            purpose,
+            base_path,
+            copy_options,
+            scope_filter,
+            ..
         }) = &self.dialog
         else {
             self.close_dialog();
@@ -1488,12 +2118,18 @@ impl App {
         };
 
         let purpose = *purpose;
+        let copy_options = *copy_options;
+        let scope_filter = scope_filter.as_deref().map(ScopeFilter::parse);
         let base_path = base_path.clone();
         let resolved_path = self.resolve_input_path(&dest_path_str, &base_path);
         let resolved_path_str = resolved_path.to_string_lossy().to_string();
 
         match purpose {
             InputPurpose::OperationDestination => {
+                if matches!(self.remote_flow, Some(RemoteFlowContext::Transfer { .. })) {
+                    self.confirm_remote_transfer(&dest_path_str, &base_path);
+                    return;
+                }
                 if let Some(mut pending) = self.pending_operation.take() {
                     if let Err(error_msg) = Self::validate_operation_destination(
                         &pending.sources,
@@ -1507,6 +2143,13 @@ impl App {
                     }
 
                     pending.dest_dir = resolved_path.clone();
+                    if let Some(options) = copy_options {
+                        pending.copy_options = options;
+                    }
+                    if let Some(filter) = scope_filter {
+                        pending.scope_filter = filter;
+                    }
+                    pending.check_free_space = true;
                     self.prepare_and_start_operation(&mut pending, &resolved_path);
                     self.pending_operation = Some(pending);
                     return;
@@ -1533,6 +2176,10 @@ impl App {
                 self.close_dialog();
             }
             InputPurpose::GoToPath => {
+                if is_remote_url(&dest_path_str) {
+                    self.confirm_remote_connect_input(&dest_path_str);
+                    return;
+                }
                 if let Err(error_msg) =
                     Self::validate_existing_directory_path(&resolved_path, &resolved_path_str)
                 {
@@ -1574,6 +2221,8 @@ impl App {
                     sources,
                     output_path: resolved_path.clone(),
                     password: None,
+                    level: CompressionLevel::default(),
+                    threads: resolve_compression_threads(self.compression_threads),
                 };
                 if supports_password(format) {
                     self.dialog = Some(DialogKind::error(
@@ -1593,6 +2242,8 @@ impl App {
                 let ArchiveFlowContext::ExtractPending {
                     archive_path,
                     format,
+                    only_entries,
+                    password,
                 } = flow
                 else {
                     self.close_dialog();
@@ -1606,17 +2257,21 @@ impl App {
                     return;
                 }
 
+                let has_password = password.is_some();
+                let name_encoding = self.archive_name_encoding(&archive_path);
                 let request = ArchiveExtractRequest {
                     archive_path,
                     dest_dir: resolved_path.clone(),
-                    password: None,
+                    password,
                     overwrite_existing: false,
                     overwrite_entries: Vec::new(),
                     skip_existing_entries: Vec::new(),
                     skip_all_existing: false,
+                    only_entries,
+                    name_encoding,
                 };
-                if supports_password(format) {
-                    match list_entries(&request.archive_path, None) {
+                if supports_password(format) && !has_password {
+                    match list_entries(&request.archive_path, None, name_encoding) {
                         Ok(_) => {
                             self.prepare_archive_extract_request(request);
                         }
@@ -1646,13 +2301,15 @@ impl App {
             InputPurpose::ArchivePassword => {
                 self.confirm_archive_password_input(dest_path_str);
             }
+            // 결과를 받을 작업과 함께 열므로 위에서 전달
+            InputPurpose::Prompt(_) => self.close_dialog(),
             InputPurpose::TerminalCommand => {
                 let command = dest_path_str.trim().to_string();
                 self.close_dialog();
                 if command.is_empty() {
                     return;
                 }
-                self.pending_terminal_command_request = Some(TerminalCommandRequest {
+                self.request_terminal_command(TerminalCommandRequest {
                     command,
                     working_dir: base_path,
                 });
@@ -1662,17 +2319,21 @@ impl App {
 
     pub(super) fn start_archive_create_worker(&mut self, request: ArchiveCreateRequest) {
         let (progress_tx, progress_rx) = mpsc::channel::<ArchiveProgressEvent>();
-        let cancel_flag = Arc::new(AtomicBool::new(false));
-        let cancel_for_worker = Arc::clone(&cancel_flag);
-        let handle =
-            std::thread::spawn(move || create_archive(&request, progress_tx, cancel_for_worker));
+        let control = Arc::new(ArchiveControl::default());
+        let control_for_worker = Arc::clone(&control);
+        let waker = self.waker.clone();
+        let handle = std::thread::spawn(move || {
+            let result = create_archive(&request, progress_tx, control_for_worker);
+            waker.wake();
+            result
+        });
 
         let progress = OperationProgress::new(OperationType::ArchiveCompress, 0, 0);
         self.archive_worker = Some(ArchiveWorkerState {
             kind: ArchiveWorkerKind::Compress,
             progress_rx,
             join_handle: Some(handle),
-            cancel_flag,
+            control,
             progress: progress.clone(),
         });
         self.dialog = Some(DialogKind::progress(progress));
@@ -1680,17 +2341,43 @@ impl App {
 
     pub(super) fn start_archive_extract_worker(&mut self, request: ArchiveExtractRequest) {
         let (progress_tx, progress_rx) = mpsc::channel::<ArchiveProgressEvent>();
-        let cancel_flag = Arc::new(AtomicBool::new(false));
-        let cancel_for_worker = Arc::clone(&cancel_flag);
-        let handle =
-            std::thread::spawn(move || extract_archive(&request, progress_tx, cancel_for_worker));
+        let control = Arc::new(ArchiveControl::default());
+        let control_for_worker = Arc::clone(&control);
+        let waker = self.waker.clone();
+        let handle = std::thread::spawn(move || {
+            let result = extract_archive(&request, progress_tx, control_for_worker);
+            waker.wake();
+            result
+        });
 
         let progress = OperationProgress::new(OperationType::ArchiveExtract, 0, 0);
         self.archive_worker = Some(ArchiveWorkerState {
             kind: ArchiveWorkerKind::Extract,
             progress_rx,
             join_handle: Some(handle),
-            cancel_flag,
+            control,
+            progress: progress.clone(),
+        });
+        self.dialog = Some(DialogKind::progress(progress));
+    }
+
+    pub(super) fn start_archive_delete_worker(&mut self, request: ArchiveDeleteRequest) {
+        let (progress_tx, progress_rx) = mpsc::channel::<ArchiveProgressEvent>();
+        let control = Arc::new(ArchiveControl::default());
+        let control_for_worker = Arc::clone(&control);
+        let waker = self.waker.clone();
+        let handle = std::thread::spawn(move || {
+            let result = delete_entries(&request, progress_tx, control_for_worker);
+            waker.wake();
+            result
+        });
+
+        let progress = OperationProgress::new(OperationType::Delete, 0, 0);
+        self.archive_worker = Some(ArchiveWorkerState {
+            kind: ArchiveWorkerKind::Delete,
+            progress_rx,
+            join_handle: Some(handle),
+            control,
             progress: progress.clone(),
         });
         self.dialog = Some(DialogKind::progress(progress));
@@ -1706,6 +2393,21 @@ impl App {
             self.close_dialog();
             return;
         };
+        let archive_path = match &flow {
+            ArchiveFlowContext::ExtractNeedsPassword { request }
+            | ArchiveFlowContext::ExtractConflictPrompt { request, .. } => {
+                Some(request.archive_path.clone())
+            }
+            ArchiveFlowContext::ExtractAutoNeedsPassword { archive_path, .. }
+            | ArchiveFlowContext::PreviewNeedsPassword { archive_path, .. } => {
+                Some(archive_path.clone())
+            }
+            _ => None,
+        };
+        // 이 세션 동안 기억 (다음 단계에서 비밀번호 오류가 나면 다시 잊음)
+        if let (Some(path), Some(password)) = (&archive_path, &password) {
+            self.archive_passwords.remember(path, password);
+        }
 
         match flow {
             ArchiveFlowContext::ExtractNeedsPassword { mut request } => {
@@ -1723,9 +2425,11 @@ impl App {
                 &archive_path,
                 &base_dir,
                 password.as_deref(),
+                self.archive_name_encoding(&archive_path),
             ) {
                 Ok(request) => self.prepare_archive_extract_request(request),
                 Err(err) => {
+                    self.archive_passwords.forget(&archive_path);
                     self.archive_flow = None;
                     self.dialog = Some(DialogKind::error(
                         "Error",
@@ -1749,6 +2453,7 @@ impl App {
                 match self.enter_archive_panel_view(&archive_path, password.as_deref()) {
                     Ok(()) => {}
                     Err(err) => {
+                        self.archive_passwords.forget(&archive_path);
                         self.dialog = Some(DialogKind::error(
                             "Error",
                             Self::format_user_error(
@@ -1777,7 +2482,7 @@ impl App {
             worker.progress.current_file = event.current_file;
             worker.progress.files_completed = event.files_completed;
             worker.progress.total_files = event.total_files;
-            worker.progress.bytes_copied = event.bytes_processed;
+            worker.progress.set_bytes_copied(event.bytes_processed);
             worker.progress.total_bytes = event.total_bytes;
             worker.progress.items_processed = event.items_processed;
             worker.progress.items_failed = event.items_failed;
@@ -1797,6 +2502,7 @@ impl App {
             return;
         };
         let kind = worker.kind;
+        let duration = worker.progress.active_elapsed();
         let result =
             handle.join().map_err(
                 |_| crate::utils::error::BokslDirError::ArchiveCreateFailed {
@@ -1804,26 +2510,68 @@ impl App {
                     reason: "Archive worker thread panicked".to_string(),
                 },
             );
-        self.finish_archive_operation(kind, result);
+        self.finish_archive_operation(kind, duration, result);
     }
 
     pub(super) fn finish_archive_operation(
         &mut self,
         kind: ArchiveWorkerKind,
+        duration: Duration,
         join_result: std::result::Result<
             std::result::Result<ArchiveSummary, crate::utils::error::BokslDirError>,
             crate::utils::error::BokslDirError,
         >,
     ) {
         self.refresh_both_panels();
+        if kind == ArchiveWorkerKind::Delete {
+            self.reload_archive_view();
+        }
         self.active_panel_state_mut().deselect_all();
         self.dialog = None;
 
         let operation_name = match kind {
             ArchiveWorkerKind::Compress => "Archive create",
             ArchiveWorkerKind::Extract => "Archive extract",
+            ArchiveWorkerKind::Delete => "Archive delete",
+            ArchiveWorkerKind::Download => "Download",
+            ArchiveWorkerKind::Upload => "Upload",
         };
 
+        match &join_result {
+            Ok(Ok(summary)) => {
+                let status = if summary.cancelled {
+                    JobStatus::Cancelled
+                } else if summary.errors.is_empty() {
+                    JobStatus::Succeeded
+                } else {
+                    JobStatus::CompletedWithErrors
+                };
+                self.record_job(
+                    operation_name,
+                    format!(
+                        "{}/{}",
+                        summary.items_processed.saturating_sub(summary.items_failed),
+                        crate::utils::formatter::pluralize(summary.total_files, "item", "items")
+                    ),
+                    status,
+                    duration,
+                    summary.errors.clone(),
+                );
+            }
+            Ok(Err(err)) | Err(err) => {
+                if let BokslDirError::ArchiveInvalidPassword { path, .. } = err {
+                    self.archive_passwords.forget(path);
+                }
+                self.record_job(
+                    operation_name,
+                    String::new(),
+                    JobStatus::Failed,
+                    duration,
+                    vec![err.to_string()],
+                );
+            }
+        }
+
         match join_result {
             Ok(Ok(summary)) => {
                 if summary.cancelled {
@@ -1885,20 +2633,49 @@ impl App {
 
     /// 작업 완료 처리
     pub(super) fn finish_operation(&mut self, mut pending: PendingOperation) {
+        // 압축 파일에서 꺼낸 임시 원본은 아래에서 지우므로 다시 시도할 수 없음
+        let retry = if self.archive_copy_temp_dir.is_none() {
+            pending.retry_failed()
+        } else {
+            None
+        };
         self.cleanup_moved_directories(&mut pending);
         self.cleanup_archive_copy_temp_dir();
 
         // 패널 새로고침
         self.refresh_both_panels();
 
+        self.record_job(
+            pending.operation_type.name(),
+            format!(
+                "{}{}",
+                crate::utils::formatter::pluralize(pending.completed_count, "file", "files"),
+                Self::update_summary(&pending)
+            ),
+            if pending.errors.is_empty() {
+                JobStatus::Succeeded
+            } else {
+                JobStatus::CompletedWithErrors
+            },
+            pending.progress.active_elapsed(),
+            pending.errors.clone(),
+        );
+
         // 결과 표시
         if pending.errors.is_empty() {
             self.close_dialog();
             self.set_toast(&format!(
-                "{} completed: {}",
+                "{} completed: {}{}{}",
                 pending.operation_type.name(),
-                crate::utils::formatter::pluralize(pending.completed_count, "file", "files")
+                crate::utils::formatter::pluralize(pending.completed_count, "file", "files"),
+                Self::move_strategy_summary(&pending),
+                Self::update_summary(&pending)
             ));
+        } else if self.offer_elevated_retry(
+            pending.operation_type.name(),
+            std::mem::take(&mut pending.denied),
+        ) {
+            // 권한 부족 항목은 확인 후 sudo로 다시 실행 (나머지 오류는 작업 목록에 기록됨)
         } else {
             let preview: Vec<String> = pending.errors.iter().take(5).cloned().collect();
             let detail = if pending.errors.len() > 5 {
@@ -1911,19 +2688,77 @@ impl App {
                 preview.join("\n")
             };
             let error_msg = format!(
-                "{} completed with errors.\nSucceeded: {}\nFailed: {}\n\n{}",
+                "{} completed with errors.\nSucceeded: {}\nFailed: {}\n\n{}\n\n{}",
                 pending.operation_type.name(),
                 pending.completed_count,
                 pending.errors.len(),
-                detail
+                detail,
+                error_report::ERROR_REPORT_HINT
             );
-            self.dialog = Some(DialogKind::error("Error", error_msg));
+            self.keep_error_report(&pending);
+            match retry {
+                Some(retry) => {
+                    // 권한 등을 고친 뒤 OK로 실패한 항목만 다시 실행
+                    self.dialog = Some(DialogKind::confirm(
+                        "Retry failed",
+                        format!(
+                            "{}\n\nRetry {} with the same destination and options?",
+                            error_msg,
+                            crate::utils::formatter::pluralize(retry.item_count(), "item", "items")
+                        ),
+                    ));
+                    self.on_dialog_accepted(|app| app.retry_failed_operation(retry));
+                }
+                None => self.dialog = Some(DialogKind::error("Error", error_msg)),
+            }
         }
 
         // 선택 상태 초기화
         self.active_panel_state_mut().deselect_all();
     }
 
+    /// 실패 항목 재시도/휴지통 대신 영구 삭제 시작 (Progress 다이얼로그 표시 + Processing 시작)
+    pub(super) fn retry_failed_operation(&mut self, mut pending: PendingOperation) {
+        let (total_bytes, total_files) = if pending.operation_type == OperationType::Delete {
+            let (total_bytes, _) = self
+                .filesystem
+                .calculate_total_size(&pending.sources)
+                .unwrap_or((0, 0));
+            (total_bytes, pending.sources.len())
+        } else {
+            (
+                pending.flattened_files.iter().map(|f| f.size).sum(),
+                pending.flattened_files.len(),
+            )
+        };
+        pending.start_processing(total_bytes, total_files);
+        self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+        self.pending_operation = Some(pending);
+    }
+
+    /// 이동 완료 토스트에 붙일 이동 방식 요약
+    fn move_strategy_summary(pending: &PendingOperation) -> String {
+        if pending.operation_type != OperationType::Move || pending.completed_count == 0 {
+            return String::new();
+        }
+        if pending.cross_device_moves == 0 {
+            " (rename)".to_string()
+        } else {
+            format!(
+                " (copy + delete across filesystems: {})",
+                pending.cross_device_moves
+            )
+        }
+    }
+
+    /// 갱신 모드 완료 요약 (최신이라 건너뛴 파일 수)
+    fn update_summary(pending: &PendingOperation) -> String {
+        if !pending.copy_options.update_only {
+            return String::new();
+        }
+        format!(" (unchanged skipped: {})", pending.unchanged_skipped)
+    }
+
     /// 파일 작업 실행 (레거시 호환용 - 충돌 해결 후 재개)
     pub fn execute_file_operation(&mut self) {
         if let Some(pending) = self.pending_operation.as_mut() {
@@ -1943,6 +2778,32 @@ impl App {
         }
     }
 
+    /// 충돌 항목 건너뛰기 (디렉토리 충돌이면 그 아래 항목까지)
+    fn skip_current_conflict(&mut self, directory: bool) {
+        if !directory {
+            self.skip_current_file();
+        } else if let Some(pending) = self.pending_operation.as_mut() {
+            pending.skip_current_tree();
+        }
+    }
+
+    /// 이미 있는 대상 디렉토리에 합치기 (디렉토리 항목만 처리하고 안의 항목은 평소대로 진행)
+    fn merge_current_directory(&mut self) {
+        let Some(mut pending) = self.pending_operation.take() else {
+            return;
+        };
+        if let Some(file_entry) = pending.flattened_files.get(pending.current_index).cloned() {
+            let file_name = file_entry
+                .source
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            self.execute_single_file_operation(&mut pending, &file_entry, &file_name);
+        }
+        self.pending_operation = Some(pending);
+    }
+
     /// 현재 파일 건너뛰기 + 인덱스 증가 (Skip/SkipAll 공용)
     pub(super) fn skip_current_file(&mut self) {
         if let Some(pending) = self.pending_operation.as_mut() {
@@ -1964,7 +2825,8 @@ impl App {
                 ConflictResolution::Cancel => {
                     self.close_dialog();
                 }
-                ConflictResolution::Overwrite => {
+                // 압축 해제 충돌은 파일 단위라 Merge가 나오지 않음
+                ConflictResolution::Overwrite | ConflictResolution::Merge => {
                     if let Some(path) = current_path {
                         request.overwrite_entries.push(path);
                     }
@@ -1998,6 +2860,15 @@ impl App {
             return;
         }
 
+        // 디렉토리 충돌: Overwrite는 대상 디렉토리를 통째로 바꾸고, Skip은 그 아래를 모두
+        // 건너뛰며, Overwrite All은 합친 뒤 안의 충돌 파일을 모두 덮어씀
+        let directory = matches!(
+            self.dialog,
+            Some(DialogKind::Conflict {
+                directory: true,
+                ..
+            })
+        );
         match resolution {
             ConflictResolution::Cancel => {
                 if let Some(pending) = self.pending_operation.take() {
@@ -2006,23 +2877,31 @@ impl App {
                     self.close_dialog();
                 }
             }
+            ConflictResolution::Merge => {
+                self.merge_current_directory();
+                self.execute_file_operation();
+            }
             ConflictResolution::Overwrite => {
                 self.remove_existing_dest();
                 self.execute_file_operation();
             }
             ConflictResolution::Skip => {
-                self.skip_current_file();
+                self.skip_current_conflict(directory);
                 self.execute_file_operation();
             }
             ConflictResolution::OverwriteAll => {
-                self.remove_existing_dest();
+                if directory {
+                    self.merge_current_directory();
+                } else {
+                    self.remove_existing_dest();
+                }
                 if let Some(pending) = self.pending_operation.as_mut() {
                     pending.conflict_resolution = Some(ConflictResolution::OverwriteAll);
                 }
                 self.execute_file_operation();
             }
             ConflictResolution::SkipAll => {
-                self.skip_current_file();
+                self.skip_current_conflict(directory);
                 if let Some(pending) = self.pending_operation.as_mut() {
                     pending.conflict_resolution = Some(ConflictResolution::SkipAll);
                 }
@@ -2034,6 +2913,13 @@ impl App {
     // === 파일 삭제 관련 메서드 (Phase 3.3) ===
 
     pub(in crate::app) fn prepare_delete_pending_dialog(&mut self, selected_button: usize) {
+        if self.is_active_panel_archive_view() {
+            self.prepare_archive_entry_delete();
+            return;
+        }
+        if self.prepare_mounted_delete_dialog(selected_button) {
+            return;
+        }
         let sources = self.get_operation_sources();
 
         if sources.is_empty() {
@@ -2096,32 +2982,15 @@ impl App {
             self.close_dialog();
             return;
         };
+        if let Some(backend) = self.active_panel_state().backend.clone() {
+            self.confirm_mounted_delete(backend.as_ref(), &pending.sources, use_trash);
+            return;
+        }
 
         if use_trash {
             // 휴지통으로 이동: 한 번에 처리
-            match self.filesystem.trash_items(&pending.sources) {
-                Ok(()) => {
-                    self.refresh_both_panels();
-                    self.active_panel_state_mut().deselect_all();
-                    self.dialog = None;
-                    self.set_toast(&format!(
-                        "Moved {} to trash.",
-                        crate::utils::formatter::pluralize(pending.sources.len(), "item", "items")
-                    ));
-                }
-                Err(e) => {
-                    self.refresh_both_panels();
-                    self.dialog = Some(DialogKind::error(
-                        "Error",
-                        Self::format_user_error(
-                            "Move to trash",
-                            pending.sources.first().map(|p| p.as_path()),
-                            &e.to_string(),
-                            "Check permissions and available disk space.",
-                        ),
-                    ));
-                }
-            }
+            let failures = self.filesystem.trash_items_each(&pending.sources);
+            self.finish_trash(&pending.sources, failures);
         } else {
             // 영구 삭제: Progress 다이얼로그 표시 + Processing 시작
             let total_bytes = pending.progress.total_bytes;
@@ -2132,6 +3001,50 @@ impl App {
         }
     }
 
+    /// 휴지통 이동 결과 반영 (옮기지 못한 항목이 있으면 대신 영구 삭제할지 묻기)
+    pub(super) fn finish_trash(&mut self, sources: &[PathBuf], failures: Vec<(PathBuf, String)>) {
+        use crate::utils::formatter::pluralize;
+        const MAX_UNTRASHED_LISTED: usize = 5;
+
+        self.refresh_both_panels();
+        if failures.is_empty() {
+            self.active_panel_state_mut().deselect_all();
+            self.dialog = None;
+            self.set_toast(&format!(
+                "Moved {} to trash.",
+                pluralize(sources.len(), "item", "items")
+            ));
+            return;
+        }
+
+        let trashed = sources.len().saturating_sub(failures.len());
+        let mut message = format!(
+            "Moved {} to trash.\nCould not move {} to trash:",
+            pluralize(trashed, "item", "items"),
+            pluralize(failures.len(), "item", "items")
+        );
+        for (path, reason) in failures.iter().take(MAX_UNTRASHED_LISTED) {
+            let name = path.file_name().unwrap_or(path.as_os_str());
+            message.push_str(&format!("\n  {}: {}", name.to_string_lossy(), reason));
+        }
+        if failures.len() > MAX_UNTRASHED_LISTED {
+            message.push_str(&format!(
+                "\n  ... and {} more",
+                failures.len() - MAX_UNTRASHED_LISTED
+            ));
+        }
+        message.push_str("\n\nPermanently delete them instead? (No leaves them in place)");
+        let paths = failures.into_iter().map(|(path, _)| path).collect();
+        self.dialog = Some(DialogKind::confirm("Trash failed", message));
+        self.on_dialog_accepted(|app| {
+            app.retry_failed_operation(PendingOperation::new(
+                OperationType::Delete,
+                paths,
+                PathBuf::new(),
+            ));
+        });
+    }
+
     /// 파일/디렉토리 삭제 실행 + 결과 기록
     pub(super) fn execute_single_delete(
         &self,
@@ -2148,9 +3061,10 @@ impl App {
         match result {
             Ok(bytes) => pending.files_completed(bytes, 1),
             Err(e) => {
-                pending.add_error(format!("{}: {}", file_name, e));
-                pending.mark_item_failed();
-                pending.file_skipped();
+                if pending.defer_busy_item(&e, self.busy_retry_attempts) {
+                    return;
+                }
+                pending.fail_current_item(file_name, &e);
             }
         }
 
@@ -2164,7 +3078,7 @@ impl App {
             return;
         };
 
-        if pending.state != OperationState::Processing {
+        if pending.state != OperationState::Processing || Self::is_waiting_busy_retry(&pending) {
             self.pending_operation = Some(pending);
             return;
         }
@@ -2186,7 +3100,9 @@ impl App {
 
         self.execute_single_delete(&mut pending, &source, &file_name);
 
-        self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+        if !self.prompt_busy_item(&pending) {
+            self.dialog = Some(DialogKind::progress(pending.progress.clone()));
+        }
         self.pending_operation = Some(pending);
     }
 
@@ -2245,14 +3161,36 @@ impl App {
     /// 새 디렉토리 생성 시작 (a)
     pub fn start_mkdir(&mut self) {
         let parent_path = self.active_panel_state().current_path.clone();
-        self.dialog = Some(DialogKind::mkdir_input(parent_path));
+        self.dialog = Some(DialogKind::mkdir_input(
+            parent_path,
+            self.mkdir_enter_created,
+        ));
+    }
+
+    /// 새 디렉토리 입력 창: 만든 뒤 들어가기 전환 (Ctrl+E, 다음에 열 때도 유지)
+    pub fn dialog_mkdir_toggle_enter_created(&mut self) {
+        if let Some(DialogKind::MkdirInput { enter_created, .. }) = &mut self.dialog {
+            *enter_created = !*enter_created;
+            self.mkdir_enter_created = *enter_created;
+        }
     }
 
     /// 새 디렉토리 생성 확인
+    ///
+    /// 날짜 자리표시자(`{yyyy}-{mm}-{dd}` 등)를 펼치고, `a/b/c`처럼 쓰면 없는 중간 디렉토리도
+    /// 함께 만듭니다.
     pub fn confirm_mkdir(&mut self, dir_name: String, parent_path: PathBuf) {
-        let dir_name = dir_name.trim().to_string();
+        let dir_name = crate::utils::file_name::expand_date_template(
+            dir_name.trim(),
+            &chrono::Local::now().naive_local(),
+        );
+        let components: Vec<&str> = dir_name
+            .split(std::path::is_separator)
+            .map(str::trim)
+            .filter(|component| !component.is_empty())
+            .collect();
 
-        if dir_name.is_empty() {
+        if components.is_empty() {
             self.dialog = Some(DialogKind::error(
                 "Error",
                 "Create directory failed.\nReason: Name cannot be empty.\nHint: Enter at least one character.",
@@ -2260,16 +3198,60 @@ impl App {
             return;
         }
 
-        let new_path = parent_path.join(&dir_name);
-
-        match self.filesystem.create_directory(&new_path) {
-            Ok(()) => {
-                self.refresh_both_panels();
-                self.focus_active_entry_by_name(&dir_name);
-                self.dialog = None;
-                self.set_toast(&format!("Directory '{}' created.", dir_name));
+        let mut new_path = parent_path.clone();
+        for (index, component) in components.iter().enumerate() {
+            let target = NameTarget::NewDirectory {
+                parent: new_path.clone(),
+            };
+            // 한 단계 이름만 고친 이름을 제안 (중간 경로는 고칠 곳이 모호하므로 오류로)
+            if components.len() == 1 {
+                if !self.check_new_name(component, &new_path, target) {
+                    return;
+                }
+            } else if let Err(problem) = self.validate_mkdir_component(component, &new_path) {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Name check",
+                        Some(&new_path.join(component)),
+                        &problem,
+                        "Fix that part of the path.",
+                    ),
+                ));
+                return;
+            }
+            if index + 1 < components.len() && new_path.join(component).is_file() {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Create directory",
+                        Some(&new_path.join(component)),
+                        "A file with this name already exists",
+                        "Choose a different path.",
+                    ),
+                ));
+                return;
+            }
+            new_path.push(component);
+        }
+
+        let display_name = components.join("/");
+        if let Some(backend) = self.active_panel_state().backend.clone() {
+            if self.confirm_mounted_mkdir(backend.as_ref(), &parent_path, &new_path) {
+                self.finish_mkdir(components[0], &display_name, new_path);
             }
+            return;
+        }
+
+        match self.filesystem.create_directory(&new_path) {
+            Ok(()) => self.finish_mkdir(components[0], &display_name, new_path),
             Err(e) => {
+                let op = ElevatedOp::CreateDir {
+                    path: new_path.clone(),
+                };
+                if self.offer_elevated_retry_for("Create directory", &e, op) {
+                    return;
+                }
                 self.dialog = Some(DialogKind::error(
                     "Error",
                     Self::format_user_error(
@@ -2283,6 +3265,32 @@ impl App {
         }
     }
 
+    /// 중간 경로 이름 하나 검사 (대상 파일시스템 규칙)
+    fn validate_mkdir_component(
+        &self,
+        name: &str,
+        parent: &Path,
+    ) -> std::result::Result<(), String> {
+        use crate::utils::file_name::{self, NameRules};
+
+        let rules = if self.active_panel_state().backend.is_some() {
+            NameRules::Unix
+        } else {
+            NameRules::for_filesystem(diskspace::filesystem_type(parent).as_deref())
+        };
+        file_name::validate(name, parent, rules).map_err(|problem| problem.to_string())
+    }
+
+    /// 만든 뒤 목록 갱신 (들어가기를 켰으면 만든 디렉토리로, 아니면 첫 단계에 커서)
+    fn finish_mkdir(&mut self, first_name: &str, display_name: &str, new_path: PathBuf) {
+        self.refresh_both_panels();
+        self.dialog = None;
+        if !self.mkdir_enter_created || !self.change_active_dir(new_path, true, None) {
+            self.focus_active_entry_by_name(first_name);
+        }
+        self.set_toast(&format!("Directory '{}' created.", display_name));
+    }
+
     /// 이름 변경 시작 (r)
     pub fn start_rename(&mut self) {
         let panel = self.active_panel_state();
@@ -2385,6 +3393,43 @@ impl App {
         self.apply_open_default_app_result(&target_path, result);
     }
 
+    /// OS 파일 관리자에서 포커스 항목 표시 (gr, '..'이면 현재 디렉토리)
+    pub fn start_reveal_in_file_manager(&mut self) {
+        let panel = self.active_panel_state();
+        if panel.is_mounted() {
+            self.set_toast("Not available on remote panel");
+            return;
+        }
+        // 압축 패널에서는 압축 파일 자체를 표시
+        let target_path = match self.archive_panel_view.as_ref() {
+            Some(view) if self.is_active_panel_archive_view() => view.archive_path.clone(),
+            _ => panel
+                .selected_entry()
+                .map(|entry| entry.path.clone())
+                .unwrap_or_else(|| panel.current_path.clone()),
+        };
+        match self.filesystem.reveal_in_file_manager(&target_path) {
+            Ok(()) => {
+                let display_name = target_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| target_path.to_string_lossy().to_string());
+                self.set_toast(&format!("Revealed: {}", display_name));
+            }
+            Err(e) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Reveal in file manager",
+                        Some(&target_path),
+                        &e.to_string(),
+                        "Check that a desktop file manager is available.",
+                    ),
+                ));
+            }
+        }
+    }
+
     pub(super) fn focused_terminal_editor_target(&self) -> std::result::Result<PathBuf, String> {
         let panel = self.active_panel_state();
         let has_parent = panel.current_path.parent().is_some();
@@ -2429,10 +3474,10 @@ impl App {
             }
         };
 
-        self.pending_terminal_editor_request = Some(TerminalEditorRequest {
+        self.send_command(Command::EditFile(TerminalEditorRequest {
             editor_command: self.default_terminal_editor.clone(),
             target_path,
-        });
+        }));
     }
 
     /// 터미널 명령 실행 입력 다이얼로그 열기 (:)
@@ -2442,19 +3487,14 @@ impl App {
         self.update_input_completion_state();
     }
 
-    pub fn take_pending_terminal_editor_request(&mut self) -> Option<TerminalEditorRequest> {
-        self.pending_terminal_editor_request.take()
-    }
-
-    pub fn take_pending_terminal_command_request(&mut self) -> Option<TerminalCommandRequest> {
-        self.pending_terminal_command_request.take()
-    }
-
-    pub fn apply_terminal_editor_result(
+    pub(super) fn apply_terminal_editor_result(
         &mut self,
         request: &TerminalEditorRequest,
         result: std::result::Result<(), String>,
     ) {
+        if self.finish_bulk_rename(request, &result) {
+            return;
+        }
         match result {
             Ok(()) => {
                 let display_name = request
@@ -2480,7 +3520,7 @@ impl App {
         }
     }
 
-    pub fn apply_terminal_command_result(
+    pub(super) fn apply_terminal_command_result(
         &mut self,
         request: &TerminalCommandRequest,
         result: std::result::Result<(), String>,
@@ -2536,6 +3576,22 @@ impl App {
             return;
         }
 
+        let parent = original_path
+            .parent()
+            .unwrap_or(Path::new(""))
+            .to_path_buf();
+        let target = NameTarget::Rename {
+            original: original_path.clone(),
+        };
+        if !self.check_new_name(&new_name, &parent, target) {
+            return;
+        }
+
+        if let Some(backend) = self.active_panel_state().backend.clone() {
+            self.confirm_mounted_rename(backend.as_ref(), &new_name, &original_path);
+            return;
+        }
+
         let new_path = original_path
             .parent()
             .map(|p| p.join(&new_name))
@@ -2549,6 +3605,13 @@ impl App {
                 self.set_toast("Rename completed");
             }
             Err(e) => {
+                let op = ElevatedOp::Rename {
+                    source: original_path.clone(),
+                    dest: new_path,
+                };
+                if self.offer_elevated_retry_for("Rename", &e, op) {
+                    return;
+                }
                 self.dialog = Some(DialogKind::error(
                     "Error",
                     Self::format_user_error(
@@ -2628,11 +3691,15 @@ impl App {
         };
 
         if let Some(entry) = panel.entries.get(entry_index).cloned() {
-            let file_type_str = match entry.file_type {
-                crate::models::file_entry::FileType::Directory => "Directory",
-                crate::models::file_entry::FileType::File => "File",
-                crate::models::file_entry::FileType::Symlink => "Symbolic Link",
-                crate::models::file_entry::FileType::Executable => "Executable",
+            let file_type_str = match (entry.reparse, entry.file_type) {
+                (Some(crate::models::file_entry::ReparseKind::Junction), _) => "Junction",
+                (Some(crate::models::file_entry::ReparseKind::Other), _) => "Reparse Point",
+                (_, file_type) => match file_type {
+                    crate::models::file_entry::FileType::Directory => "Directory",
+                    crate::models::file_entry::FileType::File => "File",
+                    crate::models::file_entry::FileType::Symlink => "Symbolic Link",
+                    crate::models::file_entry::FileType::Executable => "Executable",
+                },
             };
 
             let size_str = self.format_size_display(&entry);
@@ -2640,8 +3707,12 @@ impl App {
             let permissions_str =
                 crate::utils::formatter::format_permissions(entry.permissions.as_ref());
             let children_info = self.format_children_info(&entry);
+            let attributes = self.properties_attributes(&entry.path);
+            let entry_xattrs = self.properties_xattrs(&entry.path);
+            let entry_detected_type = self.properties_detected_type(&entry);
+            let target_count = self.get_operation_sources().len().max(1);
 
-            self.dialog = Some(DialogKind::properties(
+            let mut dialog = DialogKind::properties(
                 &entry.name,
                 entry.path.to_string_lossy(),
                 file_type_str,
@@ -2649,81 +3720,26 @@ impl App {
                 &modified_str,
                 &permissions_str,
                 children_info,
-            ));
+            );
+            if let DialogKind::Properties {
+                attributes: dialog_attributes,
+                targets,
+                xattrs,
+                detected_type,
+                ..
+            } = &mut dialog
+            {
+                *dialog_attributes = attributes;
+                *targets = target_count;
+                *xattrs = entry_xattrs;
+                *detected_type = entry_detected_type;
+            }
+            self.dialog = Some(dialog);
         }
     }
 
     // === MkdirInput 다이얼로그 입력 처리 ===
 
-    pub fn dialog_mkdir_input_char(&mut self, c: char) {
-        if let Some(DialogKind::MkdirInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::insert_char(value, cursor_pos, c);
-        }
-    }
-
-    pub fn dialog_mkdir_input_backspace(&mut self) {
-        if let Some(DialogKind::MkdirInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::backspace(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_mkdir_input_delete_prev_word(&mut self) {
-        if let Some(DialogKind::MkdirInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete_prev_word(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_mkdir_input_delete(&mut self) {
-        if let Some(DialogKind::MkdirInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_mkdir_input_left(&mut self) {
-        if let Some(DialogKind::MkdirInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::left(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_mkdir_input_right(&mut self) {
-        if let Some(DialogKind::MkdirInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::right(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_mkdir_input_home(&mut self) {
-        if let Some(DialogKind::MkdirInput { cursor_pos, .. }) = &mut self.dialog {
-            TextBufferEdit::home(cursor_pos);
-        }
-    }
-
-    pub fn dialog_mkdir_input_end(&mut self) {
-        if let Some(DialogKind::MkdirInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::end(value, cursor_pos);
-        }
-    }
-
     pub fn dialog_mkdir_toggle_button(&mut self) {
         if let Some(DialogKind::MkdirInput {
             selected_button, ..
@@ -2757,75 +3773,6 @@ impl App {
 
     // === RenameInput 다이얼로그 입력 처리 ===
 
-    pub fn dialog_rename_input_char(&mut self, c: char) {
-        if let Some(DialogKind::RenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::insert_char(value, cursor_pos, c);
-        }
-    }
-
-    pub fn dialog_rename_input_backspace(&mut self) {
-        if let Some(DialogKind::RenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::backspace(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_rename_input_delete_prev_word(&mut self) {
-        if let Some(DialogKind::RenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete_prev_word(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_rename_input_delete(&mut self) {
-        if let Some(DialogKind::RenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_rename_input_left(&mut self) {
-        if let Some(DialogKind::RenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::left(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_rename_input_right(&mut self) {
-        if let Some(DialogKind::RenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::right(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_rename_input_home(&mut self) {
-        if let Some(DialogKind::RenameInput { cursor_pos, .. }) = &mut self.dialog {
-            TextBufferEdit::home(cursor_pos);
-        }
-    }
-
-    pub fn dialog_rename_input_end(&mut self) {
-        if let Some(DialogKind::RenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::end(value, cursor_pos);
-        }
-    }
-
     pub fn dialog_rename_toggle_button(&mut self) {
         if let Some(DialogKind::RenameInput {
             selected_button, ..
@@ -2859,75 +3806,6 @@ impl App {
         }
     }
 
-    pub fn dialog_bookmark_rename_input_char(&mut self, c: char) {
-        if let Some(DialogKind::BookmarkRenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::insert_char(value, cursor_pos, c);
-        }
-    }
-
-    pub fn dialog_bookmark_rename_input_backspace(&mut self) {
-        if let Some(DialogKind::BookmarkRenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::backspace(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_bookmark_rename_input_delete_prev_word(&mut self) {
-        if let Some(DialogKind::BookmarkRenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete_prev_word(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_bookmark_rename_input_delete(&mut self) {
-        if let Some(DialogKind::BookmarkRenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_bookmark_rename_input_left(&mut self) {
-        if let Some(DialogKind::BookmarkRenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::left(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_bookmark_rename_input_right(&mut self) {
-        if let Some(DialogKind::BookmarkRenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::right(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_bookmark_rename_input_home(&mut self) {
-        if let Some(DialogKind::BookmarkRenameInput { cursor_pos, .. }) = &mut self.dialog {
-            TextBufferEdit::home(cursor_pos);
-        }
-    }
-
-    pub fn dialog_bookmark_rename_input_end(&mut self) {
-        if let Some(DialogKind::BookmarkRenameInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::end(value, cursor_pos);
-        }
-    }
-
     pub fn dialog_bookmark_rename_toggle_button(&mut self) {
         if let Some(DialogKind::BookmarkRenameInput {
             selected_button, ..
@@ -2963,25 +3841,49 @@ impl App {
 
     // === 숨김 파일 토글 (Phase 5.3) ===
 
-    /// 숨김 파일 표시/숨김 토글 (양쪽 패널 동시)
+    /// 활성 패널(탭)의 숨김 파일 표시/숨김 토글
     pub fn toggle_hidden(&mut self) {
-        let new_val = !self.left_active_panel_state().show_hidden;
+        let panel = match self.layout.active_panel() {
+            ActivePanel::Left => self.left_tabs.active_mut(),
+            ActivePanel::Right => self.right_tabs.active_mut(),
+        };
+        let new_val = !panel.show_hidden;
+        panel.show_hidden = new_val;
+        let _ = panel.refresh(&self.filesystem);
+        self.set_toast(if new_val {
+            "Hidden files shown"
+        } else {
+            "Hidden files hidden"
+        });
+    }
+
+    /// 활성 패널 기준으로 숨김 파일 표시를 전환해 두 패널에 함께 적용
+    pub fn toggle_hidden_both(&mut self) {
+        let new_val = !self.active_panel_state().show_hidden;
         self.left_active_panel_state_mut().show_hidden = new_val;
         self.right_active_panel_state_mut().show_hidden = new_val;
         let _ = self.left_tabs.active_mut().refresh(&self.filesystem);
         let _ = self.right_tabs.active_mut().refresh(&self.filesystem);
         self.set_toast(if new_val {
-            "Hidden files shown"
+            "Hidden files shown in both panels"
         } else {
-            "Hidden files hidden"
+            "Hidden files hidden in both panels"
         });
     }
 
     /// 마운트 포인트 다이얼로그 표시
     pub fn show_mount_points(&mut self) {
         let points = self.filesystem.list_mount_points();
-        let items: Vec<(String, std::path::PathBuf)> =
-            points.into_iter().map(|mp| (mp.name, mp.path)).collect();
+        let items: Vec<MountPointItem> = points
+            .into_iter()
+            .map(|mp| MountPointItem {
+                name: mp.name,
+                path: mp.path,
+                fs_type: mp.fs_type,
+                total: mp.space.map_or(0, |space| space.total),
+                used: mp.space.map_or(0, |space| space.used()),
+            })
+            .collect();
         if items.is_empty() {
             self.dialog = Some(DialogKind::message(
                 "Mount Points",
@@ -3002,20 +3904,32 @@ impl App {
 
     /// 활성 패널 디렉토리 히스토리 목록 표시 (최신순)
     pub fn show_history_list(&mut self) {
-        let items = self.active_panel_state().history_items_latest_first();
+        let items = self.history_list_items();
         if items.is_empty() {
             self.dialog = Some(DialogKind::message("History", "No history entries."));
             return;
         }
-        let selected_index = items
-            .iter()
-            .position(|(_, _, is_current)| *is_current)
-            .unwrap_or(0);
+        let selected_index = items.iter().position(|item| item.is_current).unwrap_or(0);
         self.dialog = Some(DialogKind::history_list(items, selected_index));
     }
 
-    pub(super) fn make_unique_bookmark_name(
-        &self,
+    /// 활성 패널 히스토리 목록 항목 (최신순, 고정/방문 시각 포함)
+    fn history_list_items(&self) -> Vec<HistoryListItem> {
+        let panel = self.active_panel_state();
+        panel
+            .history_items_latest_first()
+            .into_iter()
+            .map(|(_, path, is_current)| HistoryListItem {
+                pinned: panel.is_history_pinned(&path),
+                visited_at: panel.history_visited_at(&path),
+                path,
+                is_current,
+            })
+            .collect()
+    }
+
+    pub(super) fn make_unique_bookmark_name(
+        &self,
         desired_name: &str,
         exclude_index: Option<usize>,
     ) -> String {
@@ -3046,13 +3960,60 @@ impl App {
         base.to_string()
     }
 
-    pub(super) fn bookmark_items(&self) -> Vec<(String, PathBuf)> {
+    pub(super) fn bookmark_items(&self) -> Vec<BookmarkListItem> {
         self.bookmarks
             .iter()
-            .map(|b| (b.name.clone(), b.path.clone()))
+            .map(|b| BookmarkListItem {
+                name: b.name.clone(),
+                path: b.path.clone(),
+                hotkey: b.hotkey,
+                group: b.group.clone(),
+            })
             .collect()
     }
 
+    /// 현재 북마크로 목록 다이얼로그 생성 (`selected_bookmark` 위치 선택)
+    pub(super) fn bookmark_list_dialog(&self, selected_bookmark: usize) -> DialogKind {
+        DialogKind::bookmark_list(
+            self.bookmark_items(),
+            &self.bookmark_collapsed_groups,
+            selected_bookmark,
+        )
+    }
+
+    /// 편집 창을 닫고 아래 북마크 목록을 바뀐 내용으로 갱신 (`selected_bookmark` 위치 선택)
+    pub(super) fn return_to_bookmark_list(&mut self, selected_bookmark: usize) {
+        self.pop_dialog();
+        self.dialog = Some(self.bookmark_list_dialog(selected_bookmark));
+    }
+
+    /// 북마크 목록에서 선택된 행
+    pub(super) fn selected_bookmark_row(&self) -> Option<BookmarkListRow> {
+        let Some(DialogKind::BookmarkList { rows, .. }) = &self.dialog else {
+            return None;
+        };
+        rows.selected_item().cloned()
+    }
+
+    /// 북마크 목록에서 선택된 북마크 인덱스 (그룹 머리글이면 None)
+    fn selected_bookmark_index(&self) -> Option<usize> {
+        match self.selected_bookmark_row()? {
+            BookmarkListRow::Bookmark(index) if index < self.bookmarks.len() => Some(index),
+            _ => None,
+        }
+    }
+
+    /// 북마크 목록에서 선택된 북마크 항목 (편집 입력 초기값용)
+    fn selected_bookmark_item(&self) -> Option<(usize, BookmarkListItem)> {
+        let Some(DialogKind::BookmarkList { items, rows }) = &self.dialog else {
+            return None;
+        };
+        match rows.selected_item()? {
+            BookmarkListRow::Bookmark(index) => Some((*index, items.get(*index)?.clone())),
+            BookmarkListRow::Group { .. } => None,
+        }
+    }
+
     pub fn add_bookmark_current_dir(&mut self) {
         let current_path = self.active_panel_state().current_path.clone();
         if self.bookmarks.iter().any(|b| b.path == current_path) {
@@ -3076,6 +4037,8 @@ impl App {
         self.bookmarks.push(PersistedBookmark {
             name: name.clone(),
             path: current_path,
+            hotkey: None,
+            group: None,
         });
         let _ = self.save_persisted_state();
         self.set_toast(&format!("Bookmark added: {}", name));
@@ -3088,106 +4051,167 @@ impl App {
         }
 
         let current_path = self.active_panel_state().current_path.clone();
-        let items = self.bookmark_items();
-        let selected_index = items
+        let selected_index = self
+            .bookmarks
             .iter()
-            .position(|(_, path)| *path == current_path)
+            .position(|b| b.path == current_path)
             .unwrap_or(0);
-        self.dialog = Some(DialogKind::bookmark_list(items, selected_index));
+        self.dialog = Some(self.bookmark_list_dialog(selected_index));
     }
 
-    pub fn bookmark_list_move_down(&mut self) {
-        if let Some(DialogKind::BookmarkList {
-            items,
-            selected_index,
-        }) = &mut self.dialog
-        {
-            if *selected_index + 1 < items.len() {
-                *selected_index += 1;
+    /// 선택 확정 (북마크면 이동, 그룹 머리글이면 접기/펼치기)
+    pub fn bookmark_list_confirm(&mut self) {
+        match self.selected_bookmark_row() {
+            Some(BookmarkListRow::Group { name, .. }) => self.toggle_bookmark_group(&name),
+            Some(BookmarkListRow::Bookmark(index)) => {
+                let Some(bookmark) = self.bookmarks.get(index).cloned() else {
+                    return;
+                };
+                if self.change_active_dir(bookmark.path, true, None) {
+                    self.dialog = None;
+                } else {
+                    self.set_toast("Failed to open bookmark path");
+                }
             }
+            None => {}
         }
     }
 
-    pub fn bookmark_list_move_up(&mut self) {
-        if let Some(DialogKind::BookmarkList { selected_index, .. }) = &mut self.dialog {
-            if *selected_index > 0 {
-                *selected_index -= 1;
-            }
+    pub fn bookmark_list_delete_selected(&mut self) {
+        let Some(selected_index) = self.selected_bookmark_index() else {
+            return;
+        };
+
+        self.bookmarks.remove(selected_index);
+        let _ = self.save_persisted_state();
+
+        if self.bookmarks.is_empty() {
+            self.dialog = None;
+            self.set_toast("Bookmark deleted");
+            return;
         }
+
+        let new_index = selected_index.min(self.bookmarks.len().saturating_sub(1));
+        self.dialog = Some(self.bookmark_list_dialog(new_index));
+        self.set_toast("Bookmark deleted");
     }
 
-    pub fn bookmark_list_confirm(&mut self) {
-        let (selected_index, item_len) = if let Some(DialogKind::BookmarkList {
-            items,
-            selected_index,
-        }) = &self.dialog
-        {
-            (*selected_index, items.len())
-        } else {
+    /// `'` + 숫자로 해당 단축키 북마크로 이동
+    pub fn jump_to_bookmark_hotkey(&mut self, digit: u8) {
+        let Some(bookmark) = self
+            .bookmarks
+            .iter()
+            .find(|b| b.hotkey == Some(digit))
+            .cloned()
+        else {
+            self.set_toast(&format!("No bookmark on '{}", digit));
             return;
         };
-
-        if item_len == 0 || selected_index >= item_len {
-            return;
+        if !self.change_active_dir(bookmark.path, true, None) {
+            self.set_toast("Failed to open bookmark path");
         }
+    }
 
-        let Some(bookmark) = self.bookmarks.get(selected_index).cloned() else {
+    /// 선택한 북마크에 단축키 숫자 지정 (같은 숫자면 해제, 다른 북마크의 같은 숫자는 옮김)
+    pub fn bookmark_list_assign_hotkey(&mut self, digit: u8) {
+        let Some(selected_index) = self.selected_bookmark_index() else {
             return;
         };
 
-        if self.change_active_dir(bookmark.path, true, None) {
-            self.dialog = None;
+        let cleared = self.bookmarks[selected_index].hotkey == Some(digit);
+        for bookmark in &mut self.bookmarks {
+            if bookmark.hotkey == Some(digit) {
+                bookmark.hotkey = None;
+            }
+        }
+        if !cleared {
+            self.bookmarks[selected_index].hotkey = Some(digit);
+        }
+        let _ = self.save_persisted_state();
+        self.dialog = Some(self.bookmark_list_dialog(selected_index));
+        if cleared {
+            self.set_toast("Bookmark hotkey cleared");
         } else {
-            self.set_toast("Failed to open bookmark path");
+            self.set_toast(&format!("Bookmark hotkey: '{}", digit));
         }
     }
 
-    pub fn bookmark_list_delete_selected(&mut self) {
-        let selected_index =
-            if let Some(DialogKind::BookmarkList { selected_index, .. }) = &self.dialog {
-                *selected_index
-            } else {
-                return;
-            };
-
-        if selected_index >= self.bookmarks.len() {
+    pub fn start_bookmark_rename_selected(&mut self) {
+        let Some((selected_index, item)) = self.selected_bookmark_item() else {
             return;
-        }
+        };
+        self.push_dialog(DialogKind::bookmark_rename_input(item.name, selected_index));
+    }
 
-        self.bookmarks.remove(selected_index);
-        let _ = self.save_persisted_state();
+    /// 선택한 북마크의 대상 경로 편집 시작
+    pub fn start_bookmark_path_edit_selected(&mut self) {
+        let Some((selected_index, item)) = self.selected_bookmark_item() else {
+            return;
+        };
+        let value = item.path.to_string_lossy().to_string();
+        self.push_dialog(DialogKind::bookmark_path_input(value, selected_index));
+    }
 
-        if self.bookmarks.is_empty() {
-            self.dialog = None;
-            self.set_toast("Bookmark deleted");
+    /// 선택한 북마크의 소속 그룹 지정 시작
+    pub fn start_bookmark_group_edit_selected(&mut self) {
+        let Some((selected_index, item)) = self.selected_bookmark_item() else {
             return;
-        }
+        };
+        let value = item.group.unwrap_or_default();
+        self.push_dialog(DialogKind::bookmark_group_input(value, selected_index));
+    }
 
-        let new_index = selected_index.min(self.bookmarks.len().saturating_sub(1));
-        self.dialog = Some(DialogKind::bookmark_list(self.bookmark_items(), new_index));
-        self.set_toast("Bookmark deleted");
+    /// 북마크 편집 입력 확정 (이름/경로/그룹)
+    pub fn confirm_bookmark_edit(&mut self, value: String, bookmark_index: usize) {
+        let field = match &self.dialog {
+            Some(DialogKind::BookmarkRenameInput { field, .. }) => *field,
+            _ => BookmarkEditField::Name,
+        };
+        match field {
+            BookmarkEditField::Name => self.confirm_bookmark_rename(value, bookmark_index),
+            BookmarkEditField::Path => self.confirm_bookmark_path(value, bookmark_index),
+            BookmarkEditField::Group => self.confirm_bookmark_group(value, bookmark_index),
+        }
     }
 
-    pub fn start_bookmark_rename_selected(&mut self) {
-        let (selected_index, item_name) = if let Some(DialogKind::BookmarkList {
-            items,
-            selected_index,
-        }) = &self.dialog
+    /// 북마크 대상 경로 변경 (존재하는 디렉토리만 허용, 실패 시 입력 유지)
+    pub fn confirm_bookmark_path(&mut self, new_path: String, bookmark_index: usize) {
+        if bookmark_index >= self.bookmarks.len() {
+            self.close_dialog();
+            return;
+        }
+
+        let trimmed = new_path.trim();
+        if trimmed.is_empty() {
+            self.set_toast("Bookmark path cannot be empty");
+            return;
+        }
+        let base = self.active_panel_state().current_path.clone();
+        let resolved = self.resolve_input_path(trimmed, &base);
+        if !resolved.is_dir() {
+            self.set_toast("Bookmark path must be an existing directory");
+            return;
+        }
+        let resolved = resolved.canonicalize().unwrap_or(resolved);
+        if self
+            .bookmarks
+            .iter()
+            .enumerate()
+            .any(|(idx, b)| idx != bookmark_index && b.path == resolved)
         {
-            if items.is_empty() || *selected_index >= items.len() {
-                return;
-            }
-            (*selected_index, items[*selected_index].0.clone())
-        } else {
+            self.set_toast("Bookmark already exists");
             return;
-        };
+        }
 
-        self.dialog = Some(DialogKind::bookmark_rename_input(item_name, selected_index));
+        self.bookmarks[bookmark_index].path = resolved;
+        let _ = self.save_persisted_state();
+        self.return_to_bookmark_list(bookmark_index);
+        self.set_toast("Bookmark path changed");
     }
 
     pub fn confirm_bookmark_rename(&mut self, new_name: String, bookmark_index: usize) {
         if bookmark_index >= self.bookmarks.len() {
-            self.dialog = None;
+            self.close_dialog();
             return;
         }
 
@@ -3200,87 +4224,296 @@ impl App {
         let unique = self.make_unique_bookmark_name(trimmed, Some(bookmark_index));
         self.bookmarks[bookmark_index].name = unique;
         let _ = self.save_persisted_state();
-        self.dialog = Some(DialogKind::bookmark_list(
-            self.bookmark_items(),
-            bookmark_index.min(self.bookmarks.len().saturating_sub(1)),
-        ));
+        self.return_to_bookmark_list(bookmark_index);
         self.set_toast("Bookmark renamed");
     }
 
-    /// 탭 목록 다이얼로그에서 선택 이동 (아래)
-    pub fn tab_list_move_down(&mut self) {
-        if let Some(DialogKind::TabList {
-            items,
-            selected_index,
-        }) = &mut self.dialog
-        {
-            if *selected_index + 1 < items.len() {
-                *selected_index += 1;
+    // === 이름 있는 세션 ===
+
+    /// 양쪽 패널의 탭/경로/정렬/필터를 세션으로 캡처
+    pub(super) fn capture_session(&self, name: &str) -> Session {
+        let capture = |tabs: &PanelTabs, slot: PanelSlot| {
+            let mut panel = PanelSession::capture(tabs);
+            // 압축 패널 탐색 중인 탭은 압축 파일 위치로 저장
+            if let Some(view) = &self.archive_panel_view {
+                if view.panel == slot {
+                    if let Some(tab) = panel.tabs.get_mut(tabs.active_index()) {
+                        tab.path = view.base_dir.clone();
+                    }
+                }
             }
+            // 원격 패널 탭은 접속 전 로컬 경로로 저장
+            if let Some(view) = &self.remote_panel_view {
+                if view.panel == slot {
+                    if let Some(tab) = panel.tabs.get_mut(tabs.active_index()) {
+                        tab.path = view.local_dir.clone();
+                    }
+                }
+            }
+            panel
+        };
+        Session {
+            version: SESSION_VERSION,
+            name: name.to_string(),
+            active_panel: match self.active_panel() {
+                ActivePanel::Left => SessionSide::Left,
+                ActivePanel::Right => SessionSide::Right,
+            },
+            left: capture(&self.left_tabs, PanelSlot::Left),
+            right: capture(&self.right_tabs, PanelSlot::Right),
         }
     }
 
-    /// 탭 목록 다이얼로그에서 선택 이동 (위)
-    pub fn tab_list_move_up(&mut self) {
-        if let Some(DialogKind::TabList { selected_index, .. }) = &mut self.dialog {
-            if *selected_index > 0 {
-                *selected_index -= 1;
+    pub fn start_save_session(&mut self) {
+        if self.sessions_dir().is_none() {
+            self.dialog = Some(DialogKind::message(
+                "Sessions",
+                "Sessions are disabled (--no-persist).",
+            ));
+            return;
+        }
+        self.dialog = Some(DialogKind::session_name_input(""));
+        self.on_dialog_text(|app, name| app.save_session_as(&name));
+    }
+
+    pub fn save_session_as(&mut self, name: &str) {
+        let name = name.trim();
+        if name.is_empty() {
+            self.start_save_session();
+            self.set_toast("Session name cannot be empty");
+            return;
+        }
+        let Some(dir) = self.sessions_dir() else {
+            return;
+        };
+
+        let captured = self.capture_session(name);
+        match session::save_session(&dir, &captured) {
+            Ok(_) => {
+                self.set_toast(&format!("Session saved: {}", name));
+            }
+            Err(err) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error("Save session", None, &err.to_string(), ""),
+                ));
             }
         }
     }
 
-    /// 탭 목록 다이얼로그에서 선택 확인
-    pub fn tab_list_confirm(&mut self) {
-        let index = if let Some(DialogKind::TabList { selected_index, .. }) = &self.dialog {
-            Some(*selected_index)
+    fn session_items(sessions: &[Session]) -> Vec<(String, String)> {
+        sessions
+            .iter()
+            .map(|s| (s.name.clone(), s.summary()))
+            .collect()
+    }
+
+    pub fn show_session_list(&mut self) {
+        let sessions = self
+            .sessions_dir()
+            .map(|dir| session::list_sessions(&dir))
+            .unwrap_or_default();
+        if sessions.is_empty() {
+            self.dialog = Some(DialogKind::message("Sessions", "No saved sessions."));
+            return;
+        }
+        self.dialog = Some(DialogKind::session_list(Self::session_items(&sessions), 0));
+    }
+
+    fn selected_session_name(&self) -> Option<(String, usize)> {
+        if let Some(DialogKind::SessionList { list }) = &self.dialog {
+            let index = list.selected_index()?;
+            list.items.get(index).map(|(name, _)| (name.clone(), index))
         } else {
             None
+        }
+    }
+
+    pub fn session_list_confirm(&mut self) {
+        let Some((name, _)) = self.selected_session_name() else {
+            return;
         };
-        if let Some(index) = index {
-            self.switch_tab_active_panel(index);
+        let Some(saved) = self
+            .sessions_dir()
+            .and_then(|dir| session::load_session(&dir, &name))
+        else {
+            self.set_toast("Session paths not found");
+            return;
+        };
+
+        if self.restore_session(&saved) {
             self.dialog = None;
+            self.set_toast(&format!("Session restored: {}", saved.name));
+        } else {
+            self.set_toast("Session paths not found");
         }
     }
 
-    /// 히스토리 목록 다이얼로그에서 선택 이동 (아래)
-    pub fn history_list_move_down(&mut self) {
-        if let Some(DialogKind::HistoryList {
-            items,
-            selected_index,
-        }) = &mut self.dialog
-        {
-            if *selected_index + 1 < items.len() {
-                *selected_index += 1;
-            }
+    pub fn session_list_delete_selected(&mut self) {
+        let Some((name, selected_index)) = self.selected_session_name() else {
+            return;
+        };
+        let Some(dir) = self.sessions_dir() else {
+            return;
+        };
+        if let Err(err) = session::delete_session(&dir, &name) {
+            self.push_dialog(DialogKind::error(
+                "Error",
+                Self::format_user_error("Delete session", None, &err.to_string(), ""),
+            ));
+            return;
         }
+
+        let sessions = session::list_sessions(&dir);
+        if sessions.is_empty() {
+            self.dialog = None;
+        } else {
+            let new_index = selected_index.min(sessions.len() - 1);
+            self.dialog = Some(DialogKind::session_list(
+                Self::session_items(&sessions),
+                new_index,
+            ));
+        }
+        self.set_toast("Session deleted");
+    }
+
+    fn theme_items(&self) -> Vec<(String, Option<PathBuf>)> {
+        self.theme_manager
+            .available_themes()
+            .into_iter()
+            .map(|name| {
+                let path = self
+                    .theme_manager
+                    .custom_theme_path(&name)
+                    .map(Path::to_path_buf);
+                (name, path)
+            })
+            .collect()
+    }
+
+    pub fn show_theme_list(&mut self) {
+        self.dialog = Some(DialogKind::theme_list(
+            self.theme_items(),
+            self.current_theme_name(),
+        ));
+    }
+
+    pub fn theme_list_confirm(&mut self) {
+        let Some(DialogKind::ThemeList { list, .. }) = &self.dialog else {
+            return;
+        };
+        let Some((name, _)) = list.selected_item().cloned() else {
+            return;
+        };
+        self.dialog = None;
+        self.switch_theme_and_save(&name);
     }
 
-    /// 히스토리 목록 다이얼로그에서 선택 이동 (위)
-    pub fn history_list_move_up(&mut self) {
-        if let Some(DialogKind::HistoryList { selected_index, .. }) = &mut self.dialog {
-            if *selected_index > 0 {
-                *selected_index -= 1;
+    /// 사용자 테마 디렉토리 다시 읽기 (현재 테마가 사용자 테마면 즉시 반영)
+    pub fn theme_list_reload(&mut self) {
+        let selected_name = match &self.dialog {
+            Some(DialogKind::ThemeList { list, .. }) => {
+                list.selected_item().map(|(name, _)| name.clone())
             }
+            _ => return,
+        };
+        let current = self.current_theme_name().to_string();
+        self.load_custom_themes();
+        if self.theme_manager.switch_theme(&current).is_err() {
+            let _ = self.theme_manager.switch_theme("dark");
+        }
+
+        let items = self.theme_items();
+        let selected_index = selected_name
+            .and_then(|name| items.iter().position(|(n, _)| *n == name))
+            .unwrap_or(0);
+        self.dialog = Some(DialogKind::ThemeList {
+            list: ListDialogState::new(items).with_selected(selected_index),
+            current: self.current_theme_name().to_string(),
+        });
+        self.set_toast("Themes reloaded");
+    }
+
+    /// 세션 복원. 양쪽 모두 복원할 경로가 없으면 false
+    ///
+    /// 존재하지 않는 경로의 탭은 건너뛰고, 복원할 탭이 없는 패널은 현재 상태를 유지합니다.
+    pub(super) fn restore_session(&mut self, saved: &Session) -> bool {
+        let left = self.build_session_tabs(&saved.left, &self.left_tabs);
+        let right = self.build_session_tabs(&saved.right, &self.right_tabs);
+        if left.is_none() && right.is_none() {
+            return false;
+        }
+
+        self.archive_panel_view = None;
+        self.remote_panel_view = None;
+        if let Some(tabs) = left {
+            self.left_tabs = tabs;
+        }
+        if let Some(tabs) = right {
+            self.right_tabs = tabs;
+        }
+        self.layout.set_active_panel(match saved.active_panel {
+            SessionSide::Left => ActivePanel::Left,
+            SessionSide::Right => ActivePanel::Right,
+        });
+        true
+    }
+
+    fn build_session_tabs(&self, saved: &PanelSession, current: &PanelTabs) -> Option<PanelTabs> {
+        let (tabs, active_index) = saved.restorable_tabs(self.max_tabs_per_panel)?;
+        let states: Vec<PanelState> = tabs
+            .iter()
+            .enumerate()
+            .map(|(idx, tab)| {
+                let mut panel = tab.to_panel_state();
+                panel.collation = self.sort_collation;
+                panel.dirs_first = self.dirs_first;
+                panel.hide_rules = self.hide_rules();
+                if idx == active_index {
+                    // 활성 탭은 기존 디렉토리 히스토리를 이어받음
+                    panel.history_entries = current.active().history_entries.clone();
+                    panel.history_index = current.active().history_index;
+                    panel.history_visits = current.active().history_visits.clone();
+                    panel.history_pins = current.active().history_pins.clone();
+                    panel.record_history(tab.path.clone());
+                }
+                let _ = panel.refresh(&self.filesystem);
+                panel
+            })
+            .collect();
+        PanelTabs::from_tabs(states, active_index)
+    }
+
+    /// 탭 목록 다이얼로그에서 선택 확인
+    pub fn tab_list_confirm(&mut self) {
+        let index = if let Some(DialogKind::TabList { list }) = &self.dialog {
+            list.selected_index()
+        } else {
+            None
+        };
+        if let Some(index) = index {
+            self.switch_tab_active_panel(index);
+            self.dialog = None;
         }
     }
 
     /// 히스토리 목록 다이얼로그에서 선택 확인
     pub fn history_list_confirm(&mut self) {
-        let (selected_index, item_len) = if let Some(DialogKind::HistoryList {
-            items,
-            selected_index,
-        }) = &self.dialog
-        {
-            (*selected_index, items.len())
-        } else {
-            return;
+        let (item_index, item_len) = match &self.dialog {
+            Some(DialogKind::HistoryList { list }) => {
+                let Some(item_index) = list.selected_index() else {
+                    return;
+                };
+                (item_index, list.items.len())
+            }
+            _ => return,
         };
 
-        if item_len == 0 || selected_index >= item_len {
+        if item_index >= item_len {
             return;
         }
 
-        let target_index = item_len - 1 - selected_index;
+        let target_index = item_len - 1 - item_index;
         let (target_path, old_index) = {
             let panel = self.active_panel_state_mut();
             let old = panel.history_index;
@@ -3297,116 +4530,40 @@ impl App {
         }
     }
 
-    /// 현재 패널 히스토리 전체 삭제 (현재 경로만 유지)
+    /// 현재 패널 히스토리 전체 삭제 (고정 경로와 현재 경로만 유지)
     pub fn history_list_clear_all(&mut self) {
         self.active_panel_state_mut().clear_history_to_current();
-        let items = self.active_panel_state().history_items_latest_first();
+        let items = self.history_list_items();
         self.dialog = Some(DialogKind::history_list(items, 0));
         let _ = self.save_persisted_state();
         self.set_toast("History cleared");
     }
 
-    pub(super) fn archive_preview_adjust_scroll(
-        selected: usize,
-        scroll: &mut usize,
-        visible_height: usize,
-    ) {
-        if visible_height == 0 {
-            *scroll = 0;
+    /// 선택한 히스토리 경로 고정/해제 (검색어와 선택 위치 유지)
+    pub fn history_list_toggle_pin(&mut self) {
+        let Some(DialogKind::HistoryList { list }) = &self.dialog else {
             return;
-        }
-        if selected < *scroll {
-            *scroll = selected;
-        } else if selected >= *scroll + visible_height {
-            *scroll = selected + 1 - visible_height;
-        }
-    }
-
-    pub fn archive_preview_move_down(&mut self) {
-        if let Some(DialogKind::ArchivePreviewList {
-            items,
-            selected_index,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            if *selected_index + 1 < items.len() {
-                *selected_index += 1;
-                Self::archive_preview_adjust_scroll(*selected_index, scroll_offset, 12);
-            }
-        }
-    }
-
-    pub fn archive_preview_move_up(&mut self) {
-        if let Some(DialogKind::ArchivePreviewList {
-            selected_index,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            if *selected_index > 0 {
-                *selected_index -= 1;
-                Self::archive_preview_adjust_scroll(*selected_index, scroll_offset, 12);
-            }
-        }
-    }
-
-    pub fn archive_preview_page_down(&mut self) {
-        if let Some(DialogKind::ArchivePreviewList {
-            items,
-            selected_index,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            if items.is_empty() {
-                return;
-            }
-            *selected_index = (*selected_index + 12).min(items.len().saturating_sub(1));
-            Self::archive_preview_adjust_scroll(*selected_index, scroll_offset, 12);
-        }
-    }
-
-    pub fn archive_preview_page_up(&mut self) {
-        if let Some(DialogKind::ArchivePreviewList {
-            selected_index,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            *selected_index = selected_index.saturating_sub(12);
-            Self::archive_preview_adjust_scroll(*selected_index, scroll_offset, 12);
-        }
-    }
-
-    pub fn archive_preview_go_top(&mut self) {
-        if let Some(DialogKind::ArchivePreviewList {
-            selected_index,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            *selected_index = 0;
-            *scroll_offset = 0;
-        }
-    }
+        };
+        let Some(item) = list.selected_item() else {
+            return;
+        };
+        let path = item.path.clone();
+        let filter = list.filter.clone();
+        let selected_index = list.selected;
 
-    pub fn archive_preview_go_bottom(&mut self) {
-        if let Some(DialogKind::ArchivePreviewList {
-            items,
-            selected_index,
-            scroll_offset,
-            ..
-        }) = &mut self.dialog
-        {
-            if items.is_empty() {
-                *selected_index = 0;
-                *scroll_offset = 0;
-                return;
-            }
-            *selected_index = items.len() - 1;
-            Self::archive_preview_adjust_scroll(*selected_index, scroll_offset, 12);
-        }
+        let pinned = self.active_panel_state_mut().toggle_history_pin(&path);
+        let _ = self.save_persisted_state();
+        let mut dialog = DialogKind::history_list(self.history_list_items(), 0);
+        if let DialogKind::HistoryList { list } = &mut dialog {
+            list.set_filter(filter);
+            list.selected = selected_index.min(list.len().saturating_sub(1));
+        }
+        self.dialog = Some(dialog);
+        self.set_toast(if pinned {
+            "History entry pinned"
+        } else {
+            "History entry unpinned"
+        });
     }
 
     /// 히스토리 뒤로 이동 (Alt+Left)
@@ -3452,36 +4609,10 @@ impl App {
         }
     }
 
-    /// 마운트 포인트 다이얼로그에서 선택 이동 (아래)
-    pub fn mount_points_move_down(&mut self) {
-        if let Some(DialogKind::MountPoints {
-            items,
-            selected_index,
-        }) = &mut self.dialog
-        {
-            if *selected_index + 1 < items.len() {
-                *selected_index += 1;
-            }
-        }
-    }
-
-    /// 마운트 포인트 다이얼로그에서 선택 이동 (위)
-    pub fn mount_points_move_up(&mut self) {
-        if let Some(DialogKind::MountPoints { selected_index, .. }) = &mut self.dialog {
-            if *selected_index > 0 {
-                *selected_index -= 1;
-            }
-        }
-    }
-
     /// 마운트 포인트 다이얼로그에서 선택 확인
     pub fn mount_points_confirm(&mut self) {
-        let path = if let Some(DialogKind::MountPoints {
-            items,
-            selected_index,
-        }) = &self.dialog
-        {
-            items.get(*selected_index).map(|(_, p)| p.clone())
+        let path = if let Some(DialogKind::MountPoints { list }) = &self.dialog {
+            list.selected_item().map(|item| item.path.clone())
         } else {
             None
         };
@@ -3575,57 +4706,21 @@ impl App {
 
     // === FilterInput 다이얼로그 입력 처리 ===
 
-    pub fn dialog_filter_input_char(&mut self, c: char) {
-        let new_value = if let Some(DialogKind::FilterInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::insert_char(value, cursor_pos, c);
-            Some(value.clone())
-        } else {
-            None
-        };
-        if let Some(v) = new_value {
-            self.apply_live_filter(&v);
-        }
-    }
-
-    pub fn dialog_filter_input_backspace(&mut self) {
-        let new_value = if let Some(DialogKind::FilterInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::backspace(value, cursor_pos);
-            Some(value.clone())
-        } else {
-            None
-        };
-        if let Some(v) = new_value {
-            self.apply_live_filter(&v);
-        }
-    }
-
-    pub fn dialog_filter_input_delete_prev_word(&mut self) {
-        let new_value = if let Some(DialogKind::FilterInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::delete_prev_word(value, cursor_pos);
-            Some(value.clone())
-        } else {
-            None
-        };
-        if let Some(v) = new_value {
-            self.apply_live_filter(&v);
-        }
-    }
-
-    pub fn dialog_filter_input_delete(&mut self) {
+    /// 정규식 모드 체크박스 토글 (`r:` 접두사 추가/제거)
+    pub fn dialog_filter_toggle_regex(&mut self) {
         let new_value = if let Some(DialogKind::FilterInput {
             value, cursor_pos, ..
         }) = &mut self.dialog
         {
-            TextBufferEdit::delete(value, cursor_pos);
+            const REGEX_PREFIX: &str = crate::utils::name_filter::REGEX_PREFIX;
+            let prefix_len = REGEX_PREFIX.len();
+            if value.starts_with(REGEX_PREFIX) {
+                value.replace_range(..prefix_len, "");
+                *cursor_pos = cursor_pos.saturating_sub(prefix_len);
+            } else {
+                value.insert_str(0, REGEX_PREFIX);
+                *cursor_pos += prefix_len;
+            }
             Some(value.clone())
         } else {
             None
@@ -3635,39 +4730,6 @@ impl App {
         }
     }
 
-    pub fn dialog_filter_input_left(&mut self) {
-        if let Some(DialogKind::FilterInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::left(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_filter_input_right(&mut self) {
-        if let Some(DialogKind::FilterInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::right(value, cursor_pos);
-        }
-    }
-
-    pub fn dialog_filter_input_home(&mut self) {
-        if let Some(DialogKind::FilterInput { cursor_pos, .. }) = &mut self.dialog {
-            TextBufferEdit::home(cursor_pos);
-        }
-    }
-
-    pub fn dialog_filter_input_end(&mut self) {
-        if let Some(DialogKind::FilterInput {
-            value, cursor_pos, ..
-        }) = &mut self.dialog
-        {
-            TextBufferEdit::end(value, cursor_pos);
-        }
-    }
-
     pub fn dialog_filter_toggle_button(&mut self) {
         if let Some(DialogKind::FilterInput {
             selected_button, ..
@@ -0,0 +1,39 @@
+use super::*;
+
+impl App {
+    // === 좌우 패널 교체 / 반대 패널을 같은 경로로 ===
+
+    /// 좌우 패널의 탭 전체와 압축/원격 보기를 서로 맞바꿈 (Ctrl+S)
+    ///
+    /// 활성 패널 위치는 그대로 유지합니다.
+    pub(super) fn swap_panels(&mut self) {
+        std::mem::swap(&mut self.left_tabs, &mut self.right_tabs);
+        if let Some(view) = &mut self.archive_panel_view {
+            view.panel = view.panel.other();
+        }
+        if let Some(view) = &mut self.remote_panel_view {
+            view.panel = view.panel.other();
+        }
+        // 조회 중이던 git 상태는 경로가 달라져 버려지므로 다시 조회
+        for panel in [self.left_tabs.active_mut(), self.right_tabs.active_mut()] {
+            panel.git_status_stale = true;
+        }
+        self.context_menu = None;
+        self.adjust_scroll_offset();
+        self.set_toast("Panels swapped");
+    }
+
+    /// 반대 패널을 활성 패널의 현재 디렉토리로 이동 (g=)
+    pub(super) fn sync_other_panel_to_active(&mut self) {
+        if !self.require_local_panels() {
+            return;
+        }
+        let path = self.active_panel_state().current_path.clone();
+        if self.inactive_panel_state().current_path == path {
+            self.set_toast("Other panel is already in this directory");
+            return;
+        }
+        self.change_inactive_dir(path, None);
+        self.set_toast("Other panel set to current directory");
+    }
+}
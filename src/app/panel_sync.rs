@@ -0,0 +1,353 @@
+use super::*;
+use crate::system::sync;
+use crate::ui::components::dialog::PanelSyncPhase;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 페이지 이동 단위 (항목 수)
+const PANEL_SYNC_PAGE: usize = 12;
+
+impl App {
+    // === 두 패널 동기화 (비교 → 계획 검토 → 백그라운드 실행) ===
+
+    /// 왼쪽/오른쪽 패널 디렉토리 비교 시작 (백그라운드)
+    pub fn start_panel_sync(&mut self) {
+        if !self.require_local_panels() {
+            return;
+        }
+        let (left, right) = (
+            self.left_active_panel_state().current_path.clone(),
+            self.right_active_panel_state().current_path.clone(),
+        );
+        if left == right {
+            self.dialog = Some(DialogKind::message(
+                "Synchronize",
+                "Both panels show the same directory.",
+            ));
+            return;
+        }
+        if left.starts_with(&right) || right.starts_with(&left) {
+            self.dialog = Some(DialogKind::message(
+                "Synchronize",
+                "One panel is inside the other.",
+            ));
+            return;
+        }
+
+        self.cancel_panel_sync();
+        let (events_tx, events_rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_for_worker = Arc::clone(&cancel);
+        let (left_root, right_root) = (left.clone(), right.clone());
+        let waker = self.waker.clone();
+        std::thread::spawn(move || {
+            let progress_tx = events_tx.clone();
+            let result = sync::plan(&left_root, &right_root, &cancel_for_worker, &mut |count| {
+                let _ = progress_tx.send(PanelSyncEvent::Compared(count));
+            });
+            let _ = events_tx.send(PanelSyncEvent::Planned(result));
+            waker.wake();
+        });
+        self.dialog = Some(DialogKind::panel_sync_comparing(
+            left.to_string_lossy().to_string(),
+            right.to_string_lossy().to_string(),
+        ));
+        self.panel_sync = Some(PanelSyncState {
+            left,
+            right,
+            started: Instant::now(),
+            events: Some(events_rx),
+            cancel,
+        });
+    }
+
+    /// 비교/실행 이벤트 반영 (메인 루프에서 호출)
+    pub fn poll_panel_sync(&mut self) {
+        let Some(events) = self.panel_sync.as_ref().and_then(|s| s.events.as_ref()) else {
+            return;
+        };
+        let mut progress = None;
+        let mut finished = None;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                PanelSyncEvent::Compared(count) => {
+                    progress = Some(PanelSyncPhase::Comparing(count))
+                }
+                PanelSyncEvent::Progress { done, total } => {
+                    progress = Some(PanelSyncPhase::Running { done, total });
+                }
+                event => {
+                    finished = Some(event);
+                    break;
+                }
+            }
+        }
+        if let (Some(progress), Some(DialogKind::PanelSync { phase, .. })) =
+            (progress, self.dialog.as_mut())
+        {
+            *phase = progress;
+        }
+        match finished {
+            Some(PanelSyncEvent::Planned(result)) => self.finish_panel_sync_plan(result),
+            Some(PanelSyncEvent::Finished(result)) => self.finish_panel_sync(result),
+            _ => {}
+        }
+    }
+
+    fn finish_panel_sync_plan(&mut self, result: Result<Vec<SyncItem>>) {
+        match result {
+            Ok(items) if items.is_empty() => {
+                self.panel_sync = None;
+                self.dialog = Some(DialogKind::message(
+                    "Synchronize",
+                    "Panels are already in sync.",
+                ));
+            }
+            Ok(items) => {
+                if let Some(state) = self.panel_sync.as_mut() {
+                    state.events = None;
+                }
+                if let Some(DialogKind::PanelSync {
+                    items: dialog_items,
+                    selected_index,
+                    phase,
+                    ..
+                }) = self.dialog.as_mut()
+                {
+                    *dialog_items = items;
+                    *selected_index = 0;
+                    *phase = PanelSyncPhase::Review;
+                }
+            }
+            Err(BokslDirError::OperationCancelled) => self.panel_sync = None,
+            Err(err) => {
+                self.panel_sync = None;
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Synchronize panels",
+                        None,
+                        &err.to_string(),
+                        "Check both directories and read permission.",
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn finish_panel_sync(&mut self, result: Result<SyncSummary>) {
+        let Some(state) = self.panel_sync.take() else {
+            return;
+        };
+        if matches!(self.dialog, Some(DialogKind::PanelSync { .. })) {
+            self.dialog = None;
+        }
+        self.refresh_both_panels();
+        match result {
+            Ok(summary) => {
+                let mut message = format!(
+                    "Synchronized: {} copied, {} deleted",
+                    summary.copied, summary.deleted
+                );
+                if !summary.errors.is_empty() {
+                    message.push_str(&format!(", {} failed", summary.errors.len()));
+                }
+                let status = if summary.errors.is_empty() {
+                    JobStatus::Succeeded
+                } else {
+                    JobStatus::CompletedWithErrors
+                };
+                self.record_job(
+                    "Synchronize panels",
+                    format!(
+                        "{} ⇄ {}: {} copied, {} deleted",
+                        state.left.display(),
+                        state.right.display(),
+                        summary.copied,
+                        summary.deleted
+                    ),
+                    status,
+                    state.started.elapsed(),
+                    summary.errors,
+                );
+                self.set_toast(&message);
+            }
+            Err(BokslDirError::OperationCancelled) => {
+                self.record_job(
+                    "Synchronize panels",
+                    String::new(),
+                    JobStatus::Cancelled,
+                    state.started.elapsed(),
+                    Vec::new(),
+                );
+                self.set_toast("Synchronize cancelled");
+            }
+            Err(err) => {
+                self.record_job(
+                    "Synchronize panels",
+                    String::new(),
+                    JobStatus::Failed,
+                    state.started.elapsed(),
+                    vec![err.to_string()],
+                );
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Synchronize panels",
+                        None,
+                        &err.to_string(),
+                        "Check both directories and write permission.",
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// Esc: 비교 중이면 중단하고 닫기, 실행 중이면 중단 요청, 검토 중이면 닫기
+    pub fn close_panel_sync(&mut self) {
+        let running = matches!(
+            self.dialog,
+            Some(DialogKind::PanelSync {
+                phase: PanelSyncPhase::Running { .. },
+                ..
+            })
+        );
+        if running {
+            // 지금 항목을 마치면 멈추고 Finished 이벤트로 정리
+            if let Some(state) = self.panel_sync.as_ref() {
+                state.cancel.store(true, Ordering::Relaxed);
+            }
+            return;
+        }
+        self.cancel_panel_sync();
+        self.close_dialog();
+    }
+
+    /// 진행 중인 비교/실행 중단 (종료 전 정리)
+    pub(super) fn cancel_panel_sync(&mut self) {
+        if let Some(state) = self.panel_sync.take() {
+            state.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// 비교/실행 스레드가 돌고 있으면 true
+    pub(super) fn is_panel_sync_running(&self) -> bool {
+        self.panel_sync
+            .as_ref()
+            .is_some_and(|state| state.events.is_some())
+    }
+
+    fn panel_sync_review_mut(&mut self) -> Option<(&mut Vec<SyncItem>, &mut usize)> {
+        match &mut self.dialog {
+            Some(DialogKind::PanelSync {
+                items,
+                selected_index,
+                phase: PanelSyncPhase::Review,
+                ..
+            }) => Some((items, selected_index)),
+            _ => None,
+        }
+    }
+
+    fn panel_sync_select(&mut self, select: impl FnOnce(usize, usize) -> usize) {
+        if let Some((items, selected_index)) = self.panel_sync_review_mut() {
+            if !items.is_empty() {
+                *selected_index = select(*selected_index, items.len()).min(items.len() - 1);
+            }
+        }
+    }
+
+    pub fn panel_sync_move_down(&mut self) {
+        self.panel_sync_select(|index, _| index + 1);
+    }
+
+    pub fn panel_sync_move_up(&mut self) {
+        self.panel_sync_select(|index, _| index.saturating_sub(1));
+    }
+
+    pub fn panel_sync_page_down(&mut self) {
+        self.panel_sync_select(|index, _| index + PANEL_SYNC_PAGE);
+    }
+
+    pub fn panel_sync_page_up(&mut self) {
+        self.panel_sync_select(|index, _| index.saturating_sub(PANEL_SYNC_PAGE));
+    }
+
+    pub fn panel_sync_go_top(&mut self) {
+        self.panel_sync_select(|_, _| 0);
+    }
+
+    pub fn panel_sync_go_bottom(&mut self) {
+        self.panel_sync_select(|_, len| len - 1);
+    }
+
+    /// 선택 항목 체크 전환 후 다음 항목으로
+    pub fn panel_sync_toggle_checked(&mut self) {
+        if let Some((items, selected_index)) = self.panel_sync_review_mut() {
+            if let Some(item) = items.get_mut(*selected_index) {
+                item.checked = !item.checked;
+            }
+        }
+        self.panel_sync_move_down();
+    }
+
+    /// 모두 체크 (이미 모두 체크했으면 모두 해제)
+    pub fn panel_sync_toggle_all(&mut self) {
+        if let Some((items, _)) = self.panel_sync_review_mut() {
+            let check = !items.iter().all(|item| item.checked);
+            for item in items.iter_mut() {
+                item.checked = check;
+            }
+        }
+    }
+
+    /// 선택 항목 방향 지정 (반대쪽에 원본이 없으면 그쪽 사본 삭제)
+    pub fn panel_sync_set_direction(&mut self, to_right: bool) {
+        if let Some((items, selected_index)) = self.panel_sync_review_mut() {
+            if let Some(item) = items.get_mut(*selected_index) {
+                item.set_direction(to_right);
+            }
+        }
+    }
+
+    /// 체크한 항목 실행 시작 (백그라운드)
+    pub fn confirm_panel_sync(&mut self) {
+        let Some((items, _)) = self.panel_sync_review_mut() else {
+            return;
+        };
+        let total = items.iter().filter(|item| item.checked).count();
+        if total == 0 {
+            self.set_toast("Nothing checked to synchronize");
+            return;
+        }
+        let items = items.clone();
+        let Some(state) = self.panel_sync.as_mut() else {
+            return;
+        };
+        let (events_tx, events_rx) = mpsc::channel();
+        let cancel = Arc::clone(&state.cancel);
+        let (left_root, right_root) = (state.left.clone(), state.right.clone());
+        let waker = self.waker.clone();
+        std::thread::spawn(move || {
+            let progress_tx = events_tx.clone();
+            let progress_waker = waker.clone();
+            let result = sync::execute(
+                &FileSystem::new(),
+                &left_root,
+                &right_root,
+                &items,
+                &cancel,
+                &mut |done, total| {
+                    let _ = progress_tx.send(PanelSyncEvent::Progress { done, total });
+                    progress_waker.wake();
+                },
+            );
+            let _ = events_tx.send(PanelSyncEvent::Finished(result));
+            waker.wake();
+        });
+        state.started = Instant::now();
+        state.events = Some(events_rx);
+        if let Some(DialogKind::PanelSync { phase, .. }) = self.dialog.as_mut() {
+            *phase = PanelSyncPhase::Running { done: 0, total };
+        }
+    }
+}
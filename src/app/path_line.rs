@@ -0,0 +1,56 @@
+use super::*;
+
+/// 이름 가로 스크롤 한 번에 건너뛰는 글자 수
+const NAME_SCROLL_STEP: usize = 4;
+
+impl App {
+    // === 긴 이름 보기 (포커스 행 가로 스크롤 / 상태바 위 전체 경로 줄) ===
+
+    /// 전체 경로 줄 표시 전환 (설정 저장)
+    pub(super) fn toggle_path_line(&mut self) {
+        self.show_path_line = !self.show_path_line;
+        self.set_toast(if self.show_path_line {
+            "Full path line: on"
+        } else {
+            "Full path line: off"
+        });
+        self.keep_panels_in_view();
+        let _ = self.save_persisted_state();
+    }
+
+    /// 상태바 위에 표시할 활성 패널의 포커스 항목 전체 경로 (꺼져 있으면 None, ".."이면 현재 디렉토리)
+    pub fn path_line_text(&self) -> Option<String> {
+        if !self.show_path_line {
+            return None;
+        }
+        let panel = self.active_panel_state();
+        let path = panel
+            .selected_entry()
+            .map_or(&panel.current_path, |entry| &entry.path);
+        Some(path.display().to_string())
+    }
+
+    /// 포커스 항목 이름을 가로로 스크롤 (Shift+Left/Right, 다른 항목은 처음부터)
+    pub(super) fn scroll_focused_name(&mut self, forward: bool) {
+        let Some(entry) = self.active_panel_state().selected_entry() else {
+            return;
+        };
+        let path = entry.path.clone();
+        let max_offset = entry.name.chars().count().saturating_sub(1);
+        let current = self.name_scroll_for(self.active_panel_state());
+        let offset = if forward {
+            (current + NAME_SCROLL_STEP).min(max_offset)
+        } else {
+            current.saturating_sub(NAME_SCROLL_STEP)
+        };
+        self.name_scroll = (offset > 0).then_some((path, offset));
+    }
+
+    /// 패널의 포커스 항목 이름을 건너뛸 글자 수 (스크롤한 항목에 포커스가 없으면 0)
+    pub fn name_scroll_for(&self, panel: &PanelState) -> usize {
+        match (&self.name_scroll, panel.selected_entry()) {
+            (Some((path, offset)), Some(entry)) if *path == entry.path => *offset,
+            _ => 0,
+        }
+    }
+}
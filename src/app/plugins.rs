@@ -0,0 +1,215 @@
+use super::*;
+use crate::core::plugins::{PluginColumn, PluginContext, PluginEffect, PluginRegistry};
+use crate::ui::{FuzzyListItem, FuzzyListPurpose};
+
+/// 켜진 플러그인과 마지막으로 훅에 알린 활성 패널 상태
+#[derive(Default)]
+pub(super) struct PluginHost {
+    registry: PluginRegistry,
+    /// 설정 파일 `plugins` 그대로 (알 수 없는 이름도 저장할 때 유지)
+    enabled: Vec<String>,
+    /// 진입 훅을 부른 활성 패널 디렉토리
+    entered: Option<PathBuf>,
+    /// 선택 훅을 부른 선택 인덱스 (정렬)
+    selection: Vec<usize>,
+}
+
+impl PluginHost {
+    pub(super) fn enabled_names(&self) -> &[String] {
+        &self.enabled
+    }
+}
+
+/// 패널 렌더링에 넘길 플러그인 컬럼과 값
+pub struct PluginColumnsView<'a> {
+    pub columns: Vec<&'a PluginColumn>,
+    pub values: &'a HashMap<PathBuf, Vec<String>>,
+}
+
+impl App {
+    // === 플러그인 (디렉토리 진입/선택 훅, 추가 액션, 추가 컬럼) ===
+
+    /// 한 번의 폴링에서 값을 구할 최대 항목 수
+    const PLUGIN_COLUMN_BATCH: usize = 32;
+
+    /// 설정 파일의 이름으로 플러그인 켜기 (빌드에 없는 이름은 로그에 남기고 무시)
+    pub(super) fn load_plugins(&mut self, names: Vec<String>) {
+        let (registry, unknown) = crate::plugins::load(&names);
+        for name in unknown {
+            log::warn!("unknown plugin '{}' in settings", name);
+        }
+        self.plugins = PluginHost {
+            registry,
+            enabled: names,
+            ..PluginHost::default()
+        };
+    }
+
+    /// 빌드에 포함하지 않은 플러그인 등록 (설정과 상관없이 켜짐)
+    #[cfg(test)]
+    pub(super) fn register_plugin(&mut self, plugin: Box<dyn crate::core::plugins::Plugin>) {
+        self.plugins.registry.register(plugin);
+    }
+
+    /// 켜진 플러그인 이름
+    pub fn plugin_names(&self) -> Vec<&'static str> {
+        self.plugins.registry.names()
+    }
+
+    /// 패널 렌더링에 넘길 플러그인 컬럼 (컬럼을 제공하는 플러그인이 없으면 None)
+    pub fn plugin_columns_for<'a>(
+        &'a self,
+        panel: &'a PanelState,
+    ) -> Option<PluginColumnsView<'a>> {
+        self.plugins
+            .registry
+            .has_columns()
+            .then(|| PluginColumnsView {
+                columns: self.plugins.registry.columns().collect(),
+                values: &panel.plugin_values,
+            })
+    }
+
+    /// 활성 패널 상태로 훅/액션 호출 후 요청한 효과 적용
+    fn with_plugin_context(
+        &mut self,
+        call: impl FnOnce(&mut PluginRegistry, &mut PluginContext<'_>),
+    ) {
+        let panel = match self.layout.active_panel() {
+            ActivePanel::Left => self.left_tabs.active(),
+            ActivePanel::Right => self.right_tabs.active(),
+        };
+        let selected = panel
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| panel.selected_items.contains(index))
+            .map(|(_, entry)| entry)
+            .collect();
+        let mut ctx = PluginContext::new(
+            &panel.current_path,
+            &panel.entries,
+            panel.selected_entry(),
+            selected,
+        );
+        call(&mut self.plugins.registry, &mut ctx);
+        let effects = ctx.into_effects();
+        for effect in effects {
+            self.apply_plugin_effect(effect);
+        }
+    }
+
+    fn apply_plugin_effect(&mut self, effect: PluginEffect) {
+        match effect {
+            PluginEffect::Toast(message) => self.set_toast(&message),
+            PluginEffect::Navigate(path) => {
+                self.change_active_dir(path, true, None);
+            }
+            PluginEffect::ShowText { title, body } => {
+                self.dialog = Some(DialogKind::message(title, body));
+            }
+        }
+    }
+
+    /// 활성 패널의 디렉토리/선택이 바뀌었으면 훅 호출 (로컬 목록을 다 읽은 뒤에만)
+    pub(super) fn poll_plugin_hooks(&mut self) {
+        if self.plugins.registry.is_empty() {
+            return;
+        }
+        if !self.is_local_panel_view(PanelSlot::from(self.active_panel())) {
+            // 돌아오면 다시 진입으로 알림
+            self.plugins.entered = None;
+            return;
+        }
+        let panel = self.active_panel_state();
+        if panel.is_loading() {
+            return;
+        }
+        let path = panel.current_path.clone();
+        let mut selection: Vec<usize> = panel.selected_items.iter().copied().collect();
+        selection.sort_unstable();
+
+        if self.plugins.entered.as_ref() != Some(&path) {
+            self.plugins.entered = Some(path);
+            self.plugins.selection = selection;
+            self.with_plugin_context(|registry, ctx| registry.on_enter_directory(ctx));
+        } else if self.plugins.selection != selection {
+            self.plugins.selection = selection;
+            self.with_plugin_context(|registry, ctx| registry.on_select(ctx));
+        }
+    }
+
+    /// 플러그인 액션 목록 열기
+    pub fn show_plugin_actions(&mut self) {
+        let items: Vec<FuzzyListItem> = self
+            .plugins
+            .registry
+            .actions()
+            .into_iter()
+            .map(|(_, name, action)| FuzzyListItem {
+                label: action.label.to_string(),
+                detail: name.to_string(),
+            })
+            .collect();
+        if items.is_empty() {
+            self.dialog = Some(DialogKind::message(
+                "Plugin actions",
+                "No plugin actions. Enable plugins with `plugins` in the settings file.",
+            ));
+            return;
+        }
+        self.dialog = Some(DialogKind::fuzzy_list(
+            "Plugin actions",
+            FuzzyListPurpose::PluginAction,
+            items,
+        ));
+    }
+
+    /// 목록에서 고른 플러그인 액션 실행 (`index`는 목록 순서)
+    pub(super) fn run_plugin_action(&mut self, index: usize) {
+        let Some((plugin, _, action)) = self.plugins.registry.actions().get(index).copied() else {
+            return;
+        };
+        self.with_plugin_context(|registry, ctx| registry.run_action(plugin, action.id, ctx));
+    }
+
+    /// 화면에 보이는 로컬 항목의 플러그인 컬럼 값을 구해 캐시에 채움
+    ///
+    /// 한 번에 다 구하지 못해 남은 항목이 있을 수 있으면 true (메인 루프가 곧 다시 호출)
+    pub(super) fn poll_plugin_columns(&mut self) -> bool {
+        if !self.plugins.registry.has_columns() {
+            return false;
+        }
+        let (_, terminal_height) = self.layout.terminal_size();
+        let visible_rows = usize::from(terminal_height);
+        let mut budget = Self::PLUGIN_COLUMN_BATCH;
+        for slot in [PanelSlot::Left, PanelSlot::Right] {
+            if budget == 0 {
+                break;
+            }
+            if !self.is_local_panel_view(slot) {
+                continue;
+            }
+            let panel = self.panel_state_by_slot(slot);
+            let start = panel.scroll_offset.min(panel.entries.len());
+            let end = (start + visible_rows).min(panel.entries.len());
+            let values: Vec<(PathBuf, Vec<String>)> = panel.entries[start..end]
+                .iter()
+                .filter(|entry| !entry.metadata_pending)
+                .filter(|entry| !panel.plugin_values.contains_key(&entry.path))
+                .take(budget)
+                .map(|entry| {
+                    (
+                        entry.path.clone(),
+                        self.plugins.registry.column_values(entry),
+                    )
+                })
+                .collect();
+            budget -= values.len();
+            self.panel_state_by_slot_mut(slot)
+                .plugin_values
+                .extend(values);
+        }
+        budget == 0
+    }
+}
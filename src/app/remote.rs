@@ -0,0 +1,518 @@
+use super::*;
+use crate::system::remote::{remote_error, RemoteFs};
+
+impl App {
+    // === 원격 패널 (SFTP/FTP) ===
+
+    pub(super) fn is_active_panel_remote_view(&self) -> bool {
+        self.active_remote_view().is_some()
+    }
+
+    pub(super) fn active_remote_view(&self) -> Option<&RemotePanelView> {
+        self.remote_panel_view
+            .as_ref()
+            .filter(|v| v.panel == PanelSlot::from(self.active_panel()))
+    }
+
+    pub(super) fn inactive_remote_view(&self) -> Option<&RemotePanelView> {
+        self.remote_panel_view
+            .as_ref()
+            .filter(|v| v.panel != PanelSlot::from(self.active_panel()))
+    }
+
+    /// 원격 패널에서 지원하지 않는 액션이면 안내 후 true
+    pub(super) fn block_remote_only_action(&mut self, action: Action) -> bool {
+        if !self.is_active_panel_remote_view() {
+            return false;
+        }
+        let blocked = matches!(
+            action,
+            Action::OpenDefaultApp
+                | Action::OpenTerminalEditor
+                | Action::RunShell
+                | Action::ShowProperties
+                | Action::ArchiveCompress
+                | Action::ArchiveExtract
+                | Action::ArchiveExtractAuto
+                | Action::ArchivePreview
+                | Action::AddBookmark
+                | Action::TabNew
+                | Action::TabCopyToOtherPanel
+                | Action::TabSendToOtherPanel
+                | Action::TabOpenInOtherPanel
+        );
+        if blocked {
+            self.set_toast("Not available on remote panel");
+        }
+        blocked
+    }
+
+    /// 원격 접속 시작 (gs): 저장된 대상이 있으면 목록, 없으면 URL 입력
+    pub fn start_connect_remote(&mut self) {
+        if self.remote_hosts.is_empty() {
+            self.start_remote_connect_input();
+            return;
+        }
+        self.dialog = Some(DialogKind::remote_host_list(self.remote_host_items(), 0));
+    }
+
+    pub fn start_remote_connect_input(&mut self) {
+        let initial = self
+            .active_remote_view()
+            .map(|v| v.host.url_with_path(&v.current_dir))
+            .unwrap_or_else(|| format!("{}://", RemoteProtocol::Sftp.scheme()));
+        self.dialog = Some(DialogKind::remote_connect_input(initial));
+        self.on_dialog_text(|app, value| app.confirm_remote_connect_input(&value));
+    }
+
+    fn remote_host_items(&self) -> Vec<String> {
+        self.remote_hosts.iter().map(RemoteHost::url).collect()
+    }
+
+    fn selected_remote_host_index(&self) -> Option<usize> {
+        match &self.dialog {
+            Some(DialogKind::RemoteHostList { list }) => list.selected_index(),
+            _ => None,
+        }
+    }
+
+    pub fn remote_host_list_confirm(&mut self) {
+        let Some(index) = self.selected_remote_host_index() else {
+            return;
+        };
+        if let Some(host) = self.remote_hosts.get(index).cloned() {
+            self.connect_remote(host);
+        }
+    }
+
+    pub fn remote_host_list_delete_selected(&mut self) {
+        let Some(index) = self.selected_remote_host_index() else {
+            return;
+        };
+        if index < self.remote_hosts.len() {
+            self.remote_hosts.remove(index);
+            let _ = self.save_persisted_state();
+        }
+        if self.remote_hosts.is_empty() {
+            self.dialog = None;
+        } else {
+            let new_index = index.min(self.remote_hosts.len() - 1);
+            self.dialog = Some(DialogKind::remote_host_list(
+                self.remote_host_items(),
+                new_index,
+            ));
+        }
+        self.set_toast("Remote host removed");
+    }
+
+    /// URL 입력 확인 (접속 다이얼로그 / Go to Path)
+    pub(super) fn confirm_remote_connect_input(&mut self, input: &str) {
+        match RemoteHost::parse_url(input) {
+            Some(host) => self.connect_remote(host),
+            None => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    format!(
+                        "Invalid remote URL:\n{}\n\nFormat: sftp://user@host:port/path\n        ftp(s)://user:password@host:port/path",
+                        input.trim()
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// 활성 패널을 원격 호스트에 연결 (같은 주소의 기존 세션은 재사용)
+    pub(super) fn connect_remote(&mut self, host: RemoteHost) {
+        let existing = self
+            .remote_panel_view
+            .as_ref()
+            .filter(|v| v.host.same_endpoint(&host))
+            .map(|v| Arc::clone(&v.session));
+        let session = match existing {
+            Some(session) => session,
+            None => match RemoteSession::connect(&host) {
+                Ok(session) => Arc::new(Mutex::new(session)),
+                Err(err) => {
+                    let hint = match host.protocol {
+                        RemoteProtocol::Sftp => "Check the address and your ssh keys/agent (password login is not supported).",
+                        RemoteProtocol::Ftp | RemoteProtocol::Ftps => {
+                            "Check the address, user and password (URL or ~/.netrc)."
+                        }
+                    };
+                    self.dialog = Some(DialogKind::error(
+                        "Error",
+                        Self::format_user_error("Connect", None, &err.to_string(), hint),
+                    ));
+                    return;
+                }
+            },
+        };
+        self.open_remote_view(host, session);
+    }
+
+    /// 연결된 세션으로 활성 패널에 원격 목록 표시
+    pub(super) fn open_remote_view(
+        &mut self,
+        host: RemoteHost,
+        session: Arc<Mutex<RemoteSession>>,
+    ) {
+        let panel = PanelSlot::from(self.active_panel());
+        let backend = RemoteFs::new(host.clone(), Arc::clone(&session));
+        let start_dir = match session.try_lock() {
+            Ok(mut session) => {
+                if host.path.is_empty() {
+                    Ok(session.home().to_string())
+                } else {
+                    session.canonicalize(&host.path)
+                }
+            }
+            Err(_) => Err(Self::remote_busy_error(&host)),
+        };
+        let local_path = self.panel_state_by_slot(panel).current_path.clone();
+        let mounted = start_dir.and_then(|dir| {
+            self.panel_state_by_slot_mut(panel)
+                .mount(Arc::new(backend.clone()), backend.display_path(&dir))
+                .map(|()| dir)
+        });
+        let current_dir = match mounted {
+            Ok(dir) => dir,
+            Err(err) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Connect",
+                        None,
+                        &err.to_string(),
+                        "Check the remote path.",
+                    ),
+                ));
+                return;
+            }
+        };
+
+        // 접속 해제 시 돌아갈 로컬 경로
+        let local_dir = match self.remote_panel_view.as_ref().filter(|v| v.panel == panel) {
+            Some(view) => view.local_dir.clone(),
+            None => match self
+                .archive_panel_view
+                .as_ref()
+                .filter(|v| v.panel == panel)
+            {
+                Some(view) => view.base_dir.clone(),
+                None => local_path,
+            },
+        };
+        if self
+            .archive_panel_view
+            .as_ref()
+            .is_some_and(|v| v.panel == panel)
+        {
+            self.archive_panel_view = None;
+        }
+
+        let view = RemotePanelView {
+            panel,
+            host: host.clone(),
+            current_dir,
+            local_dir,
+            session,
+        };
+        self.remote_panel_view = Some(view);
+        remember_host(&mut self.remote_hosts, host.clone());
+        let _ = self.save_persisted_state();
+        self.dialog = None;
+        self.set_toast(&format!("Connected: {}", host.display_name()));
+    }
+
+    fn remote_busy_error(host: &RemoteHost) -> BokslDirError {
+        remote_error(host, "session is busy with a transfer".to_string())
+    }
+
+    fn remote_change_dir(&mut self, dir: String, focus_name: Option<&str>) {
+        let Some(mut view) = self.remote_panel_view.clone() else {
+            return;
+        };
+        let path = PathBuf::from(view.host.url_with_path(&dir));
+        let result = self
+            .panel_state_by_slot_mut(view.panel)
+            .change_directory_and_focus(path, focus_name, &FileSystem::new());
+        match result {
+            Ok(()) => {
+                view.current_dir = dir;
+                self.remote_panel_view = Some(view);
+            }
+            Err(err) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Open remote directory",
+                        Some(Path::new(&dir)),
+                        &err.to_string(),
+                        "",
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// 원격 패널 상위 이동 (루트에서는 접속 해제 후 로컬 경로로 복귀)
+    pub(super) fn remote_view_go_parent(&mut self) -> bool {
+        let Some(view) = self.active_remote_view().cloned() else {
+            return false;
+        };
+        match remote_parent(&view.current_dir) {
+            Some(parent) => {
+                let name = remote_file_name(&view.current_dir).to_string();
+                self.remote_change_dir(parent, Some(&name));
+            }
+            None => self.disconnect_remote_panel(),
+        }
+        true
+    }
+
+    pub(super) fn remote_view_enter_selected(&mut self) -> bool {
+        let Some(view) = self.active_remote_view() else {
+            return false;
+        };
+        let panel = self.panel_state_by_slot(view.panel);
+        if panel.selected_index == 0 {
+            return self.remote_view_go_parent();
+        }
+        let Some(entry) = panel.selected_entry() else {
+            return true;
+        };
+        if entry.is_directory() {
+            let dir = entry.path.to_string_lossy().to_string();
+            self.remote_change_dir(dir, None);
+        } else {
+            self.set_toast("Copy remote files to a local panel to open them");
+        }
+        true
+    }
+
+    /// 활성 원격 패널 접속 해제
+    pub(super) fn disconnect_remote_panel(&mut self) {
+        let Some(view) = self.active_remote_view().cloned() else {
+            return;
+        };
+        self.remote_panel_view = None;
+        let filesystem = FileSystem::new();
+        let panel = self.panel_state_by_slot_mut(view.panel);
+        panel.unmount();
+        if panel
+            .change_directory(view.local_dir.clone(), &filesystem)
+            .is_err()
+        {
+            let fallback = Self::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+            let _ = panel.change_directory(fallback, &filesystem);
+        }
+        self.set_toast(&format!("Disconnected: {}", view.host.display_name()));
+    }
+
+    /// 패널 경로가 원격 표시 경로와 달라졌으면(탭 전환, 히스토리 이동 등) 원격 상태 해제
+    ///
+    /// 세션 없이 남은 원격 탭은 홈 디렉토리로 되돌립니다. 메인 루프에서 호출합니다.
+    pub fn sync_remote_panel_view(&mut self) {
+        if self.archive_worker.is_some() {
+            return;
+        }
+        if let Some(view) = &self.remote_panel_view {
+            let display_path = PathBuf::from(view.host.url_with_path(&view.current_dir));
+            if self.panel_state_by_slot(view.panel).current_path != display_path {
+                self.remote_panel_view = None;
+            }
+        }
+        for slot in [PanelSlot::Left, PanelSlot::Right] {
+            if self
+                .remote_panel_view
+                .as_ref()
+                .is_some_and(|v| v.panel == slot)
+            {
+                continue;
+            }
+            let panel = self.panel_state_by_slot(slot);
+            if panel.is_mounted() || is_remote_url(&panel.current_path.to_string_lossy()) {
+                let fallback = Self::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+                let filesystem = FileSystem::new();
+                let panel = self.panel_state_by_slot_mut(slot);
+                panel.unmount();
+                let _ = panel.change_directory(fallback, &filesystem);
+            }
+        }
+    }
+
+    // === 원격 복사/이동 (다운로드/업로드) ===
+
+    /// 원격 패널이 관련된 복사/이동이면 대상 입력 다이얼로그를 띄우고 true
+    pub(super) fn start_remote_transfer(&mut self, operation_type: OperationType) -> bool {
+        let (direction, dest) = if self.is_active_panel_remote_view() {
+            let dest = self.inactive_panel_state().current_path.clone();
+            (
+                TransferDirection::Download,
+                dest.to_string_lossy().to_string(),
+            )
+        } else if let Some(view) = self.inactive_remote_view() {
+            (
+                TransferDirection::Upload,
+                view.host.url_with_path(&view.current_dir),
+            )
+        } else {
+            return false;
+        };
+
+        let sources = self.get_operation_sources();
+        if sources.is_empty() {
+            self.dialog = Some(DialogKind::message(
+                "Information",
+                "No files selected for operation.",
+            ));
+            return true;
+        }
+
+        let title = operation_type.name();
+        let prompt = match direction {
+            TransferDirection::Download => "Download to:",
+            TransferDirection::Upload => "Upload to:",
+        };
+        let base_path = match direction {
+            TransferDirection::Download => PathBuf::from(&dest),
+            TransferDirection::Upload => self.active_panel_state().current_path.clone(),
+        };
+        self.remote_flow = Some(RemoteFlowContext::Transfer {
+            direction,
+            remove_source: operation_type == OperationType::Move,
+            sources,
+        });
+        self.dialog = Some(DialogKind::operation_path_input(
+            title, prompt, dest, base_path,
+        ));
+        self.update_input_completion_state();
+        true
+    }
+
+    /// 원격 전송 대상 입력 확인
+    pub(super) fn confirm_remote_transfer(&mut self, input: &str, base_path: &Path) {
+        let Some(RemoteFlowContext::Transfer {
+            direction,
+            remove_source,
+            sources,
+        }) = self.remote_flow.clone()
+        else {
+            self.close_dialog();
+            return;
+        };
+        let Some(view) = self.remote_panel_view.clone() else {
+            self.close_dialog();
+            return;
+        };
+
+        let dest_dir = match direction {
+            TransferDirection::Download => {
+                let resolved = self.resolve_input_path(input, base_path);
+                let display = resolved.to_string_lossy().to_string();
+                if let Err(error_msg) = Self::validate_existing_directory_path(&resolved, &display)
+                {
+                    self.dialog = Some(DialogKind::error("Error", error_msg));
+                    return;
+                }
+                resolved
+            }
+            TransferDirection::Upload => {
+                let dest = Self::resolve_remote_input(&view, input);
+                let is_dir = match view.session.try_lock() {
+                    Ok(mut session) => session.stat(&dest).map(|stat| stat.is_dir),
+                    Err(_) => Err(Self::remote_busy_error(&view.host)),
+                };
+                match is_dir {
+                    Ok(true) => PathBuf::from(dest),
+                    Ok(false) => {
+                        self.dialog = Some(DialogKind::error(
+                            "Error",
+                            format!("Destination is not a directory:\n{}", dest),
+                        ));
+                        return;
+                    }
+                    Err(err) => {
+                        self.dialog = Some(DialogKind::error(
+                            "Error",
+                            format!("Destination path does not exist:\n{}\n\n{}", dest, err),
+                        ));
+                        return;
+                    }
+                }
+            }
+        };
+
+        self.remote_flow = None;
+        let operation_type = if remove_source {
+            OperationType::Move
+        } else {
+            OperationType::Copy
+        };
+        let request = RemoteTransferRequest {
+            direction,
+            sources,
+            dest_dir,
+            remove_source,
+        };
+        self.start_remote_transfer_worker(&view, request, operation_type);
+    }
+
+    /// 업로드 대상 입력을 원격 절대 경로로 변환 (URL/절대/현재 원격 디렉토리 기준 상대 경로)
+    fn resolve_remote_input(view: &RemotePanelView, input: &str) -> String {
+        let input = input.trim();
+        if let Some(host) = RemoteHost::parse_url(input) {
+            return if host.path.is_empty() {
+                view.current_dir.clone()
+            } else {
+                host.path
+            };
+        }
+        if input.is_empty() {
+            view.current_dir.clone()
+        } else if input.starts_with('/') {
+            input.to_string()
+        } else {
+            join_remote(&view.current_dir, input)
+        }
+    }
+
+    fn start_remote_transfer_worker(
+        &mut self,
+        view: &RemotePanelView,
+        request: RemoteTransferRequest,
+        operation_type: OperationType,
+    ) {
+        let (progress_tx, progress_rx) = mpsc::channel::<ArchiveProgressEvent>();
+        let control = Arc::new(ArchiveControl::default());
+        let control_for_worker = Arc::clone(&control);
+        let session = Arc::clone(&view.session);
+        let host_name = view.host.display_name();
+        let kind = match request.direction {
+            TransferDirection::Download => ArchiveWorkerKind::Download,
+            TransferDirection::Upload => ArchiveWorkerKind::Upload,
+        };
+        let waker = self.waker.clone();
+        let handle = std::thread::spawn(move || {
+            let result = match session.lock() {
+                Ok(mut session) => session.transfer(&request, &progress_tx, &control_for_worker),
+                Err(_) => Err(BokslDirError::RemoteFailed {
+                    host: host_name,
+                    reason: "session lock poisoned".to_string(),
+                }),
+            };
+            waker.wake();
+            result
+        });
+
+        let progress = OperationProgress::new(operation_type, 0, 0);
+        self.archive_worker = Some(ArchiveWorkerState {
+            kind,
+            progress_rx,
+            join_handle: Some(handle),
+            control,
+            progress: progress.clone(),
+        });
+        self.dialog = Some(DialogKind::progress(progress));
+    }
+}
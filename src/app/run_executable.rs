@@ -0,0 +1,142 @@
+use super::*;
+
+impl App {
+    // === 실행 파일 실행 (인자 / 환경 변수 입력) ===
+
+    /// 포커스한 실행 파일의 인자 입력 시작 (gx)
+    pub fn start_run_executable(&mut self) {
+        if !self.require_local_panel() {
+            return;
+        }
+        let executable = self
+            .active_panel_state()
+            .selected_entry()
+            .filter(|entry| entry.is_executable())
+            .map(|entry| entry.path.clone());
+        match executable {
+            Some(path) => self.open_run_executable_input(path),
+            None => self.set_toast("Not an executable file"),
+        }
+    }
+
+    /// 실행 파일 인자 입력 열기 (Enter: 실행, Ctrl+O: 출력을 모아 실행)
+    pub(super) fn open_run_executable_input(&mut self, executable: PathBuf) {
+        self.dialog = Some(DialogKind::run_executable_input(executable.clone()));
+        self.on_dialog_outcome(move |app, outcome| match outcome {
+            DialogOutcome::Text(input) => app.confirm_run_executable(&executable, &input, false),
+            DialogOutcome::AlternateText(input) => {
+                app.confirm_run_executable(&executable, &input, true)
+            }
+            DialogOutcome::Accepted | DialogOutcome::Cancelled => {}
+        });
+    }
+
+    /// 입력한 인자로 실행 요청 (`capture`면 출력을 모아 보여줌)
+    pub fn confirm_run_executable(&mut self, executable: &Path, input: &str, capture: bool) {
+        let (env, args) = match parse_run_input(input) {
+            Ok(parsed) => parsed,
+            Err(reason) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Run executable",
+                        Some(executable),
+                        &reason,
+                        "Check quotes in the arguments.",
+                    ),
+                ));
+                return;
+            }
+        };
+        log::info!(
+            "run executable {} ({} args)",
+            executable.display(),
+            args.len()
+        );
+        let working_dir = executable
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| self.active_panel_state().current_path.clone());
+        self.send_command(Command::RunExecutable(ExecutableRunRequest {
+            program: executable.to_path_buf(),
+            args,
+            env,
+            working_dir,
+            capture,
+        }));
+    }
+
+    /// 실행 결과 표시 (캡처 모드는 출력 보기, 아니면 종료 코드 토스트)
+    pub(super) fn apply_executable_run_result(
+        &mut self,
+        request: &ExecutableRunRequest,
+        result: std::result::Result<ExecutableRunOutcome, String>,
+    ) {
+        let name = request
+            .program
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| request.program.to_string_lossy().into_owned());
+        let outcome = match result {
+            Ok(outcome) => outcome,
+            Err(reason) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Run executable",
+                        Some(&request.program),
+                        &reason,
+                        "Check file permissions and the program's interpreter.",
+                    ),
+                ));
+                return;
+            }
+        };
+        let status = match outcome.exit_code {
+            Some(code) => format!("exit code {}", code),
+            None => "terminated by signal".to_string(),
+        };
+        match outcome.output {
+            Some(output) => {
+                let text = if output.trim().is_empty() {
+                    "(no output)".to_string()
+                } else {
+                    output
+                };
+                self.dialog = Some(DialogKind::text_viewer(
+                    format!("{} ({})", name, status),
+                    &text,
+                    false,
+                ));
+            }
+            None => self.set_toast(&format!("{}: {}", name, status)),
+        }
+    }
+}
+
+/// (환경 변수, 인자)
+type RunInput = (Vec<(String, String)>, Vec<String>);
+
+/// 입력 앞부분의 `NAME=value` 단어는 환경 변수로, 나머지는 인자로 나눔
+fn parse_run_input(input: &str) -> std::result::Result<RunInput, String> {
+    let Some(words) = shlex::split(input) else {
+        return Err("Unmatched quotes or escaping.".to_string());
+    };
+    let mut env = Vec::new();
+    let mut words = words.into_iter().peekable();
+    while let Some((name, value)) = words.peek().and_then(|word| env_assignment(word)) {
+        env.push((name, value));
+        words.next();
+    }
+    Ok((env, words.collect()))
+}
+
+fn env_assignment(word: &str) -> Option<(String, String)> {
+    let (name, value) = word.split_once('=')?;
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then(|| (name.to_string(), value.to_string()))
+}
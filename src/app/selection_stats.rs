@@ -0,0 +1,135 @@
+use super::*;
+use crate::utils::display_width::{pad_left, pad_right, truncate_end};
+use crate::utils::formatter::{format_date, format_file_size, pluralize};
+
+/// 크기순으로 보여줄 큰 파일 수
+const LARGEST_FILES: usize = 5;
+/// 확장자 표에 보여줄 최대 줄 수 (나머지는 한 줄로 합침)
+const MAX_EXTENSION_ROWS: usize = 15;
+/// 표의 확장자/이름 칸 너비
+const NAME_COLUMN_WIDTH: usize = 24;
+
+impl App {
+    // === 선택 통계 (확장자별 개수/크기, 큰 파일, 가장 오래된/최근 파일) ===
+
+    /// 선택 항목 통계 표시 (선택이 없으면 현재 디렉토리 전체, 하위 디렉토리 안은 세지 않음)
+    pub fn show_selection_stats(&mut self) {
+        let panel = self.active_panel_state();
+        let (scope, entries) = if panel.selected_items.is_empty() {
+            (
+                panel.current_path.display().to_string(),
+                panel.entries.iter().collect::<Vec<_>>(),
+            )
+        } else {
+            (
+                format!("Selection in {}", panel.current_path.display()),
+                panel.selected_entries(),
+            )
+        };
+        if entries.is_empty() {
+            self.dialog = Some(DialogKind::message("Statistics", "No items to summarize."));
+            return;
+        }
+        let report = selection_stats_report(&scope, &entries, &panel.dir_sizes);
+        self.dialog = Some(DialogKind::text_viewer("Statistics", &report, false));
+    }
+}
+
+/// 통계 본문 (요약, 확장자별 표, 큰 파일, 가장 오래된/최근 파일)
+///
+/// 디렉토리 크기는 크기 계산을 해 둔 경우에만 합계에 넣습니다.
+fn selection_stats_report(
+    scope: &str,
+    entries: &[&FileEntry],
+    dir_sizes: &HashMap<PathBuf, u64>,
+) -> String {
+    let files: Vec<&FileEntry> = entries
+        .iter()
+        .copied()
+        .filter(|entry| !entry.is_directory())
+        .collect();
+    let dir_count = entries.len() - files.len();
+    let dir_bytes: u64 = entries
+        .iter()
+        .filter(|entry| entry.is_directory())
+        .filter_map(|entry| dir_sizes.get(&entry.path))
+        .sum();
+    let file_bytes: u64 = files.iter().map(|entry| entry.size).sum();
+
+    let mut text = format!(
+        "{}\n{}, {}, {}\n",
+        scope,
+        pluralize(files.len(), "file", "files"),
+        pluralize(dir_count, "directory", "directories"),
+        format_file_size(file_bytes + dir_bytes)
+    );
+    if files.is_empty() {
+        return text;
+    }
+
+    // 확장자별 (개수, 크기), 크기가 큰 순
+    let mut by_extension: HashMap<String, (usize, u64)> = HashMap::new();
+    for entry in &files {
+        let extension = Path::new(&entry.name)
+            .extension()
+            .map(|ext| format!(".{}", ext.to_string_lossy().to_lowercase()))
+            .unwrap_or_else(|| "(none)".to_string());
+        let stat = by_extension.entry(extension).or_default();
+        stat.0 += 1;
+        stat.1 += entry.size;
+    }
+    let mut rows: Vec<(String, usize, u64)> = by_extension
+        .into_iter()
+        .map(|(extension, (count, size))| (extension, count, size))
+        .collect();
+    rows.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.cmp(&a.1)).then(a.0.cmp(&b.0)));
+    if rows.len() > MAX_EXTENSION_ROWS {
+        let rest = rows.split_off(MAX_EXTENSION_ROWS - 1);
+        let count = rest.iter().map(|row| row.1).sum();
+        let size = rest.iter().map(|row| row.2).sum();
+        rows.push((format!("({} more)", rest.len()), count, size));
+    }
+    text.push_str(&format!(
+        "\n{}{}{}\n",
+        pad_right("Extension", NAME_COLUMN_WIDTH),
+        pad_left("Files", 7),
+        pad_left("Size", 12)
+    ));
+    for (extension, count, size) in &rows {
+        text.push_str(&format!(
+            "{}{}{}\n",
+            pad_right(
+                &truncate_end(extension, NAME_COLUMN_WIDTH - 1, "..."),
+                NAME_COLUMN_WIDTH
+            ),
+            pad_left(&count.to_string(), 7),
+            pad_left(&format_file_size(*size), 12)
+        ));
+    }
+
+    let mut largest = files.clone();
+    largest.sort_by(|a, b| b.size.cmp(&a.size).then(a.name.cmp(&b.name)));
+    text.push_str("\nLargest files\n");
+    for entry in largest.iter().take(LARGEST_FILES) {
+        text.push_str(&format!(
+            "{}  {}\n",
+            pad_left(&format_file_size(entry.size), 10),
+            entry.name
+        ));
+    }
+
+    let oldest = files.iter().min_by_key(|entry| entry.modified);
+    let newest = files.iter().max_by_key(|entry| entry.modified);
+    text.push('\n');
+    for (label, entry) in [("Oldest", oldest), ("Newest", newest)] {
+        if let Some(entry) = entry {
+            text.push_str(&format!(
+                "{}  {}  {}\n",
+                pad_right(label, 8),
+                format_date(entry.modified),
+                entry.name
+            ));
+        }
+    }
+    text
+}
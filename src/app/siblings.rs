@@ -0,0 +1,70 @@
+use super::*;
+use crate::models::file_entry::FileType;
+use crate::ui::{FuzzyListItem, FuzzyListPurpose};
+
+impl App {
+    // === 형제 디렉토리 전환 (상위 디렉토리의 다른 하위 디렉토리) ===
+
+    /// 형제 디렉토리 목록 열기 (현재 디렉토리 제외, 이름순, 숨김은 패널 설정을 따름)
+    pub fn show_sibling_list(&mut self) {
+        if !self.require_local_panel() {
+            return;
+        }
+        let panel = self.active_panel_state();
+        let current = panel.current_path.clone();
+        let show_hidden = panel.show_hidden;
+        let Some(parent) = current.parent() else {
+            self.set_toast("No parent directory");
+            return;
+        };
+        let mut siblings: Vec<FileEntry> = match self.filesystem.read_directory(parent) {
+            Ok(entries) => entries
+                .into_iter()
+                .filter(|entry| {
+                    entry.path != current
+                        && (show_hidden || !entry.is_hidden)
+                        && (entry.is_directory()
+                            || (entry.file_type == FileType::Symlink && entry.path.is_dir()))
+                })
+                .collect(),
+            Err(e) => {
+                self.dialog = Some(DialogKind::error(
+                    "Error",
+                    Self::format_user_error(
+                        "Sibling directories",
+                        Some(parent),
+                        &e.to_string(),
+                        "",
+                    ),
+                ));
+                return;
+            }
+        };
+        if siblings.is_empty() {
+            self.set_toast("No sibling directories");
+            return;
+        }
+        siblings.sort_by_cached_key(|entry| entry.name.to_lowercase());
+        let items = siblings
+            .into_iter()
+            .map(|entry| FuzzyListItem {
+                label: entry.name,
+                detail: String::new(),
+            })
+            .collect();
+        self.dialog = Some(DialogKind::fuzzy_list(
+            "Switch to sibling directory",
+            FuzzyListPurpose::Siblings,
+            items,
+        ));
+    }
+
+    /// 고른 형제 디렉토리로 이동
+    pub(super) fn jump_to_sibling(&mut self, name: &str) {
+        let Some(parent) = self.active_panel_state().current_path.parent() else {
+            return;
+        };
+        let target = parent.join(name);
+        self.change_active_dir(target, true, None);
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+impl App {
+    // === 동기 탐색 (활성 패널 이동을 반대 패널에 그대로 반영) ===
+
+    pub fn is_sync_browsing(&self) -> bool {
+        self.sync_browsing
+    }
+
+    /// 동기 탐색 모드 전환 (gb)
+    pub(super) fn toggle_sync_browsing(&mut self) {
+        self.sync_browsing = !self.sync_browsing;
+        self.set_toast(if self.sync_browsing {
+            "Sync browsing: on"
+        } else {
+            "Sync browsing: off"
+        });
+    }
+
+    /// 압축/원격 보기 중에는 경로 구조가 달라 동기 탐색하지 않음
+    fn sync_browsing_applies(&self) -> bool {
+        self.sync_browsing && self.archive_panel_view.is_none() && self.remote_panel_view.is_none()
+    }
+
+    /// 활성 패널이 하위 디렉토리 `name`으로 들어간 뒤 반대 패널도 같은 이름으로 진입
+    pub(super) fn sync_enter_directory(&mut self, name: &OsStr) {
+        if !self.sync_browsing_applies() {
+            return;
+        }
+        let target = self.inactive_panel_state().current_path.join(name);
+        if !target.is_dir() {
+            self.set_toast("Sync browsing: no matching directory in other panel");
+            return;
+        }
+        self.change_inactive_dir(target, None);
+    }
+
+    /// 활성 패널이 상위로 이동한 뒤 반대 패널도 상위로 이동
+    pub(super) fn sync_go_to_parent(&mut self) {
+        if !self.sync_browsing_applies() {
+            return;
+        }
+        let current = self.inactive_panel_state().current_path.clone();
+        let Some(parent) = current.parent() else {
+            return;
+        };
+        let focus = current
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.to_string());
+        self.change_inactive_dir(parent.to_path_buf(), focus.as_deref());
+    }
+
+    /// 반대 패널 경로 변경 (히스토리 기록, 빈도 기록은 하지 않음)
+    pub(super) fn change_inactive_dir(&mut self, path: PathBuf, focus_name: Option<&str>) {
+        let panel = match self.active_panel() {
+            ActivePanel::Left => self.right_tabs.active_mut(),
+            ActivePanel::Right => self.left_tabs.active_mut(),
+        };
+        if panel
+            .change_directory_and_focus(path.clone(), focus_name, &self.filesystem)
+            .is_ok()
+        {
+            panel.record_history(path);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+impl App {
+    // === 탭 이름 / 순서 / 반대 패널로 보내기 ===
+
+    fn tabs_for(&mut self, panel: ActivePanel) -> &mut PanelTabs {
+        match panel {
+            ActivePanel::Left => &mut self.left_tabs,
+            ActivePanel::Right => &mut self.right_tabs,
+        }
+    }
+
+    fn inactive_panel(&self) -> ActivePanel {
+        match self.active_panel() {
+            ActivePanel::Left => ActivePanel::Right,
+            ActivePanel::Right => ActivePanel::Left,
+        }
+    }
+
+    /// 활성 탭 이름 변경 입력 시작 (현재 사용자 지정 이름으로 채움)
+    pub fn start_tab_rename(&mut self) {
+        self.dialog = Some(self.tab_rename_dialog());
+        self.on_dialog_text(|app, value| app.confirm_tab_rename(&value));
+    }
+
+    fn tab_rename_dialog(&self) -> DialogKind {
+        let initial = self
+            .active_panel_state()
+            .tab_title
+            .clone()
+            .unwrap_or_default();
+        DialogKind::tab_rename_input(initial)
+    }
+
+    /// 활성 탭 이름 적용 (비우면 경로 이름으로 되돌림)
+    pub fn confirm_tab_rename(&mut self, value: &str) {
+        let i18n = I18n::new(self.language);
+        let trimmed = value.trim();
+        let title = (!trimmed.is_empty()).then(|| trimmed.to_string());
+        let cleared = title.is_none();
+        self.active_panel_state_mut().tab_title = title;
+        // 탭 목록에서 바꿨으면 목록의 이름도 갱신
+        if matches!(self.dialog, Some(DialogKind::TabList { .. })) {
+            self.show_tab_list();
+        }
+        self.set_toast(i18n.msg(if cleared {
+            MessageKey::TabNameCleared
+        } else {
+            MessageKey::TabRenamed
+        }));
+    }
+
+    /// 활성 탭을 왼쪽(`forward`가 false)/오른쪽으로 한 칸 이동
+    pub fn move_tab_active_panel(&mut self, forward: bool) {
+        let i18n = I18n::new(self.language);
+        let panel = self.active_panel();
+        let tabs = self.tabs_for(panel);
+        if !tabs.move_active(forward) {
+            self.set_toast(i18n.msg(MessageKey::TabCannotMove));
+            return;
+        }
+        let index = tabs.active_index();
+        self.set_toast(&i18n.fmt(MessageKey::TabMoved, &[("index", (index + 1).to_string())]));
+    }
+
+    /// 포커스한 디렉토리를 반대 패널의 새 탭으로 열기 (현재 패널은 그대로)
+    pub fn open_focused_dir_in_other_panel_tab(&mut self) {
+        let i18n = I18n::new(self.language);
+        if !self.require_local_panels() {
+            return;
+        }
+        let Some(path) = self
+            .active_panel_state()
+            .selected_entry()
+            .filter(|entry| entry.is_directory())
+            .map(|entry| entry.path.clone())
+        else {
+            self.set_toast("Focus a directory to open in the other panel");
+            return;
+        };
+        let target = self.inactive_panel();
+        let max_tabs = self.max_tabs_per_panel;
+        if self.tabs_for(target).len() >= max_tabs {
+            self.set_toast(&i18n.fmt(
+                MessageKey::MaxTabsPerPanel,
+                &[("count", max_tabs.to_string())],
+            ));
+            return;
+        }
+
+        let mut tab = PanelState::new(path.clone());
+        tab.collation = self.sort_collation;
+        tab.dirs_first = self.dirs_first;
+        tab.hide_rules = self.hide_rules();
+        tab.show_hidden = self.active_panel_state().show_hidden;
+        tab.record_history(path);
+        let _ = tab.refresh(&self.filesystem);
+        let index = self.tabs_for(target).create_tab(&tab);
+        self.set_toast(&i18n.fmt(
+            MessageKey::TabOpenedInOtherPanel,
+            &[("index", (index + 1).to_string())],
+        ));
+    }
+
+    /// 활성 탭을 반대 패널에 복제 (`send`면 현재 패널에서는 닫음)
+    pub fn transfer_tab_to_other_panel(&mut self, send: bool) {
+        let i18n = I18n::new(self.language);
+        // 압축/원격 보기는 패널 단위 상태라 탭만 옮길 수 없음
+        if !self.require_local_panels() {
+            return;
+        }
+        let source = self.active_panel();
+        let target = self.inactive_panel();
+        let max_tabs = self.max_tabs_per_panel;
+        if self.tabs_for(target).len() >= max_tabs {
+            self.set_toast(&i18n.fmt(
+                MessageKey::MaxTabsPerPanel,
+                &[("count", max_tabs.to_string())],
+            ));
+            return;
+        }
+
+        let tab = if send {
+            match self.tabs_for(source).take_active_tab() {
+                Some(tab) => tab,
+                None => {
+                    self.set_toast(i18n.msg(MessageKey::CannotCloseLastTab));
+                    return;
+                }
+            }
+        } else {
+            self.active_panel_state().clone()
+        };
+        let index = self.tabs_for(target).create_tab(&tab);
+        self.set_toast(&i18n.fmt(
+            if send {
+                MessageKey::TabSentToOtherPanel
+            } else {
+                MessageKey::TabCopiedToOtherPanel
+            },
+            &[("index", (index + 1).to_string())],
+        ));
+    }
+}
+
+impl App {
+    // === 탭 목록 다이얼로그에서의 탭 조작 ===
+
+    /// 탭 목록에서 선택된 탭을 활성 탭으로 전환 (토스트 없음)
+    fn activate_selected_list_tab(&mut self) -> bool {
+        let Some(index) = (match &self.dialog {
+            Some(DialogKind::TabList { list }) => list.selected_index(),
+            _ => None,
+        }) else {
+            return false;
+        };
+        let panel = self.active_panel();
+        self.tabs_for(panel).switch_to(index)
+    }
+
+    /// 탭 목록에서 선택된 탭 이름 변경 (입력 창을 닫으면 탭 목록으로 복귀)
+    pub fn tab_list_rename(&mut self) {
+        if self.activate_selected_list_tab() {
+            self.push_dialog(self.tab_rename_dialog());
+            self.on_dialog_text(|app, value| app.confirm_tab_rename(&value));
+        }
+    }
+
+    /// 탭 목록에서 선택된 탭 순서 이동 후 목록 갱신
+    pub fn tab_list_move(&mut self, forward: bool) {
+        if self.activate_selected_list_tab() {
+            self.move_tab_active_panel(forward);
+            self.show_tab_list();
+        }
+    }
+
+    /// 탭 목록에서 선택된 탭을 반대 패널로 복제/보내기 후 목록 갱신
+    pub fn tab_list_transfer(&mut self, send: bool) {
+        if self.activate_selected_list_tab() {
+            self.transfer_tab_to_other_panel(send);
+            self.show_tab_list();
+        }
+    }
+}
@@ -1,11 +1,70 @@
 pub(super) struct TextBufferEdit;
 
+// 한글 조합형 자모 (U+1100 초성, U+1161 중성, U+11A7 종성 기준)
+const HANGUL_SYLLABLE_BASE: u32 = 0xAC00;
+const HANGUL_SYLLABLE_LAST: u32 = 0xD7A3;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+
 impl TextBufferEdit {
+    /// 문자 삽입
+    ///
+    /// 제어 문자는 무시하고, 조합형 자모(NFD: macOS 붙여넣기, 일부 터미널 IME)는
+    /// 직전 글자와 합쳐 완성형 음절로 저장합니다.
     pub(super) fn insert_char(value: &mut String, cursor_pos: &mut usize, c: char) {
+        if c.is_control() {
+            return;
+        }
+
+        if *cursor_pos > 0 {
+            let prev_start = Self::prev_char_start(value, *cursor_pos);
+            let prev = value[prev_start..*cursor_pos]
+                .chars()
+                .next()
+                .unwrap_or_default();
+            if let Some(composed) = Self::compose_hangul(prev, c) {
+                value.replace_range(prev_start..*cursor_pos, composed.encode_utf8(&mut [0; 4]));
+                *cursor_pos = prev_start + composed.len_utf8();
+                return;
+            }
+        }
+
         value.insert(*cursor_pos, c);
         *cursor_pos += c.len_utf8();
     }
 
+    /// 문자열 삽입 (붙여넣기/IME 확정 문자열)
+    pub(super) fn insert_str(value: &mut String, cursor_pos: &mut usize, text: &str) {
+        for c in text.chars() {
+            Self::insert_char(value, cursor_pos, c);
+        }
+    }
+
+    /// 초성+중성 → LV 음절, LV 음절+종성 → LVT 음절
+    fn compose_hangul(prev: char, next: char) -> Option<char> {
+        let (prev, next) = (prev as u32, next as u32);
+        if (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&prev)
+            && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&next)
+        {
+            let l_index = prev - HANGUL_L_BASE;
+            let v_index = next - HANGUL_V_BASE;
+            return char::from_u32(
+                HANGUL_SYLLABLE_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT,
+            );
+        }
+        if (HANGUL_SYLLABLE_BASE..=HANGUL_SYLLABLE_LAST).contains(&prev)
+            && (prev - HANGUL_SYLLABLE_BASE).is_multiple_of(HANGUL_T_COUNT)
+            && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&next)
+        {
+            return char::from_u32(prev + (next - HANGUL_T_BASE));
+        }
+        None
+    }
+
     pub(super) fn backspace(value: &mut String, cursor_pos: &mut usize) {
         if *cursor_pos == 0 {
             return;
@@ -116,9 +175,64 @@ impl TextBufferEdit {
     }
 }
 
+/// 텍스트 입력 편집 동작 (키 하나에 해당)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEdit {
+    Insert(char),
+    Backspace,
+    Delete,
+    /// 이전 단어 삭제 (Ctrl+W)
+    DeletePrevWord,
+    Left,
+    Right,
+    Home,
+    End,
+}
+
+impl TextEdit {
+    /// 값이 바뀌는 편집이면 true (커서 이동은 false)
+    pub(super) fn modifies(self) -> bool {
+        matches!(
+            self,
+            Self::Insert(_) | Self::Backspace | Self::Delete | Self::DeletePrevWord
+        )
+    }
+}
+
+/// 다이얼로그 텍스트 입력 필드 (다이얼로그가 가진 값과 바이트 단위 커서를 빌려 편집)
+pub(super) struct TextInputState<'a> {
+    value: &'a mut String,
+    cursor_pos: &'a mut usize,
+}
+
+impl<'a> TextInputState<'a> {
+    pub(super) fn new(value: &'a mut String, cursor_pos: &'a mut usize) -> Self {
+        Self { value, cursor_pos }
+    }
+
+    pub(super) fn apply(&mut self, edit: TextEdit) {
+        let (value, cursor_pos) = (&mut *self.value, &mut *self.cursor_pos);
+        match edit {
+            TextEdit::Insert(c) => TextBufferEdit::insert_char(value, cursor_pos, c),
+            TextEdit::Backspace => TextBufferEdit::backspace(value, cursor_pos),
+            TextEdit::Delete => TextBufferEdit::delete(value, cursor_pos),
+            TextEdit::DeletePrevWord => TextBufferEdit::delete_prev_word(value, cursor_pos),
+            TextEdit::Left => TextBufferEdit::left(value, cursor_pos),
+            TextEdit::Right => TextBufferEdit::right(value, cursor_pos),
+            TextEdit::Home => TextBufferEdit::home(cursor_pos),
+            TextEdit::End => TextBufferEdit::end(value, cursor_pos),
+        }
+    }
+
+    /// 붙여넣기/IME 확정 문자열 삽입
+    pub(super) fn insert_str(&mut self, text: &str) {
+        TextBufferEdit::insert_str(self.value, self.cursor_pos, text);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::TextBufferEdit;
+    use super::{TextBufferEdit, TextEdit, TextInputState};
 
     #[test]
     fn test_insert_backspace_delete_utf8_cursor_boundary() {
@@ -160,6 +274,43 @@ mod tests {
         assert_eq!(cursor_pos, value.len());
     }
 
+    #[test]
+    fn test_insert_composes_conjoining_jamo() {
+        let mut value = String::new();
+        let mut cursor_pos = 0;
+
+        // "한글" NFD: ᄒ ᅡ ᆫ ᄀ ᅳ ᆯ
+        TextBufferEdit::insert_str(
+            &mut value,
+            &mut cursor_pos,
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}.txt",
+        );
+        assert_eq!(value, "\u{D55C}\u{AE00}.txt");
+        assert_eq!(cursor_pos, value.len());
+
+        // 완성형 음절과 제어 문자
+        let mut value = "a".to_string();
+        let mut cursor_pos = 1;
+        TextBufferEdit::insert_str(&mut value, &mut cursor_pos, "\u{AC00}\n\t\u{B098}");
+        assert_eq!(value, "a\u{AC00}\u{B098}");
+        assert_eq!(cursor_pos, value.len());
+    }
+
+    #[test]
+    fn test_text_input_state_applies_edits() {
+        let mut value = "ab".to_string();
+        let mut cursor_pos = 1;
+        let mut input = TextInputState::new(&mut value, &mut cursor_pos);
+        input.apply(TextEdit::Insert('x'));
+        input.apply(TextEdit::End);
+        input.apply(TextEdit::Backspace);
+        input.insert_str("/c d");
+        input.apply(TextEdit::DeletePrevWord);
+        assert_eq!(value, "ax/c ");
+        assert_eq!(cursor_pos, value.len());
+        assert!(!TextEdit::Left.modifies());
+    }
+
     #[test]
     fn test_delete_prev_word_utf8() {
         let mut value = "/tmp/\u{D55C}\u{AE00} \u{D3F4}\u{B354}/test".to_string();
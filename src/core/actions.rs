@@ -22,24 +22,42 @@ pub enum Action {
     PageUp,
     PageDown,
     TogglePanel,
+    SwapPanels,
+    SyncOtherPanel,
     ToggleLayout,
     TabNew,
     TabClose,
+    TabRename,
+    TabMoveLeft,
+    TabMoveRight,
+    TabCopyToOtherPanel,
+    TabSendToOtherPanel,
+    TabOpenInOtherPanel,
     // File Operations
     Copy,
     Move,
     OpenDefaultApp,
     OpenTerminalEditor,
-    RunShellCommand,
+    RevealInFileManager,
+    RunExecutable,
+    GitShowDiff,
+    GitDiscardChanges,
+    RunShell,
     Delete,
     PermanentDelete,
     MakeDirectory,
+    NewFile,
     Rename,
+    RenameViaEditor,
     ShowProperties,
     ArchiveCompress,
     ArchiveExtract,
     ArchiveExtractAuto,
     ArchivePreview,
+    ForgetArchivePasswords,
+    ArchiveNameEncoding,
+    CompareFiles,
+    SyncPanels,
     // Selection
     ToggleSelection,
     InvertSelection,
@@ -49,11 +67,15 @@ pub enum Action {
     ShowHelp,
     Refresh,
     OpenMenu,
+    OpenContextMenu,
+    ShowCommandPalette,
+    ShowPluginActions,
     Quit,
     // Theme (메뉴 전용)
     ThemeDark,
     ThemeLight,
     ThemeContrast,
+    ShowThemeList,
     SetLanguageEnglish,
     SetLanguageKorean,
     // Sort (Phase 5.1)
@@ -63,25 +85,57 @@ pub enum Action {
     SortByExt,
     SortAscending,
     SortDescending,
+    ToggleNaturalSort,
+    ToggleLocaleSort,
+    ToggleDirsFirst,
     // Filter (Phase 5.2)
     StartFilter,
     ClearFilter,
     // Settings
     ToggleIconMode,
+    ToggleGitStatus,
+    ToggleInfoLine,
+    TogglePathLine,
+    ToggleTypeColumn,
+    SetAutoRefresh,
+    ToggleCopyVerify,
+    ToggleCopyPreserveTimestamps,
+    ToggleCopyPreservePermissions,
+    ToggleCopyPreserveXattrs,
+    ToggleCopyUpdateOnly,
     SetDefaultEditorVi,
     SetDefaultEditorVim,
     SetDefaultEditorNano,
     SetDefaultEditorEmacs,
     // View (Phase 5.3)
     ToggleHidden,
+    ToggleHiddenBoth,
+    ToggleHidePatterns,
+    ShowSelectionStats,
+    EditHidePatterns,
+    ToggleSyncBrowsing,
+    ToggleFollowFocus,
     ShowMountPoints,
+    AnalyzeDiskUsage,
     GoToPath,
+    ConnectRemote,
     ShowTabList,
     HistoryBack,
     HistoryForward,
+    ScrollNameLeft,
+    ScrollNameRight,
+    ShowBreadcrumbs,
     ShowHistoryList,
+    ShowJumpList,
+    ShowSiblingList,
+    FindFile,
     AddBookmark,
     ShowBookmarkList,
+    ExportBookmarks,
+    ImportBookmarks,
+    SaveSession,
+    ShowSessionList,
+    ShowJobList,
     SizeFormatAuto,
     SizeFormatBytes,
     // About
@@ -233,6 +287,22 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("Ctrl+W"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::SwapPanels,
+        id: "swap_panels",
+        label: "Swap left and right panels",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("Ctrl+S"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::SyncOtherPanel,
+        id: "sync_other_panel",
+        label: "Other panel to current directory",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("g="),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::TabNew,
         id: "tab_new",
@@ -249,6 +319,54 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("tx"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::TabRename,
+        id: "tab_rename",
+        label: "Rename tab",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("tr"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::TabMoveLeft,
+        id: "tab_move_left",
+        label: "Move tab left",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("t["),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::TabMoveRight,
+        id: "tab_move_right",
+        label: "Move tab right",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("t]"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::TabCopyToOtherPanel,
+        id: "tab_copy_other",
+        label: "Duplicate tab to other panel",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("tc"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::TabSendToOtherPanel,
+        id: "tab_send_other",
+        label: "Send tab to other panel",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("tm"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::TabOpenInOtherPanel,
+        id: "tab_open_other",
+        label: "Open directory in other panel tab",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("Shift+Enter"),
+        command_bar: None,
+    },
     // File Operations
     ActionDef {
         action: Action::Copy,
@@ -282,6 +400,38 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("o"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::RevealInFileManager,
+        id: "reveal_in_file_manager",
+        label: "Reveal in file manager",
+        category: ActionCategory::FileOperation,
+        shortcut_display: Some("gr"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::RunExecutable,
+        id: "run_executable",
+        label: "Run executable...",
+        category: ActionCategory::FileOperation,
+        shortcut_display: Some("gx"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::GitShowDiff,
+        id: "git_diff",
+        label: "Show git diff",
+        category: ActionCategory::FileOperation,
+        shortcut_display: Some("gd"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::GitDiscardChanges,
+        id: "git_discard",
+        label: "Discard git changes",
+        category: ActionCategory::FileOperation,
+        shortcut_display: None,
+        command_bar: None,
+    },
     ActionDef {
         action: Action::OpenTerminalEditor,
         id: "open_terminal_editor",
@@ -291,11 +441,11 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         command_bar: None,
     },
     ActionDef {
-        action: Action::RunShellCommand,
+        action: Action::RunShell,
         id: "run_shell_command",
         label: "Run shell command",
         category: ActionCategory::FileOperation,
-        shortcut_display: Some(":"),
+        shortcut_display: Some("!"),
         command_bar: None,
     },
     ActionDef {
@@ -334,6 +484,14 @@ pub static ACTION_DEFS: &[ActionDef] = &[
             priority: 13,
         }),
     },
+    ActionDef {
+        action: Action::NewFile,
+        id: "new_file",
+        label: "New file",
+        category: ActionCategory::FileOperation,
+        shortcut_display: Some("A"),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::Rename,
         id: "rename",
@@ -346,6 +504,14 @@ pub static ACTION_DEFS: &[ActionDef] = &[
             priority: 14,
         }),
     },
+    ActionDef {
+        action: Action::RenameViaEditor,
+        id: "rename_via_editor",
+        label: "Rename via editor",
+        category: ActionCategory::FileOperation,
+        shortcut_display: None,
+        command_bar: None,
+    },
     ActionDef {
         action: Action::ShowProperties,
         id: "file_info",
@@ -390,6 +556,38 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: None,
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ForgetArchivePasswords,
+        id: "forget_archive_passwords",
+        label: "Forget archive passwords",
+        category: ActionCategory::FileOperation,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ArchiveNameEncoding,
+        id: "archive_name_encoding",
+        label: "Archive name encoding",
+        category: ActionCategory::FileOperation,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::CompareFiles,
+        id: "compare_files",
+        label: "Compare files",
+        category: ActionCategory::FileOperation,
+        shortcut_display: Some("="),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::SyncPanels,
+        id: "sync_panels",
+        label: "Synchronize panels",
+        category: ActionCategory::FileOperation,
+        shortcut_display: None,
+        command_bar: None,
+    },
     // Selection
     ActionDef {
         action: Action::ToggleSelection,
@@ -472,6 +670,30 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("F9"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::OpenContextMenu,
+        id: "context_menu",
+        label: "Context menu",
+        category: ActionCategory::System,
+        shortcut_display: Some("m"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ShowCommandPalette,
+        id: "command_palette",
+        label: "Command palette",
+        category: ActionCategory::System,
+        shortcut_display: Some(": / Ctrl+Shift+P"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ShowPluginActions,
+        id: "plugin_actions",
+        label: "Plugin actions",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
     ActionDef {
         action: Action::Quit,
         id: "quit",
@@ -509,6 +731,14 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: None,
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ShowThemeList,
+        id: "theme_list",
+        label: "Theme list",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
     ActionDef {
         action: Action::SetLanguageEnglish,
         id: "language_en",
@@ -534,6 +764,86 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: None,
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ToggleGitStatus,
+        id: "toggle_git_status",
+        label: "Toggle git status",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::SetAutoRefresh,
+        id: "auto_refresh",
+        label: "Auto refresh interval",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleInfoLine,
+        id: "toggle_info_line",
+        label: "Toggle info line",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::TogglePathLine,
+        id: "toggle_path_line",
+        label: "Toggle full path line",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleTypeColumn,
+        id: "toggle_type_column",
+        label: "Toggle type column",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleCopyVerify,
+        id: "copy_verify",
+        label: "Toggle verify after copy",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleCopyPreserveTimestamps,
+        id: "copy_preserve_times",
+        label: "Toggle preserve timestamps",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleCopyPreservePermissions,
+        id: "copy_preserve_permissions",
+        label: "Toggle preserve permissions",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleCopyPreserveXattrs,
+        id: "copy_preserve_xattrs",
+        label: "Toggle preserve extended attributes",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleCopyUpdateOnly,
+        id: "copy_update_only",
+        label: "Toggle update only newer",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
     ActionDef {
         action: Action::SetDefaultEditorVi,
         id: "editor_preset_vi",
@@ -615,6 +925,30 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: None,
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ToggleNaturalSort,
+        id: "sort_natural",
+        label: "Toggle natural sort",
+        category: ActionCategory::Sort,
+        shortcut_display: Some("sv"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleLocaleSort,
+        id: "sort_locale",
+        label: "Toggle locale-aware sort",
+        category: ActionCategory::Sort,
+        shortcut_display: Some("sl"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleDirsFirst,
+        id: "sort_dirs_first",
+        label: "Toggle directories first",
+        category: ActionCategory::Sort,
+        shortcut_display: Some("sf"),
+        command_bar: None,
+    },
     // Filter (Phase 5.2)
     ActionDef {
         action: Action::StartFilter,
@@ -645,6 +979,54 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("."),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ToggleHiddenBoth,
+        id: "toggle_hidden_both",
+        label: "Toggle hidden files in both panels",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ShowSelectionStats,
+        id: "selection_stats",
+        label: "Selection statistics",
+        category: ActionCategory::FileOperation,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleHidePatterns,
+        id: "toggle_hide_patterns",
+        label: "Toggle pattern-hidden files",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::EditHidePatterns,
+        id: "edit_hide_patterns",
+        label: "Edit hide patterns",
+        category: ActionCategory::System,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleSyncBrowsing,
+        id: "toggle_sync_browsing",
+        label: "Toggle synchronized browsing",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("gb"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ToggleFollowFocus,
+        id: "toggle_follow_focus",
+        label: "Follow focused directory in other panel",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("gf"),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::ShowMountPoints,
         id: "mount_points",
@@ -653,6 +1035,14 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("gm"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::AnalyzeDiskUsage,
+        id: "disk_usage",
+        label: "Analyze disk usage",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("gu"),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::GoToPath,
         id: "goto_path",
@@ -661,6 +1051,14 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("gp"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ConnectRemote,
+        id: "remote_connect",
+        label: "Connect to remote host",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("gs"),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::ShowTabList,
         id: "tab_list",
@@ -685,6 +1083,30 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("Alt+Right"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ScrollNameLeft,
+        id: "scroll_name_left",
+        label: "Scroll name left",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("Shift+Left"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ScrollNameRight,
+        id: "scroll_name_right",
+        label: "Scroll name right",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("Shift+Right"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ShowBreadcrumbs,
+        id: "breadcrumbs",
+        label: "Pick path segment",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("gc"),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::ShowHistoryList,
         id: "history_list",
@@ -693,6 +1115,30 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("th"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ShowJumpList,
+        id: "jump_list",
+        label: "Jump to frequent directory",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("gj"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ShowSiblingList,
+        id: "sibling_list",
+        label: "Switch to sibling directory",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("gn"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::FindFile,
+        id: "find_file",
+        label: "Find file",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("Ctrl+P"),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::AddBookmark,
         id: "bookmark_add",
@@ -709,6 +1155,46 @@ pub static ACTION_DEFS: &[ActionDef] = &[
         shortcut_display: Some("tb"),
         command_bar: None,
     },
+    ActionDef {
+        action: Action::ExportBookmarks,
+        id: "bookmark_export",
+        label: "Export bookmarks...",
+        category: ActionCategory::Navigation,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ImportBookmarks,
+        id: "bookmark_import",
+        label: "Import bookmarks...",
+        category: ActionCategory::Navigation,
+        shortcut_display: None,
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::SaveSession,
+        id: "session_save",
+        label: "Save session as...",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("tw"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ShowSessionList,
+        id: "session_list",
+        label: "Show session list",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("ts"),
+        command_bar: None,
+    },
+    ActionDef {
+        action: Action::ShowJobList,
+        id: "job_list",
+        label: "Show recent background jobs",
+        category: ActionCategory::Navigation,
+        shortcut_display: Some("tj"),
+        command_bar: None,
+    },
     ActionDef {
         action: Action::SizeFormatAuto,
         id: "size_auto",
@@ -760,11 +1246,21 @@ fn build_key_bindings() -> Vec<KeyBinding> {
             modifiers: Some(KeyModifiers::CONTROL),
             action: Action::ToggleLayout,
         },
+        KeyBinding {
+            code: KeyCode::Char('s'),
+            modifiers: Some(KeyModifiers::CONTROL),
+            action: Action::SwapPanels,
+        },
         KeyBinding {
             code: KeyCode::F(9),
             modifiers: None,
             action: Action::OpenMenu,
         },
+        KeyBinding {
+            code: KeyCode::Char('m'),
+            modifiers: Some(KeyModifiers::NONE),
+            action: Action::OpenContextMenu,
+        },
         // 탐색: Vim
         KeyBinding {
             code: KeyCode::Char('j'),
@@ -806,6 +1302,16 @@ fn build_key_bindings() -> Vec<KeyBinding> {
             modifiers: Some(KeyModifiers::ALT),
             action: Action::HistoryForward,
         },
+        KeyBinding {
+            code: KeyCode::Left,
+            modifiers: Some(KeyModifiers::SHIFT),
+            action: Action::ScrollNameLeft,
+        },
+        KeyBinding {
+            code: KeyCode::Right,
+            modifiers: Some(KeyModifiers::SHIFT),
+            action: Action::ScrollNameRight,
+        },
         KeyBinding {
             code: KeyCode::Char('l'),
             modifiers: Some(KeyModifiers::NONE),
@@ -816,6 +1322,11 @@ fn build_key_bindings() -> Vec<KeyBinding> {
             modifiers: Some(KeyModifiers::NONE),
             action: Action::EnterSelected,
         },
+        KeyBinding {
+            code: KeyCode::Enter,
+            modifiers: Some(KeyModifiers::SHIFT),
+            action: Action::TabOpenInOtherPanel,
+        },
         // G / Home / End
         KeyBinding {
             code: KeyCode::Char('G'),
@@ -874,15 +1385,25 @@ fn build_key_bindings() -> Vec<KeyBinding> {
             modifiers: Some(KeyModifiers::NONE),
             action: Action::OpenTerminalEditor,
         },
+        KeyBinding {
+            code: KeyCode::Char('!'),
+            modifiers: Some(KeyModifiers::NONE),
+            action: Action::RunShell,
+        },
+        KeyBinding {
+            code: KeyCode::Char('!'),
+            modifiers: Some(KeyModifiers::SHIFT),
+            action: Action::RunShell,
+        },
         KeyBinding {
             code: KeyCode::Char(':'),
             modifiers: Some(KeyModifiers::NONE),
-            action: Action::RunShellCommand,
+            action: Action::ShowCommandPalette,
         },
         KeyBinding {
             code: KeyCode::Char(':'),
             modifiers: Some(KeyModifiers::SHIFT),
-            action: Action::RunShellCommand,
+            action: Action::ShowCommandPalette,
         },
         KeyBinding {
             code: KeyCode::Char('d'),
@@ -899,6 +1420,11 @@ fn build_key_bindings() -> Vec<KeyBinding> {
             modifiers: Some(KeyModifiers::NONE),
             action: Action::MakeDirectory,
         },
+        KeyBinding {
+            code: KeyCode::Char('A'),
+            modifiers: None,
+            action: Action::NewFile,
+        },
         KeyBinding {
             code: KeyCode::Char('r'),
             modifiers: Some(KeyModifiers::NONE),
@@ -909,6 +1435,11 @@ fn build_key_bindings() -> Vec<KeyBinding> {
             modifiers: Some(KeyModifiers::NONE),
             action: Action::ShowProperties,
         },
+        KeyBinding {
+            code: KeyCode::Char('='),
+            modifiers: None,
+            action: Action::CompareFiles,
+        },
         // 선택
         KeyBinding {
             code: KeyCode::Char(' '),
@@ -958,6 +1489,22 @@ fn build_key_bindings() -> Vec<KeyBinding> {
             modifiers: Some(KeyModifiers::CONTROL),
             action: Action::AddBookmark,
         },
+        KeyBinding {
+            code: KeyCode::Char('p'),
+            modifiers: Some(KeyModifiers::CONTROL),
+            action: Action::FindFile,
+        },
+        // 터미널이 Shift를 구분해 보낼 때만 Ctrl+P와 다르게 들어옴
+        KeyBinding {
+            code: KeyCode::Char('P'),
+            modifiers: Some(KeyModifiers::CONTROL.union(KeyModifiers::SHIFT)),
+            action: Action::ShowCommandPalette,
+        },
+        KeyBinding {
+            code: KeyCode::Char('p'),
+            modifiers: Some(KeyModifiers::CONTROL.union(KeyModifiers::SHIFT)),
+            action: Action::ShowCommandPalette,
+        },
     ]
 }
 
@@ -991,11 +1538,23 @@ pub fn find_sequence_action(prefix: char, key: char) -> Option<Action> {
         .map(|binding| binding.action)
 }
 
+/// 북마크 단축키 이동 prefix (`'` + 숫자)
+pub const BOOKMARK_HOTKEY_PREFIX: char = '\'';
+
+/// 매크로 녹화 prefix (`q` + 레지스터, 다른 키가 오거나 시간이 지나면 종료)
+pub const MACRO_RECORD_PREFIX: char = 'q';
+
+/// 매크로 재생 prefix (`@` + 레지스터, `@@`는 마지막 재생)
+pub const MACRO_PLAY_PREFIX: char = '@';
+
 /// 시퀀스 시작 prefix 여부
 pub fn is_sequence_prefix(c: char) -> bool {
-    sequence_bindings()
-        .iter()
-        .any(|binding| binding.prefix == c)
+    c == BOOKMARK_HOTKEY_PREFIX
+        || c == MACRO_RECORD_PREFIX
+        || c == MACRO_PLAY_PREFIX
+        || sequence_bindings()
+            .iter()
+            .any(|binding| binding.prefix == c)
 }
 
 fn build_sequence_bindings() -> Vec<SequenceBinding> {
@@ -1015,6 +1574,61 @@ fn build_sequence_bindings() -> Vec<SequenceBinding> {
             key: 'p',
             action: Action::GoToPath,
         },
+        SequenceBinding {
+            prefix: 'g',
+            key: 's',
+            action: Action::ConnectRemote,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'u',
+            action: Action::AnalyzeDiskUsage,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'r',
+            action: Action::RevealInFileManager,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'x',
+            action: Action::RunExecutable,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'd',
+            action: Action::GitShowDiff,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'j',
+            action: Action::ShowJumpList,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'n',
+            action: Action::ShowSiblingList,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'b',
+            action: Action::ToggleSyncBrowsing,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'f',
+            action: Action::ToggleFollowFocus,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: 'c',
+            action: Action::ShowBreadcrumbs,
+        },
+        SequenceBinding {
+            prefix: 'g',
+            key: '=',
+            action: Action::SyncOtherPanel,
+        },
         SequenceBinding {
             prefix: 's',
             key: 'n',
@@ -1040,6 +1654,21 @@ fn build_sequence_bindings() -> Vec<SequenceBinding> {
             key: 'r',
             action: Action::SortAscending,
         },
+        SequenceBinding {
+            prefix: 's',
+            key: 'v',
+            action: Action::ToggleNaturalSort,
+        },
+        SequenceBinding {
+            prefix: 's',
+            key: 'l',
+            action: Action::ToggleLocaleSort,
+        },
+        SequenceBinding {
+            prefix: 's',
+            key: 'f',
+            action: Action::ToggleDirsFirst,
+        },
         SequenceBinding {
             prefix: 't',
             key: 'n',
@@ -1055,6 +1684,36 @@ fn build_sequence_bindings() -> Vec<SequenceBinding> {
             key: 't',
             action: Action::ShowTabList,
         },
+        SequenceBinding {
+            prefix: 't',
+            key: 'r',
+            action: Action::TabRename,
+        },
+        SequenceBinding {
+            prefix: 't',
+            key: '[',
+            action: Action::TabMoveLeft,
+        },
+        SequenceBinding {
+            prefix: 't',
+            key: ']',
+            action: Action::TabMoveRight,
+        },
+        SequenceBinding {
+            prefix: 't',
+            key: 'c',
+            action: Action::TabCopyToOtherPanel,
+        },
+        SequenceBinding {
+            prefix: 't',
+            key: 'm',
+            action: Action::TabSendToOtherPanel,
+        },
+        SequenceBinding {
+            prefix: 't',
+            key: 'o',
+            action: Action::TabOpenInOtherPanel,
+        },
         SequenceBinding {
             prefix: 't',
             key: 'h',
@@ -1065,6 +1724,21 @@ fn build_sequence_bindings() -> Vec<SequenceBinding> {
             key: 'b',
             action: Action::ShowBookmarkList,
         },
+        SequenceBinding {
+            prefix: 't',
+            key: 'w',
+            action: Action::SaveSession,
+        },
+        SequenceBinding {
+            prefix: 't',
+            key: 's',
+            action: Action::ShowSessionList,
+        },
+        SequenceBinding {
+            prefix: 't',
+            key: 'j',
+            action: Action::ShowJobList,
+        },
         SequenceBinding {
             prefix: 'z',
             key: 'c',
@@ -1095,6 +1769,11 @@ impl Action {
     pub fn from_id(id: &str) -> Option<Action> {
         ACTION_DEFS.iter().find(|d| d.id == id).map(|d| d.action)
     }
+
+    /// 액션 id (등록되지 않은 액션이면 None)
+    pub fn id(self) -> Option<&'static str> {
+        ACTION_DEFS.iter().find(|d| d.action == self).map(|d| d.id)
+    }
 }
 
 /// 커맨드바용 항목 생성 (priority 순 정렬)
@@ -1168,6 +1847,24 @@ pub fn generate_help_entries(
         .collect()
 }
 
+/// 명령 팔레트용 엔트리 생성 (팔레트 자신 제외, 정의 순서)
+///
+/// 반환: (액션, 설명, 단축키) 목록
+pub fn generate_palette_entries(language: Language) -> Vec<(Action, &'static str, &'static str)> {
+    let i18n = I18n::new(language);
+    ACTION_DEFS
+        .iter()
+        .filter(|d| d.action != Action::ShowCommandPalette)
+        .map(|d| {
+            let label = match i18n.menu_item(d.id) {
+                "" => localized_label(language, d.id, d.label),
+                label => label,
+            };
+            (d.action, label, d.shortcut_display.unwrap_or(""))
+        })
+        .collect()
+}
+
 /// 메뉴 단축키 표시용 조회
 pub fn get_shortcut_display(id: &str) -> Option<&'static str> {
     ACTION_DEFS
@@ -1186,7 +1883,39 @@ mod tests {
         assert_eq!(Action::from_id("quit"), Some(Action::Quit));
         assert_eq!(Action::from_id("tab_new"), Some(Action::TabNew));
         assert_eq!(Action::from_id("tab_list"), Some(Action::ShowTabList));
+        assert_eq!(Action::from_id("tab_rename"), Some(Action::TabRename));
+        assert_eq!(
+            Action::from_id("tab_send_other"),
+            Some(Action::TabSendToOtherPanel)
+        );
         assert_eq!(Action::from_id("goto_path"), Some(Action::GoToPath));
+        assert_eq!(
+            Action::from_id("remote_connect"),
+            Some(Action::ConnectRemote)
+        );
+        assert_eq!(Action::from_id("theme_list"), Some(Action::ShowThemeList));
+        assert_eq!(
+            Action::from_id("context_menu"),
+            Some(Action::OpenContextMenu)
+        );
+        assert_eq!(
+            Action::from_id("disk_usage"),
+            Some(Action::AnalyzeDiskUsage)
+        );
+        assert_eq!(Action::from_id("compare_files"), Some(Action::CompareFiles));
+        assert_eq!(
+            Action::from_id("reveal_in_file_manager"),
+            Some(Action::RevealInFileManager)
+        );
+        assert_eq!(
+            Action::from_id("run_executable"),
+            Some(Action::RunExecutable)
+        );
+        assert_eq!(Action::from_id("git_diff"), Some(Action::GitShowDiff));
+        assert_eq!(
+            Action::from_id("git_discard"),
+            Some(Action::GitDiscardChanges)
+        );
         assert_eq!(
             Action::from_id("open_default"),
             Some(Action::OpenDefaultApp)
@@ -1195,10 +1924,7 @@ mod tests {
             Action::from_id("open_terminal_editor"),
             Some(Action::OpenTerminalEditor)
         );
-        assert_eq!(
-            Action::from_id("run_shell_command"),
-            Some(Action::RunShellCommand)
-        );
+        assert_eq!(Action::from_id("run_shell_command"), Some(Action::RunShell));
         assert_eq!(
             Action::from_id("archive_compress"),
             Some(Action::ArchiveCompress)
@@ -1215,6 +1941,26 @@ mod tests {
             Action::from_id("archive_preview"),
             Some(Action::ArchivePreview)
         );
+        assert_eq!(
+            Action::from_id("copy_verify"),
+            Some(Action::ToggleCopyVerify)
+        );
+        assert_eq!(
+            Action::from_id("toggle_git_status"),
+            Some(Action::ToggleGitStatus)
+        );
+        assert_eq!(
+            Action::from_id("toggle_info_line"),
+            Some(Action::ToggleInfoLine)
+        );
+        assert_eq!(
+            Action::from_id("toggle_type_column"),
+            Some(Action::ToggleTypeColumn)
+        );
+        assert_eq!(
+            Action::from_id("copy_preserve_xattrs"),
+            Some(Action::ToggleCopyPreserveXattrs)
+        );
         assert_eq!(
             Action::from_id("editor_preset_vi"),
             Some(Action::SetDefaultEditorVi)
@@ -1235,11 +1981,47 @@ mod tests {
             Action::from_id("history_list"),
             Some(Action::ShowHistoryList)
         );
+        assert_eq!(Action::from_id("jump_list"), Some(Action::ShowJumpList));
+        assert_eq!(
+            Action::from_id("toggle_sync_browsing"),
+            Some(Action::ToggleSyncBrowsing)
+        );
+        assert_eq!(Action::from_id("find_file"), Some(Action::FindFile));
+        assert_eq!(
+            Action::from_id("command_palette"),
+            Some(Action::ShowCommandPalette)
+        );
         assert_eq!(Action::from_id("bookmark_add"), Some(Action::AddBookmark));
         assert_eq!(
             Action::from_id("bookmark_list"),
             Some(Action::ShowBookmarkList)
         );
+        assert_eq!(
+            Action::from_id("bookmark_export"),
+            Some(Action::ExportBookmarks)
+        );
+        assert_eq!(
+            Action::from_id("bookmark_import"),
+            Some(Action::ImportBookmarks)
+        );
+        assert_eq!(Action::from_id("session_save"), Some(Action::SaveSession));
+        assert_eq!(
+            Action::from_id("sort_natural"),
+            Some(Action::ToggleNaturalSort)
+        );
+        assert_eq!(
+            Action::from_id("sort_locale"),
+            Some(Action::ToggleLocaleSort)
+        );
+        assert_eq!(
+            Action::from_id("sort_dirs_first"),
+            Some(Action::ToggleDirsFirst)
+        );
+        assert_eq!(
+            Action::from_id("session_list"),
+            Some(Action::ShowSessionList)
+        );
+        assert_eq!(Action::from_id("job_list"), Some(Action::ShowJobList));
         assert_eq!(Action::from_id("toggle_layout"), Some(Action::ToggleLayout));
         assert_eq!(Action::from_id("nonexistent"), None);
     }
@@ -1254,6 +2036,10 @@ mod tests {
             find_action(KeyModifiers::NONE, KeyCode::Char('k')),
             Some(Action::MoveUp)
         );
+        assert_eq!(
+            find_action(KeyModifiers::NONE, KeyCode::Char('m')),
+            Some(Action::OpenContextMenu)
+        );
         assert_eq!(
             find_action(KeyModifiers::NONE, KeyCode::Char('y')),
             Some(Action::Copy)
@@ -1266,13 +2052,32 @@ mod tests {
             find_action(KeyModifiers::NONE, KeyCode::Char('e')),
             Some(Action::OpenTerminalEditor)
         );
+        assert_eq!(
+            find_action(KeyModifiers::NONE, KeyCode::Char('!')),
+            Some(Action::RunShell)
+        );
+        assert_eq!(
+            find_action(KeyModifiers::SHIFT, KeyCode::Char('!')),
+            Some(Action::RunShell)
+        );
         assert_eq!(
             find_action(KeyModifiers::NONE, KeyCode::Char(':')),
-            Some(Action::RunShellCommand)
+            Some(Action::ShowCommandPalette)
         );
         assert_eq!(
             find_action(KeyModifiers::SHIFT, KeyCode::Char(':')),
-            Some(Action::RunShellCommand)
+            Some(Action::ShowCommandPalette)
+        );
+        assert_eq!(
+            find_action(
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+                KeyCode::Char('P')
+            ),
+            Some(Action::ShowCommandPalette)
+        );
+        assert_eq!(
+            find_action(KeyModifiers::SHIFT, KeyCode::Char('=')),
+            Some(Action::CompareFiles)
         );
         assert_eq!(
             find_action(KeyModifiers::NONE, KeyCode::Char('q')),
@@ -1306,12 +2111,47 @@ mod tests {
             find_action(KeyModifiers::CONTROL, KeyCode::Char('b')),
             Some(Action::AddBookmark)
         );
+        assert_eq!(
+            find_action(KeyModifiers::CONTROL, KeyCode::Char('p')),
+            Some(Action::FindFile)
+        );
         assert_eq!(
             find_action(KeyModifiers::CONTROL, KeyCode::Char('w')),
             Some(Action::ToggleLayout)
         );
     }
 
+    #[test]
+    fn test_find_action_new_file() {
+        assert_eq!(
+            find_action(KeyModifiers::SHIFT, KeyCode::Char('A')),
+            Some(Action::NewFile)
+        );
+        assert_eq!(Action::from_id("new_file"), Some(Action::NewFile));
+    }
+
+    #[test]
+    fn test_find_action_swap_panels() {
+        assert_eq!(
+            find_action(KeyModifiers::CONTROL, KeyCode::Char('s')),
+            Some(Action::SwapPanels)
+        );
+        assert_eq!(find_sequence_action('g', '='), Some(Action::SyncOtherPanel));
+        assert_eq!(
+            find_action(KeyModifiers::SHIFT, KeyCode::Enter),
+            Some(Action::TabOpenInOtherPanel)
+        );
+        assert_eq!(
+            find_sequence_action('t', 'o'),
+            Some(Action::TabOpenInOtherPanel)
+        );
+        assert_eq!(Action::from_id("swap_panels"), Some(Action::SwapPanels));
+        assert_eq!(
+            Action::from_id("sync_other_panel"),
+            Some(Action::SyncOtherPanel)
+        );
+    }
+
     #[test]
     fn test_find_action_alt_history_keys() {
         assert_eq!(
@@ -1345,14 +2185,65 @@ mod tests {
             Some(Action::ShowMountPoints)
         );
         assert_eq!(find_sequence_action('g', 'p'), Some(Action::GoToPath));
+        assert_eq!(find_sequence_action('g', 's'), Some(Action::ConnectRemote));
+        assert_eq!(
+            find_sequence_action('g', 'u'),
+            Some(Action::AnalyzeDiskUsage)
+        );
+        assert_eq!(
+            find_sequence_action('g', 'r'),
+            Some(Action::RevealInFileManager)
+        );
+        assert_eq!(find_sequence_action('g', 'x'), Some(Action::RunExecutable));
+        assert_eq!(find_sequence_action('g', 'd'), Some(Action::GitShowDiff));
+        assert_eq!(find_sequence_action('g', 'j'), Some(Action::ShowJumpList));
+        assert_eq!(
+            find_sequence_action('g', 'n'),
+            Some(Action::ShowSiblingList)
+        );
+        assert_eq!(
+            find_sequence_action('g', 'b'),
+            Some(Action::ToggleSyncBrowsing)
+        );
+        assert_eq!(
+            find_sequence_action('g', 'f'),
+            Some(Action::ToggleFollowFocus)
+        );
+        assert_eq!(
+            find_sequence_action('g', 'c'),
+            Some(Action::ShowBreadcrumbs)
+        );
         assert_eq!(find_sequence_action('s', 'n'), Some(Action::SortByName));
         assert_eq!(find_sequence_action('s', 's'), Some(Action::SortBySize));
         assert_eq!(find_sequence_action('s', 'd'), Some(Action::SortByDate));
         assert_eq!(find_sequence_action('s', 'e'), Some(Action::SortByExt));
         assert_eq!(find_sequence_action('s', 'r'), Some(Action::SortAscending));
+        assert_eq!(
+            find_sequence_action('s', 'v'),
+            Some(Action::ToggleNaturalSort)
+        );
+        assert_eq!(
+            find_sequence_action('s', 'l'),
+            Some(Action::ToggleLocaleSort)
+        );
+        assert_eq!(
+            find_sequence_action('s', 'f'),
+            Some(Action::ToggleDirsFirst)
+        );
         assert_eq!(find_sequence_action('t', 'n'), Some(Action::TabNew));
         assert_eq!(find_sequence_action('t', 'x'), Some(Action::TabClose));
         assert_eq!(find_sequence_action('t', 't'), Some(Action::ShowTabList));
+        assert_eq!(find_sequence_action('t', 'r'), Some(Action::TabRename));
+        assert_eq!(find_sequence_action('t', '['), Some(Action::TabMoveLeft));
+        assert_eq!(find_sequence_action('t', ']'), Some(Action::TabMoveRight));
+        assert_eq!(
+            find_sequence_action('t', 'c'),
+            Some(Action::TabCopyToOtherPanel)
+        );
+        assert_eq!(
+            find_sequence_action('t', 'm'),
+            Some(Action::TabSendToOtherPanel)
+        );
         assert_eq!(
             find_sequence_action('t', 'h'),
             Some(Action::ShowHistoryList)
@@ -1361,6 +2252,12 @@ mod tests {
             find_sequence_action('t', 'b'),
             Some(Action::ShowBookmarkList)
         );
+        assert_eq!(find_sequence_action('t', 'w'), Some(Action::SaveSession));
+        assert_eq!(
+            find_sequence_action('t', 's'),
+            Some(Action::ShowSessionList)
+        );
+        assert_eq!(find_sequence_action('t', 'j'), Some(Action::ShowJobList));
         assert_eq!(
             find_sequence_action('z', 'c'),
             Some(Action::ArchiveCompress)
@@ -1370,7 +2267,7 @@ mod tests {
             find_sequence_action('z', 'a'),
             Some(Action::ArchiveExtractAuto)
         );
-        assert_eq!(find_sequence_action('g', 'x'), None);
+        assert_eq!(find_sequence_action('g', 'q'), None);
         assert_eq!(find_sequence_action('x', 'x'), None);
     }
 
@@ -1410,19 +2307,40 @@ mod tests {
         assert!(!file_ops.iter().any(|(k, _)| *k == "zl"));
     }
 
+    #[test]
+    fn test_generate_palette_entries() {
+        let entries = generate_palette_entries(Language::Korean);
+        assert_eq!(entries.len(), ACTION_DEFS.len() - 1);
+        assert!(!entries
+            .iter()
+            .any(|(action, _, _)| *action == Action::ShowCommandPalette));
+        assert!(entries.contains(&(Action::Copy, "복사", "y")));
+        // 메뉴 전용 액션도 포함 (단축키 없음)
+        assert!(entries
+            .iter()
+            .any(|(action, _, shortcut)| *action == Action::ThemeDark && shortcut.is_empty()));
+    }
+
     #[test]
     fn test_get_shortcut_display() {
         assert_eq!(get_shortcut_display("copy"), Some("y"));
         assert_eq!(get_shortcut_display("quit"), Some("q"));
         assert_eq!(get_shortcut_display("goto_path"), Some("gp"));
+        assert_eq!(get_shortcut_display("remote_connect"), Some("gs"));
         assert_eq!(get_shortcut_display("open_default"), Some("o"));
         assert_eq!(get_shortcut_display("open_terminal_editor"), Some("e"));
-        assert_eq!(get_shortcut_display("run_shell_command"), Some(":"));
+        assert_eq!(get_shortcut_display("run_shell_command"), Some("!"));
         assert_eq!(get_shortcut_display("archive_compress"), Some("zc"));
         assert_eq!(get_shortcut_display("archive_extract"), Some("zx"));
         assert_eq!(get_shortcut_display("archive_extract_auto"), Some("za"));
+        assert_eq!(get_shortcut_display("session_save"), Some("tw"));
+        assert_eq!(get_shortcut_display("sort_natural"), Some("sv"));
+        assert_eq!(get_shortcut_display("sort_locale"), Some("sl"));
+        assert_eq!(get_shortcut_display("sort_dirs_first"), Some("sf"));
+        assert_eq!(get_shortcut_display("session_list"), Some("ts"));
         assert_eq!(get_shortcut_display("archive_preview"), None);
         assert_eq!(get_shortcut_display("theme_dark"), None);
+        assert_eq!(get_shortcut_display("theme_list"), None);
     }
 
     #[test]
@@ -1,2 +1,3 @@
 // Core Layer
 pub mod actions;
+pub mod plugins;
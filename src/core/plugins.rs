@@ -0,0 +1,284 @@
+#![allow(dead_code)]
+//! 플러그인 확장 지점 — 코어를 고치지 않고 동작을 덧붙이는 통로
+//!
+//! 플러그인은 [`Plugin`] 트레잇을 구현해 빌드에 포함하고, 설정 파일 `plugins`에 이름을
+//! 적으면 켜집니다 (사용 가능한 목록은 `crate::plugins::BUILTIN`).
+//!
+//! - 디렉토리 진입 / 선택 변경 훅: [`PluginContext`]로 현재 목록을 보고 효과를 요청
+//! - 추가 액션: "Plugin actions" 목록에서 골라 실행
+//! - 추가 컬럼: 화면에 보이는 로컬 항목만 조금씩 값을 구해 패널에 표시
+
+use crate::models::file_entry::FileEntry;
+use std::path::{Path, PathBuf};
+
+/// 플러그인이 제공하는 액션 (id는 플러그인 안에서만 고유하면 됨)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginAction {
+    pub id: &'static str,
+    pub label: &'static str,
+}
+
+/// 플러그인이 제공하는 패널 컬럼
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginColumn {
+    pub id: &'static str,
+    /// 헤더 제목
+    pub title: &'static str,
+    /// 표시 너비 (칸 수)
+    pub width: usize,
+}
+
+/// 훅/액션이 앱에 요청하는 효과 (호출이 끝난 뒤 순서대로 적용)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginEffect {
+    Toast(String),
+    /// 활성 패널을 디렉토리로 이동
+    Navigate(PathBuf),
+    /// 결과 창 표시
+    ShowText {
+        title: String,
+        body: String,
+    },
+}
+
+/// 훅/액션에 넘기는 활성 패널 상태
+pub struct PluginContext<'a> {
+    pub current_dir: &'a Path,
+    pub entries: &'a [FileEntry],
+    /// 커서 항목 (`..` 줄이나 빈 목록이면 None)
+    pub focused: Option<&'a FileEntry>,
+    /// 선택 항목 (목록 순서)
+    pub selected: Vec<&'a FileEntry>,
+    effects: Vec<PluginEffect>,
+}
+
+impl<'a> PluginContext<'a> {
+    pub fn new(
+        current_dir: &'a Path,
+        entries: &'a [FileEntry],
+        focused: Option<&'a FileEntry>,
+        selected: Vec<&'a FileEntry>,
+    ) -> Self {
+        Self {
+            current_dir,
+            entries,
+            focused,
+            selected,
+            effects: Vec::new(),
+        }
+    }
+
+    /// 작업 대상 (선택 항목, 없으면 커서 항목)
+    pub fn targets(&self) -> Vec<&'a FileEntry> {
+        if self.selected.is_empty() {
+            self.focused.into_iter().collect()
+        } else {
+            self.selected.clone()
+        }
+    }
+
+    pub fn toast(&mut self, message: impl Into<String>) {
+        self.effects.push(PluginEffect::Toast(message.into()));
+    }
+
+    pub fn navigate(&mut self, path: impl Into<PathBuf>) {
+        self.effects.push(PluginEffect::Navigate(path.into()));
+    }
+
+    pub fn show_text(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.effects.push(PluginEffect::ShowText {
+            title: title.into(),
+            body: body.into(),
+        });
+    }
+
+    pub fn into_effects(self) -> Vec<PluginEffect> {
+        self.effects
+    }
+}
+
+/// 플러그인 (필요한 훅만 구현, 나머지는 아무것도 하지 않음)
+pub trait Plugin {
+    /// 설정 파일 `plugins`에 적는 이름
+    fn name(&self) -> &'static str;
+
+    /// 활성 패널이 다른 디렉토리를 보이기 시작함 (목록을 다 읽은 뒤)
+    fn on_enter_directory(&mut self, _ctx: &mut PluginContext<'_>) {}
+
+    /// 활성 패널의 선택 항목이 바뀜
+    fn on_select(&mut self, _ctx: &mut PluginContext<'_>) {}
+
+    fn actions(&self) -> Vec<PluginAction> {
+        Vec::new()
+    }
+
+    fn run_action(&mut self, _id: &str, _ctx: &mut PluginContext<'_>) {}
+
+    fn columns(&self) -> Vec<PluginColumn> {
+        Vec::new()
+    }
+
+    /// 컬럼 값 (로컬 항목만 호출, None이면 빈 칸)
+    fn column_value(&self, _column: &str, _entry: &FileEntry) -> Option<String> {
+        None
+    }
+}
+
+/// 켜진 플러그인 목록 (등록 순서대로 훅 호출, 컬럼과 액션도 이 순서)
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Box<dyn Plugin>>,
+    /// 모든 플러그인 컬럼 (플러그인 인덱스와 함께)
+    columns: Vec<(usize, PluginColumn)>,
+}
+
+impl PluginRegistry {
+    pub fn register(&mut self, plugin: Box<dyn Plugin>) {
+        let index = self.plugins.len();
+        self.columns
+            .extend(plugin.columns().into_iter().map(|column| (index, column)));
+        self.plugins.push(plugin);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.plugins.iter().map(|plugin| plugin.name()).collect()
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = &PluginColumn> {
+        self.columns.iter().map(|(_, column)| column)
+    }
+
+    pub fn has_columns(&self) -> bool {
+        !self.columns.is_empty()
+    }
+
+    /// 항목의 컬럼 값 ([`Self::columns`] 순서, 값이 없으면 빈 문자열)
+    pub fn column_values(&self, entry: &FileEntry) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|(index, column)| {
+                self.plugins[*index]
+                    .column_value(column.id, entry)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// 모든 액션 (플러그인 인덱스, 플러그인 이름, 액션)
+    pub fn actions(&self) -> Vec<(usize, &'static str, PluginAction)> {
+        self.plugins
+            .iter()
+            .enumerate()
+            .flat_map(|(index, plugin)| {
+                let name = plugin.name();
+                plugin
+                    .actions()
+                    .into_iter()
+                    .map(move |action| (index, name, action))
+            })
+            .collect()
+    }
+
+    pub fn on_enter_directory(&mut self, ctx: &mut PluginContext<'_>) {
+        for plugin in &mut self.plugins {
+            plugin.on_enter_directory(ctx);
+        }
+    }
+
+    pub fn on_select(&mut self, ctx: &mut PluginContext<'_>) {
+        for plugin in &mut self.plugins {
+            plugin.on_select(ctx);
+        }
+    }
+
+    pub fn run_action(&mut self, plugin: usize, id: &str, ctx: &mut PluginContext<'_>) {
+        if let Some(plugin) = self.plugins.get_mut(plugin) {
+            plugin.run_action(id, ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::file_entry::FileType;
+    use std::time::SystemTime;
+
+    struct Echo;
+
+    impl Plugin for Echo {
+        fn name(&self) -> &'static str {
+            "echo"
+        }
+
+        fn actions(&self) -> Vec<PluginAction> {
+            vec![PluginAction {
+                id: "count",
+                label: "Count targets",
+            }]
+        }
+
+        fn run_action(&mut self, id: &str, ctx: &mut PluginContext<'_>) {
+            let count = ctx.targets().len();
+            ctx.toast(format!("{} {}", id, count));
+        }
+
+        fn columns(&self) -> Vec<PluginColumn> {
+            vec![PluginColumn {
+                id: "len",
+                title: "Len",
+                width: 4,
+            }]
+        }
+
+        fn column_value(&self, _column: &str, entry: &FileEntry) -> Option<String> {
+            entry.is_file().then(|| entry.name.len().to_string())
+        }
+    }
+
+    fn entry(name: &str, file_type: FileType) -> FileEntry {
+        FileEntry::new(
+            name.to_string(),
+            PathBuf::from("/tmp").join(name),
+            file_type,
+            0,
+            SystemTime::UNIX_EPOCH,
+            SystemTime::UNIX_EPOCH,
+            None,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_registry_collects_columns_and_runs_actions() {
+        let mut registry = PluginRegistry::default();
+        registry.register(Box::new(Echo));
+        assert_eq!(registry.names(), vec!["echo"]);
+        assert_eq!(
+            registry.columns().map(|c| c.title).collect::<Vec<_>>(),
+            vec!["Len"]
+        );
+
+        let entries = [
+            entry("a.txt", FileType::File),
+            entry("dir", FileType::Directory),
+        ];
+        assert_eq!(registry.column_values(&entries[0]), vec!["5"]);
+        assert_eq!(registry.column_values(&entries[1]), vec![""]);
+
+        let actions = registry.actions();
+        assert_eq!(actions.len(), 1);
+        let (plugin, name, action) = actions[0];
+        assert_eq!((name, action.id), ("echo", "count"));
+        let mut ctx = PluginContext::new(Path::new("/tmp"), &entries, entries.first(), Vec::new());
+        registry.run_action(plugin, action.id, &mut ctx);
+        assert_eq!(
+            ctx.into_effects(),
+            vec![PluginEffect::Toast("count 1".to_string())]
+        );
+    }
+}
@@ -1,17 +1,32 @@
 mod app;
 mod core;
 mod models;
+mod plugins;
+#[cfg(test)]
+mod snapshot_tests;
 mod system;
 mod ui;
 mod utils;
 
-use app::{App, TerminalCommandRequest, TerminalEditorRequest};
-use core::actions::{find_action, find_sequence_action, is_sequence_prefix};
+use app::{
+    App, ChooseOutputs, DialogOutcome, ExecutableRunOutcome, ExecutableRunRequest, Msg,
+    StartupOptions, TerminalCommandRequest, TerminalEditorRequest, TextEdit,
+};
+use core::actions::{
+    find_action, find_sequence_action, is_sequence_prefix, Action, BOOKMARK_HOTKEY_PREFIX,
+    MACRO_PLAY_PREFIX, MACRO_RECORD_PREFIX,
+};
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    cursor,
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use log::LevelFilter;
+use models::operation::CopyOption;
 use ratatui::{
     backend::CrosstermBackend,
     layout::Rect,
@@ -20,121 +35,357 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph},
     Terminal,
 };
+use std::collections::{HashMap, VecDeque};
+use std::ffi::OsString;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::mpsc;
+use std::time::Instant;
+use system::attributes::Attribute;
+use system::event_loop::{self, InputReader, LoopEvent, Waker};
 use system::ime;
 use ui::{
-    ActivePanel, CommandBar, Dialog, DialogKind, DropdownMenu, InputPurpose, LayoutMode, MenuBar,
-    Panel, PanelStatus, StatusBar, WarningScreen,
+    components::ListMove, ActivePanel, CommandBar, Dialog, DialogKind, DropdownMenu, InputPurpose,
+    LayoutMode, MenuBar, Panel, PanelStatus, StatusBar, ThemeManager, WarningScreen,
 };
 use utils::{
+    display_width,
     error::Result,
     formatter::{format_file_size, format_file_size_bytes},
+    logging,
 };
 
+const CLI_USAGE: &str = "\
+Usage: boksldir [OPTIONS] [LEFT_PATH] [RIGHT_PATH]
+
+Arguments:
+  [LEFT_PATH]   Start directory of the left panel (both panels if RIGHT_PATH is omitted)
+  [RIGHT_PATH]  Start directory of the right panel
+
+Options:
+  -t, --theme <NAME>         Start with the given theme (dark, light, high_contrast)
+      --no-persist           Do not read or write the settings file
+      --choose-dir <FILE>    Write the last active directory to FILE on exit
+      --choose-files <FILE>  Write selected file paths (one per line) to FILE on exit
+      --log-level <LEVEL>    Log to boksldir.log next to the settings file
+                             (off, error, warn, info, debug, trace; default: warn)
+      --script <FILE>        Run the actions in FILE after start (one per line)
+  -h, --help                 Print help";
+
+/// 커맨드라인 옵션
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CliOptions {
+    /// 앱 시작 옵션 (패널 경로, 테마, 설정 저장 여부)
+    startup: StartupOptions,
+    /// 쉘 연동 모드 종료 출력 대상
+    choose_outputs: ChooseOutputs,
+    /// 도움말 출력 요청
+    show_help: bool,
+    /// 로그 수준 (없으면 설정 파일 값 또는 기본값)
+    log_level: Option<LevelFilter>,
+    /// 시작 후 실행할 스크립트 파일
+    script: Option<PathBuf>,
+}
+
 fn main() -> Result<()> {
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    let options = match parse_cli_args(std::env::args_os()).and_then(validate_cli_options) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("boksldir: {}\n\n{}", message, CLI_USAGE);
+            std::process::exit(2);
+        }
+    };
+    if options.show_help {
+        println!("{}", CLI_USAGE);
+        return Ok(());
+    }
+    // 스크립트 오류는 화면을 열기 전에 알림
+    let script = match options.script.as_deref().map(app::load_script).transpose() {
+        Ok(script) => script,
+        Err(message) => {
+            eprintln!("boksldir: {}", message);
+            std::process::exit(2);
+        }
+    };
+
+    // 로그는 파일에만 기록 (--no-persist면 --log-level을 줄 때만)
+    let log_path = App::default_log_file_path()
+        .filter(|_| !options.startup.no_persist || options.log_level.is_some());
+    if let Some(path) = &log_path {
+        let level = options.log_level.unwrap_or(logging::DEFAULT_LEVEL);
+        if let Err(err) = logging::init(path, level) {
+            eprintln!("boksldir: cannot open log file {}: {}", path.display(), err);
+        }
+    }
+
+    // Setup terminal (패닉/오류로 빠져나가도 가드가 복원)
+    install_panic_hook(log_path.clone());
+    let terminal_guard = TerminalGuard::enter()?;
 
-    let backend = CrosstermBackend::new(stdout);
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
     // Create app
-    let startup_path = resolve_startup_path_arg(std::env::args_os());
-    let mut app = App::new_with_startup_path(startup_path)?;
+    let mut app = App::new_with_options(options.startup)?;
+    app.set_choose_outputs(options.choose_outputs);
+    if let Some(steps) = script {
+        app.run_script(steps);
+    }
+    if let (None, Some(level)) = (options.log_level, app.configured_log_level()) {
+        logging::set_level(level);
+    }
+    log::info!("started (version {})", env!("CARGO_PKG_VERSION"));
 
     // Run app
     let res = run_app(&mut terminal, &mut app);
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+    drop(terminal);
+    terminal_guard.restore()?;
 
     if let Err(err) = res {
+        log::error!("exited with error: {:?}", err);
         eprintln!("Error: {:?}", err);
+    } else {
+        log::info!("exited");
+    }
+    if let Some(message) = app.choose_output_error() {
+        eprintln!("boksldir: failed to write choose output: {}", message);
     }
 
     Ok(())
 }
 
-fn resolve_startup_path_arg<I, S>(mut args: I) -> Option<PathBuf>
+/// 원시 모드/대체 화면 진입 후 해제를 보장하는 가드
+///
+/// `main`이 `?`로 일찍 끝나거나 패닉으로 되감겨도 `Drop`에서 터미널을 되돌립니다.
+struct TerminalGuard {
+    active: bool,
+}
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        let guard = Self { active: true };
+        execute!(
+            io::stdout(),
+            EnterAlternateScreen,
+            EnableBracketedPaste,
+            EnableMouseCapture
+        )?;
+        Ok(guard)
+    }
+
+    /// 정상 종료 시 복원 (오류를 호출자에게 전달)
+    fn restore(mut self) -> io::Result<()> {
+        self.active = false;
+        restore_terminal()
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if self.active {
+            let _ = restore_terminal();
+        }
+    }
+}
+
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        LeaveAlternateScreen,
+        cursor::Show
+    )
+}
+
+/// 패닉 시 터미널을 먼저 복원한 뒤 메시지와 로그 파일 위치 출력
+fn install_panic_hook(log_path: Option<PathBuf>) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        log::error!("panic: {}", info);
+        default_hook(info);
+        eprintln!("{}", crash_notice(log_path.as_deref()));
+    }));
+}
+
+fn crash_notice(log_path: Option<&Path>) -> String {
+    match log_path {
+        Some(path) => format!(
+            "boksldir crashed. Details were written to {}",
+            path.display()
+        ),
+        None => "boksldir crashed. Run with --log-level debug to keep a log file.".to_string(),
+    }
+}
+
+/// 커맨드라인 인자 파싱
+///
+/// - 위치 인자 1개: 좌/우 패널 모두 해당 경로, 2개: 좌측/우측 패널 경로
+/// - `--choose-dir FILE`, `--choose-dir=FILE` 형식 모두 지원 (`--choosedir` 별칭 허용)
+/// - `--` 이후 인자는 모두 위치 인자로 취급
+fn parse_cli_args<I, S>(args: I) -> std::result::Result<CliOptions, String>
 where
-    I: Iterator<Item = S>,
-    S: Into<std::ffi::OsString>,
+    I: IntoIterator<Item = S>,
+    S: Into<OsString>,
 {
-    let _ = args.next();
-    args.next().map(|arg| PathBuf::from(arg.into()))
+    let mut options = CliOptions::default();
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let mut args = args.into_iter().map(Into::into).skip(1);
+    let mut options_ended = false;
+
+    while let Some(arg) = args.next() {
+        let flag = arg
+            .to_str()
+            .filter(|s| !options_ended && s.starts_with('-'));
+        let Some(flag) = flag else {
+            if paths.len() >= 2 {
+                return Err(format!(
+                    "unexpected argument '{}' (at most two paths are allowed)",
+                    arg.to_string_lossy()
+                ));
+            }
+            paths.push(PathBuf::from(arg));
+            continue;
+        };
+
+        let (name, inline_value) = match flag.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(OsString::from(value))),
+            _ => (flag, None),
+        };
+        match name {
+            "--" => options_ended = true,
+            "-h" | "--help" => options.show_help = true,
+            "--no-persist" => options.startup.no_persist = true,
+            "-t" | "--theme" => {
+                let value = cli_option_value(name, inline_value, &mut args)?;
+                options.startup.theme = Some(value.to_string_lossy().to_string());
+            }
+            "--choose-dir" | "--choosedir" => {
+                let value = cli_option_value(name, inline_value, &mut args)?;
+                options.choose_outputs.dir_file = Some(PathBuf::from(value));
+            }
+            "--log-level" => {
+                let value = cli_option_value(name, inline_value, &mut args)?;
+                let value = value.to_string_lossy();
+                let level = logging::parse_level(&value).ok_or_else(|| {
+                    format!(
+                        "invalid log level '{}' (off, error, warn, info, debug, trace)",
+                        value
+                    )
+                })?;
+                options.log_level = Some(level);
+            }
+            "--script" => {
+                let value = cli_option_value(name, inline_value, &mut args)?;
+                options.script = Some(PathBuf::from(value));
+            }
+            "--choose-files" | "--choosefiles" => {
+                let value = cli_option_value(name, inline_value, &mut args)?;
+                options.choose_outputs.files_file = Some(PathBuf::from(value));
+            }
+            _ => return Err(format!("unknown option '{}'", flag)),
+        }
+    }
+
+    let mut paths = paths.into_iter();
+    options.startup.left_path = paths.next();
+    options.startup.right_path = paths.next().or_else(|| options.startup.left_path.clone());
+    Ok(options)
+}
+
+fn cli_option_value(
+    name: &str,
+    inline_value: Option<OsString>,
+    args: &mut impl Iterator<Item = OsString>,
+) -> std::result::Result<OsString, String> {
+    inline_value
+        .or_else(|| args.next())
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| format!("option '{}' requires a value", name))
+}
+
+/// 터미널 진입 전 검증 (잘못된 테마 이름은 즉시 오류)
+fn validate_cli_options(options: CliOptions) -> std::result::Result<CliOptions, String> {
+    if let Some(theme) = options.startup.theme.as_deref() {
+        let mut manager = ThemeManager::new();
+        if let Some(dir) =
+            App::default_settings_file_path().and_then(|path| App::themes_dir_for(&path))
+        {
+            manager.load_custom_themes(&dir);
+        }
+        let available = manager.available_themes();
+        if !available.iter().any(|name| name == theme) {
+            return Err(format!(
+                "unknown theme '{}' (available: {})",
+                theme,
+                available.join(", ")
+            ));
+        }
+    }
+    Ok(options)
 }
 
 fn run_app<B: ratatui::backend::Backend<Error = io::Error>>(
     terminal: &mut Terminal<B>,
     app: &mut App,
 ) -> Result<()> {
+    let (events_tx, events) = event_loop::channel();
+    let waker = Waker::new(events_tx.clone());
+    app.set_waker(waker.clone());
+    let input = InputReader::spawn(events_tx);
+
+    let mut last_draw: Option<Instant> = None;
+    let mut more_work = false;
+    // 파일 작업 도중 도착해 다음 반복으로 미룬 이벤트
+    let mut deferred: Option<LoopEvent> = None;
     loop {
-        terminal.draw(|f| {
-            let size = f.area();
-
-            // 레이아웃 업데이트
-            app.layout.update(size);
-
-            match app.layout.mode() {
-                LayoutMode::TooSmall => {
-                    // 경고 화면 표시
-                    let (width, height) = app.layout.terminal_size();
-                    let warning = WarningScreen::new()
-                        .current_size(width, height)
-                        .language(app.language())
-                        .theme(app.theme_manager.current());
-                    f.render_widget(warning, size);
-                }
-                LayoutMode::SinglePanel => {
-                    render_main_ui(f, app);
-                }
-                LayoutMode::DualPanel => {
-                    render_main_ui(f, app);
-                }
-            }
-        })?;
-
-        // 파일 작업 진행 중일 때는 짧은 타임아웃으로 이벤트 체크
-        let poll_timeout = if app.is_operation_processing() {
-            std::time::Duration::from_millis(1)
-        } else if app.pending_key.is_some() {
-            std::time::Duration::from_millis(50)
-        } else {
-            std::time::Duration::from_millis(100)
+        // 화면 갱신은 frame_rate 상한까지만 (진행 이벤트가 몰려도 CPU를 쓰지 않도록)
+        let frame_interval = app.frame_interval();
+        let frame_due = last_draw.is_none_or(|drawn| drawn.elapsed() >= frame_interval);
+        if frame_due {
+            draw_frame(terminal, app)?;
+            last_draw = Some(Instant::now());
+        }
+
+        let now = Instant::now();
+        let mut timeout = app.event_wait_timeout(now, more_work);
+        if !frame_due {
+            // 건너뛴 화면은 다음 프레임 시각에 그림
+            let next_frame = last_draw.map_or(now, |drawn| drawn + frame_interval);
+            timeout = timeout.min(next_frame.saturating_duration_since(now));
+        }
+
+        // 다음 이벤트 대기 (작업 중에도 ESC 키 처리 가능)
+        let mut event = match deferred.take() {
+            Some(event) => Some(event),
+            None => match events.recv_timeout(timeout) {
+                Ok(event) => Some(event),
+                Err(mpsc::RecvTimeoutError::Timeout) => None,
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            },
         };
-
-        // Handle events (작업 중에도 ESC 키 처리 가능)
-        if event::poll(poll_timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if matches!(key.kind, KeyEventKind::Release) {
-                    continue;
-                }
-                if app.is_dialog_active() {
-                    // 다이얼로그 모드에서의 키 처리
-                    handle_dialog_keys(app, key.modifiers, key.code);
-                } else if app.is_menu_active() {
-                    // 메뉴 모드에서의 키 처리
-                    handle_menu_keys(app, key.modifiers, key.code);
-                } else {
-                    // 일반 모드에서의 키 처리
-                    handle_normal_keys(app, key.modifiers, key.code);
-                }
+        // 창 크기를 끄는 동안 쏟아지는 Resize와 중복 깨우기는 마지막 것만 반영
+        while matches!(
+            event,
+            Some(LoopEvent::Wake) | Some(LoopEvent::Input(Event::Resize(..)))
+        ) {
+            match events.try_recv() {
+                Ok(next) => event = Some(next),
+                Err(_) => break,
             }
         }
-
-        // pending 키 타임아웃 체크
-        if app.pending_key.is_some() && app.is_pending_key_expired() {
-            app.clear_pending_key();
+        match event {
+            Some(LoopEvent::Input(event)) => handle_event(app, event),
+            Some(LoopEvent::InputError(err)) => return Err(err.into()),
+            Some(LoopEvent::Wake) | None => {}
         }
-
-        // 토스트 메시지 만료 체크
-        app.clear_expired_toast();
+        waker.reset();
 
         // IME 상태 폴링
         let new_ime = ime::get_current_ime();
@@ -142,27 +393,82 @@ fn run_app<B: ratatui::backend::Backend<Error = io::Error>>(
             app.ime_status = new_ime;
         }
 
-        // 파일 작업 진행 중이면 다음 파일 처리
+        // 만료 확인과 배경 작업 결과 반영
+        let mut commands = VecDeque::from(app.update(Msg::Tick(Instant::now())));
+
+        // 파일 작업 진행 중이면 다음 프레임까지 이어서 처리
         if app.is_operation_processing() {
-            if app.is_delete_operation() {
-                app.process_next_delete();
-            } else if app.is_archive_operation() {
-                app.process_next_archive();
-            } else {
-                app.process_next_file();
+            let budget_end = Instant::now() + frame_interval;
+            app.process_operation_step();
+            while app.has_stepped_operation() && Instant::now() < budget_end {
+                // 입력이 들어오면 끊고 다음 반복에서 먼저 처리 (ESC 취소 등)
+                if let Ok(event) = events.try_recv() {
+                    deferred = Some(event);
+                    break;
+                }
+                app.process_operation_step();
             }
         }
 
-        if let Some(request) = app.take_pending_terminal_editor_request() {
-            let result = run_terminal_editor_request(&request);
-            app.refresh_both_panels();
-            app.apply_terminal_editor_result(&request, result);
-            let _ = terminal.clear();
-        }
-        if let Some(request) = app.take_pending_terminal_command_request() {
-            let result = run_terminal_command_request(&request);
-            app.refresh_both_panels();
-            app.apply_terminal_command_result(&request, result);
+        // 앱이 요청한 외부 작업 실행, 결과는 다시 앱에 전달
+        commands.extend(app.take_commands());
+        more_work = false;
+        while let Some(command) = commands.pop_front() {
+            let msg = match command {
+                app::Command::PollSoon => {
+                    more_work = true;
+                    continue;
+                }
+                app::Command::PressKey(code, modifiers) => {
+                    dispatch_key(app, modifiers, code);
+                    commands.extend(app.take_commands());
+                    continue;
+                }
+                app::Command::EditFile(request) => {
+                    input.pause();
+                    let result = run_terminal_editor_request(&request);
+                    input.resume();
+                    Msg::EditorFinished(request, result)
+                }
+                app::Command::RunShell(request) => {
+                    input.pause();
+                    let result = run_terminal_command_request(&request);
+                    input.resume();
+                    Msg::ShellCommandFinished(request, result)
+                }
+                app::Command::RunElevated(request) => {
+                    let started = Instant::now();
+                    let errors = if request.needs_terminal() {
+                        let prompt = app.elevation_terminal_prompt(&request);
+                        input.pause();
+                        let result = suspend_tui_and_run(|| {
+                            println!("{}", prompt);
+                            Ok(system::privilege::run(&request))
+                        });
+                        input.resume();
+                        result.unwrap_or_else(|e| vec![e])
+                    } else {
+                        system::privilege::run(&request)
+                    };
+                    Msg::ElevatedFinished {
+                        request,
+                        errors,
+                        duration: started.elapsed(),
+                    }
+                }
+                app::Command::RunExecutable(request) => {
+                    let result = if request.capture {
+                        run_executable_captured(&request)
+                    } else {
+                        input.pause();
+                        let result = suspend_tui_and_run(|| run_executable_in_terminal(&request));
+                        input.resume();
+                        result
+                    };
+                    Msg::ExecutableFinished(request, result)
+                }
+            };
+            commands.extend(app.update(msg));
             let _ = terminal.clear();
         }
 
@@ -171,9 +477,52 @@ fn run_app<B: ratatui::backend::Backend<Error = io::Error>>(
         }
     }
 
+    // 종료 확인을 껐거나 작업이 남은 채 끝나도 작업 스레드를 정리한 뒤 터미널 복원
+    app.cancel_running_jobs();
+
+    Ok(())
+}
+
+/// 화면 한 장 그리기
+fn draw_frame<B: ratatui::backend::Backend<Error = io::Error>>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+) -> Result<()> {
+    terminal.draw(|f| render_frame(f, app))?;
     Ok(())
 }
 
+/// 프레임 내용 그리기 (백엔드와 무관, 스냅샷 테스트도 이 경로로 그림)
+fn render_frame(f: &mut ratatui::Frame<'_>, app: &mut App) {
+    let size = f.area();
+
+    // 레이아웃 업데이트 (크기가 바뀌면 커서가 화면 안에 남도록 스크롤 보정)
+    if app.layout.update(size) {
+        app.keep_panels_in_view();
+    }
+
+    match app.layout.mode() {
+        LayoutMode::TooSmall => {
+            // 경고 화면 표시
+            let (width, height) = app.layout.terminal_size();
+            let warning = WarningScreen::new()
+                .current_size(width, height)
+                .language(app.language())
+                .theme(app.theme_manager.current());
+            f.render_widget(warning, size);
+        }
+        LayoutMode::SinglePanel => {
+            render_main_ui(f, app);
+        }
+        LayoutMode::DualPanel => {
+            render_main_ui(f, app);
+        }
+    }
+
+    // truecolor 미지원 터미널: RGB 색상을 256/16색으로 변환
+    ui::theme::downgrade_buffer(f.buffer_mut(), app.color_depth());
+}
+
 fn run_terminal_editor_request(request: &TerminalEditorRequest) -> std::result::Result<(), String> {
     suspend_tui_and_run(|| run_editor_process(&request.editor_command, &request.target_path))
 }
@@ -184,14 +533,65 @@ fn run_terminal_command_request(
     suspend_tui_and_run(|| run_shell_command(&request.command, &request.working_dir))
 }
 
-fn suspend_tui_and_run<F>(run: F) -> std::result::Result<(), String>
+fn executable_command(request: &ExecutableRunRequest) -> Command {
+    let mut command = Command::new(&request.program);
+    command
+        .args(&request.args)
+        .envs(request.env.iter().map(|(name, value)| (name, value)))
+        .current_dir(&request.working_dir);
+    command
+}
+
+/// TUI를 유지한 채 실행하고 출력을 모음 (stdin은 닫음)
+fn run_executable_captured(
+    request: &ExecutableRunRequest,
+) -> std::result::Result<ExecutableRunOutcome, String> {
+    let output = executable_command(request)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to start '{}': {}", request.program.display(), e))?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    if !output.stderr.is_empty() {
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(&String::from_utf8_lossy(&output.stderr));
+    }
+    Ok(ExecutableRunOutcome {
+        exit_code: output.status.code(),
+        output: Some(text),
+    })
+}
+
+/// TUI를 멈춘 터미널에서 실행하고, 출력을 확인할 수 있게 Enter를 기다림
+fn run_executable_in_terminal(
+    request: &ExecutableRunRequest,
+) -> std::result::Result<ExecutableRunOutcome, String> {
+    let status = executable_command(request)
+        .status()
+        .map_err(|e| format!("Failed to start '{}': {}", request.program.display(), e))?;
+    println!("\n[{}] Press Enter to return...", status);
+    let mut line = String::new();
+    let _ = io::stdin().read_line(&mut line);
+    Ok(ExecutableRunOutcome {
+        exit_code: status.code(),
+        output: None,
+    })
+}
+
+fn suspend_tui_and_run<T, F>(run: F) -> std::result::Result<T, String>
 where
-    F: FnOnce() -> std::result::Result<(), String>,
+    F: FnOnce() -> std::result::Result<T, String>,
 {
     disable_raw_mode().map_err(|e| format!("Failed to disable raw mode: {}", e))?;
 
     let mut stdout = io::stdout();
-    if let Err(e) = execute!(stdout, LeaveAlternateScreen) {
+    if let Err(e) = execute!(
+        stdout,
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        LeaveAlternateScreen
+    ) {
         let _ = enable_raw_mode();
         return Err(format!("Failed to leave alternate screen: {}", e));
     }
@@ -199,7 +599,12 @@ where
     let run_result = run();
 
     let mut restore_errors = Vec::new();
-    if let Err(e) = execute!(stdout, EnterAlternateScreen) {
+    if let Err(e) = execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableBracketedPaste,
+        EnableMouseCapture
+    ) {
         restore_errors.push(format!("enter alternate screen: {}", e));
     }
     if let Err(e) = enable_raw_mode() {
@@ -278,9 +683,7 @@ fn run_shell_command(command: &str, working_dir: &Path) -> std::result::Result<(
             }
 
             match run_shell_command_with(fallback_shell, command, working_dir) {
-                Ok(status) => {
-                    shell_command_status_to_result(command, status, Some(fallback_shell))
-                }
+                Ok(status) => shell_command_status_to_result(command, status, Some(fallback_shell)),
                 Err(fallback_err) => Err(format!(
                     "{}; fallback '{}' failed: {}",
                     primary_err, fallback_shell, fallback_err
@@ -325,19 +728,55 @@ fn shell_command_status_to_result(
 
 /// 일반 모드 키 처리 (액션 레지스트리 기반)
 fn handle_normal_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
-    // 1) pending 키 시퀀스 처리 (gg, s+키, t+키, z+키)
+    // 1) pending 키 시퀀스 처리 (gg, s+키, t+키, z+키, q+레지스터, @+레지스터)
     if let Some(pending) = app.pending_key {
         app.clear_pending_key();
         if modifiers == KeyModifiers::NONE {
             if let KeyCode::Char(key) = code {
                 if let Some(action) = find_sequence_action(pending, key) {
+                    app.record_macro_action(action);
                     app.execute_action(action);
                     return;
                 }
+                if let (BOOKMARK_HOTKEY_PREFIX, Some(digit)) = (pending, key.to_digit(10)) {
+                    app.record_macro_key(KeyCode::Char(pending), KeyModifiers::NONE);
+                    app.record_macro_key(code, modifiers);
+                    app.jump_to_bookmark_hotkey(digit as u8);
+                    return;
+                }
+                if pending == MACRO_RECORD_PREFIX
+                    && key.is_ascii_lowercase()
+                    && key != MACRO_RECORD_PREFIX
+                {
+                    app.start_macro_recording(key);
+                    return;
+                }
+                if pending == MACRO_PLAY_PREFIX
+                    && (key.is_ascii_lowercase() || key == MACRO_PLAY_PREFIX)
+                {
+                    app.play_macro(key);
+                    return;
+                }
+            }
+        }
+        // `q` 다음 레지스터가 아닌 키는 종료 (Esc는 취소)
+        if pending == MACRO_RECORD_PREFIX {
+            if code != KeyCode::Esc {
+                app.execute_action(Action::Quit);
             }
+            return;
         }
     }
 
+    // 녹화 중 `q`는 녹화 종료
+    if app.is_recording_macro()
+        && modifiers == KeyModifiers::NONE
+        && code == KeyCode::Char(MACRO_RECORD_PREFIX)
+    {
+        app.stop_macro_recording();
+        return;
+    }
+
     // 2) 시퀀스 prefix 입력 시 시퀀스 모드 진입
     if modifiers == KeyModifiers::NONE {
         if let KeyCode::Char(c) = code {
@@ -352,6 +791,7 @@ fn handle_normal_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
 
     // 3) 테이블 조회 → 액션 실행
     if let Some(action) = find_action(modifiers, code) {
+        app.record_macro_action(action);
         app.execute_action(action);
     } else if let KeyCode::Char(c) = code {
         // 4) 한글 입력 감지: 액션 매칭 실패 + 한글 문자인 경우 경고
@@ -394,6 +834,12 @@ fn handle_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
         DialogKind::DeleteConfirm { .. } => {
             handle_delete_confirm_dialog_keys(app, modifiers, code);
         }
+        DialogKind::QuitConfirm { .. } => {
+            handle_quit_confirm_dialog_keys(app, modifiers, code);
+        }
+        DialogKind::FileInUse { .. } => {
+            handle_file_in_use_dialog_keys(app, modifiers, code);
+        }
         // Phase 3.4
         DialogKind::MkdirInput { .. } => {
             handle_mkdir_input_dialog_keys(app, modifiers, code);
@@ -402,7 +848,7 @@ fn handle_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
             handle_rename_input_dialog_keys(app, modifiers, code);
         }
         DialogKind::Properties { .. } => {
-            handle_message_dialog_keys(app, modifiers, code);
+            handle_properties_dialog_keys(app, modifiers, code);
         }
         DialogKind::Help { .. } => {
             handle_help_dialog_keys(app, modifiers, code);
@@ -427,68 +873,65 @@ fn handle_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
         DialogKind::BookmarkRenameInput { .. } => {
             handle_bookmark_rename_input_dialog_keys(app, modifiers, code);
         }
-        DialogKind::ArchivePreviewList { .. } => {
-            handle_archive_preview_dialog_keys(app, code);
+        DialogKind::SessionList { .. } => {
+            handle_session_list_dialog_keys(app, code);
         }
-    }
-}
-
-#[derive(Clone, Copy)]
-struct TextEditHandlers {
-    delete_prev_word: fn(&mut App),
-    input_char: fn(&mut App, char),
-    backspace: fn(&mut App),
-    delete: fn(&mut App),
-    left: fn(&mut App),
-    right: fn(&mut App),
-    home: fn(&mut App),
-    end: fn(&mut App),
-}
-
-fn handle_text_edit_keys(
-    app: &mut App,
-    modifiers: KeyModifiers,
-    code: KeyCode,
-    handlers: TextEditHandlers,
-) -> bool {
-    if is_prev_word_delete_shortcut(modifiers, code) {
-        (handlers.delete_prev_word)(app);
-        return true;
-    }
-
-    match (modifiers, code) {
-        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => {
-            (handlers.input_char)(app, c);
-            true
+        DialogKind::JobList { .. } => {
+            handle_job_list_dialog_keys(app, code);
         }
-        (_, KeyCode::Backspace) => {
-            (handlers.backspace)(app);
-            true
+        DialogKind::RemoteHostList { .. } => {
+            handle_remote_host_list_dialog_keys(app, code);
         }
-        (_, KeyCode::Delete) => {
-            (handlers.delete)(app);
-            true
+        DialogKind::ThemeList { .. } => {
+            handle_theme_list_dialog_keys(app, code);
         }
-        (_, KeyCode::Left) => {
-            (handlers.left)(app);
-            true
+        DialogKind::ArchivePreviewList { .. } => {
+            handle_archive_preview_dialog_keys(app, code);
         }
-        (_, KeyCode::Right) => {
-            (handlers.right)(app);
-            true
+        DialogKind::TextViewer { .. } => {
+            handle_text_viewer_dialog_keys(app, code);
         }
-        (_, KeyCode::Home) => {
-            (handlers.home)(app);
-            true
+        DialogKind::FuzzyList { .. } => {
+            handle_fuzzy_list_dialog_keys(app, modifiers, code);
         }
-        (_, KeyCode::End) => {
-            (handlers.end)(app);
-            true
+        DialogKind::FileCompare { .. } => {
+            if matches!(code, KeyCode::Esc | KeyCode::Char('q')) {
+                app.cancel_file_compare();
+            }
+        }
+        DialogKind::DiskUsage { pending_delete, .. } => {
+            if pending_delete.is_some() {
+                handle_disk_usage_delete_confirm_keys(app, code);
+            } else {
+                handle_disk_usage_dialog_keys(app, code);
+            }
+        }
+        DialogKind::PanelSync { .. } => {
+            handle_panel_sync_dialog_keys(app, code);
         }
-        _ => false,
     }
 }
 
+/// 글자 입력 칸 공통 편집 키 (처리했으면 true)
+fn handle_text_edit_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) -> bool {
+    let edit = if is_prev_word_delete_shortcut(modifiers, code) {
+        TextEdit::DeletePrevWord
+    } else {
+        match (modifiers, code) {
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => TextEdit::Insert(c),
+            (_, KeyCode::Backspace) => TextEdit::Backspace,
+            (_, KeyCode::Delete) => TextEdit::Delete,
+            (_, KeyCode::Left) => TextEdit::Left,
+            (_, KeyCode::Right) => TextEdit::Right,
+            (_, KeyCode::Home) => TextEdit::Home,
+            (_, KeyCode::End) => TextEdit::End,
+            _ => return false,
+        }
+    };
+    app.dialog_text_edit(edit);
+    true
+}
+
 /// 입력 다이얼로그 키 처리
 fn handle_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
     let input_purpose = app.get_dialog_input_purpose();
@@ -502,6 +945,11 @@ fn handle_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCod
                 if let Some(value) = app.get_dialog_input_value() {
                     app.confirm_input_dialog(value);
                 }
+            } else if selected_button == 2 {
+                // Preview 버튼
+                if let Some(value) = app.get_dialog_input_value() {
+                    app.preview_operation_destination(value);
+                }
             } else {
                 // Cancel 버튼
                 app.close_dialog();
@@ -512,7 +960,11 @@ fn handle_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCod
             app.close_dialog();
         }
         // Go to Path: Tab으로 추천 적용, Shift+Tab으로 이전 추천
-        (KeyModifiers::NONE, KeyCode::Tab) if input_purpose == Some(InputPurpose::GoToPath) => {
+        // (다른 경로 입력도 적용할 추천이 있으면 Tab으로 적용)
+        (KeyModifiers::NONE, KeyCode::Tab)
+            if input_purpose == Some(InputPurpose::GoToPath)
+                || app.has_input_completion_to_apply() =>
+        {
             app.dialog_input_apply_selected_completion();
         }
         (KeyModifiers::SHIFT, KeyCode::BackTab)
@@ -520,6 +972,41 @@ fn handle_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCod
         {
             app.dialog_input_cycle_completion_prev();
         }
+        // 대체 확정 (새 파일: 만든 뒤 편집, 실행 파일: 출력 모아 실행)
+        (KeyModifiers::CONTROL, KeyCode::Char(c))
+            if matches!(
+                input_purpose,
+                Some(InputPurpose::Prompt(input)) if input.alternate_key == Some(c)
+            ) =>
+        {
+            if let Some(value) = app.get_dialog_input_value() {
+                app.finish_dialog(DialogOutcome::AlternateText(value));
+            }
+        }
+        // 복사/이동 범위 필터 편집
+        (KeyModifiers::CONTROL, KeyCode::Char('f')) => {
+            app.edit_operation_scope_filter();
+        }
+        // 복사 옵션: 검증 / 시각·권한·확장 속성 유지 전환
+        (KeyModifiers::CONTROL, KeyCode::Char('r')) => {
+            app.dialog_input_toggle_copy_option(CopyOption::Verify);
+        }
+        (KeyModifiers::CONTROL, KeyCode::Char('t')) => {
+            app.dialog_input_toggle_copy_option(CopyOption::PreserveTimestamps);
+        }
+        (KeyModifiers::CONTROL, KeyCode::Char('p')) => {
+            app.dialog_input_toggle_copy_option(CopyOption::PreservePermissions);
+        }
+        (KeyModifiers::CONTROL, KeyCode::Char('x')) => {
+            app.dialog_input_toggle_copy_option(CopyOption::PreserveXattrs);
+        }
+        (KeyModifiers::CONTROL, KeyCode::Char('u')) => {
+            app.dialog_input_toggle_copy_option(CopyOption::UpdateOnly);
+        }
+        // 복사 옵션: 심볼릭 링크 처리 (링크 → 따라가기 → 건너뛰기)
+        (KeyModifiers::CONTROL, KeyCode::Char('l')) => {
+            app.dialog_input_cycle_symlink_policy();
+        }
         // 기타 입력 다이얼로그: 버튼 전환 (Tab / Shift+Tab)
         (KeyModifiers::NONE, KeyCode::Tab) | (KeyModifiers::SHIFT, KeyCode::BackTab) => {
             app.dialog_input_toggle_button();
@@ -536,35 +1023,31 @@ fn handle_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCod
             app.dialog_input_cycle_completion_prev();
         }
         _ => {
-            let _ = handle_text_edit_keys(
-                app,
-                modifiers,
-                code,
-                TextEditHandlers {
-                    delete_prev_word: App::dialog_input_delete_prev_word,
-                    input_char: App::dialog_input_char,
-                    backspace: App::dialog_input_backspace,
-                    delete: App::dialog_input_delete,
-                    left: App::dialog_input_left,
-                    right: App::dialog_input_right,
-                    home: App::dialog_input_home,
-                    end: App::dialog_input_end,
-                },
-            );
+            let _ = handle_text_edit_keys(app, modifiers, code);
         }
     }
 }
 
 fn handle_archive_create_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
-    if is_prev_word_delete_shortcut(modifiers, code) {
-        app.archive_create_dialog_delete_prev_word();
-        return;
-    }
-
     match (modifiers, code) {
         (_, KeyCode::Esc) => app.close_dialog(),
         (KeyModifiers::NONE, KeyCode::Tab) => app.archive_create_dialog_next_field(),
         (KeyModifiers::SHIFT, KeyCode::BackTab) => app.archive_create_dialog_prev_field(),
+        // 프리셋: Alt+숫자는 어디서나, 숫자만은 글자 입력 칸이 아닐 때
+        (KeyModifiers::ALT, KeyCode::Char(c @ '1'..='9')) => {
+            app.archive_create_dialog_apply_preset(c as usize - '1' as usize);
+        }
+        (KeyModifiers::NONE, KeyCode::Char(c @ '1'..='9'))
+            if matches!(
+                app.dialog,
+                Some(DialogKind::ArchiveCreateOptions {
+                    focused_field: 1 | 4,
+                    ..
+                })
+            ) =>
+        {
+            app.archive_create_dialog_apply_preset(c as usize - '1' as usize);
+        }
         (KeyModifiers::NONE, KeyCode::Up) => app.archive_create_dialog_prev_field(),
         (KeyModifiers::NONE, KeyCode::Down) => app.archive_create_dialog_next_field(),
         (KeyModifiers::NONE, KeyCode::Char(' ')) => {
@@ -572,7 +1055,7 @@ fn handle_archive_create_dialog_keys(app: &mut App, modifiers: KeyModifiers, cod
                 if *focused_field == 1 {
                     app.archive_create_dialog_toggle_password();
                 } else {
-                    app.archive_create_dialog_char(' ');
+                    app.dialog_text_edit(TextEdit::Insert(' '));
                 }
             }
         }
@@ -600,16 +1083,11 @@ fn handle_archive_create_dialog_keys(app: &mut App, modifiers: KeyModifiers, cod
             }
             app.confirm_archive_create_dialog();
         }
-        (_, KeyCode::Backspace) => app.archive_create_dialog_backspace(),
-        (_, KeyCode::Delete) => app.archive_create_dialog_delete(),
-        (_, KeyCode::Left) => app.archive_create_dialog_left(),
-        (_, KeyCode::Right) => app.archive_create_dialog_right(),
-        (_, KeyCode::Home) => app.archive_create_dialog_home(),
-        (_, KeyCode::End) => app.archive_create_dialog_end(),
-        (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => {
-            app.archive_create_dialog_char(c);
+        (_, KeyCode::Left) => app.archive_create_dialog_move(false),
+        (_, KeyCode::Right) => app.archive_create_dialog_move(true),
+        _ => {
+            let _ = handle_text_edit_keys(app, modifiers, code);
         }
-        _ => {}
     }
 }
 
@@ -628,7 +1106,7 @@ fn handle_confirm_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyC
                     if let Some(value) = app.get_dialog_input_value() {
                         app.confirm_input_dialog(value);
                     } else {
-                        app.close_dialog();
+                        app.confirm_confirm_dialog();
                     }
                 } else {
                     // Cancel
@@ -640,6 +1118,9 @@ fn handle_confirm_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyC
         (_, KeyCode::Esc) => {
             app.close_dialog();
         }
+        // 작업 결과 창: 전체 오류 보기 / 보고서 저장
+        (_, KeyCode::Char('v')) => app.show_error_report(),
+        (_, KeyCode::Char('s')) => app.save_error_report(),
         _ => {}
     }
 }
@@ -669,9 +1150,45 @@ fn handle_conflict_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: Key
 }
 
 /// 진행률 다이얼로그 키 처리
-fn handle_progress_dialog_keys(app: &mut App, _modifiers: KeyModifiers, code: KeyCode) {
-    if code == KeyCode::Esc {
-        app.cancel_operation();
+fn handle_progress_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    match (modifiers, code) {
+        (_, KeyCode::Esc) => app.cancel_operation(),
+        (_, KeyCode::Char('p')) | (_, KeyCode::Char(' ')) => app.toggle_operation_pause(),
+        // 작업 중 종료 (확인 창에서 기다릴지/취소할지 선택)
+        (KeyModifiers::CONTROL, KeyCode::Char('c')) | (_, KeyCode::F(10)) => {
+            app.execute_action(core::actions::Action::Quit)
+        }
+        _ => {}
+    }
+}
+
+/// 작업 중 종료 확인 다이얼로그 키 처리
+fn handle_quit_confirm_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    match (modifiers, code) {
+        (KeyModifiers::NONE, KeyCode::Tab) | (_, KeyCode::Right) => {
+            app.dialog_quit_confirm_move(true);
+        }
+        (KeyModifiers::SHIFT, KeyCode::BackTab) | (_, KeyCode::Left) => {
+            app.dialog_quit_confirm_move(false);
+        }
+        (_, KeyCode::Enter) => app.confirm_quit_dialog(),
+        (_, KeyCode::Esc) => app.close_quit_dialog(),
+        _ => {}
+    }
+}
+
+/// 사용 중인 파일 다이얼로그 키 처리
+fn handle_file_in_use_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    match (modifiers, code) {
+        (KeyModifiers::NONE, KeyCode::Tab) | (_, KeyCode::Right) => {
+            app.dialog_file_in_use_move(true);
+        }
+        (KeyModifiers::SHIFT, KeyCode::BackTab) | (_, KeyCode::Left) => {
+            app.dialog_file_in_use_move(false);
+        }
+        (_, KeyCode::Enter) => app.confirm_file_in_use(),
+        (_, KeyCode::Esc) => app.close_dialog(),
+        _ => {}
     }
 }
 
@@ -717,25 +1234,27 @@ fn handle_mkdir_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code:
             }
         }
         (_, KeyCode::Esc) => app.close_dialog(),
+        // 적용할 추천이 있으면 Tab으로 적용, 없으면 버튼 전환
+        (KeyModifiers::NONE, KeyCode::Tab) if app.has_input_completion_to_apply() => {
+            app.dialog_input_apply_selected_completion();
+        }
         (KeyModifiers::NONE, KeyCode::Tab) | (KeyModifiers::SHIFT, KeyCode::BackTab) => {
             app.dialog_mkdir_toggle_button();
         }
+        (KeyModifiers::CONTROL, KeyCode::Char(' ')) => {
+            app.dialog_input_apply_selected_completion();
+        }
+        (KeyModifiers::NONE, KeyCode::Down) => {
+            app.dialog_input_cycle_completion_next();
+        }
+        (KeyModifiers::NONE, KeyCode::Up) => {
+            app.dialog_input_cycle_completion_prev();
+        }
+        (KeyModifiers::CONTROL, KeyCode::Char('e')) => {
+            app.dialog_mkdir_toggle_enter_created();
+        }
         _ => {
-            let _ = handle_text_edit_keys(
-                app,
-                modifiers,
-                code,
-                TextEditHandlers {
-                    delete_prev_word: App::dialog_mkdir_input_delete_prev_word,
-                    input_char: App::dialog_mkdir_input_char,
-                    backspace: App::dialog_mkdir_input_backspace,
-                    delete: App::dialog_mkdir_input_delete,
-                    left: App::dialog_mkdir_input_left,
-                    right: App::dialog_mkdir_input_right,
-                    home: App::dialog_mkdir_input_home,
-                    end: App::dialog_mkdir_input_end,
-                },
-            );
+            let _ = handle_text_edit_keys(app, modifiers, code);
         }
     }
 }
@@ -758,21 +1277,7 @@ fn handle_rename_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code:
             app.dialog_rename_toggle_button();
         }
         _ => {
-            let _ = handle_text_edit_keys(
-                app,
-                modifiers,
-                code,
-                TextEditHandlers {
-                    delete_prev_word: App::dialog_rename_input_delete_prev_word,
-                    input_char: App::dialog_rename_input_char,
-                    backspace: App::dialog_rename_input_backspace,
-                    delete: App::dialog_rename_input_delete,
-                    left: App::dialog_rename_input_left,
-                    right: App::dialog_rename_input_right,
-                    home: App::dialog_rename_input_home,
-                    end: App::dialog_rename_input_end,
-                },
-            );
+            let _ = handle_text_edit_keys(app, modifiers, code);
         }
     }
 }
@@ -783,10 +1288,28 @@ fn handle_message_dialog_keys(app: &mut App, _modifiers: KeyModifiers, code: Key
         KeyCode::Enter | KeyCode::Esc | KeyCode::Char(' ') => {
             app.close_dialog();
         }
+        // 작업 결과 창: 전체 오류 보기 / 보고서 저장
+        KeyCode::Char('v') => app.show_error_report(),
+        KeyCode::Char('s') => app.save_error_report(),
         _ => {}
     }
 }
 
+/// 속성 다이얼로그 키 처리 (w/h/s: 속성 전환, j/k: 확장 속성 이동, q: 격리 해제)
+fn handle_properties_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    match code {
+        KeyCode::Char('w') => app.toggle_properties_attribute(Attribute::ReadOnly),
+        KeyCode::Char('h') | KeyCode::Char('.') => {
+            app.toggle_properties_attribute(Attribute::Hidden)
+        }
+        KeyCode::Char('s') => app.toggle_properties_attribute(Attribute::System),
+        KeyCode::Char('j') | KeyCode::Down => app.properties_xattr_move(true),
+        KeyCode::Char('k') | KeyCode::Up => app.properties_xattr_move(false),
+        KeyCode::Char('q') => app.remove_properties_quarantine(),
+        _ => handle_message_dialog_keys(app, modifiers, code),
+    }
+}
+
 /// 도움말 다이얼로그 키 처리
 fn handle_help_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
     let in_search_mode = matches!(
@@ -798,24 +1321,12 @@ fn handle_help_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode
     );
 
     if in_search_mode {
-        if is_prev_word_delete_shortcut(modifiers, code) {
-            app.dialog_help_delete_prev_word();
-            return;
-        }
-
-        match (modifiers, code) {
-            (_, KeyCode::Enter) => app.dialog_help_end_search(),
-            (_, KeyCode::Esc) => app.dialog_help_clear_or_close(),
-            (_, KeyCode::Backspace) => app.dialog_help_backspace(),
-            (_, KeyCode::Delete) => app.dialog_help_delete(),
-            (_, KeyCode::Left) => app.dialog_help_cursor_left(),
-            (_, KeyCode::Right) => app.dialog_help_cursor_right(),
-            (_, KeyCode::Home) => app.dialog_help_cursor_home(),
-            (_, KeyCode::End) => app.dialog_help_cursor_end(),
-            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => {
-                app.dialog_help_input_char(c)
+        match code {
+            KeyCode::Enter => app.dialog_help_end_search(),
+            KeyCode::Esc => app.dialog_help_clear_or_close(),
+            _ => {
+                let _ = handle_text_edit_keys(app, modifiers, code);
             }
-            _ => {}
         }
         return;
     }
@@ -840,86 +1351,208 @@ fn handle_help_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode
     }
 }
 
+/// 선택 목록 다이얼로그 공통 키 처리 (이동, 검색어 입력). 처리했으면 true
+fn handle_list_dialog_keys(app: &mut App, code: KeyCode) -> bool {
+    if app.list_dialog_searching() {
+        match code {
+            KeyCode::Esc => app.list_dialog_finish_search(false),
+            KeyCode::Enter | KeyCode::Down | KeyCode::Up => app.list_dialog_finish_search(true),
+            KeyCode::Backspace => app.list_dialog_edit_search(None),
+            KeyCode::Char(c) => app.list_dialog_edit_search(Some(c)),
+            _ => {}
+        }
+        return true;
+    }
+    let movement = match code {
+        KeyCode::Char('j') | KeyCode::Down => ListMove::Down,
+        KeyCode::Char('k') | KeyCode::Up => ListMove::Up,
+        KeyCode::PageDown => ListMove::PageDown,
+        KeyCode::PageUp => ListMove::PageUp,
+        KeyCode::Home => ListMove::First,
+        KeyCode::End => ListMove::Last,
+        _ => return false,
+    };
+    app.list_dialog_move(movement);
+    true
+}
+
 /// 마운트 포인트 다이얼로그 키 처리
 fn handle_mount_points_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
     match code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.close_dialog();
         }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.mount_points_move_down();
+        KeyCode::Enter | KeyCode::Char('l') => {
+            app.mount_points_confirm();
         }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.mount_points_move_up();
+        _ => {}
+    }
+}
+
+/// 탭 목록 다이얼로그 키 처리
+fn handle_tab_list_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.close_dialog();
         }
         KeyCode::Enter | KeyCode::Char('l') => {
-            app.mount_points_confirm();
+            app.tab_list_confirm();
+        }
+        KeyCode::Char('r') => {
+            app.tab_list_rename();
+        }
+        KeyCode::Char('K') => {
+            app.tab_list_move(false);
+        }
+        KeyCode::Char('J') => {
+            app.tab_list_move(true);
+        }
+        KeyCode::Char('c') => {
+            app.tab_list_transfer(false);
+        }
+        KeyCode::Char('m') => {
+            app.tab_list_transfer(true);
+        }
+        _ => {}
+    }
+}
+
+/// 히스토리 목록 다이얼로그 키 처리
+fn handle_history_list_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.close_dialog();
+        }
+        KeyCode::Enter | KeyCode::Char('l') => {
+            app.history_list_confirm();
+        }
+        KeyCode::Char('D') => {
+            app.history_list_clear_all();
+        }
+        KeyCode::Char('/') => {
+            app.list_dialog_start_search();
+        }
+        KeyCode::Char('p') => {
+            app.history_list_toggle_pin();
+        }
+        _ => {}
+    }
+}
+
+/// 북마크 목록 다이얼로그 키 처리
+fn handle_bookmark_list_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.close_dialog();
+        }
+        KeyCode::Enter | KeyCode::Char('l') => {
+            app.bookmark_list_confirm();
+        }
+        KeyCode::Char('r') => {
+            app.start_bookmark_rename_selected();
+        }
+        KeyCode::Char('p') => {
+            app.start_bookmark_path_edit_selected();
+        }
+        KeyCode::Char('g') => {
+            app.start_bookmark_group_edit_selected();
+        }
+        KeyCode::Char(c @ '0'..='9') => {
+            app.bookmark_list_assign_hotkey(c as u8 - b'0');
+        }
+        KeyCode::Char('d') => {
+            app.bookmark_list_delete_selected();
+        }
+        _ => {}
+    }
+}
+
+/// 세션 목록 다이얼로그 키 처리
+fn handle_session_list_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.close_dialog();
+        }
+        KeyCode::Enter | KeyCode::Char('l') => {
+            app.session_list_confirm();
+        }
+        KeyCode::Char('d') => {
+            app.session_list_delete_selected();
         }
         _ => {}
     }
 }
 
-/// 탭 목록 다이얼로그 키 처리
-fn handle_tab_list_dialog_keys(app: &mut App, code: KeyCode) {
+/// 작업 목록 다이얼로그 키 처리
+fn handle_job_list_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
     match code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.close_dialog();
         }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.tab_list_move_down();
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.tab_list_move_up();
-        }
         KeyCode::Enter | KeyCode::Char('l') => {
-            app.tab_list_confirm();
+            app.job_list_show_details();
+        }
+        KeyCode::Char('D') => {
+            app.job_list_clear();
         }
         _ => {}
     }
 }
 
-/// 히스토리 목록 다이얼로그 키 처리
-fn handle_history_list_dialog_keys(app: &mut App, code: KeyCode) {
+/// 원격 접속 대상 목록 다이얼로그 키 처리
+fn handle_remote_host_list_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
     match code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.close_dialog();
         }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.history_list_move_down();
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.history_list_move_up();
-        }
         KeyCode::Enter | KeyCode::Char('l') => {
-            app.history_list_confirm();
+            app.remote_host_list_confirm();
         }
-        KeyCode::Char('D') => {
-            app.history_list_clear_all();
+        KeyCode::Char('n') => {
+            app.start_remote_connect_input();
+        }
+        KeyCode::Char('d') => {
+            app.remote_host_list_delete_selected();
         }
         _ => {}
     }
 }
 
-/// 북마크 목록 다이얼로그 키 처리
-fn handle_bookmark_list_dialog_keys(app: &mut App, code: KeyCode) {
+/// 테마 선택 다이얼로그 키 처리
+fn handle_theme_list_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
     match code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.close_dialog();
         }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.bookmark_list_move_down();
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.bookmark_list_move_up();
-        }
         KeyCode::Enter | KeyCode::Char('l') => {
-            app.bookmark_list_confirm();
+            app.theme_list_confirm();
         }
         KeyCode::Char('r') => {
-            app.start_bookmark_rename_selected();
-        }
-        KeyCode::Char('d') => {
-            app.bookmark_list_delete_selected();
+            app.theme_list_reload();
         }
         _ => {}
     }
@@ -932,50 +1565,109 @@ fn handle_bookmark_rename_input_dialog_keys(app: &mut App, modifiers: KeyModifie
             let selected_button = app.get_bookmark_rename_selected_button().unwrap_or(0);
             if selected_button == 0 {
                 if let Some((value, bookmark_index)) = app.get_bookmark_rename_input_value() {
-                    app.confirm_bookmark_rename(value, bookmark_index);
+                    app.confirm_bookmark_edit(value, bookmark_index);
                 }
             } else {
-                app.show_bookmark_list();
+                app.close_dialog();
             }
         }
-        (_, KeyCode::Esc) => app.show_bookmark_list(),
+        (_, KeyCode::Esc) => app.close_dialog(),
         (KeyModifiers::NONE, KeyCode::Tab) | (KeyModifiers::SHIFT, KeyCode::BackTab) => {
             app.dialog_bookmark_rename_toggle_button();
         }
         _ => {
-            let _ = handle_text_edit_keys(
-                app,
-                modifiers,
-                code,
-                TextEditHandlers {
-                    delete_prev_word: App::dialog_bookmark_rename_input_delete_prev_word,
-                    input_char: App::dialog_bookmark_rename_input_char,
-                    backspace: App::dialog_bookmark_rename_input_backspace,
-                    delete: App::dialog_bookmark_rename_input_delete,
-                    left: App::dialog_bookmark_rename_input_left,
-                    right: App::dialog_bookmark_rename_input_right,
-                    home: App::dialog_bookmark_rename_input_home,
-                    end: App::dialog_bookmark_rename_input_end,
-                },
-            );
+            let _ = handle_text_edit_keys(app, modifiers, code);
         }
     }
 }
 
 /// 압축 미리보기 다이얼로그 키 처리
 fn handle_archive_preview_dialog_keys(app: &mut App, code: KeyCode) {
+    if handle_list_dialog_keys(app, code) {
+        return;
+    }
+    if matches!(code, KeyCode::Esc | KeyCode::Char('q')) {
+        app.close_dialog();
+    }
+}
+
+/// 텍스트 보기 다이얼로그 키 처리
+/// 퍼지 목록 키 처리 (문자 입력은 질의 편집)
+fn handle_fuzzy_list_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    match (modifiers, code) {
+        (_, KeyCode::Esc) => app.close_dialog(),
+        (_, KeyCode::Enter) => app.confirm_fuzzy_list(),
+        (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n')) => {
+            app.fuzzy_list_move(true)
+        }
+        (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p')) => {
+            app.fuzzy_list_move(false)
+        }
+        _ => {
+            let _ = handle_text_edit_keys(app, modifiers, code);
+        }
+    }
+}
+
+fn handle_text_viewer_dialog_keys(app: &mut App, code: KeyCode) {
     match code {
         KeyCode::Esc | KeyCode::Char('q') => app.close_dialog(),
-        KeyCode::Char('j') | KeyCode::Down => app.archive_preview_move_down(),
-        KeyCode::Char('k') | KeyCode::Up => app.archive_preview_move_up(),
-        KeyCode::PageDown => app.archive_preview_page_down(),
-        KeyCode::PageUp => app.archive_preview_page_up(),
-        KeyCode::Home => app.archive_preview_go_top(),
-        KeyCode::End => app.archive_preview_go_bottom(),
+        KeyCode::Char('j') | KeyCode::Down => app.text_viewer_scroll(1),
+        KeyCode::Char('k') | KeyCode::Up => app.text_viewer_scroll(-1),
+        KeyCode::PageDown | KeyCode::Char(' ') => app.text_viewer_page_down(),
+        KeyCode::PageUp => app.text_viewer_page_up(),
+        KeyCode::Home | KeyCode::Char('g') => app.text_viewer_go_top(),
+        KeyCode::End | KeyCode::Char('G') => app.text_viewer_go_bottom(),
+        _ => {}
+    }
+}
+
+/// 디스크 사용량 분석 다이얼로그 키 처리
+fn handle_disk_usage_dialog_keys(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => app.close_disk_usage(),
+        KeyCode::Char('j') | KeyCode::Down => app.disk_usage_move_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.disk_usage_move_up(),
+        KeyCode::PageDown => app.disk_usage_page_down(),
+        KeyCode::PageUp => app.disk_usage_page_up(),
+        KeyCode::Home => app.disk_usage_go_top(),
+        KeyCode::End => app.disk_usage_go_bottom(),
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => app.disk_usage_enter(),
+        KeyCode::Char('h') | KeyCode::Left | KeyCode::Backspace => app.disk_usage_go_parent(),
+        KeyCode::Char('d') => app.disk_usage_request_delete(false),
+        KeyCode::Char('D') => app.disk_usage_request_delete(true),
+        KeyCode::Char('g') => app.disk_usage_go_to_panel(),
+        _ => {}
+    }
+}
+
+/// 패널 동기화 다이얼로그 키 처리 (비교/실행 중에는 Esc만 처리됨)
+fn handle_panel_sync_dialog_keys(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => app.close_panel_sync(),
+        KeyCode::Char('j') | KeyCode::Down => app.panel_sync_move_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.panel_sync_move_up(),
+        KeyCode::PageDown => app.panel_sync_page_down(),
+        KeyCode::PageUp => app.panel_sync_page_up(),
+        KeyCode::Home => app.panel_sync_go_top(),
+        KeyCode::End => app.panel_sync_go_bottom(),
+        KeyCode::Char(' ') => app.panel_sync_toggle_checked(),
+        KeyCode::Char('a') => app.panel_sync_toggle_all(),
+        KeyCode::Char('l') | KeyCode::Right => app.panel_sync_set_direction(true),
+        KeyCode::Char('h') | KeyCode::Left => app.panel_sync_set_direction(false),
+        KeyCode::Enter => app.confirm_panel_sync(),
         _ => {}
     }
 }
 
+/// 디스크 사용량 분석 삭제 확인 (y/Enter: 실행, 그 외: 취소)
+fn handle_disk_usage_delete_confirm_keys(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => app.disk_usage_confirm_delete(),
+        _ => app.disk_usage_cancel_delete(),
+    }
+}
+
 /// 필터 입력 다이얼로그 키 처리
 fn handle_filter_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
     match (modifiers, code) {
@@ -997,22 +1689,11 @@ fn handle_filter_input_dialog_keys(app: &mut App, modifiers: KeyModifiers, code:
         (KeyModifiers::NONE, KeyCode::Tab) | (KeyModifiers::SHIFT, KeyCode::BackTab) => {
             app.dialog_filter_toggle_button();
         }
+        (KeyModifiers::CONTROL, KeyCode::Char('r')) => {
+            app.dialog_filter_toggle_regex();
+        }
         _ => {
-            let _ = handle_text_edit_keys(
-                app,
-                modifiers,
-                code,
-                TextEditHandlers {
-                    delete_prev_word: App::dialog_filter_input_delete_prev_word,
-                    input_char: App::dialog_filter_input_char,
-                    backspace: App::dialog_filter_input_backspace,
-                    delete: App::dialog_filter_input_delete,
-                    left: App::dialog_filter_input_left,
-                    right: App::dialog_filter_input_right,
-                    home: App::dialog_filter_input_home,
-                    end: App::dialog_filter_input_end,
-                },
-            );
+            let _ = handle_text_edit_keys(app, modifiers, code);
         }
     }
 }
@@ -1070,8 +1751,62 @@ fn handle_menu_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
             }
         }
         // 종료 단축키는 메뉴에서도 동작
-        (KeyModifiers::CONTROL, KeyCode::Char('c')) => app.quit(),
-        (_, KeyCode::F(10)) => app.quit(),
+        (KeyModifiers::CONTROL, KeyCode::Char('c')) => {
+            app.execute_action(core::actions::Action::Quit)
+        }
+        (_, KeyCode::F(10)) => app.execute_action(core::actions::Action::Quit),
+        _ => {}
+    }
+}
+
+/// 컨텍스트 메뉴 키 처리
+fn handle_context_menu_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    match (modifiers, code) {
+        (_, KeyCode::Esc) | (_, KeyCode::Char('q')) | (_, KeyCode::Char('m')) => {
+            app.close_context_menu()
+        }
+        (_, KeyCode::Up) | (_, KeyCode::Char('k')) => app.context_menu_prev(),
+        (_, KeyCode::Down) | (_, KeyCode::Char('j')) => app.context_menu_next(),
+        (_, KeyCode::Enter) | (_, KeyCode::Char('l')) => app.confirm_context_menu(),
+        (KeyModifiers::CONTROL, KeyCode::Char('c')) => {
+            app.execute_action(core::actions::Action::Quit)
+        }
+        _ => {}
+    }
+}
+
+/// 제목 경로 조각 고르기 키 처리 (다른 키를 누르면 모드만 끝냄)
+fn handle_breadcrumb_keys(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    match (modifiers, code) {
+        (_, KeyCode::Left) | (_, KeyCode::Char('h')) => app.breadcrumb_move(false),
+        (_, KeyCode::Right) | (_, KeyCode::Char('l')) => app.breadcrumb_move(true),
+        (_, KeyCode::Enter) => app.confirm_breadcrumb(),
+        (KeyModifiers::CONTROL, KeyCode::Char('c')) => {
+            app.execute_action(core::actions::Action::Quit)
+        }
+        _ => app.close_breadcrumbs(),
+    }
+}
+
+/// 마우스 처리 (우클릭 컨텍스트 메뉴, 제목 경로 조각 클릭, 휠 스크롤)
+fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
+    if app.is_dialog_active() || app.is_menu_active() {
+        return;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Right) => {
+            app.open_context_menu_at(mouse.column, mouse.row)
+        }
+        MouseEventKind::Down(MouseButton::Left) if app.is_context_menu_active() => {
+            app.click_context_menu(mouse.column, mouse.row)
+        }
+        MouseEventKind::Down(MouseButton::Left) => {
+            app.click_panel_title(mouse.column, mouse.row);
+        }
+        MouseEventKind::ScrollDown if app.is_context_menu_active() => app.context_menu_next(),
+        MouseEventKind::ScrollUp if app.is_context_menu_active() => app.context_menu_prev(),
+        MouseEventKind::ScrollDown => app.execute_action(core::actions::Action::MoveDown),
+        MouseEventKind::ScrollUp => app.execute_action(core::actions::Action::MoveUp),
         _ => {}
     }
 }
@@ -1085,15 +1820,24 @@ fn render_panel(
     is_active: bool,
     language: ui::Language,
     theme: &ui::Theme,
+    highlight_rules: &ui::highlight::HighlightRules,
     area: Rect,
     icon_mode: ui::components::panel::IconMode,
     size_format: app::SizeFormat,
+    info_line: Option<&str>,
+    name_scroll: usize,
+    breadcrumb_focus: Option<usize>,
+    file_types: Option<&HashMap<PathBuf, &'static str>>,
+    plugin_columns: Option<app::PluginColumnsView<'_>>,
+    auto_refresh: Option<u64>,
 ) {
     let path = panel_state.current_path.to_string_lossy();
     let show_parent = panel_state.current_path.parent().is_some();
+    let git_summary = panel_state.git_repo.as_ref().map(|repo| repo.label());
     let panel = Panel::new()
         .title(&path)
         .tab_count(tab_count)
+        .tab_label(panel_state.tab_title.as_deref())
         .status(if is_active {
             PanelStatus::Active
         } else {
@@ -1108,8 +1852,23 @@ fn render_panel(
         .sort_state(panel_state.sort_by, panel_state.sort_order)
         .filter_pattern(panel_state.filter.as_deref())
         .size_format(size_format)
+        .dir_sizes(&panel_state.dir_sizes)
+        .git_status(&panel_state.git_status)
+        .git_summary(git_summary.as_deref())
+        .auto_refresh(auto_refresh)
+        .show_hidden(panel_state.show_hidden)
+        .info_line(info_line)
+        .name_scroll(if is_active { name_scroll } else { 0 })
+        .breadcrumb_focus(breadcrumb_focus)
+        .loading(panel_state.is_loading())
+        .file_types(file_types)
+        .highlight_rules(highlight_rules)
         .language(language)
         .theme(theme);
+    let panel = match plugin_columns {
+        Some(view) => panel.plugin_columns(view.columns, view.values),
+        None => panel,
+    };
     f.render_widget(panel, area);
 }
 
@@ -1132,6 +1891,14 @@ fn render_status_bar(f: &mut ratatui::Frame<'_>, app: &App, theme: &ui::Theme, a
     let sort_display = active_panel_state.sort_indicator_localized(app.language());
     let filter_display = active_panel_state.filter_indicator_localized(app.language());
     let ime_label = app.ime_status.display_label();
+    let disk_space = app.active_disk_space();
+    let disk_display = disk_space.map(|space| {
+        (
+            format_file_size(space.available),
+            format_file_size(space.total),
+        )
+    });
+    let (jobs_unseen, jobs_failed) = app.jobs_unseen();
     let status_bar = StatusBar::new()
         .file_count(file_count)
         .dir_count(dir_count)
@@ -1142,12 +1909,22 @@ fn render_status_bar(f: &mut ratatui::Frame<'_>, app: &App, theme: &ui::Theme, a
         .sort_info(Some(&sort_display))
         .filter_info(filter_display.as_deref())
         .show_hidden(active_panel_state.show_hidden)
+        .sync_browsing(app.is_sync_browsing())
+        .follow_focus(app.is_follow_focus())
+        .disk_space(
+            disk_display
+                .as_ref()
+                .map(|(free, total)| (free.as_str(), total.as_str())),
+        )
+        .disk_usage_high(disk_space.is_some_and(|space| space.usage_ratio() >= 0.9))
         .language(app.language())
         .ime_info(if app.ime_status.should_display() {
             Some(ime_label)
         } else {
             None
         })
+        .text_input_active(app.is_dialog_active())
+        .jobs_unseen(jobs_unseen, jobs_failed)
         .theme(theme);
     f.render_widget(status_bar, area);
 }
@@ -1172,16 +1949,7 @@ fn render_toast_overlay(f: &mut ratatui::Frame<'_>, app: &App, theme: &ui::Theme
     };
 
     let content_width = toast_area.width.saturating_sub(4) as usize;
-    let msg = if message.chars().count() > content_width {
-        let mut truncated = message
-            .chars()
-            .take(content_width.saturating_sub(1))
-            .collect::<String>();
-        truncated.push('…');
-        truncated
-    } else {
-        message.to_string()
-    };
+    let msg = display_width::truncate_end(message, content_width, display_width::ELLIPSIS);
 
     let lower = message.to_ascii_lowercase();
     let panel_bg = theme.panel_bg.to_color();
@@ -1275,6 +2043,62 @@ fn render_dropdown_if_active(
     }
 }
 
+/// 컨텍스트 메뉴 조건부 렌더링
+fn render_context_menu_if_active(f: &mut ratatui::Frame<'_>, app: &App, theme: &ui::Theme) {
+    let (Some(context), Some(area)) = (app.context_menu.as_ref(), app.context_menu_area()) else {
+        return;
+    };
+    let dropdown = DropdownMenu::new(&context.menu, &context.state).theme(theme);
+    f.render_widget(dropdown, area.intersection(f.area()));
+}
+
+/// 터미널 이벤트 처리 (Resize는 다음 draw에서 레이아웃이 다시 계산됨)
+fn handle_event(app: &mut App, event: Event) {
+    match event {
+        Event::Key(key) => {
+            if matches!(key.kind, KeyEventKind::Release) {
+                return;
+            }
+            // 매크로 녹화: 일반 모드는 실행한 액션, 그 밖의 모드는 누른 키
+            if app.is_dialog_active()
+                || app.is_menu_active()
+                || app.is_context_menu_active()
+                || app.is_breadcrumb_active()
+            {
+                app.record_macro_key(key.code, key.modifiers);
+            }
+            dispatch_key(app, key.modifiers, key.code);
+        }
+        // 붙여넣기 및 일부 터미널의 IME 확정 문자열은 Paste 이벤트로 한 번에 전달됨
+        Event::Paste(text) if app.is_dialog_active() => {
+            app.record_macro_text(&text);
+            app.dialog_paste_text(&text);
+        }
+        Event::Mouse(mouse) => handle_mouse_event(app, mouse),
+        _ => {}
+    }
+}
+
+/// 현재 모드에 맞는 키 처리
+fn dispatch_key(app: &mut App, modifiers: KeyModifiers, code: KeyCode) {
+    if app.is_dialog_active() {
+        // 다이얼로그 모드에서의 키 처리
+        handle_dialog_keys(app, modifiers, code);
+    } else if app.is_menu_active() {
+        // 메뉴 모드에서의 키 처리
+        handle_menu_keys(app, modifiers, code);
+    } else if app.is_context_menu_active() {
+        // 컨텍스트 메뉴 키 처리
+        handle_context_menu_keys(app, modifiers, code);
+    } else if app.is_breadcrumb_active() {
+        // 제목 경로 조각 고르기 키 처리
+        handle_breadcrumb_keys(app, modifiers, code);
+    } else {
+        // 일반 모드에서의 키 처리
+        handle_normal_keys(app, modifiers, code);
+    }
+}
+
 /// 메인 UI 렌더링
 fn render_main_ui(f: &mut ratatui::Frame<'_>, app: &App) {
     let areas = app.layout.areas();
@@ -1291,6 +2115,14 @@ fn render_main_ui(f: &mut ratatui::Frame<'_>, app: &App) {
     let left_tab_count = app.panel_tab_count(ActivePanel::Left);
     let right_tab_count = app.panel_tab_count(ActivePanel::Right);
 
+    // 상태바 위 전체 경로 줄 (패널 아래 한 줄을 씀)
+    let path_line = app.path_line_text();
+    let (mut left_area, mut right_area) = (areas.left_panel, areas.right_panel);
+    if path_line.is_some() {
+        left_area.height = left_area.height.saturating_sub(1);
+        right_area.height = right_area.height.saturating_sub(1);
+    }
+
     if app.layout.is_dual_panel() || active_panel == ActivePanel::Left {
         render_panel(
             f,
@@ -1299,9 +2131,16 @@ fn render_main_ui(f: &mut ratatui::Frame<'_>, app: &App) {
             active_panel == ActivePanel::Left,
             app.language(),
             theme,
-            areas.left_panel,
+            app.highlight_rules(),
+            left_area,
             app.icon_mode,
             app.size_format,
+            app.info_line_for(app.left_active_panel_state()).as_deref(),
+            app.name_scroll_for(app.left_active_panel_state()),
+            app.breadcrumb_focus_for(ActivePanel::Left),
+            app.file_types_for(app.left_active_panel_state()),
+            app.plugin_columns_for(app.left_active_panel_state()),
+            app.auto_refresh_remaining(ActivePanel::Left, Instant::now()),
         );
     }
 
@@ -1313,18 +2152,56 @@ fn render_main_ui(f: &mut ratatui::Frame<'_>, app: &App) {
             active_panel == ActivePanel::Right,
             app.language(),
             theme,
-            areas.right_panel,
+            app.highlight_rules(),
+            right_area,
             app.icon_mode,
             app.size_format,
+            app.info_line_for(app.right_active_panel_state()).as_deref(),
+            app.name_scroll_for(app.right_active_panel_state()),
+            app.breadcrumb_focus_for(ActivePanel::Right),
+            app.file_types_for(app.right_active_panel_state()),
+            app.plugin_columns_for(app.right_active_panel_state()),
+            app.auto_refresh_remaining(ActivePanel::Right, Instant::now()),
+        );
+    }
+
+    if let Some(path_line) = path_line.filter(|_| areas.status_bar.y > 0) {
+        let area = Rect {
+            x: areas.status_bar.x,
+            y: areas.status_bar.y - 1,
+            width: areas.status_bar.width,
+            height: 1,
+        };
+        // 너무 길면 앞부분을 줄여 파일 이름 쪽을 보여줌
+        let width = area.width as usize;
+        let text = if display_width::display_width(&path_line) > width {
+            format!(
+                "{}{}",
+                display_width::ELLIPSIS,
+                display_width::take_suffix(&path_line, width.saturating_sub(1))
+            )
+        } else {
+            path_line
+        };
+        f.render_widget(
+            Paragraph::new(text).style(
+                Style::default()
+                    .fg(theme.fg_primary.to_color())
+                    .bg(theme.panel_bg.to_color()),
+            ),
+            area,
         );
     }
 
     render_status_bar(f, app, theme, areas.status_bar);
 
-    let command_bar = CommandBar::new().language(app.language()).theme(theme);
-    f.render_widget(command_bar, areas.command_bar);
+    if app.layout.command_bar_visible() {
+        let command_bar = CommandBar::new().language(app.language()).theme(theme);
+        f.render_widget(command_bar, areas.command_bar);
+    }
 
     render_dropdown_if_active(f, app, theme, areas.menu_bar);
+    render_context_menu_if_active(f, app, theme);
     render_toast_overlay(f, app, theme);
 
     if let Some(ref dialog_kind) = app.dialog {
@@ -1338,7 +2215,9 @@ fn render_main_ui(f: &mut ratatui::Frame<'_>, app: &App) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::ffi::OsString;
+    use crate::ui::components::PathCompletion;
+    use crate::ui::BookmarkListItem;
+    use crate::ui::TextKey;
 
     fn dispatch_sequence(app: &mut App, prefix: char, key: char) {
         handle_normal_keys(app, KeyModifiers::NONE, KeyCode::Char(prefix));
@@ -1357,6 +2236,13 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_quote_digit_sequence_jumps_to_bookmark_hotkey() {
+        let mut app = App::new_for_test();
+        dispatch_sequence(&mut app, '\'', '3');
+        assert_eq!(app.toast_display(), Some("No bookmark on '3"));
+    }
+
     #[test]
     fn test_g_p_sequence_opens_go_to_path_dialog() {
         let mut app = App::new_for_test();
@@ -1445,6 +2331,14 @@ mod tests {
         dispatch_sequence(&mut app, 't', 'n');
         assert_eq!(app.panel_tab_count(ActivePanel::Left), 2);
 
+        let mut app = App::new_for_test();
+        dispatch_sequence(&mut app, 't', 'n');
+        dispatch_sequence(&mut app, 't', '[');
+        assert_eq!(app.panel_active_tab_index(ActivePanel::Left), 0);
+        dispatch_sequence(&mut app, 't', 'm');
+        assert_eq!(app.panel_tab_count(ActivePanel::Left), 1);
+        assert_eq!(app.panel_tab_count(ActivePanel::Right), 2);
+
         let mut app = App::new_for_test();
         dispatch_sequence(&mut app, 't', 'x');
         assert_eq!(app.panel_tab_count(ActivePanel::Left), 1);
@@ -1473,20 +2367,116 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_tab_list_keys_reorder_and_send_selected_tab() {
+        let mut app = App::new_for_test();
+        app.new_tab_active_panel();
+        app.new_tab_active_panel();
+        app.show_tab_list();
+        handle_tab_list_dialog_keys(&mut app, KeyCode::Char('k'));
+
+        // 선택된 두 번째 탭을 위로 올리면 목록 선택도 따라감
+        handle_tab_list_dialog_keys(&mut app, KeyCode::Char('K'));
+        assert_eq!(app.panel_active_tab_index(ActivePanel::Left), 0);
+        assert!(matches!(
+            app.dialog,
+            Some(DialogKind::TabList { ref list }) if list.selected == 0
+        ));
+
+        handle_tab_list_dialog_keys(&mut app, KeyCode::Char('m'));
+        assert_eq!(app.panel_tab_count(ActivePanel::Left), 2);
+        assert_eq!(app.panel_tab_count(ActivePanel::Right), 2);
+        assert!(matches!(app.dialog, Some(DialogKind::TabList { .. })));
+
+        handle_tab_list_dialog_keys(&mut app, KeyCode::Char('r'));
+        assert!(matches!(
+            app.dialog,
+            Some(DialogKind::Input {
+                purpose: InputPurpose::Prompt(input),
+                ..
+            }) if input.title == TextKey::DialogTitleRenameTab
+        ));
+    }
+
+    #[test]
+    fn test_list_dialog_keys_page_and_jump_in_any_list() {
+        let mut app = App::new_for_test();
+        app.dialog = Some(DialogKind::session_list(
+            (0..25)
+                .map(|i| (format!("s{}", i), String::new()))
+                .collect(),
+            0,
+        ));
+        let selected = |app: &App| match &app.dialog {
+            Some(DialogKind::SessionList { list }) => list.selected,
+            _ => panic!("session list dialog not shown"),
+        };
+        handle_session_list_dialog_keys(&mut app, KeyCode::PageDown);
+        assert_eq!(selected(&app), 10);
+        handle_session_list_dialog_keys(&mut app, KeyCode::End);
+        assert_eq!(selected(&app), 24);
+        handle_session_list_dialog_keys(&mut app, KeyCode::Char('k'));
+        handle_session_list_dialog_keys(&mut app, KeyCode::PageUp);
+        assert_eq!(selected(&app), 13);
+        handle_session_list_dialog_keys(&mut app, KeyCode::Home);
+        assert_eq!(selected(&app), 0);
+        // 검색을 쓰지 않는 목록에서는 `/`가 아무 일도 하지 않음
+        handle_session_list_dialog_keys(&mut app, KeyCode::Char('/'));
+        assert!(!app.list_dialog_searching());
+    }
+
+    #[test]
+    fn test_history_list_search_mode_captures_typed_keys() {
+        let mut app = App::new_for_test();
+        app.show_history_list();
+        handle_history_list_dialog_keys(&mut app, KeyCode::Char('/'));
+        // 검색 중에는 q/p도 검색어로 입력
+        handle_history_list_dialog_keys(&mut app, KeyCode::Char('q'));
+        handle_history_list_dialog_keys(&mut app, KeyCode::Char('p'));
+        handle_history_list_dialog_keys(&mut app, KeyCode::Backspace);
+        if let Some(DialogKind::HistoryList { list }) = &app.dialog {
+            assert_eq!(list.filter, "q");
+            assert!(list.searching);
+        } else {
+            panic!("history list dialog not shown");
+        }
+
+        handle_history_list_dialog_keys(&mut app, KeyCode::Esc);
+        if let Some(DialogKind::HistoryList { list }) = &app.dialog {
+            assert!(list.filter.is_empty());
+            assert!(!list.searching);
+        } else {
+            panic!("history list dialog not shown");
+        }
+        handle_history_list_dialog_keys(&mut app, KeyCode::Esc);
+        assert!(app.dialog.is_none());
+    }
+
     #[test]
     fn test_bookmark_list_dialog_key_navigation_and_rename() {
         let mut app = App::new_for_test();
         app.dialog = Some(DialogKind::bookmark_list(
             vec![
-                ("A".to_string(), std::path::PathBuf::from("/a")),
-                ("B".to_string(), std::path::PathBuf::from("/b")),
+                BookmarkListItem {
+                    name: "A".to_string(),
+                    path: std::path::PathBuf::from("/a"),
+                    hotkey: None,
+                    group: None,
+                },
+                BookmarkListItem {
+                    name: "B".to_string(),
+                    path: std::path::PathBuf::from("/b"),
+                    hotkey: None,
+                    group: None,
+                },
             ],
+            &[],
             0,
         ));
 
         handle_bookmark_list_dialog_keys(&mut app, KeyCode::Char('j'));
-        if let Some(DialogKind::BookmarkList { selected_index, .. }) = &app.dialog {
-            assert_eq!(*selected_index, 1);
+        if let Some(DialogKind::BookmarkList { rows, .. }) = &app.dialog {
+            assert_eq!(rows.selected, 1);
         } else {
             panic!("bookmark list dialog not shown");
         }
@@ -1505,14 +2495,9 @@ mod tests {
             "",
             std::path::PathBuf::from("."),
         ));
-        if let Some(DialogKind::Input {
-            completion_candidates,
-            completion_index,
-            ..
-        }) = &mut app.dialog
-        {
-            *completion_candidates = vec!["docs".to_string(), "downloads".to_string()];
-            *completion_index = Some(0);
+        if let Some(DialogKind::Input { completion, .. }) = &mut app.dialog {
+            completion.candidates = vec!["docs".to_string(), "downloads".to_string()];
+            completion.index = Some(0);
         }
 
         handle_input_dialog_keys(&mut app, KeyModifiers::NONE, KeyCode::Tab);
@@ -1536,20 +2521,41 @@ mod tests {
     }
 
     #[test]
-    fn test_input_dialog_right_applies_selected_completion() {
+    fn test_input_dialog_tab_applies_completion_for_destination() {
         let mut app = App::new_for_test();
-        app.dialog = Some(DialogKind::go_to_path_input(
-            "",
+        app.dialog = Some(DialogKind::operation_path_input(
+            "Copy",
+            "Copy to:",
+            "do",
             std::path::PathBuf::from("."),
         ));
         if let Some(DialogKind::Input {
-            completion_candidates,
-            completion_index,
+            value,
+            cursor_pos,
+            completion,
             ..
         }) = &mut app.dialog
         {
-            *completion_candidates = vec!["docs".to_string(), "downloads".to_string()];
-            *completion_index = Some(0);
+            *value = "do".to_string();
+            *cursor_pos = 2;
+            *completion = PathCompletion::new(vec!["docs".to_string()]);
+        }
+
+        handle_input_dialog_keys(&mut app, KeyModifiers::NONE, KeyCode::Tab);
+        assert_eq!(app.get_dialog_input_value().as_deref(), Some("docs"));
+        assert_eq!(app.get_dialog_input_selected_button(), Some(0));
+    }
+
+    #[test]
+    fn test_input_dialog_right_applies_selected_completion() {
+        let mut app = App::new_for_test();
+        app.dialog = Some(DialogKind::go_to_path_input(
+            "",
+            std::path::PathBuf::from("."),
+        ));
+        if let Some(DialogKind::Input { completion, .. }) = &mut app.dialog {
+            completion.candidates = vec!["docs".to_string(), "downloads".to_string()];
+            completion.index = Some(0);
         }
 
         handle_input_dialog_keys(&mut app, KeyModifiers::NONE, KeyCode::Right);
@@ -1563,14 +2569,9 @@ mod tests {
             "",
             std::path::PathBuf::from("."),
         ));
-        if let Some(DialogKind::Input {
-            completion_candidates,
-            completion_index,
-            ..
-        }) = &mut app.dialog
-        {
-            *completion_candidates = vec!["alpha".to_string(), "beta".to_string()];
-            *completion_index = Some(0);
+        if let Some(DialogKind::Input { completion, .. }) = &mut app.dialog {
+            completion.candidates = vec!["alpha".to_string(), "beta".to_string()];
+            completion.index = Some(0);
         }
 
         handle_input_dialog_keys(&mut app, KeyModifiers::NONE, KeyCode::Down);
@@ -1726,21 +2727,203 @@ mod tests {
     }
 
     #[test]
-    fn test_resolve_startup_path_arg_uses_first_argument_only() {
-        let args = vec![
-            OsString::from("boksldir"),
-            OsString::from("."),
-            OsString::from("/tmp"),
-        ];
+    fn test_parse_cli_args_single_path_applies_to_both_panels() {
+        let options = parse_cli_args(["boksldir", "."]).unwrap();
+        assert_eq!(options.startup.left_path, Some(PathBuf::from(".")));
+        assert_eq!(options.startup.right_path, Some(PathBuf::from(".")));
+    }
+
+    #[test]
+    fn test_parse_cli_args_two_paths_split_left_and_right() {
+        let options = parse_cli_args(["boksldir", "/a", "/b"]).unwrap();
+        assert_eq!(options.startup.left_path, Some(PathBuf::from("/a")));
+        assert_eq!(options.startup.right_path, Some(PathBuf::from("/b")));
+
+        let error = parse_cli_args(["boksldir", "/a", "/b", "/c"]).expect_err("too many paths");
+        assert!(error.contains("/c"));
+    }
+
+    #[test]
+    fn test_parse_cli_args_theme_and_no_persist() {
+        let options = parse_cli_args(["boksldir", "-t", "light", "--no-persist"]).unwrap();
+        assert_eq!(options.startup.theme.as_deref(), Some("light"));
+        assert!(options.startup.no_persist);
+
+        let inline = parse_cli_args(["boksldir", "--theme=dark"]).unwrap();
+        assert_eq!(inline.startup.theme.as_deref(), Some("dark"));
+        assert!(!inline.startup.no_persist);
+    }
+
+    #[test]
+    fn test_crash_notice_points_to_log_file() {
+        let notice = crash_notice(Some(Path::new("/tmp/boksldir.log")));
+        assert!(notice.contains("/tmp/boksldir.log"));
+        assert!(crash_notice(None).contains("--log-level"));
+    }
+
+    #[test]
+    fn test_parse_cli_args_log_level() {
+        let options = parse_cli_args(["boksldir", "--log-level", "Debug"]).unwrap();
+        assert_eq!(options.log_level, Some(LevelFilter::Debug));
+
+        let inline = parse_cli_args(["boksldir", "--log-level=off"]).unwrap();
+        assert_eq!(inline.log_level, Some(LevelFilter::Off));
+
+        let error = parse_cli_args(["boksldir", "--log-level", "loud"]).expect_err("bad level");
+        assert!(error.contains("loud"));
+    }
+
+    #[test]
+    fn test_validate_cli_options_rejects_unknown_theme() {
+        let options = parse_cli_args(["boksldir", "--theme", "neon"]).unwrap();
+        let error = validate_cli_options(options).expect_err("unknown theme");
+        assert!(error.contains("neon"));
+        assert!(error.contains("dark"));
+
+        let options = parse_cli_args(["boksldir", "--theme", "high_contrast"]).unwrap();
+        assert!(validate_cli_options(options).is_ok());
+    }
+
+    #[test]
+    fn test_parse_cli_args_none_when_no_user_argument() {
+        let options = parse_cli_args(["boksldir"]).unwrap();
+        assert_eq!(options, CliOptions::default());
+    }
+
+    #[test]
+    fn test_parse_cli_args_choose_outputs_with_separate_and_inline_values() {
+        let options = parse_cli_args([
+            "boksldir",
+            "--choose-dir",
+            "/tmp/dir.out",
+            "--choose-files=/tmp/files.out",
+            "/work",
+        ])
+        .unwrap();
+        assert_eq!(options.startup.left_path, Some(PathBuf::from("/work")));
+        assert_eq!(
+            options.choose_outputs.dir_file,
+            Some(PathBuf::from("/tmp/dir.out"))
+        );
+        assert_eq!(
+            options.choose_outputs.files_file,
+            Some(PathBuf::from("/tmp/files.out"))
+        );
+
+        let aliases =
+            parse_cli_args(["boksldir", "--choosedir", "a", "--choosefiles", "b"]).unwrap();
+        assert_eq!(aliases.choose_outputs.dir_file, Some(PathBuf::from("a")));
+        assert_eq!(aliases.choose_outputs.files_file, Some(PathBuf::from("b")));
+    }
+
+    #[test]
+    fn test_parse_cli_args_rejects_missing_value_and_unknown_option() {
+        let missing = parse_cli_args(["boksldir", "--choose-dir"]).expect_err("value required");
+        assert!(missing.contains("--choose-dir"));
+
+        let unknown = parse_cli_args(["boksldir", "--bogus"]).expect_err("unknown option");
+        assert!(unknown.contains("--bogus"));
+    }
+
+    #[test]
+    fn test_parse_cli_args_double_dash_treats_rest_as_path() {
+        let options = parse_cli_args(["boksldir", "--", "--choose-dir"]).unwrap();
         assert_eq!(
-            resolve_startup_path_arg(args.into_iter()),
-            Some(PathBuf::from("."))
+            options.startup.left_path,
+            Some(PathBuf::from("--choose-dir"))
+        );
+        assert!(options.choose_outputs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_cli_args_help_flag() {
+        assert!(parse_cli_args(["boksldir", "-h"]).unwrap().show_help);
+        assert!(parse_cli_args(["boksldir", "--help"]).unwrap().show_help);
+    }
+
+    /// 메인 루프처럼 Tick과 키 재생 요청을 처리 (재생할 단계가 끝날 때까지)
+    fn run_playback(app: &mut App) {
+        for _ in 0..100 {
+            let mut commands = VecDeque::from(app.update(Msg::Tick(Instant::now())));
+            let mut more_work = false;
+            while let Some(command) = commands.pop_front() {
+                match command {
+                    app::Command::PollSoon => more_work = true,
+                    app::Command::PressKey(code, modifiers) => {
+                        dispatch_key(app, modifiers, code);
+                        commands.extend(app.take_commands());
+                    }
+                    _ => {}
+                }
+            }
+            if !more_work {
+                break;
+            }
+        }
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_event(
+            app,
+            Event::Key(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE)),
         );
     }
 
     #[test]
-    fn test_resolve_startup_path_arg_none_when_no_user_argument() {
-        let args = vec![OsString::from("boksldir")];
-        assert_eq!(resolve_startup_path_arg(args.into_iter()), None);
+    fn test_macro_records_dialog_input_and_replays() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut app = App::new_for_test();
+        app.go_to_mount_point(temp.path().to_path_buf());
+
+        press(&mut app, KeyCode::Char('q'));
+        press(&mut app, KeyCode::Char('a'));
+        assert_eq!(app.pending_key_display().as_deref(), Some("rec @a"));
+        press(&mut app, KeyCode::Char('a'));
+        for c in "made".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Char('q'));
+        assert!(!app.is_recording_macro());
+        assert_eq!(app.toast_display(), Some("Recorded @a (3 steps)"));
+
+        std::fs::remove_dir(temp.path().join("made")).unwrap();
+        app.refresh_both_panels();
+        press(&mut app, KeyCode::Char('@'));
+        press(&mut app, KeyCode::Char('a'));
+        run_playback(&mut app);
+        assert!(temp.path().join("made").is_dir());
+        assert!(app.dialog.is_none());
+    }
+
+    #[test]
+    fn test_q_followed_by_other_key_quits() {
+        let mut app = App::new_for_test();
+        press(&mut app, KeyCode::Char('q'));
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.should_quit());
+        press(&mut app, KeyCode::Char('q'));
+        press(&mut app, KeyCode::Char('1'));
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn test_script_runs_actions_and_dialog_keys() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let script = temp.path().join("tidy.txt");
+        std::fs::write(&script, "# 새 디렉토리\nnew_dir\ntype logs\nkey Enter\n").unwrap();
+        let options = parse_cli_args(["boksldir", "--script", script.to_str().unwrap()]).unwrap();
+        assert_eq!(options.script.as_deref(), Some(script.as_path()));
+
+        let mut app = App::new_for_test();
+        app.go_to_mount_point(temp.path().to_path_buf());
+        app.run_script(app::load_script(&script).unwrap());
+        run_playback(&mut app);
+        assert!(temp.path().join("logs").is_dir());
+
+        std::fs::write(&script, "new_dir\nfly_away\n").unwrap();
+        assert!(app::load_script(&script)
+            .unwrap_err()
+            .ends_with("line 2: unknown action 'fly_away'"));
     }
 }
@@ -0,0 +1,122 @@
+//! 세션 동안 기억하는 압축 파일 비밀번호 (메모리에만 두고 설정 파일에 저장하지 않음)
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// 입력한 압축 비밀번호 모음
+///
+/// 기본은 압축 파일 경로별로 기억하고, 경로별 기억을 끄면 마지막으로 입력한 비밀번호를
+/// 모든 압축 파일에 먼저 시도합니다.
+pub struct ArchivePasswords {
+    per_archive: bool,
+    by_path: HashMap<PathBuf, String>,
+    last: Option<String>,
+}
+
+impl Default for ArchivePasswords {
+    fn default() -> Self {
+        Self {
+            per_archive: true,
+            by_path: HashMap::new(),
+            last: None,
+        }
+    }
+}
+
+// 비밀번호가 로그/디버그 출력에 섞이지 않게 개수만 표시
+impl fmt::Debug for ArchivePasswords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArchivePasswords")
+            .field("per_archive", &self.per_archive)
+            .field("remembered", &self.len())
+            .finish()
+    }
+}
+
+impl ArchivePasswords {
+    /// 경로별 기억 여부 (설정 파일 `archive_password_per_archive`)
+    pub fn per_archive(&self) -> bool {
+        self.per_archive
+    }
+
+    pub fn set_per_archive(&mut self, per_archive: bool) {
+        self.per_archive = per_archive;
+    }
+
+    /// 압축 파일에 먼저 시도할 비밀번호
+    pub fn get(&self, archive_path: &Path) -> Option<&str> {
+        if self.per_archive {
+            self.by_path.get(archive_path).map(String::as_str)
+        } else {
+            self.last.as_deref()
+        }
+    }
+
+    /// 입력한 비밀번호 기억 (빈 비밀번호는 무시)
+    pub fn remember(&mut self, archive_path: &Path, password: &str) {
+        if password.is_empty() {
+            return;
+        }
+        self.by_path
+            .insert(archive_path.to_path_buf(), password.to_string());
+        self.last = Some(password.to_string());
+    }
+
+    /// 맞지 않는 것으로 확인된 비밀번호 잊기
+    pub fn forget(&mut self, archive_path: &Path) {
+        if let Some(password) = self.by_path.remove(archive_path) {
+            if self.last.as_deref() == Some(password.as_str()) {
+                self.last = None;
+            }
+        } else if !self.per_archive {
+            self.last = None;
+        }
+    }
+
+    /// 기억한 비밀번호를 모두 잊고 잊은 개수 반환
+    pub fn clear(&mut self) -> usize {
+        let count = self.len();
+        self.by_path.clear();
+        self.last = None;
+        count
+    }
+
+    /// 기억한 비밀번호 수 (경로별이 아니면 마지막 비밀번호 하나)
+    pub fn len(&self) -> usize {
+        if self.per_archive {
+            self.by_path.len()
+        } else {
+            usize::from(self.last.is_some())
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passwords_are_scoped_per_archive_or_shared() {
+        let mut passwords = ArchivePasswords::default();
+        passwords.remember(Path::new("/a.zip"), "secret");
+        passwords.remember(Path::new("/b.7z"), "");
+        assert_eq!(passwords.get(Path::new("/a.zip")), Some("secret"));
+        assert_eq!(passwords.get(Path::new("/b.7z")), None);
+
+        passwords.set_per_archive(false);
+        assert_eq!(passwords.get(Path::new("/c.7z")), Some("secret"));
+        passwords.forget(Path::new("/c.7z"));
+        assert_eq!(passwords.get(Path::new("/c.7z")), None);
+
+        passwords.set_per_archive(true);
+        assert_eq!(passwords.get(Path::new("/a.zip")), Some("secret"));
+        assert_eq!(passwords.clear(), 1);
+        assert!(passwords.is_empty());
+        assert!(!format!("{:?}", passwords).contains("secret"));
+    }
+}
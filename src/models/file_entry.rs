@@ -17,6 +17,17 @@ pub enum FileType {
     Executable,
 }
 
+/// Windows 재분석 지점 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReparseKind {
+    /// 심볼릭 링크 (다른 경로를 가리키는 태그 포함)
+    Symlink,
+    /// 디렉토리 정션 / 볼륨 마운트 지점
+    Junction,
+    /// 그 밖의 재분석 지점 (클라우드 자리 표시자, 중복 제거 등)
+    Other,
+}
+
 /// 파일 엔트리
 #[derive(Debug, Clone)]
 pub struct FileEntry {
@@ -38,8 +49,20 @@ pub struct FileEntry {
     pub owner: Option<String>,
     /// 그룹 (표시 문자열, 예: gid 또는 group name)
     pub group: Option<String>,
+    /// 하드 링크 수 (Unix 계열)
+    pub links: Option<u64>,
+    /// inode 번호 (Unix 계열)
+    pub inode: Option<u64>,
+    /// 심볼릭 링크 대상 경로
+    pub link_target: Option<PathBuf>,
     /// 숨김 파일 여부
     pub is_hidden: bool,
+    /// 대상이 없는 심볼릭 링크 여부
+    pub is_broken_symlink: bool,
+    /// Windows 재분석 지점 종류 (재분석 지점이 아니면 None)
+    pub reparse: Option<ReparseKind>,
+    /// 이름만 먼저 읽어 크기/시간/권한을 아직 채우지 않음
+    pub metadata_pending: bool,
 }
 
 impl FileEntry {
@@ -65,7 +88,13 @@ impl FileEntry {
             permissions,
             owner: None,
             group: None,
+            links: None,
+            inode: None,
+            link_target: None,
             is_hidden,
+            is_broken_symlink: false,
+            reparse: None,
+            metadata_pending: false,
         }
     }
 
@@ -84,6 +113,11 @@ impl FileEntry {
         self.file_type == FileType::Symlink
     }
 
+    /// 디렉토리 정션 여부 확인
+    pub fn is_junction(&self) -> bool {
+        self.reparse == Some(ReparseKind::Junction)
+    }
+
     /// 실행 파일 여부 확인
     pub fn is_executable(&self) -> bool {
         self.file_type == FileType::Executable
@@ -0,0 +1,149 @@
+//! 디렉토리 방문 빈도/최근성 기록 (zoxide 방식 점프 목록)
+//!
+//! 방문할 때마다 순위가 1씩 오르고, 전체 순위 합이 상한을 넘으면 모든 순위를
+//! 줄여(aging) 오래 쓰지 않은 경로가 자연스럽게 빠지도록 합니다. 점수는 순위에
+//! 마지막 방문 시각에 따른 가중치를 곱해 계산합니다.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// 전체 순위 합 상한 (넘으면 aging)
+const MAX_TOTAL_RANK: f64 = 1000.0;
+/// aging 시 곱하는 비율
+const AGING_FACTOR: f64 = 0.9;
+/// aging 후 이 순위 미만이면 삭제
+const MIN_RANK: f64 = 1.0;
+/// 최대 보관 경로 수
+const MAX_ENTRIES: usize = 300;
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+
+/// 경로 하나의 방문 기록
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrecencyEntry {
+    pub path: PathBuf,
+    pub rank: f64,
+    /// 마지막 방문 시각 (UNIX 초)
+    pub last_visit: u64,
+}
+
+impl FrecencyEntry {
+    /// 최근성 가중 점수
+    pub fn score(&self, now: u64) -> f64 {
+        let age = now.saturating_sub(self.last_visit);
+        let weight = if age < HOUR {
+            4.0
+        } else if age < DAY {
+            2.0
+        } else if age < WEEK {
+            0.5
+        } else {
+            0.25
+        };
+        self.rank * weight
+    }
+}
+
+/// 방문 기록 저장소
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrecencyStore {
+    #[serde(default)]
+    entries: Vec<FrecencyEntry>,
+}
+
+impl FrecencyStore {
+    /// 방문 기록
+    pub fn visit(&mut self, path: &Path, now: u64) {
+        match self.entries.iter_mut().find(|entry| entry.path == path) {
+            Some(entry) => {
+                entry.rank += 1.0;
+                entry.last_visit = now;
+            }
+            None => self.entries.push(FrecencyEntry {
+                path: path.to_path_buf(),
+                rank: 1.0,
+                last_visit: now,
+            }),
+        }
+
+        let total: f64 = self.entries.iter().map(|entry| entry.rank).sum();
+        if total > MAX_TOTAL_RANK {
+            for entry in &mut self.entries {
+                entry.rank *= AGING_FACTOR;
+            }
+            self.entries.retain(|entry| entry.rank >= MIN_RANK);
+        }
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries
+                .sort_by(|a, b| b.score(now).total_cmp(&a.score(now)));
+            self.entries.truncate(MAX_ENTRIES);
+        }
+    }
+
+    /// 경로 삭제 (더 이상 없는 디렉토리 정리)
+    pub fn remove(&mut self, path: &Path) {
+        self.entries.retain(|entry| entry.path != path);
+    }
+
+    /// 점수 내림차순 목록
+    pub fn ranked(&self, now: u64) -> Vec<&FrecencyEntry> {
+        let mut entries: Vec<&FrecencyEntry> = self.entries.iter().collect();
+        entries.sort_by(|a, b| {
+            b.score(now)
+                .total_cmp(&a.score(now))
+                .then_with(|| b.last_visit.cmp(&a.last_visit))
+        });
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranked_paths(store: &FrecencyStore, now: u64) -> Vec<PathBuf> {
+        store
+            .ranked(now)
+            .into_iter()
+            .map(|entry| entry.path.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_visit_ranks_by_frequency_and_recency() {
+        let mut store = FrecencyStore::default();
+        let now = 10 * WEEK;
+        for _ in 0..5 {
+            store.visit(Path::new("/often"), now - 2 * WEEK);
+        }
+        store.visit(Path::new("/recent"), now - 60);
+        store.visit(Path::new("/recent"), now - 30);
+        // 2주 전 5회(5 * 0.25) < 방금 2회(2 * 4)
+        assert_eq!(
+            ranked_paths(&store, now),
+            vec![PathBuf::from("/recent"), PathBuf::from("/often")]
+        );
+        // 같은 시점이면 빈도가 높은 쪽이 앞
+        assert_eq!(
+            ranked_paths(&store, now + 4 * WEEK),
+            vec![PathBuf::from("/often"), PathBuf::from("/recent")]
+        );
+
+        store.remove(Path::new("/often"));
+        assert_eq!(ranked_paths(&store, now), vec![PathBuf::from("/recent")]);
+    }
+
+    #[test]
+    fn test_visit_ages_ranks_and_drops_rarely_used_paths() {
+        let mut store = FrecencyStore::default();
+        store.visit(Path::new("/once"), 0);
+        for _ in 0..1000 {
+            store.visit(Path::new("/daily"), 0);
+        }
+        let total: f64 = store.entries.iter().map(|entry| entry.rank).sum();
+        assert!(total <= MAX_TOTAL_RANK);
+        assert_eq!(ranked_paths(&store, 0), vec![PathBuf::from("/daily")]);
+    }
+}
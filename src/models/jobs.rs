@@ -0,0 +1,168 @@
+//! 백그라운드 작업 기록 (작업 목록 다이얼로그)
+//!
+//! 복사/이동/삭제, 압축, 전송, 파일 찾기 색인 등 끝난 작업을 최신순으로 보관하며,
+//! 작업 목록을 열어 보기 전까지 새로 끝난 작업 수를 상태바에 표시합니다.
+
+use std::collections::VecDeque;
+use std::time::{Duration, SystemTime};
+
+/// 끝난 작업의 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Succeeded,
+    /// 일부 항목 실패
+    CompletedWithErrors,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    /// 목록에 표시할 기호
+    pub fn symbol(self) -> &'static str {
+        match self {
+            JobStatus::Succeeded => "✓",
+            JobStatus::CompletedWithErrors => "!",
+            JobStatus::Failed => "✗",
+            JobStatus::Cancelled => "-",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            JobStatus::Succeeded => "Succeeded",
+            JobStatus::CompletedWithErrors => "Completed with errors",
+            JobStatus::Failed => "Failed",
+            JobStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn is_failure(self) -> bool {
+        matches!(self, JobStatus::CompletedWithErrors | JobStatus::Failed)
+    }
+}
+
+/// 끝난 작업 한 개
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    /// 작업 이름 (예: "Copy", "Archive create")
+    pub name: String,
+    /// 결과 요약 (예: "3 files")
+    pub summary: String,
+    pub status: JobStatus,
+    pub finished_at: SystemTime,
+    pub duration: Duration,
+    /// 실패 항목 메시지 (전체)
+    pub errors: Vec<String>,
+}
+
+impl Job {
+    /// 걸린 시간 ("0.4s", "2m 05s", "1h 02m")
+    pub fn duration_display(&self) -> String {
+        let secs = self.duration.as_secs();
+        if secs >= 3600 {
+            format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+        } else if secs >= 60 {
+            format!("{}m {:02}s", secs / 60, secs % 60)
+        } else {
+            format!("{:.1}s", self.duration.as_secs_f64())
+        }
+    }
+}
+
+/// 최근 작업 기록 (최신순)
+#[derive(Debug, Clone, Default)]
+pub struct JobLog {
+    jobs: VecDeque<Job>,
+    /// 작업 목록을 연 뒤 새로 끝난 작업 수
+    unseen: usize,
+    /// 새로 끝난 작업 중 실패가 있음
+    unseen_failure: bool,
+}
+
+impl JobLog {
+    /// 보관할 최대 작업 수
+    pub const MAX_JOBS: usize = 50;
+
+    pub fn push(&mut self, job: Job) {
+        self.unseen += 1;
+        self.unseen_failure |= job.status.is_failure();
+        self.jobs.push_front(job);
+        self.jobs.truncate(Self::MAX_JOBS);
+    }
+
+    pub fn jobs(&self) -> impl Iterator<Item = &Job> {
+        self.jobs.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// 새로 끝난 작업 수와 그중 실패 여부
+    pub fn unseen(&self) -> (usize, bool) {
+        (self.unseen.min(self.jobs.len()), self.unseen_failure)
+    }
+
+    pub fn mark_seen(&mut self) {
+        self.unseen = 0;
+        self.unseen_failure = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.jobs.clear();
+        self.mark_seen();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(name: &str, status: JobStatus) -> Job {
+        Job {
+            name: name.to_string(),
+            summary: String::new(),
+            status,
+            finished_at: SystemTime::UNIX_EPOCH,
+            duration: Duration::ZERO,
+            errors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_job_log_keeps_newest_first_and_tracks_unseen() {
+        let mut log = JobLog::default();
+        assert_eq!(log.unseen(), (0, false));
+
+        log.push(job("Copy", JobStatus::Succeeded));
+        log.push(job("Delete", JobStatus::Failed));
+        assert_eq!(
+            log.jobs().map(|job| job.name.as_str()).collect::<Vec<_>>(),
+            vec!["Delete", "Copy"]
+        );
+        assert_eq!(log.unseen(), (2, true));
+
+        log.mark_seen();
+        assert_eq!(log.unseen(), (0, false));
+        log.push(job("Move", JobStatus::Cancelled));
+        assert_eq!(log.unseen(), (1, false));
+
+        for i in 0..JobLog::MAX_JOBS {
+            log.push(job(&i.to_string(), JobStatus::Succeeded));
+        }
+        assert_eq!(log.jobs().count(), JobLog::MAX_JOBS);
+        assert_eq!(log.unseen(), (JobLog::MAX_JOBS, false));
+
+        let mut long = job("Copy", JobStatus::Succeeded);
+        long.duration = Duration::from_millis(400);
+        assert_eq!(long.duration_display(), "0.4s");
+        long.duration = Duration::from_secs(125);
+        assert_eq!(long.duration_display(), "2m 05s");
+        long.duration = Duration::from_secs(3720);
+        assert_eq!(long.duration_display(), "1h 02m");
+
+        log.clear();
+        assert!(log.is_empty());
+        assert_eq!(log.unseen(), (0, false));
+    }
+}
@@ -1,7 +1,13 @@
 // Data Models
+pub mod archive_passwords;
 pub mod file_entry;
+pub mod frecency;
+pub mod jobs;
+pub mod open_rules;
 pub mod operation;
 pub mod panel_state;
+pub mod remote;
+pub mod session;
 pub mod tab_state;
 
 // Phase 2.2+에서 사용 예정
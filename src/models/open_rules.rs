@@ -0,0 +1,209 @@
+//! 파일에서 Enter를 눌렀을 때의 동작 규칙
+//!
+//! 설정 파일과 같은 위치의 `open.toml`에서 로드하며, 규칙은 위에서부터 처음 맞는 것을
+//! 사용합니다. 파일이 없으면 내장 규칙(실행 파일 → 실행, 텍스트 → 보기, 나머지 → 기본 앱)을
+//! 사용합니다.
+//!
+//! ```toml
+//! default = "default_app"
+//!
+//! [[rules]]
+//! extensions = ["md", "txt", "log"]
+//! action = "viewer"
+//!
+//! [[rules]]
+//! mime = "image/*"
+//! action = "default_app"
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Enter 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnterAction {
+    /// 내장 텍스트 보기
+    Viewer,
+    /// 터미널 편집기
+    Editor,
+    /// OS 기본 앱
+    DefaultApp,
+    /// 인자를 물어본 뒤 실행 (실행 파일만, 아니면 무시)
+    Run,
+    /// 아무것도 하지 않음
+    None,
+}
+
+/// 한 개의 규칙. 지정한 조건을 모두 만족해야 맞는 것으로 봅니다 (조건이 없으면 항상 맞음).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenRule {
+    /// 점 없는 확장자 목록 (대소문자 무시)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+    /// MIME 패턴 (`image/png`, `text/*`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<String>,
+    /// 실행 권한 여부 조건
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executable: Option<bool>,
+    pub action: EnterAction,
+}
+
+/// 규칙 판별에 쓰는 파일 정보
+#[derive(Debug, Clone, Copy)]
+pub struct OpenTarget<'a> {
+    pub name: &'a str,
+    /// 매직 넘버로 판별한 MIME (읽지 못하면 None)
+    pub mime: Option<&'a str>,
+    pub executable: bool,
+}
+
+/// Enter 동작 규칙 집합
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenRules {
+    /// 맞는 규칙이 없을 때의 동작
+    #[serde(default = "default_action")]
+    pub default: EnterAction,
+    #[serde(default)]
+    pub rules: Vec<OpenRule>,
+}
+
+fn default_action() -> EnterAction {
+    EnterAction::DefaultApp
+}
+
+impl Default for OpenRules {
+    fn default() -> Self {
+        Self {
+            default: default_action(),
+            rules: vec![
+                OpenRule {
+                    extensions: Vec::new(),
+                    mime: None,
+                    executable: Some(true),
+                    action: EnterAction::Run,
+                },
+                OpenRule {
+                    extensions: Vec::new(),
+                    mime: Some("text/*".to_string()),
+                    executable: None,
+                    action: EnterAction::Viewer,
+                },
+            ],
+        }
+    }
+}
+
+impl OpenRule {
+    fn matches(&self, target: &OpenTarget<'_>) -> bool {
+        if !self.extensions.is_empty() {
+            let Some((_, ext)) = target.name.rsplit_once('.') else {
+                return false;
+            };
+            if !self
+                .extensions
+                .iter()
+                .any(|candidate| candidate.trim_start_matches('.').eq_ignore_ascii_case(ext))
+            {
+                return false;
+            }
+        }
+        if let Some(pattern) = &self.mime {
+            let Some(mime) = target.mime else {
+                return false;
+            };
+            let matched = match pattern.strip_suffix("/*") {
+                Some(prefix) => mime
+                    .split_once('/')
+                    .is_some_and(|(kind, _)| kind.eq_ignore_ascii_case(prefix)),
+                None => mime.eq_ignore_ascii_case(pattern),
+            };
+            if !matched {
+                return false;
+            }
+        }
+        self.executable
+            .is_none_or(|executable| executable == target.executable)
+    }
+}
+
+impl OpenRules {
+    pub fn from_file(path: &Path) -> Result<Self, anyhow::Error> {
+        let content = fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// 처음 맞는 규칙의 동작 (없으면 기본 동작)
+    pub fn action_for(&self, target: &OpenTarget<'_>) -> EnterAction {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(target))
+            .map_or(self.default, |rule| rule.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target<'a>(name: &'a str, mime: Option<&'a str>, executable: bool) -> OpenTarget<'a> {
+        OpenTarget {
+            name,
+            mime,
+            executable,
+        }
+    }
+
+    #[test]
+    fn test_default_rules_run_view_and_open() {
+        let rules = OpenRules::default();
+        assert_eq!(
+            rules.action_for(&target("build.sh", Some("text/x-shellscript"), true)),
+            EnterAction::Run
+        );
+        assert_eq!(
+            rules.action_for(&target("notes.md", Some("text/plain"), false)),
+            EnterAction::Viewer
+        );
+        assert_eq!(
+            rules.action_for(&target("photo.png", Some("image/png"), false)),
+            EnterAction::DefaultApp
+        );
+    }
+
+    #[test]
+    fn test_config_rules_match_in_order() {
+        let rules: OpenRules = toml::from_str(
+            r#"
+default = "none"
+
+[[rules]]
+extensions = ["RS", ".toml"]
+action = "editor"
+
+[[rules]]
+mime = "image/*"
+action = "viewer"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            rules.action_for(&target("main.rs", Some("text/plain"), false)),
+            EnterAction::Editor
+        );
+        assert_eq!(
+            rules.action_for(&target("Cargo.TOML", None, false)),
+            EnterAction::Editor
+        );
+        assert_eq!(
+            rules.action_for(&target("a.gif", Some("image/gif"), false)),
+            EnterAction::Viewer
+        );
+        assert_eq!(
+            rules.action_for(&target("README", Some("text/plain"), false)),
+            EnterAction::None
+        );
+    }
+}
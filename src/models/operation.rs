@@ -4,8 +4,16 @@
 
 #![allow(dead_code)]
 
+use crate::system::privilege::ElevatedOp;
+use crate::utils::error::BokslDirError;
+use crate::utils::scope_filter::ScopeFilter;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime};
+
+/// 전송 속도 이동 평균 구간
+pub const SPEED_WINDOW: Duration = Duration::from_secs(3);
 
 /// 평탄화된 파일 정보 (개별 파일 단위 처리용)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,6 +41,120 @@ pub struct FlattenedFile {
     pub size: u64,
 }
 
+/// 대상 경로가 앞선 항목과 대소문자만 다른 항목의 대상 경로
+///
+/// 대소문자를 구분하지 않는 대상 파일시스템에서는 두 항목이 같은 경로가 되어 나중 항목이
+/// 앞선 항목을 덮어쓰거나 디렉토리가 합쳐집니다.
+pub fn case_collisions(files: &[FlattenedFile]) -> HashSet<PathBuf> {
+    let mut first_by_key: HashMap<String, &PathBuf> = HashMap::new();
+    let mut collisions = HashSet::new();
+    for file in files {
+        let key = file.dest.to_string_lossy().to_lowercase();
+        match first_by_key.get(&key) {
+            Some(first) if **first != file.dest => {
+                collisions.insert(file.dest.clone());
+            }
+            Some(_) => {}
+            None => {
+                first_by_key.insert(key, &file.dest);
+            }
+        }
+    }
+    collisions
+}
+
+/// 실행 전 미리보기에서 항목이 받을 처리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewAction {
+    /// 대상에 새로 생성
+    Create,
+    /// 대상의 기존 항목을 덮어씀
+    Overwrite,
+    /// 처리하지 않음 (원본과 대상이 같음, 기존 디렉토리에 합침)
+    Skip,
+    /// 이동 후 원본에서 제거
+    Remove,
+}
+
+/// 미리보기 항목 (제거는 원본 경로, 나머지는 대상 경로)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreviewEntry {
+    pub action: PreviewAction,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// 복사/이동 실행 전 미리보기 (대상 경로 입력의 Preview 버튼)
+///
+/// 실제 작업과 같은 평탄화 목록을 같은 충돌 규칙으로 분류하므로, 미리보기 뒤 대상이 바뀌지
+/// 않았다면 실행 결과와 일치합니다.
+#[derive(Debug, Clone, Default)]
+pub struct OperationPreview {
+    pub entries: Vec<PreviewEntry>,
+}
+
+impl OperationPreview {
+    /// 평탄화 목록 분류 (이동은 옮길 파일의 원본을 제거 목록에 추가, 비는 디렉토리도 지움)
+    pub fn build(operation_type: OperationType, files: &[FlattenedFile]) -> Self {
+        let mut entries: Vec<PreviewEntry> = files
+            .iter()
+            .map(|file| {
+                let action = match file.entry_kind {
+                    FlattenedEntryKind::Directory if file.dest.is_dir() => PreviewAction::Skip,
+                    _ if file.entry_kind != FlattenedEntryKind::Directory
+                        && file.source == file.dest =>
+                    {
+                        PreviewAction::Skip
+                    }
+                    _ if file.dest.symlink_metadata().is_ok() => PreviewAction::Overwrite,
+                    _ => PreviewAction::Create,
+                };
+                PreviewEntry {
+                    action,
+                    path: file.dest.clone(),
+                    size: file.size,
+                }
+            })
+            .collect();
+        if operation_type == OperationType::Move {
+            let removed: Vec<PreviewEntry> = files
+                .iter()
+                .zip(&entries)
+                .filter(|(file, entry)| {
+                    file.entry_kind != FlattenedEntryKind::Directory
+                        && entry.action != PreviewAction::Skip
+                })
+                .map(|(file, _)| PreviewEntry {
+                    action: PreviewAction::Remove,
+                    path: file.source.clone(),
+                    size: file.size,
+                })
+                .collect();
+            entries.extend(removed);
+        }
+        Self { entries }
+    }
+
+    pub fn entries_for(&self, action: PreviewAction) -> impl Iterator<Item = &PreviewEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.action == action)
+    }
+
+    pub fn count(&self, action: PreviewAction) -> usize {
+        self.entries_for(action).count()
+    }
+
+    pub fn bytes(&self, action: PreviewAction) -> u64 {
+        self.entries_for(action).map(|entry| entry.size).sum()
+    }
+
+    /// 대상에 쓸 전체 바이트 (생성 + 덮어쓰기)
+    pub fn total_write_bytes(&self) -> u64 {
+        self.bytes(PreviewAction::Create) + self.bytes(PreviewAction::Overwrite)
+    }
+}
+
 /// 작업 유형
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OperationType {
@@ -85,6 +207,17 @@ pub enum ConflictResolution {
     SkipAll,
     /// 취소
     Cancel,
+    /// 디렉토리 합치기 (안의 항목을 그대로 진행하고 충돌하는 파일만 따로 처리)
+    Merge,
+}
+
+/// 파일 이동 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveStrategy {
+    /// 같은 파일시스템 내 rename
+    Rename,
+    /// 다른 파일시스템(EXDEV): 복사 후 원본 삭제
+    CopyDelete,
 }
 
 /// 작업 진행 상태
@@ -108,6 +241,16 @@ pub struct OperationProgress {
     pub items_processed: usize,
     /// 실패한 항목 수
     pub items_failed: usize,
+    /// 현재 파일에서 복사된 바이트 수 (분할 복사 중)
+    pub current_file_bytes: u64,
+    /// 현재 파일 크기
+    pub current_file_total: u64,
+    /// 속도 계산용 (시각, 누적 바이트) 샘플
+    speed_samples: VecDeque<(Instant, u64)>,
+    /// 일시정지 시작 시각
+    paused_at: Option<Instant>,
+    /// 누적 일시정지 시간
+    paused_duration: Duration,
 }
 
 impl OperationProgress {
@@ -123,6 +266,85 @@ impl OperationProgress {
             start_time: Instant::now(),
             items_processed: 0,
             items_failed: 0,
+            current_file_bytes: 0,
+            current_file_total: 0,
+            speed_samples: VecDeque::new(),
+            paused_at: None,
+            paused_duration: Duration::ZERO,
+        }
+    }
+
+    /// 시작 시각과 속도/일시정지 기록 초기화
+    pub fn restart_clock(&mut self) {
+        self.start_time = Instant::now();
+        self.speed_samples.clear();
+        self.paused_at = None;
+        self.paused_duration = Duration::ZERO;
+    }
+
+    /// 누적 바이트 갱신 (속도 샘플 기록)
+    pub fn set_bytes_copied(&mut self, bytes: u64) {
+        self.bytes_copied = bytes;
+        self.record_speed_sample(Instant::now());
+    }
+
+    /// 현재 파일 시작 (파일 진행률 초기화)
+    pub fn begin_file(&mut self, name: &str, size: u64) {
+        self.current_file = name.to_string();
+        self.current_file_bytes = 0;
+        self.current_file_total = size;
+    }
+
+    /// 현재 파일의 일부가 복사됨 (분할 복사)
+    pub fn add_file_chunk(&mut self, bytes: u64) {
+        self.current_file_bytes += bytes;
+        self.set_bytes_copied(self.bytes_copied + bytes);
+    }
+
+    fn record_speed_sample(&mut self, now: Instant) {
+        self.speed_samples.push_back((now, self.bytes_copied));
+        while let Some(&(time, _)) = self.speed_samples.front() {
+            if now.duration_since(time) > SPEED_WINDOW && self.speed_samples.len() > 2 {
+                self.speed_samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// 일시정지 여부
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// 일시정지
+    pub fn pause(&mut self) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(Instant::now());
+        }
+    }
+
+    /// 재개 (일시정지 구간은 경과 시간과 속도 계산에서 제외)
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused_duration += paused_at.elapsed();
+            self.speed_samples.clear();
+        }
+    }
+
+    /// 일시정지 시간을 제외한 경과 시간
+    pub fn active_elapsed(&self) -> Duration {
+        let paused = self.paused_duration + self.paused_at.map_or(Duration::ZERO, |t| t.elapsed());
+        self.start_time.elapsed().saturating_sub(paused)
+    }
+
+    /// 현재 파일 진행률 (0-100)
+    pub fn file_percentage(&self) -> u8 {
+        if self.current_file_total == 0 {
+            0
+        } else {
+            ((self.current_file_bytes as f64 / self.current_file_total as f64) * 100.0).min(100.0)
+                as u8
         }
     }
 
@@ -139,14 +361,24 @@ impl OperationProgress {
         }
     }
 
-    /// 전송 속도 (bytes/sec), 0.5초 이후부터 계산
+    /// 전송 속도 (bytes/sec)
+    ///
+    /// 최근 `SPEED_WINDOW` 구간 샘플의 이동 평균을 사용하고, 샘플 구간이 0.5초 미만이면
+    /// 전체 평균으로 대신합니다. 일시정지 중이거나 0.5초 이전에는 0.
     pub fn speed(&self) -> f64 {
-        let elapsed = self.start_time.elapsed().as_secs_f64();
-        if elapsed < 0.5 || self.bytes_copied == 0 {
-            0.0
-        } else {
-            self.bytes_copied as f64 / elapsed
+        let elapsed = self.active_elapsed().as_secs_f64();
+        if self.is_paused() || elapsed < 0.5 || self.bytes_copied == 0 {
+            return 0.0;
         }
+        if let (Some(&(first_time, first_bytes)), Some(&(last_time, last_bytes))) =
+            (self.speed_samples.front(), self.speed_samples.back())
+        {
+            let span = last_time.duration_since(first_time).as_secs_f64();
+            if span >= 0.5 {
+                return last_bytes.saturating_sub(first_bytes) as f64 / span;
+            }
+        }
+        self.bytes_copied as f64 / elapsed
     }
 
     /// 예상 남은 시간 (초), speed > 0 일 때만
@@ -208,12 +440,133 @@ pub enum OperationState {
     Pending,
     /// 처리 중
     Processing,
+    /// 일시정지 (현재 파일/청크 경계에서 정지, 재개 시 이어서 처리)
+    Paused,
     /// 충돌 대기 (사용자 선택 대기)
     WaitingConflict,
     /// 완료
     Completed,
 }
 
+/// 복사 옵션 항목
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyOption {
+    Verify,
+    PreserveTimestamps,
+    PreservePermissions,
+    PreserveXattrs,
+    UpdateOnly,
+}
+
+impl CopyOption {
+    /// 토스트/설정 표시 이름
+    pub fn label(&self) -> &'static str {
+        match self {
+            CopyOption::Verify => "Verify after copy",
+            CopyOption::PreserveTimestamps => "Preserve timestamps",
+            CopyOption::PreservePermissions => "Preserve permissions",
+            CopyOption::PreserveXattrs => "Preserve extended attributes",
+            CopyOption::UpdateOnly => "Update only newer",
+        }
+    }
+}
+
+/// 복사할 때 심볼릭 링크 처리 (설정 파일 `[copy] symlinks`, 복사 대상 경로 창에서 작업별로 변경)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SymlinkPolicy {
+    /// 링크를 같은 대상을 가리키는 링크로 복사
+    #[default]
+    Links,
+    /// 링크를 따라가 가리키는 파일/디렉토리 내용을 복사 (자기 상위를 가리키는 링크는 실패)
+    Follow,
+    /// 링크는 복사하지 않음
+    Skip,
+}
+
+impl SymlinkPolicy {
+    /// 다음 방식 (링크 → 따라가기 → 건너뛰기 → 링크)
+    pub fn next(self) -> Self {
+        match self {
+            SymlinkPolicy::Links => SymlinkPolicy::Follow,
+            SymlinkPolicy::Follow => SymlinkPolicy::Skip,
+            SymlinkPolicy::Skip => SymlinkPolicy::Links,
+        }
+    }
+}
+
+/// 복사 옵션 (설정 기본값, 대상 경로 다이얼로그에서 작업별로 변경)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyOptions {
+    /// 파일 복사 후 원본/대상을 다시 읽어 해시 비교
+    pub verify: bool,
+    /// 수정/접근 시각 유지
+    pub preserve_timestamps: bool,
+    /// Unix 권한(mode) 유지 (끄면 umask 기준 기본 권한)
+    pub preserve_permissions: bool,
+    /// 확장 속성(xattr) 유지 (Unix 전용)
+    pub preserve_xattrs: bool,
+    /// 갱신 모드: 대상이 크기가 같고 수정 시각이 같거나 새로우면 건너뛰고, 오래된 대상은 묻지
+    /// 않고 덮어씀 (한쪽 방향 백업 동기화용)
+    pub update_only: bool,
+    /// 심볼릭 링크 처리 (이동은 항상 링크 그대로)
+    pub symlinks: SymlinkPolicy,
+}
+
+impl CopyOptions {
+    /// 이동(복사 후 삭제)용: 원본 메타데이터를 모두 유지
+    pub fn preserve_all() -> Self {
+        Self {
+            verify: false,
+            preserve_timestamps: true,
+            preserve_permissions: true,
+            preserve_xattrs: true,
+            update_only: false,
+            symlinks: SymlinkPolicy::Links,
+        }
+    }
+
+    pub fn get(&self, option: CopyOption) -> bool {
+        match option {
+            CopyOption::Verify => self.verify,
+            CopyOption::PreserveTimestamps => self.preserve_timestamps,
+            CopyOption::PreservePermissions => self.preserve_permissions,
+            CopyOption::PreserveXattrs => self.preserve_xattrs,
+            CopyOption::UpdateOnly => self.update_only,
+        }
+    }
+
+    pub fn toggle(&mut self, option: CopyOption) {
+        let flag = match option {
+            CopyOption::Verify => &mut self.verify,
+            CopyOption::PreserveTimestamps => &mut self.preserve_timestamps,
+            CopyOption::PreservePermissions => &mut self.preserve_permissions,
+            CopyOption::PreserveXattrs => &mut self.preserve_xattrs,
+            CopyOption::UpdateOnly => &mut self.update_only,
+        };
+        *flag = !*flag;
+    }
+}
+
+impl Default for CopyOptions {
+    /// 기존 동작 유지: 권한만 복사
+    fn default() -> Self {
+        Self {
+            verify: false,
+            preserve_timestamps: false,
+            preserve_permissions: true,
+            preserve_xattrs: false,
+            update_only: false,
+            symlinks: SymlinkPolicy::Links,
+        }
+    }
+}
+
+/// 사용 중인 파일 자동 재시도 첫 대기 시간 (시도마다 두 배)
+const BUSY_RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+/// 사용 중인 파일 자동 재시도 최대 대기 시간
+const BUSY_RETRY_MAX_DELAY: Duration = Duration::from_secs(4);
+
 /// 대기 중인 작업
 #[derive(Debug, Clone)]
 pub struct PendingOperation {
@@ -229,6 +582,8 @@ pub struct PendingOperation {
     pub move_cleanup_dirs: Vec<PathBuf>,
     /// 충돌 해결 방법 (OverwriteAll/SkipAll 시 사용)
     pub conflict_resolution: Option<ConflictResolution>,
+    /// 복사 옵션 (Copy 작업에만 적용)
+    pub copy_options: CopyOptions,
     /// 현재 처리 중인 인덱스 (flattened_files 인덱스)
     pub current_index: usize,
     /// 작업 상태
@@ -237,8 +592,32 @@ pub struct PendingOperation {
     pub progress: OperationProgress,
     /// 누적 에러 목록
     pub errors: Vec<String>,
+    /// 에러가 난 시각 (errors와 같은 순서, 오류 보고서용)
+    pub error_times: Vec<SystemTime>,
     /// 완료된 파일 수
     pub completed_count: usize,
+    /// 다른 파일시스템이라 복사 후 삭제로 이동한 파일 수
+    pub cross_device_moves: usize,
+    /// 갱신 모드에서 대상이 최신이라 건너뛴 파일 수
+    pub unchanged_skipped: usize,
+    /// 권한 부족으로 실패해 관리자 권한으로 다시 시도할 수 있는 항목
+    pub denied: Vec<ElevatedOp>,
+    /// 대소문자를 구분하지 않는 대상에서 앞선 항목과 겹치는 대상 경로 (덮어쓰기 전에 항상 묻기)
+    pub case_collisions: HashSet<PathBuf>,
+    /// 범위 필터 (평탄화할 때 적용)
+    pub scope_filter: ScopeFilter,
+    /// 시작 전에 대상 여유 공간 확인 (대상 경로 입력에서 확정할 때만, 확인 후 해제)
+    pub check_free_space: bool,
+    /// 현재 항목이 사용 중이라 자동으로 다시 시도한 횟수 (항목을 마치면 0)
+    pub busy_retries: u32,
+    /// 사용 중인 현재 항목을 다시 시도할 시각
+    pub busy_retry_at: Option<Instant>,
+    /// 자동 재시도 후에도 사용 중이라 선택을 기다리는 오류 (WaitingConflict 상태)
+    pub busy_error: Option<String>,
+    /// "모두 건너뛰기"를 고른 뒤 사용 중인 파일은 묻지 않고 건너뛰기
+    pub skip_busy_all: bool,
+    /// 실패한 항목 인덱스 (Delete는 sources, 나머지는 flattened_files 기준, 실패 항목 재시도용)
+    pub failed: Vec<usize>,
 }
 
 impl PendingOperation {
@@ -252,11 +631,24 @@ impl PendingOperation {
             flattened_files: Vec::new(),
             move_cleanup_dirs: Vec::new(),
             conflict_resolution: None,
+            copy_options: CopyOptions::default(),
             current_index: 0,
             state: OperationState::Pending,
             progress: OperationProgress::new(operation_type, total_files, 0),
             errors: Vec::new(),
+            error_times: Vec::new(),
             completed_count: 0,
+            cross_device_moves: 0,
+            unchanged_skipped: 0,
+            denied: Vec::new(),
+            case_collisions: HashSet::new(),
+            scope_filter: ScopeFilter::default(),
+            check_free_space: false,
+            busy_retries: 0,
+            busy_retry_at: None,
+            busy_error: None,
+            skip_busy_all: false,
+            failed: Vec::new(),
         }
     }
 
@@ -269,27 +661,89 @@ impl PendingOperation {
         self.progress.bytes_copied = 0;
         self.progress.items_processed = 0;
         self.progress.items_failed = 0;
-        self.progress.start_time = Instant::now();
+        self.progress.current_file_bytes = 0;
+        self.progress.current_file_total = 0;
+        self.progress.restart_clock();
     }
 
     /// 현재 파일 이름 업데이트
     pub fn set_current_file(&mut self, name: &str) {
-        self.progress.current_file = name.to_string();
+        self.progress.begin_file(name, 0);
+    }
+
+    /// 현재 파일 이름과 크기 업데이트 (파일 진행률 표시용)
+    pub fn set_current_file_with_size(&mut self, name: &str, size: u64) {
+        self.progress.begin_file(name, size);
     }
 
     /// 파일/디렉토리 완료 시 진행 상태 업데이트
     ///
-    /// `file_count`: 완료된 파일 수 (디렉토리의 경우 내부 파일 수)
+    /// `file_count`: 완료된 파일 수 (디렉토리의 경우 내부 파일 수).
+    /// 분할 복사로 이미 반영된 바이트는 다시 더하지 않습니다.
     pub fn files_completed(&mut self, bytes: u64, file_count: usize) {
         self.progress.files_completed += file_count;
-        self.progress.bytes_copied += bytes;
+        let streamed = self.progress.current_file_bytes;
+        self.progress.current_file_bytes = self.progress.current_file_total;
+        self.progress
+            .set_bytes_copied(self.progress.bytes_copied + bytes.saturating_sub(streamed));
         self.completed_count += 1;
         self.progress.items_processed += 1;
+        self.busy_retries = 0;
     }
 
     /// 파일/디렉토리 건너뛰기 (에러 또는 Skip)
+    ///
+    /// 분할 복사 중 실패한 파일의 바이트는 전체 진행률에서 제외합니다.
     pub fn file_skipped(&mut self) {
+        let streamed = std::mem::take(&mut self.progress.current_file_bytes);
+        self.progress
+            .set_bytes_copied(self.progress.bytes_copied.saturating_sub(streamed));
         self.progress.items_processed += 1;
+        self.busy_retries = 0;
+    }
+
+    /// 다른 프로세스가 쓰는 파일이면 잠시 뒤 자동 재시도를 예약하고, 횟수를 다 쓰면 선택을 기다림
+    ///
+    /// 반환값: 사용 중 오류로 처리했으면 true (재시도/선택 대기는 인덱스를 그대로 두고,
+    /// "모두 건너뛰기"를 고른 뒤면 건너뛰고 다음 항목으로)
+    pub fn defer_busy_item(&mut self, error: &BokslDirError, max_attempts: u32) -> bool {
+        if !error.is_file_busy() {
+            return false;
+        }
+        if self.skip_busy_all {
+            self.file_skipped();
+            self.current_index += 1;
+        } else if self.busy_retries < max_attempts {
+            let delay = BUSY_RETRY_BASE_DELAY.saturating_mul(1 << self.busy_retries.min(16));
+            self.busy_retry_at = Some(Instant::now() + delay.min(BUSY_RETRY_MAX_DELAY));
+            self.busy_retries += 1;
+        } else {
+            self.busy_error = Some(error.to_string());
+            self.state = OperationState::WaitingConflict;
+        }
+        true
+    }
+
+    /// 현재 디렉토리 항목과 그 아래 항목을 모두 건너뛰기 (디렉토리 충돌에서 Skip)
+    ///
+    /// 평탄화 목록은 디렉토리 바로 뒤에 그 내용이 이어지므로 원본 경로가 디렉토리 아래인
+    /// 동안만 건너뜁니다.
+    pub fn skip_current_tree(&mut self) {
+        let Some(root) = self
+            .flattened_files
+            .get(self.current_index)
+            .map(|file| file.source.clone())
+        else {
+            return;
+        };
+        while self
+            .flattened_files
+            .get(self.current_index)
+            .is_some_and(|file| file.source.starts_with(&root))
+        {
+            self.file_skipped();
+            self.current_index += 1;
+        }
     }
 
     /// 현재 항목 실패 기록
@@ -297,9 +751,107 @@ impl PendingOperation {
         self.progress.items_failed += 1;
     }
 
+    /// 현재 항목을 재시도 대상으로 기록 (같은 항목은 한 번만, 다시 해도 실패하는 디렉토리 링크 제외)
+    pub fn record_failed_item(&mut self) {
+        let unsupported = self.operation_type != OperationType::Delete
+            && self
+                .flattened_files
+                .get(self.current_index)
+                .is_some_and(|file| file.entry_kind == FlattenedEntryKind::SymlinkDirectory);
+        if !unsupported && self.failed.last() != Some(&self.current_index) {
+            self.failed.push(self.current_index);
+        }
+    }
+
+    /// 실패한 항목만 같은 대상/옵션으로 다시 실행할 대기 작업 (실패 항목이 없으면 None)
+    pub fn retry_failed(&self) -> Option<PendingOperation> {
+        if self.failed.is_empty() {
+            return None;
+        }
+        let mut retry = if self.operation_type == OperationType::Delete {
+            let sources = self
+                .failed
+                .iter()
+                .filter_map(|&index| self.sources.get(index).cloned())
+                .collect();
+            PendingOperation::new(self.operation_type, sources, self.dest_dir.clone())
+        } else {
+            let mut retry = PendingOperation::new(
+                self.operation_type,
+                self.sources.clone(),
+                self.dest_dir.clone(),
+            );
+            retry.set_flattened_files(
+                self.failed
+                    .iter()
+                    .filter_map(|&index| self.flattened_files.get(index).cloned())
+                    .collect(),
+            );
+            retry.set_move_cleanup_dirs(self.move_cleanup_dirs.clone());
+            retry
+        };
+        retry.conflict_resolution = self.conflict_resolution;
+        retry.copy_options = self.copy_options;
+        retry.case_collisions = self.case_collisions.clone();
+        retry.scope_filter = self.scope_filter.clone();
+        Some(retry)
+    }
+
+    /// 평탄화할 때의 링크 처리 방식 (이동은 항상 링크 그대로)
+    pub fn symlink_policy(&self) -> SymlinkPolicy {
+        if self.operation_type == OperationType::Copy {
+            self.copy_options.symlinks
+        } else {
+            SymlinkPolicy::Links
+        }
+    }
+
+    /// 처리할 항목 수 (Delete는 sources, 나머지는 flattened_files)
+    pub fn item_count(&self) -> usize {
+        if self.operation_type == OperationType::Delete {
+            self.sources.len()
+        } else {
+            self.flattened_files.len()
+        }
+    }
+
     /// 에러 추가
     pub fn add_error(&mut self, error: String) {
         self.errors.push(error);
+        self.error_times.push(SystemTime::now());
+    }
+
+    /// 현재 항목 실패 기록 (권한 부족이면 관리자 권한 재시도 대상으로 보관)
+    pub fn fail_current_item(&mut self, file_name: &str, error: &BokslDirError) {
+        self.add_error(format!("{}: {}", file_name, error));
+        self.mark_item_failed();
+        self.record_failed_item();
+        self.file_skipped();
+        if !error.is_permission_denied() {
+            return;
+        }
+        let op = if self.operation_type == OperationType::Delete {
+            self.sources
+                .get(self.current_index)
+                .map(|path| ElevatedOp::Remove { path: path.clone() })
+        } else {
+            self.flattened_files.get(self.current_index).map(|file| {
+                match (self.operation_type, file.entry_kind) {
+                    (_, FlattenedEntryKind::Directory) => ElevatedOp::CreateDir {
+                        path: file.dest.clone(),
+                    },
+                    (OperationType::Move, _) => ElevatedOp::Move {
+                        source: file.source.clone(),
+                        dest: file.dest.clone(),
+                    },
+                    _ => ElevatedOp::Copy {
+                        source: file.source.clone(),
+                        dest: file.dest.clone(),
+                    },
+                }
+            })
+        };
+        self.denied.extend(op);
     }
 
     /// 모든 파일 처리 완료 여부
@@ -367,6 +919,52 @@ mod tests {
         assert_eq!(progress.percentage(), 0);
     }
 
+    #[test]
+    fn test_speed_uses_rolling_window_and_excludes_pause() {
+        let mut progress = OperationProgress::new(OperationType::Copy, 1, 10_000);
+        let base = Instant::now() - Duration::from_secs(10);
+        progress.start_time = base;
+        // 오래된 샘플은 구간에서 제외: 최근 2초간 2000바이트 → 1000 B/s
+        progress.bytes_copied = 1_000;
+        progress.record_speed_sample(base);
+        progress.bytes_copied = 6_000;
+        progress.record_speed_sample(base + Duration::from_secs(8));
+        progress.bytes_copied = 8_000;
+        progress.record_speed_sample(base + Duration::from_secs(10));
+        assert_eq!(progress.speed_samples.len(), 2);
+        assert!((progress.speed() - 1_000.0).abs() < 1.0);
+        assert_eq!(progress.format_eta(), "00:02");
+
+        progress.pause();
+        assert!(progress.is_paused());
+        assert_eq!(progress.speed(), 0.0);
+        progress.resume();
+        assert!(!progress.is_paused());
+        assert!(progress.speed_samples.is_empty());
+    }
+
+    #[test]
+    fn test_chunked_bytes_not_double_counted() {
+        let mut pending = PendingOperation::new(
+            OperationType::Copy,
+            vec![PathBuf::from("/a")],
+            PathBuf::new(),
+        );
+        pending.start_processing(300, 2);
+
+        pending.set_current_file_with_size("a", 200);
+        pending.progress.add_file_chunk(120);
+        assert_eq!(pending.progress.file_percentage(), 60);
+        pending.files_completed(200, 1);
+        assert_eq!(pending.progress.bytes_copied, 200);
+
+        // 실패한 파일의 부분 바이트는 제외
+        pending.set_current_file_with_size("b", 100);
+        pending.progress.add_file_chunk(40);
+        pending.file_skipped();
+        assert_eq!(pending.progress.bytes_copied, 200);
+    }
+
     #[test]
     fn test_pending_operation() {
         let sources = vec![PathBuf::from("/tmp/file1"), PathBuf::from("/tmp/file2")];
@@ -392,4 +990,116 @@ mod tests {
             FlattenedEntryKind::SymlinkDirectory
         );
     }
+
+    #[test]
+    fn test_fail_current_item_keeps_permission_denied_items_for_retry() {
+        let mut pending = PendingOperation::new(
+            OperationType::Move,
+            vec![PathBuf::from("/src/a")],
+            PathBuf::from("/dst"),
+        );
+        pending.set_flattened_files(vec![
+            FlattenedFile {
+                entry_kind: FlattenedEntryKind::Directory,
+                source: PathBuf::from("/src/a"),
+                dest: PathBuf::from("/dst/a"),
+                size: 0,
+            },
+            FlattenedFile {
+                entry_kind: FlattenedEntryKind::File,
+                source: PathBuf::from("/src/a/x"),
+                dest: PathBuf::from("/dst/a/x"),
+                size: 1,
+            },
+        ]);
+        let denied = BokslDirError::PermissionDenied {
+            path: PathBuf::from("/dst/a"),
+        };
+        pending.fail_current_item("a", &denied);
+        pending.current_index = 1;
+        pending.fail_current_item(
+            "x",
+            &BokslDirError::CopyFailed {
+                src: PathBuf::from("/src/a/x"),
+                dest: PathBuf::from("/dst/a/x"),
+                reason: "Permission denied (os error 13)".to_string(),
+            },
+        );
+        pending.fail_current_item(
+            "x",
+            &BokslDirError::PathNotFound {
+                path: PathBuf::from("/src/a/x"),
+            },
+        );
+
+        assert_eq!(pending.errors.len(), 3);
+        assert_eq!(
+            pending.denied,
+            vec![
+                ElevatedOp::CreateDir {
+                    path: PathBuf::from("/dst/a")
+                },
+                ElevatedOp::Move {
+                    source: PathBuf::from("/src/a/x"),
+                    dest: PathBuf::from("/dst/a/x")
+                },
+            ]
+        );
+        // 같은 항목을 두 번 실패해도 재시도 대상은 한 번
+        assert_eq!(pending.failed, vec![0, 1]);
+        let retry = pending.retry_failed().unwrap();
+        assert_eq!(retry.item_count(), 2);
+        assert_eq!(retry.dest_dir, PathBuf::from("/dst"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_busy_items_are_retried_with_backoff_then_wait_for_choice() {
+        let mut pending = PendingOperation::new(
+            OperationType::Delete,
+            vec![PathBuf::from("/a"), PathBuf::from("/b")],
+            PathBuf::new(),
+        );
+        pending.state = OperationState::Processing;
+        let busy = BokslDirError::Io(std::io::Error::from_raw_os_error(libc::ETXTBSY));
+        let denied = BokslDirError::PermissionDenied {
+            path: PathBuf::from("/a"),
+        };
+        assert!(busy.is_file_busy());
+        assert!(!pending.defer_busy_item(&denied, 2));
+
+        let mut delays = Vec::new();
+        for _ in 0..2 {
+            let before = Instant::now();
+            assert!(pending.defer_busy_item(&busy, 2));
+            delays.push(pending.busy_retry_at.unwrap() - before);
+        }
+        assert!(delays[1] > delays[0]);
+        assert_eq!(pending.current_index, 0);
+        assert!(pending.defer_busy_item(&busy, 2));
+        assert_eq!(pending.state, OperationState::WaitingConflict);
+        assert!(pending.busy_error.is_some());
+
+        // 모두 건너뛰기를 고른 뒤에는 묻지 않고 다음 항목으로
+        pending.skip_busy_all = true;
+        assert!(pending.defer_busy_item(&busy, 2));
+        assert_eq!(pending.current_index, 1);
+        assert_eq!(pending.busy_retries, 0);
+    }
+
+    #[test]
+    fn test_case_collisions_mark_later_entries_differing_only_by_case() {
+        let file = |dest: &str| FlattenedFile {
+            entry_kind: FlattenedEntryKind::File,
+            source: PathBuf::from("/src").join(dest),
+            dest: PathBuf::from("/dest").join(dest),
+            size: 0,
+        };
+        let files = [file("A.txt"), file("b.txt"), file("a.txt"), file("B.TXT")];
+        let collisions = case_collisions(&files);
+        assert_eq!(collisions.len(), 2);
+        assert!(collisions.contains(&PathBuf::from("/dest/a.txt")));
+        assert!(collisions.contains(&PathBuf::from("/dest/B.TXT")));
+        assert!(case_collisions(&files[..2]).is_empty());
+    }
 }
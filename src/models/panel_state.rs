@@ -1,19 +1,34 @@
 #![allow(dead_code)]
 
 use crate::models::file_entry::FileEntry;
-use crate::system::filesystem::FileSystem;
+use crate::system::filesystem::{DirectoryListing, FileSystem, MetadataHydration};
+use crate::system::git::{GitStatus, RepoSummary};
+use crate::system::vfs::VirtualFileSystem;
 use crate::ui::{I18n, Language, TextKey};
+use crate::utils::collation::{compare_names, NameCollation};
 use crate::utils::error::Result;
-use crate::utils::glob;
+use crate::utils::name_filter::NameFilter;
+use crate::utils::scope_filter::ScopeFilter;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 const MAX_HISTORY_ENTRIES: usize = 100;
 
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
 /// 정렬 기준
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SortBy {
     /// 이름
     Name,
@@ -26,7 +41,8 @@ pub enum SortBy {
 }
 
 /// 정렬 순서
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SortOrder {
     /// 오름차순
     Ascending,
@@ -34,6 +50,36 @@ pub enum SortOrder {
     Descending,
 }
 
+/// 목록의 파일/디렉토리 수와 크기 합 (상태바용, 목록이 바뀔 때만 다시 셈)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EntryTotals {
+    pub files: usize,
+    pub dirs: usize,
+    pub size: u64,
+}
+
+impl EntryTotals {
+    fn of(entries: &[FileEntry]) -> Self {
+        let mut totals = Self::default();
+        for entry in entries {
+            totals.add(entry);
+        }
+        totals
+    }
+
+    fn add(&mut self, entry: &FileEntry) {
+        self.files += usize::from(entry.is_file());
+        self.dirs += usize::from(entry.is_directory());
+        self.size += entry.size;
+    }
+
+    fn remove(&mut self, entry: &FileEntry) {
+        self.files -= usize::from(entry.is_file());
+        self.dirs -= usize::from(entry.is_directory());
+        self.size -= entry.size;
+    }
+}
+
 /// 패널 상태
 #[derive(Debug, Clone)]
 pub struct PanelState {
@@ -51,17 +97,56 @@ pub struct PanelState {
     pub sort_by: SortBy,
     /// 정렬 순서
     pub sort_order: SortOrder,
+    /// 이름 비교 방식 (자연 정렬 / 로캘 인식)
+    pub collation: NameCollation,
+    /// 디렉토리 우선 정렬 여부 (false면 파일과 섞어서 정렬)
+    pub dirs_first: bool,
+    /// 계산된 디렉토리 재귀 크기 (경로 → 바이트, 현재 디렉토리 기준)
+    pub dir_sizes: HashMap<PathBuf, u64>,
+    /// 매직 넘버로 판별한 파일 종류 설명 (종류 컬럼용, 목록을 다시 읽으면 초기화)
+    pub detected_types: HashMap<PathBuf, &'static str>,
+    /// 플러그인 컬럼 값 (컬럼 순서, 목록을 다시 읽으면 초기화)
+    pub plugin_values: HashMap<PathBuf, Vec<String>>,
+    /// 항목별 git 상태 (저장소 밖이거나 조회 전이면 비어 있음)
+    pub git_status: HashMap<PathBuf, GitStatus>,
+    /// 목록이 다시 읽혀 git 상태 재조회가 필요함
+    pub git_status_stale: bool,
+    /// 현재 경로가 속한 저장소 요약 (패널 제목 표시, 저장소 밖이면 None)
+    pub git_repo: Option<RepoSummary>,
     /// 숨김 파일 표시 여부
     pub show_hidden: bool,
+    /// 숨김 패턴 (설정 파일 `hide_patterns`, 숨김 파일 표시와 상관없이 적용)
+    pub hide_rules: ScopeFilter,
     /// 필터 패턴
     pub filter: Option<String>,
     /// 디렉토리 히스토리 (오래된 순)
     pub history_entries: Vec<PathBuf>,
     /// 현재 히스토리 인덱스
     pub history_index: usize,
+    /// 경로별 마지막 방문 시각 (UNIX 초)
+    pub history_visits: HashMap<PathBuf, u64>,
+    /// 고정한 히스토리 경로 (개수 상한을 넘어도 지워지지 않음)
+    pub history_pins: Vec<PathBuf>,
+    /// 사용자가 지정한 탭 이름 (없으면 경로 이름 표시)
+    pub tab_title: Option<String>,
+    /// 마운트된 원격 백엔드 (SFTP/FTP). None이면 로컬 파일시스템
+    pub backend: Option<Arc<dyn VirtualFileSystem>>,
+    /// 아직 읽는 중인 큰 디렉토리의 나머지 목록 (다 읽으면 None)
+    pub listing: Option<DirectoryListing>,
+    /// 첫 부분에 없어 나머지 목록이 도착하면 포커스할 항목 이름
+    pub listing_focus: Option<String>,
+    /// 이름만 먼저 읽은 항목의 메타데이터 읽기 (모두 채우면 None)
+    pub hydration: Option<MetadataHydration>,
+    /// `entries` 합계 (목록을 바꾸는 메서드가 함께 갱신)
+    totals: EntryTotals,
+    /// 다중 선택 항목의 크기 합 (선택을 바꾸는 메서드가 함께 갱신)
+    selected_bytes: u64,
 }
 
 impl PanelState {
+    /// 새로고침 때 바로 읽는 최대 항목 수 (넘는 나머지는 백그라운드에서 이어 읽음)
+    pub const SYNC_LISTING_LIMIT: usize = 10_000;
+
     fn normalize_directory_path(path: PathBuf) -> PathBuf {
         if path.as_os_str().is_empty() {
             return env::current_dir().unwrap_or(path);
@@ -76,9 +161,43 @@ impl PanelState {
         }
     }
 
+    /// 백엔드 경로는 그대로, 로컬 경로는 절대 경로로 정규화
+    fn resolve_directory_path(&self, path: PathBuf) -> PathBuf {
+        if self.backend.is_some() {
+            path
+        } else {
+            Self::normalize_directory_path(path)
+        }
+    }
+
+    /// 백엔드를 마운트하고 해당 경로로 이동 (실패하면 이전 백엔드 유지)
+    pub fn mount(&mut self, backend: Arc<dyn VirtualFileSystem>, path: PathBuf) -> Result<()> {
+        let previous = self.backend.replace(backend);
+        if let Err(error) = self.change_directory(path, &FileSystem::new()) {
+            self.backend = previous;
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// 마운트 해제 (로컬 경로 이동은 호출 측에서 처리)
+    pub fn unmount(&mut self) -> Option<Arc<dyn VirtualFileSystem>> {
+        self.backend.take()
+    }
+
+    pub fn is_mounted(&self) -> bool {
+        self.backend.is_some()
+    }
+
+    /// 현재 패널의 파일시스템 (마운트된 백엔드 또는 로컬)
+    pub fn vfs<'a>(&'a self, local: &'a dyn VirtualFileSystem) -> &'a dyn VirtualFileSystem {
+        self.backend.as_deref().unwrap_or(local)
+    }
+
     /// 새 패널 상태 생성
     pub fn new(path: PathBuf) -> Self {
         let history_seed = path.clone();
+        let history_visits = HashMap::from([(path.clone(), unix_now())]);
         Self {
             current_path: path,
             entries: Vec::new(),
@@ -87,42 +206,260 @@ impl PanelState {
             selected_items: HashSet::new(),
             sort_by: SortBy::Name,
             sort_order: SortOrder::Ascending,
+            collation: NameCollation::default(),
+            dirs_first: true,
+            dir_sizes: HashMap::new(),
+            detected_types: HashMap::new(),
+            plugin_values: HashMap::new(),
+            git_status: HashMap::new(),
+            git_status_stale: true,
+            git_repo: None,
             show_hidden: false,
+            hide_rules: ScopeFilter::default(),
             filter: None,
             history_entries: vec![history_seed],
             history_index: 0,
+            history_visits,
+            history_pins: Vec::new(),
+            tab_title: None,
+            backend: None,
+            listing: None,
+            listing_focus: None,
+            hydration: None,
+            totals: EntryTotals::default(),
+            selected_bytes: 0,
         }
     }
 
     /// 파일 목록 새로고침
     ///
-    /// 현재 경로의 파일 목록을 다시 읽어옵니다.
-    pub fn refresh(&mut self, filesystem: &FileSystem) -> Result<()> {
-        // 파일 목록 읽기
-        let mut entries = filesystem.read_directory(&self.current_path)?;
+    /// 현재 경로의 파일 목록을 다시 읽어옵니다. 마운트된 백엔드가 있으면 그 백엔드에서 읽습니다.
+    /// 항목이 `SYNC_LISTING_LIMIT`개를 넘으면 나머지는 백그라운드에서 읽고 [`Self::poll_listing`]으로 합칩니다.
+    pub fn refresh(&mut self, filesystem: &dyn VirtualFileSystem) -> Result<()> {
+        self.refresh_with_limit(filesystem, Self::SYNC_LISTING_LIMIT)
+    }
 
-        // 숨김 파일 필터링
-        if !self.show_hidden {
-            entries.retain(|entry| !entry.is_hidden);
+    pub(crate) fn refresh_with_limit(
+        &mut self,
+        filesystem: &dyn VirtualFileSystem,
+        sync_limit: usize,
+    ) -> Result<()> {
+        self.load_listing(filesystem, sync_limit, false)
+    }
+
+    /// 목록 읽기 (`cached`이면 디렉토리 이동: 백엔드가 보관한 최근 목록을 쓸 수 있음)
+    fn load_listing(
+        &mut self,
+        filesystem: &dyn VirtualFileSystem,
+        sync_limit: usize,
+        cached: bool,
+    ) -> Result<()> {
+        let backend = self.backend.clone();
+        let vfs = backend.as_deref().unwrap_or(filesystem);
+        let (entries, listing) = if cached {
+            vfs.list_cached(&self.current_path, sync_limit)?
+        } else {
+            vfs.list_streaming(&self.current_path, sync_limit)?
+        };
+        self.set_entries(entries);
+        self.listing = listing;
+        let pending: Vec<PathBuf> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.metadata_pending)
+            .map(|entry| entry.path.clone())
+            .collect();
+        self.request_metadata(pending);
+        Ok(())
+    }
+
+    /// 나머지 목록이나 메타데이터를 읽는 중인지
+    pub fn is_loading(&self) -> bool {
+        self.listing.is_some() || self.hydration.is_some()
+    }
+
+    /// 메타데이터 읽기 요청 (화면 순서대로 넘겨 위쪽 항목부터 채움)
+    fn request_metadata(&mut self, paths: Vec<PathBuf>) {
+        if paths.is_empty() {
+            return;
         }
+        self.hydration
+            .get_or_insert_with(MetadataHydration::spawn)
+            .request(paths);
+    }
 
-        // 필터 적용 (글로브 패턴 또는 부분 문자열 매칭)
-        if let Some(ref filter) = self.filter {
-            if !filter.is_empty() {
-                if glob::is_glob_pattern(filter) {
-                    entries.retain(|entry| glob::glob_match(filter, &entry.name));
-                } else {
-                    let filter_lower = filter.to_lowercase();
-                    entries.retain(|entry| entry.name.to_lowercase().contains(&filter_lower));
+    /// 백그라운드에서 읽힌 메타데이터를 항목에 채움 (바뀌었으면 true)
+    ///
+    /// 모두 채워지면 크기/날짜 정렬은 한 번 다시 정렬하되 커서 항목과 다중 선택은 같은 파일에 유지합니다.
+    pub fn poll_metadata(&mut self) -> bool {
+        let Some(hydration) = self.hydration.as_mut() else {
+            return false;
+        };
+        let ready = hydration.take_ready();
+        let done = hydration.is_done() && self.listing.is_none();
+        let changed = !ready.is_empty();
+        let positions: HashMap<&Path, usize> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (entry.path.as_path(), index))
+            .collect();
+        let updates: Vec<(usize, Option<FileEntry>)> = ready
+            .into_iter()
+            .filter_map(|hydrated| {
+                let index = *positions.get(hydrated.path.as_path())?;
+                Some((index, hydrated.entry))
+            })
+            .collect();
+        for (index, hydrated) in updates {
+            match hydrated {
+                Some(hydrated) => {
+                    let selected = self.selected_items.contains(&index);
+                    let previous = std::mem::replace(&mut self.entries[index], hydrated);
+                    self.totals.remove(&previous);
+                    self.totals.add(&self.entries[index]);
+                    if selected {
+                        self.selected_bytes =
+                            self.selected_bytes - previous.size + self.entries[index].size;
+                    }
                 }
+                // 그새 지워졌거나 읽을 수 없으면 이름만 표시
+                None => self.entries[index].metadata_pending = false,
+            }
+        }
+
+        if done {
+            self.hydration = None;
+            if matches!(self.sort_by, SortBy::Size | SortBy::Modified) {
+                let anchor = self.cursor_anchor();
+                self.sort_entries();
+                self.restore_cursor_anchor(anchor);
+            }
+        }
+        changed || done
+    }
+
+    /// 순서가 바뀌어도 커서/다중 선택을 되돌릴 기준 (커서 항목 경로와 화면상 줄, 선택한 경로)
+    fn cursor_anchor(&self) -> (Option<(PathBuf, usize)>, HashSet<PathBuf>) {
+        let parent_offset = usize::from(self.current_path.parent().is_some());
+        let cursor = self.selected_entry().map(|entry| {
+            let row = (self.selected_index - parent_offset).saturating_sub(self.scroll_offset);
+            (entry.path.clone(), row)
+        });
+        let selected_paths = self
+            .selected_items
+            .iter()
+            .filter_map(|&index| self.entries.get(index))
+            .map(|entry| entry.path.clone())
+            .collect();
+        (cursor, selected_paths)
+    }
+
+    fn restore_cursor_anchor(&mut self, anchor: (Option<(PathBuf, usize)>, HashSet<PathBuf>)) {
+        let (cursor, selected_paths) = anchor;
+        if !selected_paths.is_empty() {
+            self.selected_items = self
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| selected_paths.contains(&entry.path))
+                .map(|(index, _)| index)
+                .collect();
+        }
+        let parent_offset = usize::from(self.current_path.parent().is_some());
+        if let Some((path, row)) = cursor {
+            if let Some(index) = self.entries.iter().position(|entry| entry.path == path) {
+                self.selected_index = index + parent_offset;
+                self.scroll_offset = index.saturating_sub(row);
+            }
+        }
+    }
+
+    /// 백그라운드에서 새로 읽힌 항목을 정렬 위치에 끼워 넣음 (바뀌었으면 true)
+    ///
+    /// 이미 보이는 항목의 순서는 그대로 두고, 커서 항목과 다중 선택은 같은 파일에 유지하며
+    /// 커서가 화면에서 같은 줄에 남도록 스크롤을 함께 옮깁니다.
+    pub fn poll_listing(&mut self) -> bool {
+        let Some(listing) = self.listing.as_mut() else {
+            return false;
+        };
+        let (mut new_entries, finished) = listing.take_new();
+        if finished {
+            self.listing = None;
+            self.git_status_stale = true;
+        }
+        self.retain_visible_entries(&mut new_entries);
+        if new_entries.is_empty() {
+            if finished {
+                self.listing_focus = None;
             }
+            return finished;
         }
 
+        let parent_offset = usize::from(self.current_path.parent().is_some());
+        let anchor = self.cursor_anchor();
+        let pending: Vec<PathBuf> = new_entries
+            .iter()
+            .filter(|entry| entry.metadata_pending)
+            .map(|entry| entry.path.clone())
+            .collect();
+        new_entries.sort_by(|a, b| self.compare_entries(a, b));
+        for entry in &new_entries {
+            self.totals.add(entry);
+        }
+        let existing = std::mem::take(&mut self.entries);
+        let mut merged = Vec::with_capacity(existing.len() + new_entries.len());
+        let mut new_iter = new_entries.into_iter().peekable();
+        for entry in existing {
+            while new_iter
+                .peek()
+                .is_some_and(|new| self.compare_entries(new, &entry) == Ordering::Less)
+            {
+                merged.extend(new_iter.next());
+            }
+            merged.push(entry);
+        }
+        merged.extend(new_iter);
+        self.entries = merged;
+
+        let focus = self
+            .listing_focus
+            .clone()
+            .filter(|_| self.selected_index == 0);
+        if let Some(index) =
+            focus.and_then(|name| self.entries.iter().position(|entry| entry.name == name))
+        {
+            self.restore_cursor_anchor((None, anchor.1));
+            self.listing_focus = None;
+            self.selected_index = index + parent_offset;
+        } else {
+            self.restore_cursor_anchor(anchor);
+        }
+        self.request_metadata(pending);
+        if finished {
+            self.listing_focus = None;
+        }
+        true
+    }
+
+    /// 읽어 온 파일 목록 반영 (숨김/필터/정렬 적용, 선택 초기화)
+    ///
+    /// 로컬 파일시스템이 아닌 곳(원격 패널 등)에서 읽은 목록도 같은 규칙으로 표시합니다.
+    pub fn set_entries(&mut self, mut entries: Vec<FileEntry>) {
+        self.retain_visible_entries(&mut entries);
+        self.listing = None;
+        self.listing_focus = None;
+        self.hydration = None;
+
         self.entries = entries;
         self.sort_entries();
+        self.totals = EntryTotals::of(&self.entries);
+        self.git_status_stale = true;
+        self.detected_types.clear();
+        self.plugin_values.clear();
 
         // 디렉토리가 변경되면 선택 상태 초기화
-        self.selected_items.clear();
+        self.deselect_all();
 
         // 선택 인덱스가 범위를 벗어나면 조정
         // selected_index는 ".." 항목 포함 UI 인덱스
@@ -138,28 +475,58 @@ impl PanelState {
         if self.selected_index > max_index {
             self.selected_index = max_index;
         }
+    }
 
-        Ok(())
+    /// 숨김/필터 조건에 맞는 항목만 남김
+    fn retain_visible_entries(&self, entries: &mut Vec<FileEntry>) {
+        // 숨김 파일 필터링
+        if !self.show_hidden {
+            entries.retain(|entry| !entry.is_hidden);
+        }
+
+        // 숨김 패턴 (`*.pyc`, `__pycache__/` 등)
+        if !self.hide_rules.is_empty() {
+            entries.retain(|entry| {
+                self.hide_rules
+                    .allows(Path::new(&entry.name), entry.is_directory())
+            });
+        }
+
+        // 필터 적용 (글로브/부분 문자열/정규식, 공백 구분 OR, `!` 제외)
+        if let Some(ref filter) = self.filter {
+            let name_filter = NameFilter::parse(filter);
+            if !name_filter.is_empty() {
+                entries.retain(|entry| name_filter.matches(&entry.name));
+            }
+        }
     }
 
     /// 경로 변경
-    pub fn change_directory(&mut self, path: PathBuf, filesystem: &FileSystem) -> Result<()> {
+    pub fn change_directory(
+        &mut self,
+        path: PathBuf,
+        filesystem: &dyn VirtualFileSystem,
+    ) -> Result<()> {
         let previous_path = self.current_path.clone();
         let previous_selected_index = self.selected_index;
         let previous_scroll_offset = self.scroll_offset;
         let previous_selected_items = self.selected_items.clone();
 
-        self.current_path = Self::normalize_directory_path(path);
+        self.current_path = self.resolve_directory_path(path);
         self.selected_index = 0;
         self.scroll_offset = 0;
         self.selected_items.clear();
-        if let Err(error) = self.refresh(filesystem) {
+        if let Err(error) = self.load_listing(filesystem, Self::SYNC_LISTING_LIMIT, true) {
             self.current_path = previous_path;
             self.selected_index = previous_selected_index;
             self.scroll_offset = previous_scroll_offset;
             self.selected_items = previous_selected_items;
             return Err(error);
         }
+        if self.current_path != previous_path {
+            self.dir_sizes.clear();
+            self.git_status.clear();
+        }
         Ok(())
     }
 
@@ -168,23 +535,27 @@ impl PanelState {
         &mut self,
         path: PathBuf,
         focus_name: Option<&str>,
-        filesystem: &FileSystem,
+        filesystem: &dyn VirtualFileSystem,
     ) -> Result<()> {
         let previous_path = self.current_path.clone();
         let previous_selected_index = self.selected_index;
         let previous_scroll_offset = self.scroll_offset;
         let previous_selected_items = self.selected_items.clone();
 
-        self.current_path = Self::normalize_directory_path(path);
+        self.current_path = self.resolve_directory_path(path);
         self.scroll_offset = 0;
         self.selected_items.clear();
-        if let Err(error) = self.refresh(filesystem) {
+        if let Err(error) = self.load_listing(filesystem, Self::SYNC_LISTING_LIMIT, true) {
             self.current_path = previous_path;
             self.selected_index = previous_selected_index;
             self.scroll_offset = previous_scroll_offset;
             self.selected_items = previous_selected_items;
             return Err(error);
         }
+        if self.current_path != previous_path {
+            self.dir_sizes.clear();
+            self.git_status.clear();
+        }
 
         // 포커스할 항목 찾기
         if let Some(name) = focus_name {
@@ -195,6 +566,10 @@ impl PanelState {
                 self.selected_index = idx + offset;
             } else {
                 self.selected_index = 0;
+                // 큰 디렉토리: 나머지 목록에 있을 수 있음
+                if self.listing.is_some() {
+                    self.listing_focus = Some(name.to_string());
+                }
             }
         } else {
             self.selected_index = 0;
@@ -220,56 +595,100 @@ impl PanelState {
 
     /// 파일 개수 반환
     pub fn file_count(&self) -> usize {
-        self.entries.iter().filter(|e| e.is_file()).count()
+        self.totals.files
     }
 
     /// 디렉토리 개수 반환
     pub fn dir_count(&self) -> usize {
-        self.entries.iter().filter(|e| e.is_directory()).count()
+        self.totals.dirs
     }
 
     /// 전체 크기 반환 (바이트)
     pub fn total_size(&self) -> u64 {
-        self.entries.iter().map(|e| e.size).sum()
+        self.totals.size
+    }
+
+    /// `entries`를 직접 바꾼 뒤 합계와 선택 크기 다시 세기
+    pub fn recount_entries(&mut self) {
+        self.totals = EntryTotals::of(&self.entries);
+        self.selected_bytes = self.sum_selected_sizes();
+    }
+
+    fn sum_selected_sizes(&self) -> u64 {
+        self.selected_items
+            .iter()
+            .filter_map(|&idx| self.entries.get(idx))
+            .map(|e| e.size)
+            .sum()
     }
 
     // === 정렬 관련 메서드 (Phase 5.1) ===
 
-    /// 엔트리 정렬: 디렉토리 우선, 그 다음 기준별 정렬
+    /// 계산된 디렉토리 재귀 크기 기록 (크기순 정렬/표시에 사용)
+    pub fn set_dir_size(&mut self, path: PathBuf, size: u64) {
+        self.dir_sizes.insert(path, size);
+    }
+
+    /// 계산된 디렉토리 재귀 크기 (미계산이면 None)
+    pub fn dir_size(&self, entry: &FileEntry) -> Option<u64> {
+        if entry.is_directory() {
+            self.dir_sizes.get(&entry.path).copied()
+        } else {
+            None
+        }
+    }
+
+    /// 엔트리 정렬: 디렉토리 우선(옵션), 그 다음 기준별 정렬
     pub(crate) fn sort_entries(&mut self) {
-        let sort_by = self.sort_by;
-        let sort_order = self.sort_order;
+        let mut entries = std::mem::take(&mut self.entries);
+        entries.sort_by(|a, b| self.compare_entries(a, b));
+        self.entries = entries;
+    }
+
+    /// 현재 정렬 기준에 따른 두 항목 비교
+    fn compare_entries(&self, a: &FileEntry, b: &FileEntry) -> Ordering {
+        let size_of = |entry: &FileEntry| {
+            if entry.is_directory() {
+                self.dir_sizes
+                    .get(&entry.path)
+                    .copied()
+                    .unwrap_or(entry.size)
+            } else {
+                entry.size
+            }
+        };
 
-        self.entries.sort_by(|a, b| {
-            // 디렉토리 우선 (항상)
+        // 디렉토리 우선 (옵션)
+        if self.dirs_first {
             let dir_cmp = b.is_directory().cmp(&a.is_directory());
             if dir_cmp != Ordering::Equal {
                 return dir_cmp;
             }
+        }
 
-            // 기준별 비교
-            let cmp = match sort_by {
-                SortBy::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                SortBy::Size => a.size.cmp(&b.size),
-                SortBy::Modified => a.modified.cmp(&b.modified),
-                SortBy::Extension => {
-                    let ext_a = extract_extension(&a.name);
-                    let ext_b = extract_extension(&b.name);
-                    let ext_cmp = ext_a.cmp(&ext_b);
-                    if ext_cmp == Ordering::Equal {
-                        a.name.to_lowercase().cmp(&b.name.to_lowercase())
-                    } else {
-                        ext_cmp
-                    }
+        // 기준별 비교
+        let collation = self.collation;
+        let cmp = match self.sort_by {
+            SortBy::Name => compare_names(&a.name, &b.name, collation),
+            SortBy::Size => size_of(a).cmp(&size_of(b)),
+            SortBy::Modified => a.modified.cmp(&b.modified),
+            SortBy::Extension => {
+                let ext_a = extract_extension(&a.name);
+                let ext_b = extract_extension(&b.name);
+                let ext_cmp = compare_names(&ext_a, &ext_b, collation);
+                if ext_cmp == Ordering::Equal {
+                    compare_names(&a.name, &b.name, collation)
+                } else {
+                    ext_cmp
                 }
-            };
-
-            // 정렬 순서 적용
-            match sort_order {
-                SortOrder::Ascending => cmp,
-                SortOrder::Descending => cmp.reverse(),
             }
-        });
+        };
+
+        // 정렬 순서 적용
+        match self.sort_order {
+            SortOrder::Ascending => cmp,
+            SortOrder::Descending => cmp.reverse(),
+        }
     }
 
     /// 정렬 기준 설정 (같은 기준이면 순서 토글, 다르면 Ascending으로 리셋)
@@ -302,7 +721,21 @@ impl PanelState {
             SortOrder::Ascending => "▲",
             SortOrder::Descending => "▼",
         };
-        format!("{} {}", name, arrow)
+        let mut flags = Vec::new();
+        if !self.dirs_first {
+            flags.push("Mix");
+        }
+        if self.collation.natural {
+            flags.push("Nat");
+        }
+        if self.collation.locale {
+            flags.push("Loc");
+        }
+        if flags.is_empty() {
+            format!("{} {}", name, arrow)
+        } else {
+            format!("{} {} [{}]", name, arrow, flags.join(","))
+        }
     }
 
     pub fn sort_indicator_localized(&self, language: Language) -> String {
@@ -317,7 +750,21 @@ impl PanelState {
             SortOrder::Ascending => "▲",
             SortOrder::Descending => "▼",
         };
-        format!("{} {}", i18n.tr(name_key), arrow)
+        let mut flags = Vec::new();
+        if !self.dirs_first {
+            flags.push(i18n.tr(TextKey::SortMixedFlag));
+        }
+        if self.collation.natural {
+            flags.push(i18n.tr(TextKey::SortNaturalFlag));
+        }
+        if self.collation.locale {
+            flags.push(i18n.tr(TextKey::SortLocaleFlag));
+        }
+        if flags.is_empty() {
+            format!("{} {}", i18n.tr(name_key), arrow)
+        } else {
+            format!("{} {} [{}]", i18n.tr(name_key), arrow, flags.join(","))
+        }
     }
 
     // === 필터 관련 메서드 (Phase 5.2) ===
@@ -331,8 +778,9 @@ impl PanelState {
     ///
     /// - 연속 중복 경로는 기록하지 않음
     /// - 기존 히스토리는 보존하고 새 방문을 append
-    /// - 최대 100개 유지 (오래된 항목 삭제)
+    /// - 최대 100개 유지 (오래된 항목 삭제, 고정 경로는 마지막 기록 유지)
     pub fn record_history(&mut self, path: PathBuf) {
+        self.history_visits.insert(path.clone(), unix_now());
         if self.history_entries.is_empty() {
             self.history_entries.push(path);
             self.history_index = 0;
@@ -349,11 +797,55 @@ impl PanelState {
 
         self.history_entries.push(path);
         self.history_index = self.history_entries.len().saturating_sub(1);
+        self.trim_history();
+    }
+
+    /// 상한을 넘는 오래된 항목 삭제 (고정 경로의 마지막 기록과 현재 위치는 제외)
+    fn trim_history(&mut self) {
+        while self.history_entries.len() > MAX_HISTORY_ENTRIES {
+            let removable = (0..self.history_entries.len()).find(|&idx| {
+                let path = &self.history_entries[idx];
+                idx != self.history_index
+                    && (!self.history_pins.contains(path)
+                        || self.history_entries[idx + 1..].contains(path))
+            });
+            let Some(idx) = removable else {
+                break;
+            };
+            self.history_entries.remove(idx);
+            if idx < self.history_index {
+                self.history_index -= 1;
+            }
+        }
+        let entries = &self.history_entries;
+        self.history_visits.retain(|path, _| entries.contains(path));
+    }
+
+    /// 히스토리 이동으로 다시 방문한 경로의 시각 갱신
+    fn visit_history_index(&mut self) -> Option<PathBuf> {
+        let path = self.history_entries.get(self.history_index).cloned()?;
+        self.history_visits.insert(path.clone(), unix_now());
+        Some(path)
+    }
 
-        if self.history_entries.len() > MAX_HISTORY_ENTRIES {
-            let overflow = self.history_entries.len() - MAX_HISTORY_ENTRIES;
-            self.history_entries.drain(0..overflow);
-            self.history_index = self.history_index.saturating_sub(overflow);
+    /// 경로의 마지막 방문 시각 (기록이 없으면 None)
+    pub fn history_visited_at(&self, path: &Path) -> Option<u64> {
+        self.history_visits.get(path).copied()
+    }
+
+    /// 고정한 히스토리 경로인지
+    pub fn is_history_pinned(&self, path: &Path) -> bool {
+        self.history_pins.iter().any(|pinned| pinned == path)
+    }
+
+    /// 히스토리 경로 고정/해제 전환 (반환: 고정 여부)
+    pub fn toggle_history_pin(&mut self, path: &Path) -> bool {
+        if let Some(pos) = self.history_pins.iter().position(|pinned| pinned == path) {
+            self.history_pins.remove(pos);
+            false
+        } else {
+            self.history_pins.push(path.to_path_buf());
+            true
         }
     }
 
@@ -373,7 +865,7 @@ impl PanelState {
             return None;
         }
         self.history_index -= 1;
-        self.history_entries.get(self.history_index).cloned()
+        self.visit_history_index()
     }
 
     /// 히스토리 앞으로 이동 대상 반환 (인덱스 이동 포함)
@@ -382,7 +874,7 @@ impl PanelState {
             return None;
         }
         self.history_index += 1;
-        self.history_entries.get(self.history_index).cloned()
+        self.visit_history_index()
     }
 
     /// 히스토리 인덱스를 지정해 점프 (인덱스 이동 포함)
@@ -391,7 +883,7 @@ impl PanelState {
             return None;
         }
         self.history_index = index;
-        self.history_entries.get(self.history_index).cloned()
+        self.visit_history_index()
     }
 
     /// 히스토리 목록(최신순) 반환
@@ -412,26 +904,31 @@ impl PanelState {
             .collect()
     }
 
-    /// 현재 경로만 남기고 히스토리 전체 삭제
+    /// 고정 경로와 현재 경로만 남기고 히스토리 전체 삭제
     pub fn clear_history_to_current(&mut self) {
-        self.history_entries = vec![self.current_path.clone()];
-        self.history_index = 0;
+        let current = self.current_path.clone();
+        let mut kept: Vec<PathBuf> = Vec::new();
+        for path in &self.history_entries {
+            if *path != current && self.is_history_pinned(path) && !kept.contains(path) {
+                kept.push(path.clone());
+            }
+        }
+        kept.push(current);
+        self.history_index = kept.len() - 1;
+        self.history_entries = kept;
+        let entries = &self.history_entries;
+        self.history_visits.retain(|path, _| entries.contains(path));
     }
 
     /// 필터 상태 표시 문자열 (상태바용)
     pub fn filter_indicator(&self) -> Option<String> {
-        self.filter
-            .as_ref()
-            .filter(|f| !f.is_empty())
-            .map(|f| format!("Filter: {}", f))
+        self.filter_indicator_localized(Language::English)
     }
 
+    /// 필터 표시 문자열 (예: `Filter [Glob, Excl]: *.rs !*.o`)
     pub fn filter_indicator_localized(&self, language: Language) -> Option<String> {
-        let i18n = I18n::new(language);
-        self.filter
-            .as_ref()
-            .filter(|f| !f.is_empty())
-            .map(|f| format!("{}: {}", i18n.tr(TextKey::FilterPrefix), f))
+        let filter = self.filter.as_ref().filter(|f| !f.is_empty())?;
+        Some(I18n::new(language).filter_indicator(filter))
     }
 
     // === 다중 선택 관련 메서드 (Phase 3.1) ===
@@ -444,10 +941,21 @@ impl PanelState {
             return;
         }
 
-        if self.selected_items.contains(&entry_index) {
-            self.selected_items.remove(&entry_index);
+        let size = self.entries[entry_index].size;
+        if self.selected_items.remove(&entry_index) {
+            self.selected_bytes = self.selected_bytes.saturating_sub(size);
         } else {
             self.selected_items.insert(entry_index);
+            self.selected_bytes += size;
+        }
+    }
+
+    /// 여러 항목 추가 선택 (이미 선택된 항목은 그대로)
+    pub fn select_indices(&mut self, indices: impl IntoIterator<Item = usize>) {
+        for index in indices {
+            if !self.is_selected(index) {
+                self.toggle_selection(index);
+            }
         }
     }
 
@@ -457,6 +965,7 @@ impl PanelState {
         for i in 0..self.entries.len() {
             self.selected_items.insert(i);
         }
+        self.selected_bytes = self.totals.size;
     }
 
     /// 선택 반전
@@ -468,11 +977,13 @@ impl PanelState {
             }
         }
         self.selected_items = new_selection;
+        self.selected_bytes = self.totals.size.saturating_sub(self.selected_bytes);
     }
 
     /// 전체 해제
     pub fn deselect_all(&mut self) {
         self.selected_items.clear();
+        self.selected_bytes = 0;
     }
 
     /// 선택 여부 확인
@@ -497,11 +1008,7 @@ impl PanelState {
 
     /// 선택된 항목들의 총 크기 (바이트)
     pub fn selected_size(&self) -> u64 {
-        self.selected_items
-            .iter()
-            .filter_map(|&idx| self.entries.get(idx))
-            .map(|e| e.size)
-            .sum()
+        self.selected_bytes
     }
 }
 
@@ -525,15 +1032,35 @@ impl Default for PanelState {
             selected_items: HashSet::new(),
             sort_by: SortBy::Name,
             sort_order: SortOrder::Ascending,
+            collation: NameCollation::default(),
+            dirs_first: true,
+            dir_sizes: HashMap::new(),
+            detected_types: HashMap::new(),
+            plugin_values: HashMap::new(),
+            git_status: HashMap::new(),
+            git_status_stale: true,
+            git_repo: None,
             show_hidden: false,
+            hide_rules: ScopeFilter::default(),
             filter: None,
             history_entries: vec![PathBuf::from(".")],
             history_index: 0,
+            history_visits: HashMap::new(),
+            history_pins: Vec::new(),
+            tab_title: None,
+
+            backend: None,
+            listing: None,
+            listing_focus: None,
+            hydration: None,
+            totals: EntryTotals::default(),
+            selected_bytes: 0,
         }
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
 
@@ -563,6 +1090,96 @@ mod tests {
         assert!(!state.entries.is_empty());
     }
 
+    #[test]
+    fn test_large_directory_streams_rest_and_keeps_cursor_and_selection() {
+        let fs = FileSystem::new();
+        let temp = tempfile::tempdir().unwrap();
+        for i in 0..200 {
+            std::fs::write(temp.path().join(format!("f{:03}.txt", i)), "").unwrap();
+        }
+        let mut state = PanelState::new(temp.path().to_path_buf());
+        state.refresh_with_limit(&fs, 20).unwrap();
+        assert_eq!(state.entries.len(), 20);
+        assert!(state.is_loading());
+
+        // 첫 부분에서 커서/선택을 정한 뒤 나머지가 도착해도 같은 파일에 유지
+        state.selected_index = 6;
+        let cursor_path = state.selected_entry().unwrap().path.clone();
+        state.toggle_selection(2);
+        let selected_path = state.entries[2].path.clone();
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while state.is_loading() && std::time::Instant::now() < deadline {
+            state.poll_listing();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert!(!state.is_loading());
+        assert_eq!(state.entries.len(), 200);
+        let names: Vec<&str> = state.entries.iter().map(|e| e.name.as_str()).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+        assert_eq!(state.selected_entry().unwrap().path, cursor_path);
+        assert_eq!(state.selected_entries().len(), 1);
+        assert_eq!(state.selected_entries()[0].path, selected_path);
+
+        // 나머지 목록에만 있는 항목에 포커스
+        let mut state = PanelState::new(PathBuf::from("/"));
+        let target = temp.path().to_path_buf();
+        state.current_path = target.clone();
+        state.refresh_with_limit(&fs, 5).unwrap();
+        let missing = (0..200)
+            .map(|i| format!("f{:03}.txt", i))
+            .find(|name| !state.entries.iter().any(|entry| &entry.name == name))
+            .unwrap();
+        state.listing_focus = Some(missing.clone());
+        while state.is_loading() && std::time::Instant::now() < deadline {
+            state.poll_listing();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(state.selected_entry().unwrap().name, missing);
+    }
+
+    #[test]
+    fn test_lazy_metadata_fills_in_and_resorts_by_size() {
+        use crate::system::filesystem::LazyMetadata;
+
+        let mut fs = FileSystem::new();
+        fs.set_lazy_metadata(LazyMetadata::Always);
+        let temp = tempfile::tempdir().unwrap();
+        for (name, size) in [("a.txt", 30), ("b.txt", 10), ("c.txt", 20)] {
+            std::fs::write(temp.path().join(name), vec![b'x'; size]).unwrap();
+        }
+        std::fs::create_dir(temp.path().join("sub")).unwrap();
+        let mut state = PanelState::new(PathBuf::from("/"));
+        state.current_path = temp.path().to_path_buf();
+        state.dirs_first = false;
+        state.set_sort(SortBy::Size);
+        state.refresh(&fs).unwrap();
+
+        // 이름과 종류만 먼저 표시
+        assert_eq!(state.entries.len(), 4);
+        assert!(state.entries.iter().all(|entry| entry.metadata_pending));
+        assert!(state.entries.iter().any(|entry| entry.is_directory()));
+        assert!(state.is_loading());
+        state.selected_index = 1 + state
+            .entries
+            .iter()
+            .position(|entry| entry.name == "a.txt")
+            .unwrap();
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while state.is_loading() && std::time::Instant::now() < deadline {
+            state.poll_metadata();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert!(!state.is_loading());
+        assert!(state.entries.iter().all(|entry| !entry.metadata_pending));
+        let names: Vec<&str> = state.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["sub", "b.txt", "c.txt", "a.txt"]);
+        assert_eq!(state.selected_entry().unwrap().name, "a.txt");
+    }
+
     #[test]
     fn test_hidden_files_filtering() {
         let fs = FileSystem::new();
@@ -614,6 +1231,29 @@ mod tests {
         assert!(state.selected_items.contains(&0));
     }
 
+    #[test]
+    fn test_mount_failure_keeps_local_backend() {
+        let current_dir = std::env::current_dir().unwrap();
+        let mut state = PanelState::new(current_dir.clone());
+        let invalid_path =
+            std::env::temp_dir().join(format!("boksldir-nonexistent-mount-{}", std::process::id()));
+
+        assert!(state
+            .mount(Arc::new(FileSystem::new()), invalid_path)
+            .is_err());
+        assert!(!state.is_mounted());
+        assert_eq!(state.current_path, current_dir);
+
+        let temp_dir = std::env::temp_dir();
+        state
+            .mount(Arc::new(FileSystem::new()), temp_dir.clone())
+            .unwrap();
+        assert!(state.is_mounted());
+        assert_eq!(state.current_path, temp_dir);
+        assert!(state.unmount().is_some());
+        assert!(!state.is_mounted());
+    }
+
     #[test]
     fn test_selected_entry_returns_none_for_parent_row() {
         let mut state = PanelState::new(PathBuf::from("/tmp/child"));
@@ -786,6 +1426,43 @@ mod tests {
         assert_eq!(state.selected_count(), 0);
     }
 
+    #[test]
+    fn test_totals_are_kept_with_listing_and_selection_changes() {
+        let mut state = PanelState::default();
+        state.set_entries(vec![
+            create_test_entry_with_size("a.txt", 100),
+            create_test_entry_with_size("b.txt", 20),
+            create_test_dir("dir"),
+        ]);
+        assert_eq!((state.file_count(), state.dir_count()), (2, 1));
+        assert_eq!(state.total_size(), 120);
+
+        let a = state
+            .entries
+            .iter()
+            .position(|e| e.name == "a.txt")
+            .unwrap();
+        state.toggle_selection(a);
+        assert_eq!(state.selected_size(), 100);
+        state.invert_selection();
+        assert_eq!(state.selected_size(), 20);
+        state.select_indices([a]);
+        state.select_all();
+        assert_eq!(state.selected_size(), 120);
+        state.toggle_selection(a);
+        assert_eq!(state.selected_size(), 20);
+        state.deselect_all();
+        assert_eq!(state.selected_size(), 0);
+
+        // 목록을 직접 바꾼 경우 다시 세기
+        state.entries.push(create_test_entry_with_size("c.txt", 5));
+        state.select_indices([3]);
+        state.recount_entries();
+        assert_eq!(state.file_count(), 3);
+        assert_eq!(state.total_size(), 125);
+        assert_eq!(state.selected_size(), 5);
+    }
+
     // === 정렬 테스트 (Phase 5.1) ===
 
     #[test]
@@ -810,6 +1487,50 @@ mod tests {
         assert_eq!(state.entries[1].name, "zebra_dir");
     }
 
+    #[test]
+    fn test_sort_mixed_when_dirs_first_disabled() {
+        let mut state = PanelState::default();
+        state.dirs_first = false;
+        state.entries = vec![
+            create_test_entry("banana.txt"),
+            create_test_dir("cherry_dir"),
+            create_test_entry("apple.txt"),
+        ];
+
+        state.sort_entries();
+
+        assert_eq!(state.entries[0].name, "apple.txt");
+        assert_eq!(state.entries[1].name, "banana.txt");
+        assert_eq!(state.entries[2].name, "cherry_dir");
+        assert_eq!(state.sort_indicator(), "Name ▲ [Mix]");
+    }
+
+    #[test]
+    fn test_sort_by_size_uses_computed_dir_sizes() {
+        let mut state = PanelState::default();
+        state.sort_by = SortBy::Size;
+        state.entries = vec![
+            create_test_dir("big_dir"),
+            create_test_dir("small_dir"),
+            create_test_entry_with_size("medium.txt", 500),
+        ];
+        state.set_dir_size(PathBuf::from("/tmp/big_dir"), 10_000);
+        state.set_dir_size(PathBuf::from("/tmp/small_dir"), 10);
+
+        state.sort_entries();
+        assert_eq!(state.entries[0].name, "small_dir");
+        assert_eq!(state.entries[1].name, "big_dir");
+        assert_eq!(state.dir_size(&state.entries[1]), Some(10_000));
+
+        // 디렉토리 우선 해제 시 파일과 함께 크기순
+        state.dirs_first = false;
+        state.sort_entries();
+        assert_eq!(state.entries[0].name, "small_dir");
+        assert_eq!(state.entries[1].name, "medium.txt");
+        assert_eq!(state.entries[2].name, "big_dir");
+        assert_eq!(state.dir_size(&state.entries[1]), None);
+    }
+
     #[test]
     fn test_sort_by_name_case_insensitive() {
         let mut state = PanelState::default();
@@ -827,6 +1548,26 @@ mod tests {
         assert_eq!(state.entries[2].name, "Cherry.txt");
     }
 
+    #[test]
+    fn test_sort_by_name_natural_collation() {
+        let mut state = PanelState::default();
+        state.entries = vec![
+            create_test_entry("file10.txt"),
+            create_test_entry("file2.txt"),
+            create_test_entry("file1.txt"),
+        ];
+
+        state.sort_entries();
+        assert_eq!(state.entries[0].name, "file1.txt");
+        assert_eq!(state.entries[1].name, "file10.txt");
+
+        state.collation.natural = true;
+        state.sort_entries();
+        assert_eq!(state.entries[0].name, "file1.txt");
+        assert_eq!(state.entries[1].name, "file2.txt");
+        assert_eq!(state.entries[2].name, "file10.txt");
+    }
+
     #[test]
     fn test_sort_by_size() {
         let mut state = PanelState::default();
@@ -956,6 +1697,11 @@ mod tests {
 
         state.sort_by = SortBy::Extension;
         assert_eq!(state.sort_indicator(), "Ext ▲");
+
+        state.collation.natural = true;
+        assert_eq!(state.sort_indicator(), "Ext ▲ [Nat]");
+        state.collation.locale = true;
+        assert_eq!(state.sort_indicator(), "Ext ▲ [Nat,Loc]");
     }
 
     // === 필터 테스트 (Phase 5.2) ===
@@ -1036,7 +1782,20 @@ mod tests {
         assert!(state.filter_indicator().is_none());
 
         state.filter = Some("*.rs".to_string());
-        assert_eq!(state.filter_indicator(), Some("Filter: *.rs".to_string()));
+        assert_eq!(
+            state.filter_indicator(),
+            Some("Filter [Glob]: *.rs".to_string())
+        );
+
+        state.filter = Some(r"r:^test !\.bak$".to_string());
+        assert_eq!(
+            state.filter_indicator(),
+            Some(r"Filter [Regex,Excl]: ^test !\.bak$".to_string())
+        );
+        assert_eq!(
+            state.filter_indicator_localized(Language::Korean),
+            Some(r"필터 [정규식,제외]: ^test !\.bak$".to_string())
+        );
 
         state.filter = Some(String::new());
         assert!(state.filter_indicator().is_none());
@@ -1104,6 +1863,38 @@ mod tests {
         assert_eq!(state.history_index, 99);
     }
 
+    #[test]
+    fn test_history_pins_survive_cap_and_clear() {
+        let mut state = PanelState::new(PathBuf::from("/0"));
+        state.record_history(PathBuf::from("/pinned"));
+        assert!(state.toggle_history_pin(Path::new("/pinned")));
+        for i in 1..=120 {
+            state.record_history(PathBuf::from(format!("/{}", i)));
+        }
+
+        assert_eq!(state.history_entries.len(), 100);
+        assert_eq!(
+            state.history_entries.first(),
+            Some(&PathBuf::from("/pinned"))
+        );
+        assert_eq!(state.history_entries[1], PathBuf::from("/22"));
+        assert_eq!(state.history_index, 99);
+        assert!(state.history_visited_at(Path::new("/pinned")).is_some());
+        assert!(state.history_visited_at(Path::new("/0")).is_none());
+
+        state.current_path = PathBuf::from("/120");
+        state.clear_history_to_current();
+        assert_eq!(
+            state.history_entries,
+            vec![PathBuf::from("/pinned"), PathBuf::from("/120")]
+        );
+        assert_eq!(state.history_index, 1);
+
+        assert!(!state.toggle_history_pin(Path::new("/pinned")));
+        state.clear_history_to_current();
+        assert_eq!(state.history_entries, vec![PathBuf::from("/120")]);
+    }
+
     #[test]
     fn test_history_items_latest_first_marks_current() {
         let mut state = PanelState::new(PathBuf::from("/a"));
@@ -1152,7 +1943,7 @@ mod tests {
             state
                 .history_entries
                 .iter()
-                .filter(|p| **p == PathBuf::from("/a"))
+                .filter(|p| p.as_path() == std::path::Path::new("/a"))
                 .count(),
             2
         );
@@ -0,0 +1,348 @@
+#![allow(dead_code)]
+
+use crate::models::panel_state::{PanelState, SortBy, SortOrder};
+use crate::models::tab_state::PanelTabs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// 세션 파일 포맷 버전
+pub const SESSION_VERSION: u32 = 1;
+
+const SESSION_FILE_EXTENSION: &str = "toml";
+
+/// 세션 저장 시 활성 패널
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionSide {
+    #[default]
+    Left,
+    Right,
+}
+
+/// 탭 1개의 저장 상태
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TabSession {
+    pub path: PathBuf,
+    pub sort_by: SortBy,
+    pub sort_order: SortOrder,
+    #[serde(default)]
+    pub filter: Option<String>,
+    #[serde(default)]
+    pub show_hidden: bool,
+    /// 사용자가 지정한 탭 이름
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl TabSession {
+    /// 패널 상태에서 저장 대상 값만 추출
+    pub fn capture(panel: &PanelState) -> Self {
+        Self {
+            path: panel.current_path.clone(),
+            sort_by: panel.sort_by,
+            sort_order: panel.sort_order,
+            filter: panel.filter.clone(),
+            show_hidden: panel.show_hidden,
+            title: panel.tab_title.clone(),
+        }
+    }
+
+    /// 저장된 값으로 새 패널 상태 생성 (목록은 호출 측에서 refresh)
+    pub fn to_panel_state(&self) -> PanelState {
+        let mut panel = PanelState::new(self.path.clone());
+        panel.sort_by = self.sort_by;
+        panel.sort_order = self.sort_order;
+        panel.filter = self.filter.clone();
+        panel.show_hidden = self.show_hidden;
+        panel.tab_title = self.title.clone();
+        panel
+    }
+}
+
+/// 패널 1개의 탭 목록 저장 상태
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PanelSession {
+    #[serde(default)]
+    pub active_tab: usize,
+    #[serde(default)]
+    pub tabs: Vec<TabSession>,
+}
+
+impl PanelSession {
+    /// 패널 탭 전체 캡처
+    pub fn capture(tabs: &PanelTabs) -> Self {
+        Self {
+            active_tab: tabs.active_index(),
+            tabs: tabs.tabs().iter().map(TabSession::capture).collect(),
+        }
+    }
+
+    /// 존재하는 디렉토리 탭만 남기고 활성 탭 인덱스를 보정
+    ///
+    /// 남은 탭이 없으면 None
+    pub fn restorable_tabs(&self, max_tabs: usize) -> Option<(Vec<TabSession>, usize)> {
+        let mut active = 0;
+        let mut tabs = Vec::new();
+        for (idx, tab) in self.tabs.iter().enumerate() {
+            if tabs.len() >= max_tabs {
+                break;
+            }
+            if !tab.path.is_dir() {
+                continue;
+            }
+            if idx <= self.active_tab {
+                active = tabs.len();
+            }
+            tabs.push(tab.clone());
+        }
+        if tabs.is_empty() {
+            None
+        } else {
+            Some((tabs, active))
+        }
+    }
+}
+
+/// 이름 있는 세션 (양쪽 패널의 탭/경로/정렬/필터)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    pub version: u32,
+    pub name: String,
+    #[serde(default)]
+    pub active_panel: SessionSide,
+    pub left: PanelSession,
+    pub right: PanelSession,
+}
+
+impl Session {
+    /// 목록 표시용 요약 (각 패널 활성 탭 경로 + 탭 수)
+    pub fn summary(&self) -> String {
+        format!(
+            "L: {} | R: {}",
+            panel_summary(&self.left),
+            panel_summary(&self.right)
+        )
+    }
+}
+
+fn panel_summary(panel: &PanelSession) -> String {
+    let Some(tab) = panel
+        .tabs
+        .get(panel.active_tab)
+        .or_else(|| panel.tabs.first())
+    else {
+        return "-".to_string();
+    };
+    let path = tab.path.to_string_lossy();
+    if panel.tabs.len() > 1 {
+        format!("{} (+{})", path, panel.tabs.len() - 1)
+    } else {
+        path.to_string()
+    }
+}
+
+/// 세션 이름을 파일 이름으로 변환 (읽기 쉬운 이름 + 원래 이름의 해시)
+///
+/// 경로 구분자/특수문자는 '_'로 바꾸므로 "a/b"와 "a_b"가 같은 이름이 되고, 대소문자를
+/// 구분하지 않는 파일시스템에서는 "Work"와 "work"도 겹칩니다. 원래 이름의 해시를 붙여
+/// 서로 다른 세션이 같은 파일을 덮어쓰지 않게 합니다.
+pub fn session_file_name(name: &str) -> String {
+    let name = name.trim();
+    let stem = readable_stem(name);
+    format!(
+        "{}-{:016x}.{}",
+        stem,
+        stable_hash(name),
+        SESSION_FILE_EXTENSION
+    )
+}
+
+fn readable_stem(name: &str) -> String {
+    let stem: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | ' ') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let stem = stem.trim();
+    if stem.is_empty() {
+        "session".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+/// 실행/버전과 상관없이 같은 값을 내는 FNV-1a 64비트 해시
+fn stable_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// 세션 저장 (같은 이름은 덮어씀)
+pub fn save_session(dir: &Path, session: &Session) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let data = toml::to_string_pretty(session)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let path = dir.join(session_file_name(&session.name));
+    fs::write(&path, data)?;
+    Ok(path)
+}
+
+fn decode_session(data: &str) -> Option<Session> {
+    let parsed: Session = toml::from_str(data).ok()?;
+    if parsed.version != SESSION_VERSION || parsed.name.trim().is_empty() {
+        return None;
+    }
+    Some(parsed)
+}
+
+/// 저장된 세션 목록 (이름순, 읽을 수 없는 파일은 건너뜀)
+pub fn list_sessions(dir: &Path) -> Vec<Session> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut sessions: Vec<Session> = read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().and_then(|ext| ext.to_str()) == Some(SESSION_FILE_EXTENSION)
+        })
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|data| decode_session(&data))
+        .collect();
+    sessions.sort_by_key(|session| session.name.to_lowercase());
+    sessions
+}
+
+/// 이름으로 세션 불러오기
+pub fn load_session(dir: &Path, name: &str) -> Option<Session> {
+    let data = fs::read_to_string(dir.join(session_file_name(name))).ok()?;
+    decode_session(&data)
+}
+
+/// 이름으로 세션 삭제
+pub fn delete_session(dir: &Path, name: &str) -> io::Result<()> {
+    fs::remove_file(dir.join(session_file_name(name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample(name: &str, path: &Path) -> Session {
+        Session {
+            version: SESSION_VERSION,
+            name: name.to_string(),
+            active_panel: SessionSide::Right,
+            left: PanelSession {
+                active_tab: 0,
+                tabs: vec![TabSession {
+                    path: path.to_path_buf(),
+                    sort_by: SortBy::Size,
+                    sort_order: SortOrder::Descending,
+                    filter: Some("*.rs".to_string()),
+                    show_hidden: true,
+                    title: Some("src".to_string()),
+                }],
+            },
+            right: PanelSession::default(),
+        }
+    }
+
+    #[test]
+    fn test_session_file_name_sanitizes() {
+        let stem = |name: &str| {
+            let file = session_file_name(name);
+            file[..file.rfind('-').unwrap()].to_string()
+        };
+        assert_eq!(stem("work"), "work");
+        assert_eq!(stem(" a/b\\c "), "a_b_c");
+        assert_eq!(stem("../x"), "___x");
+        assert_eq!(stem("   "), "session");
+        assert!(session_file_name("work").ends_with(".toml"));
+        assert_eq!(session_file_name(" work "), session_file_name("work"));
+    }
+
+    #[test]
+    fn test_session_file_names_do_not_collide() {
+        // 특수문자를 '_'로 바꾸거나 대소문자만 다른 이름도 파일이 겹치지 않음
+        for (a, b) in [("a/b", "a_b"), ("Work", "work"), ("x?", "x*")] {
+            assert_ne!(
+                session_file_name(a).to_lowercase(),
+                session_file_name(b).to_lowercase()
+            );
+        }
+
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        save_session(dir, &sample("a/b", dir)).unwrap();
+        save_session(dir, &sample("a_b", dir)).unwrap();
+        let names: Vec<String> = list_sessions(dir).into_iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["a/b".to_string(), "a_b".to_string()]);
+    }
+
+    #[test]
+    fn test_save_list_load_delete_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().to_path_buf();
+        let session = sample("Work", &dir);
+        save_session(&dir, &session).unwrap();
+        save_session(&dir, &sample("alpha", &dir)).unwrap();
+
+        let names: Vec<String> = list_sessions(&dir).into_iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["alpha".to_string(), "Work".to_string()]);
+        assert_eq!(load_session(&dir, "Work"), Some(session));
+
+        delete_session(&dir, "Work").unwrap();
+        assert!(load_session(&dir, "Work").is_none());
+    }
+
+    #[test]
+    fn test_list_sessions_skips_invalid_files() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        fs::write(dir.join("broken.toml"), "not = [valid").unwrap();
+        fs::write(dir.join("note.txt"), "ignored").unwrap();
+        assert!(list_sessions(dir).is_empty());
+    }
+
+    #[test]
+    fn test_restorable_tabs_drops_missing_paths() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().to_path_buf();
+        let tab = |path: PathBuf| TabSession {
+            path,
+            sort_by: SortBy::Name,
+            sort_order: SortOrder::Ascending,
+            filter: None,
+            show_hidden: false,
+            title: None,
+        };
+        let panel = PanelSession {
+            active_tab: 2,
+            tabs: vec![tab(dir.clone()), tab(dir.join("missing")), tab(dir.clone())],
+        };
+        let (tabs, active) = panel.restorable_tabs(5).unwrap();
+        assert_eq!(tabs.len(), 2);
+        assert_eq!(active, 1);
+
+        let (tabs, active) = panel.restorable_tabs(1).unwrap();
+        assert_eq!(tabs.len(), 1);
+        assert_eq!(active, 0);
+
+        let missing = PanelSession {
+            active_tab: 0,
+            tabs: vec![tab(dir.join("missing"))],
+        };
+        assert!(missing.restorable_tabs(5).is_none());
+    }
+}
@@ -19,6 +19,25 @@ impl PanelTabs {
         }
     }
 
+    /// 저장된 탭 목록으로 생성 (빈 목록이면 None, 인덱스는 범위 내로 보정)
+    pub fn from_tabs(tabs: Vec<PanelState>, active_index: usize) -> Option<Self> {
+        if tabs.is_empty() {
+            return None;
+        }
+        let active_index = active_index.min(tabs.len() - 1);
+        Some(Self { tabs, active_index })
+    }
+
+    /// 전체 탭 상태
+    pub fn tabs(&self) -> &[PanelState] {
+        &self.tabs
+    }
+
+    /// 전체 탭 상태 (mutable)
+    pub fn tabs_mut(&mut self) -> &mut [PanelState] {
+        &mut self.tabs
+    }
+
     /// 활성 탭 상태 반환
     pub fn active(&self) -> &PanelState {
         &self.tabs[self.active_index]
@@ -86,12 +105,47 @@ impl PanelTabs {
         true
     }
 
-    /// 탭 타이틀 목록
+    /// 활성 탭을 앞(`forward`가 false) 또는 뒤로 한 칸 이동. 끝이면 false
+    pub fn move_active(&mut self, forward: bool) -> bool {
+        let target = if forward {
+            self.active_index + 1
+        } else {
+            match self.active_index.checked_sub(1) {
+                Some(index) => index,
+                None => return false,
+            }
+        };
+        if target >= self.tabs.len() {
+            return false;
+        }
+        self.tabs.swap(self.active_index, target);
+        self.active_index = target;
+        true
+    }
+
+    /// 활성 탭을 떼어 냄 (다른 패널로 보낼 때). 마지막 탭이면 None
+    pub fn take_active_tab(&mut self) -> Option<PanelState> {
+        if self.tabs.len() <= 1 {
+            return None;
+        }
+        let tab = self.tabs.remove(self.active_index);
+        if self.active_index >= self.tabs.len() {
+            self.active_index = self.tabs.len() - 1;
+        }
+        Some(tab)
+    }
+
+    /// 탭 타이틀 목록 (사용자 지정 이름 우선)
     pub fn titles(&self) -> Vec<String> {
-        self.tabs
-            .iter()
-            .map(|panel| title_from_path(&panel.current_path))
-            .collect()
+        self.tabs.iter().map(tab_title).collect()
+    }
+}
+
+/// 탭 타이틀 (사용자 지정 이름이 없으면 경로 이름)
+fn tab_title(panel: &PanelState) -> String {
+    match &panel.tab_title {
+        Some(title) => title.clone(),
+        None => title_from_path(&panel.current_path),
     }
 }
 
@@ -149,6 +203,16 @@ mod tests {
         assert_eq!(tabs.len(), 1);
     }
 
+    #[test]
+    fn test_from_tabs_clamps_active_index() {
+        assert!(PanelTabs::from_tabs(Vec::new(), 0).is_none());
+
+        let tabs = PanelTabs::from_tabs(vec![panel("/a"), panel("/b")], 7).unwrap();
+        assert_eq!(tabs.len(), 2);
+        assert_eq!(tabs.active_index(), 1);
+        assert_eq!(tabs.tabs()[0].current_path, PathBuf::from("/a"));
+    }
+
     #[test]
     fn test_next_prev_and_switch() {
         let mut tabs = PanelTabs::new(panel("/tmp"));
@@ -167,6 +231,27 @@ mod tests {
         assert!(!tabs.switch_to(5));
     }
 
+    #[test]
+    fn test_move_take_and_custom_titles() {
+        let mut tabs =
+            PanelTabs::from_tabs(vec![panel("/a"), panel("/b"), panel("/c")], 0).unwrap();
+        assert!(!tabs.move_active(false));
+        assert!(tabs.move_active(true));
+        assert_eq!(tabs.active_index(), 1);
+        assert_eq!(tabs.titles(), vec!["b", "a", "c"]);
+
+        tabs.active_mut().tab_title = Some("work".to_string());
+        assert_eq!(tabs.titles(), vec!["b", "work", "c"]);
+
+        let taken = tabs.take_active_tab().unwrap();
+        assert_eq!(taken.current_path, PathBuf::from("/a"));
+        assert_eq!(tabs.titles(), vec!["b", "c"]);
+        assert_eq!(tabs.active_index(), 1);
+        assert!(!tabs.move_active(true));
+        tabs.take_active_tab();
+        assert!(tabs.take_active_tab().is_none());
+    }
+
     #[test]
     fn test_tab_state_is_independent() {
         let mut tabs = PanelTabs::new(panel("/tmp"));
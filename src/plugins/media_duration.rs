@@ -0,0 +1,259 @@
+//! 재생 시간 컬럼 — MP4 계열(`moov/mvhd`)과 WAV(`fmt `/`data` 청크) 헤더에서 길이를 읽음
+//!
+//! 파일 전체를 읽지 않고 상자/청크 헤더만 건너뛰며 찾으므로 큰 동영상도 빠르게 표시합니다.
+
+use crate::core::plugins::{Plugin, PluginAction, PluginColumn, PluginContext};
+use crate::models::file_entry::FileEntry;
+use crate::utils::formatter::pluralize;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::Duration;
+
+pub const NAME: &str = "media-duration";
+
+const COLUMN: &str = "duration";
+const TOTAL_ACTION: &str = "total_duration";
+
+pub fn create() -> Box<dyn Plugin> {
+    Box::new(MediaDuration)
+}
+
+struct MediaDuration;
+
+impl Plugin for MediaDuration {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn actions(&self) -> Vec<PluginAction> {
+        vec![PluginAction {
+            id: TOTAL_ACTION,
+            label: "Total duration of selection",
+        }]
+    }
+
+    fn run_action(&mut self, id: &str, ctx: &mut PluginContext<'_>) {
+        if id != TOTAL_ACTION {
+            return;
+        }
+        let durations: Vec<Duration> = ctx
+            .targets()
+            .into_iter()
+            .filter(|entry| entry.is_file())
+            .filter_map(|entry| probe_path(&entry.path))
+            .collect();
+        if durations.is_empty() {
+            ctx.toast("No audio/video files");
+            return;
+        }
+        let total: Duration = durations.iter().sum();
+        ctx.toast(format!(
+            "Total {} ({})",
+            format_duration(total),
+            pluralize(durations.len(), "file", "files")
+        ));
+    }
+
+    fn columns(&self) -> Vec<PluginColumn> {
+        vec![PluginColumn {
+            id: COLUMN,
+            title: "Duration",
+            width: 8,
+        }]
+    }
+
+    fn column_value(&self, column: &str, entry: &FileEntry) -> Option<String> {
+        if column != COLUMN || !entry.is_file() {
+            return None;
+        }
+        probe_path(&entry.path).map(format_duration)
+    }
+}
+
+/// 파일 재생 시간 (읽을 수 없거나 지원하지 않는 형식이면 None)
+pub fn probe_path(path: &Path) -> Option<Duration> {
+    let file = File::open(path).ok()?;
+    probe(&mut BufReader::new(file)).ok().flatten()
+}
+
+fn probe<R: Read + Seek>(reader: &mut R) -> io::Result<Option<Duration>> {
+    let mut head = [0u8; 12];
+    reader.read_exact(&mut head)?;
+    reader.seek(SeekFrom::Start(0))?;
+    if &head[0..4] == b"RIFF" && &head[8..12] == b"WAVE" {
+        reader.seek(SeekFrom::Start(12))?;
+        wav_duration(reader)
+    } else if &head[4..8] == b"ftyp" {
+        let end = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(0))?;
+        mp4_duration(reader, end)
+    } else {
+        Ok(None)
+    }
+}
+
+/// MP4 상자 헤더 (종류, 내용 시작 위치, 상자 끝 위치)
+fn next_box<R: Read + Seek>(reader: &mut R, end: u64) -> io::Result<Option<([u8; 4], u64, u64)>> {
+    let start = reader.stream_position()?;
+    if start + 8 > end {
+        return Ok(None);
+    }
+    let mut header = [0u8; 8];
+    reader.read_exact(&mut header)?;
+    let kind = [header[4], header[5], header[6], header[7]];
+    let box_end = match u32::from_be_bytes([header[0], header[1], header[2], header[3]]) {
+        // 크기 0: 파일 끝까지
+        0 => end,
+        // 크기 1: 64비트 크기가 이어짐
+        1 => {
+            let mut large = [0u8; 8];
+            reader.read_exact(&mut large)?;
+            start.saturating_add(u64::from_be_bytes(large))
+        }
+        size => start + u64::from(size),
+    };
+    let content = reader.stream_position()?;
+    if box_end < content || box_end > end {
+        return Ok(None);
+    }
+    Ok(Some((kind, content, box_end)))
+}
+
+/// 지정 범위에서 종류가 맞는 첫 상자의 (내용 시작, 끝)
+fn find_box<R: Read + Seek>(
+    reader: &mut R,
+    end: u64,
+    wanted: &[u8; 4],
+) -> io::Result<Option<(u64, u64)>> {
+    while let Some((kind, content, box_end)) = next_box(reader, end)? {
+        if &kind == wanted {
+            return Ok(Some((content, box_end)));
+        }
+        reader.seek(SeekFrom::Start(box_end))?;
+    }
+    Ok(None)
+}
+
+fn mp4_duration<R: Read + Seek>(reader: &mut R, end: u64) -> io::Result<Option<Duration>> {
+    let Some((_, moov_end)) = find_box(reader, end, b"moov")? else {
+        return Ok(None);
+    };
+    if find_box(reader, moov_end, b"mvhd")?.is_none() {
+        return Ok(None);
+    }
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    let (timescale, duration) = if version[0] == 1 {
+        let mut fields = [0u8; 28];
+        reader.read_exact(&mut fields)?;
+        (
+            u32::from_be_bytes(fields[16..20].try_into().unwrap_or_default()),
+            u64::from_be_bytes(fields[20..28].try_into().unwrap_or_default()),
+        )
+    } else {
+        let mut fields = [0u8; 16];
+        reader.read_exact(&mut fields)?;
+        (
+            u32::from_be_bytes(fields[8..12].try_into().unwrap_or_default()),
+            u64::from(u32::from_be_bytes(
+                fields[12..16].try_into().unwrap_or_default(),
+            )),
+        )
+    };
+    // 길이를 모르면 duration이 모두 1로 채워짐
+    if timescale == 0 || duration == u64::MAX || duration == u64::from(u32::MAX) {
+        return Ok(None);
+    }
+    Ok(Some(Duration::from_secs_f64(
+        duration as f64 / f64::from(timescale),
+    )))
+}
+
+fn wav_duration<R: Read + Seek>(reader: &mut R) -> io::Result<Option<Duration>> {
+    let mut byte_rate = None;
+    loop {
+        let mut header = [0u8; 8];
+        if reader.read_exact(&mut header).is_err() {
+            return Ok(None);
+        }
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        match &header[0..4] {
+            b"fmt " if size >= 12 => {
+                let mut fmt = [0u8; 12];
+                reader.read_exact(&mut fmt)?;
+                byte_rate = Some(u32::from_le_bytes([fmt[8], fmt[9], fmt[10], fmt[11]]));
+                reader.seek(SeekFrom::Current(
+                    i64::from(size) - 12 + i64::from(size & 1),
+                ))?;
+            }
+            b"data" => {
+                return Ok(byte_rate
+                    .filter(|rate| *rate > 0)
+                    .map(|rate| Duration::from_secs_f64(f64::from(size) / f64::from(rate))));
+            }
+            // 청크는 짝수 바이트로 맞춰 저장
+            _ => {
+                reader.seek(SeekFrom::Current(i64::from(size) + i64::from(size & 1)))?;
+            }
+        }
+    }
+}
+
+/// `m:ss` / `h:mm:ss` (초 단위 반올림)
+pub fn format_duration(duration: Duration) -> String {
+    let total = duration.as_secs_f64().round() as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn mp4_box(kind: &[u8; 4], content: &[u8]) -> Vec<u8> {
+        let mut data = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(content);
+        data
+    }
+
+    #[test]
+    fn test_mp4_duration_skips_media_before_moov() {
+        let mut mvhd = vec![0u8; 4 + 8];
+        mvhd.extend_from_slice(&1000u32.to_be_bytes());
+        mvhd.extend_from_slice(&125_500u32.to_be_bytes());
+        let mut data = mp4_box(b"ftyp", b"isom\0\0\0\0");
+        data.extend(mp4_box(b"mdat", &[0u8; 100]));
+        data.extend(mp4_box(b"moov", &mp4_box(b"mvhd", &mvhd)));
+
+        let duration = probe(&mut Cursor::new(data)).unwrap();
+        assert_eq!(duration, Some(Duration::from_millis(125_500)));
+        assert_eq!(format_duration(duration.unwrap()), "2:06");
+    }
+
+    #[test]
+    fn test_wav_duration_from_byte_rate() {
+        let mut data = b"RIFF\0\0\0\0WAVE".to_vec();
+        data.extend_from_slice(b"LIST\x03\0\0\0abc\0");
+        data.extend_from_slice(b"fmt \x10\0\0\0");
+        data.extend_from_slice(&[1, 0, 2, 0]);
+        data.extend_from_slice(&44_100u32.to_le_bytes());
+        data.extend_from_slice(&176_400u32.to_le_bytes());
+        data.extend_from_slice(&[4, 0, 16, 0]);
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&(176_400u32 * 3725).to_le_bytes());
+
+        let duration = probe(&mut Cursor::new(data)).unwrap().unwrap();
+        assert_eq!(format_duration(duration), "1:02:05");
+        assert_eq!(
+            probe(&mut Cursor::new(b"plain text file".to_vec())).unwrap(),
+            None
+        );
+    }
+}
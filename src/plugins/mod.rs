@@ -0,0 +1,34 @@
+//! 빌드에 포함하는 플러그인 (설정 파일 `plugins`에 이름을 적어 켬)
+//!
+//! 새 플러그인은 이 디렉토리에 [`Plugin`] 구현을 추가하고 [`BUILTIN`]에 한 줄 등록합니다.
+
+use crate::core::plugins::{Plugin, PluginRegistry};
+
+pub mod media_duration;
+
+/// 플러그인 생성 함수
+type PluginFactory = fn() -> Box<dyn Plugin>;
+
+/// 사용 가능한 플러그인 (이름, 설명, 생성 함수)
+pub const BUILTIN: &[(&str, &str, PluginFactory)] = &[(
+    media_duration::NAME,
+    "Duration column for MP4/MOV/M4A and WAV files",
+    media_duration::create,
+)];
+
+/// 설정에 적힌 이름으로 플러그인 생성 (알 수 없는 이름은 두 번째 값으로 반환)
+pub fn load(names: &[String]) -> (PluginRegistry, Vec<String>) {
+    let mut registry = PluginRegistry::default();
+    let mut unknown = Vec::new();
+    for name in names {
+        // 같은 이름을 두 번 적어도 한 번만 등록
+        if registry.names().contains(&name.as_str()) {
+            continue;
+        }
+        match BUILTIN.iter().find(|(builtin, _, _)| builtin == name) {
+            Some((_, _, create)) => registry.register(create()),
+            None => unknown.push(name.clone()),
+        }
+    }
+    (registry, unknown)
+}
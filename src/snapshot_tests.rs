@@ -0,0 +1,197 @@
+// UI snapshot tests - TestBackend에 고정 크기로 그린 화면을 스냅샷 파일과 비교
+//
+// 스냅샷은 `src/snapshots/<이름>.snap`에 글자 격자, 스타일 격자, 스타일 범례 순서로 저장합니다.
+// 화면을 일부러 바꿨으면 `UPDATE_SNAPSHOTS=1 cargo test snapshot`으로 다시 쓰고 diff를 확인합니다.
+
+use super::*;
+use crate::app::fixture::{dir, file, fixture_time, AppFixture};
+use crate::utils::formatter::format_date;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use std::fmt::Write as _;
+use std::time::SystemTime;
+
+/// 스타일 격자에 쓰는 기호 (처음 나온 순서대로 배정)
+const STYLE_SYMBOLS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// 실제 프레임과 같은 경로로 그린 버퍼와 그리는 동안의 메뉴바 시계 값
+fn render(app: &mut App, width: u16, height: u16) -> (Buffer, [String; 2]) {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    let before = format_date(SystemTime::now());
+    terminal.draw(|f| render_frame(f, app)).unwrap();
+    let after = format_date(SystemTime::now());
+    (terminal.backend().buffer().clone(), [before, after])
+}
+
+/// 버퍼를 비교용 텍스트로 (항목 날짜와 메뉴바 시계는 시간대/현재 시각과 무관하게 가림)
+fn snapshot_text(buffer: &Buffer, clock: &[String; 2]) -> String {
+    let long_date = format_date(fixture_time());
+    let short_date = long_date[5..].to_string();
+    let area = buffer.area;
+    let mut styles: Vec<Style> = Vec::new();
+    let mut text = String::new();
+    let mut grid = String::new();
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        for x in area.left()..area.right() {
+            let cell = &buffer[(x, y)];
+            line.push_str(cell.symbol());
+            let style = cell.style();
+            let index = styles.iter().position(|s| *s == style).unwrap_or_else(|| {
+                styles.push(style);
+                styles.len() - 1
+            });
+            grid.push(STYLE_SYMBOLS.chars().nth(index).unwrap_or('?'));
+        }
+        let line = clock
+            .iter()
+            .fold(line, |line, now| line.replace(now, "<clock>---------"))
+            .replace(&long_date, "YYYY-MM-DD HH:MM")
+            .replace(&short_date, "MM-DD HH:MM");
+        text.push_str(line.trim_end());
+        text.push('\n');
+        grid.push('\n');
+    }
+    let mut legend = String::new();
+    for (style, symbol) in styles.iter().zip(STYLE_SYMBOLS.chars()) {
+        let _ = writeln!(
+            legend,
+            "{}: fg={:?} bg={:?} mod={:?}",
+            symbol,
+            style.fg.unwrap_or_default(),
+            style.bg.unwrap_or_default(),
+            style.add_modifier
+        );
+    }
+    format!("{}\n{}\n{}", text, grid, legend)
+}
+
+/// 스냅샷 파일과 비교 (다르면 `<이름>.snap.new`에 새 화면을 남기고 실패)
+fn assert_snapshot(name: &str, app: &mut App, width: u16, height: u16) {
+    let (buffer, clock) = render(app, width, height);
+    let actual = snapshot_text(&buffer, &clock);
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/snapshots");
+    let path = dir.join(format!("{}.snap", name));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {} (run with UPDATE_SNAPSHOTS=1)",
+            path.display()
+        )
+    });
+    if expected != actual {
+        let new_path = path.with_extension("snap.new");
+        std::fs::write(&new_path, &actual).unwrap();
+        let line = expected
+            .lines()
+            .zip(actual.lines())
+            .position(|(e, a)| e != a)
+            .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+        panic!(
+            "snapshot {} differs from line {} (new screen: {})",
+            name,
+            line + 1,
+            new_path.display()
+        );
+    }
+}
+
+fn sample_app() -> AppFixture {
+    AppFixture::new()
+        .left(
+            "/work/project",
+            vec![
+                dir("docs"),
+                dir("src"),
+                file("Cargo.toml", 1_520),
+                file("README.md", 8_301),
+                file("notes.txt", 42),
+            ],
+        )
+        .right(
+            "/work/backup",
+            vec![dir("2025"), file("archive.zip", 3_145_728)],
+        )
+        .cursor(3)
+        .select(&[3])
+}
+
+#[test]
+fn test_snapshot_dual_panel() {
+    assert_snapshot("dual_panel", &mut sample_app().build(), 100, 24);
+}
+
+#[test]
+fn test_snapshot_single_panel_narrow() {
+    assert_snapshot("single_panel_narrow", &mut sample_app().build(), 60, 20);
+}
+
+#[test]
+fn test_snapshot_too_small() {
+    assert_snapshot("too_small", &mut sample_app().build(), 30, 8);
+}
+
+#[test]
+fn test_snapshot_light_theme() {
+    assert_snapshot(
+        "light_theme",
+        &mut sample_app().theme("light").focus_right().build(),
+        100,
+        24,
+    );
+}
+
+#[test]
+fn test_snapshot_delete_confirm_dialog() {
+    let dialog = DialogKind::delete_confirm(
+        vec!["README.md".to_string(), "notes.txt".to_string()],
+        "8.1 KB",
+    );
+    assert_snapshot(
+        "delete_confirm_dialog",
+        &mut sample_app().dialog(dialog).build(),
+        100,
+        24,
+    );
+}
+
+#[test]
+fn test_snapshot_mkdir_dialog() {
+    let dialog = DialogKind::mkdir_input(PathBuf::from("/work/project"), false);
+    assert_snapshot(
+        "mkdir_dialog",
+        &mut sample_app().dialog(dialog).build(),
+        100,
+        24,
+    );
+}
+
+#[test]
+fn test_snapshot_archive_preview() {
+    let items = vec![
+        ("backup/".to_string(), "-".to_string()),
+        ("backup/data.db".to_string(), "2.0 MB".to_string()),
+        ("backup/logs/app.log".to_string(), "12.4 KB".to_string()),
+    ];
+    let dialog = DialogKind::archive_preview_list("archive.zip", items, false);
+    assert_snapshot(
+        "archive_preview",
+        &mut sample_app().focus_right().dialog(dialog).build(),
+        100,
+        24,
+    );
+}
+
+#[test]
+fn test_snapshot_help() {
+    assert_snapshot(
+        "help",
+        &mut sample_app().dialog(DialogKind::help()).build(),
+        100,
+        30,
+    );
+}
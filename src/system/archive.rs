@@ -1,9 +1,13 @@
 #![allow(dead_code)]
 
+use crate::system::file_type;
+pub use crate::system::name_encoding::ZipNameEncoding;
 use crate::utils::error::{BokslDirError, Result};
+use crate::utils::temp_name;
 use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
+use serde::{Deserialize, Serialize};
 use sevenz_rust2::Error as SevenZError;
 use sevenz_rust2::Password as SevenZPassword;
 use std::collections::BTreeSet;
@@ -14,6 +18,8 @@ use std::path::{Component, Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 use tar::{Archive as TarArchive, Builder as TarBuilder};
 use zip::result::ZipError;
 use zip::write::SimpleFileOptions as ZipFileOptions;
@@ -21,6 +27,45 @@ use zip::{AesMode, CompressionMethod, ZipArchive, ZipWriter};
 use zstd::stream::read::Decoder as ZstdDecoder;
 use zstd::stream::write::Encoder as ZstdEncoder;
 
+/// 일시정지 중 재개/취소 확인 주기
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 압축 작업 제어 플래그 (UI 스레드 → 작업 스레드)
+///
+/// 작업 스레드는 항목 단위 경계에서만 플래그를 확인하므로,
+/// 일시정지되어도 작성 중인 항목이 중간에 끊기지 않습니다.
+#[derive(Debug, Default)]
+pub struct ArchiveControl {
+    cancel: AtomicBool,
+    pause: AtomicBool,
+}
+
+impl ArchiveControl {
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        self.pause.store(paused, Ordering::Relaxed);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.pause.load(Ordering::Relaxed)
+    }
+
+    /// 안전한 중단 지점: 일시정지 중이면 재개/취소될 때까지 대기한 뒤 취소 여부를 반환
+    pub fn wait_while_paused(&self) -> bool {
+        while self.is_paused() && !self.is_cancelled() {
+            thread::sleep(PAUSE_POLL_INTERVAL);
+        }
+        self.is_cancelled()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ArchiveFormat {
     Zip,
@@ -58,6 +103,102 @@ pub struct ArchiveCreateRequest {
     pub sources: Vec<PathBuf>,
     pub output_path: PathBuf,
     pub password: Option<String>,
+    pub level: CompressionLevel,
+    /// tar.zst/7z 압축 스레드 수 (1이면 단일 스레드)
+    pub threads: u32,
+}
+
+/// 압축 수준 (`store`는 이미 압축된 파일을 압축하지 않고 담기만 함)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionLevel {
+    Store,
+    Fast,
+    #[default]
+    Normal,
+    Max,
+}
+
+impl CompressionLevel {
+    const ALL: [CompressionLevel; 4] = [
+        CompressionLevel::Store,
+        CompressionLevel::Fast,
+        CompressionLevel::Normal,
+        CompressionLevel::Max,
+    ];
+
+    /// 설정 파일/압축 창에 쓰는 이름
+    pub fn label(self) -> &'static str {
+        match self {
+            CompressionLevel::Store => "store",
+            CompressionLevel::Fast => "fast",
+            CompressionLevel::Normal => "normal",
+            CompressionLevel::Max => "max",
+        }
+    }
+
+    /// 다음/이전 수준 (끝에서 반대쪽으로 돌아감)
+    pub fn cycle(self, forward: bool) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|level| *level == self)
+            .unwrap_or(0);
+        let len = Self::ALL.len();
+        Self::ALL[if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        }]
+    }
+
+    fn deflate_level(self) -> i64 {
+        match self {
+            CompressionLevel::Store => 0,
+            CompressionLevel::Fast => 1,
+            CompressionLevel::Normal => 6,
+            CompressionLevel::Max => 9,
+        }
+    }
+
+    /// zstd는 담기만 하는 모드가 없어 store도 가장 빠른 수준으로 씀
+    fn zstd_level(self) -> i32 {
+        match self {
+            CompressionLevel::Store | CompressionLevel::Fast => 1,
+            CompressionLevel::Normal => 3,
+            CompressionLevel::Max => 19,
+        }
+    }
+}
+
+/// 압축 생성 프리셋 (설정 파일 `[[archive_presets]]`, 압축 창에서 숫자 키로 적용)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivePreset {
+    pub name: String,
+    /// 압축 파일 확장자 (`zip`, `tar.zst`, `7z` 등)
+    pub extension: String,
+    #[serde(default)]
+    pub level: CompressionLevel,
+    /// 비밀번호 입력 켜기 (zip/7z)
+    #[serde(default)]
+    pub password: bool,
+}
+
+/// 설정 파일에 프리셋이 없을 때 쓰는 기본 프리셋
+pub fn default_archive_presets() -> Vec<ArchivePreset> {
+    [
+        ("zip fast", "zip", CompressionLevel::Fast, false),
+        ("tar.zst max", "tar.zst", CompressionLevel::Max, false),
+        ("encrypted 7z", "7z", CompressionLevel::Normal, true),
+        ("zip store", "zip", CompressionLevel::Store, false),
+    ]
+    .into_iter()
+    .map(|(name, extension, level, password)| ArchivePreset {
+        name: name.to_string(),
+        extension: extension.to_string(),
+        level,
+        password,
+    })
+    .collect()
 }
 
 #[derive(Debug, Clone)]
@@ -69,6 +210,22 @@ pub struct ArchiveExtractRequest {
     pub overwrite_entries: Vec<String>,
     pub skip_existing_entries: Vec<String>,
     pub skip_all_existing: bool,
+    /// 풀 항목의 압축 안 경로 (디렉토리는 아래 항목 포함, 비어 있으면 전체)
+    pub only_entries: Vec<String>,
+    /// ZIP 항목 이름 인코딩 (목록과 같은 인코딩이어야 `only_entries` 등이 맞음)
+    pub name_encoding: ZipNameEncoding,
+}
+
+/// 압축 파일 안 항목 삭제 요청 (ZIP/7z)
+#[derive(Debug, Clone)]
+pub struct ArchiveDeleteRequest {
+    pub archive_path: PathBuf,
+    /// 지울 항목의 압축 안 경로 (디렉토리는 아래 항목 포함)
+    pub entries: Vec<String>,
+    /// 7z는 다시 압축하므로 암호가 걸려 있으면 필요
+    pub password: Option<String>,
+    /// ZIP 항목 이름 인코딩 (`entries`를 고른 목록과 같은 인코딩)
+    pub name_encoding: ZipNameEncoding,
 }
 
 #[derive(Debug, Clone)]
@@ -82,7 +239,7 @@ pub struct ArchiveSummary {
 }
 
 impl ArchiveSummary {
-    fn new(total_files: usize, total_bytes: u64) -> Self {
+    pub(crate) fn new(total_files: usize, total_bytes: u64) -> Self {
         Self {
             total_files,
             total_bytes,
@@ -121,12 +278,10 @@ pub fn detect_archive_format(path: &Path) -> Option<ArchiveFormat> {
     if name.ends_with(".tar.zst") || name.ends_with(".tzst") {
         return Some(ArchiveFormat::TarZst);
     }
-    match path
-        .extension()
-        .and_then(OsStr::to_str)?
-        .to_lowercase()
-        .as_str()
-    {
+    let Some(extension) = path.extension() else {
+        return detect_archive_format_by_content(path);
+    };
+    match extension.to_str()?.to_lowercase().as_str() {
         "zip" => Some(ArchiveFormat::Zip),
         "tar" => Some(ArchiveFormat::Tar),
         "7z" => Some(ArchiveFormat::SevenZ),
@@ -136,11 +291,52 @@ pub fn detect_archive_format(path: &Path) -> Option<ArchiveFormat> {
     }
 }
 
+/// 확장자 없는 파일의 압축 형식을 매직 넘버로 판별 (ZIP/7z/tar만, 파일이 없으면 None)
+///
+/// 확장자가 있는 파일(.docx 등 ZIP 기반 문서)은 기존처럼 확장자로만 판단합니다.
+fn detect_archive_format_by_content(path: &Path) -> Option<ArchiveFormat> {
+    if !path.is_file() {
+        return None;
+    }
+    match file_type::detect_path(path).ok()?.mime {
+        "application/zip" => Some(ArchiveFormat::Zip),
+        "application/x-7z-compressed" => Some(ArchiveFormat::SevenZ),
+        "application/x-tar" => Some(ArchiveFormat::Tar),
+        _ => None,
+    }
+}
+
 pub fn supports_password(format: ArchiveFormat) -> bool {
     matches!(format, ArchiveFormat::Zip | ArchiveFormat::SevenZ)
 }
 
-pub fn list_entries(path: &Path, password: Option<&str>) -> Result<Vec<ArchiveEntry>> {
+/// 항목 이름 인코딩을 고를 수 있음 (tar/7z는 이름을 UTF-8/UTF-16으로 저장)
+pub fn supports_name_encoding(format: ArchiveFormat) -> bool {
+    matches!(
+        format,
+        ArchiveFormat::Zip | ArchiveFormat::Jar | ArchiveFormat::War
+    )
+}
+
+/// 인코딩 고르기 미리보기용 이름 (ASCII가 아닌 첫 ZIP 항목 이름의 바이트열)
+pub fn zip_name_sample(path: &Path) -> Option<Vec<u8>> {
+    if !supports_name_encoding(detect_archive_format(path)?) {
+        return None;
+    }
+    let mut archive = ZipArchive::new(File::open(path).ok()?).ok()?;
+    (0..archive.len()).find_map(|idx| {
+        let entry = archive.by_index_raw(idx).ok()?;
+        let raw = entry.name_raw();
+        (!raw.is_ascii()).then(|| raw.to_vec())
+    })
+}
+
+/// 압축 파일 항목 목록 (`name_encoding`은 ZIP 이름에만 적용)
+pub fn list_entries(
+    path: &Path,
+    password: Option<&str>,
+    name_encoding: ZipNameEncoding,
+) -> Result<Vec<ArchiveEntry>> {
     let format =
         detect_archive_format(path).ok_or_else(|| BokslDirError::ArchiveUnsupportedFormat {
             path: path.to_path_buf(),
@@ -148,7 +344,7 @@ pub fn list_entries(path: &Path, password: Option<&str>) -> Result<Vec<ArchiveEn
 
     match format {
         ArchiveFormat::Zip | ArchiveFormat::Jar | ArchiveFormat::War => {
-            list_zip_entries(path, password)
+            list_zip_entries(path, password, name_encoding)
         }
         ArchiveFormat::Tar => list_tar_entries(path),
         ArchiveFormat::TarGz => list_tar_gz_entries(path),
@@ -160,7 +356,7 @@ pub fn list_entries(path: &Path, password: Option<&str>) -> Result<Vec<ArchiveEn
 pub fn create_archive(
     request: &ArchiveCreateRequest,
     progress_tx: Sender<ArchiveProgressEvent>,
-    cancel_flag: Arc<AtomicBool>,
+    control: Arc<ArchiveControl>,
 ) -> Result<ArchiveSummary> {
     let format = detect_archive_format(&request.output_path).ok_or_else(|| {
         BokslDirError::ArchiveUnsupportedFormat {
@@ -168,6 +364,12 @@ pub fn create_archive(
         }
     })?;
 
+    log::info!(
+        "archive create: {} source(s) -> {} ({:?})",
+        request.sources.len(),
+        request.output_path.display(),
+        format
+    );
     if request.sources.is_empty() {
         return Err(BokslDirError::ArchiveCreateFailed {
             path: request.output_path.clone(),
@@ -205,8 +407,9 @@ pub fn create_archive(
                 &request.output_path,
                 &items,
                 request.password.as_deref(),
+                request.level,
                 &progress_tx,
-                &cancel_flag,
+                &control,
                 &mut summary,
                 &mut files_completed,
                 &mut bytes_processed,
@@ -217,7 +420,7 @@ pub fn create_archive(
                 &request.output_path,
                 &items,
                 &progress_tx,
-                &cancel_flag,
+                &control,
                 &mut summary,
                 &mut files_completed,
                 &mut bytes_processed,
@@ -227,8 +430,9 @@ pub fn create_archive(
             create_tar_gz_archive(
                 &request.output_path,
                 &items,
+                request.level,
                 &progress_tx,
-                &cancel_flag,
+                &control,
                 &mut summary,
                 &mut files_completed,
                 &mut bytes_processed,
@@ -238,8 +442,10 @@ pub fn create_archive(
             create_tar_zst_archive(
                 &request.output_path,
                 &items,
+                request.level,
+                request.threads,
                 &progress_tx,
-                &cancel_flag,
+                &control,
                 &mut summary,
                 &mut files_completed,
                 &mut bytes_processed,
@@ -250,8 +456,10 @@ pub fn create_archive(
                 &request.output_path,
                 &request.sources,
                 request.password.as_deref(),
+                request.level,
+                request.threads,
                 &progress_tx,
-                &cancel_flag,
+                &control,
                 &mut summary,
                 total_files,
                 total_bytes,
@@ -259,13 +467,18 @@ pub fn create_archive(
         }
     }
 
+    // 취소로 중간에 멈춘 압축 파일은 남기지 않음
+    if summary.cancelled {
+        let _ = fs::remove_file(&request.output_path);
+    }
+
     Ok(summary)
 }
 
 pub fn extract_archive(
     request: &ArchiveExtractRequest,
     progress_tx: Sender<ArchiveProgressEvent>,
-    cancel_flag: Arc<AtomicBool>,
+    control: Arc<ArchiveControl>,
 ) -> Result<ArchiveSummary> {
     let format = detect_archive_format(&request.archive_path).ok_or_else(|| {
         BokslDirError::ArchiveUnsupportedFormat {
@@ -273,6 +486,12 @@ pub fn extract_archive(
         }
     })?;
 
+    log::info!(
+        "archive extract: {} -> {} ({:?})",
+        request.archive_path.display(),
+        request.dest_dir.display(),
+        format
+    );
     if !request.dest_dir.exists() || !request.dest_dir.is_dir() {
         return Err(BokslDirError::ArchiveExtractFailed {
             path: request.archive_path.clone(),
@@ -283,7 +502,14 @@ pub fn extract_archive(
         });
     }
 
-    let list = list_entries(&request.archive_path, request.password.as_deref())?;
+    let list: Vec<ArchiveEntry> = list_entries(
+        &request.archive_path,
+        request.password.as_deref(),
+        request.name_encoding,
+    )?
+    .into_iter()
+    .filter(|entry| is_requested_entry(&request.only_entries, &entry.path))
+    .collect();
     let total_files = list.len();
     let total_bytes = list.iter().map(|e| e.size).sum::<u64>();
     let mut summary = ArchiveSummary::new(total_files, total_bytes);
@@ -302,7 +528,7 @@ pub fn extract_archive(
         ArchiveFormat::Zip | ArchiveFormat::Jar | ArchiveFormat::War => extract_zip_archive(
             request,
             &progress_tx,
-            &cancel_flag,
+            &control,
             &mut summary,
             total_files,
             total_bytes,
@@ -310,7 +536,7 @@ pub fn extract_archive(
         ArchiveFormat::Tar => extract_tar_archive(
             request,
             &progress_tx,
-            &cancel_flag,
+            &control,
             &mut summary,
             total_files,
             total_bytes,
@@ -318,7 +544,7 @@ pub fn extract_archive(
         ArchiveFormat::TarGz => extract_tar_gz_archive(
             request,
             &progress_tx,
-            &cancel_flag,
+            &control,
             &mut summary,
             total_files,
             total_bytes,
@@ -326,7 +552,7 @@ pub fn extract_archive(
         ArchiveFormat::TarZst => extract_tar_zst_archive(
             request,
             &progress_tx,
-            &cancel_flag,
+            &control,
             &mut summary,
             total_files,
             total_bytes,
@@ -334,7 +560,7 @@ pub fn extract_archive(
         ArchiveFormat::SevenZ => extract_7z_archive(
             request,
             &progress_tx,
-            &cancel_flag,
+            &control,
             &mut summary,
             total_files,
             total_bytes,
@@ -344,15 +570,231 @@ pub fn extract_archive(
     Ok(summary)
 }
 
+/// 압축 파일 안 항목 삭제 가능 여부 (다른 형식은 다시 쓰기를 지원하지 않음)
+pub fn supports_entry_delete(format: ArchiveFormat) -> bool {
+    matches!(
+        format,
+        ArchiveFormat::Zip | ArchiveFormat::Jar | ArchiveFormat::War | ArchiveFormat::SevenZ
+    )
+}
+
+/// 압축 파일에서 항목 삭제
+///
+/// 남길 항목만 같은 디렉토리의 임시 파일에 다시 쓴 뒤 원래 파일을 바꿔치기합니다. ZIP은 압축된
+/// 데이터를 그대로 옮기고(암호 불필요), 7z는 남길 항목을 임시 디렉토리에 풀어 다시 압축합니다.
+/// 취소하거나 실패하면 임시 파일만 지우고 원래 파일은 그대로 둡니다.
+pub fn delete_entries(
+    request: &ArchiveDeleteRequest,
+    progress_tx: Sender<ArchiveProgressEvent>,
+    control: Arc<ArchiveControl>,
+) -> Result<ArchiveSummary> {
+    let archive_path = &request.archive_path;
+    let format = detect_archive_format(archive_path)
+        .filter(|format| supports_entry_delete(*format))
+        .ok_or_else(|| BokslDirError::ArchiveUnsupportedFormat {
+            path: archive_path.clone(),
+        })?;
+    let update_failed = |reason: String| BokslDirError::ArchiveUpdateFailed {
+        path: archive_path.clone(),
+        reason,
+    };
+
+    let list = list_entries(
+        archive_path,
+        request.password.as_deref(),
+        request.name_encoding,
+    )?;
+    let removed: Vec<&ArchiveEntry> = list
+        .iter()
+        .filter(|entry| is_requested_entry(&request.entries, &entry.path))
+        .collect();
+    if removed.is_empty() {
+        return Err(update_failed("No matching entries to delete".to_string()));
+    }
+    let mut summary =
+        ArchiveSummary::new(removed.len(), removed.iter().map(|entry| entry.size).sum());
+    log::info!(
+        "archive delete: {} ({} of {} entries)",
+        archive_path.display(),
+        removed.len(),
+        list.len()
+    );
+
+    let file_name = archive_path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .unwrap_or("archive");
+    let parent = match archive_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    // 미리 있던 파일/링크를 열거나 지우지 않도록 새로 만든 임시 파일에만 씀
+    let (temp_path, temp_file) =
+        temp_name::create_new_file(parent, &format!(".{}.boksldir-delete-", file_name), "")
+            .map_err(|e| update_failed(e.to_string()))?;
+    let total_files = list.len();
+    let total_bytes = list.iter().map(|entry| entry.size).sum::<u64>();
+    let result = match format {
+        ArchiveFormat::SevenZ => rewrite_7z_without(
+            request,
+            temp_file,
+            &progress_tx,
+            &control,
+            &mut summary,
+            total_files,
+            total_bytes,
+        ),
+        _ => rewrite_zip_without(
+            request,
+            temp_file,
+            &progress_tx,
+            &control,
+            &mut summary,
+            total_files,
+            total_bytes,
+        ),
+    };
+    if let Err(reason) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(update_failed(reason));
+    }
+    if summary.cancelled {
+        let _ = fs::remove_file(&temp_path);
+        return Ok(summary);
+    }
+
+    if let Ok(meta) = fs::metadata(archive_path) {
+        let _ = fs::set_permissions(&temp_path, meta.permissions());
+    }
+    if let Err(e) = fs::rename(&temp_path, archive_path) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(update_failed(e.to_string()));
+    }
+    summary.items_processed = removed.len();
+    Ok(summary)
+}
+
+/// 지울 항목을 빼고 ZIP 항목을 압축된 그대로 옮겨 쓰기
+fn rewrite_zip_without(
+    request: &ArchiveDeleteRequest,
+    temp_file: File,
+    progress_tx: &Sender<ArchiveProgressEvent>,
+    control: &Arc<ArchiveControl>,
+    summary: &mut ArchiveSummary,
+    total_files: usize,
+    total_bytes: u64,
+) -> std::result::Result<(), String> {
+    let file = File::open(&request.archive_path).map_err(|e| e.to_string())?;
+    let mut archive = ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let mut writer = ZipWriter::new(temp_file);
+
+    let mut files_completed = 0usize;
+    let mut bytes_processed = 0u64;
+    for idx in 0..archive.len() {
+        if should_cancel(control) {
+            summary.cancelled = true;
+            return Ok(());
+        }
+        let entry = archive.by_index_raw(idx).map_err(|e| e.to_string())?;
+        let name = request.name_encoding.decode(entry.name_raw());
+        let size = entry.size();
+        if !is_requested_entry(&request.entries, &name) {
+            writer
+                .raw_copy_file(entry)
+                .map_err(|e| format!("{}: {}", name, e))?;
+        }
+        files_completed += 1;
+        bytes_processed = bytes_processed.saturating_add(size);
+        send_progress(
+            progress_tx,
+            name,
+            files_completed,
+            total_files,
+            bytes_processed,
+            total_bytes,
+            summary,
+        );
+    }
+    writer.set_raw_comment(archive.comment().into());
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 지울 항목을 빼고 남길 7z 항목을 풀어 가며 바로 다시 압축
+///
+/// 원래 항목 정보(이름, 시각, 속성)를 그대로 옮겨 쓰고 데이터만 다시 압축하므로
+/// 풀 수 없는 이름이라도 빠뜨리지 않습니다.
+fn rewrite_7z_without(
+    request: &ArchiveDeleteRequest,
+    temp_file: File,
+    progress_tx: &Sender<ArchiveProgressEvent>,
+    control: &Arc<ArchiveControl>,
+    summary: &mut ArchiveSummary,
+    total_files: usize,
+    total_bytes: u64,
+) -> std::result::Result<(), String> {
+    let file = File::open(&request.archive_path).map_err(|e| e.to_string())?;
+    let password = request
+        .password
+        .as_deref()
+        .map(SevenZPassword::from)
+        .unwrap_or_else(SevenZPassword::empty);
+    let mut reader = sevenz_rust2::ArchiveReader::new(file, password).map_err(|e| e.to_string())?;
+    let mut writer = sevenz_rust2::ArchiveWriter::new(temp_file).map_err(|e| e.to_string())?;
+    writer.set_content_methods(sevenz_content_methods(
+        request.password.as_deref(),
+        CompressionLevel::Normal,
+        1,
+    ));
+
+    let mut files_completed = 0usize;
+    let mut bytes_processed = 0u64;
+    reader
+        .for_each_entries(|entry, data| {
+            if should_cancel(control) {
+                summary.cancelled = true;
+                return Ok(false);
+            }
+            let name = entry.name().to_string();
+            if !is_requested_entry(&request.entries, &name) {
+                let stream = (entry.has_stream && !entry.is_directory()).then_some(data);
+                writer.push_archive_entry(entry.clone(), stream)?;
+            }
+            files_completed += 1;
+            bytes_processed = bytes_processed.saturating_add(entry.size());
+            send_progress(
+                progress_tx,
+                name,
+                files_completed,
+                total_files,
+                bytes_processed,
+                total_bytes,
+                summary,
+            );
+            Ok(true)
+        })
+        .map_err(|e| e.to_string())?;
+    if summary.cancelled {
+        return Ok(());
+    }
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 pub fn list_extract_conflicts(
     archive_path: &Path,
     dest_dir: &Path,
     password: Option<&str>,
+    only_entries: &[String],
+    name_encoding: ZipNameEncoding,
 ) -> Result<Vec<String>> {
-    let entries = list_entries(archive_path, password)?;
+    let entries = list_entries(archive_path, password, name_encoding)?;
     let mut conflicts = BTreeSet::new();
 
     for entry in entries {
+        if !is_requested_entry(only_entries, &entry.path) {
+            continue;
+        }
         let raw_path = PathBuf::from(&entry.path);
         let Some(dest_path) = sanitize_extract_path(dest_dir, &raw_path) else {
             continue;
@@ -420,8 +862,113 @@ fn archive_display_path(path: &Path) -> String {
         .join("/")
 }
 
-fn should_cancel(cancel_flag: &Arc<AtomicBool>) -> bool {
-    cancel_flag.load(Ordering::Relaxed)
+fn should_cancel(control: &Arc<ArchiveControl>) -> bool {
+    control.wait_while_paused()
+}
+
+/// 진행 이벤트 사이 최소 읽기 양 (큰 파일 하나를 압축하는 동안에도 처리 속도를 갱신)
+const PROGRESS_REPORT_BYTES: u64 = 1024 * 1024;
+
+/// 읽은 바이트를 세어 일정 양마다 `report(지금까지 읽은 양)`을 부르는 읽기 래퍼
+struct ProgressReader<R, F: FnMut(u64)> {
+    inner: R,
+    report: F,
+    read: u64,
+    reported: u64,
+}
+
+impl<R, F: FnMut(u64)> ProgressReader<R, F> {
+    fn new(inner: R, report: F) -> Self {
+        Self {
+            inner,
+            report,
+            read: 0,
+            reported: 0,
+        }
+    }
+}
+
+impl<R: Read, F: FnMut(u64)> Read for ProgressReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.read += n as u64;
+        if self.read - self.reported >= PROGRESS_REPORT_BYTES {
+            self.reported = self.read;
+            (self.report)(self.read);
+        }
+        Ok(n)
+    }
+}
+
+/// 압축 해제 때 항목 내용을 옮기는 버퍼 크기 (항목 크기와 관계없이 메모리 사용을 이만큼으로 고정)
+const STREAM_BUFFER_SIZE: usize = 256 * 1024;
+
+/// 고정 크기 버퍼로 항목 내용을 옮기기
+///
+/// 일정 양마다 `report(지금까지 쓴 양)`을 부르고 일시정지/취소를 확인합니다. 취소되면
+/// `ErrorKind::Interrupted` 오류로 멈추므로 호출한 쪽은 `control.is_cancelled()`로 구분합니다.
+fn stream_copy(
+    reader: &mut dyn Read,
+    writer: &mut dyn Write,
+    control: &Arc<ArchiveControl>,
+    report: &mut dyn FnMut(u64),
+) -> io::Result<u64> {
+    let mut buf = vec![0u8; STREAM_BUFFER_SIZE];
+    let mut written = 0u64;
+    let mut reported = 0u64;
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buf[..n])?;
+        written += n as u64;
+        if written - reported >= PROGRESS_REPORT_BYTES {
+            reported = written;
+            report(written);
+            if should_cancel(control) {
+                return Err(io::Error::new(io::ErrorKind::Interrupted, "cancelled"));
+            }
+        }
+    }
+    writer.flush()?;
+    Ok(written)
+}
+
+/// tar 일반 파일 항목을 스트리밍으로 풀고 권한/수정 시간 적용 (`Entry::unpack`과 같은 결과)
+fn unpack_tar_file<R: Read>(
+    entry: &mut tar::Entry<'_, R>,
+    dest_path: &Path,
+    control: &Arc<ArchiveControl>,
+    report: &mut dyn FnMut(u64),
+) -> io::Result<()> {
+    let mode = entry.header().mode().ok();
+    let mtime = entry.header().mtime().ok();
+    let mut out = File::create(dest_path)?;
+    stream_copy(entry, &mut out, control, report)?;
+    if let Some(mtime) = mtime {
+        let _ = out.set_modified(std::time::UNIX_EPOCH + std::time::Duration::from_secs(mtime));
+    }
+    #[cfg(unix)]
+    if let Some(mode) = mode {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = out.set_permissions(fs::Permissions::from_mode(mode & 0o777));
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+    Ok(())
+}
+
+/// 압축 스레드 수 (0이면 CPU 수)
+pub fn resolve_compression_threads(configured: usize) -> u32 {
+    let threads = if configured == 0 {
+        std::thread::available_parallelism().map_or(1, usize::from)
+    } else {
+        configured
+    };
+    u32::try_from(threads.max(1)).unwrap_or(u32::MAX)
 }
 
 fn normalize_entry_name(name: &str) -> String {
@@ -434,6 +981,20 @@ fn matches_entry(list: &[String], entry_name: &str) -> bool {
         .any(|item| normalize_entry_name(item) == normalized)
 }
 
+/// 고른 항목이거나 고른 디렉토리 아래 항목 (고른 항목이 없으면 모두)
+fn is_requested_entry(only_entries: &[String], entry_name: &str) -> bool {
+    if only_entries.is_empty() {
+        return true;
+    }
+    let normalized = normalize_entry_name(entry_name);
+    only_entries.iter().any(|item| {
+        let item = normalize_entry_name(item);
+        normalized
+            .strip_prefix(&item)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    })
+}
+
 fn should_overwrite_existing(request: &ArchiveExtractRequest, entry_name: &str) -> bool {
     request.overwrite_existing || matches_entry(&request.overwrite_entries, entry_name)
 }
@@ -587,8 +1148,9 @@ fn create_zip_archive(
     output_path: &Path,
     items: &[ArchiveSourceItem],
     password: Option<&str>,
+    level: CompressionLevel,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     files_completed: &mut usize,
     bytes_processed: &mut u64,
@@ -596,13 +1158,20 @@ fn create_zip_archive(
     let file = File::create(output_path).map_err(BokslDirError::Io)?;
     let mut writer = ZipWriter::new(file);
 
-    let mut options = ZipFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut options = match level {
+        CompressionLevel::Store => {
+            ZipFileOptions::default().compression_method(CompressionMethod::Stored)
+        }
+        _ => ZipFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .compression_level(Some(level.deflate_level())),
+    };
     if let Some(pass) = password {
         options = options.with_aes_encryption(AesMode::Aes256, pass);
     }
 
     for item in items {
-        if should_cancel(cancel_flag) {
+        if should_cancel(control) {
             summary.cancelled = true;
             return Ok(());
         }
@@ -665,7 +1234,7 @@ fn create_tar_archive(
     output_path: &Path,
     items: &[ArchiveSourceItem],
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     files_completed: &mut usize,
     bytes_processed: &mut u64,
@@ -676,7 +1245,7 @@ fn create_tar_archive(
         &mut builder,
         items,
         progress_tx,
-        cancel_flag,
+        control,
         summary,
         files_completed,
         bytes_processed,
@@ -685,23 +1254,25 @@ fn create_tar_archive(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_tar_gz_archive(
     output_path: &Path,
     items: &[ArchiveSourceItem],
+    level: CompressionLevel,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     files_completed: &mut usize,
     bytes_processed: &mut u64,
 ) -> Result<()> {
     let file = File::create(output_path).map_err(BokslDirError::Io)?;
-    let encoder = GzEncoder::new(file, Compression::default());
+    let encoder = GzEncoder::new(file, Compression::new(level.deflate_level() as u32));
     let mut builder = TarBuilder::new(encoder);
     create_tar_like_archive(
         &mut builder,
         items,
         progress_tx,
-        cancel_flag,
+        control,
         summary,
         files_completed,
         bytes_processed,
@@ -711,23 +1282,29 @@ fn create_tar_gz_archive(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_tar_zst_archive(
     output_path: &Path,
     items: &[ArchiveSourceItem],
+    level: CompressionLevel,
+    threads: u32,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     files_completed: &mut usize,
     bytes_processed: &mut u64,
 ) -> Result<()> {
     let file = File::create(output_path).map_err(BokslDirError::Io)?;
-    let encoder = ZstdEncoder::new(file, 3).map_err(BokslDirError::Io)?;
+    let mut encoder = ZstdEncoder::new(file, level.zstd_level()).map_err(BokslDirError::Io)?;
+    if threads > 1 {
+        encoder.multithread(threads).map_err(BokslDirError::Io)?;
+    }
     let mut builder = TarBuilder::new(encoder);
     create_tar_like_archive(
         &mut builder,
         items,
         progress_tx,
-        cancel_flag,
+        control,
         summary,
         files_completed,
         bytes_processed,
@@ -741,13 +1318,13 @@ fn create_tar_like_archive<W: Write>(
     builder: &mut TarBuilder<W>,
     items: &[ArchiveSourceItem],
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     files_completed: &mut usize,
     bytes_processed: &mut u64,
 ) -> Result<()> {
     for item in items {
-        if should_cancel(cancel_flag) {
+        if should_cancel(control) {
             summary.cancelled = true;
             return Ok(());
         }
@@ -760,9 +1337,23 @@ fn create_tar_like_archive<W: Write>(
                 .map_err(|e| e.to_string())
         } else {
             (|| -> std::result::Result<u64, String> {
-                let mut src = File::open(&item.source_path).map_err(|e| e.to_string())?;
+                let src = File::open(&item.source_path).map_err(|e| e.to_string())?;
+                let mut header = tar::Header::new_gnu();
+                header.set_metadata(&src.metadata().map_err(|e| e.to_string())?);
+                let base = *bytes_processed;
+                let reader = ProgressReader::new(src, |read| {
+                    send_progress(
+                        progress_tx,
+                        name.clone(),
+                        *files_completed,
+                        summary.total_files,
+                        base.saturating_add(read),
+                        summary.total_bytes,
+                        summary,
+                    )
+                });
                 builder
-                    .append_file(name.clone(), &mut src)
+                    .append_data(&mut header, &name, reader)
                     .map_err(|e| e.to_string())?;
                 Ok(item.size)
             })()
@@ -798,25 +1389,40 @@ fn create_7z_archive(
     output_path: &Path,
     sources: &[PathBuf],
     password: Option<&str>,
+    level: CompressionLevel,
+    threads: u32,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     total_files: usize,
     total_bytes: u64,
 ) -> Result<()> {
-    if should_cancel(cancel_flag) {
+    if should_cancel(control) {
         summary.cancelled = true;
         return Ok(());
     }
 
     let staging = build_7z_staging_dir(sources)?;
-    let result = if let Some(pass) = password {
-        sevenz_rust2::compress_to_path_encrypted(&staging, output_path, SevenZPassword::from(pass))
-            .map_err(|e| e.to_string())
-    } else {
-        sevenz_rust2::compress_to_path(&staging, output_path).map_err(|e| e.to_string())
+    let mut progress = SevenZProgress {
+        tx: progress_tx,
+        control,
+        summary,
+        files_completed: 0,
+        bytes_processed: 0,
     };
+    let result = write_7z_archive(
+        &staging,
+        output_path,
+        password,
+        level,
+        threads,
+        Some(&mut progress),
+    )
+    .map_err(|e| e.to_string());
     let _ = fs::remove_dir_all(&staging);
+    if summary.cancelled {
+        return Ok(());
+    }
 
     match result {
         Ok(()) => {
@@ -843,6 +1449,131 @@ fn create_7z_archive(
     }
 }
 
+/// 7z 압축 중 진행 상태 (항목마다, 큰 파일은 읽는 도중에도 이벤트를 보냄)
+struct SevenZProgress<'a> {
+    tx: &'a Sender<ArchiveProgressEvent>,
+    control: &'a Arc<ArchiveControl>,
+    summary: &'a mut ArchiveSummary,
+    files_completed: usize,
+    bytes_processed: u64,
+}
+
+impl SevenZProgress<'_> {
+    fn send(&self, name: &str, bytes_processed: u64) {
+        send_progress(
+            self.tx,
+            name.to_string(),
+            self.files_completed,
+            self.summary.total_files,
+            bytes_processed,
+            self.summary.total_bytes,
+            self.summary,
+        );
+    }
+}
+
+/// 임시 디렉토리 내용을 7z로 압축 (`sevenz_rust2::compress_to_path`와 같은 구성, 압축 방식만 지정)
+///
+/// 취소하면 `progress.summary.cancelled`를 켜고 쓰다 만 채로 멈춥니다 (호출한 쪽에서 파일 정리).
+fn write_7z_archive(
+    staging: &Path,
+    output_path: &Path,
+    password: Option<&str>,
+    level: CompressionLevel,
+    threads: u32,
+    mut progress: Option<&mut SevenZProgress<'_>>,
+) -> std::result::Result<(), SevenZError> {
+    use sevenz_rust2::ArchiveWriter;
+
+    let mut writer = ArchiveWriter::create(output_path)?;
+    writer.set_content_methods(sevenz_content_methods(password, level, threads));
+    push_7z_path(staging, staging, &mut writer, &mut progress)?;
+    if progress.as_ref().is_some_and(|p| p.summary.cancelled) {
+        return Ok(());
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// 7z 데이터 압축 방식 (암호가 있으면 AES 뒤에 LZMA2/저장)
+fn sevenz_content_methods(
+    password: Option<&str>,
+    level: CompressionLevel,
+    threads: u32,
+) -> Vec<sevenz_rust2::EncoderConfiguration> {
+    use sevenz_rust2::encoder_options::{AesEncoderOptions, Lzma2Options};
+    use sevenz_rust2::{EncoderConfiguration, EncoderMethod};
+
+    let mut methods: Vec<EncoderConfiguration> = Vec::new();
+    if let Some(pass) = password.filter(|pass| !pass.is_empty()) {
+        methods.push(AesEncoderOptions::new(SevenZPassword::from(pass)).into());
+    }
+    let lzma_level = match level {
+        CompressionLevel::Store => None,
+        CompressionLevel::Fast => Some(1),
+        CompressionLevel::Normal => Some(6),
+        CompressionLevel::Max => Some(9),
+    };
+    methods.push(match lzma_level {
+        None => EncoderMethod::COPY.into(),
+        // 청크 크기 0은 사전 크기로 맞춰짐 (사전 크기 단위로 나눠 스레드마다 압축)
+        Some(lzma_level) if threads > 1 => {
+            Lzma2Options::from_level_mt(lzma_level, threads, 0).into()
+        }
+        Some(lzma_level) => Lzma2Options::from_level(lzma_level).into(),
+    });
+    methods
+}
+
+fn push_7z_path(
+    path: &Path,
+    root: &Path,
+    writer: &mut sevenz_rust2::ArchiveWriter<File>,
+    progress: &mut Option<&mut SevenZProgress<'_>>,
+) -> std::result::Result<(), SevenZError> {
+    if let Some(progress) = progress.as_deref_mut() {
+        if progress.summary.cancelled || should_cancel(progress.control) {
+            progress.summary.cancelled = true;
+            return Ok(());
+        }
+    }
+    let name = path
+        .strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string();
+    let entry = sevenz_rust2::ArchiveEntry::from_path(path, name.clone());
+    if path.is_dir() {
+        writer.push_archive_entry::<&[u8]>(entry, None)?;
+        for child in fs::read_dir(path)? {
+            let child = child?;
+            let file_type = child.file_type()?;
+            if file_type.is_dir() || file_type.is_file() {
+                push_7z_path(&child.path(), root, writer, progress)?;
+            }
+        }
+        return Ok(());
+    }
+
+    let file = File::open(path)?;
+    match progress.as_deref_mut() {
+        Some(progress) => {
+            let base = progress.bytes_processed;
+            let size = entry.size();
+            let reader =
+                ProgressReader::new(file, |read| progress.send(&name, base.saturating_add(read)));
+            writer.push_archive_entry(entry, Some(reader))?;
+            progress.files_completed += 1;
+            progress.bytes_processed = base.saturating_add(size);
+            progress.send(&name, progress.bytes_processed);
+        }
+        None => {
+            writer.push_archive_entry(entry, Some(file))?;
+        }
+    }
+    Ok(())
+}
+
 fn build_7z_staging_dir(sources: &[PathBuf]) -> Result<PathBuf> {
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -880,7 +1611,7 @@ fn copy_path_recursive(src: &Path, dest: &Path) -> Result<()> {
 fn extract_zip_archive(
     request: &ArchiveExtractRequest,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     total_files: usize,
     total_bytes: u64,
@@ -894,7 +1625,7 @@ fn extract_zip_archive(
     let mut files_completed = 0usize;
     let mut bytes_processed = 0u64;
     for idx in 0..archive.len() {
-        if should_cancel(cancel_flag) {
+        if should_cancel(control) {
             summary.cancelled = true;
             return Ok(());
         }
@@ -909,14 +1640,17 @@ fn extract_zip_archive(
                 .map_err(|e| map_zip_extract_error(&request.archive_path, e, password))?,
         };
 
-        let raw_path = PathBuf::from(entry.name());
-        let name = entry.name().to_string();
+        let name = request.name_encoding.decode(entry.name_raw());
+        let raw_path = PathBuf::from(&name);
+        if !is_requested_entry(&request.only_entries, &name) {
+            continue;
+        }
         let Some(dest_path) = sanitize_extract_path(&request.dest_dir, &raw_path) else {
             summary.items_processed += 1;
             summary.items_failed += 1;
             summary
                 .errors
-                .push(format!("{}: blocked unsafe path", entry.name()));
+                .push(format!("{}: blocked unsafe path", name));
             continue;
         };
 
@@ -982,7 +1716,24 @@ fn extract_zip_archive(
             }
             match File::create(&dest_path) {
                 Ok(mut out) => {
-                    if let Err(e) = io::copy(&mut entry, &mut out) {
+                    let mut report = |written: u64| {
+                        send_progress(
+                            progress_tx,
+                            name.clone(),
+                            files_completed,
+                            total_files,
+                            bytes_processed.saturating_add(written),
+                            total_bytes,
+                            summary,
+                        )
+                    };
+                    if let Err(e) = stream_copy(&mut entry, &mut out, control, &mut report) {
+                        drop(out);
+                        let _ = fs::remove_file(&dest_path);
+                        if control.is_cancelled() {
+                            summary.cancelled = true;
+                            return Ok(());
+                        }
                         summary.items_processed += 1;
                         summary.items_failed += 1;
                         summary.errors.push(format!("{}: {}", name, e));
@@ -1017,7 +1768,7 @@ fn extract_zip_archive(
 fn extract_tar_archive(
     request: &ArchiveExtractRequest,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     total_files: usize,
     total_bytes: u64,
@@ -1028,7 +1779,7 @@ fn extract_tar_archive(
         archive,
         request,
         progress_tx,
-        cancel_flag,
+        control,
         summary,
         total_files,
         total_bytes,
@@ -1038,7 +1789,7 @@ fn extract_tar_archive(
 fn extract_tar_gz_archive(
     request: &ArchiveExtractRequest,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     total_files: usize,
     total_bytes: u64,
@@ -1050,7 +1801,7 @@ fn extract_tar_gz_archive(
         archive,
         request,
         progress_tx,
-        cancel_flag,
+        control,
         summary,
         total_files,
         total_bytes,
@@ -1060,7 +1811,7 @@ fn extract_tar_gz_archive(
 fn extract_tar_zst_archive(
     request: &ArchiveExtractRequest,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     total_files: usize,
     total_bytes: u64,
@@ -1072,7 +1823,7 @@ fn extract_tar_zst_archive(
         archive,
         request,
         progress_tx,
-        cancel_flag,
+        control,
         summary,
         total_files,
         total_bytes,
@@ -1083,7 +1834,7 @@ fn extract_tar_like_archive<R: Read>(
     mut archive: TarArchive<R>,
     request: &ArchiveExtractRequest,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     total_files: usize,
     total_bytes: u64,
@@ -1091,7 +1842,7 @@ fn extract_tar_like_archive<R: Read>(
     let mut files_completed = 0usize;
     let mut bytes_processed = 0u64;
     for entry_result in archive.entries().map_err(BokslDirError::Io)? {
-        if should_cancel(cancel_flag) {
+        if should_cancel(control) {
             summary.cancelled = true;
             return Ok(());
         }
@@ -1116,6 +1867,9 @@ fn extract_tar_like_archive<R: Read>(
             }
         };
         let name = archive_display_path(&path_buf);
+        if !is_requested_entry(&request.only_entries, &name) {
+            continue;
+        }
         let is_dir = entry.header().entry_type().is_dir();
         let Some(dest_path) = sanitize_extract_path(&request.dest_dir, &path_buf) else {
             summary.items_processed += 1;
@@ -1180,8 +1934,32 @@ fn extract_tar_like_archive<R: Read>(
         }
 
         let size = entry.size();
-        match entry.unpack(&dest_path) {
-            Ok(_) => {
+        let result = if entry.header().entry_type().is_file() {
+            let mut report = |written: u64| {
+                send_progress(
+                    progress_tx,
+                    name.clone(),
+                    files_completed,
+                    total_files,
+                    bytes_processed.saturating_add(written),
+                    total_bytes,
+                    summary,
+                )
+            };
+            let result = unpack_tar_file(&mut entry, &dest_path, control, &mut report);
+            if result.is_err() {
+                let _ = fs::remove_file(&dest_path);
+                if control.is_cancelled() {
+                    summary.cancelled = true;
+                    return Ok(());
+                }
+            }
+            result
+        } else {
+            entry.unpack(&dest_path).map(|_| ())
+        };
+        match result {
+            Ok(()) => {
                 files_completed += 1;
                 bytes_processed = bytes_processed.saturating_add(size);
                 summary.items_processed += 1;
@@ -1208,12 +1986,12 @@ fn extract_tar_like_archive<R: Read>(
 fn extract_7z_archive(
     request: &ArchiveExtractRequest,
     progress_tx: &Sender<ArchiveProgressEvent>,
-    cancel_flag: &Arc<AtomicBool>,
+    control: &Arc<ArchiveControl>,
     summary: &mut ArchiveSummary,
     total_files: usize,
     total_bytes: u64,
 ) -> Result<()> {
-    if should_cancel(cancel_flag) {
+    if should_cancel(control) {
         summary.cancelled = true;
         return Ok(());
     }
@@ -1227,12 +2005,15 @@ fn extract_7z_archive(
                           reader: &mut dyn Read,
                           _output_path: &PathBuf|
      -> std::result::Result<bool, sevenz_rust2::Error> {
-        if should_cancel(cancel_flag) {
+        if should_cancel(control) {
             summary.cancelled = true;
             return Ok(false);
         }
 
         let entry_name = entry.name().to_string();
+        if !is_requested_entry(&request.only_entries, &entry_name) {
+            return Ok(true);
+        }
         let Some(safe_dest) = sanitize_extract_path(&dest_root, Path::new(&entry_name)) else {
             summary.items_processed += 1;
             summary.items_failed += 1;
@@ -1319,7 +2100,24 @@ fn extract_7z_archive(
         }
         match File::create(&safe_dest) {
             Ok(mut writer) => {
-                if let Err(e) = io::copy(reader, &mut writer) {
+                let mut report = |written: u64| {
+                    send_progress(
+                        progress_tx,
+                        entry_name.clone(),
+                        files_completed,
+                        total_files,
+                        bytes_processed.saturating_add(written),
+                        total_bytes,
+                        summary,
+                    )
+                };
+                if let Err(e) = stream_copy(reader, &mut writer, control, &mut report) {
+                    drop(writer);
+                    let _ = fs::remove_file(&safe_dest);
+                    if control.is_cancelled() {
+                        summary.cancelled = true;
+                        return Ok(false);
+                    }
                     summary.items_processed += 1;
                     summary.items_failed += 1;
                     summary.errors.push(format!("{}: {}", entry_name, e));
@@ -1368,7 +2166,11 @@ fn extract_7z_archive(
     Ok(())
 }
 
-fn list_zip_entries(path: &Path, password: Option<&str>) -> Result<Vec<ArchiveEntry>> {
+fn list_zip_entries(
+    path: &Path,
+    password: Option<&str>,
+    name_encoding: ZipNameEncoding,
+) -> Result<Vec<ArchiveEntry>> {
     let file = File::open(path).map_err(BokslDirError::Io)?;
     let mut archive = ZipArchive::new(file).map_err(|e| map_zip_list_error(path, e, password))?;
 
@@ -1383,7 +2185,7 @@ fn list_zip_entries(path: &Path, password: Option<&str>) -> Result<Vec<ArchiveEn
                 .map_err(|e| map_zip_list_error(path, e, password))?,
         };
         entries.push(ArchiveEntry {
-            path: entry.name().to_string(),
+            path: name_encoding.decode(entry.name_raw()),
             size: entry.size(),
             is_dir: entry.is_dir(),
         });
@@ -1482,8 +2284,8 @@ mod tests {
         tx
     }
 
-    fn cancel_flag() -> Arc<AtomicBool> {
-        Arc::new(AtomicBool::new(false))
+    fn control() -> Arc<ArchiveControl> {
+        Arc::new(ArchiveControl::default())
     }
 
     fn prepare_sample_sources(base: &Path) -> (PathBuf, PathBuf) {
@@ -1535,6 +2337,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_archive_format_sniffs_extensionless_files() {
+        let temp = tempdir().unwrap();
+        let zip_path = temp.path().join("bundle");
+        let mut writer = ZipWriter::new(File::create(&zip_path).unwrap());
+        writer
+            .start_file("a.txt", ZipFileOptions::default())
+            .unwrap();
+        writer.write_all(b"a").unwrap();
+        writer.finish().unwrap();
+        assert_eq!(detect_archive_format(&zip_path), Some(ArchiveFormat::Zip));
+
+        // 확장자가 있으면 내용이 ZIP이어도 확장자로만 판단
+        let docx = temp.path().join("report.docx");
+        fs::copy(&zip_path, &docx).unwrap();
+        assert_eq!(detect_archive_format(&docx), None);
+
+        let text = temp.path().join("README");
+        fs::write(&text, b"plain").unwrap();
+        assert_eq!(detect_archive_format(&text), None);
+    }
+
     #[test]
     fn test_supports_password() {
         assert!(supports_password(ArchiveFormat::Zip));
@@ -1551,6 +2375,480 @@ mod tests {
         assert!(sanitize_extract_path(&root, Path::new("/abs/path")).is_none());
     }
 
+    #[test]
+    fn test_extract_only_selected_entries_keeps_archive_paths() {
+        let temp = tempdir().expect("create tempdir");
+        let src_dir = temp.path().join("src");
+        fs::create_dir_all(&src_dir).expect("create src dir");
+        let (file_path, nested_dir) = prepare_sample_sources(&src_dir);
+        fs::write(nested_dir.join("gamma.txt"), b"gamma").expect("write nested file");
+
+        for name in ["picked.zip", "picked.tar.gz"] {
+            let archive_path = temp.path().join(name);
+            create_archive(
+                &ArchiveCreateRequest {
+                    sources: vec![file_path.clone(), nested_dir.clone()],
+                    output_path: archive_path.clone(),
+                    password: None,
+                    level: CompressionLevel::default(),
+                    threads: 1,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("create archive");
+
+            let dest = temp.path().join(format!("dest-{}", name));
+            fs::create_dir_all(&dest).expect("create destination dir");
+            fs::write(dest.join("alpha.txt"), b"existing").expect("create existing");
+            let only_entries = vec!["nested/gamma.txt".to_string()];
+            // 고르지 않은 항목은 충돌로 세지 않음
+            assert!(list_extract_conflicts(
+                &archive_path,
+                &dest,
+                None,
+                &only_entries,
+                ZipNameEncoding::Auto
+            )
+            .expect("list conflicts")
+            .is_empty());
+
+            let summary = extract_archive(
+                &ArchiveExtractRequest {
+                    archive_path,
+                    dest_dir: dest.clone(),
+                    password: None,
+                    overwrite_existing: false,
+                    overwrite_entries: Vec::new(),
+                    skip_existing_entries: Vec::new(),
+                    skip_all_existing: false,
+                    only_entries,
+                    name_encoding: ZipNameEncoding::Auto,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("extract selected entries");
+            assert_eq!(summary.total_files, 1, "{}", name);
+            assert_eq!(summary.items_failed, 0, "{}: {:?}", name, summary.errors);
+            assert!(dest.join("nested").join("gamma.txt").exists());
+            assert!(!dest.join("nested").join("beta.txt").exists());
+            assert_eq!(fs::read(dest.join("alpha.txt")).unwrap(), b"existing");
+        }
+        assert!(is_requested_entry(
+            &["nested".to_string()],
+            "nested/beta.txt"
+        ));
+        assert!(!is_requested_entry(
+            &["nest".to_string()],
+            "nested/beta.txt"
+        ));
+    }
+
+    #[test]
+    fn test_create_archive_honors_compression_level() {
+        let temp = tempdir().expect("create tempdir");
+        let src_dir = temp.path().join("src");
+        fs::create_dir_all(&src_dir).expect("create src dir");
+        let (file_path, _) = prepare_sample_sources(&src_dir);
+        fs::write(&file_path, "alpha ".repeat(2000)).expect("write compressible file");
+
+        let mut sizes = Vec::new();
+        for (name, level) in [
+            ("store.zip", CompressionLevel::Store),
+            ("max.zip", CompressionLevel::Max),
+            ("store.7z", CompressionLevel::Store),
+            ("max.tar.zst", CompressionLevel::Max),
+            ("store.tar.gz", CompressionLevel::Store),
+        ] {
+            let archive_path = temp.path().join(name);
+            create_archive(
+                &ArchiveCreateRequest {
+                    sources: vec![file_path.clone()],
+                    output_path: archive_path.clone(),
+                    password: None,
+                    level,
+                    threads: 2,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("create archive");
+            let dest = temp.path().join(format!("out-{}", name));
+            fs::create_dir_all(&dest).expect("create destination dir");
+            extract_archive(
+                &ArchiveExtractRequest {
+                    archive_path: archive_path.clone(),
+                    dest_dir: dest.clone(),
+                    password: None,
+                    overwrite_existing: false,
+                    overwrite_entries: Vec::new(),
+                    skip_existing_entries: Vec::new(),
+                    skip_all_existing: false,
+                    only_entries: Vec::new(),
+                    name_encoding: ZipNameEncoding::Auto,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("extract archive");
+            assert_eq!(
+                fs::read(dest.join("alpha.txt")).unwrap(),
+                fs::read(&file_path).unwrap(),
+                "{}",
+                name
+            );
+            sizes.push(fs::metadata(&archive_path).unwrap().len());
+        }
+        // store는 원본 크기(12000바이트)보다 작아지지 않음
+        assert!(sizes[0] > 12_000 && sizes[1] < 1_000, "{:?}", sizes);
+        assert!(sizes[2] > 12_000, "{:?}", sizes);
+
+        let mut zip = ZipArchive::new(File::open(temp.path().join("store.zip")).unwrap()).unwrap();
+        assert_eq!(
+            zip.by_index(0).unwrap().compression(),
+            CompressionMethod::Stored
+        );
+        assert_eq!(CompressionLevel::Max.cycle(true), CompressionLevel::Store);
+        assert_eq!(CompressionLevel::Store.cycle(false), CompressionLevel::Max);
+    }
+
+    #[test]
+    fn test_multithreaded_create_reports_progress_within_large_file() {
+        let temp = tempdir().expect("create tempdir");
+        let big = temp.path().join("big.bin");
+        let data: Vec<u8> = (0..3 * 1024 * 1024u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&big, &data).expect("write large file");
+
+        for name in ["big.7z", "big.tar.zst"] {
+            let archive_path = temp.path().join(name);
+            let (tx, rx) = mpsc::channel();
+            create_archive(
+                &ArchiveCreateRequest {
+                    sources: vec![big.clone()],
+                    output_path: archive_path.clone(),
+                    password: None,
+                    level: CompressionLevel::Fast,
+                    threads: 4,
+                },
+                tx,
+                control(),
+            )
+            .expect("create archive");
+            // 파일 하나를 읽는 도중에도 처리량 계산용 진행 이벤트가 옴
+            let partial = rx
+                .try_iter()
+                .filter(|event| {
+                    event.bytes_processed > 0 && event.bytes_processed < event.total_bytes
+                })
+                .count();
+            assert!(partial >= 2, "{}: {} partial events", name, partial);
+
+            let dest = temp.path().join(format!("out-{}", name));
+            fs::create_dir_all(&dest).expect("create destination dir");
+            extract_archive(
+                &ArchiveExtractRequest {
+                    archive_path,
+                    dest_dir: dest.clone(),
+                    password: None,
+                    overwrite_existing: false,
+                    overwrite_entries: Vec::new(),
+                    skip_existing_entries: Vec::new(),
+                    skip_all_existing: false,
+                    only_entries: Vec::new(),
+                    name_encoding: ZipNameEncoding::Auto,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("extract archive");
+            assert_eq!(fs::read(dest.join("big.bin")).unwrap(), data, "{}", name);
+        }
+        assert!(resolve_compression_threads(0) >= 1);
+        assert_eq!(resolve_compression_threads(3), 3);
+    }
+
+    #[test]
+    fn test_stream_copy_reads_through_fixed_buffer() {
+        struct Probe<R> {
+            inner: R,
+            largest_read: usize,
+        }
+        impl<R: Read> Read for Probe<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.largest_read = self.largest_read.max(buf.len());
+                self.inner.read(buf)
+            }
+        }
+
+        let total = 64 * 1024 * 1024u64;
+        let mut reader = Probe {
+            inner: io::repeat(7).take(total),
+            largest_read: 0,
+        };
+        let mut reports = Vec::new();
+        let written = stream_copy(&mut reader, &mut io::sink(), &control(), &mut |n| {
+            reports.push(n)
+        })
+        .expect("stream copy");
+        assert_eq!(written, total);
+        assert_eq!(reader.largest_read, STREAM_BUFFER_SIZE);
+        assert_eq!(reports.len(), (total / PROGRESS_REPORT_BYTES) as usize);
+
+        // 취소되면 다음 보고 시점에 멈춤
+        let cancelled = control();
+        cancelled.cancel();
+        let result = stream_copy(
+            &mut io::repeat(0).take(total),
+            &mut io::sink(),
+            &cancelled,
+            &mut |_| {},
+        );
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Interrupted);
+    }
+
+    #[test]
+    fn test_extract_streams_large_entry_with_byte_progress() {
+        let temp = tempdir().expect("create tempdir");
+        let big = temp.path().join("huge.bin");
+        let data: Vec<u8> = (0..8 * 1024 * 1024u32).map(|i| (i % 253) as u8).collect();
+        fs::write(&big, &data).expect("write large file");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&big, fs::Permissions::from_mode(0o640)).unwrap();
+        }
+
+        for name in ["huge.zip", "huge.tar.zst", "huge.7z"] {
+            let archive_path = temp.path().join(name);
+            create_archive(
+                &ArchiveCreateRequest {
+                    sources: vec![big.clone()],
+                    output_path: archive_path.clone(),
+                    password: None,
+                    level: CompressionLevel::Fast,
+                    threads: 1,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("create archive");
+
+            let dest = temp.path().join(format!("out-{}", name));
+            fs::create_dir_all(&dest).expect("create destination dir");
+            let (tx, rx) = mpsc::channel();
+            extract_archive(
+                &ArchiveExtractRequest {
+                    archive_path,
+                    dest_dir: dest.clone(),
+                    password: None,
+                    overwrite_existing: false,
+                    overwrite_entries: Vec::new(),
+                    skip_existing_entries: Vec::new(),
+                    skip_all_existing: false,
+                    only_entries: Vec::new(),
+                    name_encoding: ZipNameEncoding::Auto,
+                },
+                tx,
+                control(),
+            )
+            .expect("extract archive");
+            // 항목 하나를 푸는 도중에도 바이트 진행이 옴
+            let partial = rx
+                .try_iter()
+                .filter(|event| event.files_completed == 0 && event.bytes_processed > 0)
+                .count();
+            assert!(partial >= 4, "{}: {} partial events", name, partial);
+            let out = dest.join("huge.bin");
+            assert!(fs::read(&out).unwrap() == data, "{}", name);
+            #[cfg(unix)]
+            if name.ends_with(".tar.zst") {
+                use std::os::unix::fs::PermissionsExt;
+                let mode = fs::metadata(&out).unwrap().permissions().mode();
+                assert_eq!(mode & 0o777, 0o640);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zip_legacy_cp949_names_list_extract_and_delete() {
+        let temp = tempdir().expect("create tempdir");
+        let archive_path = temp.path().join("legacy.zip");
+        // UTF-8 표시 없이 CP949 이름을 쓰는 옛 압축 프로그램처럼, ASCII 이름을 같은 길이의 CP949로 바꿈
+        let mut writer = ZipWriter::new(File::create(&archive_path).unwrap());
+        let options = ZipFileOptions::default().compression_method(CompressionMethod::Stored);
+        writer.start_file("XXXX/YYYY.txt", options).unwrap();
+        writer.write_all(b"korean").unwrap();
+        writer.start_file("keep.txt", options).unwrap();
+        writer.write_all(b"ascii").unwrap();
+        writer.finish().unwrap();
+        let bytes = fs::read(&archive_path).unwrap();
+        let cp949_dir = [0xB9, 0xAE, 0xBC, 0xAD]; // 문서
+        let cp949_file = [0xC7, 0xD1, 0xB1, 0xDB]; // 한글
+        let mut patched = Vec::with_capacity(bytes.len());
+        let mut idx = 0;
+        while idx < bytes.len() {
+            match bytes.get(idx..idx + 4) {
+                Some(b"XXXX") => patched.extend_from_slice(&cp949_dir),
+                Some(b"YYYY") => patched.extend_from_slice(&cp949_file),
+                _ => {
+                    patched.push(bytes[idx]);
+                    idx += 1;
+                    continue;
+                }
+            }
+            idx += 4;
+        }
+        fs::write(&archive_path, patched).unwrap();
+
+        let names = |encoding| -> Vec<String> {
+            list_entries(&archive_path, None, encoding)
+                .expect("list zip entries")
+                .into_iter()
+                .map(|entry| entry.path)
+                .collect()
+        };
+        assert_eq!(names(ZipNameEncoding::Auto), ["문서/한글.txt", "keep.txt"]);
+        assert_eq!(names(ZipNameEncoding::Cp437), ["╣«╝¡/╟╤▒█.txt", "keep.txt"]);
+        assert_eq!(
+            zip_name_sample(&archive_path).map(|raw| ZipNameEncoding::Cp949.decode(&raw)),
+            Some("문서/한글.txt".to_string())
+        );
+
+        let dest = temp.path().join("out");
+        fs::create_dir_all(&dest).unwrap();
+        let request = ArchiveExtractRequest {
+            archive_path: archive_path.clone(),
+            dest_dir: dest.clone(),
+            password: None,
+            overwrite_existing: false,
+            overwrite_entries: Vec::new(),
+            skip_existing_entries: Vec::new(),
+            skip_all_existing: false,
+            only_entries: vec!["문서".to_string()],
+            name_encoding: ZipNameEncoding::Cp949,
+        };
+        extract_archive(&request, progress_tx(), control()).expect("extract zip");
+        assert_eq!(fs::read(dest.join("문서/한글.txt")).unwrap(), b"korean");
+        assert!(!dest.join("keep.txt").exists());
+        assert_eq!(
+            list_extract_conflicts(&archive_path, &dest, None, &[], ZipNameEncoding::Auto).unwrap(),
+            ["문서/한글.txt"]
+        );
+
+        delete_entries(
+            &ArchiveDeleteRequest {
+                archive_path: archive_path.clone(),
+                entries: vec!["문서/한글.txt".to_string()],
+                password: None,
+                name_encoding: ZipNameEncoding::Auto,
+            },
+            progress_tx(),
+            control(),
+        )
+        .expect("delete entry");
+        assert_eq!(names(ZipNameEncoding::Auto), ["keep.txt"]);
+    }
+
+    #[test]
+    fn test_delete_entries_rewrites_zip_and_7z() {
+        let temp = tempdir().expect("create tempdir");
+        let src_dir = temp.path().join("src");
+        fs::create_dir_all(&src_dir).expect("create src dir");
+        let (file_path, nested_dir) = prepare_sample_sources(&src_dir);
+
+        for name in ["trim.zip", "trim.7z"] {
+            let archive_path = temp.path().join(name);
+            create_archive(
+                &ArchiveCreateRequest {
+                    sources: vec![file_path.clone(), nested_dir.clone()],
+                    output_path: archive_path.clone(),
+                    password: None,
+                    level: CompressionLevel::default(),
+                    threads: 1,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("create archive");
+
+            let summary = delete_entries(
+                &ArchiveDeleteRequest {
+                    archive_path: archive_path.clone(),
+                    entries: vec!["nested".to_string()],
+                    password: None,
+                    name_encoding: ZipNameEncoding::Auto,
+                },
+                progress_tx(),
+                control(),
+            )
+            .expect("delete entries");
+            assert!(summary.errors.is_empty(), "{}: {:?}", name, summary.errors);
+            let remaining: Vec<String> = list_entries(&archive_path, None, ZipNameEncoding::Auto)
+                .expect("list rewritten archive")
+                .into_iter()
+                // 7z는 루트 디렉토리를 빈 이름 항목으로 기록
+                .filter(|entry| !entry.path.is_empty())
+                .map(|entry| entry.path)
+                .collect();
+            assert_eq!(remaining, vec!["alpha.txt".to_string()], "{}", name);
+            assert!(fs::read_dir(temp.path()).unwrap().all(|entry| !entry
+                .unwrap()
+                .file_name()
+                .to_string_lossy()
+                .contains("delete")));
+        }
+
+        // 7z: 풀 수 없는 이름도 빠뜨리지 않고, 남긴 항목의 시각을 유지
+        let archive_path = temp.path().join("names.7z");
+        let modified = sevenz_rust2::NtTime::from(126_000_000_000_000_000u64);
+        let mut writer = sevenz_rust2::ArchiveWriter::create(&archive_path).unwrap();
+        for name in ["../outside.txt", "keep.txt", "drop.txt"] {
+            let mut entry = sevenz_rust2::ArchiveEntry::new_file(name);
+            entry.has_last_modified_date = true;
+            entry.last_modified_date = modified;
+            writer
+                .push_archive_entry(entry, Some(name.as_bytes()))
+                .unwrap();
+        }
+        writer.finish().unwrap();
+        delete_entries(
+            &ArchiveDeleteRequest {
+                archive_path: archive_path.clone(),
+                entries: vec!["drop.txt".to_string()],
+                password: None,
+                name_encoding: ZipNameEncoding::Auto,
+            },
+            progress_tx(),
+            control(),
+        )
+        .expect("delete 7z entry");
+        let reader = sevenz_rust2::ArchiveReader::new(
+            File::open(&archive_path).unwrap(),
+            SevenZPassword::empty(),
+        )
+        .unwrap();
+        let kept: Vec<(String, u64)> = reader
+            .archive()
+            .files
+            .iter()
+            .map(|entry| (entry.name.clone(), entry.last_modified_date.into()))
+            .collect();
+        assert_eq!(
+            kept,
+            vec![
+                ("../outside.txt".to_string(), modified.into()),
+                ("keep.txt".to_string(), modified.into()),
+            ]
+        );
+
+        let tar_path = temp.path().join("keep.tar");
+        fs::write(&tar_path, b"").unwrap();
+        assert!(!supports_entry_delete(
+            detect_archive_format(&tar_path).unwrap()
+        ));
+    }
+
     #[test]
     fn test_zip_create_extract_and_list_roundtrip() {
         let temp = tempdir().expect("create tempdir");
@@ -1563,13 +2861,16 @@ mod tests {
             sources: vec![file_path.clone(), nested_dir.clone()],
             output_path: archive_path.clone(),
             password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
         };
-        let create_summary = create_archive(&create_request, progress_tx(), cancel_flag())
-            .expect("create zip archive");
+        let create_summary =
+            create_archive(&create_request, progress_tx(), control()).expect("create zip archive");
         assert_eq!(create_summary.items_failed, 0);
         assert!(archive_path.exists());
 
-        let entries = list_entries(&archive_path, None).expect("list zip entries");
+        let entries =
+            list_entries(&archive_path, None, ZipNameEncoding::Auto).expect("list zip entries");
         assert!(entries.iter().any(|e| e.path == "alpha.txt"));
         assert!(entries.iter().any(|e| e.path == "nested/beta.txt"));
 
@@ -1583,14 +2884,71 @@ mod tests {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding: ZipNameEncoding::Auto,
         };
-        let extract_summary = extract_archive(&extract_request, progress_tx(), cancel_flag())
+        let extract_summary = extract_archive(&extract_request, progress_tx(), control())
             .expect("extract zip archive");
         assert_eq!(extract_summary.items_failed, 0);
         assert!(dest.join("alpha.txt").exists());
         assert!(dest.join("nested").join("beta.txt").exists());
     }
 
+    #[test]
+    fn test_cancelled_archive_create_removes_partial_output() {
+        let temp = tempdir().expect("create tempdir");
+        let (file_path, nested_dir) = prepare_sample_sources(temp.path());
+        let request = ArchiveCreateRequest {
+            sources: vec![file_path, nested_dir],
+            output_path: temp.path().join("cancelled.zip"),
+            password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
+        };
+
+        let control = control();
+        control.cancel();
+        let summary = create_archive(&request, progress_tx(), control).expect("create zip");
+        assert!(summary.cancelled);
+        assert!(!request.output_path.exists());
+    }
+
+    #[test]
+    fn test_paused_archive_waits_until_resumed() {
+        let temp = tempdir().expect("create tempdir");
+        let src_dir = temp.path().join("src");
+        fs::create_dir_all(&src_dir).expect("create src dir");
+        let (file_path, nested_dir) = prepare_sample_sources(&src_dir);
+        let request = ArchiveCreateRequest {
+            sources: vec![file_path, nested_dir],
+            output_path: temp.path().join("paused.zip"),
+            password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
+        };
+
+        let control = control();
+        control.set_paused(true);
+        let worker_control = Arc::clone(&control);
+        let handle = thread::spawn(move || create_archive(&request, progress_tx(), worker_control));
+
+        thread::sleep(Duration::from_millis(150));
+        assert!(!handle.is_finished(), "paused worker should wait");
+
+        control.set_paused(false);
+        let summary = handle.join().unwrap().expect("create after resume");
+        assert!(!summary.cancelled);
+        assert_eq!(summary.items_failed, 0);
+    }
+
+    #[test]
+    fn test_cancel_while_paused_stops_archive() {
+        let control = control();
+        control.set_paused(true);
+        control.cancel();
+        assert!(should_cancel(&control));
+    }
+
     #[test]
     fn test_zip_password_list_and_extract_errors() {
         let temp = tempdir().expect("create tempdir");
@@ -1602,23 +2960,29 @@ mod tests {
             sources: vec![src_file],
             output_path: archive_path.clone(),
             password: Some("correct-password".to_string()),
+            level: CompressionLevel::default(),
+            threads: 1,
         };
-        create_archive(&create_request, progress_tx(), cancel_flag())
-            .expect("create encrypted zip");
+        create_archive(&create_request, progress_tx(), control()).expect("create encrypted zip");
 
-        let no_password = list_entries(&archive_path, None);
+        let no_password = list_entries(&archive_path, None, ZipNameEncoding::Auto);
         assert!(matches!(
             no_password,
             Err(BokslDirError::ArchivePasswordRequired { .. })
         ));
 
-        let wrong_password = list_entries(&archive_path, Some("wrong-password"));
+        let wrong_password =
+            list_entries(&archive_path, Some("wrong-password"), ZipNameEncoding::Auto);
         assert!(matches!(
             wrong_password,
             Err(BokslDirError::ArchiveInvalidPassword { .. })
         ));
 
-        let with_password = list_entries(&archive_path, Some("correct-password"));
+        let with_password = list_entries(
+            &archive_path,
+            Some("correct-password"),
+            ZipNameEncoding::Auto,
+        );
         assert!(with_password.is_ok());
 
         let wrong_dest = temp.path().join("wrong");
@@ -1631,8 +2995,10 @@ mod tests {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding: ZipNameEncoding::Auto,
         };
-        let wrong_extract_result = extract_archive(&wrong_extract, progress_tx(), cancel_flag());
+        let wrong_extract_result = extract_archive(&wrong_extract, progress_tx(), control());
         assert!(matches!(
             wrong_extract_result,
             Err(BokslDirError::ArchiveInvalidPassword { .. })
@@ -1648,8 +3014,10 @@ mod tests {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding: ZipNameEncoding::Auto,
         };
-        let ok_extract_result = extract_archive(&ok_extract, progress_tx(), cancel_flag());
+        let ok_extract_result = extract_archive(&ok_extract, progress_tx(), control());
         assert!(ok_extract_result.is_ok());
         assert!(ok_dest.join("secret.txt").exists());
     }
@@ -1683,8 +3051,10 @@ mod tests {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding: ZipNameEncoding::Auto,
         };
-        let summary = extract_archive(&request, progress_tx(), cancel_flag()).expect("extract zip");
+        let summary = extract_archive(&request, progress_tx(), control()).expect("extract zip");
 
         assert!(summary.items_failed >= 2);
         assert!(summary
@@ -1712,15 +3082,18 @@ mod tests {
             sources: vec![src.clone()],
             output_path: archive_path.clone(),
             password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
         };
-        create_archive(&create_request, progress_tx(), cancel_flag()).expect("create zip");
+        create_archive(&create_request, progress_tx(), control()).expect("create zip");
 
         let dest = temp.path().join("dest");
         fs::create_dir_all(&dest).expect("create dest");
         fs::write(dest.join("sample.txt"), b"existing").expect("create existing");
 
         let conflicts =
-            list_extract_conflicts(&archive_path, &dest, None).expect("list extract conflicts");
+            list_extract_conflicts(&archive_path, &dest, None, &[], ZipNameEncoding::Auto)
+                .expect("list extract conflicts");
         assert_eq!(conflicts, vec!["sample.txt".to_string()]);
     }
 
@@ -1734,8 +3107,10 @@ mod tests {
             sources: vec![src.clone()],
             output_path: archive_path.clone(),
             password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
         };
-        create_archive(&create_request, progress_tx(), cancel_flag()).expect("create zip");
+        create_archive(&create_request, progress_tx(), control()).expect("create zip");
 
         let dest = temp.path().join("dest");
         fs::create_dir_all(&dest).expect("create dest");
@@ -1749,8 +3124,10 @@ mod tests {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding: ZipNameEncoding::Auto,
         };
-        let summary = extract_archive(&request, progress_tx(), cancel_flag()).expect("extract zip");
+        let summary = extract_archive(&request, progress_tx(), control()).expect("extract zip");
         assert_eq!(summary.items_failed, 0);
         assert_eq!(
             fs::read(dest.join("sample.txt")).expect("read extracted file"),
@@ -1770,10 +3147,12 @@ mod tests {
             sources: vec![file_path, nested_dir],
             output_path: archive_path.clone(),
             password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
         };
-        create_archive(&create_request, progress_tx(), cancel_flag()).expect("create tar.zst");
+        create_archive(&create_request, progress_tx(), control()).expect("create tar.zst");
 
-        let list = list_entries(&archive_path, None).expect("list tar.zst");
+        let list = list_entries(&archive_path, None, ZipNameEncoding::Auto).expect("list tar.zst");
         assert!(list.iter().any(|e| e.path == "alpha.txt"));
         assert!(list.iter().any(|e| e.path == "nested/beta.txt"));
 
@@ -1787,9 +3166,11 @@ mod tests {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding: ZipNameEncoding::Auto,
         };
-        let summary = extract_archive(&extract_request, progress_tx(), cancel_flag())
-            .expect("extract tar.zst");
+        let summary =
+            extract_archive(&extract_request, progress_tx(), control()).expect("extract tar.zst");
         assert_eq!(summary.items_failed, 0);
         assert!(dest.join("alpha.txt").exists());
         assert!(dest.join("nested").join("beta.txt").exists());
@@ -1806,8 +3187,10 @@ mod tests {
             sources: vec![src],
             output_path: archive_path.clone(),
             password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
         };
-        create_archive(&create_request, progress_tx(), cancel_flag()).expect("create 7z");
+        create_archive(&create_request, progress_tx(), control()).expect("create 7z");
 
         let dest = temp.path().join("dest");
         fs::create_dir_all(&dest).expect("create destination dir");
@@ -1819,9 +3202,11 @@ mod tests {
             overwrite_entries: Vec::new(),
             skip_existing_entries: Vec::new(),
             skip_all_existing: false,
+            only_entries: Vec::new(),
+            name_encoding: ZipNameEncoding::Auto,
         };
         let summary =
-            extract_archive(&extract_request, progress_tx(), cancel_flag()).expect("extract 7z");
+            extract_archive(&extract_request, progress_tx(), control()).expect("extract 7z");
         assert_eq!(summary.items_failed, 0);
         assert!(dest.join("plain.txt").exists());
     }
@@ -1838,9 +3223,11 @@ mod tests {
             sources: vec![src],
             output_path: archive_path.clone(),
             password: None,
+            level: CompressionLevel::default(),
+            threads: 1,
         };
 
-        let result = create_archive(&create_request, progress_tx(), cancel_flag());
+        let result = create_archive(&create_request, progress_tx(), control());
         assert!(matches!(
             result,
             Err(BokslDirError::ArchiveCreateFailed { path, .. }) if path == archive_path
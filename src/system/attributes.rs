@@ -0,0 +1,282 @@
+//! 파일 속성 (읽기 전용 / 숨김 / 시스템) 및 확장 속성(xattr)
+//!
+//! Windows는 `FILE_ATTRIBUTE_*` 플래그를 직접 바꾸고, Unix는 쓰기 권한 비트와
+//! 이름 앞의 `.` 규칙(숨김 = 이름 변경)을 사용합니다. 시스템 속성은 Windows 전용,
+//! 확장 속성은 Unix 전용입니다.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// 속성 다이얼로그에 표시/전환할 파일 속성
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FileAttributes {
+    pub readonly: bool,
+    pub hidden: bool,
+    /// 시스템 속성 (지원하지 않는 플랫폼이면 None)
+    pub system: Option<bool>,
+}
+
+impl FileAttributes {
+    pub fn get(&self, attribute: Attribute) -> Option<bool> {
+        match attribute {
+            Attribute::ReadOnly => Some(self.readonly),
+            Attribute::Hidden => Some(self.hidden),
+            Attribute::System => self.system,
+        }
+    }
+}
+
+/// 전환 가능한 속성 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Attribute {
+    ReadOnly,
+    Hidden,
+    System,
+}
+
+/// 경로의 현재 속성 조회 (심볼릭 링크는 따라감)
+pub fn read_attributes(path: &Path) -> io::Result<FileAttributes> {
+    let metadata = fs::metadata(path)?;
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        let flags = metadata.file_attributes();
+        Ok(FileAttributes {
+            readonly: flags & windows::FILE_ATTRIBUTE_READONLY != 0,
+            hidden: flags & windows::FILE_ATTRIBUTE_HIDDEN != 0,
+            system: Some(flags & windows::FILE_ATTRIBUTE_SYSTEM != 0),
+        })
+    }
+    #[cfg(not(windows))]
+    {
+        Ok(FileAttributes {
+            readonly: metadata.permissions().readonly(),
+            hidden: is_dot_name(path),
+            system: None,
+        })
+    }
+}
+
+/// 속성 설정. 적용 후 경로를 반환 (Unix 숨김 전환은 이름이 바뀜)
+pub fn set_attribute(path: &Path, attribute: Attribute, enabled: bool) -> io::Result<PathBuf> {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        let flag = match attribute {
+            Attribute::ReadOnly => windows::FILE_ATTRIBUTE_READONLY,
+            Attribute::Hidden => windows::FILE_ATTRIBUTE_HIDDEN,
+            Attribute::System => windows::FILE_ATTRIBUTE_SYSTEM,
+        };
+        let flags = fs::metadata(path)?.file_attributes();
+        let flags = if enabled { flags | flag } else { flags & !flag };
+        windows::set_file_attributes(path, flags)?;
+        Ok(path.to_path_buf())
+    }
+    #[cfg(not(windows))]
+    {
+        match attribute {
+            Attribute::ReadOnly => {
+                set_unix_readonly(path, enabled)?;
+                Ok(path.to_path_buf())
+            }
+            Attribute::Hidden => set_dot_name(path, enabled),
+            Attribute::System => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "System attribute is only available on Windows",
+            )),
+        }
+    }
+}
+
+/// macOS 격리(인터넷에서 받은 파일) 확장 속성 이름
+pub const QUARANTINE_XATTR: &str = "com.apple.quarantine";
+
+/// 확장 속성 (이름, 값) 목록 (이름순). 지원하지 않는 파일시스템/플랫폼이면 빈 목록
+pub fn list_xattrs(path: &Path) -> io::Result<Vec<(String, Vec<u8>)>> {
+    #[cfg(unix)]
+    {
+        let names = match xattr::list(path) {
+            Ok(names) => names,
+            Err(e) if e.kind() == io::ErrorKind::Unsupported => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut attrs = Vec::new();
+        for name in names {
+            // 목록 조회 후 사라진 속성은 건너뜀
+            if let Some(value) = xattr::get(path, &name)? {
+                attrs.push((name.to_string_lossy().into_owned(), value));
+            }
+        }
+        attrs.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(attrs)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        Ok(Vec::new())
+    }
+}
+
+/// 확장 속성 하나 제거
+pub fn remove_xattr(path: &Path, name: &str) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        xattr::remove(path, name)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (path, name);
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Extended attributes are not supported on this platform",
+        ))
+    }
+}
+
+/// 확장 속성 값 표시용 문자열 (출력 가능한 UTF-8이면 그대로, 아니면 16진수, 최대 `max_chars`자)
+pub fn format_xattr_value(value: &[u8], max_chars: usize) -> String {
+    let text = match std::str::from_utf8(value) {
+        Ok(text) if !text.chars().any(char::is_control) => text.to_string(),
+        _ => value.iter().map(|byte| format!("{:02x}", byte)).collect(),
+    };
+    if text.chars().count() <= max_chars {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(not(windows))]
+fn is_dot_name(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.') && name != "." && name != "..")
+}
+
+/// 읽기 전용이면 모든 쓰기 비트 제거, 해제하면 소유자 쓰기 비트만 추가
+#[cfg(unix)]
+fn set_unix_readonly(path: &Path, readonly: bool) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = fs::metadata(path)?.permissions();
+    let mode = permissions.mode();
+    permissions.set_mode(if readonly {
+        mode & !0o222
+    } else {
+        mode | 0o200
+    });
+    fs::set_permissions(path, permissions)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn set_unix_readonly(path: &Path, readonly: bool) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    #[allow(clippy::permissions_set_readonly_false)]
+    permissions.set_readonly(readonly);
+    fs::set_permissions(path, permissions)
+}
+
+/// 이름 앞 `.`을 붙이거나 떼서 숨김 전환 (대상 이름이 있으면 AlreadyExists)
+#[cfg(not(windows))]
+fn set_dot_name(path: &Path, hidden: bool) -> io::Result<PathBuf> {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid file name"))?;
+    let new_name = match (hidden, name.strip_prefix('.')) {
+        (true, None) => format!(".{}", name),
+        (false, Some(rest)) if !rest.is_empty() => rest.to_string(),
+        _ => return Ok(path.to_path_buf()),
+    };
+    let target = path.with_file_name(new_name);
+    if target.symlink_metadata().is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Target already exists: {}", target.display()),
+        ));
+    }
+    fs::rename(path, &target)?;
+    Ok(target)
+}
+
+#[cfg(windows)]
+mod windows {
+    use std::io;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::Path;
+
+    pub const FILE_ATTRIBUTE_READONLY: u32 = 0x1;
+    pub const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+    pub const FILE_ATTRIBUTE_SYSTEM: u32 = 0x4;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn SetFileAttributesW(path: *const u16, attributes: u32) -> i32;
+    }
+
+    pub fn set_file_attributes(path: &Path, attributes: u32) -> io::Result<()> {
+        let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+        // SAFETY: NUL 종료 UTF-16 경로를 전달
+        if unsafe { SetFileAttributesW(wide.as_ptr(), attributes) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_readonly_and_dot_name_hidden_toggle() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("notes.txt");
+        fs::write(&file, b"x").unwrap();
+
+        let attrs = read_attributes(&file).unwrap();
+        assert!(!attrs.readonly && !attrs.hidden);
+        assert_eq!(attrs.system, None);
+
+        set_attribute(&file, Attribute::ReadOnly, true).unwrap();
+        assert!(read_attributes(&file).unwrap().readonly);
+        set_attribute(&file, Attribute::ReadOnly, false).unwrap();
+        assert!(!read_attributes(&file).unwrap().readonly);
+
+        let hidden = set_attribute(&file, Attribute::Hidden, true).unwrap();
+        assert_eq!(hidden, temp.path().join(".notes.txt"));
+        assert!(read_attributes(&hidden).unwrap().hidden);
+
+        // 원래 이름이 다시 생겼으면 덮어쓰지 않음
+        fs::write(&file, b"y").unwrap();
+        let err = set_attribute(&hidden, Attribute::Hidden, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(set_attribute(&file, Attribute::System, true).is_err());
+    }
+
+    #[test]
+    fn test_xattr_list_remove_and_value_format() {
+        assert_eq!(
+            format_xattr_value(b"0081;5f00;Safari;", 40),
+            "0081;5f00;Safari;"
+        );
+        assert_eq!(format_xattr_value(&[0, 0xff, 0x10], 40), "00ff10");
+        assert_eq!(format_xattr_value(b"abcdefgh", 5), "abcd…");
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("download.zip");
+        fs::write(&file, b"x").unwrap();
+        // tmpfs 등 user xattr을 지원하지 않는 환경에서는 건너뜀
+        if xattr::set(&file, "user.boksldir", b"tag").is_err() {
+            return;
+        }
+        assert_eq!(
+            list_xattrs(&file).unwrap(),
+            vec![("user.boksldir".to_string(), b"tag".to_vec())]
+        );
+        remove_xattr(&file, "user.boksldir").unwrap();
+        assert!(list_xattrs(&file).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+//! 두 파일 내용 비교 (패널 간 같은 이름 파일)
+//!
+//! 크기가 다르면 읽지 않고 다름으로 판정합니다. 작은 파일은 두 파일을 함께 읽으며
+//! 바이트 단위로 비교해 첫 차이 위치를 찾고, 큰 파일은 한 파일씩 끝까지 읽어
+//! SHA-256을 비교합니다 (두 파일을 번갈아 읽는 디스크 탐색을 피함).
+
+use crate::system::filesystem::COPY_CHUNK_SIZE;
+use crate::system::vfs::VirtualFileSystem;
+use crate::utils::error::{BokslDirError, Result};
+use std::io::Read;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 이 크기 이상이면 해시 비교
+pub const HASH_COMPARE_THRESHOLD: u64 = 256 * 1024 * 1024;
+
+/// 판정에 사용한 방법
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareMethod {
+    /// 크기만 비교 (크기가 다름)
+    Size,
+    /// 바이트 단위 비교
+    Bytes,
+    /// SHA-256 비교
+    Hash,
+}
+
+impl CompareMethod {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CompareMethod::Size => "size only",
+            CompareMethod::Bytes => "byte-by-byte",
+            CompareMethod::Hash => "SHA-256",
+        }
+    }
+}
+
+/// 비교 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareOutcome {
+    pub identical: bool,
+    pub method: CompareMethod,
+    pub left_size: u64,
+    pub right_size: u64,
+    /// 첫 차이 바이트 위치 (바이트 비교에서만)
+    pub first_difference: Option<u64>,
+}
+
+/// 두 파일 비교
+///
+/// `on_progress(읽은 바이트, 전체 바이트)`는 청크마다 호출되며, `cancel`이 설정되면
+/// `OperationCancelled`로 중단합니다.
+pub fn compare_files(
+    left_fs: &dyn VirtualFileSystem,
+    left: &Path,
+    right_fs: &dyn VirtualFileSystem,
+    right: &Path,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Result<CompareOutcome> {
+    compare_with_threshold(
+        (left_fs, left),
+        (right_fs, right),
+        HASH_COMPARE_THRESHOLD,
+        cancel,
+        on_progress,
+    )
+}
+
+fn compare_with_threshold(
+    (left_fs, left): (&dyn VirtualFileSystem, &Path),
+    (right_fs, right): (&dyn VirtualFileSystem, &Path),
+    hash_threshold: u64,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Result<CompareOutcome> {
+    let left_stat = left_fs.stat(left)?;
+    let right_stat = right_fs.stat(right)?;
+    for (path, stat) in [(left, &left_stat), (right, &right_stat)] {
+        if stat.is_dir {
+            return Err(BokslDirError::NotAFile {
+                path: path.to_path_buf(),
+            });
+        }
+    }
+    let mut outcome = CompareOutcome {
+        identical: false,
+        method: CompareMethod::Size,
+        left_size: left_stat.size,
+        right_size: right_stat.size,
+        first_difference: None,
+    };
+    if left_stat.size != right_stat.size {
+        return Ok(outcome);
+    }
+
+    let size = left_stat.size;
+    let mut left_reader = left_fs.open_read(left)?;
+    if size >= hash_threshold {
+        outcome.method = CompareMethod::Hash;
+        let total = size * 2;
+        let left_hash = hash_stream(&mut left_reader, 0, total, cancel, on_progress)?;
+        drop(left_reader);
+        let mut right_reader = right_fs.open_read(right)?;
+        let right_hash = hash_stream(&mut right_reader, size, total, cancel, on_progress)?;
+        outcome.identical = left_hash == right_hash;
+        return Ok(outcome);
+    }
+
+    outcome.method = CompareMethod::Bytes;
+    let mut right_reader = right_fs.open_read(right)?;
+    let mut left_buf = vec![0; COPY_CHUNK_SIZE];
+    let mut right_buf = vec![0; COPY_CHUNK_SIZE];
+    let mut offset = 0u64;
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(BokslDirError::OperationCancelled);
+        }
+        let left_len = read_full(&mut left_reader, &mut left_buf)?;
+        let right_len = read_full(&mut right_reader, &mut right_buf)?;
+        let common = left_len.min(right_len);
+        if let Some(pos) = left_buf[..common]
+            .iter()
+            .zip(&right_buf[..common])
+            .position(|(a, b)| a != b)
+        {
+            outcome.first_difference = Some(offset + pos as u64);
+            return Ok(outcome);
+        }
+        if left_len != right_len {
+            // 비교 도중 한쪽 파일 길이가 바뀜
+            outcome.first_difference = Some(offset + common as u64);
+            return Ok(outcome);
+        }
+        if left_len == 0 {
+            break;
+        }
+        offset += left_len as u64;
+        on_progress(offset.min(size), size);
+    }
+    outcome.identical = true;
+    Ok(outcome)
+}
+
+/// 버퍼가 찰 때까지 읽기 (EOF면 그보다 짧음)
+fn read_full(reader: &mut dyn Read, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(filled)
+}
+
+fn hash_stream(
+    reader: &mut dyn Read,
+    base: u64,
+    total: u64,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Result<[u8; 32]> {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; COPY_CHUNK_SIZE];
+    let mut done = base;
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(BokslDirError::OperationCancelled);
+        }
+        let read = read_full(reader, &mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        done += read as u64;
+        on_progress(done.min(total), total);
+    }
+    Ok(hasher.finalize().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::FileSystem;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn compare(left: &Path, right: &Path, threshold: u64) -> Result<CompareOutcome> {
+        let fs = FileSystem::new();
+        compare_with_threshold(
+            (&fs, left),
+            (&fs, right),
+            threshold,
+            &AtomicBool::new(false),
+            &mut |_, _| {},
+        )
+    }
+
+    #[test]
+    fn test_compare_detects_first_difference_and_size_mismatch() {
+        let temp = TempDir::new().unwrap();
+        let (a, b, c) = (
+            temp.path().join("a.bin"),
+            temp.path().join("b.bin"),
+            temp.path().join("c.bin"),
+        );
+        let mut data = vec![3u8; COPY_CHUNK_SIZE + 10];
+        fs::write(&a, &data).unwrap();
+        fs::write(&b, &data).unwrap();
+        data[COPY_CHUNK_SIZE + 4] = 9;
+        fs::write(&c, &data).unwrap();
+
+        let same = compare(&a, &b, HASH_COMPARE_THRESHOLD).unwrap();
+        assert!(same.identical);
+        assert_eq!(same.method, CompareMethod::Bytes);
+
+        let diff = compare(&a, &c, HASH_COMPARE_THRESHOLD).unwrap();
+        assert!(!diff.identical);
+        assert_eq!(diff.first_difference, Some(COPY_CHUNK_SIZE as u64 + 4));
+
+        fs::write(&c, "short").unwrap();
+        let sized = compare(&a, &c, HASH_COMPARE_THRESHOLD).unwrap();
+        assert!(!sized.identical);
+        assert_eq!(sized.method, CompareMethod::Size);
+        assert_eq!(sized.right_size, 5);
+    }
+
+    #[test]
+    fn test_compare_uses_hash_above_threshold() {
+        let temp = TempDir::new().unwrap();
+        let (a, b) = (temp.path().join("a.bin"), temp.path().join("b.bin"));
+        fs::write(&a, "hello world").unwrap();
+        fs::write(&b, "hello world").unwrap();
+        let same = compare(&a, &b, 4).unwrap();
+        assert!(same.identical);
+        assert_eq!(same.method, CompareMethod::Hash);
+
+        fs::write(&b, "hello there").unwrap();
+        let diff = compare(&a, &b, 4).unwrap();
+        assert!(!diff.identical);
+        assert_eq!(diff.first_difference, None);
+    }
+
+    #[test]
+    fn test_compare_rejects_directories_and_honors_cancel() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("a.bin");
+        fs::write(&file, "x").unwrap();
+        assert!(matches!(
+            compare(temp.path(), &file, HASH_COMPARE_THRESHOLD),
+            Err(BokslDirError::NotAFile { .. })
+        ));
+        let fs = FileSystem::new();
+        assert!(matches!(
+            compare_files(
+                &fs,
+                &file,
+                &fs,
+                &file,
+                &AtomicBool::new(true),
+                &mut |_, _| {}
+            ),
+            Err(BokslDirError::OperationCancelled)
+        ));
+    }
+}
@@ -0,0 +1,261 @@
+//! 디스크 사용량 분석 (ncdu 방식 트리 스캔)
+//!
+//! 실제 디스크 점유 크기(블록 수 기준)를 합산하며, 심볼릭 링크는 따라가지 않고
+//! 하드 링크는 한 번만 셉니다. 시작 경로와 다른 파일시스템은 건너뜁니다.
+
+use crate::system::reparse;
+use crate::utils::error::{BokslDirError, Result};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 진행률 콜백 호출 간격 (항목 수)
+const PROGRESS_INTERVAL: u64 = 500;
+
+/// 사용량 트리 노드
+#[derive(Debug, Clone)]
+pub struct UsageNode {
+    pub name: String,
+    pub path: PathBuf,
+    /// 하위 항목 포함 디스크 점유 크기
+    pub size: u64,
+    pub is_dir: bool,
+    /// 하위 파일 수 (디렉토리 제외)
+    pub file_count: u64,
+    /// 읽지 못한 하위 디렉토리가 있음
+    pub has_error: bool,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+}
+
+/// 스캔 결과 트리 (0번이 루트)
+#[derive(Debug, Clone)]
+pub struct UsageTree {
+    nodes: Vec<UsageNode>,
+}
+
+impl UsageTree {
+    pub const ROOT: usize = 0;
+
+    pub fn node(&self, id: usize) -> &UsageNode {
+        &self.nodes[id]
+    }
+
+    /// 크기 내림차순(같으면 이름순) 하위 항목
+    pub fn sorted_children(&self, id: usize) -> Vec<usize> {
+        let mut children = self.nodes[id].children.clone();
+        children.sort_by(|&a, &b| {
+            let (a, b) = (&self.nodes[a], &self.nodes[b]);
+            b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name))
+        });
+        children
+    }
+
+    /// 삭제된 항목을 트리에서 떼어내고 상위 합계에서 뺌
+    pub fn remove(&mut self, id: usize) {
+        let Some(parent) = self.nodes[id].parent else {
+            return;
+        };
+        let (size, files) = (self.nodes[id].size, self.nodes[id].file_count);
+        self.nodes[parent].children.retain(|&child| child != id);
+        let mut ancestor = Some(parent);
+        while let Some(current) = ancestor {
+            let node = &mut self.nodes[current];
+            node.size = node.size.saturating_sub(size);
+            node.file_count = node.file_count.saturating_sub(files);
+            ancestor = node.parent;
+        }
+    }
+}
+
+/// 파일 하나의 디스크 점유 크기와 하드 링크 식별자
+#[cfg(unix)]
+fn allocated_size(metadata: &fs::Metadata) -> (u64, Option<(u64, u64)>) {
+    use std::os::unix::fs::MetadataExt;
+    let link =
+        (metadata.nlink() > 1 && !metadata.is_dir()).then(|| (metadata.dev(), metadata.ino()));
+    (metadata.blocks() * 512, link)
+}
+
+#[cfg(not(unix))]
+fn allocated_size(metadata: &fs::Metadata) -> (u64, Option<(u64, u64)>) {
+    (metadata.len(), None)
+}
+
+#[cfg(unix)]
+fn device_id(metadata: &fs::Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.dev())
+}
+
+#[cfg(not(unix))]
+fn device_id(_metadata: &fs::Metadata) -> Option<u64> {
+    None
+}
+
+/// 디렉토리 트리 스캔
+///
+/// `on_progress(항목 수, 누적 크기)`는 주기적으로 호출되며, `cancel`이 설정되면
+/// `OperationCancelled`로 중단합니다.
+pub fn scan(
+    root: &Path,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Result<UsageTree> {
+    let root_meta = fs::symlink_metadata(root).map_err(|_| BokslDirError::PathNotFound {
+        path: root.to_path_buf(),
+    })?;
+    if !root_meta.is_dir() {
+        return Err(BokslDirError::NotADirectory {
+            path: root.to_path_buf(),
+        });
+    }
+    let root_device = device_id(&root_meta);
+    let mut nodes = vec![UsageNode {
+        name: root.to_string_lossy().to_string(),
+        path: root.to_path_buf(),
+        size: allocated_size(&root_meta).0,
+        is_dir: true,
+        file_count: 0,
+        has_error: false,
+        parent: None,
+        children: Vec::new(),
+    }];
+    let mut seen_links = HashSet::new();
+    let mut scanned = 0u64;
+    let mut total = 0u64;
+    let mut pending = vec![UsageTree::ROOT];
+
+    while let Some(dir_id) = pending.pop() {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(BokslDirError::OperationCancelled);
+        }
+        let Ok(entries) = fs::read_dir(&nodes[dir_id].path) else {
+            nodes[dir_id].has_error = true;
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                nodes[dir_id].has_error = true;
+                continue;
+            };
+            // 정션 등 다른 경로를 가리키는 재분석 지점은 따라가지 않음
+            let is_dir = metadata.is_dir() && !reparse::is_link(&entry.path(), &metadata);
+            let (mut size, link) = allocated_size(&metadata);
+            if let Some(link) = link {
+                if !seen_links.insert(link) {
+                    size = 0;
+                }
+            }
+            let id = nodes.len();
+            nodes.push(UsageNode {
+                name: entry.file_name().to_string_lossy().to_string(),
+                path: entry.path(),
+                size,
+                is_dir,
+                file_count: u64::from(!is_dir),
+                has_error: false,
+                parent: Some(dir_id),
+                children: Vec::new(),
+            });
+            nodes[dir_id].children.push(id);
+            if is_dir && device_id(&metadata) == root_device {
+                pending.push(id);
+            }
+            scanned += 1;
+            total += size;
+            if scanned.is_multiple_of(PROGRESS_INTERVAL) {
+                on_progress(scanned, total);
+            }
+        }
+    }
+    on_progress(scanned, total);
+
+    // 자식은 항상 부모보다 뒤에 추가되므로 역순으로 합산하면 상위 합계가 완성됨
+    for id in (1..nodes.len()).rev() {
+        let (size, files, error) = (nodes[id].size, nodes[id].file_count, nodes[id].has_error);
+        if let Some(parent) = nodes[id].parent {
+            let parent = &mut nodes[parent];
+            parent.size += size;
+            parent.file_count += files;
+            parent.has_error |= error;
+        }
+    }
+    Ok(UsageTree { nodes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn names(tree: &UsageTree, id: usize) -> Vec<String> {
+        tree.sorted_children(id)
+            .into_iter()
+            .map(|child| tree.node(child).name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_scan_sums_sizes_and_sorts_by_size() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("big/inner")).unwrap();
+        fs::write(temp.path().join("big/inner/a.bin"), vec![1u8; 64 * 1024]).unwrap();
+        fs::write(temp.path().join("big/b.bin"), vec![1u8; 16 * 1024]).unwrap();
+        fs::write(temp.path().join("small.txt"), "x").unwrap();
+
+        let mut progress_calls = 0;
+        let tree = scan(temp.path(), &AtomicBool::new(false), &mut |_, _| {
+            progress_calls += 1
+        })
+        .unwrap();
+        assert!(progress_calls >= 1);
+
+        let root = tree.node(UsageTree::ROOT);
+        assert_eq!(root.file_count, 3);
+        assert_eq!(names(&tree, UsageTree::ROOT), vec!["big", "small.txt"]);
+        let big = tree.sorted_children(UsageTree::ROOT)[0];
+        assert!(tree.node(big).size >= 80 * 1024);
+        assert!(root.size >= tree.node(big).size);
+        assert_eq!(names(&tree, big), vec!["inner", "b.bin"]);
+
+        let mut tree = tree;
+        let before = tree.node(UsageTree::ROOT).size;
+        let big_size = tree.node(big).size;
+        tree.remove(big);
+        assert_eq!(tree.node(UsageTree::ROOT).size, before - big_size);
+        assert_eq!(tree.node(UsageTree::ROOT).file_count, 1);
+        assert_eq!(names(&tree, UsageTree::ROOT), vec!["small.txt"]);
+    }
+
+    #[test]
+    fn test_scan_cancel_and_invalid_root() {
+        let temp = TempDir::new().unwrap();
+        assert!(matches!(
+            scan(temp.path(), &AtomicBool::new(true), &mut |_, _| {}),
+            Err(BokslDirError::OperationCancelled)
+        ));
+        let file = temp.path().join("file.txt");
+        fs::write(&file, "x").unwrap();
+        assert!(matches!(
+            scan(&file, &AtomicBool::new(false), &mut |_, _| {}),
+            Err(BokslDirError::NotADirectory { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_counts_hard_links_once() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("a.bin"), vec![1u8; 32 * 1024]).unwrap();
+        fs::hard_link(temp.path().join("a.bin"), temp.path().join("b.bin")).unwrap();
+        let tree = scan(temp.path(), &AtomicBool::new(false), &mut |_, _| {}).unwrap();
+        let sizes: Vec<u64> = tree
+            .sorted_children(UsageTree::ROOT)
+            .into_iter()
+            .map(|id| tree.node(id).size)
+            .collect();
+        assert_eq!(sizes.iter().filter(|&&size| size == 0).count(), 1);
+    }
+}
@@ -0,0 +1,306 @@
+//! 디스크 공간/파일시스템 정보 (statvfs, GetDiskFreeSpaceExW, GetVolumeInformationW)
+//!
+//! 조회할 수 없는 플랫폼이나 경로에서는 None을 반환하며, 상태바/마운트 포인트
+//! 다이얼로그는 해당 정보를 생략합니다.
+
+use std::path::Path;
+
+/// 경로가 속한 파일시스템의 용량 (바이트)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiskSpace {
+    pub total: u64,
+    /// 전체 여유 공간 (root 예약 포함)
+    pub free: u64,
+    /// 현재 사용자가 쓸 수 있는 공간
+    pub available: u64,
+}
+
+impl DiskSpace {
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.free)
+    }
+
+    /// 사용률 (0.0 ~ 1.0)
+    pub fn usage_ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.used() as f64 / self.total as f64
+        }
+    }
+}
+
+/// 경로가 속한 파일시스템의 용량 조회
+#[cfg(unix)]
+#[allow(clippy::useless_conversion)] // statvfs 필드 폭은 플랫폼마다 다름 (u32/u64)
+pub fn disk_space(path: &Path) -> Option<DiskSpace> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path_c = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: NUL 종료 문자열과 statvfs 크기의 버퍼를 전달
+    if unsafe { libc::statvfs(path_c.as_ptr(), stat.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: statvfs가 성공하면 구조체가 채워짐
+    let stat = unsafe { stat.assume_init() };
+    let block = u64::from(stat.f_frsize);
+    Some(DiskSpace {
+        total: u64::from(stat.f_blocks) * block,
+        free: u64::from(stat.f_bfree) * block,
+        available: u64::from(stat.f_bavail) * block,
+    })
+}
+
+/// 경로가 속한 볼륨의 용량 조회
+#[cfg(windows)]
+pub fn disk_space(path: &Path) -> Option<DiskSpace> {
+    use std::os::windows::ffi::OsStrExt;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetDiskFreeSpaceExW(
+            directory: *const u16,
+            available: *mut u64,
+            total: *mut u64,
+            free: *mut u64,
+        ) -> i32;
+    }
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let (mut available, mut total, mut free) = (0u64, 0u64, 0u64);
+    // SAFETY: NUL 종료 UTF-16 경로와 유효한 출력 포인터를 전달
+    let ok = unsafe { GetDiskFreeSpaceExW(wide.as_ptr(), &mut available, &mut total, &mut free) };
+    (ok != 0).then_some(DiskSpace {
+        total,
+        free,
+        available,
+    })
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn disk_space(_path: &Path) -> Option<DiskSpace> {
+    None
+}
+
+/// 두 경로가 같은 파일시스템에 있는지 (이동이 이름 변경으로 끝나는지 판단, 모르면 true)
+#[cfg(unix)]
+pub fn same_filesystem(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (std::fs::metadata(a), std::fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev(),
+        _ => true,
+    }
+}
+
+/// 두 경로가 같은 볼륨에 있는지 (드라이브/UNC 접두사 비교)
+#[cfg(not(unix))]
+pub fn same_filesystem(a: &Path, b: &Path) -> bool {
+    let prefix = |path: &Path| {
+        path.components()
+            .next()
+            .map(|part| part.as_os_str().to_string_lossy().to_lowercase())
+    };
+    prefix(a) == prefix(b)
+}
+
+/// 경로가 속한 파일시스템 종류 (ext4, apfs, ntfs 등)
+#[cfg(target_os = "linux")]
+pub fn filesystem_type(path: &Path) -> Option<String> {
+    let table = std::fs::read_to_string("/proc/self/mounts").ok()?;
+    let path = path.canonicalize().ok()?;
+    mount_table_fs_type(&table, &path)
+}
+
+#[cfg(target_os = "macos")]
+pub fn filesystem_type(path: &Path) -> Option<String> {
+    use std::ffi::{CStr, CString};
+    use std::os::unix::ffi::OsStrExt;
+
+    let path_c = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statfs>::uninit();
+    // SAFETY: NUL 종료 문자열과 statfs 크기의 버퍼를 전달
+    if unsafe { libc::statfs(path_c.as_ptr(), stat.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: statfs가 성공하면 f_fstypename은 NUL 종료 문자열
+    let stat = unsafe { stat.assume_init() };
+    let name = unsafe { CStr::from_ptr(stat.f_fstypename.as_ptr()) };
+    Some(name.to_string_lossy().to_string())
+}
+
+/// 경로가 속한 볼륨의 파일시스템 종류 (NTFS, FAT32 등)
+#[cfg(windows)]
+pub fn filesystem_type(path: &Path) -> Option<String> {
+    use std::os::windows::ffi::OsStrExt;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetVolumePathNameW(path: *const u16, root: *mut u16, root_len: u32) -> i32;
+    }
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut root = [0u16; 512];
+    // SAFETY: NUL 종료 UTF-16 경로와 길이를 맞춘 출력 버퍼를 전달
+    if unsafe { GetVolumePathNameW(wide.as_ptr(), root.as_mut_ptr(), root.len() as u32) } == 0 {
+        return None;
+    }
+    let len = root.iter().position(|&c| c == 0).unwrap_or(root.len());
+    let root = std::path::PathBuf::from(String::from_utf16_lossy(&root[..len]));
+    volume_information(&root).map(|(_, fs_type)| fs_type)
+}
+
+/// 볼륨 이름과 파일시스템 종류 (`root`는 `C:\` 같은 볼륨 루트)
+#[cfg(windows)]
+pub fn volume_information(root: &Path) -> Option<(String, String)> {
+    use std::os::windows::ffi::OsStrExt;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetVolumeInformationW(
+            root: *const u16,
+            name: *mut u16,
+            name_len: u32,
+            serial: *mut u32,
+            max_component: *mut u32,
+            flags: *mut u32,
+            fs_name: *mut u16,
+            fs_name_len: u32,
+        ) -> i32;
+    }
+
+    let wide: Vec<u16> = root.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut name = [0u16; 261];
+    let mut fs_name = [0u16; 261];
+    // SAFETY: NUL 종료 UTF-16 경로, 길이를 맞춘 출력 버퍼, 쓰지 않는 값은 null
+    let ok = unsafe {
+        GetVolumeInformationW(
+            wide.as_ptr(),
+            name.as_mut_ptr(),
+            name.len() as u32,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            fs_name.as_mut_ptr(),
+            fs_name.len() as u32,
+        )
+    };
+    if ok == 0 {
+        return None;
+    }
+    let text = |buf: &[u16]| {
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        String::from_utf16_lossy(&buf[..len])
+    };
+    Some((text(&name), text(&fs_name)))
+}
+
+/// 연결된 드라이브 루트 목록 (`C:\`, `D:\` ...)
+#[cfg(windows)]
+pub fn logical_drives() -> Vec<std::path::PathBuf> {
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetLogicalDrives() -> u32;
+    }
+
+    // SAFETY: 인자 없는 조회 함수 (비트 0 = A:)
+    let mask = unsafe { GetLogicalDrives() };
+    (0..26u8)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| std::path::PathBuf::from(format!("{}:\\", (b'A' + bit) as char)))
+        .collect()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+pub fn filesystem_type(_path: &Path) -> Option<String> {
+    None
+}
+
+/// `/proc/self/mounts` 형식 테이블에서 경로를 포함하는 가장 긴 마운트 포인트의 종류
+#[cfg(any(target_os = "linux", test))]
+fn mount_table_fs_type(table: &str, path: &Path) -> Option<String> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _device = fields.next()?;
+            let mount_point = decode_mount_field(fields.next()?);
+            let fs_type = fields.next()?;
+            path.starts_with(&mount_point)
+                .then(|| (mount_point.len(), fs_type.to_string()))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, fs_type)| fs_type)
+}
+
+/// 마운트 테이블의 8진 이스케이프(`\040` = 공백 등) 해제
+#[cfg(any(target_os = "linux", test))]
+fn decode_mount_field(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 4 <= bytes.len() {
+            let digits = std::str::from_utf8(&bytes[i + 1..i + 4]).unwrap_or("");
+            if let Ok(value) = u8::from_str_radix(digits, 8) {
+                out.push(value);
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disk_space_usage_ratio() {
+        let space = DiskSpace {
+            total: 1000,
+            free: 250,
+            available: 200,
+        };
+        assert_eq!(space.used(), 750);
+        assert!((space.usage_ratio() - 0.75).abs() < f64::EPSILON);
+        assert_eq!(DiskSpace::default().usage_ratio(), 0.0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_disk_space_reads_temp_dir() {
+        let space = disk_space(&std::env::temp_dir()).expect("statvfs");
+        assert!(space.total > 0);
+        assert!(space.free <= space.total);
+        assert!(disk_space(Path::new("/nonexistent/boksldir-disk")).is_none());
+    }
+
+    #[test]
+    fn test_mount_table_picks_longest_mount_point() {
+        let table = "\
+/dev/sda1 / ext4 rw,relatime 0 0
+proc /proc proc rw 0 0
+/dev/sdb1 /mnt/my\\040disk vfat rw 0 0
+/dev/sdc1 /mnt/my ntfs rw 0 0
+";
+        assert_eq!(
+            mount_table_fs_type(table, Path::new("/home/user")).as_deref(),
+            Some("ext4")
+        );
+        assert_eq!(
+            mount_table_fs_type(table, Path::new("/mnt/my disk/photos")).as_deref(),
+            Some("vfat")
+        );
+        assert_eq!(
+            mount_table_fs_type(table, Path::new("/mnt/mydisk")).as_deref(),
+            Some("ext4")
+        );
+        assert_eq!(mount_table_fs_type("", Path::new("/")), None);
+    }
+}
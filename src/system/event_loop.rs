@@ -0,0 +1,169 @@
+//! 메인 루프 이벤트 대기 (터미널 입력 스레드 + 백그라운드 작업 깨우기)
+//!
+//! 메인 루프는 짧은 주기로 폴링하지 않고 채널에서 다음 이벤트를 기다립니다.
+//! 터미널 입력은 전용 스레드가 읽어 보내고, 백그라운드 작업은 끝날 때 [`Waker`]로 깨웁니다.
+//! 외부 프로그램에 터미널을 넘기는 동안에는 [`InputReader::pause`]로 입력 읽기를 멈춥니다.
+
+use crossterm::event::{self, Event};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// 입력 스레드가 정지/종료 요청을 확인하는 주기
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// 일시정지 요청 후 입력 스레드가 멈출 때까지 기다리는 최대 시간
+const PAUSE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// 메인 루프가 기다리는 이벤트
+#[derive(Debug)]
+pub enum LoopEvent {
+    Input(Event),
+    /// 백그라운드 작업 결과가 도착함
+    Wake,
+    /// 터미널 입력을 더 읽을 수 없음 (입력 스레드 종료)
+    InputError(io::Error),
+}
+
+/// 메인 루프 이벤트 채널 (보내는 쪽은 입력 스레드와 [`Waker`])
+pub fn channel() -> (Sender<LoopEvent>, Receiver<LoopEvent>) {
+    mpsc::channel()
+}
+
+/// 백그라운드 스레드에서 메인 루프 깨우기
+///
+/// 메인 루프가 처리하기 전의 깨우기는 하나로 합쳐집니다. 채널이 없으면(테스트) 아무 일도 하지 않습니다.
+#[derive(Debug, Clone, Default)]
+pub struct Waker {
+    inner: Option<Arc<WakerInner>>,
+}
+
+#[derive(Debug)]
+struct WakerInner {
+    tx: Sender<LoopEvent>,
+    pending: AtomicBool,
+}
+
+impl Waker {
+    pub fn new(tx: Sender<LoopEvent>) -> Self {
+        Self {
+            inner: Some(Arc::new(WakerInner {
+                tx,
+                pending: AtomicBool::new(false),
+            })),
+        }
+    }
+
+    pub fn wake(&self) {
+        if let Some(inner) = &self.inner {
+            if !inner.pending.swap(true, Ordering::SeqCst) {
+                let _ = inner.tx.send(LoopEvent::Wake);
+            }
+        }
+    }
+
+    /// 작업 결과를 반영하기 직전에 호출 (이후의 깨우기는 다시 전달됨)
+    pub fn reset(&self) {
+        if let Some(inner) = &self.inner {
+            inner.pending.store(false, Ordering::SeqCst);
+        }
+    }
+}
+
+/// 터미널 입력을 읽어 채널로 보내는 스레드
+pub struct InputReader {
+    shared: Arc<InputShared>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[derive(Default)]
+struct InputShared {
+    paused: AtomicBool,
+    parked: AtomicBool,
+    stop: AtomicBool,
+}
+
+impl InputReader {
+    pub fn spawn(tx: Sender<LoopEvent>) -> Self {
+        let shared = Arc::new(InputShared::default());
+        let worker = Arc::clone(&shared);
+        let handle = thread::spawn(move || {
+            while !worker.stop.load(Ordering::SeqCst) {
+                if worker.paused.load(Ordering::SeqCst) {
+                    worker.parked.store(true, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(10));
+                    continue;
+                }
+                match event::poll(INPUT_POLL_INTERVAL) {
+                    Ok(true) => match event::read() {
+                        Ok(event) => {
+                            if tx.send(LoopEvent::Input(event)).is_err() {
+                                break;
+                            }
+                        }
+                        Err(err) => {
+                            let _ = tx.send(LoopEvent::InputError(err));
+                            break;
+                        }
+                    },
+                    Ok(false) => {}
+                    Err(err) => {
+                        let _ = tx.send(LoopEvent::InputError(err));
+                        break;
+                    }
+                }
+            }
+        });
+        Self {
+            shared,
+            handle: Some(handle),
+        }
+    }
+
+    /// 입력 읽기 중단 (스레드가 터미널에서 손을 뗄 때까지 기다림)
+    pub fn pause(&self) {
+        self.shared.parked.store(false, Ordering::SeqCst);
+        self.shared.paused.store(true, Ordering::SeqCst);
+        let deadline = Instant::now() + PAUSE_TIMEOUT;
+        while !self.shared.parked.load(Ordering::SeqCst) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    pub fn resume(&self) {
+        self.shared.paused.store(false, Ordering::SeqCst);
+    }
+}
+
+impl Drop for InputReader {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waker_coalesces_until_reset() {
+        let (tx, rx) = channel();
+        let waker = Waker::new(tx);
+        waker.wake();
+        waker.clone().wake();
+        assert!(matches!(rx.try_recv(), Ok(LoopEvent::Wake)));
+        assert!(rx.try_recv().is_err());
+
+        waker.reset();
+        waker.wake();
+        assert!(matches!(rx.try_recv(), Ok(LoopEvent::Wake)));
+
+        // 채널 없는 기본 Waker는 무시
+        Waker::default().wake();
+    }
+}
@@ -0,0 +1,206 @@
+//! 빠른 복사 백엔드 (reflink 복제, 희소 파일 구간 탐색)
+//!
+//! 지원하지 않는 플랫폼/파일시스템에서는 실패를 반환하며,
+//! 호출자(`filesystem`)는 일반 스트리밍 복사로 대체합니다.
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+use crate::utils::temp_name;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// 두 경로가 같은 파일을 가리키는지 (하드 링크, 심볼릭 링크 디렉토리 경유 포함)
+///
+/// Unix는 장치/아이노드 번호로 비교하고, 그 밖의 플랫폼은 경로가 같은지만 봅니다.
+/// 한쪽이라도 없으면 false입니다.
+#[cfg(unix)]
+pub fn same_file(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (std::fs::metadata(a), std::fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+pub fn same_file(a: &Path, b: &Path) -> bool {
+    a == b
+}
+
+/// 복제본을 만들 임시 이름 접두사 (대상과 같은 디렉토리, 숨김 파일)
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn clone_temp_prefix(dest: &Path) -> Option<(&Path, String)> {
+    let name = dest.file_name()?.to_string_lossy();
+    let dir = match dest.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    Some((dir, format!(".{}.boksldir-clone-", name)))
+}
+
+/// 새로 만드는 파일의 기본 권한 (`0666 & !umask`, 일반 복사의 `File::create`와 같게)
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn new_file_mode() -> u32 {
+    0o666 & !process_umask()
+}
+
+/// 현재 umask (/proc에서 읽고, 없으면 잠깐 바꿔 읽은 뒤 되돌림)
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn process_umask() -> u32 {
+    let from_proc = std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| {
+            status.lines().find_map(|line| {
+                let value = line.strip_prefix("Umask:")?.trim();
+                u32::from_str_radix(value, 8).ok()
+            })
+        });
+    from_proc.unwrap_or_else(|| {
+        // SAFETY: umask는 항상 성공하며 바로 이전 값으로 되돌림
+        let previous = unsafe { libc::umask(0o022) };
+        unsafe { libc::umask(previous) };
+        // macOS의 mode_t는 u16
+        #[allow(clippy::useless_conversion)]
+        u32::from(previous)
+    })
+}
+
+/// 임시 복제본에 새 파일 권한을 주고 대상 이름으로 바꿈 (실패하면 임시 파일만 삭제)
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn publish_clone(temp: &Path, dest: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = std::fs::Permissions::from_mode(new_file_mode());
+    if std::fs::set_permissions(temp, mode).is_ok() && std::fs::rename(temp, dest).is_ok() {
+        return true;
+    }
+    let _ = std::fs::remove_file(temp);
+    false
+}
+
+/// reflink(copy-on-write) 복제 시도 (btrfs/XFS: FICLONE)
+///
+/// 대상이 있으면 그 파일에 그대로 복제해 권한/소유자/하드 링크를 유지하고, 없으면 새로 만든
+/// 임시 파일에 복제한 뒤 새 파일 기본 권한으로 대상 이름을 붙입니다. 같은 파일이거나
+/// 같은 파일시스템이 아니거나 지원하지 않으면 false를 반환하며, 이때 기존 대상은
+/// 바뀌지 않고 지우는 것은 이 함수가 만든 임시 파일뿐입니다.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn reflink(src: &Path, dest: &Path) -> bool {
+    use std::os::fd::AsRawFd;
+
+    fn clone_into(dest_file: &File, src_file: &File) -> bool {
+        // SAFETY: 두 디스크립터 모두 호출자가 연 유효한 파일
+        let result = unsafe {
+            libc::ioctl(
+                dest_file.as_raw_fd(),
+                libc::FICLONE as _,
+                src_file.as_raw_fd(),
+            )
+        };
+        result == 0
+    }
+
+    if same_file(src, dest) {
+        return false;
+    }
+    let Ok(src_file) = File::open(src) else {
+        return false;
+    };
+    if std::fs::symlink_metadata(dest).is_ok() {
+        // 잘라내지 않고 열기: 복제가 실패하면 내용이 그대로 남음
+        let Ok(dest_file) = std::fs::OpenOptions::new().write(true).open(dest) else {
+            return false;
+        };
+        let Ok(src_len) = src_file.metadata().map(|metadata| metadata.len()) else {
+            return false;
+        };
+        // 원본보다 길던 대상의 뒷부분은 복제 후 잘라냄
+        return clone_into(&dest_file, &src_file) && dest_file.set_len(src_len).is_ok();
+    }
+    let Some((dir, prefix)) = clone_temp_prefix(dest) else {
+        return false;
+    };
+    let Ok((temp, temp_file)) = temp_name::create_new_file(dir, &prefix, "") else {
+        return false;
+    };
+    let cloned = clone_into(&temp_file, &src_file);
+    drop(temp_file);
+    if cloned {
+        return publish_clone(&temp, dest);
+    }
+    let _ = std::fs::remove_file(&temp);
+    false
+}
+
+/// reflink(copy-on-write) 복제 시도 (APFS: clonefile)
+///
+/// clonefile은 대상이 없어야 하므로 대상이 이미 있으면 false를 반환합니다(일반 복사가 그
+/// 파일에 덮어써 권한/소유자/하드 링크 유지). 없으면 비어 있는 임시 이름으로 복제한 뒤
+/// 새 파일 기본 권한으로 대상 이름을 붙입니다. 시각/xattr은 원본 값이 함께 복제됩니다.
+#[cfg(target_os = "macos")]
+pub fn reflink(src: &Path, dest: &Path) -> bool {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    if std::fs::symlink_metadata(dest).is_ok() {
+        return false;
+    }
+    let Some((dir, prefix)) = clone_temp_prefix(dest) else {
+        return false;
+    };
+    let Ok(temp) = temp_name::unused_path(dir, &prefix, "") else {
+        return false;
+    };
+    let (Ok(src_c), Ok(temp_c)) = (
+        CString::new(src.as_os_str().as_bytes()),
+        CString::new(temp.as_os_str().as_bytes()),
+    ) else {
+        return false;
+    };
+    // SAFETY: 두 포인터 모두 위에서 만든 NUL 종료 문자열
+    // (임시 이름이 그 사이 생겼으면 EEXIST로 실패하므로 남의 파일을 덮지 않음)
+    if unsafe { libc::clonefile(src_c.as_ptr(), temp_c.as_ptr(), 0) } != 0 {
+        return false;
+    }
+    publish_clone(&temp, dest)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+pub fn reflink(_src: &Path, _dest: &Path) -> bool {
+    false
+}
+
+/// offset 이후 첫 데이터 구간 `[start, end)` (희소 파일의 구멍 건너뛰기)
+///
+/// `Ok(None)`이면 offset 이후는 모두 구멍입니다. lseek로 파일 위치가 바뀌므로
+/// 호출자는 읽기 전에 위치를 다시 지정해야 합니다.
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+pub fn data_range(file: &File, offset: u64) -> io::Result<Option<(u64, u64)>> {
+    use std::os::fd::AsRawFd;
+
+    let fd = file.as_raw_fd();
+    let offset =
+        libc::off_t::try_from(offset).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+    // SAFETY: fd는 file이 소유한 유효한 디스크립터
+    let start = unsafe { libc::lseek(fd, offset, libc::SEEK_DATA) };
+    if start < 0 {
+        let err = io::Error::last_os_error();
+        return if err.raw_os_error() == Some(libc::ENXIO) {
+            Ok(None)
+        } else {
+            Err(err)
+        };
+    }
+    // SAFETY: 위와 동일
+    let end = unsafe { libc::lseek(fd, start, libc::SEEK_HOLE) };
+    if end < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(Some((start as u64, end as u64)))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+pub fn data_range(_file: &File, _offset: u64) -> io::Result<Option<(u64, u64)>> {
+    Err(io::Error::from(io::ErrorKind::Unsupported))
+}
@@ -0,0 +1,138 @@
+//! 파일 찾기용 파일 이름 색인 (현재 경로 아래 전체)
+//!
+//! 심볼릭 링크 디렉토리는 따라가지 않으며 `.git` 등 VCS 메타데이터 디렉토리는
+//! 건너뜁니다. 결과는 시작 경로 기준 상대 경로(`/` 구분)로 일정 개수씩 묶어
+//! 전달해, 색인 중에도 목록을 바로 좁힐 수 있게 합니다.
+
+use crate::utils::error::{BokslDirError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 한 번에 전달하는 경로 수
+const BATCH_SIZE: usize = 2000;
+
+/// 색인하지 않는 디렉토리 이름
+const SKIPPED_DIRS: [&str; 4] = [".git", ".hg", ".svn", "node_modules"];
+
+/// 색인 결과 요약
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexSummary {
+    pub files: usize,
+    /// 상한에 도달해 중간에 멈춤
+    pub truncated: bool,
+}
+
+/// `root` 아래 파일 색인
+///
+/// `on_batch`는 상대 경로 묶음마다 호출되며, `cancel`이 설정되면
+/// `OperationCancelled`로 중단합니다. 숨김 항목은 `include_hidden`일 때만 포함합니다.
+pub fn index_files(
+    root: &Path,
+    include_hidden: bool,
+    max_files: usize,
+    cancel: &AtomicBool,
+    on_batch: &mut dyn FnMut(Vec<String>),
+) -> Result<IndexSummary> {
+    if !root.is_dir() {
+        return Err(BokslDirError::NotADirectory {
+            path: root.to_path_buf(),
+        });
+    }
+    let mut pending: Vec<PathBuf> = vec![PathBuf::new()];
+    let mut batch = Vec::with_capacity(BATCH_SIZE);
+    let mut files = 0;
+    let mut truncated = false;
+
+    'walk: while let Some(relative) = pending.pop() {
+        let Ok(read_dir) = fs::read_dir(root.join(&relative)) else {
+            continue;
+        };
+        let mut subdirs = Vec::new();
+        for entry in read_dir.flatten() {
+            if cancel.load(Ordering::Relaxed) {
+                return Err(BokslDirError::OperationCancelled);
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !include_hidden && name.starts_with('.') {
+                continue;
+            }
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = relative.join(&name);
+            if file_type.is_dir() {
+                if !SKIPPED_DIRS.contains(&name.as_str()) {
+                    subdirs.push(path);
+                }
+                continue;
+            }
+            if files == max_files {
+                truncated = true;
+                break 'walk;
+            }
+            files += 1;
+            batch.push(path.to_string_lossy().replace('\\', "/"));
+            if batch.len() == BATCH_SIZE {
+                on_batch(std::mem::take(&mut batch));
+            }
+        }
+        // 이름순으로 꺼내도록 역순으로 쌓음
+        subdirs.sort_unstable_by(|a, b| b.cmp(a));
+        pending.extend(subdirs);
+    }
+    if !batch.is_empty() {
+        on_batch(batch);
+    }
+    Ok(IndexSummary { files, truncated })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn collect(root: &Path, include_hidden: bool, max_files: usize) -> (Vec<String>, bool) {
+        let mut paths = Vec::new();
+        let summary = index_files(
+            root,
+            include_hidden,
+            max_files,
+            &AtomicBool::new(false),
+            &mut |batch| paths.extend(batch),
+        )
+        .unwrap();
+        paths.sort();
+        assert_eq!(summary.files, paths.len());
+        (paths, summary.truncated)
+    }
+
+    #[test]
+    fn test_index_files_walks_tree_and_skips_vcs_and_hidden() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src/app")).unwrap();
+        fs::create_dir_all(root.join(".git/objects")).unwrap();
+        fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        fs::write(root.join("README.md"), "").unwrap();
+        fs::write(root.join(".env"), "").unwrap();
+        fs::write(root.join("src/main.rs"), "").unwrap();
+        fs::write(root.join("src/app/state.rs"), "").unwrap();
+        fs::write(root.join(".git/HEAD"), "").unwrap();
+        fs::write(root.join("node_modules/pkg/index.js"), "").unwrap();
+
+        let (paths, truncated) = collect(root, false, 100);
+        assert_eq!(paths, vec!["README.md", "src/app/state.rs", "src/main.rs"]);
+        assert!(!truncated);
+
+        let (paths, _) = collect(root, true, 100);
+        assert_eq!(
+            paths,
+            vec![".env", "README.md", "src/app/state.rs", "src/main.rs"]
+        );
+
+        let (paths, truncated) = collect(root, false, 2);
+        assert_eq!(paths.len(), 2);
+        assert!(truncated);
+    }
+}
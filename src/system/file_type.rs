@@ -0,0 +1,373 @@
+//! 매직 넘버 기반 파일 종류 판별
+//!
+//! 파일 앞부분(최대 [`SNIFF_LEN`] 바이트)의 시그니처로 "PNG image", "ELF executable" 같은
+//! 설명과 MIME 타입을 구합니다. 속성 다이얼로그, 패널의 종류 컬럼, 확장자 없는 압축 파일
+//! 판별에 사용합니다.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// 판별에 읽는 최대 바이트 수 (tar 헤더의 `ustar` 위치 포함)
+pub const SNIFF_LEN: usize = 512;
+
+/// 판별된 파일 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DetectedType {
+    /// 사람이 읽는 설명 (예: "PNG image")
+    pub description: &'static str,
+    /// MIME 타입
+    pub mime: &'static str,
+    pub category: TypeCategory,
+}
+
+/// 파일 종류 분류 (미리보기 방식 선택용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeCategory {
+    Image,
+    Audio,
+    Video,
+    Document,
+    Archive,
+    Executable,
+    Text,
+    Binary,
+}
+
+const fn detected(
+    description: &'static str,
+    mime: &'static str,
+    category: TypeCategory,
+) -> DetectedType {
+    DetectedType {
+        description,
+        mime,
+        category,
+    }
+}
+
+/// 앞부분 시그니처 (오프셋 0에서 바로 비교)
+const SIGNATURES: &[(&[u8], DetectedType)] = &[
+    (
+        b"\x89PNG\r\n\x1a\n",
+        detected("PNG image", "image/png", TypeCategory::Image),
+    ),
+    (
+        b"\xff\xd8\xff",
+        detected("JPEG image", "image/jpeg", TypeCategory::Image),
+    ),
+    (
+        b"GIF87a",
+        detected("GIF image", "image/gif", TypeCategory::Image),
+    ),
+    (
+        b"GIF89a",
+        detected("GIF image", "image/gif", TypeCategory::Image),
+    ),
+    (
+        b"\x00\x00\x01\x00",
+        detected("ICO image", "image/x-icon", TypeCategory::Image),
+    ),
+    (
+        b"II*\x00",
+        detected("TIFF image", "image/tiff", TypeCategory::Image),
+    ),
+    (
+        b"MM\x00*",
+        detected("TIFF image", "image/tiff", TypeCategory::Image),
+    ),
+    (
+        b"%PDF-",
+        detected("PDF document", "application/pdf", TypeCategory::Document),
+    ),
+    (
+        b"SQLite format 3\x00",
+        detected(
+            "SQLite database",
+            "application/vnd.sqlite3",
+            TypeCategory::Binary,
+        ),
+    ),
+    (
+        b"PK\x03\x04",
+        detected("ZIP archive", "application/zip", TypeCategory::Archive),
+    ),
+    (
+        b"PK\x05\x06",
+        detected("ZIP archive", "application/zip", TypeCategory::Archive),
+    ),
+    (
+        b"7z\xbc\xaf\x27\x1c",
+        detected(
+            "7-Zip archive",
+            "application/x-7z-compressed",
+            TypeCategory::Archive,
+        ),
+    ),
+    (
+        b"\x1f\x8b",
+        detected(
+            "gzip compressed data",
+            "application/gzip",
+            TypeCategory::Archive,
+        ),
+    ),
+    (
+        b"\x28\xb5\x2f\xfd",
+        detected(
+            "Zstandard compressed data",
+            "application/zstd",
+            TypeCategory::Archive,
+        ),
+    ),
+    (
+        b"BZh",
+        detected(
+            "bzip2 compressed data",
+            "application/x-bzip2",
+            TypeCategory::Archive,
+        ),
+    ),
+    (
+        b"\xfd7zXZ\x00",
+        detected(
+            "XZ compressed data",
+            "application/x-xz",
+            TypeCategory::Archive,
+        ),
+    ),
+    (
+        b"Rar!\x1a\x07",
+        detected("RAR archive", "application/vnd.rar", TypeCategory::Archive),
+    ),
+    (
+        b"\x7fELF",
+        detected(
+            "ELF executable",
+            "application/x-executable",
+            TypeCategory::Executable,
+        ),
+    ),
+    (
+        b"\xcf\xfa\xed\xfe",
+        detected(
+            "Mach-O executable",
+            "application/x-mach-binary",
+            TypeCategory::Executable,
+        ),
+    ),
+    (
+        b"\xce\xfa\xed\xfe",
+        detected(
+            "Mach-O executable",
+            "application/x-mach-binary",
+            TypeCategory::Executable,
+        ),
+    ),
+    (
+        b"\xca\xfe\xba\xbe",
+        detected(
+            "Mach-O universal binary",
+            "application/x-mach-binary",
+            TypeCategory::Executable,
+        ),
+    ),
+    (
+        b"MZ",
+        detected(
+            "PE executable (Windows)",
+            "application/vnd.microsoft.portable-executable",
+            TypeCategory::Executable,
+        ),
+    ),
+    (
+        b"\x00asm",
+        detected(
+            "WebAssembly module",
+            "application/wasm",
+            TypeCategory::Executable,
+        ),
+    ),
+    (
+        b"ID3",
+        detected("MP3 audio", "audio/mpeg", TypeCategory::Audio),
+    ),
+    (
+        b"fLaC",
+        detected("FLAC audio", "audio/flac", TypeCategory::Audio),
+    ),
+    (
+        b"OggS",
+        detected("Ogg media", "audio/ogg", TypeCategory::Audio),
+    ),
+    (
+        b"\x1a\x45\xdf\xa3",
+        detected(
+            "Matroska/WebM video",
+            "video/x-matroska",
+            TypeCategory::Video,
+        ),
+    ),
+    (
+        b"#!",
+        detected("Script", "text/x-shellscript", TypeCategory::Text),
+    ),
+    (
+        b"%!PS",
+        detected(
+            "PostScript document",
+            "application/postscript",
+            TypeCategory::Document,
+        ),
+    ),
+];
+
+const TAR: DetectedType = detected("tar archive", "application/x-tar", TypeCategory::Archive);
+const TEXT: DetectedType = detected("Text", "text/plain", TypeCategory::Text);
+const UTF8_TEXT: DetectedType = detected("UTF-8 text", "text/plain", TypeCategory::Text);
+const EMPTY: DetectedType = detected("Empty", "application/x-empty", TypeCategory::Binary);
+const BINARY: DetectedType = detected(
+    "Binary data",
+    "application/octet-stream",
+    TypeCategory::Binary,
+);
+
+/// 앞부분 바이트로 종류 판별 (시그니처가 없으면 텍스트/바이너리로 구분)
+pub fn detect_bytes(header: &[u8]) -> DetectedType {
+    if header.is_empty() {
+        return EMPTY;
+    }
+    if let Some(kind) = detect_container(header) {
+        return kind;
+    }
+    if let Some((_, kind)) = SIGNATURES
+        .iter()
+        .find(|(magic, _)| header.starts_with(magic))
+    {
+        return *kind;
+    }
+    if header.len() >= 262 && &header[257..262] == b"ustar" {
+        return TAR;
+    }
+    detect_text(header)
+}
+
+/// 파일 앞부분을 읽어 종류 판별
+pub fn detect_path(path: &Path) -> io::Result<DetectedType> {
+    let mut header = Vec::with_capacity(SNIFF_LEN);
+    File::open(path)?
+        .take(SNIFF_LEN as u64)
+        .read_to_end(&mut header)?;
+    Ok(detect_bytes(&header))
+}
+
+/// RIFF/ISO BMFF 처럼 하위 형식 태그가 중간에 있거나 시그니처가 짧아 추가 확인이 필요한 형식
+fn detect_container(header: &[u8]) -> Option<DetectedType> {
+    // BMP: "BM" 뒤 예약 필드(6..10)가 0
+    if header.len() >= 14 && header.starts_with(b"BM") && header[6..10] == [0; 4] {
+        return Some(detected("BMP image", "image/bmp", TypeCategory::Image));
+    }
+    if header.len() >= 12 && header.starts_with(b"RIFF") {
+        return match &header[8..12] {
+            b"WEBP" => Some(detected("WebP image", "image/webp", TypeCategory::Image)),
+            b"WAVE" => Some(detected("WAV audio", "audio/wav", TypeCategory::Audio)),
+            b"AVI " => Some(detected(
+                "AVI video",
+                "video/x-msvideo",
+                TypeCategory::Video,
+            )),
+            _ => None,
+        };
+    }
+    if header.len() >= 12 && &header[4..8] == b"ftyp" {
+        return Some(match &header[8..12] {
+            b"heic" | b"heix" | b"mif1" => {
+                detected("HEIF image", "image/heif", TypeCategory::Image)
+            }
+            b"avif" => detected("AVIF image", "image/avif", TypeCategory::Image),
+            b"M4A " => detected("MPEG-4 audio", "audio/mp4", TypeCategory::Audio),
+            b"qt  " => detected("QuickTime video", "video/quicktime", TypeCategory::Video),
+            _ => detected("MPEG-4 video", "video/mp4", TypeCategory::Video),
+        });
+    }
+    None
+}
+
+/// NUL이 없고 제어 문자가 거의 없으면 텍스트 (앞부분이 잘린 UTF-8 문자는 허용)
+fn detect_text(header: &[u8]) -> DetectedType {
+    if header.contains(&0) {
+        return BINARY;
+    }
+    let text = match std::str::from_utf8(header) {
+        Ok(text) => text,
+        Err(e) if header.len() - e.valid_up_to() < 4 && e.error_len().is_none() => {
+            // 잘린 마지막 문자만 제외
+            std::str::from_utf8(&header[..e.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return BINARY,
+    };
+    let controls = text
+        .chars()
+        .filter(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t' | '\x0c'))
+        .count();
+    if controls * 10 > text.chars().count().max(1) {
+        return BINARY;
+    }
+    if text.is_ascii() {
+        TEXT
+    } else {
+        UTF8_TEXT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_common_signatures() {
+        assert_eq!(
+            detect_bytes(b"\x89PNG\r\n\x1a\n....").description,
+            "PNG image"
+        );
+        assert_eq!(detect_bytes(b"%PDF-1.7\n").description, "PDF document");
+        assert_eq!(
+            detect_bytes(b"\x7fELF\x02\x01\x01").description,
+            "ELF executable"
+        );
+        assert_eq!(
+            detect_bytes(b"RIFF\x00\x00\x00\x00WEBPVP8 ").mime,
+            "image/webp"
+        );
+        assert_eq!(
+            detect_bytes(b"\x00\x00\x00\x18ftypisom").description,
+            "MPEG-4 video"
+        );
+        assert_eq!(
+            detect_bytes(b"PK\x03\x04\x14\x00").category,
+            TypeCategory::Archive
+        );
+
+        let mut tar = vec![b'a'; 300];
+        tar[100] = 0;
+        tar[257..262].copy_from_slice(b"ustar");
+        assert_eq!(detect_bytes(&tar).description, "tar archive");
+    }
+
+    #[test]
+    fn test_detect_text_binary_and_empty() {
+        assert_eq!(detect_bytes(b"").description, "Empty");
+        assert_eq!(detect_bytes(b"hello\nworld\n").description, "Text");
+        assert_eq!(
+            detect_bytes("안녕하세요\n".as_bytes()).description,
+            "UTF-8 text"
+        );
+        // 읽기 경계에서 잘린 멀티바이트 문자
+        assert_eq!(
+            detect_bytes(&"가나".as_bytes()[..5]).description,
+            "UTF-8 text"
+        );
+        assert_eq!(detect_bytes(b"ab\x00cd").description, "Binary data");
+        assert_eq!(detect_bytes(b"#!/bin/sh\necho hi\n").description, "Script");
+    }
+}
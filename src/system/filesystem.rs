@@ -1,47 +1,378 @@
 #![allow(dead_code)]
 
 use crate::models::file_entry::{FileEntry, FileType};
-use crate::models::operation::{FlattenedEntryKind, FlattenedFile};
+use crate::models::operation::{
+    CopyOptions, FlattenedEntryKind, FlattenedFile, MoveStrategy, SymlinkPolicy,
+};
+use crate::system::diskspace::{self, DiskSpace};
+use crate::system::fast_copy;
+use crate::system::listing_cache::ListingCache;
+use crate::system::reparse;
 use crate::utils::error::{BokslDirError, Result};
+use crate::utils::scope_filter::ScopeFilter;
+use crate::utils::temp_name;
+use crate::utils::win_path::long_path;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs::{self, Metadata};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 /// 마운트 포인트 정보
 #[derive(Debug, Clone)]
 pub struct MountPoint {
     pub name: String,
     pub path: PathBuf,
+    pub fs_type: Option<String>,
+    pub space: Option<DiskSpace>,
+}
+
+impl MountPoint {
+    /// 파일시스템 종류/용량을 함께 조회해 생성
+    pub fn new(name: String, path: PathBuf) -> Self {
+        Self {
+            fs_type: diskspace::filesystem_type(&path),
+            space: diskspace::disk_space(&path),
+            name,
+            path,
+        }
+    }
+}
+
+/// 백그라운드 디렉토리 읽기에서 한 번에 넘기는 항목 수
+const LISTING_BATCH: usize = 2_000;
+
+/// 백그라운드에서 이어 읽는 디렉토리 목록
+///
+/// 읽은 항목은 공유 버퍼에 쌓이고, 핸들(패널 상태와 그 복제본)마다 가져간 위치를 따로 기억합니다.
+/// 모든 핸들이 사라지면 읽기 스레드도 멈춥니다.
+#[derive(Debug, Clone)]
+pub struct DirectoryListing {
+    shared: Arc<ListingShared>,
+    taken: usize,
+}
+
+#[derive(Debug, Default)]
+struct ListingShared {
+    entries: Mutex<Vec<FileEntry>>,
+    finished: AtomicBool,
+}
+
+impl ListingShared {
+    fn append(&self, batch: &mut Vec<FileEntry>) {
+        if batch.is_empty() {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.append(batch);
+    }
+}
+
+impl DirectoryListing {
+    /// 지난번 이후 새로 읽힌 항목과 읽기 완료 여부
+    pub fn take_new(&mut self) -> (Vec<FileEntry>, bool) {
+        // 완료 표시는 마지막 항목을 넣은 뒤에 세우므로 먼저 확인
+        let finished = self.shared.finished.load(AtomicOrdering::SeqCst);
+        let entries = self
+            .shared
+            .entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let new = entries[self.taken..].to_vec();
+        self.taken = entries.len();
+        (new, finished)
+    }
+}
+
+/// 목록을 이름만 먼저 읽고 메타데이터는 나중에 채울지 (설정 파일 `lazy_metadata`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LazyMetadata {
+    /// 네트워크 파일시스템(NFS, SMB, sshfs 등)에서만
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// 네트워크 파일시스템 종류 (항목마다 stat 하면 느린 곳)
+const NETWORK_FS_TYPES: &[&str] = &[
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "smbfs",
+    "afpfs",
+    "webdav",
+    "davfs",
+    "9p",
+    "afs",
+    "ceph",
+    "glusterfs",
+    "fuse.sshfs",
+    "fuse.rclone",
+    "fuse.s3fs",
+    "fuse.gcsfuse",
+];
+
+fn is_network_fs_type(fs_type: &str) -> bool {
+    NETWORK_FS_TYPES.contains(&fs_type)
+}
+
+/// 이름만 먼저 보여준 항목의 메타데이터(크기, 시간, 권한)를 백그라운드에서 읽음
+///
+/// 요청한 순서대로 읽어 공유 버퍼에 쌓고, 핸들마다 가져간 위치를 따로 기억합니다.
+/// 모든 핸들이 사라지면 읽기 스레드도 멈춥니다.
+#[derive(Debug, Clone)]
+pub struct MetadataHydration {
+    requests: Sender<PathBuf>,
+    results: Arc<Mutex<Vec<HydratedEntry>>>,
+    requested: usize,
+    taken: usize,
+}
+
+/// 메타데이터를 읽은 결과 (`entry`가 None이면 읽을 수 없었음)
+#[derive(Debug, Clone)]
+pub struct HydratedEntry {
+    pub path: PathBuf,
+    pub entry: Option<FileEntry>,
+}
+
+impl MetadataHydration {
+    pub fn spawn() -> Self {
+        let (requests, queue) = mpsc::channel::<PathBuf>();
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let worker = Arc::clone(&results);
+        std::thread::spawn(move || {
+            let filesystem = FileSystem::new();
+            for path in queue {
+                let entry = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .and_then(|name| filesystem.entry_from_path(path.clone(), name));
+                let mut results = worker.lock().unwrap_or_else(|e| e.into_inner());
+                results.push(HydratedEntry { path, entry });
+            }
+        });
+        Self {
+            requests,
+            results,
+            requested: 0,
+            taken: 0,
+        }
+    }
+
+    /// 메타데이터 읽기 요청 (먼저 요청한 항목부터 읽음)
+    pub fn request(&mut self, paths: impl IntoIterator<Item = PathBuf>) {
+        for path in paths {
+            if self.requests.send(path).is_err() {
+                return;
+            }
+            self.requested += 1;
+        }
+    }
+
+    /// 요청한 항목을 모두 가져갔는지
+    pub fn is_done(&self) -> bool {
+        self.taken >= self.requested
+    }
+
+    /// 지난번 이후 새로 읽힌 결과
+    pub fn take_ready(&mut self) -> Vec<HydratedEntry> {
+        let results = self.results.lock().unwrap_or_else(|e| e.into_inner());
+        let ready = results[self.taken..].to_vec();
+        self.taken = results.len();
+        ready
+    }
+}
+
+/// 평탄화 중 상태 (범위 필터, 링크 처리 방식, 따라간 링크 경로)
+struct FlattenWalk<'a> {
+    filter: &'a ScopeFilter,
+    symlinks: SymlinkPolicy,
+    result: Vec<FlattenedFile>,
+    filtered_out: usize,
+    /// 따라간 링크의 위치/대상 (정규화 경로, 순환 감지용)
+    followed: Vec<PathBuf>,
 }
 
 /// 파일 시스템 모듈
-pub struct FileSystem;
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    /// 최근 디렉토리 목록 (앱의 로컬 파일시스템만 사용)
+    listing_cache: Option<Arc<Mutex<ListingCache>>>,
+    lazy_metadata: LazyMetadata,
+}
 
 impl FileSystem {
     /// 새 파일 시스템 인스턴스 생성
     pub fn new() -> Self {
-        Self
+        Self {
+            listing_cache: None,
+            lazy_metadata: LazyMetadata::Never,
+        }
+    }
+
+    /// 최근 디렉토리 목록을 최대 `max_bytes`만큼 보관하는 인스턴스
+    pub fn with_listing_cache(max_bytes: usize) -> Self {
+        Self {
+            listing_cache: Some(Arc::new(Mutex::new(ListingCache::new(max_bytes)))),
+            lazy_metadata: LazyMetadata::default(),
+        }
+    }
+
+    pub fn lazy_metadata(&self) -> LazyMetadata {
+        self.lazy_metadata
+    }
+
+    pub fn set_lazy_metadata(&mut self, mode: LazyMetadata) {
+        self.lazy_metadata = mode;
+    }
+
+    /// 이 디렉토리 목록을 이름만 먼저 읽을지
+    fn lists_names_first(&self, path: &Path) -> bool {
+        match self.lazy_metadata {
+            LazyMetadata::Always => true,
+            LazyMetadata::Never => false,
+            LazyMetadata::Auto => {
+                diskspace::filesystem_type(path).is_some_and(|fs_type| is_network_fs_type(&fs_type))
+            }
+        }
+    }
+
+    fn cache_listing(&self, path: &Path, modified: Option<SystemTime>, entries: &[FileEntry]) {
+        if let (Some(cache), Some(modified)) = (&self.listing_cache, modified) {
+            let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+            cache.insert(path.to_path_buf(), modified, entries.to_vec());
+        }
+    }
+
+    /// 디렉토리 읽기 (보관한 목록이 있고 디렉토리가 그 뒤로 바뀌지 않았으면 그대로 사용)
+    pub fn read_directory_cached(
+        &self,
+        path: &Path,
+        sync_limit: usize,
+    ) -> Result<(Vec<FileEntry>, Option<DirectoryListing>)> {
+        if let Some(cache) = &self.listing_cache {
+            Self::check_directory(path)?;
+            if let Some(modified) = directory_modified(path) {
+                let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(entries) = cache.get(path, modified) {
+                    return Ok((entries, None));
+                }
+            }
+        }
+        self.read_directory_streaming(path, sync_limit)
     }
 
     /// 디렉토리 읽기
     ///
     /// 주어진 경로의 디렉토리를 읽어서 파일 엔트리 리스트를 반환합니다.
     pub fn read_directory(&self, path: &Path) -> Result<Vec<FileEntry>> {
-        // 1. 경로 존재 확인
+        // 1. 경로 존재 / 디렉토리 여부 확인
+        Self::check_directory(path)?;
+
+        // 2. 디렉토리 읽기
+        let read_dir = Self::open_directory(path)?;
+
+        // 3. 각 엔트리에 대해 메타데이터 파싱 (에러 발생 시 해당 엔트리는 스킵)
+        Ok(read_dir
+            .filter_map(|entry| self.entry_from_dir_entry(entry.ok()?))
+            .collect())
+    }
+
+    /// 디렉토리 읽기 (큰 디렉토리는 나눠서)
+    ///
+    /// 처음 `sync_limit`개는 바로 반환하고, 남은 항목이 있으면 백그라운드 스레드가 이어 읽어
+    /// [`DirectoryListing`]으로 넘깁니다. 네트워크 파일시스템 등 이름만 먼저 읽는 경우 항목은
+    /// `metadata_pending` 상태이며 [`MetadataHydration`]으로 나중에 채웁니다.
+    pub fn read_directory_streaming(
+        &self,
+        path: &Path,
+        sync_limit: usize,
+    ) -> Result<(Vec<FileEntry>, Option<DirectoryListing>)> {
+        Self::check_directory(path)?;
+        // 읽는 도중 바뀌면 다음 조회 때 수정 시간이 달라 캐시를 쓰지 않도록 먼저 기록
+        let modified = directory_modified(path);
+        let names_first = self.lists_names_first(path);
+        let mut read_dir = Self::open_directory(path)?;
+        let mut entries = Vec::new();
+        while entries.len() < sync_limit {
+            let Some(entry) = read_dir.next() else {
+                if !names_first {
+                    self.cache_listing(path, modified, &entries);
+                }
+                return Ok((entries, None));
+            };
+            if let Some(entry) = entry
+                .ok()
+                .and_then(|entry| self.read_entry(entry, names_first))
+            {
+                entries.push(entry);
+            }
+        }
+
+        let shared = Arc::new(ListingShared::default());
+        let worker = Arc::clone(&shared);
+        let cache = self
+            .listing_cache
+            .as_ref()
+            .filter(|_| !names_first)
+            .map(|_| (self.clone(), path.to_path_buf(), entries.clone()));
+        std::thread::spawn(move || {
+            let filesystem = FileSystem::new();
+            let mut batch = Vec::with_capacity(LISTING_BATCH);
+            for entry in read_dir {
+                // 목록을 기다리는 패널이 없으면 중단
+                if Arc::strong_count(&worker) == 1 {
+                    return;
+                }
+                if let Some(entry) = entry
+                    .ok()
+                    .and_then(|entry| filesystem.read_entry(entry, names_first))
+                {
+                    batch.push(entry);
+                }
+                if batch.len() >= LISTING_BATCH {
+                    worker.append(&mut batch);
+                }
+            }
+            worker.append(&mut batch);
+            if let Some((filesystem, path, mut all)) = cache {
+                all.extend(
+                    worker
+                        .entries
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .iter()
+                        .cloned(),
+                );
+                filesystem.cache_listing(&path, modified, &all);
+            }
+            worker.finished.store(true, AtomicOrdering::SeqCst);
+        });
+        Ok((entries, Some(DirectoryListing { shared, taken: 0 })))
+    }
+
+    fn check_directory(path: &Path) -> Result<()> {
         if !path.exists() {
             return Err(BokslDirError::PathNotFound {
                 path: path.to_path_buf(),
             });
         }
-
-        // 2. 디렉토리 여부 확인
         if !path.is_dir() {
             return Err(BokslDirError::NotADirectory {
                 path: path.to_path_buf(),
             });
         }
+        Ok(())
+    }
 
-        // 3. 디렉토리 읽기
-        let read_dir = fs::read_dir(path).map_err(|e| {
+    fn open_directory(path: &Path) -> Result<fs::ReadDir> {
+        fs::read_dir(long_path(path)).map_err(|e| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 BokslDirError::PermissionDenied {
                     path: path.to_path_buf(),
@@ -49,83 +380,117 @@ impl FileSystem {
             } else {
                 BokslDirError::Io(e)
             }
-        })?;
-
-        // 4. 각 엔트리에 대해 메타데이터 파싱
-        let mut entries = Vec::new();
-
-        for entry in read_dir {
-            // 에러 발생 시 해당 엔트리는 스킵
-            let Ok(entry) = entry else { continue };
+        })
+    }
 
-            let entry_path = entry.path();
+    fn read_entry(&self, entry: fs::DirEntry, names_first: bool) -> Option<FileEntry> {
+        if names_first {
+            self.entry_without_metadata(entry)
+        } else {
+            self.entry_from_dir_entry(entry)
+        }
+    }
 
-            // 링크 자체 메타데이터
-            let Ok(link_metadata) = fs::symlink_metadata(&entry_path) else {
-                continue;
-            };
+    /// 이름과 종류만 채운 항목 (디렉토리 읽기 결과만 사용, 항목마다 stat 하지 않음)
+    fn entry_without_metadata(&self, entry: fs::DirEntry) -> Option<FileEntry> {
+        let entry_path = entry.path();
+        let kind = entry.file_type().ok()?;
+        let file_type = if kind.is_dir() {
+            FileType::Directory
+        } else if kind.is_symlink() {
+            FileType::Symlink
+        } else {
+            FileType::File
+        };
+        let mut file_entry = FileEntry::new(
+            entry.file_name().to_string_lossy().to_string(),
+            entry_path.clone(),
+            file_type,
+            0,
+            std::time::UNIX_EPOCH,
+            std::time::UNIX_EPOCH,
+            None,
+            self.is_hidden(&entry_path),
+        );
+        file_entry.metadata_pending = true;
+        Some(file_entry)
+    }
 
-            // 파일 이름
-            let name = entry.file_name().to_string_lossy().to_string();
+    /// 디렉토리 항목 하나의 메타데이터 파싱 (읽을 수 없으면 None)
+    fn entry_from_dir_entry(&self, entry: fs::DirEntry) -> Option<FileEntry> {
+        let name = entry.file_name().to_string_lossy().to_string();
+        self.entry_from_path(entry.path(), name)
+    }
 
-            // 파일 타입 판단
-            let file_type = self.get_file_type(&entry_path, &link_metadata);
+    fn entry_from_path(&self, entry_path: PathBuf, name: String) -> Option<FileEntry> {
+        // 링크 자체 메타데이터
+        let link_metadata = fs::symlink_metadata(&entry_path).ok()?;
+        let reparse = reparse::reparse_kind(&entry_path, &link_metadata);
 
-            // 표시용 메타데이터 (symlink는 대상 메타데이터 우선)
-            let display_metadata = if file_type == FileType::Symlink {
-                fs::metadata(&entry_path).ok().unwrap_or(link_metadata)
-            } else {
-                link_metadata
-            };
+        // 파일 타입 판단
+        let file_type = self.get_file_type(&entry_path, &link_metadata);
 
-            // 크기 (디렉토리/symlink 디렉토리는 0)
-            let size = match file_type {
-                FileType::Directory => 0,
-                FileType::Symlink => {
-                    if display_metadata.is_file() {
-                        display_metadata.len()
-                    } else {
-                        0
-                    }
+        // 표시용 메타데이터 (symlink는 대상 메타데이터 우선, 대상이 없으면 깨진 링크)
+        let target_metadata = if file_type == FileType::Symlink {
+            fs::metadata(&entry_path).ok()
+        } else {
+            None
+        };
+        let is_broken_symlink = file_type == FileType::Symlink && target_metadata.is_none();
+        let display_metadata = target_metadata.unwrap_or(link_metadata);
+
+        // 크기 (디렉토리/symlink 디렉토리는 0)
+        let size = match file_type {
+            FileType::Directory => 0,
+            FileType::Symlink => {
+                if display_metadata.is_file() {
+                    display_metadata.len()
+                } else {
+                    0
                 }
-                _ => display_metadata.len(),
-            };
-
-            // 수정 시간
-            let modified = display_metadata
-                .modified()
-                .unwrap_or_else(|_| std::time::SystemTime::now());
-            // 생성 시간 (없으면 수정 시간 fallback)
-            let created = display_metadata.created().unwrap_or(modified);
-
-            // 권한 (Unix 계열에서만)
-            let permissions = Some(display_metadata.permissions());
-
-            // 숨김 파일 여부
-            let is_hidden = self.is_hidden(&entry_path);
-
-            let mut file_entry = FileEntry::new(
-                name,
-                entry_path,
-                file_type,
-                size,
-                modified,
-                created,
-                permissions,
-                is_hidden,
-            );
-
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::MetadataExt;
-                file_entry.owner = Some(display_metadata.uid().to_string());
-                file_entry.group = Some(display_metadata.gid().to_string());
             }
+            _ => display_metadata.len(),
+        };
 
-            entries.push(file_entry);
+        // 수정 시간
+        let modified = display_metadata
+            .modified()
+            .unwrap_or_else(|_| std::time::SystemTime::now());
+        // 생성 시간 (없으면 수정 시간 fallback)
+        let created = display_metadata.created().unwrap_or(modified);
+
+        // 권한 (Unix 계열에서만)
+        let permissions = Some(display_metadata.permissions());
+
+        // 숨김 파일 여부
+        let is_hidden = self.is_hidden(&entry_path);
+
+        let mut file_entry = FileEntry::new(
+            name,
+            entry_path,
+            file_type,
+            size,
+            modified,
+            created,
+            permissions,
+            is_hidden,
+        );
+        file_entry.is_broken_symlink = is_broken_symlink;
+        file_entry.reparse = reparse;
+        if file_type == FileType::Symlink {
+            file_entry.link_target = fs::read_link(&file_entry.path).ok();
         }
 
-        Ok(entries)
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            file_entry.owner = Some(display_metadata.uid().to_string());
+            file_entry.group = Some(display_metadata.gid().to_string());
+            file_entry.links = Some(display_metadata.nlink());
+            file_entry.inode = Some(display_metadata.ino());
+        }
+
+        Some(file_entry)
     }
 
     /// 파일 타입 판단
@@ -236,6 +601,42 @@ impl FileSystem {
         }
     }
 
+    /// OS 파일 관리자(Finder, 탐색기, xdg-open)에서 항목 표시
+    ///
+    /// Finder와 탐색기는 항목을 선택한 상태로 열고, 그 밖의 환경은 항목이 있는
+    /// 디렉토리를 엽니다.
+    pub fn reveal_in_file_manager(&self, path: &Path) -> Result<()> {
+        use std::process::{Command, Stdio};
+
+        if !path.exists() {
+            return Err(BokslDirError::PathNotFound {
+                path: path.to_path_buf(),
+            });
+        }
+        let (program, args) = reveal_command(path);
+        let open_failed = |reason: String| BokslDirError::ExternalOpenFailed {
+            path: path.to_path_buf(),
+            reason,
+        };
+        // 파일 관리자 출력이 TUI 화면을 덮지 않도록 표준 입출력을 닫음
+        let status = Command::new(program)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map_err(|e| open_failed(format!("{}: {}", program, e)))?;
+        // explorer.exe는 성공해도 0이 아닌 종료 코드를 돌려줌
+        if status.success() || cfg!(windows) {
+            Ok(())
+        } else {
+            Err(open_failed(format!(
+                "{} exited with status {}",
+                program, status
+            )))
+        }
+    }
+
     // === Phase 5.3: 마운트 포인트 ===
 
     /// 시스템 마운트 포인트 목록 반환
@@ -244,23 +645,33 @@ impl FileSystem {
         let mut points = Vec::new();
 
         // 홈 디렉토리
-        if let Ok(home) = std::env::var("HOME") {
-            let home_path = PathBuf::from(&home);
+        if let Some(home_path) = dirs::home_dir() {
             if home_path.is_dir() {
-                points.push(MountPoint {
-                    name: format!("~ ({})", home),
-                    path: home_path,
-                });
+                let name = format!("~ ({})", home_path.display());
+                points.push(MountPoint::new(name, home_path));
             }
         }
 
         // 루트
-        let root = PathBuf::from("/");
-        if root.is_dir() {
-            points.push(MountPoint {
-                name: "/".to_string(),
-                path: root,
-            });
+        #[cfg(not(windows))]
+        {
+            let root = PathBuf::from("/");
+            if root.is_dir() {
+                points.push(MountPoint::new("/".to_string(), root));
+            }
+        }
+
+        // Windows: 드라이브 문자 (볼륨 이름 표시)
+        #[cfg(windows)]
+        {
+            for drive in diskspace::logical_drives() {
+                let letter = drive.to_string_lossy().trim_end_matches('\\').to_string();
+                let name = match diskspace::volume_information(&drive) {
+                    Some((label, _)) if !label.is_empty() => format!("{} {}", letter, label),
+                    _ => letter,
+                };
+                points.push(MountPoint::new(name, drive));
+            }
         }
 
         // macOS: /Volumes/*
@@ -272,10 +683,7 @@ impl FileSystem {
                     let path = entry.path();
                     if path.is_dir() {
                         let name = entry.file_name().to_string_lossy().to_string();
-                        points.push(MountPoint {
-                            name: format!("/Volumes/{}", name),
-                            path,
-                        });
+                        points.push(MountPoint::new(format!("/Volumes/{}", name), path));
                     }
                 }
             }
@@ -291,7 +699,7 @@ impl FileSystem {
                         let path = entry.path();
                         if path.is_dir() {
                             let name = path.to_string_lossy().to_string();
-                            points.push(MountPoint { name, path });
+                            points.push(MountPoint::new(name, path));
                         }
                     }
                 }
@@ -324,7 +732,7 @@ impl FileSystem {
         }
 
         // 복사 실행
-        fs::copy(src, dest).map_err(|e| {
+        fs::copy(long_path(src), long_path(dest)).map_err(|e| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 BokslDirError::PermissionDenied {
                     path: dest.to_path_buf(),
@@ -339,6 +747,92 @@ impl FileSystem {
         })
     }
 
+    /// 옵션에 따라 시각/권한/xattr을 유지하며 파일 복사 (권한을 유지하지 않으면 umask 기준)
+    pub fn copy_file_with_options(
+        &self,
+        src: &Path,
+        dest: &Path,
+        options: &CopyOptions,
+    ) -> Result<u64> {
+        // 읽기 전에 원본 접근 시각 확보 (복사 중 갱신됨)
+        let src_metadata = fs::metadata(src).ok();
+        let bytes = if options.preserve_permissions {
+            self.copy_file(src, dest)?
+        } else {
+            if src == dest {
+                return Err(BokslDirError::SameSourceAndDest {
+                    path: src.to_path_buf(),
+                });
+            }
+            let mut reader =
+                fs::File::open(long_path(src)).map_err(|e| copy_io_error(src, dest, e))?;
+            let mut writer =
+                fs::File::create(long_path(dest)).map_err(|e| copy_io_error(src, dest, e))?;
+            std::io::copy(&mut reader, &mut writer).map_err(|e| copy_io_error(src, dest, e))?
+        };
+        if let Some(metadata) = src_metadata {
+            apply_copy_metadata(&metadata, src, dest, options)?;
+        }
+        Ok(bytes)
+    }
+
+    /// reflink(copy-on-write) 복제 후 옵션에 따라 메타데이터 적용
+    ///
+    /// 같은 파일시스템의 대용량 파일 복사에 사용합니다. 복제할 수 없으면 `Ok(None)`을
+    /// 반환하므로 호출자는 분할 복사로 대체합니다. 대상이 원본과 같은 파일(하드 링크,
+    /// 링크 디렉토리 경유)이면 어느 쪽도 건드리지 않고 `SameSourceAndDest`를 반환합니다.
+    /// 복제본 권한은 일반 복사와 같습니다(새 파일은 umask 기본값, 기존 대상은 그대로). 작은 파일은 `fs::copy`가
+    /// 플랫폼별 복제(copy_file_range/fclonefileat)를 이미 시도합니다.
+    pub fn try_clone_file(
+        &self,
+        src: &Path,
+        dest: &Path,
+        options: &CopyOptions,
+    ) -> Result<Option<u64>> {
+        let Ok(src_metadata) = fs::metadata(src) else {
+            return Ok(None);
+        };
+        if src == dest || fast_copy::same_file(src, dest) {
+            return Err(BokslDirError::SameSourceAndDest {
+                path: src.to_path_buf(),
+            });
+        }
+        if !fast_copy::reflink(src, dest) {
+            return Ok(None);
+        }
+        apply_copy_metadata(&src_metadata, src, dest, options)?;
+        Ok(Some(src_metadata.len()))
+    }
+
+    /// 파일 내용 SHA-256 해시 (COPY_CHUNK_SIZE 단위로 읽음)
+    pub fn file_hash(&self, path: &Path) -> Result<[u8; 32]> {
+        use sha2::{Digest, Sha256};
+        use std::io::Read;
+
+        let mut file = fs::File::open(path)?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0; COPY_CHUNK_SIZE];
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    /// 복사 검증: 원본과 대상을 다시 읽어 해시 비교
+    pub fn verify_copy(&self, src: &Path, dest: &Path) -> Result<()> {
+        if self.file_hash(src)? != self.file_hash(dest)? {
+            return Err(BokslDirError::VerifyFailed {
+                src: src.to_path_buf(),
+                dest: dest.to_path_buf(),
+            });
+        }
+        Ok(())
+    }
+
     /// 디렉토리 재귀 복사
     ///
     /// 소스 디렉토리를 대상 경로로 재귀적으로 복사합니다.
@@ -365,7 +859,7 @@ impl FileSystem {
         }
 
         // 대상 디렉토리 생성
-        fs::create_dir_all(dest).map_err(|e| {
+        fs::create_dir_all(long_path(dest)).map_err(|e| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 BokslDirError::PermissionDenied {
                     path: dest.to_path_buf(),
@@ -382,7 +876,7 @@ impl FileSystem {
         let mut total_bytes = 0u64;
 
         // 소스 디렉토리 내용 순회
-        for entry in fs::read_dir(src).map_err(BokslDirError::Io)? {
+        for entry in fs::read_dir(long_path(src)).map_err(BokslDirError::Io)? {
             let entry = entry.map_err(BokslDirError::Io)?;
             let entry_path = entry.path();
             let file_name = entry.file_name();
@@ -407,6 +901,14 @@ impl FileSystem {
     /// 반환값: 이동된 바이트 수
     #[allow(clippy::unused_self)]
     pub fn move_file(&self, src: &Path, dest: &Path) -> Result<u64> {
+        self.move_file_with_strategy(src, dest)
+            .map(|(bytes, _)| bytes)
+    }
+
+    /// 파일 이동 (사용한 이동 방식 포함)
+    ///
+    /// 반환값: (이동된 바이트 수, 이동 방식)
+    pub fn move_file_with_strategy(&self, src: &Path, dest: &Path) -> Result<(u64, MoveStrategy)> {
         // 소스와 대상이 동일한지 확인
         if src == dest {
             return Err(BokslDirError::SameSourceAndDest {
@@ -425,19 +927,70 @@ impl FileSystem {
         let file_size = src.metadata().map(|m| m.len()).unwrap_or(0);
 
         // 먼저 rename 시도 (같은 파일시스템 내에서는 빠름)
-        if fs::rename(src, dest).is_ok() {
-            return Ok(file_size);
+        if self.try_rename(src, dest)? {
+            return Ok((file_size, MoveStrategy::Rename));
         }
 
-        // rename 실패 시 복사 후 삭제
-        self.copy_file(src, dest)?;
-        fs::remove_file(src).map_err(|e| BokslDirError::MoveFailed {
+        // 다른 파일시스템이면 복사 후 삭제 (메타데이터 유지)
+        self.copy_file_with_options(src, dest, &CopyOptions::preserve_all())?;
+        fs::remove_file(long_path(src)).map_err(|e| BokslDirError::MoveFailed {
             src: src.to_path_buf(),
             dest: dest.to_path_buf(),
             reason: format!("Failed to remove source after copy: {}", e),
         })?;
 
-        Ok(file_size)
+        Ok((file_size, MoveStrategy::CopyDelete))
+    }
+
+    /// 심볼릭 링크를 같은 대상을 가리키는 링크로 복사 (대상이 없는 링크도 그대로)
+    pub fn copy_symlink(&self, src: &Path, dest: &Path) -> Result<u64> {
+        let copy_failed = |e: std::io::Error| BokslDirError::CopyFailed {
+            src: src.to_path_buf(),
+            dest: dest.to_path_buf(),
+            reason: e.to_string(),
+        };
+        let target = fs::read_link(src).map_err(copy_failed)?;
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&target, dest).map_err(copy_failed)?;
+        #[cfg(windows)]
+        {
+            if fs::metadata(src).is_ok_and(|m| m.is_dir()) {
+                std::os::windows::fs::symlink_dir(&target, dest).map_err(copy_failed)?;
+            } else {
+                std::os::windows::fs::symlink_file(&target, dest).map_err(copy_failed)?;
+            }
+        }
+        Ok(0)
+    }
+
+    /// 심볼릭 링크 이동 (다른 파일시스템이면 링크를 새로 만들고 원본 링크 삭제)
+    pub fn move_symlink(&self, src: &Path, dest: &Path) -> Result<(u64, MoveStrategy)> {
+        if self.try_rename(src, dest)? {
+            return Ok((0, MoveStrategy::Rename));
+        }
+        self.copy_symlink(src, dest)?;
+        fs::remove_file(long_path(src)).map_err(|e| BokslDirError::MoveFailed {
+            src: src.to_path_buf(),
+            dest: dest.to_path_buf(),
+            reason: format!("Failed to remove source after copy: {}", e),
+        })?;
+        Ok((0, MoveStrategy::CopyDelete))
+    }
+
+    /// rename 시도
+    ///
+    /// 반환값: 성공 시 true, 다른 파일시스템(EXDEV)이라 복사 후 삭제가 필요하면 false.
+    /// 그 외 실패(권한 등)는 복사로 대체하지 않고 에러로 반환합니다.
+    pub fn try_rename(&self, src: &Path, dest: &Path) -> Result<bool> {
+        match fs::rename(long_path(src), long_path(dest)) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => Ok(false),
+            Err(e) => Err(BokslDirError::MoveFailed {
+                src: src.to_path_buf(),
+                dest: dest.to_path_buf(),
+                reason: e.to_string(),
+            }),
+        }
     }
 
     /// 디렉토리 이동
@@ -470,13 +1023,13 @@ impl FileSystem {
         let (total_bytes, _) = self.calculate_total_size(&[src.to_path_buf()])?;
 
         // 먼저 rename 시도 (같은 파일시스템 내에서는 빠름)
-        if fs::rename(src, dest).is_ok() {
+        if fs::rename(long_path(src), long_path(dest)).is_ok() {
             return Ok(total_bytes);
         }
 
         // rename 실패 시 복사 후 삭제
         self.copy_directory(src, dest)?;
-        fs::remove_dir_all(src).map_err(|e| BokslDirError::MoveFailed {
+        fs::remove_dir_all(long_path(src)).map_err(|e| BokslDirError::MoveFailed {
             src: src.to_path_buf(),
             dest: dest.to_path_buf(),
             reason: format!("Failed to remove source after copy: {}", e),
@@ -495,7 +1048,7 @@ impl FileSystem {
         for path in paths {
             let metadata = fs::symlink_metadata(path).map_err(BokslDirError::Io)?;
 
-            if metadata.file_type().is_symlink() {
+            if reparse::is_link(path, &metadata) {
                 total_bytes += self.symlink_target_file_size(path);
                 total_files += 1;
             } else if metadata.is_file() {
@@ -521,7 +1074,7 @@ impl FileSystem {
             let entry_path = entry.path();
             let metadata = fs::symlink_metadata(&entry_path).map_err(BokslDirError::Io)?;
 
-            if metadata.file_type().is_symlink() {
+            if reparse::is_link(&entry_path, &metadata) {
                 total_bytes += self.symlink_target_file_size(&entry_path);
                 total_files += 1;
             } else if metadata.is_file() {
@@ -558,7 +1111,7 @@ impl FileSystem {
 
         let size = path.metadata().map(|m| m.len()).unwrap_or(0);
 
-        fs::remove_file(path).map_err(|e| {
+        fs::remove_file(long_path(path)).map_err(|e| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 BokslDirError::PermissionDenied {
                     path: path.to_path_buf(),
@@ -593,7 +1146,7 @@ impl FileSystem {
         // 삭제 전 크기 계산
         let (total_bytes, _) = self.calculate_total_size(&[path.to_path_buf()])?;
 
-        fs::remove_dir_all(path).map_err(|e| {
+        fs::remove_dir_all(long_path(path)).map_err(|e| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 BokslDirError::PermissionDenied {
                     path: path.to_path_buf(),
@@ -618,9 +1171,28 @@ impl FileSystem {
         })
     }
 
+    /// 휴지통으로 이동하고 옮기지 못한 항목과 이유 반환
+    ///
+    /// 한 번에 안 되면(휴지통이 없는 네트워크 마운트 등) 아직 남은 항목만 하나씩 다시 시도합니다.
+    #[allow(clippy::unused_self)]
+    pub fn trash_items_each(&self, paths: &[PathBuf]) -> Vec<(PathBuf, String)> {
+        if trash::delete_all(paths).is_ok() {
+            return Vec::new();
+        }
+        paths
+            .iter()
+            .filter(|path| fs::symlink_metadata(path).is_ok())
+            .filter_map(|path| {
+                trash::delete(path)
+                    .err()
+                    .map(|e| (path.clone(), e.to_string()))
+            })
+            .collect()
+    }
+
     // === Phase 3.4: 디렉토리 생성, 이름 변경 ===
 
-    /// 새 디렉토리 생성
+    /// 새 디렉토리 생성 (없는 중간 디렉토리도 함께)
     #[allow(clippy::unused_self)]
     pub fn create_directory(&self, path: &Path) -> Result<()> {
         if path.exists() {
@@ -629,7 +1201,7 @@ impl FileSystem {
             });
         }
 
-        fs::create_dir(path).map_err(|e| {
+        fs::create_dir_all(long_path(path)).map_err(|e| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 BokslDirError::PermissionDenied {
                     path: path.to_path_buf(),
@@ -649,13 +1221,7 @@ impl FileSystem {
             });
         }
 
-        if dest.exists() {
-            return Err(BokslDirError::FileExists {
-                path: dest.to_path_buf(),
-            });
-        }
-
-        fs::rename(src, dest).map_err(|e| {
+        let map_error = |e: std::io::Error| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 BokslDirError::PermissionDenied {
                     path: src.to_path_buf(),
@@ -667,7 +1233,42 @@ impl FileSystem {
                     reason: e.to_string(),
                 }
             }
-        })
+        };
+
+        if dest.exists() {
+            // 대소문자 구분 없는 파일시스템에서 대소문자만 바꾸면 대상이 자기 자신으로 보임
+            if is_case_only_alias(src, dest) {
+                return rename_via_temp(src, dest).map_err(map_error);
+            }
+            return Err(BokslDirError::FileExists {
+                path: dest.to_path_buf(),
+            });
+        }
+
+        fs::rename(long_path(src), long_path(dest)).map_err(map_error)
+    }
+
+    /// 디렉토리가 대소문자를 구분하지 않는 파일시스템에 있음 (macOS/Windows 기본값)
+    ///
+    /// 디렉토리 안의 항목(없으면 디렉토리 자신)을 대소문자를 바꾼 이름으로 찾아 보고, 대소문자가
+    /// 있는 이름이 없으면 플랫폼 기본값을 씁니다.
+    #[allow(clippy::unused_self)]
+    pub fn is_case_insensitive(&self, dir: &Path) -> bool {
+        let probe = fs::read_dir(dir)
+            .ok()
+            .and_then(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .find(|path| swap_case_file_name(path).is_some())
+            })
+            .or_else(|| Some(dir.to_path_buf()).filter(|dir| swap_case_file_name(dir).is_some()));
+        match probe {
+            Some(path) => swap_case_file_name(&path).is_some_and(|swapped| {
+                fs::symlink_metadata(&swapped).is_ok() && !has_exact_entry(&swapped)
+            }),
+            None => cfg!(any(target_os = "macos", windows)),
+        }
     }
 
     /// 소스 목록을 평탄화하여 개별 파일 목록 생성
@@ -678,44 +1279,134 @@ impl FileSystem {
         sources: &[std::path::PathBuf],
         dest_dir: &Path,
     ) -> Result<Vec<FlattenedFile>> {
-        let mut result = Vec::new();
+        self.flatten_sources_filtered(
+            sources,
+            dest_dir,
+            &ScopeFilter::default(),
+            SymlinkPolicy::Links,
+        )
+        .map(|(files, _)| files)
+    }
+
+    /// 범위 필터와 심볼릭 링크 처리 방식을 적용해 평탄화 (걸러낸 항목 수 함께 반환)
+    ///
+    /// 제외한 디렉토리는 읽지 않고 1개로 셉니다. 포함 패턴이 있으면 남은 파일이 없는
+    /// 디렉토리도 걸러냅니다. 링크를 따라갈 때 상위 디렉토리를 가리키는 링크는 들어가지
+    /// 않고 `SymlinkDirectory`로 남깁니다. 건너뛴 링크도 걸러낸 항목 수에 셉니다.
+    pub fn flatten_sources_filtered(
+        &self,
+        sources: &[std::path::PathBuf],
+        dest_dir: &Path,
+        filter: &ScopeFilter,
+        symlinks: SymlinkPolicy,
+    ) -> Result<(Vec<FlattenedFile>, usize)> {
+        let mut walk = FlattenWalk {
+            filter,
+            symlinks,
+            result: Vec::new(),
+            filtered_out: 0,
+            followed: Vec::new(),
+        };
 
         for source in sources {
             let file_name = source.file_name().unwrap_or_default();
             let dest_base = dest_dir.join(file_name);
             let metadata = fs::symlink_metadata(source).map_err(BokslDirError::Io)?;
+            let is_dir = metadata.is_dir() && !reparse::is_link(source, &metadata);
+            if !filter.allows(Path::new(file_name), is_dir) {
+                walk.filtered_out += 1;
+                continue;
+            }
+            self.flatten_entry(source, source, &dest_base, &dest_base, &metadata, &mut walk)?;
+        }
 
-            if metadata.file_type().is_symlink() {
-                let entry_kind = self.classify_symlink_entry_kind(source);
-                result.push(FlattenedFile {
-                    entry_kind,
-                    source: source.clone(),
-                    dest: dest_base,
-                    size: if entry_kind == FlattenedEntryKind::SymlinkFile {
-                        self.symlink_target_file_size(source)
-                    } else {
-                        0
-                    },
-                });
-            } else if metadata.is_file() {
-                result.push(FlattenedFile {
-                    entry_kind: FlattenedEntryKind::File,
-                    source: source.clone(),
-                    dest: dest_base,
-                    size: metadata.len(),
-                });
-            } else if metadata.is_dir() {
-                result.push(FlattenedFile {
-                    entry_kind: FlattenedEntryKind::Directory,
-                    source: source.clone(),
-                    dest: dest_base.clone(),
-                    size: 0,
-                });
-                self.flatten_directory(source, source, &dest_base, &mut result)?;
+        let FlattenWalk {
+            mut result,
+            mut filtered_out,
+            ..
+        } = walk;
+        if filter.has_includes() {
+            let mut used_dirs = HashSet::new();
+            for file in &result {
+                if file.entry_kind != FlattenedEntryKind::Directory {
+                    used_dirs.extend(
+                        file.dest
+                            .ancestors()
+                            .skip(1)
+                            .take_while(|dir| *dir != dest_dir)
+                            .map(Path::to_path_buf),
+                    );
+                }
             }
+            let before = result.len();
+            result.retain(|file| {
+                file.entry_kind != FlattenedEntryKind::Directory || used_dirs.contains(&file.dest)
+            });
+            filtered_out += before - result.len();
         }
 
-        Ok(result)
+        Ok((result, filtered_out))
+    }
+
+    /// 항목 하나를 평탄화 (디렉토리면 안쪽까지)
+    fn flatten_entry(
+        &self,
+        base_source: &Path,
+        source: &Path,
+        dest: &Path,
+        dest_base: &Path,
+        metadata: &Metadata,
+        walk: &mut FlattenWalk<'_>,
+    ) -> Result<()> {
+        let push = |walk: &mut FlattenWalk<'_>, entry_kind, size| {
+            walk.result.push(FlattenedFile {
+                entry_kind,
+                source: source.to_path_buf(),
+                dest: dest.to_path_buf(),
+                size,
+            })
+        };
+        if reparse::is_link(source, metadata) {
+            let entry_kind = self.classify_symlink_entry_kind(source);
+            match walk.symlinks {
+                SymlinkPolicy::Skip => walk.filtered_out += 1,
+                SymlinkPolicy::Links => push(walk, entry_kind, 0),
+                SymlinkPolicy::Follow if entry_kind == FlattenedEntryKind::SymlinkFile => {
+                    // 대상이 없는 링크는 그대로 두어 복사할 때 오류로 알림
+                    match fs::metadata(source) {
+                        Ok(target) => push(walk, FlattenedEntryKind::File, target.len()),
+                        Err(_) => push(walk, entry_kind, 0),
+                    }
+                }
+                SymlinkPolicy::Follow => {
+                    // 지나온 디렉토리(자신 포함)를 가리키면 끝없이 돌므로 들어가지 않음
+                    let target = fs::canonicalize(source).map_err(BokslDirError::Io)?;
+                    let parent = source.parent().and_then(|p| fs::canonicalize(p).ok());
+                    let loops = walk
+                        .followed
+                        .iter()
+                        .chain(parent.as_ref())
+                        .any(|dir| dir.starts_with(&target));
+                    if loops {
+                        push(walk, FlattenedEntryKind::SymlinkDirectory, 0);
+                        return Ok(());
+                    }
+                    push(walk, FlattenedEntryKind::Directory, 0);
+                    let depth = walk.followed.len();
+                    walk.followed.extend(parent);
+                    walk.followed.push(target);
+                    let walked = self.flatten_directory(base_source, source, dest_base, walk);
+                    walk.followed.truncate(depth);
+                    walked?;
+                }
+            }
+        } else if metadata.is_file() {
+            push(walk, FlattenedEntryKind::File, metadata.len());
+        } else if metadata.is_dir() {
+            push(walk, FlattenedEntryKind::Directory, 0);
+            self.flatten_directory(base_source, source, dest_base, walk)?;
+        }
+        Ok(())
     }
 
     /// 디렉토리를 재귀적으로 평탄화
@@ -724,7 +1415,7 @@ impl FileSystem {
         base_source: &Path,
         current_source: &Path,
         dest_base: &Path,
-        result: &mut Vec<FlattenedFile>,
+        walk: &mut FlattenWalk<'_>,
     ) -> Result<()> {
         for entry in fs::read_dir(current_source).map_err(BokslDirError::Io)? {
             let entry = entry.map_err(BokslDirError::Io)?;
@@ -737,35 +1428,26 @@ impl FileSystem {
                 .unwrap_or(&entry_path)
                 .to_path_buf();
             let dest_path = dest_base.join(&relative);
-
-            if metadata.file_type().is_symlink() {
-                let entry_kind = self.classify_symlink_entry_kind(&entry_path);
-                result.push(FlattenedFile {
-                    entry_kind,
-                    source: entry_path.clone(),
-                    dest: dest_path,
-                    size: if entry_kind == FlattenedEntryKind::SymlinkFile {
-                        self.symlink_target_file_size(&entry_path)
-                    } else {
-                        0
-                    },
-                });
-            } else if metadata.is_file() {
-                result.push(FlattenedFile {
-                    entry_kind: FlattenedEntryKind::File,
-                    source: entry_path,
-                    dest: dest_path,
-                    size: metadata.len(),
-                });
-            } else if metadata.is_dir() {
-                result.push(FlattenedFile {
-                    entry_kind: FlattenedEntryKind::Directory,
-                    source: entry_path.clone(),
-                    dest: dest_path.clone(),
-                    size: 0,
-                });
-                self.flatten_directory(base_source, &entry_path, dest_base, result)?;
+            let is_link = reparse::is_link(&entry_path, &metadata);
+            let scope_path = base_source
+                .file_name()
+                .map_or_else(|| relative.clone(), |name| Path::new(name).join(&relative));
+            if !walk
+                .filter
+                .allows(&scope_path, metadata.is_dir() && !is_link)
+            {
+                walk.filtered_out += 1;
+                continue;
             }
+
+            self.flatten_entry(
+                base_source,
+                &entry_path,
+                &dest_path,
+                dest_base,
+                &metadata,
+                walk,
+            )?;
         }
 
         Ok(())
@@ -804,6 +1486,358 @@ impl Default for FileSystem {
     }
 }
 
+/// 갱신 모드 복사에서 이미 있는 대상 파일 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateCheck {
+    /// 크기가 같고 수정 시각이 같거나 새로움 (건너뜀)
+    UpToDate,
+    /// 원본보다 오래됨 (묻지 않고 덮어씀)
+    Outdated,
+    /// 대상이 없거나, 더 새롭지만 크기가 다르거나, 시각을 읽을 수 없음 (평소처럼 처리)
+    Other,
+}
+
+/// 원본과 대상 파일의 크기/수정 시각 비교
+pub fn update_check(source: &Path, dest: &Path) -> UpdateCheck {
+    let (Ok(source_meta), Ok(dest_meta)) = (fs::metadata(source), fs::metadata(dest)) else {
+        return UpdateCheck::Other;
+    };
+    if !dest_meta.is_file() {
+        return UpdateCheck::Other;
+    }
+    match (source_meta.modified(), dest_meta.modified()) {
+        (Ok(source_time), Ok(dest_time)) if dest_time < source_time => UpdateCheck::Outdated,
+        (Ok(_), Ok(_)) if source_meta.len() == dest_meta.len() => UpdateCheck::UpToDate,
+        _ => UpdateCheck::Other,
+    }
+}
+
+/// 디렉토리 자체의 수정 시간 (항목이 추가/삭제/이름 변경되면 바뀜)
+fn directory_modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).ok()?.modified().ok()
+}
+
+/// 파일 이름의 대소문자를 뒤집은 경로 (바뀌는 글자가 없으면 None)
+fn swap_case_file_name(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let swapped: String = name
+        .chars()
+        .map(|c| {
+            if c.is_uppercase() {
+                c.to_lowercase().next().unwrap_or(c)
+            } else {
+                c.to_uppercase().next().unwrap_or(c)
+            }
+        })
+        .collect();
+    (swapped != name).then(|| path.with_file_name(swapped))
+}
+
+/// 상위 디렉토리에 정확히 같은 이름의 항목이 있음
+fn has_exact_entry(path: &Path) -> bool {
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        return false;
+    };
+    fs::read_dir(parent)
+        .map(|entries| entries.flatten().any(|entry| entry.file_name() == name))
+        .unwrap_or(false)
+}
+
+/// `dest`가 `src`의 대소문자만 바꾼 이름이고, 대소문자를 구분하지 않아 같은 항목을 가리킴
+fn is_case_only_alias(src: &Path, dest: &Path) -> bool {
+    let (Some(src_name), Some(dest_name)) = (
+        src.file_name().and_then(|name| name.to_str()),
+        dest.file_name().and_then(|name| name.to_str()),
+    ) else {
+        return false;
+    };
+    src.parent() == dest.parent()
+        && src_name != dest_name
+        && src_name.to_lowercase() == dest_name.to_lowercase()
+        && !has_exact_entry(dest)
+}
+
+/// 임시 이름을 거쳐 두 단계로 이름 변경 (두 번째 단계가 실패하면 원래 이름으로 되돌림)
+///
+/// 임시 이름은 비어 있는 것만 써서 이미 있는 항목을 덮지 않습니다.
+fn rename_via_temp(src: &Path, dest: &Path) -> std::io::Result<()> {
+    let name = src.file_name().unwrap_or_default().to_string_lossy();
+    let dir = match src.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let temp = temp_name::unused_path(dir, &format!(".{}.boksldir-rename-", name), "")?;
+    fs::rename(long_path(src), long_path(&temp))?;
+    fs::rename(long_path(&temp), long_path(dest)).inspect_err(|_| {
+        let _ = fs::rename(long_path(&temp), long_path(src));
+    })
+}
+
+fn copy_io_error(src: &Path, dest: &Path, e: std::io::Error) -> BokslDirError {
+    if e.kind() == std::io::ErrorKind::PermissionDenied {
+        BokslDirError::PermissionDenied {
+            path: dest.to_path_buf(),
+        }
+    } else {
+        BokslDirError::CopyFailed {
+            src: src.to_path_buf(),
+            dest: dest.to_path_buf(),
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// 플랫폼별 "파일 관리자에서 보기" 명령
+fn reveal_command(path: &Path) -> (&'static str, Vec<std::ffi::OsString>) {
+    if cfg!(target_os = "macos") {
+        ("open", vec!["-R".into(), path.into()])
+    } else if cfg!(windows) {
+        let mut select = std::ffi::OsString::from("/select,");
+        select.push(path);
+        ("explorer", vec![select])
+    } else {
+        let dir = if path.is_dir() {
+            path
+        } else {
+            path.parent().unwrap_or(path)
+        };
+        ("xdg-open", vec![dir.into()])
+    }
+}
+
+/// 복사된 파일에 원본 메타데이터 적용 (xattr → 권한 → 시각 순)
+///
+/// 시각은 마지막에 적용해야 다른 메타데이터 변경에 덮이지 않습니다.
+fn apply_copy_metadata(
+    src_metadata: &Metadata,
+    src: &Path,
+    dest: &Path,
+    options: &CopyOptions,
+) -> Result<()> {
+    let metadata_error = |e: std::io::Error| BokslDirError::CopyFailed {
+        src: src.to_path_buf(),
+        dest: dest.to_path_buf(),
+        reason: format!("Failed to preserve metadata: {}", e),
+    };
+    if options.preserve_xattrs {
+        copy_xattrs(src, dest).map_err(metadata_error)?;
+    }
+    if options.preserve_permissions {
+        fs::set_permissions(dest, src_metadata.permissions()).map_err(metadata_error)?;
+    }
+    if options.preserve_timestamps {
+        let mut times =
+            fs::FileTimes::new().set_modified(src_metadata.modified().map_err(metadata_error)?);
+        if let Ok(accessed) = src_metadata.accessed() {
+            times = times.set_accessed(accessed);
+        }
+        // Windows는 시각 변경에 쓰기 권한 핸들이 필요
+        fs::OpenOptions::new()
+            .read(true)
+            .write(cfg!(windows))
+            .open(dest)
+            .and_then(|file| file.set_times(times))
+            .map_err(metadata_error)?;
+    }
+    Ok(())
+}
+
+/// 확장 속성 복사 (지원하지 않는 파일시스템은 무시)
+#[cfg(unix)]
+fn copy_xattrs(src: &Path, dest: &Path) -> std::io::Result<()> {
+    let names = match xattr::list(src) {
+        Ok(names) => names,
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    for name in names {
+        if let Some(value) = xattr::get(src, &name)? {
+            match xattr::set(dest, &name, &value) {
+                Err(e) if e.kind() == std::io::ErrorKind::Unsupported => return Ok(()),
+                result => result?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn copy_xattrs(_src: &Path, _dest: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// 분할 복사 대상 최소 파일 크기 (이보다 작으면 한 번에 복사)
+pub const CHUNKED_COPY_THRESHOLD: u64 = 8 * 1024 * 1024;
+/// 분할 복사 1회 처리량
+pub const COPY_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// 대용량 파일 분할 복사
+///
+/// 메인 루프가 `step()`을 반복 호출해 파일 단위 진행률을 갱신하고,
+/// 일시정지/취소 키 입력을 처리할 수 있게 합니다.
+#[derive(Debug)]
+pub struct ChunkedCopy {
+    reader: fs::File,
+    src_metadata: Option<Metadata>,
+    writer: Option<fs::File>,
+    src: PathBuf,
+    dest: PathBuf,
+    copied: u64,
+    total: u64,
+    buffer: Vec<u8>,
+    /// 희소 파일 구간 탐색 사용 여부 (미지원 시 false로 전환)
+    sparse: bool,
+}
+
+impl ChunkedCopy {
+    /// 소스/대상 파일 열기 (대상은 새로 생성하거나 덮어씀)
+    pub fn open(src: &Path, dest: &Path) -> Result<Self> {
+        let map_err = |e: std::io::Error, path: &Path| {
+            if e.kind() == std::io::ErrorKind::PermissionDenied {
+                BokslDirError::PermissionDenied {
+                    path: path.to_path_buf(),
+                }
+            } else {
+                BokslDirError::CopyFailed {
+                    src: src.to_path_buf(),
+                    dest: dest.to_path_buf(),
+                    reason: e.to_string(),
+                }
+            }
+        };
+        // 대상을 잘라내기 전에 같은 파일인지 확인 (하드 링크면 원본이 비워짐)
+        if src == dest || fast_copy::same_file(src, dest) {
+            return Err(BokslDirError::SameSourceAndDest {
+                path: src.to_path_buf(),
+            });
+        }
+        let reader = fs::File::open(long_path(src)).map_err(|e| map_err(e, src))?;
+        let src_metadata = reader.metadata().ok();
+        let total = src_metadata.as_ref().map(Metadata::len).unwrap_or(0);
+        let writer = fs::File::create(long_path(dest)).map_err(|e| map_err(e, dest))?;
+        Ok(Self {
+            reader,
+            src_metadata,
+            writer: Some(writer),
+            src: src.to_path_buf(),
+            dest: dest.to_path_buf(),
+            copied: 0,
+            total,
+            buffer: vec![0; COPY_CHUNK_SIZE],
+            sparse: true,
+        })
+    }
+
+    pub fn src(&self) -> &Path {
+        &self.src
+    }
+
+    pub fn dest(&self) -> &Path {
+        &self.dest
+    }
+
+    pub fn copied(&self) -> u64 {
+        self.copied
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// 한 조각 복사. 반환값: 이번에 처리한 바이트 수 (0이면 완료)
+    ///
+    /// 희소 파일의 구멍은 읽지 않고 대상에서도 같은 만큼 건너뛰어(구멍 유지)
+    /// 처리한 바이트로 계산합니다. 구간 탐색을 지원하지 않으면 전체를 읽습니다.
+    pub fn step(&mut self) -> Result<u64> {
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        let Some(writer) = self.writer.as_mut() else {
+            return Ok(0);
+        };
+        let mut limit = self.buffer.len();
+        if self.sparse {
+            let position = self.copied;
+            let range = fast_copy::data_range(&self.reader, position);
+            let seek_result = match range {
+                Ok(Some((start, _))) if start > position => {
+                    let result = self
+                        .reader
+                        .seek(SeekFrom::Start(start))
+                        .and_then(|_| writer.seek(SeekFrom::Start(start)));
+                    if result.is_ok() {
+                        self.copied = start;
+                        return Ok(start - position);
+                    }
+                    result.map(|_| ())
+                }
+                Ok(Some((_, end))) => {
+                    limit = limit.min(end.saturating_sub(position).max(1) as usize);
+                    self.reader.seek(SeekFrom::Start(position)).map(|_| ())
+                }
+                Ok(None) => {
+                    // 나머지가 모두 구멍: 길이만 맞춤
+                    let remaining = self.total.saturating_sub(position);
+                    if remaining == 0 {
+                        return Ok(0);
+                    }
+                    let result = writer.set_len(self.total);
+                    if result.is_ok() {
+                        self.copied = self.total;
+                        return Ok(remaining);
+                    }
+                    result
+                }
+                Err(_) => {
+                    self.sparse = false;
+                    self.reader.seek(SeekFrom::Start(position)).map(|_| ())
+                }
+            };
+            if let Err(e) = seek_result {
+                return Err(self.copy_error(e));
+            }
+        }
+        let read = self
+            .reader
+            .read(&mut self.buffer[..limit])
+            .map_err(|e| self.copy_error(e))?;
+        if read == 0 {
+            return Ok(0);
+        }
+        if let Some(writer) = self.writer.as_mut() {
+            if let Err(e) = writer.write_all(&self.buffer[..read]) {
+                return Err(self.copy_error(e));
+            }
+        }
+        self.copied += read as u64;
+        Ok(read as u64)
+    }
+
+    /// 복사 완료 처리 (옵션에 따라 메타데이터 적용). 반환값: 복사된 총 바이트 수
+    pub fn finish(mut self, options: &CopyOptions) -> Result<u64> {
+        if let Some(writer) = self.writer.take() {
+            writer.sync_all().map_err(|e| self.copy_error(e))?;
+        }
+        if let Some(metadata) = &self.src_metadata {
+            apply_copy_metadata(metadata, &self.src, &self.dest, options)?;
+        }
+        Ok(self.copied)
+    }
+
+    /// 중단 처리: 불완전한 대상 파일 삭제
+    pub fn abort(mut self) {
+        self.writer.take();
+        let _ = fs::remove_file(&self.dest);
+    }
+
+    fn copy_error(&self, e: std::io::Error) -> BokslDirError {
+        BokslDirError::CopyFailed {
+            src: self.src.clone(),
+            dest: self.dest.clone(),
+            reason: e.to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -859,6 +1893,31 @@ mod tests {
         assert!(!fs.is_hidden(&visible_path));
     }
 
+    #[test]
+    fn test_case_only_rename_goes_through_temp_name() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("File.txt");
+        let dest = temp.path().join("file.txt");
+        fs::write(&src, b"x").unwrap();
+
+        // 대소문자만 다른 별개 항목이 실제로 있으면 같은 항목이 아님
+        fs::write(&dest, b"y").unwrap();
+        assert!(!is_case_only_alias(&src, &dest));
+        fs::remove_file(&dest).unwrap();
+
+        rename_via_temp(&src, &dest).unwrap();
+        let names: Vec<_> = fs::read_dir(temp.path())
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name())
+            .collect();
+        assert_eq!(names, ["file.txt"]);
+        assert_eq!(fs::read(&dest).unwrap(), b"x");
+
+        #[cfg(target_os = "linux")]
+        assert!(!FileSystem::new().is_case_insensitive(temp.path()));
+    }
+
     #[test]
     fn test_create_directory() {
         let fs_instance = FileSystem::new();
@@ -974,6 +2033,47 @@ mod tests {
             .any(|f| f.entry_kind == FlattenedEntryKind::File && f.dest == expected_file));
     }
 
+    #[test]
+    fn test_flatten_sources_filtered_prunes_excluded_dirs_and_empty_dirs() {
+        let fs = FileSystem::new();
+        let temp = TempDir::new().unwrap();
+        let source_root = temp.path().join("app");
+        let dest_root = temp.path().join("dest");
+        fs::create_dir_all(source_root.join("node_modules/pkg")).unwrap();
+        fs::create_dir_all(source_root.join("img/raw")).unwrap();
+        fs::create_dir_all(source_root.join("docs")).unwrap();
+        fs::write(source_root.join("node_modules/pkg/x.jpg"), "x").unwrap();
+        fs::write(source_root.join("img/a.jpg"), "aaa").unwrap();
+        fs::write(source_root.join("img/raw/b.txt"), "b").unwrap();
+        fs::write(source_root.join("docs/readme.md"), "r").unwrap();
+        fs::create_dir_all(&dest_root).unwrap();
+
+        let filter = ScopeFilter::parse("*.jpg !node_modules/");
+        let (flattened, filtered_out) = fs
+            .flatten_sources_filtered(
+                std::slice::from_ref(&source_root),
+                &dest_root,
+                &filter,
+                SymlinkPolicy::Links,
+            )
+            .unwrap();
+
+        let dests: Vec<PathBuf> = flattened
+            .iter()
+            .map(|f| f.dest.strip_prefix(&dest_root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            dests,
+            vec![
+                PathBuf::from("app"),
+                PathBuf::from("app/img"),
+                PathBuf::from("app/img/a.jpg"),
+            ]
+        );
+        // node_modules(1) + raw/b.txt + readme.md + 비어버린 raw/docs 디렉토리
+        assert_eq!(filtered_out, 5);
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_read_directory_detects_symlink_type() {
@@ -992,6 +2092,24 @@ mod tests {
             .find(|entry| entry.name == "target_link")
             .expect("symlink entry not found");
         assert_eq!(symlink_entry.file_type, FileType::Symlink);
+        assert!(!symlink_entry.is_broken_symlink);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_directory_detects_broken_symlink() {
+        let fs = FileSystem::new();
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        unix_fs::symlink(dir.join("missing.txt"), dir.join("dangling")).unwrap();
+
+        let entries = fs.read_directory(dir).unwrap();
+        let entry = entries
+            .iter()
+            .find(|entry| entry.name == "dangling")
+            .expect("symlink entry not found");
+        assert_eq!(entry.file_type, FileType::Symlink);
+        assert!(entry.is_broken_symlink);
     }
 
     #[cfg(unix)]
@@ -1057,6 +2175,344 @@ mod tests {
         assert!(!flattened.iter().any(|f| f.dest == outside_dest));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_flatten_sources_follows_or_skips_symlinks() {
+        let fs = FileSystem::new();
+        let temp = TempDir::new().unwrap();
+        let source_root = temp.path().join("src");
+        let external_dir = temp.path().join("external");
+        let dest_root = temp.path().join("dest");
+        fs::create_dir_all(&source_root).unwrap();
+        fs::create_dir_all(&external_dir).unwrap();
+        fs::write(external_dir.join("outside.txt"), "outside").unwrap();
+        unix_fs::symlink(&external_dir, source_root.join("external_link")).unwrap();
+        unix_fs::symlink(&source_root, source_root.join("loop")).unwrap();
+        // 따라간 디렉토리에서 원래 트리로 돌아오는 링크
+        unix_fs::symlink(&source_root, external_dir.join("back")).unwrap();
+
+        let flatten = |symlinks| {
+            fs.flatten_sources_filtered(
+                std::slice::from_ref(&source_root),
+                &dest_root,
+                &ScopeFilter::default(),
+                symlinks,
+            )
+            .unwrap()
+        };
+        let kind_of = |files: &[FlattenedFile], relative: &str| {
+            files
+                .iter()
+                .find(|f| f.dest == dest_root.join("src").join(relative))
+                .map(|f| f.entry_kind)
+        };
+
+        let (files, _) = flatten(SymlinkPolicy::Follow);
+        assert_eq!(
+            kind_of(&files, "external_link"),
+            Some(FlattenedEntryKind::Directory)
+        );
+        let outside = files
+            .iter()
+            .find(|f| f.dest == dest_root.join("src/external_link/outside.txt"))
+            .unwrap();
+        assert_eq!(outside.entry_kind, FlattenedEntryKind::File);
+        assert_eq!(outside.size, 7);
+        assert_eq!(
+            kind_of(&files, "loop"),
+            Some(FlattenedEntryKind::SymlinkDirectory)
+        );
+        assert_eq!(
+            kind_of(&files, "external_link/back"),
+            Some(FlattenedEntryKind::SymlinkDirectory)
+        );
+
+        let (files, filtered_out) = flatten(SymlinkPolicy::Skip);
+        assert_eq!(files.len(), 1);
+        assert_eq!(filtered_out, 2);
+    }
+
+    #[test]
+    fn test_chunked_copy_copies_in_steps() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("big.bin");
+        let dest = temp.path().join("copy.bin");
+        let data: Vec<u8> = (0..COPY_CHUNK_SIZE * 2 + 10).map(|i| i as u8).collect();
+        fs::write(&src, &data).unwrap();
+
+        let mut copy = ChunkedCopy::open(&src, &dest).unwrap();
+        assert_eq!(copy.total(), data.len() as u64);
+        let mut steps = 0;
+        while copy.step().unwrap() > 0 {
+            steps += 1;
+        }
+        assert_eq!(steps, 3);
+        assert_eq!(
+            copy.finish(&CopyOptions::default()).unwrap(),
+            data.len() as u64
+        );
+        assert_eq!(fs::read(&dest).unwrap(), data);
+
+        // 중단 시 불완전한 대상 파일 삭제
+        let mut copy = ChunkedCopy::open(&src, &dest).unwrap();
+        copy.step().unwrap();
+        copy.abort();
+        assert!(!dest.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_clone_and_chunked_copy_refuse_hard_link_to_source() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("a.bin");
+        let link = temp.path().join("link.bin");
+        fs::write(&src, b"keep me").unwrap();
+        fs::hard_link(&src, &link).unwrap();
+
+        let fs_ops = FileSystem::new();
+        assert!(matches!(
+            fs_ops.try_clone_file(&src, &link, &CopyOptions::default()),
+            Err(BokslDirError::SameSourceAndDest { .. })
+        ));
+        assert!(!fast_copy::reflink(&src, &link));
+        assert!(matches!(
+            ChunkedCopy::open(&src, &link),
+            Err(BokslDirError::SameSourceAndDest { .. })
+        ));
+        assert_eq!(fs::read(&src).unwrap(), b"keep me");
+        assert_eq!(fs::read(&link).unwrap(), b"keep me");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reflink_gives_same_modes_as_streaming_copy() {
+        use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("a.bin");
+        fs::write(&src, b"data").unwrap();
+        fs::set_permissions(&src, fs::Permissions::from_mode(0o600)).unwrap();
+        let streamed = temp.path().join("streamed.bin");
+        fs::File::create(&streamed).unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+
+        // 새 대상: 원본/임시 파일 권한(0600)이 아니라 새 파일 기본 권한
+        let fresh = temp.path().join("fresh.bin");
+        if fast_copy::reflink(&src, &fresh) {
+            assert_eq!(mode(&fresh), mode(&streamed));
+        }
+
+        // 기존 대상: 같은 파일(아이노드)에 덮어써 권한과 하드 링크 유지
+        let existing = temp.path().join("existing.bin");
+        fs::write(&existing, b"longer old contents").unwrap();
+        fs::set_permissions(&existing, fs::Permissions::from_mode(0o640)).unwrap();
+        let link = temp.path().join("existing-link.bin");
+        fs::hard_link(&existing, &link).unwrap();
+        let inode = fs::metadata(&existing).unwrap().ino();
+        if fast_copy::reflink(&src, &existing) {
+            assert_eq!(fs::read(&link).unwrap(), b"data");
+        } else {
+            assert_eq!(fs::read(&link).unwrap(), b"longer old contents");
+        }
+        assert_eq!(fs::metadata(&existing).unwrap().ino(), inode);
+        assert_eq!(mode(&existing), 0o640);
+    }
+
+    #[test]
+    fn test_reflink_failure_leaves_existing_dest_alone() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("a.bin");
+        let dest = temp.path().join("b.bin");
+        fs::write(&src, b"new").unwrap();
+        fs::write(&dest, b"old").unwrap();
+
+        // 복제 성공이면 대상이 바뀌고, 실패면 대상과 임시 파일 흔적이 그대로여야 함
+        if fast_copy::reflink(&src, &dest) {
+            assert_eq!(fs::read(&dest).unwrap(), b"new");
+        } else {
+            assert_eq!(fs::read(&dest).unwrap(), b"old");
+        }
+        let names: Vec<_> = fs::read_dir(temp.path())
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name())
+            .collect();
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn test_move_file_with_strategy_renames_on_same_filesystem() {
+        let fs_ops = FileSystem::new();
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("a.txt");
+        let dest = temp.path().join("b.txt");
+        fs::write(&src, "hello").unwrap();
+
+        let (bytes, strategy) = fs_ops.move_file_with_strategy(&src, &dest).unwrap();
+        assert_eq!((bytes, strategy), (5, MoveStrategy::Rename));
+        assert!(!src.exists());
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "hello");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_move_file_with_strategy_falls_back_across_devices() {
+        use std::os::unix::fs::MetadataExt;
+
+        // /dev/shm이 다른 파일시스템일 때만 검증 (EXDEV 재현)
+        let Ok(other) = tempfile::tempdir_in("/dev/shm") else {
+            return;
+        };
+        let temp = TempDir::new().unwrap();
+        if fs::metadata(other.path()).unwrap().dev() == fs::metadata(temp.path()).unwrap().dev() {
+            return;
+        }
+        let fs_ops = FileSystem::new();
+        let src = temp.path().join("a.txt");
+        let dest = other.path().join("a.txt");
+        fs::write(&src, "hello").unwrap();
+
+        assert!(!fs_ops.try_rename(&src, &dest).unwrap());
+        let (bytes, strategy) = fs_ops.move_file_with_strategy(&src, &dest).unwrap();
+        assert_eq!((bytes, strategy), (5, MoveStrategy::CopyDelete));
+        assert!(!src.exists());
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_chunked_copy_preserves_sparse_file_content() {
+        use std::io::{Seek, SeekFrom, Write};
+
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("sparse.img");
+        let dest = temp.path().join("sparse_copy.img");
+        let total = (COPY_CHUNK_SIZE * 8) as u64;
+        let mut file = File::create(&src).unwrap();
+        file.set_len(total).unwrap();
+        file.seek(SeekFrom::Start(COPY_CHUNK_SIZE as u64 * 3))
+            .unwrap();
+        file.write_all(b"middle").unwrap();
+        file.seek(SeekFrom::Start(total - 4)).unwrap();
+        file.write_all(b"tail").unwrap();
+        drop(file);
+
+        let mut copy = ChunkedCopy::open(&src, &dest).unwrap();
+        let mut processed = 0;
+        loop {
+            let step = copy.step().unwrap();
+            if step == 0 {
+                break;
+            }
+            processed += step;
+        }
+        assert_eq!(processed, total);
+        assert_eq!(copy.finish(&CopyOptions::default()).unwrap(), total);
+        assert_eq!(fs::read(&dest).unwrap(), fs::read(&src).unwrap());
+
+        #[cfg(unix)]
+        {
+            // 구멍을 지원하는 파일시스템이면 대상도 원본보다 크게 할당되지 않음
+            use std::os::unix::fs::MetadataExt;
+            let src_blocks = fs::metadata(&src).unwrap().blocks();
+            let dest_blocks = fs::metadata(&dest).unwrap().blocks();
+            if src_blocks * 512 < total {
+                assert!(dest_blocks * 512 < total);
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_file_with_options_preserves_metadata() {
+        use std::os::unix::fs::PermissionsExt;
+        use std::time::{Duration, SystemTime};
+
+        let fs_ops = FileSystem::new();
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("run.sh");
+        fs::write(&src, "#!/bin/sh").unwrap();
+        fs::set_permissions(&src, fs::Permissions::from_mode(0o750)).unwrap();
+        let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        File::options()
+            .write(true)
+            .open(&src)
+            .unwrap()
+            .set_times(fs::FileTimes::new().set_modified(old).set_accessed(old))
+            .unwrap();
+        let has_xattr = xattr::set(&src, "user.boksldir", b"tag").is_ok();
+
+        let dest = temp.path().join("kept.sh");
+        fs_ops
+            .copy_file_with_options(&src, &dest, &CopyOptions::preserve_all())
+            .unwrap();
+        let meta = fs::metadata(&dest).unwrap();
+        assert_eq!(meta.modified().unwrap(), old);
+        assert_eq!(meta.permissions().mode() & 0o777, 0o750);
+        if has_xattr {
+            assert_eq!(
+                xattr::get(&dest, "user.boksldir").unwrap(),
+                Some(b"tag".to_vec())
+            );
+        }
+
+        // 권한/시각 미유지: 새 파일 기본 권한(실행 비트 없음), 현재 시각
+        let dest = temp.path().join("plain.sh");
+        let options = CopyOptions {
+            preserve_permissions: false,
+            ..CopyOptions::default()
+        };
+        fs_ops
+            .copy_file_with_options(&src, &dest, &options)
+            .unwrap();
+        let meta = fs::metadata(&dest).unwrap();
+        assert_eq!(meta.permissions().mode() & 0o111, 0);
+        assert_ne!(meta.modified().unwrap(), old);
+        assert_eq!(fs::read(&dest).unwrap(), b"#!/bin/sh");
+    }
+
+    #[test]
+    fn test_verify_copy_detects_mismatch() {
+        let fs_ops = FileSystem::new();
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("a.txt");
+        let dest = temp.path().join("b.txt");
+        fs::write(&src, "same").unwrap();
+        fs::write(&dest, "same").unwrap();
+        assert!(fs_ops.verify_copy(&src, &dest).is_ok());
+
+        fs::write(&dest, "diff").unwrap();
+        assert!(matches!(
+            fs_ops.verify_copy(&src, &dest),
+            Err(BokslDirError::VerifyFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reveal_command_targets_item_or_containing_directory() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("a.txt");
+        fs::write(&file, "x").unwrap();
+        let (program, args) = reveal_command(&file);
+        if cfg!(target_os = "macos") {
+            assert_eq!(program, "open");
+            assert_eq!(args, vec![std::ffi::OsString::from("-R"), file.into()]);
+        } else if cfg!(windows) {
+            assert_eq!(program, "explorer");
+            assert!(args[0].to_string_lossy().starts_with("/select,"));
+        } else {
+            assert_eq!(program, "xdg-open");
+            assert_eq!(args, vec![temp.path().as_os_str().to_os_string()]);
+            let (_, args) = reveal_command(temp.path());
+            assert_eq!(args, vec![temp.path().as_os_str().to_os_string()]);
+        }
+
+        let missing = temp.path().join("missing.txt");
+        assert!(matches!(
+            FileSystem::new().reveal_in_file_manager(&missing),
+            Err(BokslDirError::PathNotFound { .. })
+        ));
+    }
+
     #[cfg(target_os = "macos")]
     #[test]
     fn test_open_with_default_app_nonexistent_path_returns_path_not_found() {
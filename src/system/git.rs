@@ -0,0 +1,385 @@
+//! git 작업 트리 상태 조회 (`git status --porcelain` 호출)와 파일 단위 git 작업
+//!
+//! git이 없거나 저장소 밖이면 None을 반환하며, 패널은 상태 표시를 생략합니다.
+
+use crate::utils::error::{BokslDirError, Result};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// 패널 항목의 git 상태 (표시 우선순위 낮은 순)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GitStatus {
+    Ignored,
+    Untracked,
+    Staged,
+    Modified,
+    Conflicted,
+}
+
+impl GitStatus {
+    /// 패널 상태 컬럼 표시 문자
+    pub fn marker(self) -> char {
+        match self {
+            GitStatus::Ignored => '!',
+            GitStatus::Untracked => '?',
+            GitStatus::Staged => '+',
+            GitStatus::Modified => 'M',
+            GitStatus::Conflicted => 'U',
+        }
+    }
+
+    /// porcelain v1의 두 글자 상태 코드 해석
+    fn from_code(index: u8, worktree: u8) -> Option<Self> {
+        match (index, worktree) {
+            (b'!', b'!') => Some(GitStatus::Ignored),
+            (b'?', b'?') => Some(GitStatus::Untracked),
+            (b'U', _) | (_, b'U') | (b'A', b'A') | (b'D', b'D') => Some(GitStatus::Conflicted),
+            (_, b'M' | b'D' | b'T') => Some(GitStatus::Modified),
+            (b'M' | b'A' | b'D' | b'R' | b'C' | b'T', _) => Some(GitStatus::Staged),
+            _ => None,
+        }
+    }
+}
+
+/// 저장소 요약 (현재 브랜치와 upstream 대비 커밋 수)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RepoSummary {
+    /// 브랜치 이름 (분리된 HEAD는 "HEAD")
+    pub branch: String,
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+impl RepoSummary {
+    /// 패널 제목 표시 문자열 (예: `main ↑2 ↓1`)
+    pub fn label(&self) -> String {
+        let mut label = self.branch.clone();
+        if self.ahead > 0 {
+            label.push_str(&format!(" ↑{}", self.ahead));
+        }
+        if self.behind > 0 {
+            label.push_str(&format!(" ↓{}", self.behind));
+        }
+        label
+    }
+}
+
+/// 디렉토리 조회 결과
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryStatus {
+    pub repo: RepoSummary,
+    /// 직계 항목별 상태 (`child 경로 → 상태`)
+    pub entries: HashMap<PathBuf, GitStatus>,
+}
+
+/// 디렉토리의 저장소 요약과 직계 항목별 git 상태
+///
+/// 하위 디렉토리는 안쪽 변경 중 가장 우선순위가 높은 상태로 표시하되, 무시 상태는
+/// 해당 항목 자체가 무시된 경우에만 표시합니다. 저장소 밖이면 None.
+pub fn directory_status(dir: &Path) -> Option<DirectoryStatus> {
+    let root = run_git(dir, &["rev-parse", "--show-toplevel"])?;
+    let root = PathBuf::from(String::from_utf8_lossy(&root).trim_end());
+    let output = run_git(
+        dir,
+        &[
+            "status",
+            "--porcelain=v1",
+            "-z",
+            "--branch",
+            "--ignored=matching",
+            "--untracked-files=normal",
+            "--",
+            ".",
+        ],
+    )?;
+    // rev-parse는 심볼릭 링크를 푼 경로를 돌려주므로 같은 기준으로 맞춤
+    let canonical_dir = dir.canonicalize().ok()?;
+    let root = root.canonicalize().unwrap_or(root);
+    let relative_dir = canonical_dir.strip_prefix(&root).ok()?;
+    Some(DirectoryStatus {
+        repo: parse_branch_header(&output),
+        entries: children_status(&parse_porcelain(&output), relative_dir, dir),
+    })
+}
+
+/// 파일(또는 디렉토리)의 HEAD 대비 변경 내용 (`git diff`, 변경 없으면 빈 문자열)
+pub fn diff(path: &Path) -> Result<String> {
+    let (dir, name) = split_path(path)?;
+    let head = run_git_checked(
+        dir,
+        &["diff", "HEAD", "--no-color", "--no-ext-diff", "--"],
+        name,
+    );
+    let output = match head {
+        Ok(output) => output,
+        // 첫 커밋 전에는 HEAD가 없으므로 스테이징/작업 트리 변경을 이어 붙임
+        Err(_) => {
+            let mut output = run_git_checked(
+                dir,
+                &["diff", "--cached", "--no-color", "--no-ext-diff", "--"],
+                name,
+            )?;
+            output.extend(run_git_checked(
+                dir,
+                &["diff", "--no-color", "--no-ext-diff", "--"],
+                name,
+            )?);
+            output
+        }
+    };
+    Ok(String::from_utf8_lossy(&output).into_owned())
+}
+
+/// 작업 트리 변경 되돌리기 (`git checkout -- <path>`, 스테이징된 내용으로 복원)
+pub fn discard_changes(path: &Path) -> Result<()> {
+    let (dir, name) = split_path(path)?;
+    run_git_checked(dir, &["checkout", "--"], name).map(|_| ())
+}
+
+fn split_path(path: &Path) -> Result<(&Path, &std::ffi::OsStr)> {
+    match (path.parent(), path.file_name()) {
+        (Some(dir), Some(name)) => Ok((dir, name)),
+        _ => Err(BokslDirError::PathNotFound {
+            path: path.to_path_buf(),
+        }),
+    }
+}
+
+/// `dir`에서 git 실행 (`args` 뒤에 `name` 추가), 실패 시 stderr를 사유로 반환
+fn run_git_checked(dir: &Path, args: &[&str], name: &std::ffi::OsStr) -> Result<Vec<u8>> {
+    let git_error = |reason: String| BokslDirError::GitFailed {
+        path: dir.join(name),
+        reason,
+    };
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .arg(name)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| git_error(err.to_string()))?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(git_error(stderr.trim().to_string()))
+    }
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> Option<Vec<u8>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .map_err(|err| log::debug!("git {:?} in {}: {}", args, dir.display(), err))
+        .ok()?;
+    output.status.success().then_some(output.stdout)
+}
+
+/// `--branch` 헤더 해석 (`## main...origin/main [ahead 2, behind 1]`)
+fn parse_branch_header(output: &[u8]) -> RepoSummary {
+    let first = output.split(|&b| b == 0).next().unwrap_or_default();
+    let header = String::from_utf8_lossy(first);
+    let Some(header) = header.strip_prefix("## ") else {
+        return RepoSummary::default();
+    };
+    let header = header
+        .strip_prefix("No commits yet on ")
+        .or_else(|| header.strip_prefix("Initial commit on "))
+        .unwrap_or(header);
+    let (branch, tracking) = match header.split_once(" [") {
+        Some((branch, tracking)) => (branch, tracking.trim_end_matches(']')),
+        None => (header, ""),
+    };
+    let branch = branch.split_once("...").map_or(branch, |(local, _)| local);
+    let mut summary = RepoSummary {
+        branch: if branch.starts_with("HEAD (") {
+            "HEAD".to_string()
+        } else {
+            branch.to_string()
+        },
+        ..RepoSummary::default()
+    };
+    for part in tracking.split(", ") {
+        if let Some(count) = part.strip_prefix("ahead ") {
+            summary.ahead = count.parse().unwrap_or(0);
+        } else if let Some(count) = part.strip_prefix("behind ") {
+            summary.behind = count.parse().unwrap_or(0);
+        }
+    }
+    summary
+}
+
+/// `git status --porcelain=v1 -z` 출력 → (저장소 기준 상대 경로, 상태)
+fn parse_porcelain(output: &[u8]) -> Vec<(PathBuf, GitStatus)> {
+    let mut records = output.split(|&b| b == 0);
+    let mut statuses = Vec::new();
+    while let Some(record) = records.next() {
+        if record.len() < 4 || record.starts_with(b"## ") {
+            continue;
+        }
+        let (index, worktree) = (record[0], record[1]);
+        if matches!(index, b'R' | b'C') {
+            // 이름 변경/복사는 원래 경로가 다음 레코드로 따라옴
+            records.next();
+        }
+        let Some(status) = GitStatus::from_code(index, worktree) else {
+            continue;
+        };
+        let path = String::from_utf8_lossy(&record[3..]);
+        statuses.push((PathBuf::from(path.trim_end_matches('/')), status));
+    }
+    statuses
+}
+
+/// 상대 경로 상태 목록을 `relative_dir`의 직계 항목 기준으로 합침
+///
+/// 결과 키는 패널 항목 경로와 같도록 `display_dir` 아래 경로로 만듭니다.
+fn children_status(
+    statuses: &[(PathBuf, GitStatus)],
+    relative_dir: &Path,
+    display_dir: &Path,
+) -> HashMap<PathBuf, GitStatus> {
+    let mut children: HashMap<PathBuf, GitStatus> = HashMap::new();
+    for (path, status) in statuses {
+        let Ok(rest) = path.strip_prefix(relative_dir) else {
+            continue;
+        };
+        let mut components = rest.components();
+        let Some(Component::Normal(child)) = components.next() else {
+            continue;
+        };
+        let is_direct = components.next().is_none();
+        if *status == GitStatus::Ignored && !is_direct {
+            continue;
+        }
+        let entry = children.entry(display_dir.join(child)).or_insert(*status);
+        *entry = (*entry).max(*status);
+    }
+    children
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_porcelain_codes_and_renames() {
+        let output = b" M src/main.rs\0M  staged.txt\0R  new.rs\0old.rs\0?? notes/\0!! target/\0UU conflict.txt\0MM both.txt\0";
+        let parsed = parse_porcelain(output);
+        assert_eq!(
+            parsed,
+            vec![
+                (PathBuf::from("src/main.rs"), GitStatus::Modified),
+                (PathBuf::from("staged.txt"), GitStatus::Staged),
+                (PathBuf::from("new.rs"), GitStatus::Staged),
+                (PathBuf::from("notes"), GitStatus::Untracked),
+                (PathBuf::from("target"), GitStatus::Ignored),
+                (PathBuf::from("conflict.txt"), GitStatus::Conflicted),
+                (PathBuf::from("both.txt"), GitStatus::Modified),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_branch_header_tracking_and_detached() {
+        let parse = |header: &str| parse_branch_header(format!("{}\0", header).as_bytes());
+        assert_eq!(
+            parse("## main...origin/main [ahead 2, behind 1]"),
+            RepoSummary {
+                branch: "main".to_string(),
+                ahead: 2,
+                behind: 1,
+            }
+        );
+        assert_eq!(
+            parse("## main...origin/main [ahead 2, behind 1]").label(),
+            "main ↑2 ↓1"
+        );
+        assert_eq!(
+            parse("## feature/x...origin/feature/x [gone]").label(),
+            "feature/x"
+        );
+        assert_eq!(parse("## No commits yet on master").label(), "master");
+        assert_eq!(parse("## HEAD (no branch)").label(), "HEAD");
+        assert_eq!(parse(" M file.txt"), RepoSummary::default());
+    }
+
+    #[test]
+    fn test_children_status_aggregates_subdirectories() {
+        let statuses = vec![
+            (PathBuf::from("app/src/a.rs"), GitStatus::Staged),
+            (PathBuf::from("app/src/b.rs"), GitStatus::Modified),
+            (PathBuf::from("app/build"), GitStatus::Ignored),
+            (PathBuf::from("app/docs/cache/x.tmp"), GitStatus::Ignored),
+            (PathBuf::from("app/new.txt"), GitStatus::Untracked),
+            (PathBuf::from("other/c.rs"), GitStatus::Modified),
+        ];
+        let base = Path::new("/repo/app");
+        let children = children_status(&statuses, Path::new("app"), base);
+        assert_eq!(children.get(&base.join("src")), Some(&GitStatus::Modified));
+        assert_eq!(children.get(&base.join("build")), Some(&GitStatus::Ignored));
+        assert_eq!(
+            children.get(&base.join("new.txt")),
+            Some(&GitStatus::Untracked)
+        );
+        assert!(!children.contains_key(&base.join("docs")));
+        assert_eq!(children.len(), 3);
+    }
+
+    #[test]
+    fn test_directory_status_reads_real_repository() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let repo = temp.path();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .arg("-C")
+                .arg(repo)
+                .args(args)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|status| status.success())
+        };
+        if !git(&["init", "-q"]) {
+            return; // git 미설치 환경
+        }
+        std::fs::create_dir(repo.join("src")).unwrap();
+        std::fs::write(repo.join("src/lib.rs"), "fn a() {}").unwrap();
+        std::fs::write(repo.join("staged.txt"), "x").unwrap();
+        std::fs::write(repo.join("new.txt"), "x").unwrap();
+        assert!(git(&["add", "src/lib.rs", "staged.txt"]));
+
+        let status = directory_status(repo).unwrap();
+        assert!(!status.repo.branch.is_empty());
+        let status = status.entries;
+        assert_eq!(status.get(&repo.join("src")), Some(&GitStatus::Staged));
+        assert_eq!(
+            status.get(&repo.join("new.txt")),
+            Some(&GitStatus::Untracked)
+        );
+
+        let sub = directory_status(&repo.join("src")).unwrap().entries;
+        assert_eq!(sub.get(&repo.join("src/lib.rs")), Some(&GitStatus::Staged));
+
+        // 스테이징 후 수정: diff에 두 변경이 모두 보이고, 되돌리면 스테이징 내용으로 복원
+        std::fs::write(repo.join("src/lib.rs"), "fn b() {}").unwrap();
+        let text = diff(&repo.join("src/lib.rs")).unwrap();
+        assert!(text.contains("+fn a() {}"));
+        assert!(text.contains("+fn b() {}"));
+        discard_changes(&repo.join("src/lib.rs")).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(repo.join("src/lib.rs")).unwrap(),
+            "fn a() {}"
+        );
+        assert!(matches!(
+            discard_changes(&repo.join("missing.txt")),
+            Err(BokslDirError::GitFailed { .. })
+        ));
+    }
+}
@@ -5,6 +5,7 @@
 
 /// IME 상태
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
 pub enum ImeStatus {
     /// 한글 입력 모드
     Korean,
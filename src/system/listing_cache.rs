@@ -0,0 +1,174 @@
+//! 최근 디렉토리 목록 캐시
+//!
+//! 히스토리 뒤로/앞으로 등으로 방금 보던 디렉토리에 다시 들어갈 때 목록을 다시 읽지 않고
+//! 바로 보여주기 위해, 경로별로 읽은 항목(메타데이터 포함)과 그때의 디렉토리 수정 시간을 보관합니다.
+//! 디렉토리 수정 시간이 달라졌으면(항목 추가/삭제/이름 변경) 캐시를 쓰지 않고, 새로고침은 항상
+//! 다시 읽어 캐시를 바꿉니다. 전체 크기가 `max_bytes`를 넘으면 가장 오래 쓰지 않은 목록부터 버립니다.
+
+use crate::models::file_entry::FileEntry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// 캐시 메모리 상한 기본값
+pub const DEFAULT_MAX_BYTES: usize = 32 * 1024 * 1024;
+
+#[derive(Debug)]
+struct CachedListing {
+    /// 목록을 읽기 시작할 때의 디렉토리 수정 시간
+    modified: SystemTime,
+    entries: Vec<FileEntry>,
+    bytes: usize,
+    last_used: u64,
+}
+
+/// 경로별 디렉토리 목록 (오래 쓰지 않은 순서로 버림)
+#[derive(Debug)]
+pub struct ListingCache {
+    listings: HashMap<PathBuf, CachedListing>,
+    max_bytes: usize,
+    total_bytes: usize,
+    clock: u64,
+}
+
+impl ListingCache {
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            listings: HashMap::new(),
+            max_bytes,
+            total_bytes: 0,
+            clock: 0,
+        }
+    }
+
+    /// 디렉토리 수정 시간이 같을 때만 보관한 목록 반환
+    pub fn get(&mut self, dir: &Path, modified: SystemTime) -> Option<Vec<FileEntry>> {
+        self.clock += 1;
+        let clock = self.clock;
+        let listing = self.listings.get_mut(dir)?;
+        if listing.modified != modified {
+            self.invalidate(dir);
+            return None;
+        }
+        listing.last_used = clock;
+        Some(listing.entries.clone())
+    }
+
+    /// 목록 보관 (같은 경로는 교체, 상한보다 큰 목록은 보관하지 않음)
+    pub fn insert(&mut self, dir: PathBuf, modified: SystemTime, entries: Vec<FileEntry>) {
+        self.invalidate(&dir);
+        let bytes = estimated_bytes(&dir, &entries);
+        if bytes > self.max_bytes {
+            return;
+        }
+        self.clock += 1;
+        self.total_bytes += bytes;
+        self.listings.insert(
+            dir,
+            CachedListing {
+                modified,
+                entries,
+                bytes,
+                last_used: self.clock,
+            },
+        );
+        while self.total_bytes > self.max_bytes {
+            let Some(oldest) = self
+                .listings
+                .iter()
+                .min_by_key(|(_, listing)| listing.last_used)
+                .map(|(path, _)| path.clone())
+            else {
+                break;
+            };
+            self.invalidate(&oldest);
+        }
+    }
+
+    pub fn invalidate(&mut self, dir: &Path) {
+        if let Some(listing) = self.listings.remove(dir) {
+            self.total_bytes -= listing.bytes;
+        }
+    }
+}
+
+impl Default for ListingCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BYTES)
+    }
+}
+
+/// 목록 하나의 대략적인 메모리 사용량 (구조체 크기 + 문자열 길이)
+fn estimated_bytes(dir: &Path, entries: &[FileEntry]) -> usize {
+    dir.as_os_str().len()
+        + entries
+            .iter()
+            .map(|entry| {
+                std::mem::size_of::<FileEntry>()
+                    + entry.name.len()
+                    + entry.path.as_os_str().len()
+                    + entry.owner.as_ref().map_or(0, String::len)
+                    + entry.group.as_ref().map_or(0, String::len)
+                    + entry
+                        .link_target
+                        .as_ref()
+                        .map_or(0, |target| target.as_os_str().len())
+            })
+            .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::file_entry::FileType;
+    use std::time::Duration;
+
+    fn entries(dir: &str, count: usize) -> Vec<FileEntry> {
+        (0..count)
+            .map(|i| {
+                let name = format!("file{}", i);
+                FileEntry::new(
+                    name.clone(),
+                    PathBuf::from(dir).join(name),
+                    FileType::File,
+                    0,
+                    SystemTime::UNIX_EPOCH,
+                    SystemTime::UNIX_EPOCH,
+                    None,
+                    false,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_listing_cache_checks_mtime_and_evicts_least_recently_used() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+        let one = estimated_bytes(Path::new("/a"), &entries("/a", 10));
+        let mut cache = ListingCache::new(one * 2 + one / 2);
+
+        cache.insert(PathBuf::from("/a"), t0, entries("/a", 10));
+        cache.insert(PathBuf::from("/b"), t0, entries("/b", 10));
+        assert_eq!(cache.get(Path::new("/a"), t0).map(|e| e.len()), Some(10));
+
+        // 상한을 넘으면 가장 오래 쓰지 않은 /b부터 버림
+        cache.insert(PathBuf::from("/c"), t0, entries("/c", 10));
+        assert_eq!(cache.listings.len(), 2);
+        assert!(cache.get(Path::new("/b"), t0).is_none());
+        assert!(cache.get(Path::new("/a"), t0).is_some());
+        assert!(cache.total_bytes <= one * 2 + one / 2);
+
+        // 디렉토리가 바뀌었으면 쓰지 않고 버림
+        assert!(cache.get(Path::new("/c"), t1).is_none());
+        assert_eq!(cache.listings.len(), 1);
+
+        // 상한보다 큰 목록은 보관하지 않음
+        cache.insert(PathBuf::from("/big"), t0, entries("/big", 100));
+        assert!(cache.get(Path::new("/big"), t0).is_none());
+
+        cache.invalidate(Path::new("/a"));
+        assert!(cache.listings.is_empty());
+        assert_eq!(cache.total_bytes, 0);
+    }
+}
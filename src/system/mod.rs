@@ -1,12 +1,34 @@
 // System Layer
 pub mod archive;
+pub mod attributes;
+pub mod compare;
+pub mod disk_usage;
+pub mod diskspace;
+pub mod event_loop;
+pub mod fast_copy;
+pub mod file_index;
+pub mod file_type;
 pub mod filesystem;
+pub mod ftp;
+pub mod git;
 pub mod ime;
+pub mod listing_cache;
+pub mod name_encoding;
+pub mod privilege;
+pub mod remote;
+pub mod reparse;
+pub mod sftp;
+pub mod sync;
+pub mod users;
+pub mod vfs;
 
 pub use archive::{
-    create_archive, detect_archive_format, extract_archive, list_entries, list_extract_conflicts,
-    supports_password, ArchiveCreateRequest, ArchiveEntry, ArchiveExtractRequest, ArchiveFormat,
-    ArchiveProgressEvent, ArchiveSummary,
+    create_archive, default_archive_presets, delete_entries, detect_archive_format,
+    extract_archive, list_entries, list_extract_conflicts, resolve_compression_threads,
+    supports_entry_delete, supports_name_encoding, supports_password, zip_name_sample,
+    ArchiveControl, ArchiveCreateRequest, ArchiveDeleteRequest, ArchiveEntry,
+    ArchiveExtractRequest, ArchiveFormat, ArchivePreset, ArchiveProgressEvent, ArchiveSummary,
+    CompressionLevel, ZipNameEncoding,
 };
 pub use filesystem::FileSystem;
 #[cfg(not(test))]
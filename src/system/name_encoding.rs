@@ -0,0 +1,220 @@
+//! ZIP 항목 이름 인코딩 (UTF-8 표시가 없는 옛 압축 파일의 CP949/CP437 이름 해석)
+//!
+//! 한국어 Windows에서 만든 ZIP은 이름을 CP949(EUC-KR 확장)로 저장하는 경우가 많아, CP437로
+//! 읽으면 글자가 깨집니다. 자동 감지는 UTF-8 → CP949 → CP437 순으로 바이트열 전체가 올바르게
+//! 해석되는 첫 인코딩을 고릅니다. CP949 한글 영역은 KS X 1001 2350자를 뺀 나머지 8822자가
+//! 확장 영역에 유니코드 순서대로 놓이므로 완성형 여부 비트맵만으로 계산합니다.
+
+mod tables;
+
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use tables::{CP437_HIGH, KSX1001_HANGUL_BITMAP, KSX1001_HANJA, KSX1001_SYMBOLS};
+
+/// ZIP 항목 이름 인코딩 (설정 파일 `zip_name_encoding`, 압축 보기에서 압축 파일별로 변경)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ZipNameEncoding {
+    /// UTF-8 → CP949 → CP437 순으로 감지
+    #[default]
+    Auto,
+    Utf8,
+    Cp949,
+    Cp437,
+}
+
+impl ZipNameEncoding {
+    pub const ALL: [ZipNameEncoding; 4] = [
+        ZipNameEncoding::Auto,
+        ZipNameEncoding::Utf8,
+        ZipNameEncoding::Cp949,
+        ZipNameEncoding::Cp437,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ZipNameEncoding::Auto => "auto",
+            ZipNameEncoding::Utf8 => "utf-8",
+            ZipNameEncoding::Cp949 => "cp949",
+            ZipNameEncoding::Cp437 => "cp437",
+        }
+    }
+
+    /// 이름 바이트열 해석 (고른 인코딩으로 해석할 수 없는 글자는 U+FFFD)
+    pub fn decode(self, raw: &[u8]) -> String {
+        if raw.is_ascii() {
+            return String::from_utf8_lossy(raw).into_owned();
+        }
+        match self {
+            ZipNameEncoding::Auto => std::str::from_utf8(raw)
+                .map(str::to_string)
+                .ok()
+                .or_else(|| decode_cp949(raw))
+                .unwrap_or_else(|| decode_cp437(raw)),
+            ZipNameEncoding::Utf8 => String::from_utf8_lossy(raw).into_owned(),
+            ZipNameEncoding::Cp949 => decode_cp949_lossy(raw),
+            ZipNameEncoding::Cp437 => decode_cp437(raw),
+        }
+    }
+}
+
+/// CP437 해석 (모든 바이트에 글자가 있으므로 실패하지 않음)
+pub fn decode_cp437(raw: &[u8]) -> String {
+    raw.iter()
+        .map(|&b| {
+            if b < 0x80 {
+                b as char
+            } else {
+                char::from_u32(u32::from(CP437_HIGH[usize::from(b - 0x80)])).unwrap_or('\u{FFFD}')
+            }
+        })
+        .collect()
+}
+
+/// CP949 해석 (바이트열 전체가 올바를 때만)
+pub fn decode_cp949(raw: &[u8]) -> Option<String> {
+    let mut out = String::with_capacity(raw.len());
+    let mut iter = raw.iter().copied();
+    while let Some(lead) = iter.next() {
+        if lead < 0x80 {
+            out.push(lead as char);
+            continue;
+        }
+        out.push(cp949_pair(lead, iter.next()?)?);
+    }
+    Some(out)
+}
+
+/// CP949 해석 (잘못된 바이트는 U+FFFD, 뒤 바이트가 ASCII면 따로 해석)
+pub fn decode_cp949_lossy(raw: &[u8]) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut idx = 0;
+    while idx < raw.len() {
+        let lead = raw[idx];
+        if lead < 0x80 {
+            out.push(lead as char);
+            idx += 1;
+            continue;
+        }
+        match raw.get(idx + 1).and_then(|&trail| cp949_pair(lead, trail)) {
+            Some(ch) => {
+                out.push(ch);
+                idx += 2;
+            }
+            None => {
+                out.push('\u{FFFD}');
+                idx += if raw.get(idx + 1).is_some_and(|&b| b >= 0x80) {
+                    2
+                } else {
+                    1
+                };
+            }
+        }
+    }
+    out
+}
+
+/// 2바이트 CP949 글자
+fn cp949_pair(lead: u8, trail: u8) -> Option<char> {
+    let code = if (0xA1..=0xFE).contains(&lead) && (0xA1..=0xFE).contains(&trail) {
+        let row = usize::from(lead - 0xA1);
+        let col = usize::from(trail - 0xA1);
+        match lead {
+            0xA1..=0xAC => u32::from(KSX1001_SYMBOLS[row * 94 + col]),
+            0xB0..=0xC8 => u32::from(hangul_tables().0[usize::from(lead - 0xB0) * 94 + col]),
+            0xCA..=0xFD => u32::from(KSX1001_HANJA[usize::from(lead - 0xCA) * 94 + col]),
+            _ => 0,
+        }
+    } else {
+        let extension = &hangul_tables().1;
+        uhc_index(lead, trail)
+            .and_then(|index| extension.get(index))
+            .map_or(0, |&code| u32::from(code))
+    };
+    if code == 0 {
+        None
+    } else {
+        char::from_u32(code)
+    }
+}
+
+/// 확장 영역 위치 (0x81~0xA0 리드는 0x41~0xFE, 0xA1~0xC6 리드는 0x41~0xA0 중 영문자/0x81 이상)
+fn uhc_index(lead: u8, trail: u8) -> Option<usize> {
+    let trail_index = match trail {
+        0x41..=0x5A => trail - 0x41,
+        0x61..=0x7A => trail - 0x61 + 26,
+        0x81..=0xFE => trail - 0x81 + 52,
+        _ => return None,
+    };
+    let trail_index = usize::from(trail_index);
+    match lead {
+        0x81..=0xA0 => Some(usize::from(lead - 0x81) * 178 + trail_index),
+        0xA1..=0xC6 if trail < 0xA1 => Some(32 * 178 + usize::from(lead - 0xA1) * 84 + trail_index),
+        _ => None,
+    }
+}
+
+/// (KS X 1001 완성형 2350자, 나머지 확장 영역 8822자), 유니코드 순
+fn hangul_tables() -> &'static (Vec<u16>, Vec<u16>) {
+    static TABLES: OnceLock<(Vec<u16>, Vec<u16>)> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        (0..11172u16)
+            .map(|n| {
+                (
+                    0xAC00 + n,
+                    (KSX1001_HANGUL_BITMAP[usize::from(n / 8)] >> (n % 8)) & 1 == 1,
+                )
+            })
+            .fold(
+                (Vec::with_capacity(2350), Vec::with_capacity(8822)),
+                |(mut ks, mut rest), (code, in_ks)| {
+                    if in_ks {
+                        ks.push(code);
+                    } else {
+                        rest.push(code);
+                    }
+                    (ks, rest)
+                },
+            )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cp949_decodes_wansung_extension_and_hanja() {
+        // "한글 문서.txt" (완성형), "똠방" (똠은 확장 영역), "漢字", 기호 "※"
+        let raw = [
+            0xC7, 0xD1, 0xB1, 0xDB, b' ', 0xB9, 0xAE, 0xBC, 0xAD, b'.', b't', b'x', b't',
+        ];
+        assert_eq!(decode_cp949(&raw).as_deref(), Some("한글 문서.txt"));
+        assert_eq!(
+            decode_cp949(&[0x8C, 0x63, 0xB9, 0xE6]).as_deref(),
+            Some("똠방")
+        );
+        assert_eq!(decode_cp949(&[0xC7, 0xD1, 0xC6, 0x60]), None);
+        assert_eq!(
+            decode_cp949(&[0xF9, 0xD3, 0xED, 0xAE]).as_deref(),
+            Some("漢字")
+        );
+        assert_eq!(decode_cp949(&[0xA1, 0xD8]).as_deref(), Some("※"));
+        assert_eq!(hangul_tables().0.len(), 2350);
+        assert_eq!(hangul_tables().1.len(), 8822);
+        assert_eq!(decode_cp949(&[0xC7]), None);
+        assert_eq!(decode_cp949_lossy(&[0xC7, b'a', 0xB0, 0xA1]), "\u{FFFD}a가");
+    }
+
+    #[test]
+    fn test_auto_detection_prefers_utf8_then_cp949() {
+        let korean = [0xC7, 0xD1, 0xB1, 0xDB];
+        assert_eq!(ZipNameEncoding::Auto.decode("한글".as_bytes()), "한글");
+        assert_eq!(ZipNameEncoding::Auto.decode(&korean), "한글");
+        assert_eq!(ZipNameEncoding::Cp437.decode(&korean), "╟╤▒█");
+        assert_eq!(ZipNameEncoding::Auto.decode(&[0x80, 0xFF]), "Ç\u{A0}");
+        assert!(ZipNameEncoding::Utf8.decode(&korean).contains('\u{FFFD}'));
+        assert_eq!(ZipNameEncoding::Cp949.decode(b"plain/name"), "plain/name");
+    }
+}
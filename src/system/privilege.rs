@@ -0,0 +1,273 @@
+//! 권한 부족으로 실패한 파일 작업을 관리자 권한으로 다시 실행
+//!
+//! Unix에서는 항목마다 `sudo`로 `cp`/`mv`/`rm`/`mkdir`를 실행합니다 (셸을 거치지 않으므로
+//! 경로를 따로 감쌀 필요가 없고, 비밀번호는 sudo 인증 캐시 덕분에 처음 한 번만 묻습니다).
+//! `SUDO_ASKPASS`가 있으면 `sudo -A`로 비밀번호 창 프로그램을 쓰고, 없으면 TUI를 잠시 내린
+//! 터미널에서 sudo가 직접 묻습니다. Windows(UAC)는 아직 지원하지 않아 안내 오류만 반환합니다.
+//!
+//! 대상이 이미 있는 디렉토리면 `cp`/`mv`가 그 안으로 옮기므로, Linux(GNU/BusyBox)에서는
+//! `-T`로 대상을 그대로 쓰게 하고 그 밖의 Unix에서는 실행 전에 거부합니다. `mv -n`은 대상이
+//! 있으면 아무것도 하지 않고 성공으로 끝나는 구현이 있어 실행 뒤 결과를 다시 확인합니다.
+
+use std::ffi::OsString;
+#[cfg(unix)]
+use std::io;
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::process::Command;
+
+/// 관리자 권한으로 다시 실행할 작업 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ElevatedOp {
+    /// 파일 복사 (속성 유지, 심볼릭 링크는 링크로)
+    Copy { source: PathBuf, dest: PathBuf },
+    /// 파일 이동 (대상이 있으면 덮어씀)
+    Move { source: PathBuf, dest: PathBuf },
+    /// 파일/디렉토리 영구 삭제
+    Remove { path: PathBuf },
+    /// 디렉토리 생성 (상위 디렉토리 포함)
+    CreateDir { path: PathBuf },
+    /// 이름 변경 (대상이 있으면 실패)
+    Rename { source: PathBuf, dest: PathBuf },
+}
+
+impl ElevatedOp {
+    /// sudo에 넘길 명령과 인자
+    pub fn args(&self) -> Vec<OsString> {
+        let (program, flags, paths): (&str, &[&str], Vec<&PathBuf>) = match self {
+            ElevatedOp::Copy { source, dest } => ("cp", COPY_FLAGS, vec![source, dest]),
+            ElevatedOp::Move { source, dest } => ("mv", MOVE_FLAGS, vec![source, dest]),
+            ElevatedOp::Remove { path } => ("rm", &["-rf"], vec![path]),
+            ElevatedOp::CreateDir { path } => ("mkdir", &["-p"], vec![path]),
+            ElevatedOp::Rename { source, dest } => ("mv", RENAME_FLAGS, vec![source, dest]),
+        };
+        let mut args: Vec<OsString> = std::iter::once(program)
+            .chain(flags.iter().copied())
+            .chain(std::iter::once("--"))
+            .map(OsString::from)
+            .collect();
+        args.extend(paths.into_iter().map(|path| path.clone().into_os_string()));
+        args
+    }
+
+    /// 원본과 대상 (복사/이동/이름 변경만)
+    #[cfg(unix)]
+    fn transfer(&self) -> Option<(&Path, &Path)> {
+        match self {
+            ElevatedOp::Copy { source, dest }
+            | ElevatedOp::Move { source, dest }
+            | ElevatedOp::Rename { source, dest } => Some((source, dest)),
+            ElevatedOp::Remove { .. } | ElevatedOp::CreateDir { .. } => None,
+        }
+    }
+
+    /// 실행 전 확인 (`-T`가 없는 Unix에서 대상이 디렉토리면 그 안으로 들어가므로 거부)
+    #[cfg(unix)]
+    fn check_before(&self) -> Result<(), String> {
+        let Some((_, dest)) = self.transfer() else {
+            return Ok(());
+        };
+        if !NO_TARGET_DIRECTORY && dest.is_dir() {
+            return Err(format!(
+                "{}: destination is an existing directory",
+                self.describe()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 실행 뒤 확인 (명령이 성공으로 끝났어도 원본/대상이 기대한 상태인지)
+    #[cfg(unix)]
+    fn check_after(&self) -> Result<(), String> {
+        let Some((source, dest)) = self.transfer() else {
+            return Ok(());
+        };
+        if exists(dest) == Some(false) {
+            return Err(format!("{}: destination was not created", self.describe()));
+        }
+        // 대소문자만 바꾸는 이름 변경은 대소문자를 구분하지 않는 파일시스템에서 원본 경로로도 보임
+        let case_only =
+            source.to_string_lossy().to_lowercase() == dest.to_string_lossy().to_lowercase();
+        let moved = matches!(self, ElevatedOp::Move { .. } | ElevatedOp::Rename { .. });
+        if moved && !case_only && exists(source) == Some(true) {
+            return Err(format!(
+                "{}: source is still there (destination already exists?)",
+                self.describe()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 확인 창/오류 메시지용 명령 표시
+    pub fn describe(&self) -> String {
+        self.args()
+            .iter()
+            .filter(|arg| arg.as_os_str() != "--")
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// `cp`/`mv`가 `-T`(대상을 디렉토리로 보지 않음)를 지원 (GNU coreutils, BusyBox)
+const NO_TARGET_DIRECTORY: bool = cfg!(target_os = "linux");
+const COPY_FLAGS: &[&str] = if NO_TARGET_DIRECTORY {
+    &["-a", "-T"]
+} else {
+    &["-a"]
+};
+const MOVE_FLAGS: &[&str] = if NO_TARGET_DIRECTORY {
+    &["-f", "-T"]
+} else {
+    &["-f"]
+};
+const RENAME_FLAGS: &[&str] = if NO_TARGET_DIRECTORY {
+    &["-n", "-T"]
+} else {
+    &["-n"]
+};
+
+/// 경로에 항목이 있는지 (링크는 따라가지 않음, 권한이 없어 확인하지 못하면 None)
+#[cfg(unix)]
+fn exists(path: &Path) -> Option<bool> {
+    match std::fs::symlink_metadata(path) {
+        Ok(_) => Some(true),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Some(false),
+        Err(_) => None,
+    }
+}
+
+/// 관리자 권한 재시도 요청 (메인 루프에서 실행)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElevatedRequest {
+    /// 원래 작업 이름 (작업 기록/알림용)
+    pub operation: String,
+    pub ops: Vec<ElevatedOp>,
+}
+
+impl ElevatedRequest {
+    /// 비밀번호를 터미널에서 물어야 함 (TUI를 잠시 내려야 함)
+    pub fn needs_terminal(&self) -> bool {
+        cfg!(unix) && std::env::var_os("SUDO_ASKPASS").is_none()
+    }
+}
+
+/// 관리자 권한 재시도를 제안할 수 있음 (Unix에서 `sudo`가 PATH에 있음)
+pub fn is_available() -> bool {
+    #[cfg(unix)]
+    {
+        std::env::var_os("PATH").is_some_and(|paths| {
+            std::env::split_paths(&paths).any(|dir| dir.join("sudo").is_file())
+        })
+    }
+    #[cfg(not(unix))]
+    {
+        false
+    }
+}
+
+/// 요청의 작업을 차례로 실행하고 실패한 작업의 오류 반환
+pub fn run(request: &ElevatedRequest) -> Vec<String> {
+    #[cfg(unix)]
+    {
+        let askpass = std::env::var_os("SUDO_ASKPASS").is_some();
+        let mut errors = Vec::new();
+        for op in &request.ops {
+            let mut command = Command::new("sudo");
+            if askpass {
+                command.arg("-A");
+            }
+            if let Err(problem) = op.check_before() {
+                errors.push(problem);
+                continue;
+            }
+            let result = command.arg("--").args(op.args()).status();
+            match result {
+                Ok(status) if status.success() => {
+                    if let Err(problem) = op.check_after() {
+                        errors.push(problem);
+                    }
+                }
+                Ok(status) => errors.push(format!("{}: exited with {}", op.describe(), status)),
+                Err(e) => {
+                    errors.push(format!("{}: failed to start sudo: {}", op.describe(), e));
+                    break;
+                }
+            }
+        }
+        errors
+    }
+    #[cfg(not(unix))]
+    {
+        vec![format!(
+            "{}: elevation (UAC) is not supported yet. Run BokslDir as Administrator.",
+            request.operation
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elevated_op_args_keep_paths_as_separate_arguments() {
+        let op = ElevatedOp::Copy {
+            source: PathBuf::from("/etc/a b"),
+            dest: PathBuf::from("/opt/-x"),
+        };
+        let expected: Vec<OsString> = std::iter::once("cp")
+            .chain(COPY_FLAGS.iter().copied())
+            .chain(["--", "/etc/a b", "/opt/-x"])
+            .map(OsString::from)
+            .collect();
+        assert_eq!(op.args(), expected);
+        if NO_TARGET_DIRECTORY {
+            assert_eq!(op.describe(), "cp -a -T /etc/a b /opt/-x");
+        }
+        assert_eq!(
+            ElevatedOp::Remove {
+                path: PathBuf::from("/x")
+            }
+            .describe(),
+            "rm -rf /x"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rename_check_after_catches_noop_and_missing_dest() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("a");
+        let dest = temp.path().join("b");
+        std::fs::write(&source, "a").unwrap();
+        std::fs::write(&dest, "b").unwrap();
+        let rename = ElevatedOp::Rename {
+            source: source.clone(),
+            dest: dest.clone(),
+        };
+        // `mv -n`이 기존 대상을 두고 성공으로 끝난 경우
+        assert!(rename
+            .check_after()
+            .unwrap_err()
+            .contains("source is still there"));
+
+        std::fs::remove_file(&dest).unwrap();
+        assert!(rename.check_after().unwrap_err().contains("not created"));
+
+        std::fs::rename(&source, &dest).unwrap();
+        assert!(rename.check_after().is_ok());
+
+        // 대상이 디렉토리면 `-T`로 막거나 실행 전에 거부
+        std::fs::create_dir(&source).unwrap();
+        let copy = ElevatedOp::Copy {
+            source: dest.clone(),
+            dest: source.clone(),
+        };
+        if NO_TARGET_DIRECTORY {
+            assert!(copy.args().contains(&OsString::from("-T")));
+        } else {
+            assert!(copy.check_before().is_err());
+        }
+    }
+}
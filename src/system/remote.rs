@@ -0,0 +1,663 @@
+//! 원격 파일시스템 세션 (SFTP/FTP/FTPS 공통 인터페이스와 전송)
+//!
+//! 패널과 전송 워커는 프로토콜과 무관하게 `RemoteSession`만 사용합니다.
+
+#![allow(dead_code)]
+
+use crate::models::file_entry::FileEntry;
+use crate::models::remote::{RemoteHost, RemoteProtocol};
+use crate::system::archive::{ArchiveControl, ArchiveProgressEvent, ArchiveSummary};
+use crate::system::ftp::FtpSession;
+use crate::system::sftp::SftpSession;
+use crate::system::vfs::{VfsStat, VfsWriter, VirtualFileSystem};
+use crate::utils::error::{BokslDirError, Result};
+use std::fs;
+use std::io::{self, Cursor, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// 진행률 이벤트 최소 간격
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 원격 경로 결합 (`/` 구분)
+pub fn join_remote(dir: &str, name: &str) -> String {
+    if dir.is_empty() || dir == "." {
+        name.to_string()
+    } else if dir.ends_with('/') {
+        format!("{}{}", dir, name)
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+/// 원격 경로의 상위 디렉토리 (루트면 None)
+pub fn remote_parent(path: &str) -> Option<String> {
+    let trimmed = path.trim_end_matches('/');
+    if trimmed.is_empty() {
+        return None;
+    }
+    match trimmed.rfind('/') {
+        Some(0) => Some("/".to_string()),
+        Some(pos) => Some(trimmed[..pos].to_string()),
+        None => None,
+    }
+}
+
+/// 원격 경로의 마지막 이름
+pub fn remote_file_name(path: &str) -> &str {
+    let trimmed = path.trim_end_matches('/');
+    trimmed.rsplit('/').next().unwrap_or(trimmed)
+}
+
+pub(crate) fn remote_error(host: &RemoteHost, reason: String) -> BokslDirError {
+    BokslDirError::RemoteFailed {
+        host: host.display_name(),
+        reason,
+    }
+}
+
+/// 원격 경로 속성 (링크를 따라간 값)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RemoteStat {
+    pub is_dir: bool,
+    pub size: u64,
+    pub permissions: Option<u32>,
+}
+
+/// 재귀 전송 대상 항목
+#[derive(Debug, Clone)]
+struct TransferItem {
+    source: String,
+    dest: String,
+    is_dir: bool,
+    size: u64,
+    permissions: Option<u32>,
+}
+
+/// 전송 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// 원격 → 로컬
+    Download,
+    /// 로컬 → 원격
+    Upload,
+}
+
+/// 원격 전송 요청 (워커 스레드 입력)
+#[derive(Debug, Clone)]
+pub struct RemoteTransferRequest {
+    pub direction: TransferDirection,
+    /// 원본 경로 (다운로드면 원격 경로, 업로드면 로컬 경로)
+    pub sources: Vec<PathBuf>,
+    /// 대상 디렉토리 (다운로드면 로컬, 업로드면 원격)
+    pub dest_dir: PathBuf,
+    /// 전송 성공 후 원본 삭제 (이동)
+    pub remove_source: bool,
+}
+
+/// 프로토콜별 원격 세션
+#[derive(Debug)]
+pub enum RemoteSession {
+    Sftp(SftpSession),
+    Ftp(FtpSession),
+}
+
+impl RemoteSession {
+    /// URL 프로토콜에 맞는 세션으로 접속
+    pub fn connect(host: &RemoteHost) -> Result<Self> {
+        log::info!("connecting to {:?} {}", host.protocol, host.display_name());
+        let session = match host.protocol {
+            RemoteProtocol::Sftp => SftpSession::connect(host).map(RemoteSession::Sftp),
+            RemoteProtocol::Ftp | RemoteProtocol::Ftps => {
+                FtpSession::connect(host).map(RemoteSession::Ftp)
+            }
+        };
+        if let Err(err) = &session {
+            log::warn!("connection to {} failed: {}", host.display_name(), err);
+        }
+        session
+    }
+
+    pub fn host(&self) -> &RemoteHost {
+        match self {
+            RemoteSession::Sftp(session) => session.host(),
+            RemoteSession::Ftp(session) => session.host(),
+        }
+    }
+
+    /// 원격 홈 디렉토리 (접속 직후 작업 디렉토리)
+    pub fn home(&self) -> &str {
+        match self {
+            RemoteSession::Sftp(session) => session.home(),
+            RemoteSession::Ftp(session) => session.home(),
+        }
+    }
+
+    /// 경로 정규화 (상대 경로는 홈 기준)
+    pub fn canonicalize(&mut self, path: &str) -> Result<String> {
+        match self {
+            RemoteSession::Sftp(session) => session.canonicalize(path),
+            RemoteSession::Ftp(session) => session.canonicalize(path),
+        }
+    }
+
+    /// 디렉토리 목록을 패널 엔트리로 읽기
+    pub fn list(&mut self, dir: &str) -> Result<Vec<FileEntry>> {
+        match self {
+            RemoteSession::Sftp(session) => session.list(dir),
+            RemoteSession::Ftp(session) => session.list(dir),
+        }
+    }
+
+    pub fn stat(&mut self, path: &str) -> Result<RemoteStat> {
+        match self {
+            RemoteSession::Sftp(session) => session.stat(path).map(|attrs| RemoteStat {
+                is_dir: attrs.is_dir(),
+                size: attrs.size.unwrap_or(0),
+                permissions: attrs.permissions,
+            }),
+            RemoteSession::Ftp(session) => session.stat(path),
+        }
+    }
+
+    fn exists(&mut self, path: &str) -> bool {
+        match self {
+            RemoteSession::Sftp(session) => session.exists(path),
+            RemoteSession::Ftp(session) => session.exists(path),
+        }
+    }
+
+    pub fn make_dir(&mut self, path: &str) -> Result<()> {
+        match self {
+            RemoteSession::Sftp(session) => session.make_dir(path),
+            RemoteSession::Ftp(session) => session.make_dir(path),
+        }
+    }
+
+    pub fn rename(&mut self, from: &str, to: &str) -> Result<()> {
+        match self {
+            RemoteSession::Sftp(session) => session.rename(from, to),
+            RemoteSession::Ftp(session) => session.rename(from, to),
+        }
+    }
+
+    /// 파일 또는 디렉토리(재귀) 삭제
+    pub fn remove_all(&mut self, path: &str) -> Result<()> {
+        match self {
+            RemoteSession::Sftp(session) => session.remove_all(path),
+            RemoteSession::Ftp(session) => session.remove_all(path),
+        }
+    }
+
+    fn child_names(&mut self, dir: &str) -> Result<Vec<String>> {
+        match self {
+            RemoteSession::Sftp(session) => session.child_names(dir),
+            RemoteSession::Ftp(session) => session.child_names(dir),
+        }
+    }
+
+    fn download_file<F: Write + Seek>(
+        &mut self,
+        path: &str,
+        dest: &mut F,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<()> {
+        match self {
+            RemoteSession::Sftp(session) => session.download_file(path, dest, on_chunk),
+            RemoteSession::Ftp(session) => session.download_file(path, dest, on_chunk),
+        }
+    }
+
+    fn upload_file(
+        &mut self,
+        source: &mut dyn Read,
+        path: &str,
+        permissions: Option<u32>,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<()> {
+        match self {
+            RemoteSession::Sftp(session) => {
+                session.upload_file(source, path, permissions, on_chunk)
+            }
+            RemoteSession::Ftp(session) => session.upload_file(source, path, permissions, on_chunk),
+        }
+    }
+
+    fn remove_file(&mut self, path: &str) -> io::Result<()> {
+        match self {
+            RemoteSession::Sftp(session) => session.remove_file(path),
+            RemoteSession::Ftp(session) => session.remove_file(path),
+        }
+    }
+
+    /// 원격 원본을 재귀로 펼치기 (디렉토리 먼저)
+    fn collect_remote_items(
+        &mut self,
+        source: &str,
+        dest: PathBuf,
+        items: &mut Vec<TransferItem>,
+    ) -> Result<()> {
+        let stat = self.stat(source)?;
+        if stat.is_dir {
+            items.push(TransferItem {
+                source: source.to_string(),
+                dest: dest.to_string_lossy().to_string(),
+                is_dir: true,
+                size: 0,
+                permissions: stat.permissions,
+            });
+            for name in self.child_names(source)? {
+                self.collect_remote_items(&join_remote(source, &name), dest.join(&name), items)?;
+            }
+        } else {
+            items.push(TransferItem {
+                source: source.to_string(),
+                dest: dest.to_string_lossy().to_string(),
+                is_dir: false,
+                size: stat.size,
+                permissions: stat.permissions,
+            });
+        }
+        Ok(())
+    }
+
+    /// 전송 실행 (워커 스레드). 진행률은 압축 작업과 같은 이벤트로 보냅니다.
+    ///
+    /// 대상에 이미 있는 파일은 덮어쓰지 않고 오류로 기록합니다.
+    pub fn transfer(
+        &mut self,
+        request: &RemoteTransferRequest,
+        progress_tx: &Sender<ArchiveProgressEvent>,
+        control: &ArchiveControl,
+    ) -> Result<ArchiveSummary> {
+        // 원본별 펼친 항목 (이동 시 원본 단위로 삭제 판단)
+        let mut groups: Vec<(PathBuf, Vec<TransferItem>)> = Vec::new();
+        for source in &request.sources {
+            let name = source
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| remote_file_name(&source.to_string_lossy()).to_string());
+            let mut items = Vec::new();
+            match request.direction {
+                TransferDirection::Download => self.collect_remote_items(
+                    &source.to_string_lossy(),
+                    request.dest_dir.join(&name),
+                    &mut items,
+                )?,
+                TransferDirection::Upload => collect_local_items(
+                    source,
+                    join_remote(&request.dest_dir.to_string_lossy(), &name),
+                    &mut items,
+                )?,
+            }
+            groups.push((source.clone(), items));
+        }
+        let total_files = groups.iter().map(|(_, items)| items.len()).sum();
+        let total_bytes = groups
+            .iter()
+            .flat_map(|(_, items)| items.iter())
+            .map(|item| item.size)
+            .sum();
+        let mut summary = ArchiveSummary::new(total_files, total_bytes);
+        let mut bytes_done = 0u64;
+        let mut last_event = Instant::now() - PROGRESS_INTERVAL;
+
+        let send_progress = |summary: &ArchiveSummary, current: &str, bytes: u64| {
+            let _ = progress_tx.send(ArchiveProgressEvent {
+                current_file: current.to_string(),
+                files_completed: summary.items_processed - summary.items_failed,
+                total_files: summary.total_files,
+                bytes_processed: bytes,
+                total_bytes: summary.total_bytes,
+                items_processed: summary.items_processed,
+                items_failed: summary.items_failed,
+            });
+        };
+
+        'groups: for (source, items) in &groups {
+            let mut group_failed = false;
+            for item in items {
+                if control.wait_while_paused() {
+                    summary.cancelled = true;
+                    break 'groups;
+                }
+                let display_name = remote_file_name(&item.source).to_string();
+                send_progress(&summary, &display_name, bytes_done);
+                let result = if item.is_dir {
+                    self.transfer_dir(request.direction, item)
+                } else {
+                    let mut on_chunk = |bytes: u64| {
+                        bytes_done += bytes;
+                        if last_event.elapsed() >= PROGRESS_INTERVAL {
+                            last_event = Instant::now();
+                            send_progress(&summary, &display_name, bytes_done);
+                        }
+                        !control.wait_while_paused()
+                    };
+                    self.transfer_file(request.direction, item, &mut on_chunk)
+                };
+                summary.items_processed += 1;
+                match result {
+                    Ok(()) => {}
+                    Err(BokslDirError::OperationCancelled) => {
+                        summary.items_processed -= 1;
+                        summary.cancelled = true;
+                        break 'groups;
+                    }
+                    Err(e) => {
+                        summary.items_failed += 1;
+                        summary.errors.push(format!("{}: {}", display_name, e));
+                        group_failed = true;
+                    }
+                }
+            }
+            if request.remove_source && !group_failed {
+                let removed = match request.direction {
+                    TransferDirection::Download => self.remove_all(&source.to_string_lossy()),
+                    TransferDirection::Upload => remove_local(source),
+                };
+                if let Err(e) = removed {
+                    summary.errors.push(format!(
+                        "Failed to remove source after copy {}: {}",
+                        source.display(),
+                        e
+                    ));
+                }
+            }
+        }
+        send_progress(&summary, "", bytes_done);
+        Ok(summary)
+    }
+
+    fn transfer_dir(&mut self, direction: TransferDirection, item: &TransferItem) -> Result<()> {
+        match direction {
+            TransferDirection::Download => {
+                fs::create_dir_all(&item.dest).map_err(BokslDirError::Io)
+            }
+            TransferDirection::Upload => match self.stat(&item.dest) {
+                Ok(stat) if stat.is_dir => Ok(()),
+                Ok(_) => Err(BokslDirError::FileExists {
+                    path: PathBuf::from(&item.dest),
+                }),
+                Err(_) => self.make_dir(&item.dest),
+            },
+        }
+    }
+
+    fn transfer_file(
+        &mut self,
+        direction: TransferDirection,
+        item: &TransferItem,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> Result<()> {
+        let copy_error = |e: io::Error| {
+            if e.kind() == io::ErrorKind::Interrupted {
+                BokslDirError::OperationCancelled
+            } else {
+                BokslDirError::CopyFailed {
+                    src: PathBuf::from(&item.source),
+                    dest: PathBuf::from(&item.dest),
+                    reason: e.to_string(),
+                }
+            }
+        };
+        match direction {
+            TransferDirection::Download => {
+                let dest = Path::new(&item.dest);
+                if fs::symlink_metadata(dest).is_ok() {
+                    return Err(BokslDirError::FileExists {
+                        path: dest.to_path_buf(),
+                    });
+                }
+                let mut file = fs::File::create(dest).map_err(BokslDirError::Io)?;
+                let result = self.download_file(&item.source, &mut file, on_chunk);
+                drop(file);
+                match result {
+                    Ok(()) => {
+                        apply_downloaded_metadata(dest, item.permissions);
+                        Ok(())
+                    }
+                    Err(e) => {
+                        let _ = fs::remove_file(dest);
+                        Err(copy_error(e))
+                    }
+                }
+            }
+            TransferDirection::Upload => {
+                if self.exists(&item.dest) {
+                    return Err(BokslDirError::FileExists {
+                        path: PathBuf::from(&item.dest),
+                    });
+                }
+                let mut file = fs::File::open(&item.source).map_err(BokslDirError::Io)?;
+                match self.upload_file(&mut file, &item.dest, item.permissions, on_chunk) {
+                    Ok(()) => Ok(()),
+                    Err(e) => {
+                        let _ = self.remove_file(&item.dest);
+                        Err(copy_error(e))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 원격 세션을 패널 백엔드로 마운트 (패널 경로는 `sftp://host/dir` 형식)
+///
+/// 전송 워커가 세션을 쓰는 동안에는 기다리지 않고 busy 오류를 돌려줍니다.
+#[derive(Debug, Clone)]
+pub struct RemoteFs {
+    host: RemoteHost,
+    session: Arc<Mutex<RemoteSession>>,
+}
+
+impl RemoteFs {
+    pub fn new(host: RemoteHost, session: Arc<Mutex<RemoteSession>>) -> Self {
+        Self { host, session }
+    }
+
+    pub fn session(&self) -> &Arc<Mutex<RemoteSession>> {
+        &self.session
+    }
+
+    /// 원격 절대 경로 → 패널 표시 경로
+    pub fn display_path(&self, remote_path: &str) -> PathBuf {
+        PathBuf::from(self.host.url_with_path(remote_path))
+    }
+
+    /// 패널 경로(URL 또는 원격 절대 경로) → 원격 절대 경로
+    pub fn remote_path(&self, path: &Path) -> String {
+        let path = path.to_string_lossy();
+        match path.strip_prefix(&self.host.endpoint_url()) {
+            Some("") => "/".to_string(),
+            Some(rest) => rest.to_string(),
+            None => path.to_string(),
+        }
+    }
+
+    fn with_session<T>(&self, op: impl FnOnce(&mut RemoteSession) -> Result<T>) -> Result<T> {
+        match self.session.try_lock() {
+            Ok(mut session) => op(&mut session),
+            Err(_) => Err(remote_error(
+                &self.host,
+                "session is busy with a transfer".to_string(),
+            )),
+        }
+    }
+}
+
+/// 원격 쓰기 스트림 (메모리에 모았다가 commit 시 업로드)
+struct RemoteWriter {
+    fs: RemoteFs,
+    path: String,
+    buffer: Vec<u8>,
+}
+
+impl Write for RemoteWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl VfsWriter for RemoteWriter {
+    fn commit(self: Box<Self>) -> Result<()> {
+        let RemoteWriter { fs, path, buffer } = *self;
+        fs.with_session(|session| {
+            if session.exists(&path) {
+                return Err(BokslDirError::FileExists {
+                    path: PathBuf::from(&path),
+                });
+            }
+            session
+                .upload_file(&mut Cursor::new(buffer), &path, None, &mut |_| true)
+                .map_err(|e| BokslDirError::CopyFailed {
+                    src: PathBuf::new(),
+                    dest: PathBuf::from(&path),
+                    reason: e.to_string(),
+                })
+        })
+    }
+}
+
+impl VirtualFileSystem for RemoteFs {
+    fn list(&self, dir: &Path) -> Result<Vec<FileEntry>> {
+        let dir = self.remote_path(dir);
+        self.with_session(|session| session.list(&dir))
+    }
+
+    fn stat(&self, path: &Path) -> Result<VfsStat> {
+        let path = self.remote_path(path);
+        let stat = self.with_session(|session| session.stat(&path))?;
+        Ok(VfsStat {
+            is_dir: stat.is_dir,
+            size: stat.size,
+            modified: None,
+            permissions: stat.permissions,
+        })
+    }
+
+    /// 원격 파일을 메모리로 받아 읽기 (뷰어/미리보기용, 큰 파일은 전송 사용)
+    fn open_read(&self, path: &Path) -> Result<Box<dyn Read + Send>> {
+        let path = self.remote_path(path);
+        let mut buffer = Cursor::new(Vec::new());
+        self.with_session(|session| {
+            session
+                .download_file(&path, &mut buffer, &mut |_| true)
+                .map_err(|e| remote_error(session.host(), format!("{}: {}", path, e)))
+        })?;
+        buffer.set_position(0);
+        Ok(Box::new(buffer))
+    }
+
+    fn open_write(&self, path: &Path) -> Result<Box<dyn VfsWriter>> {
+        Ok(Box::new(RemoteWriter {
+            fs: self.clone(),
+            path: self.remote_path(path),
+            buffer: Vec::new(),
+        }))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let (from, to) = (self.remote_path(from), self.remote_path(to));
+        self.with_session(|session| session.rename(&from, &to))
+    }
+
+    fn delete(&self, path: &Path) -> Result<()> {
+        let path = self.remote_path(path);
+        self.with_session(|session| session.remove_all(&path))
+    }
+
+    fn mkdir(&self, path: &Path) -> Result<()> {
+        let path = self.remote_path(path);
+        self.with_session(|session| session.make_dir(&path))
+    }
+
+    fn parent(&self, path: &Path) -> Option<PathBuf> {
+        remote_parent(&self.remote_path(path)).map(|parent| self.display_path(&parent))
+    }
+}
+
+/// 로컬 원본을 재귀로 펼치기 (디렉토리 먼저, 심볼릭 링크는 대상 기준)
+fn collect_local_items(source: &Path, dest: String, items: &mut Vec<TransferItem>) -> Result<()> {
+    let metadata = fs::metadata(source).map_err(|_| BokslDirError::PathNotFound {
+        path: source.to_path_buf(),
+    })?;
+    #[cfg(unix)]
+    let permissions = {
+        use std::os::unix::fs::PermissionsExt;
+        Some(metadata.permissions().mode())
+    };
+    #[cfg(not(unix))]
+    let permissions = None;
+    if metadata.is_dir() {
+        items.push(TransferItem {
+            source: source.to_string_lossy().to_string(),
+            dest: dest.clone(),
+            is_dir: true,
+            size: 0,
+            permissions,
+        });
+        let mut children: Vec<_> = fs::read_dir(source)
+            .map_err(BokslDirError::Io)?
+            .filter_map(|entry| entry.ok())
+            .collect();
+        children.sort_by_key(|entry| entry.file_name());
+        for child in children {
+            let name = child.file_name().to_string_lossy().to_string();
+            collect_local_items(&child.path(), join_remote(&dest, &name), items)?;
+        }
+    } else {
+        items.push(TransferItem {
+            source: source.to_string_lossy().to_string(),
+            dest,
+            is_dir: false,
+            size: metadata.len(),
+            permissions,
+        });
+    }
+    Ok(())
+}
+
+fn remove_local(path: &Path) -> Result<()> {
+    let result = if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
+    result.map_err(|e| BokslDirError::DeleteFailed {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })
+}
+
+/// 받은 파일에 원격 권한 적용 (실패는 무시)
+fn apply_downloaded_metadata(dest: &Path, permissions: Option<u32>) {
+    #[cfg(unix)]
+    if let Some(mode) = permissions {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(dest, fs::Permissions::from_mode(mode & 0o7777));
+    }
+    #[cfg(not(unix))]
+    let _ = (dest, permissions);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_path_helpers() {
+        assert_eq!(join_remote("/", "a"), "/a");
+        assert_eq!(join_remote("/srv", "a"), "/srv/a");
+        assert_eq!(remote_parent("/srv/data/"), Some("/srv".to_string()));
+        assert_eq!(remote_parent("/srv"), Some("/".to_string()));
+        assert_eq!(remote_parent("/"), None);
+        assert_eq!(remote_file_name("/srv/data/"), "data");
+    }
+}
@@ -0,0 +1,135 @@
+//! Windows 재분석 지점(reparse point) 판별
+//!
+//! Rust 표준 라이브러리는 심볼릭 링크와 디렉토리 정션을 모두 `is_symlink()`로 보고하므로,
+//! 둘을 구분하려면 `FindFirstFileW`로 재분석 태그를 직접 읽습니다. 다른 경로를 가리키는 태그
+//! (name surrogate)는 링크로 보고 크기 계산/평탄화에서 따라가지 않으며, 그 밖의 재분석 지점
+//! (OneDrive 자리 표시자, 중복 제거 파일 등)은 일반 파일/디렉토리처럼 다루고 표시만 구분합니다.
+//! Windows가 아니면 항상 재분석 지점이 아닙니다.
+
+use crate::models::file_entry::ReparseKind;
+use std::fs::Metadata;
+use std::path::Path;
+
+/// 디렉토리 정션 / 볼륨 마운트 지점
+#[cfg_attr(not(windows), allow(dead_code))]
+pub const IO_REPARSE_TAG_MOUNT_POINT: u32 = 0xA000_0003;
+/// 심볼릭 링크
+#[cfg_attr(not(windows), allow(dead_code))]
+pub const IO_REPARSE_TAG_SYMLINK: u32 = 0xA000_000C;
+/// 다른 경로를 가리키는 태그 표시 비트
+#[cfg_attr(not(windows), allow(dead_code))]
+const NAME_SURROGATE_BIT: u32 = 0x2000_0000;
+
+impl ReparseKind {
+    /// 재분석 태그 분류 (다른 경로를 가리키는 태그는 심볼릭 링크로 취급)
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub fn from_tag(tag: u32) -> Self {
+        match tag {
+            IO_REPARSE_TAG_MOUNT_POINT => ReparseKind::Junction,
+            IO_REPARSE_TAG_SYMLINK => ReparseKind::Symlink,
+            tag if tag & NAME_SURROGATE_BIT != 0 => ReparseKind::Symlink,
+            _ => ReparseKind::Other,
+        }
+    }
+
+    /// 다른 경로를 가리킴 (재귀 탐색에서 따라가지 않음)
+    pub fn is_link(self) -> bool {
+        matches!(self, ReparseKind::Symlink | ReparseKind::Junction)
+    }
+}
+
+/// 경로의 재분석 지점 종류 (`metadata`는 링크를 따라가지 않은 메타데이터)
+pub fn reparse_kind(path: &Path, metadata: &Metadata) -> Option<ReparseKind> {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+        if metadata.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT == 0 {
+            return None;
+        }
+        Some(windows::reparse_tag(path).map_or(ReparseKind::Other, ReparseKind::from_tag))
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = (path, metadata);
+        None
+    }
+}
+
+/// 재귀 탐색에서 따라가지 않을 링크 (심볼릭 링크 또는 정션)
+pub fn is_link(path: &Path, metadata: &Metadata) -> bool {
+    metadata.file_type().is_symlink()
+        || reparse_kind(path, metadata).is_some_and(ReparseKind::is_link)
+}
+
+#[cfg(windows)]
+mod windows {
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::Path;
+
+    const INVALID_HANDLE_VALUE: isize = -1;
+
+    #[repr(C)]
+    struct FileTime {
+        low: u32,
+        high: u32,
+    }
+
+    #[repr(C)]
+    struct Win32FindDataW {
+        file_attributes: u32,
+        creation_time: FileTime,
+        last_access_time: FileTime,
+        last_write_time: FileTime,
+        file_size_high: u32,
+        file_size_low: u32,
+        /// 재분석 지점이면 재분석 태그
+        reserved0: u32,
+        reserved1: u32,
+        file_name: [u16; 260],
+        alternate_file_name: [u16; 14],
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn FindFirstFileW(path: *const u16, data: *mut Win32FindDataW) -> isize;
+        fn FindClose(handle: isize) -> i32;
+    }
+
+    /// 재분석 태그 (읽을 수 없으면 None)
+    pub fn reparse_tag(path: &Path) -> Option<u32> {
+        let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+        // SAFETY: NUL 종료 UTF-16 경로와 호출 동안 살아 있는 출력 버퍼를 전달
+        unsafe {
+            let mut data: Win32FindDataW = std::mem::zeroed();
+            let handle = FindFirstFileW(wide.as_ptr(), &mut data);
+            if handle == INVALID_HANDLE_VALUE {
+                return None;
+            }
+            FindClose(handle);
+            Some(data.reserved0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reparse_tags_classify_links() {
+        assert_eq!(
+            ReparseKind::from_tag(IO_REPARSE_TAG_MOUNT_POINT),
+            ReparseKind::Junction
+        );
+        assert_eq!(
+            ReparseKind::from_tag(IO_REPARSE_TAG_SYMLINK),
+            ReparseKind::Symlink
+        );
+        // WSL 심볼릭 링크 (name surrogate)
+        assert!(ReparseKind::from_tag(0xA000_001D).is_link());
+        // OneDrive 자리 표시자 / 중복 제거
+        assert_eq!(ReparseKind::from_tag(0x9000_601A), ReparseKind::Other);
+        assert!(!ReparseKind::from_tag(0x8000_0013).is_link());
+    }
+}
@@ -0,0 +1,1393 @@
+//! SFTP 원격 파일시스템 (OpenSSH `ssh -s sftp` 서브시스템 위의 SFTP v3)
+//!
+//! 인증과 호스트 키 확인은 시스템 ssh 설정(키, 에이전트, `~/.ssh/config`, known_hosts)을
+//! 그대로 사용합니다. 비밀번호 입력 창은 띄우지 않으며(BatchMode), 처음 접속하는 호스트의
+//! 키는 known_hosts에 자동 등록합니다(accept-new).
+
+#![allow(dead_code)]
+
+use crate::models::file_entry::{FileEntry, FileType};
+use crate::models::remote::RemoteHost;
+use crate::system::remote::{join_remote, remote_error};
+use crate::utils::error::{BokslDirError, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+
+const SFTP_VERSION: u32 = 3;
+/// 요청 1개당 읽기/쓰기 크기
+const TRANSFER_CHUNK_SIZE: u32 = 32 * 1024;
+/// 동시에 보내 둘 읽기/쓰기 요청 수 (왕복 지연 숨김)
+const MAX_OUTSTANDING_REQUESTS: usize = 16;
+/// 수신 패킷 최대 크기 (비정상 길이 방어)
+const MAX_PACKET_SIZE: usize = 512 * 1024;
+/// ssh stderr 보관 최대 길이 (접속 실패 원인 표시용)
+const STDERR_LOG_LIMIT: usize = 4096;
+
+const SSH_FXP_INIT: u8 = 1;
+const SSH_FXP_VERSION: u8 = 2;
+const SSH_FXP_OPEN: u8 = 3;
+const SSH_FXP_CLOSE: u8 = 4;
+const SSH_FXP_READ: u8 = 5;
+const SSH_FXP_WRITE: u8 = 6;
+const SSH_FXP_LSTAT: u8 = 7;
+const SSH_FXP_OPENDIR: u8 = 11;
+const SSH_FXP_READDIR: u8 = 12;
+const SSH_FXP_REMOVE: u8 = 13;
+const SSH_FXP_MKDIR: u8 = 14;
+const SSH_FXP_RMDIR: u8 = 15;
+const SSH_FXP_REALPATH: u8 = 16;
+const SSH_FXP_STAT: u8 = 17;
+const SSH_FXP_RENAME: u8 = 18;
+const SSH_FXP_STATUS: u8 = 101;
+const SSH_FXP_HANDLE: u8 = 102;
+const SSH_FXP_DATA: u8 = 103;
+const SSH_FXP_NAME: u8 = 104;
+const SSH_FXP_ATTRS: u8 = 105;
+
+const SSH_FX_OK: u32 = 0;
+const SSH_FX_EOF: u32 = 1;
+const SSH_FX_NO_SUCH_FILE: u32 = 2;
+const SSH_FX_PERMISSION_DENIED: u32 = 3;
+
+const SSH_FXF_READ: u32 = 0x01;
+const SSH_FXF_WRITE: u32 = 0x02;
+const SSH_FXF_CREAT: u32 = 0x08;
+const SSH_FXF_TRUNC: u32 = 0x10;
+
+const SSH_FILEXFER_ATTR_SIZE: u32 = 0x01;
+const SSH_FILEXFER_ATTR_UIDGID: u32 = 0x02;
+const SSH_FILEXFER_ATTR_PERMISSIONS: u32 = 0x04;
+const SSH_FILEXFER_ATTR_ACMODTIME: u32 = 0x08;
+const SSH_FILEXFER_ATTR_EXTENDED: u32 = 0x8000_0000;
+
+const S_IFMT: u32 = 0o170000;
+const S_IFDIR: u32 = 0o040000;
+const S_IFLNK: u32 = 0o120000;
+
+/// 파일 속성 (SFTP ATTRS 중 사용하는 값)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SftpAttrs {
+    pub size: Option<u64>,
+    pub uid: Option<u32>,
+    pub gid: Option<u32>,
+    pub permissions: Option<u32>,
+    pub atime: Option<u32>,
+    pub mtime: Option<u32>,
+}
+
+impl SftpAttrs {
+    pub fn is_dir(&self) -> bool {
+        self.permissions
+            .is_some_and(|mode| mode & S_IFMT == S_IFDIR)
+    }
+
+    pub fn is_symlink(&self) -> bool {
+        self.permissions
+            .is_some_and(|mode| mode & S_IFMT == S_IFLNK)
+    }
+
+    fn encode(&self, packet: &mut PacketWriter) {
+        let mut flags = 0;
+        if self.size.is_some() {
+            flags |= SSH_FILEXFER_ATTR_SIZE;
+        }
+        if self.permissions.is_some() {
+            flags |= SSH_FILEXFER_ATTR_PERMISSIONS;
+        }
+        if self.atime.is_some() && self.mtime.is_some() {
+            flags |= SSH_FILEXFER_ATTR_ACMODTIME;
+        }
+        packet.put_u32(flags);
+        if let Some(size) = self.size {
+            packet.put_u64(size);
+        }
+        if let Some(mode) = self.permissions {
+            packet.put_u32(mode);
+        }
+        if let (Some(atime), Some(mtime)) = (self.atime, self.mtime) {
+            packet.put_u32(atime);
+            packet.put_u32(mtime);
+        }
+    }
+
+    fn decode(reader: &mut PacketReader<'_>) -> io::Result<Self> {
+        let flags = reader.u32()?;
+        let mut attrs = Self::default();
+        if flags & SSH_FILEXFER_ATTR_SIZE != 0 {
+            attrs.size = Some(reader.u64()?);
+        }
+        if flags & SSH_FILEXFER_ATTR_UIDGID != 0 {
+            attrs.uid = Some(reader.u32()?);
+            attrs.gid = Some(reader.u32()?);
+        }
+        if flags & SSH_FILEXFER_ATTR_PERMISSIONS != 0 {
+            attrs.permissions = Some(reader.u32()?);
+        }
+        if flags & SSH_FILEXFER_ATTR_ACMODTIME != 0 {
+            attrs.atime = Some(reader.u32()?);
+            attrs.mtime = Some(reader.u32()?);
+        }
+        if flags & SSH_FILEXFER_ATTR_EXTENDED != 0 {
+            for _ in 0..reader.u32()? {
+                reader.bytes()?;
+                reader.bytes()?;
+            }
+        }
+        Ok(attrs)
+    }
+}
+
+/// 패킷 작성 버퍼 (길이 필드는 finish에서 채움)
+struct PacketWriter {
+    buf: Vec<u8>,
+}
+
+impl PacketWriter {
+    fn new(kind: u8) -> Self {
+        let mut buf = Vec::with_capacity(64);
+        buf.extend_from_slice(&[0; 4]);
+        buf.push(kind);
+        Self { buf }
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        self.buf.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn put_u64(&mut self, value: u64) {
+        self.buf.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn put_bytes(&mut self, value: &[u8]) {
+        self.put_u32(value.len() as u32);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn put_str(&mut self, value: &str) {
+        self.put_bytes(value.as_bytes());
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let len = (self.buf.len() - 4) as u32;
+        self.buf[..4].copy_from_slice(&len.to_be_bytes());
+        self.buf
+    }
+}
+
+/// 수신 패킷 읽기 커서
+struct PacketReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PacketReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "truncated SFTP packet"))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        let high = self.u32()? as u64;
+        let low = self.u32()? as u64;
+        Ok((high << 32) | low)
+    }
+
+    fn bytes(&mut self) -> io::Result<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        Ok(String::from_utf8_lossy(self.bytes()?).to_string())
+    }
+}
+
+/// 응답 패킷 (요청 id 이후 본문)
+struct Response {
+    kind: u8,
+    id: u32,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn reader(&self) -> PacketReader<'_> {
+        PacketReader::new(&self.body)
+    }
+
+    /// STATUS 응답을 io 결과로 변환 (다른 응답이면 InvalidData)
+    fn status(&self) -> io::Result<u32> {
+        if self.kind != SSH_FXP_STATUS {
+            return Err(unexpected_response(self.kind));
+        }
+        let mut reader = self.reader();
+        let code = reader.u32()?;
+        if code == SSH_FX_OK || code == SSH_FX_EOF {
+            return Ok(code);
+        }
+        let message = reader.string().unwrap_or_default();
+        Err(status_error(code, message))
+    }
+
+    /// 기대한 종류가 아니면 STATUS 에러로 해석
+    fn expect(self, kind: u8) -> io::Result<Self> {
+        if self.kind == kind {
+            return Ok(self);
+        }
+        self.status()?;
+        Err(unexpected_response(self.kind))
+    }
+}
+
+fn status_error(code: u32, message: String) -> io::Error {
+    let kind = match code {
+        SSH_FX_NO_SUCH_FILE => io::ErrorKind::NotFound,
+        SSH_FX_PERMISSION_DENIED => io::ErrorKind::PermissionDenied,
+        _ => io::ErrorKind::Other,
+    };
+    let message = if message.is_empty() {
+        format!("SFTP status {}", code)
+    } else {
+        message
+    };
+    io::Error::new(kind, message)
+}
+
+fn unexpected_response(kind: u8) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected SFTP response type {}", kind),
+    )
+}
+
+/// SFTP v3 클라이언트 (전송 계층: 임의의 Read/Write 쌍)
+pub struct SftpClient<R: Read, W: Write> {
+    reader: R,
+    writer: W,
+    next_id: u32,
+}
+
+impl<R: Read, W: Write> SftpClient<R, W> {
+    /// INIT/VERSION 교환 후 클라이언트 생성
+    pub fn handshake(reader: R, writer: W) -> io::Result<Self> {
+        let mut client = Self {
+            reader,
+            writer,
+            next_id: 1,
+        };
+        let mut init = PacketWriter::new(SSH_FXP_INIT);
+        init.put_u32(SFTP_VERSION);
+        client.writer.write_all(&init.finish())?;
+        client.writer.flush()?;
+
+        let (kind, body) = client.read_packet()?;
+        if kind != SSH_FXP_VERSION {
+            return Err(unexpected_response(kind));
+        }
+        let version = PacketReader::new(&body).u32()?;
+        if version < SFTP_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("SFTP version {} is not supported", version),
+            ));
+        }
+        Ok(client)
+    }
+
+    fn read_packet(&mut self) -> io::Result<(u8, Vec<u8>)> {
+        let mut len = [0u8; 4];
+        self.reader.read_exact(&mut len)?;
+        let len = u32::from_be_bytes(len) as usize;
+        if len == 0 || len > MAX_PACKET_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid SFTP packet length {}", len),
+            ));
+        }
+        let mut body = vec![0u8; len];
+        self.reader.read_exact(&mut body)?;
+        let kind = body.remove(0);
+        Ok((kind, body))
+    }
+
+    /// 요청 전송 (응답은 기다리지 않음). 반환값: 요청 id
+    fn send(&mut self, kind: u8, build: impl FnOnce(&mut PacketWriter)) -> io::Result<u32> {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        let mut packet = PacketWriter::new(kind);
+        packet.put_u32(id);
+        build(&mut packet);
+        self.writer.write_all(&packet.finish())?;
+        Ok(id)
+    }
+
+    fn recv(&mut self) -> io::Result<Response> {
+        let (kind, body) = self.read_packet()?;
+        let mut reader = PacketReader::new(&body);
+        let id = reader.u32()?;
+        let body = body[reader.pos..].to_vec();
+        Ok(Response { kind, id, body })
+    }
+
+    /// 요청 1개 전송 후 해당 응답 대기
+    fn request(&mut self, kind: u8, build: impl FnOnce(&mut PacketWriter)) -> io::Result<Response> {
+        let id = self.send(kind, build)?;
+        self.writer.flush()?;
+        let response = self.recv()?;
+        if response.id != id {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "SFTP response id mismatch",
+            ));
+        }
+        Ok(response)
+    }
+
+    fn request_status(
+        &mut self,
+        kind: u8,
+        build: impl FnOnce(&mut PacketWriter),
+    ) -> io::Result<()> {
+        self.request(kind, build)?.status().map(|_| ())
+    }
+
+    fn request_attrs(&mut self, kind: u8, path: &str) -> io::Result<SftpAttrs> {
+        let response = self
+            .request(kind, |p| p.put_str(path))?
+            .expect(SSH_FXP_ATTRS)?;
+        SftpAttrs::decode(&mut response.reader())
+    }
+
+    /// 절대 경로로 정규화 (`.`이면 원격 홈 디렉토리)
+    pub fn realpath(&mut self, path: &str) -> io::Result<String> {
+        let response = self
+            .request(SSH_FXP_REALPATH, |p| p.put_str(path))?
+            .expect(SSH_FXP_NAME)?;
+        let mut reader = response.reader();
+        if reader.u32()? == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "empty REALPATH"));
+        }
+        reader.string()
+    }
+
+    /// 링크를 따라간 속성
+    pub fn stat(&mut self, path: &str) -> io::Result<SftpAttrs> {
+        self.request_attrs(SSH_FXP_STAT, path)
+    }
+
+    /// 링크 자체 속성
+    pub fn lstat(&mut self, path: &str) -> io::Result<SftpAttrs> {
+        self.request_attrs(SSH_FXP_LSTAT, path)
+    }
+
+    /// 디렉토리 목록 (`.`/`..` 제외, 속성은 링크 자체 기준)
+    pub fn read_dir(&mut self, path: &str) -> io::Result<Vec<(String, SftpAttrs)>> {
+        let handle = self.open_handle(SSH_FXP_OPENDIR, |p| p.put_str(path))?;
+        let mut entries = Vec::new();
+        let result = loop {
+            let response = match self.request(SSH_FXP_READDIR, |p| p.put_bytes(&handle)) {
+                Ok(response) => response,
+                Err(e) => break Err(e),
+            };
+            if response.kind == SSH_FXP_STATUS {
+                match response.status() {
+                    Ok(_) => break Ok(()),
+                    Err(e) => break Err(e),
+                }
+            }
+            if response.kind != SSH_FXP_NAME {
+                break Err(unexpected_response(response.kind));
+            }
+            let mut reader = response.reader();
+            let parsed = (|| -> io::Result<()> {
+                for _ in 0..reader.u32()? {
+                    let name = reader.string()?;
+                    let _longname = reader.bytes()?;
+                    let attrs = SftpAttrs::decode(&mut reader)?;
+                    if name != "." && name != ".." {
+                        entries.push((name, attrs));
+                    }
+                }
+                Ok(())
+            })();
+            if let Err(e) = parsed {
+                break Err(e);
+            }
+        };
+        let closed = self.close(&handle);
+        result.and(closed).map(|_| entries)
+    }
+
+    fn open_handle(
+        &mut self,
+        kind: u8,
+        build: impl FnOnce(&mut PacketWriter),
+    ) -> io::Result<Vec<u8>> {
+        let response = self.request(kind, build)?.expect(SSH_FXP_HANDLE)?;
+        Ok(response.reader().bytes()?.to_vec())
+    }
+
+    fn open(&mut self, path: &str, flags: u32, attrs: &SftpAttrs) -> io::Result<Vec<u8>> {
+        self.open_handle(SSH_FXP_OPEN, |p| {
+            p.put_str(path);
+            p.put_u32(flags);
+            attrs.encode(p);
+        })
+    }
+
+    fn close(&mut self, handle: &[u8]) -> io::Result<()> {
+        self.request_status(SSH_FXP_CLOSE, |p| p.put_bytes(handle))
+    }
+
+    pub fn mkdir(&mut self, path: &str) -> io::Result<()> {
+        self.request_status(SSH_FXP_MKDIR, |p| {
+            p.put_str(path);
+            SftpAttrs::default().encode(p);
+        })
+    }
+
+    pub fn rmdir(&mut self, path: &str) -> io::Result<()> {
+        self.request_status(SSH_FXP_RMDIR, |p| p.put_str(path))
+    }
+
+    pub fn remove(&mut self, path: &str) -> io::Result<()> {
+        self.request_status(SSH_FXP_REMOVE, |p| p.put_str(path))
+    }
+
+    pub fn rename(&mut self, from: &str, to: &str) -> io::Result<()> {
+        self.request_status(SSH_FXP_RENAME, |p| {
+            p.put_str(from);
+            p.put_str(to);
+        })
+    }
+
+    /// 원격 파일을 writer로 받기. on_chunk가 false를 반환하면 중단(Interrupted)
+    ///
+    /// 읽기 요청을 여러 개 먼저 보내 두고 응답 순서와 무관하게 offset 위치에 씁니다.
+    pub fn download<F: Write + Seek>(
+        &mut self,
+        path: &str,
+        dest: &mut F,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<u64> {
+        let handle = self.open(path, SSH_FXF_READ, &SftpAttrs::default())?;
+        let result = self.download_handle(&handle, dest, on_chunk);
+        let closed = self.close(&handle);
+        let total = result?;
+        closed?;
+        Ok(total)
+    }
+
+    fn download_handle<F: Write + Seek>(
+        &mut self,
+        handle: &[u8],
+        dest: &mut F,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<u64> {
+        // 요청 id → (offset, 요청 길이)
+        let mut outstanding: HashMap<u32, (u64, u32)> = HashMap::new();
+        let mut retries: Vec<(u64, u32)> = Vec::new();
+        let mut next_offset = 0u64;
+        let mut eof = false;
+        let mut total = 0u64;
+        let mut cancelled = false;
+        loop {
+            while !cancelled && outstanding.len() < MAX_OUTSTANDING_REQUESTS {
+                let (offset, len) = if let Some(retry) = retries.pop() {
+                    retry
+                } else if !eof {
+                    let offset = next_offset;
+                    next_offset += TRANSFER_CHUNK_SIZE as u64;
+                    (offset, TRANSFER_CHUNK_SIZE)
+                } else {
+                    break;
+                };
+                let id = self.send(SSH_FXP_READ, |p| {
+                    p.put_bytes(handle);
+                    p.put_u64(offset);
+                    p.put_u32(len);
+                })?;
+                outstanding.insert(id, (offset, len));
+            }
+            if outstanding.is_empty() {
+                break;
+            }
+            self.writer.flush()?;
+            let response = self.recv()?;
+            let Some((offset, len)) = outstanding.remove(&response.id) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "SFTP response id mismatch",
+                ));
+            };
+            if response.kind == SSH_FXP_STATUS {
+                // EOF: 이 offset 이후 데이터 없음
+                response.status()?;
+                eof = true;
+                continue;
+            }
+            let response = response.expect(SSH_FXP_DATA)?;
+            let data = response.reader().bytes()?;
+            dest.seek(SeekFrom::Start(offset))?;
+            dest.write_all(data)?;
+            total += data.len() as u64;
+            if !data.is_empty() && (data.len() as u32) < len {
+                // 짧은 응답: 남은 구간을 다시 요청
+                retries.push((offset + data.len() as u64, len - data.len() as u32));
+            }
+            if !on_chunk(data.len() as u64) {
+                cancelled = true;
+            }
+        }
+        if cancelled {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "cancelled"));
+        }
+        Ok(total)
+    }
+
+    /// reader 내용을 원격 파일로 보내기 (기존 파일은 덮어씀)
+    pub fn upload(
+        &mut self,
+        source: &mut dyn Read,
+        path: &str,
+        permissions: Option<u32>,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<u64> {
+        let attrs = SftpAttrs {
+            permissions: permissions.map(|mode| mode & 0o7777),
+            ..SftpAttrs::default()
+        };
+        let handle = self.open(path, SSH_FXF_WRITE | SSH_FXF_CREAT | SSH_FXF_TRUNC, &attrs)?;
+        let result = self.upload_handle(&handle, source, on_chunk);
+        let closed = self.close(&handle);
+        let total = result?;
+        closed?;
+        Ok(total)
+    }
+
+    fn upload_handle(
+        &mut self,
+        handle: &[u8],
+        source: &mut dyn Read,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<u64> {
+        let mut buffer = vec![0u8; TRANSFER_CHUNK_SIZE as usize];
+        let mut outstanding = 0usize;
+        let mut offset = 0u64;
+        let mut done = false;
+        let mut first_error = None;
+        while !done || outstanding > 0 {
+            while !done && outstanding < MAX_OUTSTANDING_REQUESTS {
+                let read = source.read(&mut buffer)?;
+                if read == 0 {
+                    done = true;
+                    break;
+                }
+                let chunk = &buffer[..read];
+                self.send(SSH_FXP_WRITE, |p| {
+                    p.put_bytes(handle);
+                    p.put_u64(offset);
+                    p.put_bytes(chunk);
+                })?;
+                offset += read as u64;
+                outstanding += 1;
+                if !on_chunk(read as u64) {
+                    done = true;
+                    first_error = Some(io::Error::new(io::ErrorKind::Interrupted, "cancelled"));
+                }
+            }
+            if outstanding == 0 {
+                break;
+            }
+            self.writer.flush()?;
+            let response = self.recv()?;
+            outstanding -= 1;
+            if let Err(e) = response.status() {
+                first_error.get_or_insert(e);
+                done = true;
+            }
+        }
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(offset),
+        }
+    }
+}
+
+/// SFTP 속성으로 패널 엔트리 생성
+fn remote_file_entry(
+    dir: &str,
+    name: String,
+    attrs: &SftpAttrs,
+    target: Option<&SftpAttrs>,
+) -> FileEntry {
+    let mode = attrs.permissions.unwrap_or(0);
+    let file_type = if attrs.is_dir() {
+        FileType::Directory
+    } else if attrs.is_symlink() {
+        FileType::Symlink
+    } else if mode & 0o111 != 0 {
+        FileType::Executable
+    } else {
+        FileType::File
+    };
+    let display = target.unwrap_or(attrs);
+    let size = if file_type == FileType::Directory || display.is_dir() {
+        0
+    } else {
+        display.size.unwrap_or(0)
+    };
+    let modified = display
+        .mtime
+        .map(|secs| UNIX_EPOCH + Duration::from_secs(secs as u64))
+        .unwrap_or(UNIX_EPOCH);
+    #[cfg(unix)]
+    let permissions = display.permissions.map(|mode| {
+        use std::os::unix::fs::PermissionsExt;
+        fs::Permissions::from_mode(mode)
+    });
+    #[cfg(not(unix))]
+    let permissions = None;
+    let is_hidden = name.starts_with('.');
+    let mut entry = FileEntry::new(
+        name.clone(),
+        PathBuf::from(join_remote(dir, &name)),
+        file_type,
+        size,
+        modified,
+        modified,
+        permissions,
+        is_hidden,
+    );
+    entry.owner = display.uid.map(|uid| uid.to_string());
+    entry.group = display.gid.map(|gid| gid.to_string());
+    entry.is_broken_symlink = attrs.is_symlink() && target.is_none();
+    entry
+}
+
+type SessionReader = Box<dyn Read + Send>;
+type SessionWriter = Box<dyn Write + Send>;
+
+/// ssh 프로세스 위의 SFTP 세션
+pub struct SftpSession {
+    host: RemoteHost,
+    client: SftpClient<SessionReader, SessionWriter>,
+    child: Option<Child>,
+    stderr_log: Arc<Mutex<String>>,
+    home: String,
+}
+
+impl std::fmt::Debug for SftpSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SftpSession")
+            .field("host", &self.host)
+            .field("home", &self.home)
+            .finish()
+    }
+}
+
+impl SftpSession {
+    /// ssh로 접속해 SFTP 서브시스템 시작
+    pub fn connect(host: &RemoteHost) -> Result<Self> {
+        let mut command = Command::new("ssh");
+        command.args([
+            "-oBatchMode=yes",
+            "-oStrictHostKeyChecking=accept-new",
+            "-oConnectTimeout=10",
+            "-oServerAliveInterval=15",
+        ]);
+        if let Some(port) = host.port {
+            command.arg("-p").arg(port.to_string());
+        }
+        if let Some(user) = &host.user {
+            command.arg("-l").arg(user);
+        }
+        command
+            .arg("-s")
+            .arg(&host.host)
+            .arg("sftp")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = command
+            .spawn()
+            .map_err(|e| remote_error(host, e.to_string()))?;
+        let stderr_log = Arc::new(Mutex::new(String::new()));
+        if let Some(mut stderr) = child.stderr.take() {
+            let log = Arc::clone(&stderr_log);
+            std::thread::spawn(move || {
+                let mut buf = [0u8; 512];
+                while let Ok(read) = stderr.read(&mut buf) {
+                    if read == 0 {
+                        break;
+                    }
+                    if let Ok(mut log) = log.lock() {
+                        log.push_str(&String::from_utf8_lossy(&buf[..read]));
+                        if log.len() > STDERR_LOG_LIMIT {
+                            let cut = log.len() - STDERR_LOG_LIMIT;
+                            let cut = (cut..log.len())
+                                .find(|i| log.is_char_boundary(*i))
+                                .unwrap_or(0);
+                            log.drain(..cut);
+                        }
+                    }
+                }
+            });
+        }
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return Err(remote_error(host, "failed to open ssh pipes".to_string()));
+        };
+
+        let reader: SessionReader = Box::new(BufReader::new(stdout));
+        let writer: SessionWriter = Box::new(BufWriter::new(stdin));
+        match Self::from_streams(host, reader, writer) {
+            Ok(mut session) => {
+                session.child = Some(child);
+                session.stderr_log = stderr_log;
+                Ok(session)
+            }
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                // stderr 수집 스레드가 남은 출력을 읽을 시간
+                std::thread::sleep(Duration::from_millis(50));
+                let stderr = stderr_log
+                    .lock()
+                    .map(|log| log.trim().to_string())
+                    .unwrap_or_default();
+                let reason = match e {
+                    BokslDirError::RemoteFailed { reason, .. } if stderr.is_empty() => reason,
+                    _ if stderr.is_empty() => e.to_string(),
+                    _ => stderr,
+                };
+                Err(remote_error(host, reason))
+            }
+        }
+    }
+
+    /// 이미 열린 전송 스트림으로 세션 생성 (핸드셰이크 + 홈 조회)
+    fn from_streams(
+        host: &RemoteHost,
+        reader: SessionReader,
+        writer: SessionWriter,
+    ) -> Result<Self> {
+        let mut client =
+            SftpClient::handshake(reader, writer).map_err(|e| remote_error(host, e.to_string()))?;
+        let home = client
+            .realpath(".")
+            .map_err(|e| remote_error(host, e.to_string()))?;
+        Ok(Self {
+            host: host.clone(),
+            client,
+            child: None,
+            stderr_log: Arc::new(Mutex::new(String::new())),
+            home,
+        })
+    }
+
+    pub fn host(&self) -> &RemoteHost {
+        &self.host
+    }
+
+    /// 원격 홈 디렉토리 (접속 시 REALPATH ".")
+    pub fn home(&self) -> &str {
+        &self.home
+    }
+
+    /// 경로 정규화 (상대 경로는 홈 기준)
+    pub fn canonicalize(&mut self, path: &str) -> Result<String> {
+        let path = if path.is_empty() { "." } else { path };
+        self.client
+            .realpath(path)
+            .map_err(|e| self.path_error(Path::new(path), e))
+    }
+
+    fn path_error(&self, path: &Path, e: io::Error) -> BokslDirError {
+        match e.kind() {
+            io::ErrorKind::NotFound => BokslDirError::PathNotFound {
+                path: path.to_path_buf(),
+            },
+            io::ErrorKind::PermissionDenied => BokslDirError::PermissionDenied {
+                path: path.to_path_buf(),
+            },
+            _ => remote_error(&self.host, format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    /// 디렉토리 목록을 패널 엔트리로 읽기 (심볼릭 링크는 대상 속성 조회)
+    pub fn list(&mut self, dir: &str) -> Result<Vec<FileEntry>> {
+        let attrs = self
+            .client
+            .stat(dir)
+            .map_err(|e| self.path_error(Path::new(dir), e))?;
+        if !attrs.is_dir() {
+            return Err(BokslDirError::NotADirectory {
+                path: PathBuf::from(dir),
+            });
+        }
+        let items = self
+            .client
+            .read_dir(dir)
+            .map_err(|e| self.path_error(Path::new(dir), e))?;
+        let mut entries = Vec::with_capacity(items.len());
+        for (name, attrs) in items {
+            let target = if attrs.is_symlink() {
+                self.client.stat(&join_remote(dir, &name)).ok()
+            } else {
+                None
+            };
+            entries.push(remote_file_entry(dir, name, &attrs, target.as_ref()));
+        }
+        Ok(entries)
+    }
+
+    /// 경로 속성 (링크를 따라감)
+    pub fn stat(&mut self, path: &str) -> Result<SftpAttrs> {
+        self.client
+            .stat(path)
+            .map_err(|e| self.path_error(Path::new(path), e))
+    }
+
+    pub fn exists(&mut self, path: &str) -> bool {
+        self.client.lstat(path).is_ok()
+    }
+
+    pub fn make_dir(&mut self, path: &str) -> Result<()> {
+        if self.exists(path) {
+            return Err(BokslDirError::FileExists {
+                path: PathBuf::from(path),
+            });
+        }
+        self.client
+            .mkdir(path)
+            .map_err(|e| self.path_error(Path::new(path), e))
+    }
+
+    pub fn rename(&mut self, from: &str, to: &str) -> Result<()> {
+        if self.exists(to) {
+            return Err(BokslDirError::FileExists {
+                path: PathBuf::from(to),
+            });
+        }
+        self.client
+            .rename(from, to)
+            .map_err(|e| BokslDirError::RenameFailed {
+                src: PathBuf::from(from),
+                dest: PathBuf::from(to),
+                reason: e.to_string(),
+            })
+    }
+
+    /// 파일 또는 디렉토리(재귀) 삭제
+    pub fn remove_all(&mut self, path: &str) -> Result<()> {
+        let attrs = self
+            .client
+            .lstat(path)
+            .map_err(|e| self.path_error(Path::new(path), e))?;
+        let delete_error = |e: io::Error| BokslDirError::DeleteFailed {
+            path: PathBuf::from(path),
+            reason: e.to_string(),
+        };
+        if attrs.is_dir() {
+            let children = self.client.read_dir(path).map_err(delete_error)?;
+            for (name, _) in children {
+                self.remove_all(&join_remote(path, &name))?;
+            }
+            self.client.rmdir(path).map_err(delete_error)
+        } else {
+            self.client.remove(path).map_err(delete_error)
+        }
+    }
+
+    /// 디렉토리의 하위 이름 목록 (재귀 전송용)
+    pub fn child_names(&mut self, dir: &str) -> Result<Vec<String>> {
+        let items = self
+            .client
+            .read_dir(dir)
+            .map_err(|e| self.path_error(Path::new(dir), e))?;
+        Ok(items.into_iter().map(|(name, _)| name).collect())
+    }
+
+    pub fn download_file<F: Write + Seek>(
+        &mut self,
+        path: &str,
+        dest: &mut F,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<()> {
+        self.client.download(path, dest, on_chunk).map(|_| ())
+    }
+
+    pub fn upload_file(
+        &mut self,
+        source: &mut dyn Read,
+        path: &str,
+        permissions: Option<u32>,
+        on_chunk: &mut dyn FnMut(u64) -> bool,
+    ) -> io::Result<()> {
+        self.client
+            .upload(source, path, permissions, on_chunk)
+            .map(|_| ())
+    }
+
+    pub fn remove_file(&mut self, path: &str) -> io::Result<()> {
+        self.client.remove(path)
+    }
+}
+
+impl Drop for SftpSession {
+    fn drop(&mut self) {
+        if let Some(child) = self.child.as_mut() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// 테스트용 가짜 SFTP 서버 (앱 테스트에서도 원격 패널 세션으로 사용)
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// 테스트용 SFTP 서버 (TempDir을 원격 루트로 사용)
+    struct FakeServer {
+        root: PathBuf,
+        handles: HashMap<Vec<u8>, FakeHandle>,
+        next_handle: u32,
+    }
+
+    enum FakeHandle {
+        File(fs::File),
+        Dir(Option<Vec<(String, SftpAttrs)>>),
+    }
+
+    fn local_attrs(metadata: &fs::Metadata) -> SftpAttrs {
+        use std::os::unix::fs::PermissionsExt;
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or(0);
+        SftpAttrs {
+            size: Some(metadata.len()),
+            uid: Some(1000),
+            gid: Some(1000),
+            permissions: Some(metadata.permissions().mode()),
+            atime: Some(mtime),
+            mtime: Some(mtime),
+        }
+    }
+
+    impl FakeServer {
+        fn local(&self, path: &str) -> PathBuf {
+            self.root.join(path.trim_start_matches('/'))
+        }
+
+        fn status(id: u32, result: io::Result<()>) -> Vec<u8> {
+            let mut packet = PacketWriter::new(SSH_FXP_STATUS);
+            packet.put_u32(id);
+            match result {
+                Ok(()) => packet.put_u32(SSH_FX_OK),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    packet.put_u32(SSH_FX_NO_SUCH_FILE)
+                }
+                Err(_) => packet.put_u32(4),
+            }
+            packet.put_str("");
+            packet.put_str("");
+            packet.finish()
+        }
+
+        fn eof(id: u32) -> Vec<u8> {
+            let mut packet = PacketWriter::new(SSH_FXP_STATUS);
+            packet.put_u32(id);
+            packet.put_u32(SSH_FX_EOF);
+            packet.put_str("");
+            packet.put_str("");
+            packet.finish()
+        }
+
+        fn attrs(id: u32, result: io::Result<fs::Metadata>) -> Vec<u8> {
+            match result {
+                Ok(metadata) => {
+                    let mut packet = PacketWriter::new(SSH_FXP_ATTRS);
+                    packet.put_u32(id);
+                    local_attrs(&metadata).encode_full(&mut packet);
+                    packet.finish()
+                }
+                Err(e) => Self::status(id, Err(e)),
+            }
+        }
+
+        fn new_handle(&mut self, id: u32, handle: FakeHandle) -> Vec<u8> {
+            let key = self.next_handle.to_be_bytes().to_vec();
+            self.next_handle += 1;
+            self.handles.insert(key.clone(), handle);
+            let mut packet = PacketWriter::new(SSH_FXP_HANDLE);
+            packet.put_u32(id);
+            packet.put_bytes(&key);
+            packet.finish()
+        }
+
+        fn handle(&mut self, kind: u8, body: &[u8]) -> io::Result<Vec<u8>> {
+            let mut r = PacketReader::new(body);
+            if kind == SSH_FXP_INIT {
+                let mut packet = PacketWriter::new(SSH_FXP_VERSION);
+                packet.put_u32(SFTP_VERSION);
+                return Ok(packet.finish());
+            }
+            let id = r.u32()?;
+            let reply = match kind {
+                SSH_FXP_REALPATH => {
+                    let path = r.string()?;
+                    let resolved = if path == "." { "/".to_string() } else { path };
+                    let mut packet = PacketWriter::new(SSH_FXP_NAME);
+                    packet.put_u32(id);
+                    packet.put_u32(1);
+                    packet.put_str(&resolved);
+                    packet.put_str(&resolved);
+                    packet.put_u32(0);
+                    packet.finish()
+                }
+                SSH_FXP_STAT => Self::attrs(id, fs::metadata(self.local(&r.string()?))),
+                SSH_FXP_LSTAT => Self::attrs(id, fs::symlink_metadata(self.local(&r.string()?))),
+                SSH_FXP_OPENDIR => match fs::read_dir(self.local(&r.string()?)) {
+                    Ok(read_dir) => {
+                        let mut entries: Vec<_> = read_dir
+                            .filter_map(|e| e.ok())
+                            .map(|e| {
+                                let attrs = local_attrs(&e.metadata().unwrap());
+                                (e.file_name().to_string_lossy().to_string(), attrs)
+                            })
+                            .collect();
+                        entries.sort_by(|a, b| a.0.cmp(&b.0));
+                        self.new_handle(id, FakeHandle::Dir(Some(entries)))
+                    }
+                    Err(e) => Self::status(id, Err(e)),
+                },
+                SSH_FXP_READDIR => {
+                    let key = r.bytes()?.to_vec();
+                    match self.handles.get_mut(&key) {
+                        Some(FakeHandle::Dir(entries)) => match entries.take() {
+                            Some(entries) => {
+                                let mut packet = PacketWriter::new(SSH_FXP_NAME);
+                                packet.put_u32(id);
+                                packet.put_u32(entries.len() as u32 + 1);
+                                packet.put_str(".");
+                                packet.put_str(".");
+                                packet.put_u32(0);
+                                for (name, attrs) in entries {
+                                    packet.put_str(&name);
+                                    packet.put_str(&name);
+                                    attrs.encode_full(&mut packet);
+                                }
+                                packet.finish()
+                            }
+                            None => Self::eof(id),
+                        },
+                        _ => Self::status(id, Err(io::ErrorKind::InvalidInput.into())),
+                    }
+                }
+                SSH_FXP_OPEN => {
+                    let path = self.local(&r.string()?);
+                    let flags = r.u32()?;
+                    let result = fs::OpenOptions::new()
+                        .read(flags & SSH_FXF_READ != 0)
+                        .write(flags & SSH_FXF_WRITE != 0)
+                        .create(flags & SSH_FXF_CREAT != 0)
+                        .truncate(flags & SSH_FXF_TRUNC != 0)
+                        .open(path);
+                    match result {
+                        Ok(file) => self.new_handle(id, FakeHandle::File(file)),
+                        Err(e) => Self::status(id, Err(e)),
+                    }
+                }
+                SSH_FXP_READ => {
+                    let key = r.bytes()?.to_vec();
+                    let offset = r.u64()?;
+                    let len = r.u32()? as usize;
+                    let Some(FakeHandle::File(file)) = self.handles.get_mut(&key) else {
+                        return Ok(Self::status(id, Err(io::ErrorKind::InvalidInput.into())));
+                    };
+                    // 짧은 읽기 재요청 경로도 확인하도록 최대 10000바이트만 반환
+                    let mut buf = vec![0u8; len.min(10_000)];
+                    file.seek(SeekFrom::Start(offset))?;
+                    let read = file.read(&mut buf)?;
+                    if read == 0 {
+                        Self::eof(id)
+                    } else {
+                        let mut packet = PacketWriter::new(SSH_FXP_DATA);
+                        packet.put_u32(id);
+                        packet.put_bytes(&buf[..read]);
+                        packet.finish()
+                    }
+                }
+                SSH_FXP_WRITE => {
+                    let key = r.bytes()?.to_vec();
+                    let offset = r.u64()?;
+                    let data = r.bytes()?;
+                    let Some(FakeHandle::File(file)) = self.handles.get_mut(&key) else {
+                        return Ok(Self::status(id, Err(io::ErrorKind::InvalidInput.into())));
+                    };
+                    file.seek(SeekFrom::Start(offset))?;
+                    Self::status(id, file.write_all(data))
+                }
+                SSH_FXP_CLOSE => {
+                    let key = r.bytes()?.to_vec();
+                    self.handles.remove(&key);
+                    Self::status(id, Ok(()))
+                }
+                SSH_FXP_MKDIR => Self::status(id, fs::create_dir(self.local(&r.string()?))),
+                SSH_FXP_RMDIR => Self::status(id, fs::remove_dir(self.local(&r.string()?))),
+                SSH_FXP_REMOVE => Self::status(id, fs::remove_file(self.local(&r.string()?))),
+                SSH_FXP_RENAME => {
+                    let from = self.local(&r.string()?);
+                    let to = self.local(&r.string()?);
+                    Self::status(id, fs::rename(from, to))
+                }
+                _ => Self::status(id, Err(io::ErrorKind::Unsupported.into())),
+            };
+            Ok(reply)
+        }
+    }
+
+    impl SftpAttrs {
+        /// 서버 응답용 인코딩 (UIDGID 포함)
+        fn encode_full(&self, packet: &mut PacketWriter) {
+            packet.put_u32(
+                SSH_FILEXFER_ATTR_SIZE
+                    | SSH_FILEXFER_ATTR_UIDGID
+                    | SSH_FILEXFER_ATTR_PERMISSIONS
+                    | SSH_FILEXFER_ATTR_ACMODTIME,
+            );
+            packet.put_u64(self.size.unwrap_or(0));
+            packet.put_u32(self.uid.unwrap_or(0));
+            packet.put_u32(self.gid.unwrap_or(0));
+            packet.put_u32(self.permissions.unwrap_or(0));
+            packet.put_u32(self.atime.unwrap_or(0));
+            packet.put_u32(self.mtime.unwrap_or(0));
+        }
+    }
+
+    /// TempDir을 루트로 하는 가짜 서버에 연결된 세션
+    pub(crate) fn fake_session() -> (TempDir, SftpSession) {
+        let remote = TempDir::new().unwrap();
+        let (client_reader, mut server_writer) = io::pipe().unwrap();
+        let (mut server_reader, client_writer) = io::pipe().unwrap();
+        let mut server = FakeServer {
+            root: remote.path().to_path_buf(),
+            handles: HashMap::new(),
+            next_handle: 1,
+        };
+        std::thread::spawn(move || loop {
+            let mut len = [0u8; 4];
+            if server_reader.read_exact(&mut len).is_err() {
+                break;
+            }
+            let mut body = vec![0u8; u32::from_be_bytes(len) as usize];
+            if server_reader.read_exact(&mut body).is_err() {
+                break;
+            }
+            let Ok(reply) = server.handle(body[0], &body[1..]) else {
+                break;
+            };
+            if server_writer.write_all(&reply).is_err() {
+                break;
+            }
+        });
+        let host = RemoteHost::parse_url("sftp://tester@fake").unwrap();
+        let session =
+            SftpSession::from_streams(&host, Box::new(client_reader), Box::new(client_writer))
+                .unwrap();
+        (remote, session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::fake_session;
+    use super::*;
+    use crate::system::archive::ArchiveControl;
+    use crate::system::remote::{RemoteSession, RemoteTransferRequest, TransferDirection};
+    use std::sync::mpsc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_session_lists_and_manages_remote_entries() {
+        let (remote, mut session) = fake_session();
+        assert_eq!(session.home(), "/");
+        fs::write(remote.path().join("b.txt"), "hello").unwrap();
+        fs::write(remote.path().join(".hidden"), "").unwrap();
+
+        session.make_dir("/docs").unwrap();
+        assert!(matches!(
+            session.make_dir("/docs"),
+            Err(BokslDirError::FileExists { .. })
+        ));
+
+        let entries = session.list("/").unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec![".hidden", "b.txt", "docs"]);
+        assert!(entries[0].is_hidden);
+        assert_eq!(entries[1].size, 5);
+        assert_eq!(entries[1].path, PathBuf::from("/b.txt"));
+        assert!(entries[2].is_directory());
+        assert!(matches!(
+            session.list("/b.txt"),
+            Err(BokslDirError::NotADirectory { .. })
+        ));
+
+        session.rename("/b.txt", "/docs/c.txt").unwrap();
+        assert!(remote.path().join("docs/c.txt").exists());
+        session.remove_all("/docs").unwrap();
+        assert!(!remote.path().join("docs").exists());
+        assert!(matches!(
+            session.stat("/missing"),
+            Err(BokslDirError::PathNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_remote_fs_backs_mounted_panel() {
+        use crate::models::PanelState;
+        use crate::system::filesystem::FileSystem;
+        use crate::system::remote::RemoteFs;
+        use crate::system::vfs::VirtualFileSystem;
+        use std::io::Read;
+        use std::sync::{Arc, Mutex};
+
+        let (remote, session) = fake_session();
+        let host = session.host().clone();
+        let backend = RemoteFs::new(host, Arc::new(Mutex::new(RemoteSession::Sftp(session))));
+        let root = backend.display_path("/");
+        assert_eq!(root, PathBuf::from("sftp://tester@fake/"));
+
+        backend.mkdir(&root.join("docs")).unwrap();
+        let file = root.join("docs").join("a.txt");
+        let mut writer = backend.open_write(&file).unwrap();
+        writer.write_all(b"hello").unwrap();
+        writer.commit().unwrap();
+        assert_eq!(
+            fs::read_to_string(remote.path().join("docs/a.txt")).unwrap(),
+            "hello"
+        );
+        assert!(matches!(
+            backend.open_write(&file).unwrap().commit(),
+            Err(BokslDirError::FileExists { .. })
+        ));
+        let mut content = String::new();
+        backend
+            .open_read(Path::new("/docs/a.txt"))
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "hello");
+        assert_eq!(backend.stat(&file).unwrap().size, 5);
+        assert_eq!(backend.parent(&file), Some(root.join("docs")));
+        assert_eq!(backend.parent(&root), None);
+
+        let mut panel = PanelState::new(PathBuf::from("/tmp"));
+        panel
+            .mount(Arc::new(backend.clone()), root.join("docs"))
+            .unwrap();
+        assert_eq!(panel.current_path, root.join("docs"));
+        assert_eq!(panel.entries[0].path, PathBuf::from("/docs/a.txt"));
+        fs::write(remote.path().join("docs/b.txt"), "").unwrap();
+        panel.refresh(&FileSystem::new()).unwrap();
+        assert_eq!(panel.entries.len(), 2);
+    }
+
+    #[test]
+    fn test_transfer_round_trips_directory_tree() {
+        let (remote, session) = fake_session();
+        let mut session = RemoteSession::Sftp(session);
+        let local = TempDir::new().unwrap();
+        let tree = local.path().join("tree");
+        fs::create_dir_all(tree.join("sub")).unwrap();
+        // 청크 크기와 동시 요청 수를 넘는 파일로 파이프라인 경로 확인
+        let big: Vec<u8> = (0..(TRANSFER_CHUNK_SIZE as usize * 20 + 123))
+            .map(|i| (i % 251) as u8)
+            .collect();
+        fs::write(tree.join("big.bin"), &big).unwrap();
+        fs::write(tree.join("sub/small.txt"), "small").unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let control = ArchiveControl::default();
+        let upload = RemoteTransferRequest {
+            direction: TransferDirection::Upload,
+            sources: vec![tree.clone()],
+            dest_dir: PathBuf::from("/"),
+            remove_source: true,
+        };
+        let summary = session.transfer(&upload, &tx, &control).unwrap();
+        assert_eq!(summary.items_failed, 0, "{:?}", summary.errors);
+        assert_eq!(summary.items_processed, 4);
+        assert_eq!(fs::read(remote.path().join("tree/big.bin")).unwrap(), big);
+        assert!(!tree.exists());
+
+        let download = RemoteTransferRequest {
+            direction: TransferDirection::Download,
+            sources: vec![PathBuf::from("/tree")],
+            dest_dir: local.path().to_path_buf(),
+            remove_source: false,
+        };
+        let summary = session.transfer(&download, &tx, &control).unwrap();
+        assert_eq!(summary.items_failed, 0, "{:?}", summary.errors);
+        assert_eq!(fs::read(tree.join("big.bin")).unwrap(), big);
+        assert_eq!(
+            fs::read_to_string(tree.join("sub/small.txt")).unwrap(),
+            "small"
+        );
+        assert!(remote.path().join("tree").exists());
+
+        // 이미 있는 파일은 덮어쓰지 않음
+        let summary = session.transfer(&download, &tx, &control).unwrap();
+        assert_eq!(summary.items_failed, 2);
+        let last = rx.try_iter().last().unwrap();
+        assert_eq!(last.items_processed, 4);
+    }
+
+    #[test]
+    fn test_transfer_cancel_keeps_source() {
+        let (remote, session) = fake_session();
+        let mut session = RemoteSession::Sftp(session);
+        let local = TempDir::new().unwrap();
+        fs::write(remote.path().join("data.bin"), vec![7u8; 200_000]).unwrap();
+
+        let (tx, _rx) = mpsc::channel();
+        let control = ArchiveControl::default();
+        control.cancel();
+        let request = RemoteTransferRequest {
+            direction: TransferDirection::Download,
+            sources: vec![PathBuf::from("/data.bin")],
+            dest_dir: local.path().to_path_buf(),
+            remove_source: true,
+        };
+        let summary = session.transfer(&request, &tx, &control).unwrap();
+        assert!(summary.cancelled);
+        assert!(!local.path().join("data.bin").exists());
+        assert!(remote.path().join("data.bin").exists());
+    }
+}
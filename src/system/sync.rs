@@ -0,0 +1,411 @@
+//! 두 패널 디렉토리 동기화 (전송 계획 세우기, 검토한 계획 실행)
+//!
+//! 양쪽 트리를 상대 경로로 맞춰 크기와 수정 시각을 비교합니다. 한쪽에만 있는 항목은 반대쪽으로
+//! 복사, 양쪽에 있지만 다른 파일은 더 새로운 쪽에서 복사하도록 제안하며, 한쪽에만 있는 디렉토리는
+//! 펼치지 않고 한 항목으로 다룹니다. 수정 시각은 FAT 해상도를 고려해 2초 차이까지 같게 봅니다.
+//! 복사는 시각을 유지하므로 동기화 직후 다시 비교하면 같은 파일로 판정됩니다.
+
+use crate::models::operation::CopyOptions;
+use crate::system::filesystem::FileSystem;
+use crate::utils::error::{BokslDirError, Result};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// 같은 시각으로 보는 수정 시각 차이
+const MTIME_TOLERANCE: Duration = Duration::from_secs(2);
+
+/// 항목별 전송 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncAction {
+    /// 왼쪽 → 오른쪽 복사
+    CopyToRight,
+    /// 오른쪽 → 왼쪽 복사
+    CopyToLeft,
+    /// 왼쪽에만 있는 항목 삭제
+    DeleteLeft,
+    /// 오른쪽에만 있는 항목 삭제
+    DeleteRight,
+}
+
+impl SyncAction {
+    pub fn is_delete(self) -> bool {
+        matches!(self, SyncAction::DeleteLeft | SyncAction::DeleteRight)
+    }
+}
+
+/// 한쪽 트리의 항목 정보 (디렉토리 크기는 하위 파일 합계)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncSide {
+    pub is_dir: bool,
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+}
+
+/// 전송 계획 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncItem {
+    /// 양쪽 루트 기준 상대 경로
+    pub relative: PathBuf,
+    pub left: Option<SyncSide>,
+    pub right: Option<SyncSide>,
+    pub action: SyncAction,
+    /// 실행할 항목 (크기만 다르거나 종류가 다른 항목은 기본으로 꺼 둠)
+    pub checked: bool,
+}
+
+impl SyncItem {
+    /// 방향 지정 (반대쪽에 원본이 없는 항목은 그쪽 사본 삭제가 됨)
+    pub fn set_direction(&mut self, to_right: bool) {
+        self.action = match (to_right, self.left.is_some(), self.right.is_some()) {
+            (true, true, _) => SyncAction::CopyToRight,
+            (true, false, _) => SyncAction::DeleteRight,
+            (false, _, true) => SyncAction::CopyToLeft,
+            (false, _, false) => SyncAction::DeleteLeft,
+        };
+        self.checked = true;
+    }
+
+    /// 보낼 쪽 크기 (삭제는 지울 쪽 크기)
+    pub fn size(&self) -> u64 {
+        let side = match self.action {
+            SyncAction::CopyToRight | SyncAction::DeleteLeft => self.left,
+            SyncAction::CopyToLeft | SyncAction::DeleteRight => self.right,
+        };
+        side.map_or(0, |side| side.size)
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.left.or(self.right).is_some_and(|side| side.is_dir)
+    }
+}
+
+/// 실행 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncSummary {
+    pub copied: usize,
+    pub deleted: usize,
+    pub bytes: u64,
+    /// 실패한 항목 ("상대 경로: 오류")
+    pub errors: Vec<String>,
+}
+
+/// 두 디렉토리를 비교해 전송 계획 작성 (`on_progress(비교한 항목 수)`)
+pub fn plan(
+    left_root: &Path,
+    right_root: &Path,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(u64),
+) -> Result<Vec<SyncItem>> {
+    let mut items = Vec::new();
+    let mut compared = 0;
+    plan_dir(
+        left_root,
+        right_root,
+        Path::new(""),
+        cancel,
+        &mut compared,
+        on_progress,
+        &mut items,
+    )?;
+    Ok(items)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn plan_dir(
+    left_root: &Path,
+    right_root: &Path,
+    relative: &Path,
+    cancel: &AtomicBool,
+    compared: &mut u64,
+    on_progress: &mut dyn FnMut(u64),
+    items: &mut Vec<SyncItem>,
+) -> Result<()> {
+    let left_dir = left_root.join(relative);
+    let right_dir = right_root.join(relative);
+    let left_entries = read_entries(&left_dir)?;
+    let mut right_entries = read_entries(&right_dir)?;
+    let mut names: Vec<(OsString, Option<PathBuf>, Option<PathBuf>)> = Vec::new();
+    for (name, path) in left_entries {
+        let right = right_entries.remove(&name);
+        names.push((name, Some(path), right));
+    }
+    names.extend(
+        right_entries
+            .into_iter()
+            .map(|(name, path)| (name, None, Some(path))),
+    );
+    names.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (name, left_path, right_path) in names {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(BokslDirError::OperationCancelled);
+        }
+        *compared += 1;
+        on_progress(*compared);
+        let child = relative.join(&name);
+        let left = left_path.as_deref().and_then(side_info);
+        let right = right_path.as_deref().and_then(side_info);
+        let (action, checked) = match (left, right) {
+            (None, None) => continue,
+            (Some(_), None) => (SyncAction::CopyToRight, true),
+            (None, Some(_)) => (SyncAction::CopyToLeft, true),
+            (Some(l), Some(r)) if l.is_dir && r.is_dir => {
+                plan_dir(
+                    left_root,
+                    right_root,
+                    &child,
+                    cancel,
+                    compared,
+                    on_progress,
+                    items,
+                )?;
+                continue;
+            }
+            (Some(l), Some(r)) if l.is_dir != r.is_dir => (SyncAction::CopyToRight, false),
+            (Some(l), Some(r)) => match newer_side(l.modified, r.modified) {
+                Some(true) => (SyncAction::CopyToRight, true),
+                Some(false) => (SyncAction::CopyToLeft, true),
+                None if l.size == r.size => continue,
+                None => (SyncAction::CopyToRight, false),
+            },
+        };
+        let (left, right) = (
+            left.map(|side| with_tree_size(side, left_path.as_deref())),
+            right.map(|side| with_tree_size(side, right_path.as_deref())),
+        );
+        items.push(SyncItem {
+            relative: child,
+            left,
+            right,
+            action,
+            checked,
+        });
+    }
+    Ok(())
+}
+
+/// 디렉토리 항목 (이름 → 경로)
+fn read_entries(dir: &Path) -> Result<BTreeMap<OsString, PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(BokslDirError::Io)?;
+    Ok(entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| (entry.file_name(), entry.path()))
+        .collect())
+}
+
+/// 항목 정보 (파일 링크는 가리키는 파일 기준, 디렉토리 링크는 순환을 피해 제외, 읽을 수 없으면 None)
+fn side_info(path: &Path) -> Option<SyncSide> {
+    let metadata = fs::metadata(path).ok()?;
+    if metadata.is_dir() && fs::symlink_metadata(path).ok()?.file_type().is_symlink() {
+        return None;
+    }
+    Some(SyncSide {
+        is_dir: metadata.is_dir(),
+        size: if metadata.is_dir() { 0 } else { metadata.len() },
+        modified: metadata.modified().ok(),
+    })
+}
+
+fn with_tree_size(side: SyncSide, path: Option<&Path>) -> SyncSide {
+    match path {
+        Some(path) if side.is_dir => SyncSide {
+            size: tree_size(path),
+            ..side
+        },
+        _ => side,
+    }
+}
+
+fn tree_size(dir: &Path) -> u64 {
+    fs::read_dir(dir).map_or(0, |entries| {
+        entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| match entry.metadata() {
+                Ok(metadata) if metadata.is_dir() => tree_size(&entry.path()),
+                Ok(metadata) => metadata.len(),
+                Err(_) => 0,
+            })
+            .sum()
+    })
+}
+
+/// 더 새로운 쪽 (true = 왼쪽, 차이가 허용 범위 안이거나 시각을 모르면 None)
+fn newer_side(left: Option<SystemTime>, right: Option<SystemTime>) -> Option<bool> {
+    let (left, right) = (left?, right?);
+    match left.duration_since(right) {
+        Ok(diff) if diff > MTIME_TOLERANCE => Some(true),
+        Ok(_) => None,
+        Err(err) if err.duration() > MTIME_TOLERANCE => Some(false),
+        Err(_) => None,
+    }
+}
+
+/// 체크한 항목 실행 (`on_progress(끝낸 항목 수, 전체 항목 수)`, 실패한 항목은 건너뛰고 계속)
+pub fn execute(
+    filesystem: &FileSystem,
+    left_root: &Path,
+    right_root: &Path,
+    items: &[SyncItem],
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(usize, usize),
+) -> Result<SyncSummary> {
+    let checked: Vec<&SyncItem> = items.iter().filter(|item| item.checked).collect();
+    let total = checked.len();
+    let mut summary = SyncSummary::default();
+    for (done, item) in checked.into_iter().enumerate() {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(BokslDirError::OperationCancelled);
+        }
+        on_progress(done, total);
+        let left = left_root.join(&item.relative);
+        let right = right_root.join(&item.relative);
+        let result = match item.action {
+            SyncAction::CopyToRight => copy_replacing(filesystem, &left, &right, cancel),
+            SyncAction::CopyToLeft => copy_replacing(filesystem, &right, &left, cancel),
+            SyncAction::DeleteLeft => remove_path(filesystem, &left),
+            SyncAction::DeleteRight => remove_path(filesystem, &right),
+        };
+        match result {
+            Ok(bytes) if item.action.is_delete() => {
+                summary.deleted += 1;
+                summary.bytes += bytes;
+            }
+            Ok(bytes) => {
+                summary.copied += 1;
+                summary.bytes += bytes;
+            }
+            Err(BokslDirError::OperationCancelled) => {
+                return Err(BokslDirError::OperationCancelled)
+            }
+            Err(err) => summary
+                .errors
+                .push(format!("{}: {}", item.relative.display(), err)),
+        }
+    }
+    on_progress(total, total);
+    Ok(summary)
+}
+
+/// 대상을 지우고 원본 트리를 시각/권한을 유지하며 복사
+fn copy_replacing(
+    filesystem: &FileSystem,
+    src: &Path,
+    dest: &Path,
+    cancel: &AtomicBool,
+) -> Result<u64> {
+    if fs::symlink_metadata(dest).is_ok() {
+        remove_path(filesystem, dest)?;
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).map_err(BokslDirError::Io)?;
+    }
+    copy_tree(filesystem, src, dest, &CopyOptions::preserve_all(), cancel)
+}
+
+fn copy_tree(
+    filesystem: &FileSystem,
+    src: &Path,
+    dest: &Path,
+    options: &CopyOptions,
+    cancel: &AtomicBool,
+) -> Result<u64> {
+    if cancel.load(Ordering::Relaxed) {
+        return Err(BokslDirError::OperationCancelled);
+    }
+    if !src.is_dir() {
+        return filesystem.copy_file_with_options(src, dest, options);
+    }
+    filesystem.create_directory(dest)?;
+    let mut bytes = 0;
+    for (name, path) in read_entries(src)? {
+        bytes += copy_tree(filesystem, &path, &dest.join(name), options, cancel)?;
+    }
+    Ok(bytes)
+}
+
+fn remove_path(filesystem: &FileSystem, path: &Path) -> Result<u64> {
+    if fs::symlink_metadata(path).is_ok_and(|metadata| metadata.is_dir()) {
+        filesystem.delete_directory(path)
+    } else {
+        filesystem.delete_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn set_mtime(path: &Path, time: SystemTime) {
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_plan_proposes_newer_side_and_orphans_then_executes() {
+        let temp = TempDir::new().unwrap();
+        let (left, right) = (temp.path().join("left"), temp.path().join("right"));
+        fs::create_dir_all(left.join("only_left_dir")).unwrap();
+        fs::create_dir_all(right.join("shared")).unwrap();
+        fs::create_dir_all(left.join("shared")).unwrap();
+        fs::write(left.join("only_left_dir/a.txt"), "aaa").unwrap();
+        fs::write(right.join("orphan.txt"), "orphan").unwrap();
+        let hour = Duration::from_secs(3600);
+        let now = SystemTime::now();
+        for (side, body, time) in [(&left, "old", now - hour), (&right, "newer", now)] {
+            fs::write(side.join("shared/doc.txt"), body).unwrap();
+            set_mtime(&side.join("shared/doc.txt"), time);
+            fs::write(side.join("same.txt"), "same").unwrap();
+            set_mtime(&side.join("same.txt"), now - hour);
+        }
+
+        let cancel = AtomicBool::new(false);
+        let mut items = plan(&left, &right, &cancel, &mut |_| {}).unwrap();
+        let summary: Vec<_> = items
+            .iter()
+            .map(|item| (item.relative.clone(), item.action, item.size()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (PathBuf::from("only_left_dir"), SyncAction::CopyToRight, 3),
+                (PathBuf::from("orphan.txt"), SyncAction::CopyToLeft, 6),
+                (PathBuf::from("shared/doc.txt"), SyncAction::CopyToLeft, 5),
+            ]
+        );
+
+        items[1].set_direction(true);
+        assert_eq!(items[1].action, SyncAction::DeleteRight);
+        let result = execute(
+            &FileSystem::new(),
+            &left,
+            &right,
+            &items,
+            &cancel,
+            &mut |_, _| {},
+        )
+        .unwrap();
+        assert_eq!((result.copied, result.deleted), (2, 1));
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            fs::read_to_string(right.join("only_left_dir/a.txt")).unwrap(),
+            "aaa"
+        );
+        assert_eq!(
+            fs::read_to_string(left.join("shared/doc.txt")).unwrap(),
+            "newer"
+        );
+        assert!(!right.join("orphan.txt").exists());
+        assert!(plan(&left, &right, &cancel, &mut |_| {})
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+//! uid/gid → 사용자/그룹 이름 조회 (Unix 전용, 프로세스 동안 캐시)
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+type NameCache = Mutex<HashMap<u32, Option<String>>>;
+
+/// uid에 해당하는 사용자 이름 (없으면 None)
+pub fn user_name(uid: u32) -> Option<String> {
+    static CACHE: OnceLock<NameCache> = OnceLock::new();
+    cached(&CACHE, uid, lookup_user)
+}
+
+/// gid에 해당하는 그룹 이름 (없으면 None)
+pub fn group_name(gid: u32) -> Option<String> {
+    static CACHE: OnceLock<NameCache> = OnceLock::new();
+    cached(&CACHE, gid, lookup_group)
+}
+
+fn cached(
+    cache: &OnceLock<NameCache>,
+    id: u32,
+    lookup: fn(u32) -> Option<String>,
+) -> Option<String> {
+    let cache = cache.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut names = cache.lock().unwrap_or_else(|e| e.into_inner());
+    names.entry(id).or_insert_with(|| lookup(id)).clone()
+}
+
+/// getpwuid_r/getgrgid_r 버퍼 크기 (ERANGE면 두 배씩 늘림)
+#[cfg(unix)]
+const INITIAL_BUFFER: usize = 1024;
+#[cfg(unix)]
+const MAX_BUFFER: usize = 1024 * 1024;
+
+#[cfg(unix)]
+fn lookup_user(uid: u32) -> Option<String> {
+    let mut size = INITIAL_BUFFER;
+    loop {
+        let mut buffer = vec![0 as libc::c_char; size];
+        let mut passwd = std::mem::MaybeUninit::<libc::passwd>::uninit();
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+        // SAFETY: passwd 구조체와 buffer 길이를 정확히 전달하고, 성공 시 result가 passwd를 가리킴
+        let rc = unsafe {
+            libc::getpwuid_r(
+                uid as libc::uid_t,
+                passwd.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut result,
+            )
+        };
+        if rc == libc::ERANGE && size < MAX_BUFFER {
+            size *= 2;
+            continue;
+        }
+        if rc != 0 || result.is_null() {
+            return None;
+        }
+        // SAFETY: 성공하면 pw_name은 buffer 안의 NUL 종료 문자열
+        let name = unsafe { std::ffi::CStr::from_ptr((*result).pw_name) };
+        return Some(name.to_string_lossy().into_owned());
+    }
+}
+
+#[cfg(unix)]
+fn lookup_group(gid: u32) -> Option<String> {
+    let mut size = INITIAL_BUFFER;
+    loop {
+        let mut buffer = vec![0 as libc::c_char; size];
+        let mut group = std::mem::MaybeUninit::<libc::group>::uninit();
+        let mut result: *mut libc::group = std::ptr::null_mut();
+        // SAFETY: group 구조체와 buffer 길이를 정확히 전달하고, 성공 시 result가 group을 가리킴
+        let rc = unsafe {
+            libc::getgrgid_r(
+                gid as libc::gid_t,
+                group.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut result,
+            )
+        };
+        if rc == libc::ERANGE && size < MAX_BUFFER {
+            size *= 2;
+            continue;
+        }
+        if rc != 0 || result.is_null() {
+            return None;
+        }
+        // SAFETY: 성공하면 gr_name은 buffer 안의 NUL 종료 문자열
+        let name = unsafe { std::ffi::CStr::from_ptr((*result).gr_name) };
+        return Some(name.to_string_lossy().into_owned());
+    }
+}
+
+#[cfg(not(unix))]
+fn lookup_user(_uid: u32) -> Option<String> {
+    None
+}
+
+#[cfg(not(unix))]
+fn lookup_group(_gid: u32) -> Option<String> {
+    None
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_names_resolve_and_cache() {
+        assert_eq!(user_name(0).as_deref(), Some("root"));
+        assert_eq!(user_name(0).as_deref(), Some("root"));
+        assert!(group_name(0).is_some());
+        assert_eq!(user_name(u32::MAX - 1), None);
+    }
+}
@@ -0,0 +1,218 @@
+//! 가상 파일시스템 (로컬/원격 패널 공통 인터페이스)
+//!
+//! 구현은 로컬 파일시스템(`FileSystem`)과 SFTP/FTP 원격(`RemoteFs`) 두 가지입니다.
+//! 원격 패널은 백엔드를 마운트해 목록 읽기와 디렉토리 생성/이름 변경/삭제를 이 트레잇으로
+//! 처리합니다. 경로는 백엔드가 패널에 표시하는 형식 그대로 주고받습니다(로컬은 일반 경로,
+//! 원격은 `sftp://host/dir` 또는 원격 절대 경로).
+//!
+//! 아직 백엔드가 아닌 것: 원격 전송(업로드/다운로드)은 `RemotePanelView`의 세션을 직접 쓰고,
+//! 압축 파일 내부는 별도 패널 상태(`ArchivePanelView`)로 처리하며, 휴지통 이동은 로컬
+//! `FileSystem::trash_items`만 지원합니다. 그래서 앱의 로컬 전용 기능은 마운트 여부만이 아니라
+//! 압축/원격 패널 보기 상태까지 보는 `App::is_local_panel_view`로 확인합니다.
+
+#![allow(dead_code)]
+
+use crate::models::file_entry::FileEntry;
+use crate::system::filesystem::{DirectoryListing, FileSystem};
+use crate::utils::error::{BokslDirError, Result};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// 경로 속성 (링크를 따라간 값)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VfsStat {
+    pub is_dir: bool,
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+    pub permissions: Option<u32>,
+}
+
+/// 쓰기 스트림. `commit`을 호출해야 내용이 확정됩니다 (원격은 이때 업로드).
+pub trait VfsWriter: Write + Send {
+    fn commit(self: Box<Self>) -> Result<()>;
+}
+
+/// 패널 백엔드 (로컬 파일시스템, SFTP/FTP 원격)
+pub trait VirtualFileSystem: Send + Sync + std::fmt::Debug {
+    /// 디렉토리 목록 (숨김/필터/정렬은 패널이 적용)
+    fn list(&self, dir: &Path) -> Result<Vec<FileEntry>>;
+
+    /// 디렉토리 목록 (처음 `sync_limit`개 이후는 백그라운드에서 이어 읽을 수 있음)
+    ///
+    /// 나눠 읽기를 지원하지 않는 백엔드는 전체 목록을 한 번에 반환합니다.
+    fn list_streaming(
+        &self,
+        dir: &Path,
+        _sync_limit: usize,
+    ) -> Result<(Vec<FileEntry>, Option<DirectoryListing>)> {
+        Ok((self.list(dir)?, None))
+    }
+
+    /// 디렉토리로 이동할 때의 목록 (최근 목록을 보관하는 백엔드는 바뀌지 않은 디렉토리를 다시 읽지 않음)
+    fn list_cached(
+        &self,
+        dir: &Path,
+        sync_limit: usize,
+    ) -> Result<(Vec<FileEntry>, Option<DirectoryListing>)> {
+        self.list_streaming(dir, sync_limit)
+    }
+
+    fn stat(&self, path: &Path) -> Result<VfsStat>;
+
+    fn open_read(&self, path: &Path) -> Result<Box<dyn Read + Send>>;
+
+    /// 새 파일 쓰기 (이미 있으면 FileExists)
+    fn open_write(&self, path: &Path) -> Result<Box<dyn VfsWriter>>;
+
+    /// 이름 변경 (대상이 있으면 FileExists)
+    fn rename(&self, from: &Path, to: &Path) -> Result<()>;
+
+    /// 파일 또는 디렉토리(재귀) 영구 삭제
+    fn delete(&self, path: &Path) -> Result<()>;
+
+    /// 디렉토리 생성 (이미 있으면 FileExists)
+    fn mkdir(&self, path: &Path) -> Result<()>;
+
+    /// 휴지통 지원 여부
+    fn supports_trash(&self) -> bool {
+        false
+    }
+
+    /// 패널의 상위 디렉토리 (백엔드 루트면 None)
+    fn parent(&self, path: &Path) -> Option<PathBuf> {
+        path.parent().map(Path::to_path_buf)
+    }
+}
+
+impl VfsWriter for fs::File {
+    fn commit(self: Box<Self>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// 로컬 파일시스템 (기본 백엔드)
+impl VirtualFileSystem for FileSystem {
+    fn list(&self, dir: &Path) -> Result<Vec<FileEntry>> {
+        self.read_directory(dir)
+    }
+
+    fn list_streaming(
+        &self,
+        dir: &Path,
+        sync_limit: usize,
+    ) -> Result<(Vec<FileEntry>, Option<DirectoryListing>)> {
+        self.read_directory_streaming(dir, sync_limit)
+    }
+
+    fn list_cached(
+        &self,
+        dir: &Path,
+        sync_limit: usize,
+    ) -> Result<(Vec<FileEntry>, Option<DirectoryListing>)> {
+        self.read_directory_cached(dir, sync_limit)
+    }
+
+    fn stat(&self, path: &Path) -> Result<VfsStat> {
+        let metadata = fs::metadata(path).map_err(|_| BokslDirError::PathNotFound {
+            path: path.to_path_buf(),
+        })?;
+        #[cfg(unix)]
+        let permissions = {
+            use std::os::unix::fs::PermissionsExt;
+            Some(metadata.permissions().mode())
+        };
+        #[cfg(not(unix))]
+        let permissions = None;
+        Ok(VfsStat {
+            is_dir: metadata.is_dir(),
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+            permissions,
+        })
+    }
+
+    fn open_read(&self, path: &Path) -> Result<Box<dyn Read + Send>> {
+        let file = fs::File::open(path).map_err(BokslDirError::Io)?;
+        Ok(Box::new(file))
+    }
+
+    fn open_write(&self, path: &Path) -> Result<Box<dyn VfsWriter>> {
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => BokslDirError::FileExists {
+                    path: path.to_path_buf(),
+                },
+                _ => BokslDirError::Io(e),
+            })?;
+        Ok(Box::new(file))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.rename_path(from, to)
+    }
+
+    fn delete(&self, path: &Path) -> Result<()> {
+        if path.is_dir() && !path.is_symlink() {
+            self.delete_directory(path).map(|_| ())
+        } else {
+            self.delete_file(path).map(|_| ())
+        }
+    }
+
+    fn mkdir(&self, path: &Path) -> Result<()> {
+        self.create_directory(path)
+    }
+
+    fn supports_trash(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_local_backend_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let vfs: &dyn VirtualFileSystem = &FileSystem::new();
+        let dir = temp.path().join("docs");
+        vfs.mkdir(&dir).unwrap();
+        assert!(matches!(
+            vfs.mkdir(&dir),
+            Err(BokslDirError::FileExists { .. })
+        ));
+
+        let file = dir.join("a.txt");
+        let mut writer = vfs.open_write(&file).unwrap();
+        writer.write_all(b"hello").unwrap();
+        writer.commit().unwrap();
+        assert!(vfs.open_write(&file).is_err());
+        let mut content = String::new();
+        vfs.open_read(&file)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "hello");
+        assert_eq!(vfs.stat(&file).unwrap().size, 5);
+        assert!(vfs.stat(&dir).unwrap().is_dir);
+
+        vfs.rename(&file, &dir.join("b.txt")).unwrap();
+        let names: Vec<_> = vfs
+            .list(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        assert_eq!(names, vec!["b.txt"]);
+        vfs.delete(&dir).unwrap();
+        assert!(!dir.exists());
+        assert_eq!(vfs.parent(&dir), Some(temp.path().to_path_buf()));
+    }
+}
@@ -8,5 +8,9 @@ mod builders;
 mod kind;
 mod render;
 
-pub use kind::{DialogKind, InputPurpose};
+pub use kind::{
+    BookmarkEditField, BookmarkListItem, BookmarkListRow, DialogKind, DiskUsageItem, FuzzyListItem,
+    FuzzyListPurpose, HistoryListItem, InputPurpose, MountPointItem, PanelSyncPhase, PromptInput,
+    SpaceEstimate,
+};
 pub use render::Dialog;
@@ -1,7 +1,20 @@
-use super::{DialogKind, InputPurpose};
-use crate::models::operation::OperationProgress;
+use super::{
+    BookmarkEditField, BookmarkListItem, BookmarkListRow, DialogKind, DiskUsageItem, FuzzyListItem,
+    FuzzyListPurpose, HistoryListItem, InputPurpose, MountPointItem, PanelSyncPhase, PromptInput,
+    SpaceEstimate,
+};
+use crate::models::jobs::Job;
+use crate::models::operation::{CopyOptions, OperationProgress};
+use crate::system::CompressionLevel;
+use crate::ui::components::list_dialog::{ListDialogState, ListNavigation};
+use crate::ui::components::path_input::PathCompletion;
+use crate::ui::TextKey;
+use crate::utils::fuzzy;
 use std::path::PathBuf;
 
+/// 압축 미리보기 목록 한 페이지 줄 수
+const ARCHIVE_PREVIEW_PAGE: usize = 12;
+
 impl DialogKind {
     /// 새 입력 다이얼로그 생성
     pub fn input(
@@ -29,9 +42,80 @@ impl DialogKind {
             selected_button: 0, // OK 기본 선택
             purpose: InputPurpose::OperationDestination,
             base_path,
-            completion_candidates: Vec::new(),
-            completion_index: None,
+            completion: PathCompletion::default(),
+            mask_input: false,
+            copy_options: None,
+            show_preview: false,
+            scope_filter: None,
+            free_space: None,
+        }
+    }
+
+    /// 복사 옵션 체크박스 표시 (입력 다이얼로그 전용)
+    pub fn with_copy_options(mut self, options: CopyOptions) -> Self {
+        if let DialogKind::Input { copy_options, .. } = &mut self {
+            *copy_options = Some(options);
+        }
+        self
+    }
+
+    /// 미리보기 버튼 표시 (입력 다이얼로그 전용)
+    pub fn with_preview(mut self) -> Self {
+        if let DialogKind::Input { show_preview, .. } = &mut self {
+            *show_preview = true;
+        }
+        self
+    }
+
+    /// 필요한 공간/여유 공간 표시 (입력 다이얼로그 전용)
+    pub fn with_free_space(mut self, estimate: SpaceEstimate) -> Self {
+        if let DialogKind::Input { free_space, .. } = &mut self {
+            *free_space = Some(estimate);
+        }
+        self
+    }
+
+    /// 범위 필터 줄 표시 (입력 다이얼로그 전용)
+    pub fn with_scope_filter(mut self, filter: impl Into<String>) -> Self {
+        if let DialogKind::Input { scope_filter, .. } = &mut self {
+            *scope_filter = Some(filter.into());
+        }
+        self
+    }
+
+    /// 복사/이동 범위 필터 입력 다이얼로그
+    pub fn operation_scope_filter_input(initial: impl Into<String>) -> Self {
+        let input = PromptInput::new(
+            TextKey::DialogTitleScopeFilter,
+            TextKey::DialogPromptScopeFilter,
+        )
+        .with_path_completion();
+        Self::prompt_input(input, "", initial, PathBuf::from("."))
+    }
+
+    /// 작업과 함께 여는 입력 다이얼로그 (`title`은 제목 뒤에 붙는 이름, 없으면 빈 문자열)
+    pub fn prompt_input(
+        input: PromptInput,
+        title: impl Into<String>,
+        initial: impl Into<String>,
+        base_path: PathBuf,
+    ) -> Self {
+        let value: String = initial.into();
+        let cursor_pos = value.len();
+        DialogKind::Input {
+            title: title.into(),
+            prompt: String::new(),
+            value,
+            cursor_pos,
+            selected_button: 0,
+            purpose: InputPurpose::Prompt(input),
+            base_path,
+            completion: PathCompletion::default(),
             mask_input: false,
+            copy_options: None,
+            show_preview: false,
+            scope_filter: None,
+            free_space: None,
         }
     }
 
@@ -47,9 +131,12 @@ impl DialogKind {
             selected_button: 0,
             purpose: InputPurpose::GoToPath,
             base_path,
-            completion_candidates: Vec::new(),
-            completion_index: None,
+            completion: PathCompletion::default(),
             mask_input: false,
+            copy_options: None,
+            show_preview: false,
+            scope_filter: None,
+            free_space: None,
         }
     }
 
@@ -65,14 +152,21 @@ impl DialogKind {
             selected_button: 0,
             purpose: InputPurpose::ArchiveCreatePath,
             base_path,
-            completion_candidates: Vec::new(),
-            completion_index: None,
+            completion: PathCompletion::default(),
             mask_input: false,
+            copy_options: None,
+            show_preview: false,
+            scope_filter: None,
+            free_space: None,
         }
     }
 
     /// 압축 생성 다이얼로그 생성
-    pub fn archive_create_options_input(initial: impl Into<String>, base_path: PathBuf) -> Self {
+    pub fn archive_create_options_input(
+        initial: impl Into<String>,
+        base_path: PathBuf,
+        presets: Vec<String>,
+    ) -> Self {
         let path_value: String = initial.into();
         let path_cursor_pos = path_value.len();
         DialogKind::ArchiveCreateOptions {
@@ -86,6 +180,8 @@ impl DialogKind {
             focused_field: 0,
             selected_button: 0,
             base_path,
+            level: CompressionLevel::default(),
+            presets,
         }
     }
 
@@ -101,9 +197,12 @@ impl DialogKind {
             selected_button: 0,
             purpose: InputPurpose::ArchiveExtractDestination,
             base_path,
-            completion_candidates: Vec::new(),
-            completion_index: None,
+            completion: PathCompletion::default(),
             mask_input: false,
+            copy_options: None,
+            show_preview: false,
+            scope_filter: None,
+            free_space: None,
         }
     }
 
@@ -117,9 +216,12 @@ impl DialogKind {
             selected_button: 0,
             purpose: InputPurpose::ArchivePassword,
             base_path: PathBuf::from("."),
-            completion_candidates: Vec::new(),
-            completion_index: None,
+            completion: PathCompletion::default(),
             mask_input: true,
+            copy_options: None,
+            show_preview: false,
+            scope_filter: None,
+            free_space: None,
         }
     }
 
@@ -135,12 +237,85 @@ impl DialogKind {
             selected_button: 0,
             purpose: InputPurpose::TerminalCommand,
             base_path,
-            completion_candidates: Vec::new(),
-            completion_index: None,
+            completion: PathCompletion::default(),
             mask_input: false,
+            copy_options: None,
+            show_preview: false,
+            scope_filter: None,
+            free_space: None,
         }
     }
 
+    /// 북마크 내보내기/가져오기 파일 경로 입력
+    pub fn bookmark_file_input(
+        export: bool,
+        initial: impl Into<String>,
+        base_path: PathBuf,
+    ) -> Self {
+        let title = if export {
+            TextKey::DialogTitleExportBookmarks
+        } else {
+            TextKey::DialogTitleImportBookmarks
+        };
+        let input =
+            PromptInput::new(title, TextKey::DialogPromptBookmarkFile).with_path_completion();
+        Self::prompt_input(input, "", initial, base_path)
+    }
+
+    /// 세션 이름 입력 다이얼로그 생성
+    pub fn session_name_input(initial: impl Into<String>) -> Self {
+        let input = PromptInput::new(
+            TextKey::DialogTitleSaveSession,
+            TextKey::DialogPromptSessionName,
+        );
+        Self::prompt_input(input, "", initial, PathBuf::from("."))
+    }
+
+    /// 탭 이름 입력 다이얼로그
+    pub fn tab_rename_input(initial: impl Into<String>) -> Self {
+        let input = PromptInput::new(TextKey::DialogTitleRenameTab, TextKey::DialogPromptTabName);
+        Self::prompt_input(input, "", initial, PathBuf::from("."))
+    }
+
+    /// 숨김 패턴 편집 다이얼로그 (공백 구분 글로브)
+    pub fn hide_patterns_input(initial: impl Into<String>) -> Self {
+        let input = PromptInput::new(
+            TextKey::DialogTitleHidePatterns,
+            TextKey::DialogPromptHidePatterns,
+        );
+        Self::prompt_input(input, "", initial, PathBuf::from("."))
+    }
+
+    /// 자동 새로고침 주기 입력 다이얼로그 (초)
+    pub fn auto_refresh_input(initial: impl Into<String>) -> Self {
+        let input = PromptInput::new(
+            TextKey::DialogTitleAutoRefresh,
+            TextKey::DialogPromptAutoRefresh,
+        );
+        Self::prompt_input(input, "", initial, PathBuf::from("."))
+    }
+
+    /// 새 파일 이름 입력 다이얼로그 (Ctrl+E: 만든 뒤 편집)
+    pub fn new_file_input(parent_path: PathBuf) -> Self {
+        let input = PromptInput::new(TextKey::DialogTitleNewFile, TextKey::DialogPromptNewFile)
+            .with_alternate_key('e');
+        Self::prompt_input(input, "", "", parent_path)
+    }
+
+    /// 실행 파일 인자 입력 다이얼로그 (제목에 파일 이름 표시, Ctrl+O: 출력 보기)
+    pub fn run_executable_input(executable: PathBuf) -> Self {
+        let name = executable
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let input = PromptInput::new(
+            TextKey::DialogTitleRunExecutable,
+            TextKey::DialogPromptRunArgs,
+        )
+        .with_alternate_key('o');
+        Self::prompt_input(input, name, "", executable)
+    }
+
     /// 새 확인 다이얼로그 생성
     pub fn confirm(title: impl Into<String>, message: impl Into<String>) -> Self {
         DialogKind::Confirm {
@@ -156,6 +331,26 @@ impl DialogKind {
             source_path: source,
             dest_path: dest,
             selected_option: 0,
+            directory: false,
+        }
+    }
+
+    /// 디렉토리 충돌 다이얼로그 (기본 선택: Merge)
+    pub fn directory_conflict(source: PathBuf, dest: PathBuf) -> Self {
+        DialogKind::Conflict {
+            source_path: source,
+            dest_path: dest,
+            selected_option: 5,
+            directory: true,
+        }
+    }
+
+    /// 사용 중인 파일 선택 다이얼로그 (기본 선택: 다시 시도)
+    pub fn file_in_use(path: PathBuf, error: impl Into<String>) -> Self {
+        DialogKind::FileInUse {
+            path,
+            error: error.into(),
+            selected_button: 0,
         }
     }
 
@@ -189,13 +384,23 @@ impl DialogKind {
         }
     }
 
+    /// 새 작업 중 종료 확인 다이얼로그 생성
+    pub fn quit_confirm(jobs: Vec<String>) -> Self {
+        DialogKind::QuitConfirm {
+            jobs,
+            selected_button: 0,
+        }
+    }
+
     /// 새 디렉토리 생성 입력 다이얼로그
-    pub fn mkdir_input(parent_path: PathBuf) -> Self {
+    pub fn mkdir_input(parent_path: PathBuf, enter_created: bool) -> Self {
         DialogKind::MkdirInput {
             value: String::new(),
             cursor_pos: 0,
             selected_button: 0,
             parent_path,
+            enter_created,
+            completion: PathCompletion::default(),
         }
     }
 
@@ -223,42 +428,236 @@ impl DialogKind {
     }
 
     /// 마운트 포인트 선택 다이얼로그
-    pub fn mount_points(items: Vec<(String, std::path::PathBuf)>) -> Self {
+    pub fn mount_points(items: Vec<MountPointItem>) -> Self {
         DialogKind::MountPoints {
+            list: ListDialogState::new(items),
+        }
+    }
+
+    /// 파일 비교 진행 다이얼로그
+    pub fn file_compare(name: impl Into<String>) -> Self {
+        DialogKind::FileCompare {
+            name: name.into(),
+            checked: 0,
+            total: 0,
+        }
+    }
+
+    /// 디스크 사용량 분석 다이얼로그 (스캔 시작 상태)
+    pub fn disk_usage_scanning(path: impl Into<String>) -> Self {
+        DialogKind::DiskUsage {
+            path: path.into(),
+            total: 0,
+            items: Vec::new(),
+            selected_index: 0,
+            scanning: Some((0, 0)),
+            pending_delete: None,
+        }
+    }
+
+    /// 디스크 사용량 분석 결과 다이얼로그
+    pub fn disk_usage(
+        path: impl Into<String>,
+        total: u64,
+        items: Vec<DiskUsageItem>,
+        selected_index: usize,
+    ) -> Self {
+        let selected_index = selected_index.min(items.len().saturating_sub(1));
+        DialogKind::DiskUsage {
+            path: path.into(),
+            total,
             items,
+            selected_index,
+            scanning: None,
+            pending_delete: None,
+        }
+    }
+
+    /// 패널 동기화 다이얼로그 (비교 시작 상태)
+    pub fn panel_sync_comparing(left: impl Into<String>, right: impl Into<String>) -> Self {
+        DialogKind::PanelSync {
+            left: left.into(),
+            right: right.into(),
+            items: Vec::new(),
             selected_index: 0,
+            phase: PanelSyncPhase::Comparing(0),
         }
     }
 
     /// 탭 목록 선택 다이얼로그
     pub fn tab_list(items: Vec<String>, selected_index: usize) -> Self {
         DialogKind::TabList {
-            items,
-            selected_index,
+            list: ListDialogState::new(items).with_selected(selected_index),
         }
     }
 
     /// 히스토리 목록 선택 다이얼로그
-    pub fn history_list(
-        items: Vec<(String, std::path::PathBuf, bool)>,
-        selected_index: usize,
-    ) -> Self {
+    pub fn history_list(items: Vec<HistoryListItem>, selected_index: usize) -> Self {
         DialogKind::HistoryList {
-            items,
-            selected_index,
+            list: ListDialogState::new(items)
+                .with_selected(selected_index)
+                .with_search(|item, needle| {
+                    item.path.to_string_lossy().to_lowercase().contains(needle)
+                }),
+        }
+    }
+
+    /// 선택 목록 다이얼로그의 공통 목록 상태 (목록 다이얼로그가 아니면 None)
+    pub fn list_navigation_mut(&mut self) -> Option<&mut dyn ListNavigation> {
+        match self {
+            DialogKind::MountPoints { list } => Some(list),
+            DialogKind::TabList { list } | DialogKind::RemoteHostList { list } => Some(list),
+            DialogKind::HistoryList { list } => Some(list),
+            DialogKind::BookmarkList { rows, .. } => Some(rows),
+            DialogKind::SessionList { list } | DialogKind::ArchivePreviewList { list, .. } => {
+                Some(list)
+            }
+            DialogKind::JobList { list } => Some(list),
+            DialogKind::ThemeList { list, .. } => Some(list),
+            _ => None,
+        }
+    }
+
+    /// 목록 다이얼로그에서 검색어 입력 중이면 true
+    pub fn list_searching(&self) -> bool {
+        match self {
+            DialogKind::HistoryList { list } => list.searching,
+            _ => false,
         }
     }
 
     /// 북마크 목록 선택 다이얼로그
-    pub fn bookmark_list(items: Vec<(String, std::path::PathBuf)>, selected_index: usize) -> Self {
+    ///
+    /// 그룹 없는 북마크를 먼저, 이후 그룹을 이름순으로 표시합니다. 선택한 북마크가
+    /// 접힌 그룹에 있으면 그룹 머리글을 선택합니다.
+    pub fn bookmark_list(
+        items: Vec<BookmarkListItem>,
+        collapsed_groups: &[String],
+        selected_bookmark: usize,
+    ) -> Self {
+        let mut rows: Vec<BookmarkListRow> = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.group.is_none())
+            .map(|(index, _)| BookmarkListRow::Bookmark(index))
+            .collect();
+        let mut groups: Vec<&str> = items
+            .iter()
+            .filter_map(|item| item.group.as_deref())
+            .collect();
+        groups.sort_by_key(|name| name.to_lowercase());
+        groups.dedup();
+        for group in groups {
+            let members: Vec<usize> = items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.group.as_deref() == Some(group))
+                .map(|(index, _)| index)
+                .collect();
+            let collapsed = collapsed_groups.iter().any(|name| name == group);
+            rows.push(BookmarkListRow::Group {
+                name: group.to_string(),
+                count: members.len(),
+                collapsed,
+            });
+            if !collapsed {
+                rows.extend(members.into_iter().map(BookmarkListRow::Bookmark));
+            }
+        }
+
+        let selected_group = items
+            .get(selected_bookmark)
+            .and_then(|item| item.group.as_deref());
+        let selected_index = rows
+            .iter()
+            .position(|row| *row == BookmarkListRow::Bookmark(selected_bookmark))
+            .or_else(|| {
+                rows.iter().position(|row| {
+                    matches!(row, BookmarkListRow::Group { name, .. } if Some(name.as_str()) == selected_group)
+                })
+            })
+            .unwrap_or(0);
         DialogKind::BookmarkList {
             items,
-            selected_index,
+            rows: ListDialogState::new(rows).with_selected(selected_index),
+        }
+    }
+
+    /// 북마크 목록에서 그룹 머리글 선택
+    pub fn select_bookmark_group(&mut self, group: &str) {
+        if let DialogKind::BookmarkList { rows, .. } = self {
+            if let Some(index) = rows
+                .items
+                .iter()
+                .position(|row| matches!(row, BookmarkListRow::Group { name, .. } if name == group))
+            {
+                rows.selected = index;
+            }
+        }
+    }
+
+    /// 세션 목록 선택 다이얼로그
+    pub fn session_list(items: Vec<(String, String)>, selected_index: usize) -> Self {
+        DialogKind::SessionList {
+            list: ListDialogState::new(items).with_selected(selected_index),
+        }
+    }
+
+    /// 작업 목록 다이얼로그
+    pub fn job_list(jobs: Vec<Job>) -> Self {
+        DialogKind::JobList {
+            list: ListDialogState::new(jobs),
+        }
+    }
+
+    /// 원격 접속 대상 목록 다이얼로그
+    pub fn remote_host_list(items: Vec<String>, selected_index: usize) -> Self {
+        DialogKind::RemoteHostList {
+            list: ListDialogState::new(items).with_selected(selected_index),
+        }
+    }
+
+    /// 원격 접속 URL 입력 다이얼로그 생성
+    pub fn remote_connect_input(initial: impl Into<String>) -> Self {
+        let input = PromptInput::new(
+            TextKey::DialogTitleConnectRemote,
+            TextKey::DialogPromptRemoteUrl,
+        );
+        Self::prompt_input(input, "", initial, PathBuf::from("."))
+    }
+
+    /// 테마 선택 다이얼로그 (현재 테마에 커서)
+    pub fn theme_list(items: Vec<(String, Option<std::path::PathBuf>)>, current: &str) -> Self {
+        let selected_index = items
+            .iter()
+            .position(|(name, _)| name == current)
+            .unwrap_or(0);
+        DialogKind::ThemeList {
+            list: ListDialogState::new(items).with_selected(selected_index),
+            current: current.to_string(),
         }
     }
 
     /// 북마크 이름 변경 입력 다이얼로그
     pub fn bookmark_rename_input(value: impl Into<String>, bookmark_index: usize) -> Self {
+        Self::bookmark_edit_input(value, bookmark_index, BookmarkEditField::Name)
+    }
+
+    /// 북마크 그룹 지정 입력 다이얼로그
+    pub fn bookmark_group_input(value: impl Into<String>, bookmark_index: usize) -> Self {
+        Self::bookmark_edit_input(value, bookmark_index, BookmarkEditField::Group)
+    }
+
+    /// 북마크 대상 경로 편집 입력 다이얼로그
+    pub fn bookmark_path_input(value: impl Into<String>, bookmark_index: usize) -> Self {
+        Self::bookmark_edit_input(value, bookmark_index, BookmarkEditField::Path)
+    }
+
+    fn bookmark_edit_input(
+        value: impl Into<String>,
+        bookmark_index: usize,
+        field: BookmarkEditField,
+    ) -> Self {
         let value: String = value.into();
         let cursor_pos = value.len();
         DialogKind::BookmarkRenameInput {
@@ -266,6 +665,7 @@ impl DialogKind {
             cursor_pos,
             selected_button: 0,
             bookmark_index,
+            field,
         }
     }
 
@@ -277,10 +677,75 @@ impl DialogKind {
     ) -> Self {
         DialogKind::ArchivePreviewList {
             archive_name: archive_name.into(),
+            list: ListDialogState::new(items).with_page(ARCHIVE_PREVIEW_PAGE),
+            truncated,
+        }
+    }
+
+    /// 텍스트 보기 다이얼로그 (탭은 공백 4칸으로 펼침)
+    pub fn text_viewer(title: impl Into<String>, text: &str, diff: bool) -> Self {
+        DialogKind::TextViewer {
+            title: title.into(),
+            lines: text
+                .lines()
+                .map(|line| line.replace('\t', "    "))
+                .collect(),
+            scroll_offset: 0,
+            diff,
+        }
+    }
+
+    /// 퍼지 목록 다이얼로그 (빈 질의: 전체 항목을 원래 순서로)
+    pub fn fuzzy_list(
+        title: impl Into<String>,
+        purpose: FuzzyListPurpose,
+        items: Vec<FuzzyListItem>,
+    ) -> Self {
+        let mut dialog = DialogKind::FuzzyList {
+            title: title.into(),
+            query: String::new(),
+            cursor_pos: 0,
             items,
+            matches: Vec::new(),
             selected_index: 0,
-            scroll_offset: 0,
-            truncated,
+            purpose,
+            loading: false,
+        };
+        dialog.refilter_fuzzy_list();
+        dialog
+    }
+
+    /// 퍼지 목록 질의로 일치 항목 다시 계산 (선택은 맨 위로)
+    pub fn refilter_fuzzy_list(&mut self) {
+        self.rematch_fuzzy_list();
+        if let DialogKind::FuzzyList { selected_index, .. } = self {
+            *selected_index = 0;
+        }
+    }
+
+    /// 백그라운드로 모은 항목 추가 (선택 위치는 유지)
+    pub fn extend_fuzzy_list(&mut self, more: Vec<FuzzyListItem>, done: bool) {
+        if let DialogKind::FuzzyList { items, loading, .. } = self {
+            items.extend(more);
+            *loading = !done;
+        }
+        self.rematch_fuzzy_list();
+    }
+
+    fn rematch_fuzzy_list(&mut self) {
+        if let DialogKind::FuzzyList {
+            query,
+            items,
+            matches,
+            selected_index,
+            ..
+        } = self
+        {
+            *matches = fuzzy::filter(query, items.iter().map(|item| item.label.as_str()))
+                .into_iter()
+                .map(|(index, found)| (index, found.positions))
+                .collect();
+            *selected_index = (*selected_index).min(matches.len().saturating_sub(1));
         }
     }
 
@@ -312,6 +777,11 @@ impl DialogKind {
             modified: modified.into(),
             permissions: permissions.into(),
             children_info,
+            detected_type: None,
+            attributes: None,
+            targets: 1,
+            xattrs: Vec::new(),
+            xattr_index: 0,
         }
     }
 }
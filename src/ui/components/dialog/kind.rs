@@ -1,6 +1,133 @@
-use crate::models::operation::{ConflictResolution, OperationProgress};
+use crate::models::jobs::Job;
+use crate::models::operation::{ConflictResolution, CopyOptions, OperationProgress};
+use crate::system::attributes::FileAttributes;
+use crate::system::sync::SyncItem;
+use crate::system::CompressionLevel;
+use crate::ui::components::list_dialog::ListDialogState;
+use crate::ui::components::path_input::PathCompletion;
+use crate::ui::TextKey;
 use std::path::PathBuf;
 
+/// 마운트 포인트 다이얼로그 항목 (용량을 모르면 total = 0)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountPointItem {
+    pub name: String,
+    pub path: PathBuf,
+    pub fs_type: Option<String>,
+    pub total: u64,
+    pub used: u64,
+}
+
+/// 디스크 사용량 분석 목록 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskUsageItem {
+    pub name: String,
+    pub size: u64,
+    pub is_dir: bool,
+    /// 읽지 못한 하위 디렉토리가 있어 크기가 일부만 집계됨
+    pub incomplete: bool,
+}
+
+/// 복사/이동에 필요한 공간과 대상 여유 공간 (대상 경로 입력 버튼 줄에 표시)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpaceEstimate {
+    /// 필요한 바이트 (크기 계산을 하지 않은 디렉토리는 빠짐)
+    pub needed: u64,
+    /// 크기를 모르는 디렉토리가 있어 실제로는 더 필요할 수 있음
+    pub partial: bool,
+    /// 대상 파일시스템에서 현재 사용자가 쓸 수 있는 공간 (모르면 None)
+    pub available: Option<u64>,
+}
+
+impl SpaceEstimate {
+    /// 여유 공간이 모자라는 것이 확실함
+    pub fn is_short(&self) -> bool {
+        self.available
+            .is_some_and(|available| self.needed > available)
+    }
+}
+
+/// 패널 동기화 다이얼로그 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelSyncPhase {
+    /// 양쪽 트리 비교 중 (비교한 항목 수)
+    Comparing(u64),
+    /// 전송 계획 검토 (체크/방향 변경)
+    Review,
+    /// 체크한 항목 실행 중
+    Running { done: usize, total: usize },
+}
+
+/// 퍼지 목록 항목 (`label`로 매칭, `detail`은 오른쪽 보조 설명)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyListItem {
+    pub label: String,
+    pub detail: String,
+}
+
+/// 퍼지 목록 다이얼로그 목적 (선택 확정 시 동작)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuzzyListPurpose {
+    /// 자주/최근 방문한 디렉토리로 이동
+    JumpList,
+    /// 상위 디렉토리의 다른 하위 디렉토리로 이동 (`label`은 이름)
+    Siblings,
+    /// 현재 경로 아래 파일로 이동 (`label`은 상대 경로)
+    FindFile,
+    /// 고른 액션 실행 (항목 순서는 팔레트 엔트리 순서)
+    CommandPalette,
+    /// 압축 보기의 ZIP 항목 이름 인코딩 (`label`은 인코딩 이름)
+    ArchiveNameEncoding,
+    /// 플러그인 액션 실행 (항목 순서는 켜진 플러그인의 액션 순서)
+    PluginAction,
+}
+
+/// 디렉토리 히스토리 목록 항목 (최신순)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryListItem {
+    pub path: PathBuf,
+    /// 현재 히스토리 위치
+    pub is_current: bool,
+    /// 고정 여부 (개수 상한으로 지워지지 않음)
+    pub pinned: bool,
+    /// 마지막 방문 시각 (UNIX 초)
+    pub visited_at: Option<u64>,
+}
+
+/// 북마크 목록 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkListItem {
+    pub name: String,
+    pub path: PathBuf,
+    /// 이동 단축키 숫자 (`'` + 숫자)
+    pub hotkey: Option<u8>,
+    /// 소속 그룹 (없으면 맨 위에 그룹 없이 표시)
+    pub group: Option<String>,
+}
+
+/// 북마크 목록 표시 행
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookmarkListRow {
+    /// 그룹 머리글 (접으면 소속 북마크 행을 숨김)
+    Group {
+        name: String,
+        count: usize,
+        collapsed: bool,
+    },
+    /// 북마크 (`items` 인덱스)
+    Bookmark(usize),
+}
+
+/// 북마크 편집 입력 대상
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkEditField {
+    Name,
+    /// 대상 경로 (존재하는 디렉토리만 허용)
+    Path,
+    /// 소속 그룹 (비우면 그룹 해제)
+    Group,
+}
+
 /// 입력 다이얼로그 목적
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputPurpose {
@@ -16,6 +143,43 @@ pub enum InputPurpose {
     ArchivePassword,
     /// 터미널 명령 실행
     TerminalCommand,
+    /// 결과를 받을 작업과 함께 연 입력 (`App::on_dialog_text`로 값 전달)
+    Prompt(PromptInput),
+}
+
+/// 작업과 함께 여는 입력 창의 제목/안내와 동작
+///
+/// 입력 값은 창을 열 때 지정한 작업이 받으므로, 새 입력 창을 추가해도
+/// `InputPurpose` 분기를 늘리지 않습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PromptInput {
+    pub title: TextKey,
+    pub prompt: TextKey,
+    /// 경로 자동완성 추천 사용
+    pub path_completion: bool,
+    /// Ctrl+키 대체 확정 (`DialogOutcome::AlternateText`로 전달)
+    pub alternate_key: Option<char>,
+}
+
+impl PromptInput {
+    pub const fn new(title: TextKey, prompt: TextKey) -> Self {
+        Self {
+            title,
+            prompt,
+            path_completion: false,
+            alternate_key: None,
+        }
+    }
+
+    pub const fn with_path_completion(mut self) -> Self {
+        self.path_completion = true;
+        self
+    }
+
+    pub const fn with_alternate_key(mut self, key: char) -> Self {
+        self.alternate_key = Some(key);
+        self
+    }
 }
 
 /// 다이얼로그 종류
@@ -30,9 +194,17 @@ pub enum DialogKind {
         selected_button: usize, // 0: OK, 1: Cancel
         purpose: InputPurpose,
         base_path: PathBuf,
-        completion_candidates: Vec<String>,
-        completion_index: Option<usize>,
+        /// 경로 자동완성 추천 (히스토리 + 파일시스템)
+        completion: PathCompletion,
         mask_input: bool,
+        /// 복사 옵션 체크박스 (Copy 대상 경로 입력에서만 Some)
+        copy_options: Option<CopyOptions>,
+        /// 미리보기 버튼 (selected_button 2, 복사/이동 대상 경로 입력에서만 표시)
+        show_preview: bool,
+        /// 범위 필터 문자열 (복사/이동 대상 경로 입력에서만 Some, Ctrl+F로 편집)
+        scope_filter: Option<String>,
+        /// 필요한 공간과 대상 여유 공간 (버튼 줄 오른쪽, 공간이 필요한 복사/이동에서만 Some)
+        free_space: Option<SpaceEstimate>,
     },
     /// 압축 생성 입력 다이얼로그 (경로 + 비밀번호 옵션)
     ArchiveCreateOptions {
@@ -46,6 +218,10 @@ pub enum DialogKind {
         focused_field: usize, // 0:path, 1:checkbox, 2:password, 3:confirm, 4:buttons
         selected_button: usize, // 0: OK, 1: Cancel
         base_path: PathBuf,
+        /// 압축 수준 (checkbox 줄에서 Left/Right로 변경)
+        level: CompressionLevel,
+        /// 프리셋 이름 (숫자 키 순서)
+        presets: Vec<String>,
     },
     /// 확인 다이얼로그 (Yes/No)
     Confirm {
@@ -57,7 +233,9 @@ pub enum DialogKind {
     Conflict {
         source_path: PathBuf,
         dest_path: PathBuf,
-        selected_option: usize, // 0-4: Overwrite, Skip, OverwriteAll, SkipAll, Cancel
+        selected_option: usize, // 0-4: Overwrite, Skip, OverwriteAll, SkipAll, Cancel, 5: Merge
+        /// 이미 있는 디렉토리 위로 디렉토리를 복사/이동 (Merge 표시, Overwrite는 통째로 바꾸기)
+        directory: bool,
     },
     /// 진행률 다이얼로그
     Progress { progress: OperationProgress },
@@ -71,6 +249,17 @@ pub enum DialogKind {
         total_size: String,
         selected_button: usize, // 0: 휴지통, 1: 영구 삭제, 2: 취소
     },
+    /// 작업 중 종료 확인 다이얼로그 (진행 중인 작업 이름)
+    QuitConfirm {
+        jobs: Vec<String>,
+        selected_button: usize, // 0: 끝나면 종료, 1: 취소하고 종료, 2: 취소
+    },
+    /// 자동 재시도 후에도 다른 프로세스가 쓰고 있는 파일 (복사/이동/삭제 중)
+    FileInUse {
+        path: PathBuf,
+        error: String,
+        selected_button: usize, // 0: 다시 시도, 1: 건너뛰기, 2: 모두 건너뛰기, 3: 취소
+    },
     // Phase 3.4: 기타 파일 작업
     /// 새 디렉토리 생성 입력 다이얼로그
     MkdirInput {
@@ -78,6 +267,10 @@ pub enum DialogKind {
         cursor_pos: usize,
         selected_button: usize, // 0: OK, 1: Cancel
         parent_path: PathBuf,
+        /// 만든 뒤 바로 들어가기 (Ctrl+E)
+        enter_created: bool,
+        /// parent_path 기준 디렉토리 자동완성 추천
+        completion: PathCompletion,
     },
     /// 이름 변경 입력 다이얼로그
     RenameInput {
@@ -101,23 +294,33 @@ pub enum DialogKind {
     },
     /// 마운트 포인트 선택 다이얼로그 (Phase 5.3)
     MountPoints {
-        items: Vec<(String, std::path::PathBuf)>,
-        selected_index: usize,
+        list: ListDialogState<MountPointItem>,
     },
     /// 탭 목록 선택 다이얼로그 (Phase 6.1)
-    TabList {
-        items: Vec<String>,
-        selected_index: usize,
-    },
-    /// 디렉토리 히스토리 목록 선택 다이얼로그 (Phase 6.2)
+    TabList { list: ListDialogState<String> },
+    /// 디렉토리 히스토리 목록 선택 다이얼로그 (Phase 6.2, `/`로 경로 부분 문자열 검색)
     HistoryList {
-        items: Vec<(String, std::path::PathBuf, bool)>,
-        selected_index: usize,
+        list: ListDialogState<HistoryListItem>,
     },
     /// 북마크 목록 선택 다이얼로그 (Phase 6.3)
+    /// 북마크 목록 (그룹별 접기/펼치기)
     BookmarkList {
-        items: Vec<(String, std::path::PathBuf)>,
-        selected_index: usize,
+        items: Vec<BookmarkListItem>,
+        /// 보이는 줄 (그룹 머리글 + 펼친 그룹의 북마크)
+        rows: ListDialogState<BookmarkListRow>,
+    },
+    /// 저장된 세션 목록 선택 다이얼로그 (이름, 요약)
+    SessionList {
+        list: ListDialogState<(String, String)>,
+    },
+    /// 최근 백그라운드 작업 목록 (최신순)
+    JobList { list: ListDialogState<Job> },
+    /// 저장된 원격 접속 대상 목록 (URL)
+    RemoteHostList { list: ListDialogState<String> },
+    /// 테마 선택 다이얼로그 (이름, 사용자 테마 파일 경로)
+    ThemeList {
+        list: ListDialogState<(String, Option<std::path::PathBuf>)>,
+        current: String,
     },
     /// 북마크 이름 변경 입력 다이얼로그 (Phase 6.3)
     BookmarkRenameInput {
@@ -125,14 +328,60 @@ pub enum DialogKind {
         cursor_pos: usize,
         selected_button: usize, // 0: OK, 1: Cancel
         bookmark_index: usize,
+        field: BookmarkEditField,
     },
     /// 압축 파일 내부 목록 미리보기
     ArchivePreviewList {
         archive_name: String,
-        items: Vec<(String, String)>,
+        list: ListDialogState<(String, String)>,
+        truncated: bool,
+    },
+    /// 입력 즉시 좁혀지는 퍼지 목록 다이얼로그
+    FuzzyList {
+        title: String,
+        query: String,
+        cursor_pos: usize,
+        items: Vec<FuzzyListItem>,
+        /// 질의와 일치한 항목 (원래 인덱스, 일치한 글자 위치), 점수순
+        matches: Vec<(usize, Vec<usize>)>,
+        /// `matches` 안에서의 선택 위치
         selected_index: usize,
+        purpose: FuzzyListPurpose,
+        /// 항목을 백그라운드에서 아직 모으는 중
+        loading: bool,
+    },
+    /// 읽기 전용 텍스트 보기 (diff면 추가/삭제 줄을 색상으로 구분)
+    TextViewer {
+        title: String,
+        lines: Vec<String>,
         scroll_offset: usize,
-        truncated: bool,
+        diff: bool,
+    },
+    /// 패널 간 파일 내용 비교 진행 (checked/total 바이트)
+    FileCompare {
+        name: String,
+        checked: u64,
+        total: u64,
+    },
+    /// 디스크 사용량 분석 (크기순 목록, 스캔 중이면 진행 상황 표시)
+    DiskUsage {
+        path: String,
+        /// 현재 디렉토리 전체 크기 (비율 계산 기준)
+        total: u64,
+        items: Vec<DiskUsageItem>,
+        selected_index: usize,
+        /// 스캔 중 (항목 수, 누적 크기)
+        scanning: Option<(u64, u64)>,
+        /// 삭제 확인 대기 (true = 영구 삭제, false = 휴지통)
+        pending_delete: Option<bool>,
+    },
+    /// 두 패널 동기화 (전송 계획 체크 목록, 비교/실행 중이면 진행 상황 표시)
+    PanelSync {
+        left: String,
+        right: String,
+        items: Vec<SyncItem>,
+        selected_index: usize,
+        phase: PanelSyncPhase,
     },
     /// 파일 속성 다이얼로그
     Properties {
@@ -143,6 +392,16 @@ pub enum DialogKind {
         modified: String,
         permissions: String,
         children_info: Option<String>, // 디렉토리인 경우 하위 항목 수
+        /// 매직 넘버로 판별한 파일 형식 (디렉토리/비로컬이면 None)
+        detected_type: Option<String>,
+        /// 전환 가능한 속성 (로컬 파일이 아니면 None)
+        attributes: Option<FileAttributes>,
+        /// 속성 변경이 적용될 항목 수 (선택 항목 또는 포커스 항목)
+        targets: usize,
+        /// 확장 속성 (이름, 잘라낸 표시 값)
+        xattrs: Vec<(String, String)>,
+        /// 확장 속성 목록 선택 위치
+        xattr_index: usize,
     },
 }
 
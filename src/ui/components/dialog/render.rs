@@ -1,22 +1,35 @@
-use super::{DialogKind, InputPurpose};
+use super::{
+    BookmarkEditField, BookmarkListItem, BookmarkListRow, DialogKind, DiskUsageItem, FuzzyListItem,
+    HistoryListItem, InputPurpose, MountPointItem, PanelSyncPhase, SpaceEstimate,
+};
 use crate::core::actions::generate_help_entries;
-use crate::models::operation::OperationProgress;
+use crate::models::jobs::Job;
+use crate::models::operation::{CopyOptions, OperationProgress, SymlinkPolicy};
+use crate::system::attributes::{self, FileAttributes};
+use crate::system::sync::{SyncAction, SyncItem};
+use crate::system::CompressionLevel;
+use crate::ui::components::path_input::{PathCompletion, SuggestionList};
 use crate::ui::{localize_runtime_text, I18n, Language, MessageKey, TextKey, Theme};
-use crate::utils::formatter::format_file_size;
-use crate::utils::path_display;
+use crate::utils::formatter::{self, format_file_size};
+use crate::utils::name_filter::split_regex_prefix;
+use crate::utils::{display_width, file_name, path_display};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Gauge, Paragraph, Widget, Wrap},
 };
 use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
 use unicode_width::UnicodeWidthStr;
 
 /// 다이얼로그 내부 좌우 패딩 (border 안쪽 여백)
 const DIALOG_H_PADDING: u16 = 2;
 /// 다이얼로그 내부 상단 패딩 (border 아래 여백)
 const DIALOG_V_PADDING: u16 = 1;
+/// 속성 다이얼로그에 한 번에 보이는 확장 속성 수
+const PROPERTIES_XATTR_ROWS: usize = 5;
 
 fn contains_case_insensitive(text: &str, needle: &str) -> bool {
     if needle.is_empty() {
@@ -25,6 +38,50 @@ fn contains_case_insensitive(text: &str, needle: &str) -> bool {
     text.to_lowercase().contains(&needle.to_lowercase())
 }
 
+/// 입력 필드 표시 범위 계산 (display width 기반)
+///
+/// 커서(와 커서 아래 문자)가 `max_width` 칸 안에 보이도록 스크롤한 뒤
+/// (시작 바이트, 끝 바이트, 커서 표시 열)을 반환합니다. 전각 문자가 경계에서
+/// 잘리지 않도록 문자 단위로 자릅니다.
+fn input_window(value: &str, cursor_pos: usize, max_width: usize) -> (usize, usize, usize) {
+    if max_width == 0 {
+        return (0, 0, 0);
+    }
+    let char_width = |c: char| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+    let cursor_col = UnicodeWidthStr::width(&value[..cursor_pos]);
+    let cursor_cells = value[cursor_pos..]
+        .chars()
+        .next()
+        .map(char_width)
+        .unwrap_or(1)
+        .max(1);
+    let min_start_col = (cursor_col + cursor_cells).saturating_sub(max_width);
+
+    let mut start_byte = 0;
+    let mut start_col = 0;
+    for (i, c) in value.char_indices() {
+        if start_col >= min_start_col {
+            start_byte = i;
+            break;
+        }
+        start_col += char_width(c);
+        start_byte = i + c.len_utf8();
+    }
+
+    let mut end_byte = start_byte;
+    let mut shown_width = 0;
+    for c in value[start_byte..].chars() {
+        let cw = char_width(c);
+        if shown_width + cw > max_width {
+            break;
+        }
+        shown_width += cw;
+        end_byte += c.len_utf8();
+    }
+
+    (start_byte, end_byte, cursor_col - start_col)
+}
+
 pub struct Dialog<'a> {
     kind: &'a DialogKind,
     bg_color: Color,
@@ -116,22 +173,38 @@ impl<'a> Dialog<'a> {
         let sh = screen.height;
 
         let (width, height) = match self.kind {
-            DialogKind::Input { .. } => {
+            DialogKind::Input {
+                scope_filter,
+                copy_options,
+                ..
+            } => {
                 let w = ((sw as f32 * 0.72) as u16).clamp(56, 110);
-                let h = 12u16;
+                let h =
+                    12u16 + u16::from(scope_filter.is_some()) + u16::from(copy_options.is_some());
                 (w, h)
             }
             DialogKind::ArchiveCreateOptions { .. } => {
                 let w = ((sw as f32 * 0.72) as u16).clamp(56, 110);
-                let h = 15u16;
+                let h = 17u16;
                 (w, h)
             }
-            DialogKind::MkdirInput { .. }
-            | DialogKind::RenameInput { .. }
+            DialogKind::MkdirInput { completion, .. } => {
+                // 추천이 있으면 제목 + 최대 5줄만큼 늘림
+                let rows = completion.candidates.len().min(5) as u16;
+                let h = if rows > 0 { 9 + rows } else { 8 };
+                (50u16.min(sw.saturating_sub(4)).max(30), h)
+            }
+            DialogKind::RenameInput { .. }
             | DialogKind::BookmarkRenameInput { .. }
             | DialogKind::FilterInput { .. } => (50u16.min(sw.saturating_sub(4)).max(30), 7u16),
-            DialogKind::Confirm { .. } => (40u16.min(sw.saturating_sub(4)).max(25), 8u16),
+            DialogKind::Confirm { message, .. } => {
+                let lines = message.lines().count().max(1) as u16;
+                let w = if lines > 1 { 64u16 } else { 40u16 };
+                let h = (6 + lines).min(sh.saturating_sub(4)).max(8);
+                (w.min(sw.saturating_sub(4)).max(25), h)
+            }
             DialogKind::Conflict { .. } => (55u16.min(sw.saturating_sub(4)).max(35), 15u16),
+            DialogKind::FileInUse { .. } => (60u16.min(sw.saturating_sub(4)).max(40), 10u16),
             DialogKind::Progress { .. } => (56u16.min(sw.saturating_sub(4)).max(36), 12u16),
             DialogKind::Error { message, .. } | DialogKind::Message { message, .. } => {
                 let lines = message.lines().count().max(1);
@@ -140,8 +213,14 @@ impl<'a> Dialog<'a> {
                 (w, h)
             }
             DialogKind::DeleteConfirm { items, .. } => {
-                let list_lines = items.len().min(10) as u16;
-                let w = 45u16.min(sw.saturating_sub(4)).max(30);
+                let list_lines = items.len().min(20) as u16;
+                let w = 72u16.min(sw.saturating_sub(4)).max(30);
+                let h = (7 + list_lines).min(sh.saturating_sub(4)).max(8);
+                (w, h)
+            }
+            DialogKind::QuitConfirm { jobs, .. } => {
+                let list_lines = jobs.len().min(10) as u16;
+                let w = 56u16.min(sw.saturating_sub(4)).max(30);
                 let h = (7 + list_lines).min(sh.saturating_sub(4)).max(8);
                 (w, h)
             }
@@ -150,38 +229,105 @@ impl<'a> Dialog<'a> {
                 let h = sh.saturating_sub(6).max(15);
                 (w, h)
             }
-            DialogKind::MountPoints { items, .. } => {
-                let list_lines = items.len().min(15) as u16;
-                let w = 50u16.min(sw.saturating_sub(4)).max(30);
+            DialogKind::MountPoints { list } => {
+                let list_lines = list.len().min(15) as u16;
+                let w = 76u16.min(sw.saturating_sub(4)).max(30);
                 let h = (4 + list_lines).min(sh.saturating_sub(4)).max(6);
                 (w, h)
             }
-            DialogKind::TabList { items, .. } => {
-                let list_lines = items.len().min(10) as u16;
+            DialogKind::TabList { list } => {
+                let list_lines = list.len().min(10) as u16;
                 let w = 45u16.min(sw.saturating_sub(4)).max(30);
                 let h = (4 + list_lines).min(sh.saturating_sub(4)).max(6);
                 (w, h)
             }
-            DialogKind::HistoryList { items, .. } => {
-                let list_lines = items.len().min(12) as u16;
+            DialogKind::HistoryList { list } => {
+                // 검색 중이면 검색 줄과 빈 줄 추가
+                let search_lines = if list.searching || !list.filter.is_empty() {
+                    2
+                } else {
+                    0
+                };
+                let list_lines = list.items.len().min(12) as u16 + search_lines;
+                let w = 70u16.min(sw.saturating_sub(4)).max(40);
+                let h = (4 + list_lines).min(sh.saturating_sub(4)).max(8);
+                (w, h)
+            }
+            DialogKind::BookmarkList { rows, .. } => {
+                let list_lines = rows.len().min(12) as u16;
+                let w = 80u16.min(sw.saturating_sub(4)).max(40);
+                let h = (4 + list_lines).min(sh.saturating_sub(4)).max(8);
+                (w, h)
+            }
+            DialogKind::SessionList { list } => {
+                let list_lines = list.len().min(12) as u16;
+                let w = 80u16.min(sw.saturating_sub(4)).max(40);
+                let h = (4 + list_lines).min(sh.saturating_sub(4)).max(8);
+                (w, h)
+            }
+            DialogKind::JobList { list } => {
+                let list_lines = list.len().min(12) as u16;
+                let w = 80u16.min(sw.saturating_sub(4)).max(40);
+                let h = (4 + list_lines).min(sh.saturating_sub(4)).max(8);
+                (w, h)
+            }
+            DialogKind::RemoteHostList { list } => {
+                let list_lines = list.len().min(12) as u16;
                 let w = 70u16.min(sw.saturating_sub(4)).max(40);
                 let h = (4 + list_lines).min(sh.saturating_sub(4)).max(8);
                 (w, h)
             }
-            DialogKind::BookmarkList { items, .. } => {
-                let list_lines = items.len().min(12) as u16;
+            DialogKind::ThemeList { list, .. } => {
+                let list_lines = list.len().min(12) as u16;
                 let w = 70u16.min(sw.saturating_sub(4)).max(40);
                 let h = (4 + list_lines).min(sh.saturating_sub(4)).max(8);
                 (w, h)
             }
-            DialogKind::ArchivePreviewList { items, .. } => {
-                let list_lines = items.len().min(16) as u16;
+            DialogKind::ArchivePreviewList { list, .. } => {
+                let list_lines = list.len().min(16) as u16;
                 let w = 90u16.min(sw.saturating_sub(4)).max(48);
                 let h = (5 + list_lines).min(sh.saturating_sub(4)).max(10);
                 (w, h)
             }
-            DialogKind::Properties { children_info, .. } => {
-                let base = if children_info.is_some() { 12u16 } else { 11 };
+            DialogKind::FuzzyList { items, loading, .. } => {
+                // 질의에 따라 크기가 바뀌지 않도록 전체 항목 수 기준 (색인 중이면 최대)
+                let list_lines = if *loading {
+                    15
+                } else {
+                    items.len().clamp(1, 15) as u16
+                };
+                let w = 80u16.min(sw.saturating_sub(4)).max(40);
+                let h = (4 + list_lines).min(sh.saturating_sub(4)).max(6);
+                (w, h)
+            }
+            DialogKind::TextViewer { .. } => {
+                let w = 100u16.min(sw.saturating_sub(4)).max(40);
+                let h = sh.saturating_sub(4).max(10);
+                (w, h)
+            }
+            DialogKind::FileCompare { .. } => (56u16.min(sw.saturating_sub(4)).max(36), 7u16),
+            DialogKind::DiskUsage { .. } | DialogKind::PanelSync { .. } => {
+                let w = 90u16.min(sw.saturating_sub(4)).max(48);
+                let h = 24u16.min(sh.saturating_sub(4)).max(10);
+                (w, h)
+            }
+            DialogKind::Properties {
+                children_info,
+                detected_type,
+                attributes,
+                xattrs,
+                ..
+            } => {
+                let xattr_rows = if xattrs.is_empty() {
+                    0
+                } else {
+                    1 + xattrs.len().min(PROPERTIES_XATTR_ROWS) as u16
+                };
+                let base = 11u16
+                    + u16::from(children_info.is_some())
+                    + u16::from(detected_type.is_some())
+                    + u16::from(attributes.is_some())
+                    + xattr_rows;
                 let w = 80u16.min(sw.saturating_sub(8)).max(40);
                 (w, base)
             }
@@ -232,7 +378,155 @@ impl<'a> Dialog<'a> {
         width
     }
 
-    /// 입력 다이얼로그 렌더링
+    /// 복사 옵션 3줄 (검증 / 메타데이터 유지 / 심볼릭 링크 처리)
+    fn render_copy_options(&self, buf: &mut Buffer, x: u16, y: u16, options: CopyOptions) {
+        let label_style = Style::default().fg(self.fg_color).bg(self.bg_color);
+        let checkbox = |checked: bool, label: &str| {
+            let mark = if checked { "[x]" } else { "[ ]" };
+            let style = if checked {
+                label_style
+            } else {
+                Style::default()
+                    .fg(self.border_color)
+                    .bg(self.bg_color)
+                    .add_modifier(Modifier::DIM)
+            };
+            Span::styled(format!("{} {}", mark, label), style)
+        };
+
+        let verify = Line::from(vec![
+            checkbox(options.verify, self.i18n().tr(TextKey::DialogCopyVerify)),
+            Span::styled("  ", label_style),
+            checkbox(
+                options.update_only,
+                self.i18n().tr(TextKey::DialogCopyUpdateOnly),
+            ),
+        ]);
+        buf.set_line(x, y, &verify, u16::MAX);
+
+        let preserve = Line::from(vec![
+            Span::styled(
+                format!("{} ", self.i18n().tr(TextKey::DialogCopyPreserve)),
+                label_style,
+            ),
+            checkbox(
+                options.preserve_timestamps,
+                self.i18n().tr(TextKey::DialogCopyPreserveTimestamps),
+            ),
+            Span::styled("  ", label_style),
+            checkbox(
+                options.preserve_permissions,
+                self.i18n().tr(TextKey::DialogCopyPreservePermissions),
+            ),
+            Span::styled("  ", label_style),
+            checkbox(
+                options.preserve_xattrs,
+                self.i18n().tr(TextKey::DialogCopyPreserveXattrs),
+            ),
+        ]);
+        buf.set_line(x, y + 1, &preserve, u16::MAX);
+
+        let symlinks = self.i18n().tr(match options.symlinks {
+            SymlinkPolicy::Links => TextKey::DialogCopySymlinksLinks,
+            SymlinkPolicy::Follow => TextKey::DialogCopySymlinksFollow,
+            SymlinkPolicy::Skip => TextKey::DialogCopySymlinksSkip,
+        });
+        let symlinks = Line::from(vec![
+            Span::styled(
+                format!("{} ", self.i18n().tr(TextKey::DialogCopySymlinks)),
+                label_style,
+            ),
+            Span::styled(symlinks, label_style.add_modifier(Modifier::BOLD)),
+        ]);
+        buf.set_line(x, y + 2, &symlinks, u16::MAX);
+    }
+
+    /// 새 디렉토리: 날짜 자리표시자/중간 경로를 펼친 경로와 만든 뒤 들어가기 체크박스 (입력 필드 아래)
+    /// 경로 자동완성 추천 목록 (다이얼로그 색상 적용)
+    fn suggestion_list<'b>(
+        &self,
+        completion: &'b PathCompletion,
+        title: &'b str,
+    ) -> SuggestionList<'b> {
+        SuggestionList::new(completion, title)
+            .colors(self.fg_color, self.bg_color, self.border_color)
+            .selected_colors(self.button_selected_fg, self.button_selected_bg)
+    }
+
+    fn render_mkdir_options(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        value: &str,
+        enter_created: bool,
+        completion: &PathCompletion,
+    ) {
+        let x = area.x + DIALOG_H_PADDING;
+        let y = area.y + DIALOG_V_PADDING + 2;
+        let width = area.width.saturating_sub(DIALOG_H_PADDING * 2);
+        let muted = Style::default()
+            .fg(self.border_color)
+            .bg(self.bg_color)
+            .add_modifier(Modifier::DIM);
+        let expanded =
+            file_name::expand_date_template(value.trim(), &chrono::Local::now().naive_local());
+        if expanded != value.trim() || value.contains(std::path::is_separator) {
+            let label = format!("{} ", self.i18n().tr(TextKey::DialogMkdirCreates));
+            let text_width = (width as usize).saturating_sub(label.chars().count());
+            let line = Line::from(vec![
+                Span::styled(label, muted),
+                Span::styled(
+                    path_display::truncate_middle(&expanded, text_width),
+                    Style::default().fg(self.fg_color).bg(self.bg_color),
+                ),
+            ]);
+            buf.set_line(x, y, &line, width);
+        }
+
+        let mark = if enter_created { "[x]" } else { "[ ]" };
+        let text = format!("{} {}", mark, self.i18n().tr(TextKey::DialogMkdirEnter));
+        let style = if enter_created {
+            Style::default().fg(self.fg_color).bg(self.bg_color)
+        } else {
+            muted
+        };
+        buf.set_stringn(x, y + 1, text, width as usize, style);
+
+        // 자동완성 목록 (체크박스 아래 ~ 버튼 위)
+        let button_y = area.y + area.height.saturating_sub(2);
+        let title = self.i18n().tr(TextKey::DialogSuggestions);
+        self.suggestion_list(completion, title).render(
+            Rect {
+                x,
+                y: y + 2,
+                width,
+                height: button_y.saturating_sub(y + 3),
+            },
+            buf,
+        );
+    }
+
+    /// 필터 다이얼로그 정규식 모드 체크박스 (입력 필드 아래)
+    fn render_filter_regex_checkbox(&self, buf: &mut Buffer, area: Rect, value: &str) {
+        let (regex_mode, _) = split_regex_prefix(value);
+        let mark = if regex_mode { "[x]" } else { "[ ]" };
+        let text = format!("{} {}", mark, self.i18n().tr(TextKey::DialogFilterRegex));
+        let style = if regex_mode {
+            Style::default().fg(self.fg_color).bg(self.bg_color)
+        } else {
+            Style::default()
+                .fg(self.border_color)
+                .bg(self.bg_color)
+                .add_modifier(Modifier::DIM)
+        };
+        buf.set_string(
+            area.x + DIALOG_H_PADDING,
+            area.y + DIALOG_V_PADDING + 2,
+            text,
+            style,
+        );
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn render_input(
         &self,
@@ -242,12 +536,15 @@ impl<'a> Dialog<'a> {
         prompt: &str,
         value: &str,
         purpose: InputPurpose,
-        completion_candidates: &[String],
-        completion_index: Option<usize>,
+        completion: &PathCompletion,
         cursor_pos: usize,
         selected_button: usize,
         show_suggestions_panel: bool,
         mask_input: bool,
+        copy_options: Option<CopyOptions>,
+        show_preview: bool,
+        scope_filter: Option<&str>,
+        free_space: Option<SpaceEstimate>,
     ) {
         let (title_text, prompt_text) = match purpose {
             InputPurpose::GoToPath => (
@@ -278,6 +575,17 @@ impl<'a> Dialog<'a> {
                 self.i18n().tr(TextKey::DialogTitleRunCommand).to_string(),
                 self.i18n().tr(TextKey::DialogPromptCommand).to_string(),
             ),
+            InputPurpose::Prompt(input) => {
+                let title_text = self.i18n().tr(input.title);
+                (
+                    if title.is_empty() {
+                        title_text.to_string()
+                    } else {
+                        format!("{} - {}", title_text, title)
+                    },
+                    self.i18n().tr(input.prompt).to_string(),
+                )
+            }
             InputPurpose::OperationDestination => (
                 localize_runtime_text(self.language, title),
                 localize_runtime_text(self.language, prompt),
@@ -330,121 +638,50 @@ impl<'a> Dialog<'a> {
         } else {
             cursor_pos
         };
-        let value_display_width = UnicodeWidthStr::width(visible_value.as_str());
 
-        // 스크롤 처리: 커서가 보이도록 표시 시작점 결정
-        let (display_value, cursor_display_col) = if value_display_width <= max_display {
-            // 전체 표시 가능
-            let cursor_col: usize = visible_value[..visible_cursor_pos]
-                .chars()
-                .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0))
-                .sum();
-            (visible_value.as_str(), cursor_col)
-        } else {
-            // 스크롤 필요: 커서 위치를 기준으로 표시 범위 계산
-            let cursor_col_from_start: usize = visible_value[..visible_cursor_pos]
-                .chars()
-                .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0))
-                .sum();
-
-            if cursor_col_from_start < max_display {
-                // 커서가 앞쪽이면 앞에서부터 표시
-                (visible_value.as_str(), cursor_col_from_start)
-            } else {
-                // 커서가 화면 밖이면 커서가 오른쪽 끝에 오도록 스크롤
-                let mut start_byte = 0;
-                let mut width_sum = 0;
-                // 뒤에서부터 max_display만큼의 너비를 찾음
-                let target_start_width = cursor_col_from_start.saturating_sub(max_display - 1);
-                for (i, c) in visible_value.char_indices() {
-                    let cw = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-                    if width_sum >= target_start_width {
-                        start_byte = i;
-                        break;
-                    }
-                    width_sum += cw;
-                }
-                let display = &visible_value[start_byte..];
-                let cursor_col = cursor_col_from_start - width_sum;
-                (display, cursor_col)
-            }
-        };
+        // 스크롤 처리: 커서가 보이도록 표시 범위 결정
+        let (start_byte, end_byte, cursor_display_col) =
+            input_window(&visible_value, visible_cursor_pos, max_display);
         let value_style = Style::default().fg(self.fg_color).bg(self.input_bg);
-        buf.set_string(inner.x + 1, input_y, display_value, value_style);
+        buf.set_string(
+            inner.x + 1,
+            input_y,
+            &visible_value[start_byte..end_byte],
+            value_style,
+        );
 
         // 커서 표시
-        let cursor_x = inner.x + 1 + cursor_display_col as u16;
-        if cursor_x < inner.x + input_width - 1 {
-            if let Some(cell) = buf.cell_mut((cursor_x, input_y)) {
-                if cursor_pos < value.len() {
-                    // 문자가 있는 위치: 반전 스타일
-                    cell.set_style(Style::default().fg(self.input_bg).bg(self.fg_color));
-                } else {
-                    // 문자열 끝: 블록 커서 문자 표시
-                    cell.set_char('▏');
-                    cell.set_style(Style::default().fg(self.fg_color).bg(self.input_bg));
-                }
-            }
-        }
+        self.render_input_cursor(
+            buf,
+            inner.x + 1 + cursor_display_col as u16,
+            input_y,
+            inner.x + input_width - 1,
+            visible_value[visible_cursor_pos..].chars().next(),
+        );
 
         // 자동완성 목록 (표시 가능한 높이만 렌더, 선택 항목 기준 스크롤)
         if show_suggestions_panel && !mask_input && inner.height >= 5 {
             let title_y = inner.y + 2;
-            let list_y = inner.y + 3;
             let button_y = area.y + area.height.saturating_sub(2);
             let show_hint = purpose == InputPurpose::GoToPath;
-            let list_bottom_y = if show_hint {
+            let list_bottom_y = if copy_options.is_some() {
+                button_y.saturating_sub(3)
+            } else if show_hint {
                 button_y.saturating_sub(1)
             } else {
                 button_y
-            };
-            let visible_rows = list_bottom_y.saturating_sub(list_y) as usize;
-            let total_candidates = completion_candidates.len();
-            if total_candidates > 0 {
-                let selected = completion_index.unwrap_or(0).min(total_candidates - 1);
-                let selected_display = selected + 1;
-
-                let title_style = Style::default()
-                    .fg(self.border_color)
-                    .bg(self.bg_color)
-                    .add_modifier(Modifier::DIM);
-                let title = format!(
-                    "{} ({}/{})",
-                    self.i18n().tr(TextKey::DialogSuggestions),
-                    selected_display,
-                    total_candidates
-                );
-                buf.set_string(inner.x, title_y, title, title_style);
-
-                if visible_rows > 0 {
-                    let mut scroll = 0usize;
-                    if selected >= visible_rows {
-                        scroll = selected + 1 - visible_rows;
-                    }
-
-                    for (row, candidate) in completion_candidates
-                        .iter()
-                        .enumerate()
-                        .skip(scroll)
-                        .take(visible_rows)
-                    {
-                        let y = list_y + (row - scroll) as u16;
-                        let marker = if row == selected { "> " } else { "  " };
-                        let content_width =
-                            inner.width.saturating_sub(marker.width() as u16) as usize;
-                        let text = path_display::truncate_middle(candidate, content_width);
-                        let line = format!("{}{}", marker, text);
-                        let style = if row == selected {
-                            Style::default()
-                                .fg(self.button_selected_fg)
-                                .bg(self.button_selected_bg)
-                        } else {
-                            Style::default().fg(self.fg_color).bg(self.bg_color)
-                        };
-                        buf.set_string(inner.x, y, line, style);
-                    }
-                }
             }
+            .saturating_sub(u16::from(scope_filter.is_some()));
+            let title = self.i18n().tr(TextKey::DialogSuggestions);
+            self.suggestion_list(completion, title).render(
+                Rect {
+                    x: inner.x,
+                    y: title_y,
+                    width: inner.width,
+                    height: list_bottom_y.saturating_sub(title_y),
+                },
+                buf,
+            );
 
             if show_hint {
                 let hint = self.i18n().tr(TextKey::DialogSuggestionHint);
@@ -462,6 +699,45 @@ impl<'a> Dialog<'a> {
             }
         }
 
+        // 범위 필터 (복사 옵션 바로 위, 없으면 버튼 위 1줄)
+        if let Some(filter) = scope_filter {
+            let filter_y = area.y
+                + area
+                    .height
+                    .saturating_sub(if copy_options.is_some() { 6 } else { 3 });
+            let label = self.i18n().tr(TextKey::DialogScopeFilter);
+            let (text, style) = if filter.trim().is_empty() {
+                (
+                    self.i18n().tr(TextKey::DialogScopeFilterAll),
+                    Style::default()
+                        .fg(self.border_color)
+                        .bg(self.bg_color)
+                        .add_modifier(Modifier::DIM),
+                )
+            } else {
+                (filter, Style::default().fg(self.fg_color).bg(self.bg_color))
+            };
+            let text_width = inner.width.saturating_sub(label.width() as u16 + 1) as usize;
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{} ", label),
+                    Style::default().fg(self.fg_color).bg(self.bg_color),
+                ),
+                Span::styled(path_display::truncate_middle(text, text_width), style),
+            ]);
+            buf.set_line(inner.x, filter_y, &line, inner.width);
+        }
+
+        // 복사 옵션 체크박스 (버튼 위 3줄)
+        if let Some(options) = copy_options {
+            self.render_copy_options(
+                buf,
+                inner.x,
+                area.y + area.height.saturating_sub(5),
+                options,
+            );
+        }
+
         // 버튼
         let button_y = area.y + area.height.saturating_sub(2);
         let ok_width = self.render_button(
@@ -471,13 +747,54 @@ impl<'a> Dialog<'a> {
             self.i18n().tr(TextKey::Ok),
             selected_button == 0,
         );
-        self.render_button(
+        let cancel_width = self.render_button(
             buf,
             inner.x + ok_width + 2,
             button_y,
             self.i18n().tr(TextKey::Cancel),
             selected_button == 1,
         );
+        let mut buttons_end = inner.x + ok_width + cancel_width + 2;
+        if show_preview {
+            buttons_end += 2 + self.render_button(
+                buf,
+                inner.x + ok_width + cancel_width + 4,
+                button_y,
+                self.i18n().tr(TextKey::Preview),
+                selected_button == 2,
+            );
+        }
+
+        // 필요한 공간/여유 공간 (버튼 줄 오른쪽, 자리가 모자라면 생략)
+        if let Some(estimate) = free_space {
+            let mut need = format_file_size(estimate.needed);
+            if estimate.partial {
+                need.push('+');
+            }
+            let text = match estimate.available {
+                Some(available) => self.i18n().fmt(
+                    MessageKey::DialogFreeSpace,
+                    &[("need", need), ("free", format_file_size(available))],
+                ),
+                None => self
+                    .i18n()
+                    .fmt(MessageKey::DialogSpaceNeeded, &[("need", need)]),
+            };
+            let text_x = (inner.x + inner.width).saturating_sub(text.width() as u16);
+            if text_x >= buttons_end + 2 {
+                let color = if estimate.is_short() {
+                    self.warning_color
+                } else {
+                    self.muted_color
+                };
+                buf.set_string(
+                    text_x,
+                    button_y,
+                    text,
+                    Style::default().fg(color).bg(self.bg_color),
+                );
+            }
+        }
     }
 
     /// 확인 다이얼로그 렌더링
@@ -550,10 +867,16 @@ impl<'a> Dialog<'a> {
         source: &Path,
         dest: &Path,
         selected_option: usize,
+        directory: bool,
     ) {
         // 테두리
+        let title = if directory {
+            TextKey::DialogTitleDirectoryExists
+        } else {
+            TextKey::DialogTitleFileExists
+        };
         let block = Block::default()
-            .title(self.i18n().tr(TextKey::DialogTitleFileExists))
+            .title(self.i18n().tr(title))
             .title_style(
                 Style::default()
                     .fg(self.warning_color)
@@ -589,26 +912,36 @@ impl<'a> Dialog<'a> {
         buf.set_string(inner.x + 8, inner.y, source_name, path_style);
 
         // 대상 경로 표시
-        buf.set_string(
+        let target_text = if directory {
+            TextKey::DialogTargetDirectoryExists
+        } else {
+            TextKey::DialogTargetExists
+        };
+        buf.set_stringn(
             inner.x,
             inner.y + 2,
-            self.i18n().tr(TextKey::DialogTargetExists),
+            self.i18n().tr(target_text),
+            inner.width as usize,
             msg_style,
         );
         let truncated_path = path_display::truncate_path_buf(dest, inner.width as usize);
         buf.set_string(inner.x, inner.y + 3, &truncated_path, path_style);
 
         // 옵션 버튼들 (2줄로 배치)
-        // 첫 번째 줄: Overwrite, Skip
-        let row1_options = [
-            self.i18n().tr(TextKey::DialogOverwrite),
-            self.i18n().tr(TextKey::DialogSkip),
+        // 첫 번째 줄: Overwrite, Skip (디렉토리 충돌: Merge, Replace, Skip)
+        let mut row1_options = vec![
+            (0, self.i18n().tr(TextKey::DialogOverwrite)),
+            (1, self.i18n().tr(TextKey::DialogSkip)),
         ];
+        if directory {
+            row1_options[0].1 = self.i18n().tr(TextKey::DialogReplace);
+            row1_options.insert(0, (5, self.i18n().tr(TextKey::DialogMerge)));
+        }
         let button_y1 = inner.y + 6;
         let mut x = inner.x;
 
-        for (i, option) in row1_options.iter().enumerate() {
-            let width = self.render_button(buf, x, button_y1, option, selected_option == i);
+        for (index, option) in row1_options {
+            let width = self.render_button(buf, x, button_y1, option, selected_option == index);
             x += width + 1;
         }
 
@@ -627,19 +960,97 @@ impl<'a> Dialog<'a> {
         }
     }
 
+    /// 사용 중인 파일 다이얼로그 렌더링 (다시 시도/건너뛰기/모두 건너뛰기/취소)
+    fn render_file_in_use(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        path: &Path,
+        error: &str,
+        selected_button: usize,
+    ) {
+        let block = Block::default()
+            .title(self.i18n().tr(TextKey::DialogTitleFileInUse))
+            .title_style(
+                Style::default()
+                    .fg(self.warning_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.warning_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(DIALOG_V_PADDING * 2),
+        };
+
+        buf.set_stringn(
+            inner.x,
+            inner.y,
+            self.i18n().tr(TextKey::DialogFileInUse),
+            inner.width as usize,
+            Style::default().fg(self.fg_color),
+        );
+        let truncated_path = path_display::truncate_path_buf(path, inner.width as usize);
+        buf.set_string(
+            inner.x,
+            inner.y + 1,
+            &truncated_path,
+            Style::default().fg(self.title_color),
+        );
+        buf.set_stringn(
+            inner.x,
+            inner.y + 3,
+            error,
+            inner.width as usize,
+            Style::default().fg(self.muted_color),
+        );
+
+        let button_y = area.y + area.height - 2;
+        let mut x = inner.x;
+        let labels = [
+            TextKey::DialogRetry,
+            TextKey::DialogSkip,
+            TextKey::DialogSkipAll,
+            TextKey::Cancel,
+        ];
+        for (index, label) in labels.into_iter().enumerate() {
+            let width = self.render_button(
+                buf,
+                x,
+                button_y,
+                self.i18n().tr(label),
+                selected_button == index,
+            );
+            x += width + 1;
+        }
+    }
+
     /// 진행률 다이얼로그 렌더링
     fn render_progress(&self, buf: &mut Buffer, area: Rect, progress: &OperationProgress) {
         let operation_name = localize_runtime_text(self.language, progress.operation_type.name());
-        let title = format!(" {} ", operation_name);
+        let title_style = Style::default()
+            .fg(self.title_color)
+            .add_modifier(Modifier::BOLD);
+        let mut title = vec![Span::styled(format!(" {} ", operation_name), title_style)];
+        if progress.is_paused() {
+            // 일시정지 배지
+            title.push(Span::styled(
+                format!(" {} ", self.i18n().tr(TextKey::ProgressPausedBadge)),
+                Style::default()
+                    .fg(self.bg_color)
+                    .bg(self.warning_color)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
 
         // 테두리
         let block = Block::default()
-            .title(title)
-            .title_style(
-                Style::default()
-                    .fg(self.title_color)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .title(Line::from(title))
             .borders(Borders::ALL)
             .border_style(Style::default().fg(self.border_color))
             .style(Style::default().bg(self.bg_color));
@@ -657,23 +1068,35 @@ impl<'a> Dialog<'a> {
         let truncated = path_display::truncate_middle(&progress.current_file, inner.width as usize);
         buf.set_string(inner.x, inner.y, &truncated, file_style);
 
-        // 진행률 바
-        let gauge_area = Rect {
-            x: inner.x,
-            y: inner.y + 2,
-            width: inner.width,
-            height: 1,
+        // 진행률 바: 현재 파일 / 전체
+        let file_label = if progress.current_file_total > 0 {
+            format!(
+                "{} {}%  {} / {}",
+                self.i18n().tr(TextKey::ProgressFile),
+                progress.file_percentage(),
+                format_file_size(progress.current_file_bytes),
+                format_file_size(progress.current_file_total)
+            )
+        } else {
+            self.i18n().tr(TextKey::ProgressFile).to_string()
         };
+        self.render_progress_gauge(
+            buf,
+            inner.x,
+            inner.y + 1,
+            inner.width,
+            progress.file_percentage(),
+            file_label,
+        );
         let percent = progress.percentage();
-        let gauge = Gauge::default()
-            .ratio(percent as f64 / 100.0)
-            .gauge_style(
-                Style::default()
-                    .fg(self.progress_filled)
-                    .bg(self.progress_unfilled),
-            )
-            .label(format!("{}%", percent));
-        gauge.render(gauge_area, buf);
+        self.render_progress_gauge(
+            buf,
+            inner.x,
+            inner.y + 2,
+            inner.width,
+            percent,
+            format!("{} {}%", self.i18n().tr(TextKey::ProgressTotal), percent),
+        );
 
         // 파일 카운트
         let count_text = self.i18n().fmt(
@@ -706,34 +1129,156 @@ impl<'a> Dialog<'a> {
         );
         buf.set_string(inner.x, inner.y + 6, &processed_text, file_style);
 
-        // 속도 / ETA
-        let speed_eta = format!(
-            "{}  {}: {}",
-            progress.format_speed(),
-            self.i18n().tr(TextKey::DialogEta),
-            progress.format_eta()
-        );
-        let speed_style = Style::default().fg(self.success_color);
-        buf.set_string(inner.x, inner.y + 7, &speed_eta, speed_style);
+        // 속도 / ETA (일시정지 중에는 상태 표시)
+        if progress.is_paused() {
+            let paused_style = Style::default()
+                .fg(self.warning_color)
+                .add_modifier(Modifier::BOLD);
+            buf.set_string(
+                inner.x,
+                inner.y + 7,
+                self.i18n().tr(TextKey::ProgressPaused),
+                paused_style,
+            );
+        } else {
+            let speed_eta = format!(
+                "{}  {}: {}",
+                localize_runtime_text(self.language, &progress.format_speed()),
+                self.i18n().tr(TextKey::DialogEta),
+                progress.format_eta()
+            );
+            let speed_style = Style::default().fg(self.success_color);
+            buf.set_string(inner.x, inner.y + 7, &speed_eta, speed_style);
+        }
 
-        // Esc 안내
+        // 키 안내
+        let hint = if progress.is_paused() {
+            self.i18n().tr(TextKey::DialogProgressHintResume)
+        } else {
+            self.i18n().tr(TextKey::DialogProgressHintPause)
+        };
         let hint_style = Style::default().fg(self.muted_color);
-        buf.set_string(
-            inner.x,
-            inner.y + 9,
-            self.i18n().tr(TextKey::DialogPressEscToCancel),
-            hint_style,
-        );
+        buf.set_string(inner.x, inner.y + 9, hint, hint_style);
     }
 
-    /// 삭제 확인 다이얼로그 렌더링
-    fn render_delete_confirm(
+    /// `ext4  [██████░░░░]  62%  310.0G/500.0G ` 형식의 사용량 표시
+    fn mount_point_usage_text(item: &MountPointItem) -> String {
+        const BAR_WIDTH: usize = 10;
+        let fs_type = item.fs_type.as_deref().unwrap_or("");
+        if item.total == 0 {
+            return format!(" {:<6} ", fs_type);
+        }
+        let ratio = item.used as f64 / item.total as f64;
+        let filled = ((ratio * BAR_WIDTH as f64).round() as usize).min(BAR_WIDTH);
+        format!(
+            " {:<6} [{}{}] {:>3}% {:>15} ",
+            fs_type,
+            "█".repeat(filled),
+            "░".repeat(BAR_WIDTH - filled),
+            (ratio * 100.0).round() as u32,
+            format!(
+                "{}/{}",
+                format_file_size(item.used),
+                format_file_size(item.total)
+            ),
+        )
+    }
+
+    fn render_progress_gauge(
         &self,
         buf: &mut Buffer,
-        area: Rect,
-        items: &[String],
-        total_size: &str,
-        selected_button: usize,
+        x: u16,
+        y: u16,
+        width: u16,
+        percent: u8,
+        label: String,
+    ) {
+        let gauge_area = Rect {
+            x,
+            y,
+            width,
+            height: 1,
+        };
+        Gauge::default()
+            .ratio(percent.min(100) as f64 / 100.0)
+            .gauge_style(
+                Style::default()
+                    .fg(self.progress_filled)
+                    .bg(self.progress_unfilled),
+            )
+            .label(label)
+            .render(gauge_area, buf);
+    }
+
+    /// 파일 비교 진행 다이얼로그 렌더링
+    fn render_file_compare(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        name: &str,
+        checked: u64,
+        total: u64,
+    ) {
+        let title = format!(" {} ", localize_runtime_text(self.language, "Compare"));
+        let block = Block::default()
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(DIALOG_V_PADDING * 2),
+        };
+        let truncated = path_display::truncate_middle(name, inner.width as usize);
+        buf.set_string(
+            inner.x,
+            inner.y,
+            &truncated,
+            Style::default().fg(self.fg_color),
+        );
+
+        let percent = checked
+            .saturating_mul(100)
+            .checked_div(total)
+            .map_or(0, |percent| percent.min(100) as u8);
+        self.render_progress_gauge(
+            buf,
+            inner.x,
+            inner.y + 1,
+            inner.width,
+            percent,
+            format!(
+                "{}%  {} / {}",
+                percent,
+                format_file_size(checked),
+                format_file_size(total)
+            ),
+        );
+        buf.set_string(
+            inner.x,
+            inner.y + 3,
+            self.i18n().tr(TextKey::DialogPressEscToCancel),
+            Style::default().fg(self.muted_color),
+        );
+    }
+
+    /// 삭제 확인 다이얼로그 렌더링
+    fn render_delete_confirm(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        items: &[String],
+        total_size: &str,
+        selected_button: usize,
     ) {
         // 테두리
         let block = Block::default()
@@ -820,6 +1365,76 @@ impl<'a> Dialog<'a> {
         );
     }
 
+    /// 작업 중 종료 확인 다이얼로그 렌더링
+    fn render_quit_confirm(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        jobs: &[String],
+        selected_button: usize,
+    ) {
+        let block = Block::default()
+            .title(self.i18n().tr(TextKey::DialogTitleQuit))
+            .title_style(
+                Style::default()
+                    .fg(self.warning_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.warning_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(DIALOG_V_PADDING * 2),
+        };
+
+        let header_style = Style::default()
+            .fg(self.fg_color)
+            .add_modifier(Modifier::BOLD);
+        buf.set_string(
+            inner.x,
+            inner.y,
+            self.i18n().tr(TextKey::DialogQuitRunning),
+            header_style,
+        );
+
+        let item_style = Style::default().fg(self.title_color);
+        let max_items = (inner.height.saturating_sub(4)) as usize; // 헤더 + 빈줄 + 버튼줄 + 빈줄
+        for (i, job) in jobs.iter().take(max_items).enumerate() {
+            let line = format!("  · {}", job);
+            buf.set_stringn(
+                inner.x,
+                inner.y + 2 + i as u16,
+                &line,
+                inner.width as usize,
+                item_style,
+            );
+        }
+
+        // 버튼 (하단)
+        let button_y = area.y + area.height - 2;
+        let mut x = inner.x;
+        let labels = [
+            TextKey::DialogWaitAndQuit,
+            TextKey::DialogCancelJobsAndQuit,
+            TextKey::Cancel,
+        ];
+        for (index, label) in labels.into_iter().enumerate() {
+            let width = self.render_button(
+                buf,
+                x,
+                button_y,
+                self.i18n().tr(label),
+                selected_button == index,
+            );
+            x += width + 1;
+        }
+    }
+
     /// 텍스트 필드 렌더링 헬퍼 (cursor_pos는 바이트 인덱스)
     fn render_text_field(
         &self,
@@ -837,27 +1452,58 @@ impl<'a> Dialog<'a> {
             }
         }
 
-        // 입력값 표시
+        // 입력값 표시 (포커스 없는 필드는 앞에서부터 표시)
+        let max_display = width.saturating_sub(2) as usize;
+        let (start_byte, end_byte, cursor_col) =
+            input_window(value, cursor_pos.unwrap_or(0), max_display);
         let value_style = Style::default().fg(self.fg_color).bg(self.input_bg);
-        buf.set_string(x + 1, y, value, value_style);
+        buf.set_string(x + 1, y, &value[start_byte..end_byte], value_style);
 
         // 커서 표시
         if let Some(cpos) = cursor_pos {
-            let cursor_col: usize = value[..cpos]
-                .chars()
-                .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0))
-                .sum();
-            let cursor_x = x + 1 + cursor_col as u16;
-            if cursor_x < x + width - 1 {
-                if let Some(cell) = buf.cell_mut((cursor_x, y)) {
-                    if cpos < value.len() {
-                        cell.set_style(Style::default().fg(self.input_bg).bg(self.fg_color));
-                    } else {
-                        cell.set_char('▏');
-                        cell.set_style(Style::default().fg(self.fg_color).bg(self.input_bg));
+            self.render_input_cursor(
+                buf,
+                x + 1 + cursor_col as u16,
+                y,
+                x + width - 1,
+                value[cpos..].chars().next(),
+            );
+        }
+    }
+
+    /// 입력 커서 렌더링
+    ///
+    /// 커서 아래 문자가 있으면 반전 스타일(전각 문자는 두 칸 모두), 문자열 끝이면 `▏` 표시.
+    /// `limit_x`는 입력 필드 오른쪽 끝(제외)입니다.
+    fn render_input_cursor(
+        &self,
+        buf: &mut Buffer,
+        cursor_x: u16,
+        y: u16,
+        limit_x: u16,
+        under_cursor: Option<char>,
+    ) {
+        if cursor_x >= limit_x {
+            return;
+        }
+        match under_cursor {
+            Some(c) => {
+                let cells = unicode_width::UnicodeWidthChar::width(c)
+                    .unwrap_or(1)
+                    .max(1) as u16;
+                let style = Style::default().fg(self.input_bg).bg(self.fg_color);
+                for cx in cursor_x..(cursor_x + cells).min(limit_x) {
+                    if let Some(cell) = buf.cell_mut((cx, y)) {
+                        cell.set_style(style);
                     }
                 }
             }
+            None => {
+                if let Some(cell) = buf.cell_mut((cursor_x, y)) {
+                    cell.set_char('▏');
+                    cell.set_style(Style::default().fg(self.fg_color).bg(self.input_bg));
+                }
+            }
         }
     }
 
@@ -874,6 +1520,11 @@ impl<'a> Dialog<'a> {
         modified: &str,
         permissions: &str,
         children_info: &Option<String>,
+        detected_type: Option<&str>,
+        attributes: Option<&FileAttributes>,
+        targets: usize,
+        xattrs: &[(String, String)],
+        xattr_index: usize,
     ) {
         let localized_file_type = localize_runtime_text(self.language, file_type);
         let localized_size = localize_runtime_text(self.language, size);
@@ -907,14 +1558,22 @@ impl<'a> Dialog<'a> {
         let mut y = inner.y;
         let label_width = 12u16;
 
-        let rows: Vec<(&str, &str)> = vec![
+        let mut rows: Vec<(&str, &str)> = vec![
             (self.i18n().tr(TextKey::DialogName), name),
             (self.i18n().tr(TextKey::DialogPath), path),
-            (self.i18n().tr(TextKey::DialogType), &localized_file_type),
-            (self.i18n().tr(TextKey::DialogSize), &localized_size),
+            (
+                self.i18n().tr(TextKey::DialogType),
+                localized_file_type.as_str(),
+            ),
+        ];
+        if let Some(detected) = detected_type {
+            rows.push((self.i18n().tr(TextKey::DialogDetectedType), detected));
+        }
+        rows.extend([
+            (self.i18n().tr(TextKey::DialogSize), localized_size.as_str()),
             (self.i18n().tr(TextKey::DialogModified), modified),
             (self.i18n().tr(TextKey::DialogPermissions), permissions),
-        ];
+        ]);
 
         for (label, value) in &rows {
             buf.set_string(inner.x, y, label, label_style);
@@ -934,6 +1593,100 @@ impl<'a> Dialog<'a> {
                 label_style,
             );
             buf.set_string(inner.x + label_width, y, info, value_style);
+            y += 1;
+        }
+
+        if let Some(attributes) = attributes {
+            let mark = |on: bool| if on { "[x]" } else { "[ ]" };
+            let mut value = format!(
+                "{} {}  {} {}",
+                mark(attributes.readonly),
+                self.i18n().tr(TextKey::DialogAttrReadOnly),
+                mark(attributes.hidden),
+                self.i18n().tr(TextKey::DialogAttrHidden),
+            );
+            if let Some(system) = attributes.system {
+                value.push_str(&format!(
+                    "  {} {}",
+                    mark(system),
+                    self.i18n().tr(TextKey::DialogAttrSystem)
+                ));
+            }
+            if targets > 1 {
+                value.push(' ');
+                value.push_str(&self.i18n().fmt(
+                    MessageKey::AttributeTargets,
+                    &[("count", targets.to_string())],
+                ));
+            }
+            buf.set_string(
+                inner.x,
+                y,
+                self.i18n().tr(TextKey::DialogAttributes),
+                label_style,
+            );
+            buf.set_string(inner.x + label_width, y, &value, value_style);
+
+            // 하단 힌트
+            let hint = self.i18n().tr(if attributes.system.is_some() {
+                TextKey::DialogHintPropertiesWindows
+            } else {
+                TextKey::DialogHintProperties
+            });
+            let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+            buf.set_string(
+                hint_x,
+                area.y + area.height - 1,
+                hint,
+                Style::default().fg(self.muted_color),
+            );
+            y += 1;
+        }
+
+        if !xattrs.is_empty() {
+            buf.set_string(
+                inner.x,
+                y,
+                self.i18n().tr(TextKey::DialogXattrs),
+                label_style,
+            );
+            let quarantined = cfg!(target_os = "macos")
+                && xattrs
+                    .iter()
+                    .any(|(name, _)| name == attributes::QUARANTINE_XATTR);
+            let hint = format!(
+                "{}  {}",
+                xattrs.len(),
+                self.i18n().tr(if quarantined {
+                    TextKey::DialogHintXattrsQuarantine
+                } else {
+                    TextKey::DialogHintXattrs
+                })
+            );
+            buf.set_string(inner.x + label_width, y, &hint, label_style);
+            y += 1;
+
+            let selected_style = Style::default()
+                .fg(self.button_selected_fg)
+                .bg(self.button_selected_bg);
+            let start = (xattr_index + 1).saturating_sub(PROPERTIES_XATTR_ROWS);
+            let row_width = inner.width.saturating_sub(2) as usize;
+            for (offset, (name, value)) in xattrs
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(PROPERTIES_XATTR_ROWS)
+            {
+                let line =
+                    display_width::truncate_end(&format!("{} = {}", name, value), row_width, "…");
+                let style = if offset == xattr_index {
+                    selected_style
+                } else {
+                    value_style
+                };
+                buf.set_string(inner.x + 2, y, &line, style);
+                y += 1;
+            }
         }
 
         // OK 버튼
@@ -944,12 +1697,12 @@ impl<'a> Dialog<'a> {
         self.render_button(buf, button_x, button_y, ok_label, true);
     }
 
-    /// 도움말 다이얼로그 렌더링
+    /// 마운트 포인트 다이얼로그 렌더링 (이름 + 파일시스템 종류/사용률 막대/용량)
     fn render_mount_points(
         &self,
         buf: &mut Buffer,
         area: Rect,
-        items: &[(String, std::path::PathBuf)],
+        items: &[MountPointItem],
         selected_index: usize,
     ) {
         let block = Block::default()
@@ -983,7 +1736,7 @@ impl<'a> Dialog<'a> {
             0
         };
 
-        for (i, (name, _path)) in items.iter().skip(scroll).enumerate() {
+        for (i, item) in items.iter().skip(scroll).enumerate() {
             if i >= visible_height {
                 break;
             }
@@ -995,12 +1748,11 @@ impl<'a> Dialog<'a> {
             };
 
             let y = inner.y + i as u16;
-            let display = format!(" {:<width$}", name, width = inner.width as usize - 1);
-            let display = if display.len() > inner.width as usize {
-                display[..inner.width as usize].to_string()
-            } else {
-                display
-            };
+            let info = Self::mount_point_usage_text(item);
+            let width = inner.width as usize;
+            let name_width = width.saturating_sub(info.width()).max(width / 3);
+            let name = display_width::fit_width(&format!(" {}", item.name), name_width);
+            let display = display_width::fit_width(&format!("{}{}", name, info), width);
             buf.set_string(inner.x, y, &display, style);
         }
 
@@ -1062,12 +1814,12 @@ impl<'a> Dialog<'a> {
 
             let y = inner.y + i as u16;
             let label = format!(" {}: {}", actual_index + 1, name);
-            let display = format!("{:<width$}", label, width = inner.width as usize);
+            let display = display_width::fit_width(&label, inner.width as usize);
             buf.set_string(inner.x, y, &display, style);
         }
 
         // 하단 힌트
-        let hint = self.i18n().tr(TextKey::DialogHintMoveGoClose);
+        let hint = self.i18n().tr(TextKey::DialogHintTabList);
         let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
         let hint_y = area.y + area.height - 1;
         buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
@@ -1077,7 +1829,9 @@ impl<'a> Dialog<'a> {
         &self,
         buf: &mut Buffer,
         area: Rect,
-        items: &[(String, std::path::PathBuf, bool)],
+        items: &[HistoryListItem],
+        (filter, searching): (&str, bool),
+        visible: &[usize],
         selected_index: usize,
     ) {
         let block = Block::default()
@@ -1092,12 +1846,33 @@ impl<'a> Dialog<'a> {
             .style(Style::default().bg(self.bg_color));
         block.render(area, buf);
 
-        let inner = Rect {
+        let mut inner = Rect {
             x: area.x + DIALOG_H_PADDING,
             y: area.y + DIALOG_V_PADDING,
             width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
             height: area.height.saturating_sub(3),
         };
+        if searching || !filter.is_empty() {
+            buf.set_string(inner.x, inner.y, "/", Style::default().fg(self.title_color));
+            self.render_text_field(
+                buf,
+                inner.x + 2,
+                inner.y,
+                inner.width.saturating_sub(2),
+                filter,
+                searching.then(|| filter.chars().count()),
+            );
+            inner.y += 2;
+            inner.height = inner.height.saturating_sub(2);
+            if visible.is_empty() {
+                buf.set_string(
+                    inner.x,
+                    inner.y,
+                    self.i18n().tr(TextKey::DialogNoFuzzyMatches),
+                    Style::default().fg(self.muted_color),
+                );
+            }
+        }
 
         let normal_style = Style::default().fg(self.fg_color);
         let selected_style = Style::default()
@@ -1111,10 +1886,13 @@ impl<'a> Dialog<'a> {
             0
         };
 
-        for (i, (display_path, _path, is_current)) in items.iter().skip(scroll).enumerate() {
+        for (i, item_index) in visible.iter().skip(scroll).enumerate() {
             if i >= visible_height {
                 break;
             }
+            let Some(item) = items.get(*item_index) else {
+                continue;
+            };
             let actual_index = scroll + i;
             let style = if actual_index == selected_index {
                 selected_style
@@ -1122,68 +1900,755 @@ impl<'a> Dialog<'a> {
                 normal_style
             };
 
-            let y = inner.y + i as u16;
-            let prefix = format!(" {}: ", actual_index + 1);
-            let marker = if *is_current {
-                self.i18n().tr(TextKey::DialogHistoryCurrentMarker)
+            let y = inner.y + i as u16;
+            let pin = if item.pinned { "*" } else { " " };
+            let visited = item
+                .visited_at
+                .map(|secs| formatter::format_date(UNIX_EPOCH + Duration::from_secs(secs)))
+                .unwrap_or_default();
+            let prefix = format!(" {:>2}: {} {:<16}  ", item_index + 1, pin, visited);
+            let marker = if item.is_current {
+                self.i18n().tr(TextKey::DialogHistoryCurrentMarker)
+            } else {
+                ""
+            };
+            let total_width = inner.width as usize;
+            let reserved = UnicodeWidthStr::width(prefix.as_str()) + UnicodeWidthStr::width(marker);
+            let path_max_width = total_width.saturating_sub(reserved);
+            let display_path = item.path.to_string_lossy();
+            let path_display = path_display::truncate_path(&display_path, path_max_width);
+            let label = format!("{}{}{}", prefix, path_display, marker);
+            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
+                path_display::truncate_middle(&label, total_width)
+            } else {
+                display_width::pad_right(&label, total_width)
+            };
+            buf.set_string(inner.x, y, &display, style);
+        }
+
+        // 스크롤바 (내용이 화면보다 많을 때만)
+        let total_items = visible.len();
+        if total_items > visible_height && visible_height > 0 {
+            let track_height = visible_height;
+            let max_scroll = total_items.saturating_sub(visible_height);
+            let thumb_height = (track_height * track_height / total_items).max(1);
+            let thumb_pos = (scroll * (track_height.saturating_sub(thumb_height)))
+                .checked_div(max_scroll)
+                .unwrap_or(0);
+
+            let scrollbar_x = area.x + area.width - 2;
+            let track_style = Style::default().fg(self.progress_unfilled);
+            let thumb_style = Style::default().fg(self.muted_color);
+
+            for i in 0..track_height {
+                let sy = inner.y + i as u16;
+                let (symbol, style) = if i >= thumb_pos && i < thumb_pos + thumb_height {
+                    ("┃", thumb_style)
+                } else {
+                    ("│", track_style)
+                };
+                buf.set_string(scrollbar_x, sy, symbol, style);
+            }
+        }
+
+        let hint = self.i18n().tr(if searching {
+            TextKey::DialogHintHistorySearch
+        } else {
+            TextKey::DialogHintHistoryList
+        });
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    fn render_bookmark_list(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        (items, rows): (&[BookmarkListItem], &[BookmarkListRow]),
+        selected_index: usize,
+    ) {
+        let block = Block::default()
+            .title(self.i18n().tr(TextKey::DialogTitleBookmarks))
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(3),
+        };
+
+        let normal_style = Style::default().fg(self.fg_color);
+        let selected_style = Style::default()
+            .fg(self.button_selected_fg)
+            .bg(self.button_selected_bg);
+
+        let visible_height = inner.height as usize;
+        let scroll = if selected_index >= visible_height {
+            selected_index - visible_height + 1
+        } else {
+            0
+        };
+
+        let group_style = Style::default()
+            .fg(self.title_color)
+            .add_modifier(Modifier::BOLD);
+        let total_width = inner.width as usize;
+        for (i, row) in rows.iter().skip(scroll).take(visible_height).enumerate() {
+            let actual_index = scroll + i;
+            let y = inner.y + i as u16;
+            let item = match row {
+                BookmarkListRow::Group {
+                    name,
+                    count,
+                    collapsed,
+                } => {
+                    let marker = if *collapsed { '▸' } else { '▾' };
+                    let label = format!(" {} {} ({})", marker, name, count);
+                    let style = if actual_index == selected_index {
+                        selected_style.add_modifier(Modifier::BOLD)
+                    } else {
+                        group_style
+                    };
+                    let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
+                        path_display::truncate_middle(&label, total_width)
+                    } else {
+                        display_width::pad_right(&label, total_width)
+                    };
+                    buf.set_string(inner.x, y, &display, style);
+                    continue;
+                }
+                BookmarkListRow::Bookmark(index) => match items.get(*index) {
+                    Some(item) => item,
+                    None => continue,
+                },
+            };
+            let style = if actual_index == selected_index {
+                selected_style
+            } else {
+                normal_style
+            };
+
+            let indent = if item.group.is_some() { "  " } else { "" };
+            let prefix = match item.hotkey {
+                Some(digit) => format!("{} '{} ", indent, digit),
+                None => format!("{}    ", indent),
+            };
+            let path_text = item.path.to_string_lossy();
+            let content = format!(
+                "{}{}",
+                item.name,
+                if path_text.is_empty() { "" } else { " - " }
+            );
+            let content_width = UnicodeWidthStr::width(content.as_str());
+            let path_width =
+                total_width.saturating_sub(UnicodeWidthStr::width(prefix.as_str()) + content_width);
+            let truncated_path = path_display::truncate_middle(&path_text, path_width);
+            let label = format!("{}{}{}", prefix, content, truncated_path);
+            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
+                path_display::truncate_middle(&label, total_width)
+            } else {
+                display_width::pad_right(&label, total_width)
+            };
+            buf.set_string(inner.x, y, &display, style);
+        }
+
+        let hint = self.i18n().tr(TextKey::DialogHintBookmarkList);
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    fn render_session_list(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        items: &[(String, String)],
+        selected_index: usize,
+    ) {
+        let block = Block::default()
+            .title(self.i18n().tr(TextKey::DialogTitleSessions))
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(3),
+        };
+
+        let normal_style = Style::default().fg(self.fg_color);
+        let selected_style = Style::default()
+            .fg(self.button_selected_fg)
+            .bg(self.button_selected_bg);
+
+        let visible_height = inner.height as usize;
+        let scroll = if selected_index >= visible_height {
+            selected_index - visible_height + 1
+        } else {
+            0
+        };
+
+        for (i, (name, summary)) in items.iter().skip(scroll).enumerate() {
+            if i >= visible_height {
+                break;
+            }
+            let actual_index = scroll + i;
+            let style = if actual_index == selected_index {
+                selected_style
+            } else {
+                normal_style
+            };
+
+            let y = inner.y + i as u16;
+            let head = format!(" {}: {} - ", actual_index + 1, name);
+            let total_width = inner.width as usize;
+            let summary_width = total_width.saturating_sub(UnicodeWidthStr::width(head.as_str()));
+            let truncated_summary = path_display::truncate_middle(summary, summary_width);
+            let label = format!("{}{}", head, truncated_summary);
+            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
+                path_display::truncate_middle(&label, total_width)
+            } else {
+                display_width::pad_right(&label, total_width)
+            };
+            buf.set_string(inner.x, y, &display, style);
+        }
+
+        let hint = self.i18n().tr(TextKey::DialogHintMoveRestoreDeleteClose);
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    fn render_job_list(&self, buf: &mut Buffer, area: Rect, jobs: &[Job], selected_index: usize) {
+        let block = Block::default()
+            .title(self.i18n().tr(TextKey::DialogTitleJobs))
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(3),
+        };
+
+        let selected_style = Style::default()
+            .fg(self.button_selected_fg)
+            .bg(self.button_selected_bg);
+
+        let visible_height = inner.height as usize;
+        let scroll = (selected_index + 1).saturating_sub(visible_height);
+        let total_width = inner.width as usize;
+
+        for (i, job) in jobs.iter().skip(scroll).take(visible_height).enumerate() {
+            let style = if scroll + i == selected_index {
+                selected_style
+            } else if job.status.is_failure() {
+                Style::default().fg(self.error_color)
+            } else {
+                Style::default().fg(self.fg_color)
+            };
+            let label = format!(
+                " {} {}  {} - {} ({})",
+                job.status.symbol(),
+                formatter::format_date(job.finished_at),
+                localize_runtime_text(self.language, &job.name),
+                job.summary,
+                job.duration_display()
+            );
+            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
+                display_width::truncate_end(&label, total_width, display_width::ELLIPSIS)
+            } else {
+                display_width::pad_right(&label, total_width)
+            };
+            buf.set_string(inner.x, inner.y + i as u16, &display, style);
+        }
+
+        let hint = self.i18n().tr(TextKey::DialogHintJobList);
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    fn render_remote_host_list(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        items: &[String],
+        selected_index: usize,
+    ) {
+        let block = Block::default()
+            .title(self.i18n().tr(TextKey::DialogTitleRemoteHosts))
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(3),
+        };
+
+        let normal_style = Style::default().fg(self.fg_color);
+        let selected_style = Style::default()
+            .fg(self.button_selected_fg)
+            .bg(self.button_selected_bg);
+
+        let visible_height = inner.height as usize;
+        let scroll = if selected_index >= visible_height {
+            selected_index - visible_height + 1
+        } else {
+            0
+        };
+
+        for (i, url) in items.iter().skip(scroll).enumerate() {
+            if i >= visible_height {
+                break;
+            }
+            let actual_index = scroll + i;
+            let style = if actual_index == selected_index {
+                selected_style
+            } else {
+                normal_style
+            };
+
+            let y = inner.y + i as u16;
+            let total_width = inner.width as usize;
+            let label = format!(" {}: {}", actual_index + 1, url);
+            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
+                path_display::truncate_middle(&label, total_width)
+            } else {
+                display_width::pad_right(&label, total_width)
+            };
+            buf.set_string(inner.x, y, &display, style);
+        }
+
+        let hint = self.i18n().tr(TextKey::DialogHintMoveConnectNewDeleteClose);
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    fn render_theme_list(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        items: &[(String, Option<std::path::PathBuf>)],
+        current: &str,
+        selected_index: usize,
+    ) {
+        let block = Block::default()
+            .title(self.i18n().tr(TextKey::DialogTitleThemes))
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(3),
+        };
+
+        let normal_style = Style::default().fg(self.fg_color);
+        let selected_style = Style::default()
+            .fg(self.button_selected_fg)
+            .bg(self.button_selected_bg);
+
+        let visible_height = inner.height as usize;
+        let scroll = if selected_index >= visible_height {
+            selected_index - visible_height + 1
+        } else {
+            0
+        };
+
+        for (i, (name, path)) in items.iter().skip(scroll).enumerate() {
+            if i >= visible_height {
+                break;
+            }
+            let actual_index = scroll + i;
+            let style = if actual_index == selected_index {
+                selected_style
+            } else {
+                normal_style
+            };
+
+            let y = inner.y + i as u16;
+            let marker = if name == current { "●" } else { " " };
+            let head = format!(" {} {} - ", marker, name);
+            let source = match path {
+                Some(path) => path.to_string_lossy().to_string(),
+                None => self.i18n().tr(TextKey::ThemeBuiltIn).to_string(),
+            };
+            let total_width = inner.width as usize;
+            let source_width = total_width.saturating_sub(UnicodeWidthStr::width(head.as_str()));
+            let label = format!(
+                "{}{}",
+                head,
+                path_display::truncate_middle(&source, source_width)
+            );
+            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
+                path_display::truncate_middle(&label, total_width)
+            } else {
+                display_width::pad_right(&label, total_width)
+            };
+            buf.set_string(inner.x, y, &display, style);
+        }
+
+        let hint = self.i18n().tr(TextKey::DialogHintMoveApplyReloadClose);
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn render_archive_preview_list(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        archive_name: &str,
+        items: &[(String, String)],
+        selected_index: usize,
+        scroll_offset: usize,
+        truncated: bool,
+    ) {
+        let title = self.i18n().fmt(
+            MessageKey::DialogArchivePreviewTitle,
+            &[("name", archive_name.to_string())],
+        );
+        let block = Block::default()
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(3),
+        };
+
+        let normal_style = Style::default().fg(self.fg_color);
+        let selected_style = Style::default()
+            .fg(self.button_selected_fg)
+            .bg(self.button_selected_bg);
+
+        let visible_height = inner.height as usize;
+        let scroll = scroll_offset.min(items.len().saturating_sub(visible_height));
+        let size_col = 12usize.min(inner.width as usize / 3);
+
+        for (row, (path, size_text)) in items.iter().skip(scroll).take(visible_height).enumerate() {
+            let actual_index = scroll + row;
+            let style = if actual_index == selected_index {
+                selected_style
+            } else {
+                normal_style
+            };
+            let y = inner.y + row as u16;
+            let path_width = inner.width as usize - size_col - 2;
+            let path_display = path_display::truncate_middle(path, path_width);
+            let left = display_width::pad_right(&path_display, path_width);
+            let line = format!("{}  {}", left, display_width::pad_left(size_text, size_col));
+            buf.set_string(inner.x, y, line, style);
+        }
+
+        let mut hint = self.i18n().fmt(
+            MessageKey::DialogArchivePreviewHint,
+            &[("count", items.len().to_string())],
+        );
+        if truncated {
+            hint.push_str(self.i18n().tr(TextKey::DialogArchivePreviewTruncated));
+        }
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    /// 텍스트 보기 렌더링 (마지막 페이지에서는 스크롤 고정)
+    fn render_text_viewer(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        title: &str,
+        lines: &[String],
+        scroll_offset: usize,
+        diff: bool,
+    ) {
+        let title = localize_runtime_text(self.language, title);
+        let title = path_display::truncate_middle(&title, area.width.saturating_sub(4) as usize);
+        let block = Block::default()
+            .title(format!(" {} ", title))
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(3),
+        };
+        let visible_height = inner.height as usize;
+        let scroll = scroll_offset.min(lines.len().saturating_sub(visible_height));
+        for (row, line) in lines.iter().skip(scroll).take(visible_height).enumerate() {
+            let color = if !diff {
+                self.fg_color
+            } else if line.starts_with("+++") || line.starts_with("---") {
+                self.muted_color
+            } else if line.starts_with('+') {
+                self.success_color
+            } else if line.starts_with('-') {
+                self.error_color
+            } else if line.starts_with("@@") {
+                self.title_color
+            } else {
+                self.fg_color
+            };
+            buf.set_stringn(
+                inner.x,
+                inner.y + row as u16,
+                line,
+                inner.width as usize,
+                Style::default().fg(color),
+            );
+        }
+
+        let hint = self.i18n().fmt(
+            MessageKey::DialogTextViewerHint,
+            &[
+                ("line", (scroll + 1).min(lines.len()).to_string()),
+                ("count", lines.len().to_string()),
+            ],
+        );
+        let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
+        let hint_y = area.y + area.height - 1;
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+    }
+
+    /// 퍼지 목록 렌더링 (일치한 글자 강조, 넘치는 항목은 앞부분 생략)
+    #[allow(clippy::too_many_arguments)]
+    fn render_fuzzy_list(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        title: &str,
+        (query, cursor_pos): (&str, usize),
+        (items, loading): (&[FuzzyListItem], bool),
+        matches: &[(usize, Vec<usize>)],
+        selected_index: usize,
+    ) {
+        let title = localize_runtime_text(self.language, title);
+        let block = Block::default()
+            .title(format!(" {} ", title))
+            .title_style(
+                Style::default()
+                    .fg(self.title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color))
+            .style(Style::default().bg(self.bg_color));
+        block.render(area, buf);
+
+        let inner = Rect {
+            x: area.x + DIALOG_H_PADDING,
+            y: area.y + DIALOG_V_PADDING,
+            width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
+            height: area.height.saturating_sub(2),
+        };
+        buf.set_string(inner.x, inner.y, ">", Style::default().fg(self.title_color));
+        self.render_text_field(
+            buf,
+            inner.x + 2,
+            inner.y,
+            inner.width.saturating_sub(2),
+            query,
+            Some(cursor_pos),
+        );
+
+        let list_y = inner.y + 2;
+        let visible_height = inner.height.saturating_sub(2) as usize;
+        if matches.is_empty() {
+            let empty = self.i18n().tr(TextKey::DialogNoFuzzyMatches);
+            buf.set_string(
+                inner.x,
+                list_y,
+                empty,
+                Style::default().fg(self.muted_color),
+            );
+        }
+        let scroll = (selected_index + 1).saturating_sub(visible_height);
+        let width = inner.width as usize;
+        for (row, (item_index, positions)) in
+            matches.iter().skip(scroll).take(visible_height).enumerate()
+        {
+            let Some(item) = items.get(*item_index) else {
+                continue;
+            };
+            let selected = scroll + row == selected_index;
+            let base = if selected {
+                Style::default()
+                    .fg(self.button_selected_fg)
+                    .bg(self.button_selected_bg)
             } else {
-                ""
+                Style::default().fg(self.fg_color)
             };
-            let total_width = inner.width as usize;
-            let reserved = UnicodeWidthStr::width(prefix.as_str()) + UnicodeWidthStr::width(marker);
-            let path_max_width = total_width.saturating_sub(reserved);
-            let path_display = path_display::truncate_path(display_path, path_max_width);
-            let label = format!("{}{}{}", prefix, path_display, marker);
-            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
-                path_display::truncate_middle(&label, total_width)
+            let matched = if selected {
+                base.add_modifier(Modifier::BOLD)
             } else {
-                format!("{:<width$}", label, width = total_width)
+                base.fg(self.title_color).add_modifier(Modifier::BOLD)
             };
-            buf.set_string(inner.x, y, &display, style);
-        }
+            let y = list_y + row as u16;
+            if selected {
+                buf.set_string(inner.x, y, " ".repeat(width), base);
+            }
 
-        // 스크롤바 (내용이 화면보다 많을 때만)
-        let total_items = items.len();
-        if total_items > visible_height && visible_height > 0 {
-            let track_height = visible_height;
-            let max_scroll = total_items.saturating_sub(visible_height);
-            let thumb_height = (track_height * track_height / total_items).max(1);
-            let thumb_pos = if max_scroll == 0 {
-                0
+            let detail_width = item.detail.width();
+            let label_width = if detail_width > 0 && detail_width + 12 < width {
+                width - detail_width - 2
             } else {
-                scroll * (track_height.saturating_sub(thumb_height)) / max_scroll
+                width
             };
-
-            let scrollbar_x = area.x + area.width - 2;
-            let track_style = Style::default().fg(self.progress_unfilled);
-            let thumb_style = Style::default().fg(self.muted_color);
-
-            for i in 0..track_height {
-                let sy = inner.y + i as u16;
-                let (symbol, style) = if i >= thumb_pos && i < thumb_pos + thumb_height {
-                    ("┃", thumb_style)
+            let mut x = inner.x;
+            for (c, style) in
+                Self::fuzzy_label_cells(&item.label, positions, label_width, base, matched)
+            {
+                let cell_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0) as u16;
+                buf.set_string(x, y, c.to_string(), style);
+                x += cell_width;
+            }
+            if label_width < width {
+                let detail_style = if selected {
+                    base
                 } else {
-                    ("│", track_style)
+                    Style::default().fg(self.muted_color)
                 };
-                buf.set_string(scrollbar_x, sy, symbol, style);
+                let detail_x = inner.x + (width - detail_width) as u16;
+                buf.set_string(detail_x, y, &item.detail, detail_style);
             }
         }
 
-        let hint = self.i18n().tr(TextKey::DialogHintMoveGoClearClose);
+        let hint_key = if loading {
+            MessageKey::DialogFuzzyListLoadingHint
+        } else {
+            MessageKey::DialogFuzzyListHint
+        };
+        let hint = self.i18n().fmt(
+            hint_key,
+            &[
+                ("count", matches.len().to_string()),
+                ("total", items.len().to_string()),
+            ],
+        );
         let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
         let hint_y = area.y + area.height - 1;
         buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
     }
 
-    fn render_bookmark_list(
+    /// 퍼지 목록 레이블의 표시 글자 (폭을 넘으면 앞부분을 `…`로 생략)
+    fn fuzzy_label_cells(
+        label: &str,
+        positions: &[usize],
+        max_width: usize,
+        base: Style,
+        matched: Style,
+    ) -> Vec<(char, Style)> {
+        let chars: Vec<char> = label.chars().collect();
+        let char_width = |c: &char| unicode_width::UnicodeWidthChar::width(*c).unwrap_or(0);
+        let mut start = 0;
+        let mut cells = Vec::new();
+        if label.width() > max_width {
+            let mut used = 1; // `…`
+            start = chars.len();
+            while start > 0 && used + char_width(&chars[start - 1]) <= max_width {
+                start -= 1;
+                used += char_width(&chars[start]);
+            }
+            cells.push(('…', base));
+        }
+        cells.extend(chars.iter().enumerate().skip(start).map(|(index, &c)| {
+            let style = if positions.binary_search(&index).is_ok() {
+                matched
+            } else {
+                base
+            };
+            (c, style)
+        }));
+        cells
+    }
+
+    /// 디스크 사용량 분석 렌더링 (`  12.3G  45.2% [████░░░░░░] name/`)
+    #[allow(clippy::too_many_arguments)]
+    fn render_disk_usage(
         &self,
         buf: &mut Buffer,
         area: Rect,
-        items: &[(String, std::path::PathBuf)],
+        path: &str,
+        total: u64,
+        items: &[DiskUsageItem],
         selected_index: usize,
+        scanning: Option<(u64, u64)>,
+        pending_delete: Option<bool>,
     ) {
+        const BAR_WIDTH: usize = 10;
+        let title_path = path_display::truncate_middle(path, area.width as usize / 2);
+        let title = self.i18n().fmt(
+            MessageKey::DialogDiskUsageTitle,
+            &[("path", title_path), ("size", format_file_size(total))],
+        );
         let block = Block::default()
-            .title(self.i18n().tr(TextKey::DialogTitleBookmarks))
+            .title(title)
             .title_style(
                 Style::default()
                     .fg(self.title_color)
@@ -1200,68 +2665,106 @@ impl<'a> Dialog<'a> {
             width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
             height: area.height.saturating_sub(3),
         };
+        let hint_y = area.y + area.height - 1;
+
+        if let Some((files, bytes)) = scanning {
+            let text = self.i18n().fmt(
+                MessageKey::DialogDiskUsageScanning,
+                &[
+                    ("files", files.to_string()),
+                    ("size", format_file_size(bytes)),
+                ],
+            );
+            let x = inner.x + inner.width.saturating_sub(text.width() as u16) / 2;
+            let y = inner.y + inner.height / 2;
+            buf.set_string(x, y, text, Style::default().fg(self.fg_color));
+            return;
+        }
 
         let normal_style = Style::default().fg(self.fg_color);
         let selected_style = Style::default()
             .fg(self.button_selected_fg)
             .bg(self.button_selected_bg);
-
         let visible_height = inner.height as usize;
-        let scroll = if selected_index >= visible_height {
-            selected_index - visible_height + 1
-        } else {
-            0
-        };
+        let scroll = (selected_index + 1).saturating_sub(visible_height);
 
-        for (i, (name, path)) in items.iter().skip(scroll).enumerate() {
-            if i >= visible_height {
-                break;
-            }
-            let actual_index = scroll + i;
-            let style = if actual_index == selected_index {
+        for (row, item) in items.iter().skip(scroll).take(visible_height).enumerate() {
+            let style = if scroll + row == selected_index {
                 selected_style
             } else {
                 normal_style
             };
-
-            let y = inner.y + i as u16;
-            let prefix = format!(" {}: ", actual_index + 1);
-            let path_text = path.to_string_lossy();
-            let content = format!("{}{}", name, if path_text.is_empty() { "" } else { " - " });
-            let content_width = UnicodeWidthStr::width(content.as_str());
-            let total_width = inner.width as usize;
-            let path_width =
-                total_width.saturating_sub(UnicodeWidthStr::width(prefix.as_str()) + content_width);
-            let truncated_path = path_display::truncate_middle(&path_text, path_width);
-            let label = format!("{}{}{}", prefix, content, truncated_path);
-            let display = if UnicodeWidthStr::width(label.as_str()) > total_width {
-                path_display::truncate_middle(&label, total_width)
+            let ratio = if total == 0 {
+                0.0
             } else {
-                path_display::pad_right_to_width(&label, total_width)
+                item.size as f64 / total as f64
             };
-            buf.set_string(inner.x, y, &display, style);
+            let filled = ((ratio * BAR_WIDTH as f64).round() as usize).min(BAR_WIDTH);
+            let name = match (item.is_dir, item.incomplete) {
+                (true, true) => format!("{}/ (!)", item.name),
+                (true, false) => format!("{}/", item.name),
+                _ => item.name.clone(),
+            };
+            let line = format!(
+                "{:>9} {:>5.1}% [{}{}] {}",
+                format_file_size(item.size),
+                ratio * 100.0,
+                "█".repeat(filled),
+                "░".repeat(BAR_WIDTH - filled),
+                name
+            );
+            let line = display_width::fit_width(&line, inner.width as usize);
+            buf.set_string(inner.x, inner.y + row as u16, line, style);
         }
 
-        let hint = self.i18n().tr(TextKey::DialogHintMoveGoRenameDeleteClose);
+        let selected = items.get(selected_index);
+        let (hint, hint_color) = match (pending_delete, selected) {
+            (Some(permanent), Some(item)) => {
+                let key = if permanent {
+                    MessageKey::DialogDiskUsageConfirmDelete
+                } else {
+                    MessageKey::DialogDiskUsageConfirmTrash
+                };
+                let hint = self.i18n().fmt(
+                    key,
+                    &[
+                        ("name", item.name.clone()),
+                        ("size", format_file_size(item.size)),
+                    ],
+                );
+                (hint, self.warning_color)
+            }
+            _ => (
+                self.i18n().fmt(
+                    MessageKey::DialogDiskUsageHint,
+                    &[("count", items.len().to_string())],
+                ),
+                self.muted_color,
+            ),
+        };
+        let hint = display_width::take_prefix(&hint, area.width.saturating_sub(2) as usize);
         let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
-        let hint_y = area.y + area.height - 1;
-        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(hint_color));
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn render_archive_preview_list(
+    /// 패널 동기화 계획 렌더링 (`[x] 복사 →      12.3K  docs/a.txt`)
+    fn render_panel_sync(
         &self,
         buf: &mut Buffer,
         area: Rect,
-        archive_name: &str,
-        items: &[(String, String)],
+        (left, right): (&str, &str),
+        items: &[SyncItem],
         selected_index: usize,
-        scroll_offset: usize,
-        truncated: bool,
+        phase: PanelSyncPhase,
     ) {
+        const ACTION_WIDTH: usize = 12;
+        let side_width = area.width as usize / 3;
         let title = self.i18n().fmt(
-            MessageKey::DialogArchivePreviewTitle,
-            &[("name", archive_name.to_string())],
+            MessageKey::DialogPanelSyncTitle,
+            &[
+                ("left", path_display::truncate_middle(left, side_width)),
+                ("right", path_display::truncate_middle(right, side_width)),
+            ],
         );
         let block = Block::default()
             .title(title)
@@ -1281,41 +2784,87 @@ impl<'a> Dialog<'a> {
             width: area.width.saturating_sub(DIALOG_H_PADDING * 2),
             height: area.height.saturating_sub(3),
         };
+        let hint_y = area.y + area.height - 1;
+
+        if let PanelSyncPhase::Comparing(count) = phase {
+            let text = self.i18n().fmt(
+                MessageKey::DialogPanelSyncComparing,
+                &[("count", count.to_string())],
+            );
+            let x = inner.x + inner.width.saturating_sub(text.width() as u16) / 2;
+            let y = inner.y + inner.height / 2;
+            buf.set_string(x, y, text, Style::default().fg(self.fg_color));
+            return;
+        }
 
         let normal_style = Style::default().fg(self.fg_color);
+        let muted_style = Style::default().fg(self.muted_color);
+        let delete_style = Style::default().fg(self.warning_color);
         let selected_style = Style::default()
             .fg(self.button_selected_fg)
             .bg(self.button_selected_bg);
-
         let visible_height = inner.height as usize;
-        let scroll = scroll_offset.min(items.len().saturating_sub(visible_height));
-        let size_col = 12usize.min(inner.width as usize / 3);
+        let scroll = (selected_index + 1).saturating_sub(visible_height);
 
-        for (row, (path, size_text)) in items.iter().skip(scroll).take(visible_height).enumerate() {
-            let actual_index = scroll + row;
-            let style = if actual_index == selected_index {
+        for (row, item) in items.iter().skip(scroll).take(visible_height).enumerate() {
+            let style = if scroll + row == selected_index {
                 selected_style
+            } else if !item.checked {
+                muted_style
+            } else if item.action.is_delete() {
+                delete_style
             } else {
                 normal_style
             };
-            let y = inner.y + row as u16;
-            let path_width = inner.width as usize - size_col - 2;
-            let path_display = path_display::truncate_middle(path, path_width);
-            let left = path_display::pad_right_to_width(&path_display, path_width);
-            let line = format!("{}  {:>width$}", left, size_text, width = size_col);
-            buf.set_string(inner.x, y, line, style);
+            let action = self.i18n().tr(match item.action {
+                SyncAction::CopyToRight => TextKey::SyncCopyToRight,
+                SyncAction::CopyToLeft => TextKey::SyncCopyToLeft,
+                SyncAction::DeleteLeft => TextKey::SyncDeleteLeft,
+                SyncAction::DeleteRight => TextKey::SyncDeleteRight,
+            });
+            let name = if item.is_dir() {
+                format!("{}/", item.relative.display())
+            } else {
+                item.relative.display().to_string()
+            };
+            let line = format!(
+                "[{}] {} {:>9}  {}",
+                if item.checked { "x" } else { " " },
+                display_width::fit_width(action, ACTION_WIDTH),
+                format_file_size(item.size()),
+                name
+            );
+            let line = display_width::fit_width(&line, inner.width as usize);
+            buf.set_string(inner.x, inner.y + row as u16, line, style);
         }
 
-        let mut hint = self.i18n().fmt(
-            MessageKey::DialogArchivePreviewHint,
-            &[("count", items.len().to_string())],
-        );
-        if truncated {
-            hint.push_str(self.i18n().tr(TextKey::DialogArchivePreviewTruncated));
-        }
+        let (hint, hint_color) = match phase {
+            PanelSyncPhase::Running { done, total } => (
+                self.i18n().fmt(
+                    MessageKey::DialogPanelSyncRunning,
+                    &[("done", done.to_string()), ("total", total.to_string())],
+                ),
+                self.warning_color,
+            ),
+            _ => {
+                let checked = items.iter().filter(|item| item.checked);
+                let size: u64 = checked.clone().map(SyncItem::size).sum();
+                (
+                    self.i18n().fmt(
+                        MessageKey::DialogPanelSyncHint,
+                        &[
+                            ("checked", checked.count().to_string()),
+                            ("count", items.len().to_string()),
+                            ("size", format_file_size(size)),
+                        ],
+                    ),
+                    self.muted_color,
+                )
+            }
+        };
+        let hint = display_width::take_prefix(&hint, area.width.saturating_sub(2) as usize);
         let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
-        let hint_y = area.y + area.height - 1;
-        buf.set_string(hint_x, hint_y, hint, Style::default().fg(self.muted_color));
+        buf.set_string(hint_x, hint_y, hint, Style::default().fg(hint_color));
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1332,6 +2881,8 @@ impl<'a> Dialog<'a> {
         password_confirm_cursor_pos: usize,
         focused_field: usize,
         selected_button: usize,
+        level: CompressionLevel,
+        presets: &[String],
     ) {
         let block = Block::default()
             .title(self.i18n().tr(TextKey::DialogTitleCreateArchive))
@@ -1391,7 +2942,20 @@ impl<'a> Dialog<'a> {
             checkbox,
             self.i18n().tr(TextKey::DialogUsePassword)
         );
+        let level_x = inner.x + checkbox_line.width() as u16 + 4;
         buf.set_string(inner.x, inner.y + 3, checkbox_line, checkbox_style);
+        let level_line = format!(
+            "{} < {} >",
+            self.i18n().tr(TextKey::DialogCompression),
+            level.label()
+        );
+        buf.set_stringn(
+            level_x,
+            inner.y + 3,
+            level_line,
+            (inner.x + inner.width).saturating_sub(level_x) as usize,
+            checkbox_style,
+        );
 
         let password_label_style = if use_password {
             if focused_field == 2 {
@@ -1451,6 +3015,27 @@ impl<'a> Dialog<'a> {
             },
         );
 
+        if !presets.is_empty() {
+            let listed = presets
+                .iter()
+                .take(9)
+                .enumerate()
+                .map(|(index, name)| format!("{} {}", index + 1, name))
+                .collect::<Vec<_>>()
+                .join("  ");
+            buf.set_stringn(
+                inner.x,
+                inner.y + 11,
+                format!(
+                    "{} {}",
+                    self.i18n().tr(TextKey::DialogArchivePresets),
+                    listed
+                ),
+                inner.width as usize,
+                dim_style,
+            );
+        }
+
         let hint = self.i18n().tr(TextKey::DialogHintArchiveCreate);
         let hint_x = area.x + (area.width.saturating_sub(hint.width() as u16)) / 2;
         let hint_y = area.y + area.height.saturating_sub(3);
@@ -1649,11 +3234,9 @@ impl<'a> Dialog<'a> {
         if total_items > visible_height && visible_height > 0 {
             let track_height = visible_height;
             let thumb_height = (track_height * track_height / total_items).max(1);
-            let thumb_pos = if max_scroll == 0 {
-                0
-            } else {
-                effective_scroll * (track_height.saturating_sub(thumb_height)) / max_scroll
-            };
+            let thumb_pos = (effective_scroll * (track_height.saturating_sub(thumb_height)))
+                .checked_div(max_scroll)
+                .unwrap_or(0);
 
             let scrollbar_x = area.x + area.width - 2;
             let track_style = Style::default().fg(self.progress_unfilled);
@@ -1751,9 +3334,12 @@ impl Widget for Dialog<'_> {
                 cursor_pos,
                 selected_button,
                 purpose,
-                completion_candidates,
-                completion_index,
+                completion,
                 mask_input,
+                copy_options,
+                show_preview,
+                scope_filter,
+                free_space,
                 ..
             } => {
                 self.render_input(
@@ -1763,12 +3349,15 @@ impl Widget for Dialog<'_> {
                     prompt,
                     value,
                     *purpose,
-                    completion_candidates,
-                    *completion_index,
+                    completion,
                     *cursor_pos,
                     *selected_button,
                     true,
                     *mask_input,
+                    *copy_options,
+                    *show_preview,
+                    scope_filter.as_deref(),
+                    *free_space,
                 );
             }
             DialogKind::ArchiveCreateOptions {
@@ -1781,6 +3370,8 @@ impl Widget for Dialog<'_> {
                 password_confirm_cursor_pos,
                 focused_field,
                 selected_button,
+                level,
+                presets,
                 ..
             } => {
                 self.render_archive_create_options(
@@ -1795,6 +3386,8 @@ impl Widget for Dialog<'_> {
                     *password_confirm_cursor_pos,
                     *focused_field,
                     *selected_button,
+                    *level,
+                    presets,
                 );
             }
             DialogKind::Confirm {
@@ -1808,8 +3401,16 @@ impl Widget for Dialog<'_> {
                 source_path,
                 dest_path,
                 selected_option,
+                directory,
             } => {
-                self.render_conflict(buf, dialog_area, source_path, dest_path, *selected_option);
+                self.render_conflict(
+                    buf,
+                    dialog_area,
+                    source_path,
+                    dest_path,
+                    *selected_option,
+                    *directory,
+                );
             }
             DialogKind::Progress { progress } => {
                 self.render_progress(buf, dialog_area, progress);
@@ -1827,10 +3428,25 @@ impl Widget for Dialog<'_> {
             } => {
                 self.render_delete_confirm(buf, dialog_area, items, total_size, *selected_button);
             }
+            DialogKind::FileInUse {
+                path,
+                error,
+                selected_button,
+            } => {
+                self.render_file_in_use(buf, dialog_area, path, error, *selected_button);
+            }
+            DialogKind::QuitConfirm {
+                jobs,
+                selected_button,
+            } => {
+                self.render_quit_confirm(buf, dialog_area, jobs, *selected_button);
+            }
             DialogKind::MkdirInput {
                 value,
                 cursor_pos,
                 selected_button,
+                enter_created,
+                completion,
                 ..
             } => {
                 self.render_input(
@@ -1840,13 +3456,17 @@ impl Widget for Dialog<'_> {
                     self.i18n().tr(TextKey::DialogDirectoryName),
                     value,
                     InputPurpose::OperationDestination,
-                    &[],
-                    None,
+                    completion,
                     *cursor_pos,
                     *selected_button,
                     false,
                     false,
+                    None,
+                    false,
+                    None,
+                    None,
                 );
+                self.render_mkdir_options(buf, dialog_area, value, *enter_created, completion);
             }
             DialogKind::RenameInput {
                 value,
@@ -1861,33 +3481,54 @@ impl Widget for Dialog<'_> {
                     self.i18n().tr(TextKey::DialogNewName),
                     value,
                     InputPurpose::OperationDestination,
-                    &[],
-                    None,
+                    &PathCompletion::default(),
                     *cursor_pos,
                     *selected_button,
                     false,
                     false,
+                    None,
+                    false,
+                    None,
+                    None,
                 );
             }
             DialogKind::BookmarkRenameInput {
                 value,
                 cursor_pos,
                 selected_button,
+                field,
                 ..
             } => {
+                let (title, prompt) = match field {
+                    BookmarkEditField::Name => (
+                        TextKey::DialogBookmarkRename,
+                        TextKey::DialogNewBookmarkName,
+                    ),
+                    BookmarkEditField::Path => (
+                        TextKey::DialogBookmarkEditPath,
+                        TextKey::DialogNewBookmarkPath,
+                    ),
+                    BookmarkEditField::Group => (
+                        TextKey::DialogBookmarkGroup,
+                        TextKey::DialogNewBookmarkGroup,
+                    ),
+                };
                 self.render_input(
                     buf,
                     dialog_area,
-                    self.i18n().tr(TextKey::DialogBookmarkRename),
-                    self.i18n().tr(TextKey::DialogNewBookmarkName),
+                    self.i18n().tr(title),
+                    self.i18n().tr(prompt),
                     value,
                     InputPurpose::OperationDestination,
-                    &[],
-                    None,
+                    &PathCompletion::default(),
                     *cursor_pos,
                     *selected_button,
                     false,
                     false,
+                    None,
+                    false,
+                    None,
+                    None,
                 );
             }
             DialogKind::FilterInput {
@@ -1902,52 +3543,131 @@ impl Widget for Dialog<'_> {
                     self.i18n().tr(TextKey::DialogFilterPattern),
                     value,
                     InputPurpose::OperationDestination,
-                    &[],
-                    None,
+                    &PathCompletion::default(),
                     *cursor_pos,
                     *selected_button,
                     false,
                     false,
+                    None,
+                    false,
+                    None,
+                    None,
+                );
+                self.render_filter_regex_checkbox(buf, dialog_area, value);
+            }
+            DialogKind::MountPoints { list } => {
+                self.render_mount_points(buf, dialog_area, &list.items, list.selected);
+            }
+            DialogKind::TabList { list } => {
+                self.render_tab_list(buf, dialog_area, &list.items, list.selected);
+            }
+            DialogKind::HistoryList { list } => {
+                self.render_history_list(
+                    buf,
+                    dialog_area,
+                    &list.items,
+                    (&list.filter, list.searching),
+                    &list.visible,
+                    list.selected,
                 );
             }
-            DialogKind::MountPoints {
+            DialogKind::BookmarkList { items, rows } => {
+                self.render_bookmark_list(buf, dialog_area, (items, &rows.items), rows.selected);
+            }
+            DialogKind::SessionList { list } => {
+                self.render_session_list(buf, dialog_area, &list.items, list.selected);
+            }
+            DialogKind::JobList { list } => {
+                self.render_job_list(buf, dialog_area, &list.items, list.selected);
+            }
+            DialogKind::RemoteHostList { list } => {
+                self.render_remote_host_list(buf, dialog_area, &list.items, list.selected);
+            }
+            DialogKind::ThemeList { list, current } => {
+                self.render_theme_list(buf, dialog_area, &list.items, current, list.selected);
+            }
+            DialogKind::FuzzyList {
+                title,
+                query,
+                cursor_pos,
                 items,
+                matches,
                 selected_index,
+                loading,
+                ..
             } => {
-                self.render_mount_points(buf, dialog_area, items, *selected_index);
+                self.render_fuzzy_list(
+                    buf,
+                    dialog_area,
+                    title,
+                    (query, *cursor_pos),
+                    (items, *loading),
+                    matches,
+                    *selected_index,
+                );
             }
-            DialogKind::TabList {
-                items,
-                selected_index,
+            DialogKind::TextViewer {
+                title,
+                lines,
+                scroll_offset,
+                diff,
+            } => {
+                self.render_text_viewer(buf, dialog_area, title, lines, *scroll_offset, *diff);
+            }
+            DialogKind::FileCompare {
+                name,
+                checked,
+                total,
             } => {
-                self.render_tab_list(buf, dialog_area, items, *selected_index);
+                self.render_file_compare(buf, dialog_area, name, *checked, *total);
             }
-            DialogKind::HistoryList {
+            DialogKind::DiskUsage {
+                path,
+                total,
                 items,
                 selected_index,
+                scanning,
+                pending_delete,
             } => {
-                self.render_history_list(buf, dialog_area, items, *selected_index);
+                self.render_disk_usage(
+                    buf,
+                    dialog_area,
+                    path,
+                    *total,
+                    items,
+                    *selected_index,
+                    *scanning,
+                    *pending_delete,
+                );
             }
-            DialogKind::BookmarkList {
+            DialogKind::PanelSync {
+                left,
+                right,
                 items,
                 selected_index,
+                phase,
             } => {
-                self.render_bookmark_list(buf, dialog_area, items, *selected_index);
+                self.render_panel_sync(
+                    buf,
+                    dialog_area,
+                    (left, right),
+                    items,
+                    *selected_index,
+                    *phase,
+                );
             }
             DialogKind::ArchivePreviewList {
                 archive_name,
-                items,
-                selected_index,
-                scroll_offset,
+                list,
                 truncated,
             } => {
                 self.render_archive_preview_list(
                     buf,
                     dialog_area,
                     archive_name,
-                    items,
-                    *selected_index,
-                    *scroll_offset,
+                    &list.items,
+                    list.selected,
+                    list.scroll,
                     *truncated,
                 );
             }
@@ -1967,6 +3687,11 @@ impl Widget for Dialog<'_> {
                 modified,
                 permissions,
                 children_info,
+                detected_type,
+                attributes,
+                targets,
+                xattrs,
+                xattr_index,
             } => {
                 self.render_properties(
                     buf,
@@ -1978,6 +3703,11 @@ impl Widget for Dialog<'_> {
                     modified,
                     permissions,
                     children_info,
+                    detected_type.as_deref(),
+                    attributes.as_ref(),
+                    *targets,
+                    xattrs,
+                    *xattr_index,
                 );
             }
         }
@@ -2003,8 +3733,7 @@ mod tests {
                 selected_button,
                 purpose,
                 base_path,
-                completion_candidates,
-                completion_index,
+                completion,
                 ..
             } => {
                 assert_eq!(title, "Copy");
@@ -2014,8 +3743,8 @@ mod tests {
                 assert_eq!(selected_button, 0);
                 assert_eq!(purpose, InputPurpose::OperationDestination);
                 assert_eq!(base_path, PathBuf::from("."));
-                assert!(completion_candidates.is_empty());
-                assert!(completion_index.is_none());
+                assert!(completion.candidates.is_empty());
+                assert!(completion.index.is_none());
             }
             _ => panic!("Expected Input dialog"),
         }
@@ -2031,8 +3760,7 @@ mod tests {
                 value,
                 purpose,
                 base_path,
-                completion_candidates,
-                completion_index,
+                completion,
                 ..
             } => {
                 assert_eq!(title, "Go to Path");
@@ -2040,8 +3768,8 @@ mod tests {
                 assert_eq!(value, "/tmp");
                 assert_eq!(purpose, InputPurpose::GoToPath);
                 assert_eq!(base_path, PathBuf::from("/tmp"));
-                assert!(completion_candidates.is_empty());
-                assert!(completion_index.is_none());
+                assert!(completion.candidates.is_empty());
+                assert!(completion.index.is_none());
             }
             _ => panic!("Expected Input dialog"),
         }
@@ -2049,7 +3777,11 @@ mod tests {
 
     #[test]
     fn test_archive_create_options_input_creation() {
-        let dialog = DialogKind::archive_create_options_input("/tmp/a.zip", PathBuf::from("/tmp"));
+        let dialog = DialogKind::archive_create_options_input(
+            "/tmp/a.zip",
+            PathBuf::from("/tmp"),
+            Vec::new(),
+        );
         match dialog {
             DialogKind::ArchiveCreateOptions {
                 path_value,
@@ -2077,7 +3809,11 @@ mod tests {
 
     #[test]
     fn test_archive_create_options_localized_in_korean() {
-        let dialog = DialogKind::archive_create_options_input("/tmp/a.zip", PathBuf::from("/tmp"));
+        let dialog = DialogKind::archive_create_options_input(
+            "/tmp/a.zip",
+            PathBuf::from("/tmp"),
+            vec!["zip fast".to_string()],
+        );
         let area = Rect {
             x: 0,
             y: 0,
@@ -2104,6 +3840,16 @@ mod tests {
         let normalized: String = rendered.chars().filter(|c| !c.is_whitespace()).collect();
         assert!(normalized.contains("압축경로:"), "rendered=\n{}", rendered);
         assert!(normalized.contains("비밀번호"), "rendered=\n{}", rendered);
+        assert!(
+            normalized.contains("압축수준:<normal>"),
+            "rendered=\n{}",
+            rendered
+        );
+        assert!(
+            normalized.contains("프리셋:1zipfast"),
+            "rendered=\n{}",
+            rendered
+        );
         assert!(
             !rendered.contains("Archive path:"),
             "rendered=\n{}",
@@ -2116,6 +3862,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_input_window_uses_display_width() {
+        // 짧은 값: 전체 표시, 커서 열은 전각 폭 기준
+        let value = "a\u{D55C}\u{AE00}";
+        assert_eq!(input_window(value, value.len(), 10), (0, value.len(), 5));
+        assert_eq!(input_window(value, 1, 10), (0, value.len(), 1));
+
+        // 긴 값: 커서가 오른쪽 끝에 보이도록 스크롤하고 전각 문자는 쪼개지 않음
+        let value = "\u{AC00}\u{B098}\u{B2E4}\u{B77C}\u{B9C8}";
+        let (start, end, col) = input_window(value, value.len(), 5);
+        assert_eq!(&value[start..end], "\u{B77C}\u{B9C8}");
+        assert_eq!(col, 4);
+
+        let (start, end, col) = input_window(value, 0, 5);
+        assert_eq!(&value[start..end], "\u{AC00}\u{B098}");
+        assert_eq!(col, 0);
+    }
+
     #[test]
     fn test_terminal_command_input_localized_in_korean() {
         let dialog = DialogKind::terminal_command_input("", PathBuf::from("/tmp"));
@@ -2177,14 +3941,9 @@ mod tests {
     #[test]
     fn test_go_to_path_does_not_render_inline_ghost_text() {
         let mut kind = DialogKind::go_to_path_input("/Users/boksl/", PathBuf::from("/Users/boksl"));
-        if let DialogKind::Input {
-            completion_candidates,
-            completion_index,
-            ..
-        } = &mut kind
-        {
-            *completion_candidates = vec!["/Users/boksl/IdeaProjects".to_string()];
-            *completion_index = Some(0);
+        if let DialogKind::Input { completion, .. } = &mut kind {
+            completion.candidates = vec!["/Users/boksl/IdeaProjects".to_string()];
+            completion.index = Some(0);
         }
 
         let area = Rect {
@@ -2214,17 +3973,12 @@ mod tests {
     #[test]
     fn test_suggestions_title_shows_selected_and_total_count() {
         let mut kind = DialogKind::go_to_path_input("/Users/boksl/", PathBuf::from("/Users/boksl"));
-        if let DialogKind::Input {
-            completion_candidates,
-            completion_index,
-            ..
-        } = &mut kind
-        {
-            *completion_candidates = vec![
+        if let DialogKind::Input { completion, .. } = &mut kind {
+            completion.candidates = vec![
                 "/Users/boksl/IdeaProjects".to_string(),
                 "/Users/boksl/Downloads".to_string(),
             ];
-            *completion_index = Some(1);
+            completion.index = Some(1);
         }
 
         let area = Rect {
@@ -2258,14 +4012,9 @@ mod tests {
     #[test]
     fn test_go_to_path_shows_tab_apply_hint() {
         let mut kind = DialogKind::go_to_path_input("/Users/boksl/", PathBuf::from("/Users/boksl"));
-        if let DialogKind::Input {
-            completion_candidates,
-            completion_index,
-            ..
-        } = &mut kind
-        {
-            *completion_candidates = vec!["/Users/boksl/IdeaProjects".to_string()];
-            *completion_index = Some(0);
+        if let DialogKind::Input { completion, .. } = &mut kind {
+            completion.candidates = vec!["/Users/boksl/IdeaProjects".to_string()];
+            completion.index = Some(0);
         }
 
         let area = Rect {
@@ -2295,7 +4044,7 @@ mod tests {
 
     #[test]
     fn test_mkdir_input_hides_suggestions_panel() {
-        let kind = DialogKind::mkdir_input(PathBuf::from("."));
+        let kind = DialogKind::mkdir_input(PathBuf::from("."), false);
         let area = Rect {
             x: 0,
             y: 0,
@@ -2431,4 +4180,51 @@ mod tests {
             Some(ConflictResolution::OverwriteAll)
         );
     }
+
+    #[test]
+    fn test_fuzzy_list_highlights_matches_and_elides_long_labels() {
+        let long = format!("/very/{}/target", "deep".repeat(30));
+        let mut kind = DialogKind::fuzzy_list(
+            "Jump to directory",
+            super::super::FuzzyListPurpose::JumpList,
+            vec![
+                FuzzyListItem {
+                    label: "/home/me/src".to_string(),
+                    detail: String::new(),
+                },
+                FuzzyListItem {
+                    label: long,
+                    detail: String::new(),
+                },
+            ],
+        );
+        if let DialogKind::FuzzyList {
+            query, cursor_pos, ..
+        } = &mut kind
+        {
+            *query = "trgt".to_string();
+            *cursor_pos = 4;
+        }
+        kind.refilter_fuzzy_list();
+
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        let dialog = Dialog::new(&kind);
+        let dialog_area = dialog.calculate_area(area);
+        dialog.render(area, &mut buf);
+
+        let row_y = dialog_area.y + DIALOG_V_PADDING + 2;
+        let row_x = dialog_area.x + DIALOG_H_PADDING;
+        let row: String = (row_x..dialog_area.x + dialog_area.width - DIALOG_H_PADDING)
+            .filter_map(|x| buf.cell((x, row_y)).map(|cell| cell.symbol().to_string()))
+            .collect();
+        assert!(row.starts_with('…'), "{}", row);
+        assert!(row.trim_end().ends_with("/target"), "{}", row);
+        let bold: String = (row_x..dialog_area.x + dialog_area.width)
+            .filter_map(|x| buf.cell((x, row_y)))
+            .filter(|cell| cell.modifier.contains(Modifier::BOLD))
+            .map(|cell| cell.symbol().to_string())
+            .collect();
+        assert_eq!(bold, "trgt");
+    }
 }
@@ -399,6 +399,11 @@ impl<'a> DropdownMenu<'a> {
         self
     }
 
+    /// 메뉴 상자 크기 (너비, 높이)
+    pub fn size(&self) -> (u16, u16) {
+        (self.calculate_width(), self.menu.items.len() as u16 + 2)
+    }
+
     /// 메뉴의 너비 계산
     fn calculate_width(&self) -> u16 {
         let max_label = self
@@ -636,6 +641,19 @@ fn menu_action(id: &str, label: &str) -> MenuItem {
     item
 }
 
+/// 항목 컨텍스트 메뉴 생성 (그룹 사이에 구분선)
+pub fn create_context_menu(language: Language, groups: &[Vec<&str>]) -> Menu {
+    let i18n = I18n::new(language);
+    let mut items = Vec::new();
+    for group in groups.iter().filter(|group| !group.is_empty()) {
+        if !items.is_empty() {
+            items.push(MenuItem::separator());
+        }
+        items.extend(group.iter().map(|id| menu_action(id, i18n.menu_item(id))));
+    }
+    Menu::new("context", "").items(items)
+}
+
 /// 기본 메뉴 생성
 pub fn create_default_menus(language: Language) -> Vec<Menu> {
     let i18n = I18n::new(language);
@@ -644,13 +662,19 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
             .hotkey('f')
             .items(vec![
                 menu_action("new_dir", i18n.menu_item("new_dir")),
+                menu_action("new_file", i18n.menu_item("new_file")),
                 MenuItem::separator(),
                 menu_action("open_default", i18n.menu_item("open_default")),
                 menu_action(
                     "open_terminal_editor",
                     i18n.menu_item("open_terminal_editor"),
                 ),
+                menu_action(
+                    "reveal_in_file_manager",
+                    i18n.menu_item("reveal_in_file_manager"),
+                ),
                 menu_action("run_shell_command", i18n.menu_item("run_shell_command")),
+                menu_action("run_executable", i18n.menu_item("run_executable")),
                 MenuItem::separator(),
                 menu_action("archive_compress", i18n.menu_item("archive_compress")),
                 menu_action("archive_extract", i18n.menu_item("archive_extract")),
@@ -659,8 +683,17 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
                     i18n.menu_item("archive_extract_auto"),
                 ),
                 menu_action("archive_preview", i18n.menu_item("archive_preview")),
+                menu_action(
+                    "archive_name_encoding",
+                    i18n.menu_item("archive_name_encoding"),
+                ),
+                menu_action(
+                    "forget_archive_passwords",
+                    i18n.menu_item("forget_archive_passwords"),
+                ),
                 MenuItem::separator(),
                 menu_action("rename", i18n.menu_item("rename")),
+                menu_action("rename_via_editor", i18n.menu_item("rename_via_editor")),
                 menu_action("delete", i18n.menu_item("delete")),
                 menu_action("perm_delete", i18n.menu_item("perm_delete")),
                 MenuItem::separator(),
@@ -676,12 +709,26 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
                 menu_action("invert_selection", i18n.menu_item("invert_selection")),
                 menu_action("deselect", i18n.menu_item("deselect")),
             ]),
+        Menu::new("compare", i18n.tr(TextKey::MenuCompare))
+            .hotkey('c')
+            .items(vec![
+                menu_action("compare_files", i18n.menu_item("compare_files")),
+                menu_action("sync_panels", i18n.menu_item("sync_panels")),
+                MenuItem::separator(),
+                menu_action("git_diff", i18n.menu_item("git_diff")),
+                menu_action("git_discard", i18n.menu_item("git_discard")),
+            ]),
         Menu::new("view", i18n.tr(TextKey::MenuView))
             .hotkey('v')
             .items(vec![
                 menu_action("refresh", i18n.menu_item("refresh")),
+                menu_action("auto_refresh", i18n.menu_item("auto_refresh")),
                 menu_action("toggle_layout", i18n.menu_item("toggle_layout")),
+                menu_action("swap_panels", i18n.menu_item("swap_panels")),
+                menu_action("sync_other_panel", i18n.menu_item("sync_other_panel")),
                 menu_action("file_info", i18n.menu_item("file_info")),
+                menu_action("selection_stats", i18n.menu_item("selection_stats")),
+                menu_action("toggle_info_line", i18n.menu_item("toggle_info_line")),
                 MenuItem::separator(),
                 MenuItem::submenu(
                     "sort_by",
@@ -691,6 +738,10 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
                         menu_action("sort_size", i18n.menu_item("sort_size")),
                         menu_action("sort_date", i18n.menu_item("sort_date")),
                         menu_action("sort_ext", i18n.menu_item("sort_ext")),
+                        MenuItem::separator(),
+                        menu_action("sort_natural", i18n.menu_item("sort_natural")),
+                        menu_action("sort_locale", i18n.menu_item("sort_locale")),
+                        menu_action("sort_dirs_first", i18n.menu_item("sort_dirs_first")),
                     ],
                 ),
                 MenuItem::submenu(
@@ -706,10 +757,32 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
                 menu_action("filter_clear", i18n.menu_item("filter_clear")),
                 MenuItem::separator(),
                 menu_action("toggle_hidden", i18n.menu_item("toggle_hidden")),
+                menu_action("toggle_hidden_both", i18n.menu_item("toggle_hidden_both")),
+                menu_action(
+                    "toggle_hide_patterns",
+                    i18n.menu_item("toggle_hide_patterns"),
+                ),
+                menu_action("edit_hide_patterns", i18n.menu_item("edit_hide_patterns")),
+                menu_action(
+                    "toggle_sync_browsing",
+                    i18n.menu_item("toggle_sync_browsing"),
+                ),
+                menu_action("toggle_follow_focus", i18n.menu_item("toggle_follow_focus")),
                 menu_action("mount_points", i18n.menu_item("mount_points")),
+                menu_action("disk_usage", i18n.menu_item("disk_usage")),
                 menu_action("goto_path", i18n.menu_item("goto_path")),
+                menu_action("breadcrumbs", i18n.menu_item("breadcrumbs")),
+                menu_action("remote_connect", i18n.menu_item("remote_connect")),
                 menu_action("history_list", i18n.menu_item("history_list")),
+                menu_action("jump_list", i18n.menu_item("jump_list")),
+                menu_action("sibling_list", i18n.menu_item("sibling_list")),
+                menu_action("find_file", i18n.menu_item("find_file")),
                 menu_action("bookmark_list", i18n.menu_item("bookmark_list")),
+                menu_action("bookmark_export", i18n.menu_item("bookmark_export")),
+                menu_action("bookmark_import", i18n.menu_item("bookmark_import")),
+                menu_action("session_save", i18n.menu_item("session_save")),
+                menu_action("session_list", i18n.menu_item("session_list")),
+                menu_action("job_list", i18n.menu_item("job_list")),
                 MenuItem::submenu(
                     "size_format",
                     i18n.menu_group("size_format"),
@@ -726,9 +799,11 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
                     "theme",
                     i18n.menu_group("theme"),
                     vec![
-                        menu_action("theme_dark", "Dark (기본)"),
-                        menu_action("theme_light", "Light"),
-                        menu_action("theme_contrast", "High Contrast"),
+                        menu_action("theme_dark", i18n.menu_item("theme_dark")),
+                        menu_action("theme_light", i18n.menu_item("theme_light")),
+                        menu_action("theme_contrast", i18n.menu_item("theme_contrast")),
+                        MenuItem::separator(),
+                        menu_action("theme_list", i18n.menu_item("theme_list")),
                     ],
                 ),
                 MenuItem::submenu(
@@ -739,6 +814,23 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
                         menu_action("language_ko", "한국어"),
                     ],
                 ),
+                MenuItem::submenu(
+                    "copy_options",
+                    i18n.menu_group("copy_options"),
+                    vec![
+                        menu_action("copy_verify", i18n.menu_item("copy_verify")),
+                        menu_action("copy_preserve_times", i18n.menu_item("copy_preserve_times")),
+                        menu_action(
+                            "copy_preserve_permissions",
+                            i18n.menu_item("copy_preserve_permissions"),
+                        ),
+                        menu_action(
+                            "copy_preserve_xattrs",
+                            i18n.menu_item("copy_preserve_xattrs"),
+                        ),
+                        menu_action("copy_update_only", i18n.menu_item("copy_update_only")),
+                    ],
+                ),
                 MenuItem::submenu(
                     "default_editor",
                     i18n.menu_group("default_editor"),
@@ -751,11 +843,15 @@ pub fn create_default_menus(language: Language) -> Vec<Menu> {
                 ),
                 MenuItem::separator(),
                 menu_action("toggle_icons", i18n.menu_item("toggle_icons")),
+                menu_action("toggle_git_status", i18n.menu_item("toggle_git_status")),
+                menu_action("toggle_path_line", i18n.menu_item("toggle_path_line")),
+                menu_action("toggle_type_column", i18n.menu_item("toggle_type_column")),
             ]),
         Menu::new("help", i18n.tr(TextKey::MenuHelp))
             .hotkey('h')
             .items(vec![
                 menu_action("help_keys", i18n.menu_item("help_keys")),
+                menu_action("command_palette", i18n.menu_item("command_palette")),
                 menu_action("about", i18n.menu_item("about")),
             ]),
     ]
@@ -802,10 +898,25 @@ mod tests {
     #[test]
     fn test_default_menus() {
         let menus = create_default_menus(Language::English);
-        assert_eq!(menus.len(), 5);
+        assert_eq!(menus.len(), 6);
         assert_eq!(menus[0].title, "File(F)");
     }
 
+    #[test]
+    fn test_context_menu_separates_non_empty_groups() {
+        let menu = create_context_menu(
+            Language::English,
+            &[vec!["copy", "move"], vec![], vec!["file_info"]],
+        );
+        let ids: Vec<&str> = menu.items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["copy", "move", "", "file_info"]);
+        assert!(menu.items[2].is_separator());
+        assert_eq!(menu.items[0].label, "Copy");
+        assert_eq!(menu.items[0].shortcut.as_deref(), Some("y"));
+        let state = MenuState::new();
+        assert_eq!(DropdownMenu::new(&menu, &state).size().1, 6);
+    }
+
     #[test]
     fn test_calculate_width_uses_unicode_display_width() {
         let menu = Menu::new("test", "테스트").items(vec![
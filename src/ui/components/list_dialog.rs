@@ -0,0 +1,225 @@
+// List dialog state - 선택 목록 다이얼로그 공용 상태
+//
+// 마운트 포인트 / 탭 / 히스토리 / 북마크 / 세션 / 작업 / 원격 / 테마 / 압축 미리보기 목록이
+// 함께 쓰는 선택 이동, 페이지 이동, 검색어 거르기
+
+/// 목록 선택 이동
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListMove {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    First,
+    Last,
+}
+
+/// 한 페이지 기본 줄 수
+const DEFAULT_PAGE: usize = 10;
+
+/// 항목 목록 + 선택 위치 (검색어가 있으면 맞는 항목만 보임)
+#[derive(Debug, Clone)]
+pub struct ListDialogState<T> {
+    pub items: Vec<T>,
+    /// 보이는 `items` 인덱스 (검색어가 없으면 전체)
+    pub visible: Vec<usize>,
+    /// `visible` 안에서의 선택 위치
+    pub selected: usize,
+    /// 첫 번째로 보이는 줄 (이동할 때마다 선택이 `page` 줄 안에 보이게 조정)
+    pub scroll: usize,
+    page: usize,
+    /// 검색어 (대소문자 무시)
+    pub filter: String,
+    /// 검색어 입력 중
+    pub searching: bool,
+    matcher: Option<fn(&T, &str) -> bool>,
+}
+
+impl<T> ListDialogState<T> {
+    /// 첫 항목을 선택한 목록
+    pub fn new(items: Vec<T>) -> Self {
+        let visible = (0..items.len()).collect();
+        Self {
+            items,
+            visible,
+            selected: 0,
+            scroll: 0,
+            page: DEFAULT_PAGE,
+            filter: String::new(),
+            searching: false,
+            matcher: None,
+        }
+    }
+
+    /// 선택 위치 지정 (범위를 넘으면 마지막 항목)
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected.min(self.visible.len().saturating_sub(1));
+        self.keep_selected_visible();
+        self
+    }
+
+    /// 한 페이지 줄 수 (PageUp/PageDown 이동량, 스크롤 창 높이)
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page.max(1);
+        self.keep_selected_visible();
+        self
+    }
+
+    /// `/` 검색 사용 (항목이 소문자 검색어에 맞는지 판단하는 함수)
+    pub fn with_search(mut self, matcher: fn(&T, &str) -> bool) -> Self {
+        self.matcher = Some(matcher);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// 선택한 항목의 `items` 인덱스
+    pub fn selected_index(&self) -> Option<usize> {
+        self.visible.get(self.selected).copied()
+    }
+
+    pub fn selected_item(&self) -> Option<&T> {
+        self.items.get(self.selected_index()?)
+    }
+
+    /// 선택 이동 (끝에서 멈춤)
+    pub fn move_selection(&mut self, movement: ListMove) {
+        let last = self.visible.len().saturating_sub(1);
+        self.selected = match movement {
+            ListMove::Up => self.selected.saturating_sub(1),
+            ListMove::Down => (self.selected + 1).min(last),
+            ListMove::PageUp => self.selected.saturating_sub(self.page),
+            ListMove::PageDown => (self.selected + self.page).min(last),
+            ListMove::First => 0,
+            ListMove::Last => last,
+        };
+        self.keep_selected_visible();
+    }
+
+    /// 검색어 변경 (맞는 항목만 남기고 선택 초기화)
+    pub fn set_filter(&mut self, value: String) {
+        let needle = value.to_lowercase();
+        self.visible = match self.matcher {
+            Some(matcher) if !needle.is_empty() => self
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| matcher(item, &needle))
+                .map(|(index, _)| index)
+                .collect(),
+            _ => (0..self.items.len()).collect(),
+        };
+        self.filter = value;
+        self.selected = 0;
+        self.scroll = 0;
+    }
+
+    /// 검색을 쓰는 목록이면 true
+    pub fn searchable(&self) -> bool {
+        self.matcher.is_some()
+    }
+
+    /// 검색어 입력 시작 (검색을 쓰지 않는 목록이면 무시)
+    pub fn start_search(&mut self) {
+        self.searching = self.searchable();
+    }
+
+    /// 검색어 편집 (`None`이면 한 글자 지움)
+    pub fn edit_search(&mut self, input: Option<char>) {
+        let mut value = self.filter.clone();
+        match input {
+            Some(c) => value.push(c),
+            None => {
+                value.pop();
+            }
+        }
+        self.set_filter(value);
+    }
+
+    /// 검색어 입력 종료 (`keep_filter`가 false면 검색어도 지움)
+    pub fn finish_search(&mut self, keep_filter: bool) {
+        if !keep_filter {
+            self.set_filter(String::new());
+        }
+        self.searching = false;
+    }
+
+    fn keep_selected_visible(&mut self) {
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + self.page {
+            self.scroll = self.selected + 1 - self.page;
+        }
+    }
+}
+
+/// 항목 종류와 상관없이 목록 다이얼로그를 다루는 공통 동작 (키 처리용)
+pub trait ListNavigation {
+    fn move_selection(&mut self, movement: ListMove);
+    fn start_search(&mut self);
+    fn edit_search(&mut self, input: Option<char>);
+    fn finish_search(&mut self, keep_filter: bool);
+}
+
+impl<T> ListNavigation for ListDialogState<T> {
+    fn move_selection(&mut self, movement: ListMove) {
+        ListDialogState::move_selection(self, movement);
+    }
+
+    fn start_search(&mut self) {
+        ListDialogState::start_search(self);
+    }
+
+    fn edit_search(&mut self, input: Option<char>) {
+        ListDialogState::edit_search(self, input);
+    }
+
+    fn finish_search(&mut self, keep_filter: bool) {
+        ListDialogState::finish_search(self, keep_filter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_dialog_moves_pages_and_scrolls() {
+        let mut list = ListDialogState::new((0..30).collect::<Vec<_>>()).with_page(12);
+        list.move_selection(ListMove::Up);
+        assert_eq!(list.selected, 0);
+        list.move_selection(ListMove::PageDown);
+        list.move_selection(ListMove::Down);
+        assert_eq!((list.selected, list.scroll), (13, 2));
+        list.move_selection(ListMove::Last);
+        assert_eq!((list.selected, list.scroll), (29, 18));
+        list.move_selection(ListMove::PageUp);
+        list.move_selection(ListMove::First);
+        assert_eq!((list.selected, list.scroll), (0, 0));
+        assert_eq!(
+            ListDialogState::new(vec![1, 2]).with_selected(9).selected,
+            1
+        );
+    }
+
+    #[test]
+    fn test_list_dialog_search_filters_visible_items() {
+        let mut list = ListDialogState::new(vec!["Alpha", "beta", "gamma"])
+            .with_search(|item, needle| item.to_lowercase().contains(needle));
+        list.start_search();
+        list.edit_search(Some('A'));
+        list.edit_search(Some('m'));
+        assert_eq!(list.visible, vec![2]);
+        assert_eq!(list.selected_item(), Some(&"gamma"));
+        list.edit_search(None);
+        assert_eq!(list.len(), 3);
+        list.finish_search(false);
+        assert!(!list.searching && list.filter.is_empty());
+
+        let mut plain = ListDialogState::new(vec![1]);
+        plain.start_search();
+        assert!(!plain.searching);
+    }
+}
@@ -2,16 +2,23 @@
 pub mod command_bar;
 pub mod dialog;
 pub mod dropdown_menu;
+pub mod list_dialog;
 pub mod menu_bar;
 pub mod panel;
+pub mod path_input;
 pub mod status_bar;
 pub mod warning;
 
 // Re-export components for convenience
 pub use command_bar::CommandBar;
-pub use dialog::{Dialog, DialogKind, InputPurpose};
-pub use dropdown_menu::{create_default_menus, DropdownMenu, Menu, MenuState};
+pub use dialog::{
+    BookmarkEditField, BookmarkListItem, BookmarkListRow, Dialog, DialogKind, DiskUsageItem,
+    FuzzyListItem, FuzzyListPurpose, HistoryListItem, InputPurpose, MountPointItem,
+};
+pub use dropdown_menu::{create_context_menu, create_default_menus, DropdownMenu, Menu, MenuState};
+pub use list_dialog::{ListDialogState, ListMove};
 pub use menu_bar::MenuBar;
 pub use panel::{Panel, PanelStatus};
+pub use path_input::PathCompletion;
 pub use status_bar::StatusBar;
 pub use warning::WarningScreen;
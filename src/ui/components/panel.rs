@@ -4,14 +4,17 @@
 // 파일 리스트 표시, 선택 상태, 테두리 렌더링
 
 use crate::app::SizeFormat;
-use crate::models::file_entry::{FileEntry, FileType};
+use crate::core::plugins::PluginColumn;
+use crate::models::file_entry::{FileEntry, FileType, ReparseKind};
 use crate::models::panel_state::{SortBy, SortOrder};
+use crate::system::git::GitStatus;
+use crate::ui::highlight::HighlightRules;
 use crate::ui::{I18n, Language, TextKey, Theme};
 use crate::utils::formatter::{
     format_date, format_file_size, format_file_size_bytes, format_permissions,
 };
-use crate::utils::glob;
-use crate::utils::path_display;
+use crate::utils::name_filter::NameFilter;
+use crate::utils::{display_width, path_display};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -19,8 +22,10 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Widget},
 };
-use std::collections::HashSet;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use unicode_width::UnicodeWidthStr;
 
 /// 아이콘 표시 모드
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -46,6 +51,8 @@ pub struct Panel<'a> {
     title: &'a str,
     /// 현재 패널 탭 개수
     tab_count: usize,
+    /// 사용자가 지정한 활성 탭 이름
+    tab_label: Option<&'a str>,
     /// 패널 상태
     status: PanelStatus,
     /// 파일 목록
@@ -80,6 +87,11 @@ pub struct Panel<'a> {
     executable_color: Color,
     /// 심볼릭 링크 색상
     symlink_color: Color,
+    /// 강조/경고/오류/성공 색상 (git 상태 표시)
+    accent_color: Color,
+    warning_color: Color,
+    error_color: Color,
+    success_color: Color,
     /// 아이콘 모드
     icon_mode: IconMode,
     /// 현재 정렬 기준
@@ -90,10 +102,41 @@ pub struct Panel<'a> {
     filter_pattern: Option<&'a str>,
     /// 파일 크기 표시 형식
     size_format: SizeFormat,
+    /// 계산된 디렉토리 재귀 크기 (없으면 "-" 표시)
+    dir_sizes: Option<&'a HashMap<PathBuf, u64>>,
+    /// 항목별 git 상태 (비어 있으면 상태 컬럼 생략)
+    git_status: Option<&'a HashMap<PathBuf, GitStatus>>,
+    /// 저장소 요약 (제목 뒤에 표시, 예: `main ↑2`)
+    git_summary: Option<&'a str>,
+    /// 자동 새로고침까지 남은 초 (제목 뒤에 `⟳5s`로 흐리게 표시)
+    auto_refresh: Option<u64>,
+    /// 숨김 파일 표시 여부 (켜면 제목 뒤에 `.*`로 흐리게 표시)
+    show_hidden: bool,
+    /// 매직 넘버로 판별한 파일 종류 (있으면 종류 컬럼 표시)
+    file_types: Option<&'a HashMap<PathBuf, &'static str>>,
+    /// 플러그인 컬럼 (종류 컬럼 뒤에 순서대로, 자리가 모자라면 뒤쪽부터 생략)
+    plugin_columns: Vec<&'a PluginColumn>,
+    /// 플러그인 컬럼 값 (항목 경로 → 컬럼 순서 값, 아직 없으면 빈 칸)
+    plugin_values: Option<&'a HashMap<PathBuf, Vec<String>>>,
+    /// 패널 하단 정보 줄 (포커스 항목의 `ls -l` 형식 요약)
+    info_line: Option<&'a str>,
+    /// 포커스 항목 이름에서 건너뛸 글자 수 (긴 이름 가로 스크롤)
+    name_scroll: usize,
+    /// 경로 표시줄 모드에서 고른 조각 (제목에 강조 표시)
+    breadcrumb_focus: Option<usize>,
+    /// 나머지 목록을 읽는 중 (하단 테두리에 읽은 개수 표시)
+    loading: bool,
+    /// 파일 하이라이트 규칙 (없으면 파일 타입별 테마 색상만 사용)
+    highlight_rules: Option<&'a HighlightRules>,
+    /// 최근 수정 판단 기준 시각
+    now: SystemTime,
     /// UI 언어
     language: Language,
 }
 
+/// 패널 제목에 표시할 탭 이름 최대 폭
+const TAB_LABEL_MAX_WIDTH: usize = 16;
+
 /// 빈 HashSet을 위한 정적 참조
 static EMPTY_SELECTION: std::sync::LazyLock<HashSet<usize>> =
     std::sync::LazyLock::new(HashSet::new);
@@ -103,6 +146,7 @@ impl<'a> Default for Panel<'a> {
         Self {
             title: "",
             tab_count: 1,
+            tab_label: None,
             status: PanelStatus::default(),
             entries: &[],
             selected_index: 0,
@@ -120,11 +164,29 @@ impl<'a> Default for Panel<'a> {
             directory_color: Color::Rgb(86, 156, 214),
             executable_color: Color::Rgb(78, 201, 176),
             symlink_color: Color::Rgb(206, 145, 120),
+            accent_color: Color::Rgb(0, 120, 212),
+            warning_color: Color::Rgb(220, 180, 0),
+            error_color: Color::Rgb(244, 71, 71),
+            success_color: Color::Rgb(78, 201, 176),
             icon_mode: IconMode::default(),
             sort_by: SortBy::Name,
             sort_order: SortOrder::Ascending,
             filter_pattern: None,
             size_format: SizeFormat::default(),
+            dir_sizes: None,
+            git_status: None,
+            git_summary: None,
+            auto_refresh: None,
+            show_hidden: false,
+            info_line: None,
+            name_scroll: 0,
+            breadcrumb_focus: None,
+            loading: false,
+            file_types: None,
+            plugin_columns: Vec::new(),
+            plugin_values: None,
+            highlight_rules: None,
+            now: SystemTime::now(),
             language: Language::English,
         }
     }
@@ -147,6 +209,12 @@ impl<'a> Panel<'a> {
         self
     }
 
+    /// 활성 탭 이름 설정 (탭 개수 표시 옆에 함께 표시)
+    pub fn tab_label(mut self, label: Option<&'a str>) -> Self {
+        self.tab_label = label.filter(|label| !label.is_empty());
+        self
+    }
+
     /// 패널 상태 설정
     pub fn status(mut self, status: PanelStatus) -> Self {
         self.status = status;
@@ -220,6 +288,83 @@ impl<'a> Panel<'a> {
         self
     }
 
+    /// 계산된 디렉토리 크기 설정
+    pub fn dir_sizes(mut self, sizes: &'a HashMap<PathBuf, u64>) -> Self {
+        self.dir_sizes = Some(sizes);
+        self
+    }
+
+    /// 항목별 git 상태 설정
+    pub fn git_status(mut self, status: &'a HashMap<PathBuf, GitStatus>) -> Self {
+        self.git_status = Some(status);
+        self
+    }
+
+    /// 저장소 요약 설정
+    pub fn git_summary(mut self, summary: Option<&'a str>) -> Self {
+        self.git_summary = summary;
+        self
+    }
+
+    /// 자동 새로고침까지 남은 초 설정 (끄면 None)
+    pub fn auto_refresh(mut self, remaining: Option<u64>) -> Self {
+        self.auto_refresh = remaining;
+        self
+    }
+
+    /// 패널(탭)의 숨김 파일 표시 여부 설정
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// 종류 컬럼용 판별 결과 설정
+    pub fn file_types(mut self, types: Option<&'a HashMap<PathBuf, &'static str>>) -> Self {
+        self.file_types = types;
+        self
+    }
+
+    /// 플러그인 컬럼과 값 설정
+    pub fn plugin_columns(
+        mut self,
+        columns: Vec<&'a PluginColumn>,
+        values: &'a HashMap<PathBuf, Vec<String>>,
+    ) -> Self {
+        self.plugin_columns = columns;
+        self.plugin_values = Some(values);
+        self
+    }
+
+    /// 하단 정보 줄 설정
+    pub fn info_line(mut self, line: Option<&'a str>) -> Self {
+        self.info_line = line;
+        self
+    }
+
+    /// 경로 표시줄 모드에서 고른 조각 설정
+    pub fn breadcrumb_focus(mut self, focus: Option<usize>) -> Self {
+        self.breadcrumb_focus = focus;
+        self
+    }
+
+    /// 포커스 항목 이름 가로 스크롤 설정
+    pub fn name_scroll(mut self, offset: usize) -> Self {
+        self.name_scroll = offset;
+        self
+    }
+
+    /// 목록 읽는 중 표시 설정
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// 파일 하이라이트 규칙 설정
+    pub fn highlight_rules(mut self, rules: &'a HighlightRules) -> Self {
+        self.highlight_rules = Some(rules);
+        self
+    }
+
     pub fn language(mut self, language: Language) -> Self {
         self.language = language;
         self
@@ -256,6 +401,10 @@ impl<'a> Panel<'a> {
         self.directory_color = theme.directory.to_color();
         self.executable_color = theme.executable.to_color();
         self.symlink_color = theme.symlink.to_color();
+        self.accent_color = theme.accent.to_color();
+        self.warning_color = theme.warning.to_color();
+        self.error_color = theme.error.to_color();
+        self.success_color = theme.success.to_color();
         self
     }
 
@@ -276,16 +425,23 @@ impl<'a> Panel<'a> {
         }
     }
 
-    /// 파일 타입에 따른 아이콘 반환
-    fn file_icon(&self, file_type: &FileType) -> &str {
+    /// 파일 타입에 따른 아이콘 반환 (Windows 정션/재분석 지점은 따로 표시)
+    fn file_icon(&self, entry: &FileEntry) -> &str {
+        match (self.icon_mode, entry.reparse) {
+            (IconMode::Emoji, Some(ReparseKind::Junction)) => return "🔀",
+            (IconMode::Emoji, Some(ReparseKind::Other)) => return "💠",
+            (IconMode::Ascii, Some(ReparseKind::Junction)) => return "%",
+            (IconMode::Ascii, Some(ReparseKind::Other)) => return "~",
+            _ => {}
+        }
         match self.icon_mode {
-            IconMode::Emoji => match file_type {
+            IconMode::Emoji => match entry.file_type {
                 FileType::Directory => "📁",
                 FileType::File => "📄",
                 FileType::Executable => "🔧",
                 FileType::Symlink => "🔗",
             },
-            IconMode::Ascii => match file_type {
+            IconMode::Ascii => match entry.file_type {
                 FileType::Directory => "/",
                 FileType::File => " ",
                 FileType::Executable => "*",
@@ -294,6 +450,13 @@ impl<'a> Panel<'a> {
         }
     }
 
+    /// 엔트리 색상 반환 (선택되지 않은 상태, 하이라이트 규칙 우선)
+    fn entry_color(&self, entry: &FileEntry) -> Color {
+        self.highlight_rules
+            .and_then(|rules| rules.color_for(entry, self.now))
+            .unwrap_or_else(|| self.file_color(&entry.file_type))
+    }
+
     /// 파일 타입에 따른 색상 반환 (선택되지 않은 상태)
     fn file_color(&self, file_type: &FileType) -> Color {
         match file_type {
@@ -311,18 +474,27 @@ struct ColumnLayout {
     show_created: bool,
     show_owner: bool,
     show_size: bool,
+    show_type: bool,
+    /// 표시할 플러그인 컬럼 너비 (앞에서부터, 생략한 컬럼은 빠짐)
+    plugin_widths: Vec<usize>,
     date_format: &'static str,
     name_width: usize,
     created_width: usize,
     owner_width: usize,
     size_width: usize,
+    type_width: usize,
     date_width: usize,
     perm_width: usize,
 }
 
 impl Panel<'_> {
     /// 패널 너비 기반 컬럼 표시 여부/크기 결정
-    fn calculate_column_layout(width: usize, has_scrollbar: bool) -> ColumnLayout {
+    fn calculate_column_layout(
+        width: usize,
+        has_scrollbar: bool,
+        type_column: bool,
+        plugin_columns: &[usize],
+    ) -> ColumnLayout {
         let scrollbar_width = if has_scrollbar { 1 } else { 0 };
 
         // 우선순위: 생성일 > 소유
@@ -347,26 +519,45 @@ impl Panel<'_> {
         let owner_width = if show_owner { 10 } else { 0 };
         let date_width = if date_format == "long" { 17 } else { 12 };
         let size_width = if show_size { 10 } else { 0 };
+        // 종류 컬럼은 켜져 있고 이름 칸이 충분히 남을 때만
+        let show_type = type_column && width >= 60;
+        let type_width = if show_type { 16 } else { 0 };
         let margins = 6;
         let name_width = width
             .saturating_sub(perm_width)
             .saturating_sub(created_width)
             .saturating_sub(owner_width)
             .saturating_sub(size_width)
+            .saturating_sub(type_width)
             .saturating_sub(date_width)
             .saturating_sub(margins)
             .saturating_sub(scrollbar_width);
+        // 플러그인 컬럼은 이름 칸이 20칸 이상 남는 만큼만 (구분 공백 포함)
+        let mut plugin_widths = Vec::new();
+        let mut name_width = name_width;
+        if width >= 60 {
+            for &column_width in plugin_columns {
+                if name_width < 20 + column_width + 1 {
+                    break;
+                }
+                name_width -= column_width + 1;
+                plugin_widths.push(column_width);
+            }
+        }
 
         ColumnLayout {
             show_permissions,
             show_created,
             show_owner,
             show_size,
+            show_type,
+            plugin_widths,
             date_format,
             name_width,
             created_width,
             owner_width,
             size_width,
+            type_width,
             date_width,
             perm_width,
         }
@@ -411,6 +602,22 @@ impl Panel<'_> {
             ));
         }
 
+        if layout.show_type {
+            header_spans.push(Span::raw(" "));
+            header_spans.push(Span::styled(
+                Self::fit_cell(i18n.tr(TextKey::PanelHeaderType), layout.type_width),
+                header_style,
+            ));
+        }
+
+        for (column, &width) in self.plugin_columns.iter().zip(&layout.plugin_widths) {
+            header_spans.push(Span::raw(" "));
+            header_spans.push(Span::styled(
+                Self::fit_cell(column.title, width),
+                header_style,
+            ));
+        }
+
         let modified_label = if self.sort_by == SortBy::Modified {
             format!("{} {}", i18n.tr(TextKey::PanelHeaderModified), arrow)
         } else {
@@ -486,6 +693,21 @@ impl Panel<'_> {
         *y += 1;
     }
 
+    /// git 상태 컬럼 표시 여부 (저장소 밖이거나 변경이 없으면 생략)
+    fn show_git_column(&self) -> bool {
+        self.git_status.is_some_and(|status| !status.is_empty())
+    }
+
+    fn git_status_color(&self, status: GitStatus) -> Color {
+        match status {
+            GitStatus::Ignored => self.inactive_border_color,
+            GitStatus::Untracked => self.accent_color,
+            GitStatus::Staged => self.success_color,
+            GitStatus::Modified => self.warning_color,
+            GitStatus::Conflicted => self.error_color,
+        }
+    }
+
     /// 단일 파일 행 렌더링
     fn render_file_entry(
         &self,
@@ -515,7 +737,7 @@ impl Panel<'_> {
                 " ",
             ),
             (false, true) => (self.file_marked_color, None, "*"),
-            (false, false) => (self.file_color(&entry.file_type), None, " "),
+            (false, false) => (self.entry_color(entry), None, " "),
         };
 
         let style = if let Some(bg_color) = bg {
@@ -536,9 +758,31 @@ impl Panel<'_> {
 
         let mut line_spans = vec![Span::styled(marker, marker_style)];
 
+        // git 상태 (상태 없는 항목은 공백)
+        let git_width = usize::from(self.show_git_column());
+        if git_width > 0 {
+            let status = self
+                .git_status
+                .and_then(|status| status.get(&entry.path).copied());
+            let git_style = match (status, bg) {
+                (Some(status), Some(bg_color)) => Style::default()
+                    .fg(self.git_status_color(status))
+                    .bg(bg_color),
+                (Some(status), None) => Style::default().fg(self.git_status_color(status)),
+                (None, _) => style,
+            };
+            let symbol = status.map_or(' ', GitStatus::marker);
+            line_spans.push(Span::styled(symbol.to_string(), git_style));
+        }
+
         // 아이콘 + 파일명 (필터 하이라이트 지원)
-        let icon = self.file_icon(&entry.file_type);
-        let display_name = self.truncate_name(&entry.name, layout.name_width.saturating_sub(4));
+        let icon = self.file_icon(entry);
+        let name_width = layout.name_width.saturating_sub(4 + git_width);
+        let display_name = if is_cursor && self.name_scroll > 0 {
+            Self::scrolled_name(&entry.name, name_width, self.name_scroll)
+        } else {
+            self.truncate_name(&entry.name, name_width)
+        };
         let icon_str = format!("{} ", icon);
         line_spans.push(Span::styled(&icon_str, style));
 
@@ -553,60 +797,91 @@ impl Panel<'_> {
                 .add_modifier(Modifier::UNDERLINED)
         };
 
-        if let Some(pattern) = self.filter_pattern {
-            if !pattern.is_empty() && !glob::is_glob_pattern(pattern) {
-                // contains 매칭: 매칭 부분만 하이라이트
+        let name_filter = self.filter_pattern.map(NameFilter::parse);
+        if let Some(name_filter) = name_filter.filter(|f| !f.is_empty()) {
+            if let Some(pattern_lower) = name_filter.single_text_term() {
+                // 단일 contains 매칭: 매칭 부분만 하이라이트
+                // find()는 바이트 위치이므로 소문자 변환으로 길이가 바뀌지 않은 경우만 분할
                 let name_lower = display_name.to_lowercase();
-                let pattern_lower = pattern.to_lowercase();
-                if let Some(pos) = name_lower.find(&pattern_lower) {
-                    let before: String = display_name.chars().take(pos).collect();
-                    let matched: String = display_name
-                        .chars()
-                        .skip(pos)
-                        .take(pattern_lower.len())
-                        .collect();
-                    let after: String = display_name
-                        .chars()
-                        .skip(pos + pattern_lower.len())
-                        .collect();
-                    line_spans.push(Span::styled(before, style));
-                    line_spans.push(Span::styled(matched, highlight_style));
-                    line_spans.push(Span::styled(after, style));
+                let match_range = name_lower
+                    .find(pattern_lower)
+                    .map(|pos| pos..pos + pattern_lower.len())
+                    .filter(|range| {
+                        name_lower.len() == display_name.len()
+                            && display_name.is_char_boundary(range.start)
+                            && display_name.is_char_boundary(range.end)
+                    });
+                if let Some(range) = match_range {
+                    line_spans.push(Span::styled(&display_name[..range.start], style));
+                    line_spans.push(Span::styled(&display_name[range.clone()], highlight_style));
+                    line_spans.push(Span::styled(&display_name[range.end..], style));
                 } else {
                     line_spans.push(Span::styled(&display_name, style));
                 }
-            } else if glob::is_glob_pattern(pattern) {
-                // glob 매칭: 전체 이름에 하이라이트 스타일
-                line_spans.push(Span::styled(&display_name, highlight_style));
             } else {
-                line_spans.push(Span::styled(&display_name, style));
+                // glob/정규식/다중 패턴: 전체 이름에 하이라이트 스타일
+                line_spans.push(Span::styled(&display_name, highlight_style));
             }
         } else {
             line_spans.push(Span::styled(&display_name, style));
         }
 
-        let name_with_icon_width = icon_str.width() + display_name.width();
-        let name_padding = layout.name_width.saturating_sub(name_with_icon_width + 1);
+        let name_with_icon_width =
+            display_width::display_width(&icon_str) + display_width::display_width(&display_name);
+        let name_padding = layout
+            .name_width
+            .saturating_sub(name_with_icon_width + 1 + git_width);
         line_spans.push(Span::styled(" ".repeat(name_padding), style));
 
         // 크기
         if layout.show_size {
             line_spans.push(Span::styled(" ", style));
-            let size_str = if entry.is_directory() {
-                "-".to_string()
+            let size = if entry.is_directory() {
+                self.dir_sizes
+                    .and_then(|sizes| sizes.get(&entry.path).copied())
             } else {
-                match self.size_format {
-                    SizeFormat::Auto => format_file_size(entry.size),
-                    SizeFormat::Bytes => format_file_size_bytes(entry.size),
-                }
+                Some(entry.size)
+            };
+            let size_str = match size {
+                _ if entry.metadata_pending && !entry.is_directory() => "…".to_string(),
+                None => "-".to_string(),
+                Some(size) => match self.size_format {
+                    SizeFormat::Auto => format_file_size(size),
+                    SizeFormat::Bytes => format_file_size_bytes(size),
+                },
             };
             line_spans.push(Span::styled(format!("{:>9}", size_str), style));
         }
 
-        // 날짜 (format_date()는 항상 "YYYY-MM-DD HH:MM" 16자 반환)
+        if layout.show_type {
+            line_spans.push(Span::styled(" ", style));
+            let type_str = self
+                .file_types
+                .and_then(|types| types.get(&entry.path).copied())
+                .unwrap_or("");
+            line_spans.push(Span::styled(
+                Self::fit_cell(type_str, layout.type_width),
+                style,
+            ));
+        }
+
+        let plugin_values = self
+            .plugin_values
+            .and_then(|values| values.get(&entry.path));
+        for (index, &width) in layout.plugin_widths.iter().enumerate() {
+            line_spans.push(Span::styled(" ", style));
+            let value = plugin_values
+                .and_then(|values| values.get(index))
+                .map_or("", String::as_str);
+            line_spans.push(Span::styled(Self::fit_cell(value, width), style));
+        }
+
+        // 날짜 (format_date()는 항상 "YYYY-MM-DD HH:MM" 16자 반환, 메타데이터를 읽기 전이면 "…")
         line_spans.push(Span::styled(" ", style));
         let full_date = format_date(entry.modified);
-        let date_str = if layout.date_format == "long" {
+        let date_str = if entry.metadata_pending {
+            "…".to_string()
+        } else if layout.date_format == "long" {
             full_date
         } else {
             // short: "MM-DD HH:MM" (11자)
@@ -621,7 +896,9 @@ impl Panel<'_> {
         if layout.show_created {
             line_spans.push(Span::styled(" ", style));
             let full_created = format_date(entry.created);
-            let created_str = if layout.date_format == "long" {
+            let created_str = if entry.metadata_pending {
+                "…".to_string()
+            } else if layout.date_format == "long" {
                 full_created
             } else {
                 full_created.get(5..).unwrap_or(&full_created).to_string()
@@ -635,7 +912,11 @@ impl Panel<'_> {
         // 권한
         if layout.show_permissions {
             line_spans.push(Span::styled(" ", style));
-            let perm_str = format_permissions(entry.permissions.as_ref());
+            let perm_str = if entry.metadata_pending {
+                "…".to_string()
+            } else {
+                format_permissions(entry.permissions.as_ref())
+            };
             line_spans.push(Span::styled(
                 Self::fit_cell(&perm_str, layout.perm_width),
                 style,
@@ -656,6 +937,71 @@ impl Panel<'_> {
         *y += 1;
     }
 
+    /// 제목 줄 구성 (탭 표시 문자열, 경로 표시줄에 쓸 너비, 저장소 요약, 숨김/자동 새로고침 표시)
+    fn title_layout(
+        &self,
+        area_width: u16,
+    ) -> (Option<String>, usize, Option<String>, Option<String>) {
+        let show_tab_count = self.tab_count > 1 || self.tab_label.is_some();
+        let count_text = match self.tab_label {
+            Some(label) if self.tab_count > 1 => format!(
+                "[{}:{}]",
+                self.tab_count,
+                display_width::truncate_end(label, TAB_LABEL_MAX_WIDTH, "...")
+            ),
+            Some(label) => format!(
+                "[{}]",
+                display_width::truncate_end(label, TAB_LABEL_MAX_WIDTH, "...")
+            ),
+            None => format!("[{}]", self.tab_count),
+        };
+        let count_width = if show_tab_count {
+            count_text.width()
+        } else {
+            0
+        };
+        let mut title_max_width = (area_width as usize)
+            .saturating_sub(4)
+            .saturating_sub(if show_tab_count { count_width + 1 } else { 0 });
+        // 저장소 요약은 경로를 최소 10칸 남길 수 있을 때만 표시
+        let git_text = self
+            .git_summary
+            .map(|summary| format!("({}) ", summary))
+            .filter(|text| text.width() + 10 <= title_max_width);
+        if let Some(text) = &git_text {
+            title_max_width -= text.width();
+        }
+        let mut indicator_text = String::new();
+        if self.show_hidden {
+            indicator_text.push_str(".* ");
+        }
+        if let Some(remaining) = self.auto_refresh {
+            indicator_text.push_str(&format!("⟳{}s ", remaining));
+        }
+        let indicator_text = Some(indicator_text)
+            .filter(|text| !text.is_empty() && text.width() + 10 <= title_max_width);
+        if let Some(text) = &indicator_text {
+            title_max_width -= text.width();
+        }
+        (
+            show_tab_count.then_some(count_text),
+            title_max_width,
+            git_text,
+            indicator_text,
+        )
+    }
+
+    /// 제목 줄의 `column` 위치에 있는 경로 조각 번호 (마우스 클릭)
+    pub fn breadcrumb_at(&self, area: Rect, column: u16) -> Option<usize> {
+        let (_, title_max_width, _, _) = self.title_layout(area.width);
+        let crumbs = path_display::breadcrumbs(Path::new(self.title));
+        let spans =
+            path_display::breadcrumb_spans(&crumbs, self.breadcrumb_focus, title_max_width.max(1));
+        // 제목은 왼쪽 모서리와 공백 한 칸 뒤부터
+        let offset = column.checked_sub(area.x + 2)?;
+        path_display::breadcrumb_at(&spans, offset as usize)
+    }
+
     /// 빈 패널 메시지 렌더링
     fn render_empty_state(&self, inner: Rect, buf: &mut Buffer, y: u16) {
         let empty_text = Line::from(vec![Span::styled(
@@ -672,21 +1018,35 @@ impl Widget for Panel<'_> {
             return;
         }
 
-        let show_tab_count = self.tab_count > 1;
-        let count_text = format!("[{}]", self.tab_count);
-        let count_width = if show_tab_count {
-            count_text.width()
-        } else {
-            0
-        };
-        let title_max_width = (area.width as usize)
-            .saturating_sub(4)
-            .saturating_sub(if show_tab_count { count_width + 1 } else { 0 });
-        let display_title = path_display::truncate_path(self.title, title_max_width.max(1));
-        let mut title_spans = vec![Span::styled(
-            format!(" {} ", display_title),
-            self.title_style(),
-        )];
+        let (count_text, title_max_width, git_text, indicator_text) = self.title_layout(area.width);
+        let show_tab_count = count_text.is_some();
+        let count_text = count_text.unwrap_or_default();
+        let count_width = count_text.width();
+        let crumbs = path_display::breadcrumbs(Path::new(self.title));
+        let mut title_spans = vec![Span::styled(" ", self.title_style())];
+        for (text, index) in
+            path_display::breadcrumb_spans(&crumbs, self.breadcrumb_focus, title_max_width.max(1))
+        {
+            let style = match index {
+                Some(index) if Some(index) == self.breadcrumb_focus => Style::default()
+                    .fg(self.file_selected_color)
+                    .bg(self.file_selected_bg_color)
+                    .add_modifier(Modifier::BOLD),
+                Some(_) => self.title_style(),
+                None => Style::default().fg(self.inactive_border_color),
+            };
+            title_spans.push(Span::styled(text, style));
+        }
+        title_spans.push(Span::styled(" ", self.title_style()));
+        if let Some(text) = git_text {
+            title_spans.push(Span::styled(text, Style::default().fg(self.accent_color)));
+        }
+        if let Some(text) = indicator_text {
+            title_spans.push(Span::styled(
+                text,
+                Style::default().fg(self.inactive_border_color),
+            ));
+        }
         if show_tab_count {
             title_spans.push(Span::styled(
                 count_text.clone(),
@@ -706,27 +1066,55 @@ impl Widget for Panel<'_> {
                     .add_modifier(Modifier::BOLD),
             )];
         }
-
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(self.border_color()))
             .title(Line::from(title_spans))
             .style(Style::default().bg(self.bg_color));
+        if self.loading {
+            let i18n = I18n::new(self.language);
+            block = block.title_bottom(Line::from(Span::styled(
+                format!(
+                    " {} {} ",
+                    i18n.tr(TextKey::PanelLoading),
+                    self.entries.len()
+                ),
+                Style::default().fg(self.accent_color),
+            )));
+        }
 
-        let inner = block.inner(area);
+        let mut inner = block.inner(area);
         block.render(area, buf);
 
         if inner.height < 3 {
             return;
         }
 
+        // 하단 정보 줄 (목록이 최소 3줄 남을 때만)
+        if let Some(line) = self.info_line.filter(|_| inner.height >= 4) {
+            inner.height -= 1;
+            let text = display_width::truncate_end(line, inner.width as usize, "...");
+            buf.set_string(
+                inner.x,
+                inner.y + inner.height,
+                text,
+                Style::default().fg(self.file_normal_color),
+            );
+        }
+
         // 스크롤바 필요 여부 계산 (헤더 2줄 + ".." 1줄 차감)
         let header_lines: usize = 2;
         let parent_line: usize = if self.show_parent { 1 } else { 0 };
         let file_area_height = (inner.height as usize).saturating_sub(header_lines + parent_line);
         let has_scrollbar = self.entries.len() > file_area_height;
+        let plugin_widths: Vec<usize> = self.plugin_columns.iter().map(|c| c.width).collect();
 
-        let layout = Self::calculate_column_layout(inner.width as usize, has_scrollbar);
+        let layout = Self::calculate_column_layout(
+            inner.width as usize,
+            has_scrollbar,
+            self.file_types.is_some(),
+            &plugin_widths,
+        );
         let mut y: u16 = 0;
 
         self.render_header(&layout, inner, buf, &mut y);
@@ -758,11 +1146,9 @@ impl Widget for Panel<'_> {
             if track_height > 0 && total_items > 0 {
                 let thumb_height = (track_height * track_height / total_items).max(1);
                 let max_scroll = total_items.saturating_sub(file_area_height);
-                let thumb_pos = if max_scroll == 0 {
-                    0
-                } else {
-                    self.scroll_offset * (track_height.saturating_sub(thumb_height)) / max_scroll
-                };
+                let thumb_pos = (self.scroll_offset * (track_height.saturating_sub(thumb_height)))
+                    .checked_div(max_scroll)
+                    .unwrap_or(0);
 
                 let scrollbar_x = inner.x + inner.width - 1;
                 let track_start_y = inner.y + (header_lines + parent_line) as u16;
@@ -792,13 +1178,11 @@ impl Panel<'_> {
     /// 중간 생략 방식: "very_long_fi...ated.txt" (확장자 유지)
     /// 확장자 없거나 숨김파일(.bashrc)은 끝에서 자름
     fn truncate_name(&self, name: &str, max_width: usize) -> String {
-        let display_width = name.width();
-        if display_width <= max_width {
+        if display_width::display_width(name) <= max_width {
             return name.to_string();
         }
 
         let ellipsis = "...";
-        let ellipsis_width = 3;
 
         // 확장자 분리: 마지막 '.' 기준 (숨김파일 제외)
         let (stem, ext) = match name.rfind('.') {
@@ -806,42 +1190,42 @@ impl Panel<'_> {
             _ => (name, ""),
         };
 
-        let ext_width = ext.width();
+        let reserved_width = ellipsis.len() + display_width::display_width(ext);
 
         // 확장자 + "..." 만으로 max_width 초과 시 끝에서 자르기 방식
-        if ellipsis_width + ext_width >= max_width || ext.is_empty() {
-            let mut truncated = String::new();
-            let mut current_width = 0;
-            for ch in name.chars() {
-                let ch_width = ch.width().unwrap_or(1);
-                if current_width + ch_width + ellipsis_width > max_width {
-                    truncated.push_str(ellipsis);
-                    break;
-                }
-                truncated.push(ch);
-                current_width += ch_width;
-            }
-            return truncated;
+        if reserved_width >= max_width || ext.is_empty() {
+            return display_width::truncate_end(name, max_width, ellipsis);
         }
 
         // 중간 생략: stem 앞부분 + "..." + 확장자
-        let available_stem_width = max_width - ellipsis_width - ext_width;
-        let mut truncated = String::new();
-        let mut current_width = 0;
-        for ch in stem.chars() {
-            let ch_width = ch.width().unwrap_or(1);
-            if current_width + ch_width > available_stem_width {
+        let stem_prefix = display_width::take_prefix(stem, max_width - reserved_width);
+        format!("{}{}{}", stem_prefix, ellipsis, ext)
+    }
+
+    /// 앞에서 `offset`글자를 건너뛴 이름 (`…` 표시, 끝이 보이면 더 건너뛰지 않음)
+    fn scrolled_name(name: &str, max_width: usize, offset: usize) -> String {
+        if display_width::display_width(name) <= max_width || max_width < 2 {
+            return display_width::truncate_end(name, max_width, "...");
+        }
+        let mut rest = name;
+        for _ in 0..offset {
+            if display_width::display_width(rest) < max_width {
                 break;
             }
-            truncated.push(ch);
-            current_width += ch_width;
+            let mut chars = rest.chars();
+            chars.next();
+            rest = chars.as_str();
         }
-        truncated.push_str(ellipsis);
-        truncated.push_str(ext);
-        truncated
+        format!(
+            "…{}",
+            display_width::truncate_end(rest, max_width - 1, "...")
+        )
     }
 
     fn owner_text(&self, entry: &FileEntry) -> String {
+        if entry.metadata_pending {
+            return "…".to_string();
+        }
         match (entry.owner.as_deref(), entry.group.as_deref()) {
             (Some(owner), Some(group)) if owner == group => owner.to_string(),
             (Some(owner), Some(group)) => format!("{}/{}", owner, group),
@@ -852,20 +1236,7 @@ impl Panel<'_> {
     }
 
     fn fit_cell(text: &str, width: usize) -> String {
-        if width == 0 {
-            return String::new();
-        }
-        let mut out = String::new();
-        let mut used = 0usize;
-        for ch in text.chars() {
-            let w = ch.width().unwrap_or(1);
-            if used + w > width {
-                break;
-            }
-            out.push(ch);
-            used += w;
-        }
-        path_display::pad_right_to_width(&out, width)
+        display_width::fit_width(text, width)
     }
 }
 
@@ -899,6 +1270,15 @@ mod tests {
         assert_eq!(inactive_panel.status, PanelStatus::Inactive);
     }
 
+    #[test]
+    fn test_scrolled_name_skips_prefix_until_end_is_visible() {
+        let name = "0123456789abcdef.txt";
+        assert_eq!(Panel::scrolled_name(name, 12, 4), "…456789ab...");
+        // 끝이 보이면 더 건너뛰지 않음
+        assert_eq!(Panel::scrolled_name(name, 12, 100), "…9abcdef.txt");
+        assert_eq!(Panel::scrolled_name("short.txt", 12, 4), "short.txt");
+    }
+
     #[test]
     fn test_truncate_name() {
         let panel = Panel::new();
@@ -922,4 +1302,30 @@ mod tests {
         let truncated = panel.truncate_name(hidden, 15);
         assert!(truncated.ends_with("..."));
     }
+
+    #[test]
+    fn test_truncate_name_uses_display_width() {
+        let panel = Panel::new();
+
+        // 한글 파일명은 칸 수 기준으로 잘리고 확장자는 유지
+        let truncated = panel.truncate_name("가나다라마바사아자차.txt", 12);
+        assert_eq!(truncated, "가나....txt");
+        assert!(display_width::display_width(&truncated) <= 12);
+
+        // 이모지 포함 이름도 폭을 넘지 않음
+        let truncated = panel.truncate_name("😀😀😀😀😀😀😀😀", 9);
+        assert_eq!(truncated, "😀😀😀...");
+        assert!(display_width::display_width(&truncated) <= 9);
+    }
+
+    #[test]
+    fn test_plugin_columns_keep_name_width() {
+        let layout = Panel::calculate_column_layout(80, false, false, &[8, 30]);
+        assert_eq!(layout.plugin_widths, vec![8]);
+        assert!(layout.name_width >= 20);
+
+        // 좁은 패널에서는 플러그인 컬럼을 생략
+        let layout = Panel::calculate_column_layout(50, false, false, &[8]);
+        assert!(layout.plugin_widths.is_empty());
+    }
 }
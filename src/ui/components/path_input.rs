@@ -0,0 +1,153 @@
+// Path input completion - 경로 입력 자동완성 컴포넌트
+//
+// 경로 이동 / 새 디렉토리 / 복사·이동 대상 입력이 함께 쓰는 추천 목록 상태와 렌더링
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::utils::path_display;
+
+/// 자동완성 추천 목록과 현재 선택
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathCompletion {
+    pub candidates: Vec<String>,
+    pub index: Option<usize>,
+}
+
+impl PathCompletion {
+    /// 추천 목록으로 생성 (비어 있지 않으면 첫 항목 선택)
+    pub fn new(candidates: Vec<String>) -> Self {
+        let index = (!candidates.is_empty()).then_some(0);
+        Self { candidates, index }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+
+    /// 현재 선택 추천
+    pub fn selected(&self) -> Option<&str> {
+        self.candidates.get(self.index?).map(String::as_str)
+    }
+
+    /// 다음(forward) / 이전 추천으로 순환하고 새 선택 반환
+    pub fn cycle(&mut self, forward: bool) -> Option<&str> {
+        let len = self.candidates.len();
+        if len == 0 {
+            return None;
+        }
+        let next = match self.index {
+            Some(idx) if forward => (idx + 1) % len,
+            Some(idx) => (idx + len - 1) % len,
+            None => 0,
+        };
+        self.index = Some(next);
+        self.selected()
+    }
+}
+
+/// 추천 목록 위젯 (첫 줄 제목 + 선택 항목 기준 스크롤 목록)
+pub struct SuggestionList<'a> {
+    completion: &'a PathCompletion,
+    title: &'a str,
+    fg_color: Color,
+    bg_color: Color,
+    muted_color: Color,
+    selected_fg: Color,
+    selected_bg: Color,
+}
+
+impl<'a> SuggestionList<'a> {
+    pub fn new(completion: &'a PathCompletion, title: &'a str) -> Self {
+        Self {
+            completion,
+            title,
+            fg_color: Color::Rgb(212, 212, 212),
+            bg_color: Color::Rgb(30, 30, 30),
+            muted_color: Color::Gray,
+            selected_fg: Color::Black,
+            selected_bg: Color::Cyan,
+        }
+    }
+
+    /// 일반 항목 색상 (전경, 배경, 제목)
+    pub fn colors(mut self, fg: Color, bg: Color, muted: Color) -> Self {
+        self.fg_color = fg;
+        self.bg_color = bg;
+        self.muted_color = muted;
+        self
+    }
+
+    /// 선택 항목 색상
+    pub fn selected_colors(mut self, fg: Color, bg: Color) -> Self {
+        self.selected_fg = fg;
+        self.selected_bg = bg;
+        self
+    }
+}
+
+impl Widget for SuggestionList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let total = self.completion.candidates.len();
+        if total == 0 || area.height == 0 {
+            return;
+        }
+        let selected = self.completion.index.unwrap_or(0).min(total - 1);
+
+        let title_style = Style::default()
+            .fg(self.muted_color)
+            .bg(self.bg_color)
+            .add_modifier(Modifier::DIM);
+        let title = format!("{} ({}/{})", self.title, selected + 1, total);
+        buf.set_stringn(area.x, area.y, title, area.width as usize, title_style);
+
+        let visible_rows = area.height.saturating_sub(1) as usize;
+        if visible_rows == 0 {
+            return;
+        }
+        let scroll = (selected + 1).saturating_sub(visible_rows);
+        for (row, candidate) in self
+            .completion
+            .candidates
+            .iter()
+            .enumerate()
+            .skip(scroll)
+            .take(visible_rows)
+        {
+            let y = area.y + 1 + (row - scroll) as u16;
+            let marker = if row == selected { "> " } else { "  " };
+            let content_width = area.width.saturating_sub(marker.width() as u16) as usize;
+            let line = format!(
+                "{}{}",
+                marker,
+                path_display::truncate_middle(candidate, content_width)
+            );
+            let style = if row == selected {
+                Style::default().fg(self.selected_fg).bg(self.selected_bg)
+            } else {
+                Style::default().fg(self.fg_color).bg(self.bg_color)
+            };
+            buf.set_string(area.x, y, line, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_completion_cycles_both_ways() {
+        let mut completion = PathCompletion::new(vec!["a".into(), "b".into(), "c".into()]);
+        assert_eq!(completion.selected(), Some("a"));
+        assert_eq!(completion.cycle(false), Some("c"));
+        assert_eq!(completion.cycle(true), Some("a"));
+        assert_eq!(completion.cycle(true), Some("b"));
+        assert_eq!(PathCompletion::new(Vec::new()).cycle(true), None);
+    }
+}
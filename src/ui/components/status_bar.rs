@@ -4,6 +4,7 @@
 // 파일/디렉토리 개수, 총 크기, 선택된 항목 정보 표시
 
 use crate::ui::{I18n, Language, MessageKey, TextKey, Theme};
+use crate::utils::display_width::{self, display_width};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -11,7 +12,6 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Paragraph, Widget},
 };
-use unicode_width::UnicodeWidthStr;
 
 /// 상태바 컴포넌트
 pub struct StatusBar<'a> {
@@ -35,8 +35,20 @@ pub struct StatusBar<'a> {
     filter_info: Option<&'a str>,
     /// 숨김 파일 표시 여부
     show_hidden: bool,
+    /// 동기 탐색 모드 여부
+    sync_browsing: bool,
+    /// 포커스 디렉토리 따라가기 모드 여부
+    follow_focus: bool,
     /// IME 상태 표시
     ime_info: Option<&'a str>,
+    /// 활성 패널 디스크 여유/전체 용량 (포맷된 문자열)
+    disk_space: Option<(&'a str, &'a str)>,
+    /// 디스크 사용률이 높음 (경고색 표시)
+    disk_usage_high: bool,
+    /// 텍스트 입력 중 여부 (한글 IME 경고 생략)
+    text_input_active: bool,
+    /// 작업 목록을 연 뒤 새로 끝난 작업 수, 그중 실패 여부
+    jobs_unseen: (usize, bool),
     /// 배경색
     bg_color: Color,
     /// 전경색
@@ -65,7 +77,13 @@ impl<'a> Default for StatusBar<'a> {
             sort_info: None,
             filter_info: None,
             show_hidden: false,
+            sync_browsing: false,
+            follow_focus: false,
             ime_info: None,
+            disk_space: None,
+            disk_usage_high: false,
+            text_input_active: false,
+            jobs_unseen: (0, false),
             bg_color: Color::Rgb(30, 30, 30),
             fg_color: Color::Rgb(212, 212, 212),
             accent_color: Color::Rgb(0, 120, 212),
@@ -142,12 +160,48 @@ impl<'a> StatusBar<'a> {
         self
     }
 
+    /// 동기 탐색 모드 표시 여부 설정
+    pub fn sync_browsing(mut self, enabled: bool) -> Self {
+        self.sync_browsing = enabled;
+        self
+    }
+
+    /// 포커스 디렉토리 따라가기 모드 표시 여부 설정
+    pub fn follow_focus(mut self, enabled: bool) -> Self {
+        self.follow_focus = enabled;
+        self
+    }
+
     /// IME 상태 설정
     pub fn ime_info(mut self, info: Option<&'a str>) -> Self {
         self.ime_info = info;
         self
     }
 
+    /// 디스크 여유/전체 용량 설정
+    pub fn disk_space(mut self, space: Option<(&'a str, &'a str)>) -> Self {
+        self.disk_space = space;
+        self
+    }
+
+    /// 디스크 사용률 경고 여부 설정
+    pub fn disk_usage_high(mut self, high: bool) -> Self {
+        self.disk_usage_high = high;
+        self
+    }
+
+    /// 텍스트 입력 중 여부 설정
+    pub fn text_input_active(mut self, active: bool) -> Self {
+        self.text_input_active = active;
+        self
+    }
+
+    /// 새로 끝난 작업 수 / 실패 여부 설정
+    pub fn jobs_unseen(mut self, count: usize, failed: bool) -> Self {
+        self.jobs_unseen = (count, failed);
+        self
+    }
+
     /// 배경색 설정
     pub fn bg_color(mut self, color: Color) -> Self {
         self.bg_color = color;
@@ -187,7 +241,11 @@ impl Widget for StatusBar<'_> {
 
         // 토스트 메시지가 있으면 토스트만 표시
         if let Some(toast_msg) = self.toast {
-            let toast_text = format!(" {} ", toast_msg);
+            let toast_text = display_width::truncate_end(
+                &format!(" {} ", toast_msg),
+                w,
+                display_width::ELLIPSIS,
+            );
             let toast_style = Style::default().fg(self.warning_color).bg(self.bg_color);
             let line = Line::from(Span::styled(&toast_text, toast_style));
             Paragraph::new(line).render(area, buf);
@@ -263,6 +321,27 @@ impl Widget for StatusBar<'_> {
             String::new()
         };
 
+        // 동기 탐색 모드 표시
+        let mut sync_info_str = if self.sync_browsing {
+            format!("[{}] ", i18n.tr(TextKey::SyncBrowsing))
+        } else {
+            String::new()
+        };
+        if self.follow_focus {
+            sync_info_str.push_str(&format!("[{}] ", i18n.tr(TextKey::FollowFocus)));
+        }
+
+        // 새로 끝난 작업 알림
+        let (jobs_count, jobs_failed) = self.jobs_unseen;
+        let jobs_info_str = if jobs_count > 0 {
+            format!(
+                "[{}] ",
+                i18n.fmt(MessageKey::StatusJobs, &[("count", jobs_count.to_string())])
+            )
+        } else {
+            String::new()
+        };
+
         // IME 상태 표시
         let ime_info_str = if let Some(info) = self.ime_info {
             format!("[{}] ", info)
@@ -271,29 +350,68 @@ impl Widget for StatusBar<'_> {
         };
 
         // 가용 공간 계산 (unicode width 사용)
-        let right_total_width = UnicodeWidthStr::width(ime_info_str.as_str())
-            + UnicodeWidthStr::width(hidden_info_str.as_str())
-            + UnicodeWidthStr::width(filter_info_str.as_str())
-            + UnicodeWidthStr::width(sort_info_str.as_str());
-
-        let left_len = left_info.len() + selected_info.len() + pending_info.len();
+        let right_total_width = display_width(&ime_info_str)
+            + display_width(&jobs_info_str)
+            + display_width(&sync_info_str)
+            + display_width(&hidden_info_str)
+            + display_width(&filter_info_str)
+            + display_width(&sort_info_str);
+
+        let left_len = display_width(&left_info)
+            + display_width(&selected_info)
+            + display_width(&pending_info);
+
+        // 디스크 용량: 남는 공간이 있을 때만 표시
+        let disk_info_str = match self.disk_space {
+            Some((free, total)) => {
+                let text = format!(
+                    "[{}] ",
+                    i18n.fmt(
+                        MessageKey::StatusDiskFree,
+                        &[("free", free.to_string()), ("total", total.to_string())],
+                    )
+                );
+                if left_len + right_total_width + display_width(&text) < w {
+                    text
+                } else {
+                    String::new()
+                }
+            }
+            None => String::new(),
+        };
+        let right_total_width = right_total_width + display_width(&disk_info_str);
         let padding_len =
             area.width
                 .saturating_sub(left_len as u16 + right_total_width as u16) as usize;
         let padding = " ".repeat(padding_len);
 
-        // IME 상태 색상: 한글이면 노란색 경고, 영문이면 녹색
-        let ime_color = if self.ime_info == Some("한글") {
+        // IME 상태 색상: 일반 모드에서 한글이면 노란색 경고, 그 외에는 녹색
+        let ime_color = if self.ime_info == Some("한글") && !self.text_input_active {
+            self.warning_color
+        } else {
+            self.success_color
+        };
+
+        let jobs_color = if jobs_failed {
             self.warning_color
         } else {
             self.success_color
         };
 
+        let disk_color = if self.disk_usage_high {
+            self.warning_color
+        } else {
+            self.muted_color
+        };
+
         let spans = vec![
             Span::styled(&left_info, Style::default().fg(self.fg_color)),
             Span::styled(&selected_info, Style::default().fg(self.warning_color)),
             Span::styled(&pending_info, Style::default().fg(self.accent_color)),
             Span::raw(padding),
+            Span::styled(disk_info_str, Style::default().fg(disk_color)),
+            Span::styled(jobs_info_str, Style::default().fg(jobs_color)),
+            Span::styled(sync_info_str, Style::default().fg(self.accent_color)),
             Span::styled(hidden_info_str, Style::default().fg(self.warning_color)),
             Span::styled(filter_info_str, Style::default().fg(self.success_color)),
             Span::styled(sort_info_str, Style::default().fg(self.accent_color)),
@@ -322,6 +440,37 @@ mod tests {
         assert_eq!(status_bar.total_size, "1.2GB");
     }
 
+    #[test]
+    fn test_status_bar_shows_disk_space_when_it_fits() {
+        let render = |width: u16| {
+            let area = Rect::new(0, 0, width, 1);
+            let mut buf = Buffer::empty(area);
+            StatusBar::new()
+                .disk_space(Some(("12.0G", "500.0G")))
+                .render(area, &mut buf);
+            (0..width)
+                .map(|x| buf[(x, 0)].symbol().to_string())
+                .collect::<String>()
+        };
+        assert!(render(100).contains("[12.0G free of 500.0G]"));
+        assert!(!render(30).contains("free of"));
+    }
+
+    #[test]
+    fn test_status_bar_shows_sync_indicator() {
+        let area = Rect::new(0, 0, 80, 1);
+        let mut buf = Buffer::empty(area);
+        StatusBar::new().sync_browsing(true).render(area, &mut buf);
+        let text: String = (0..80).map(|x| buf[(x, 0)].symbol().to_string()).collect();
+        assert!(text.contains("[SYNC]"));
+
+        let mut buf = Buffer::empty(area);
+        StatusBar::new().follow_focus(true).render(area, &mut buf);
+        let text: String = (0..80).map(|x| buf[(x, 0)].symbol().to_string()).collect();
+        assert!(text.contains("[FOLLOW]"));
+        assert!(!text.contains("[SYNC]"));
+    }
+
     #[test]
     fn test_status_bar_with_ime() {
         let status_bar = StatusBar::new().ime_info(Some("한글"));
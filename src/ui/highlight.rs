@@ -0,0 +1,270 @@
+//! 파일 하이라이트 규칙
+//!
+//! 확장자 그룹별 색상과 속성(실행 파일, 심볼릭 링크, 깨진 링크, 최근 수정, 숨김)별
+//! 특수 색상을 정의합니다. 테마의 `[highlight]` 섹션에서 로드하며,
+//! `~/.boksldir/highlight.toml`이 있으면 테마와 무관하게 전역 규칙으로 사용합니다.
+
+use crate::models::file_entry::{FileEntry, FileType};
+use crate::ui::theme::ColorDef;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// 기본 최근 수정 기준 (분)
+pub const DEFAULT_RECENT_MINUTES: u64 = 60;
+
+/// 확장자 그룹 규칙
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighlightGroup {
+    pub name: String,
+    pub color: ColorDef,
+    /// 점 없는 확장자 목록 (대소문자 무시)
+    pub extensions: Vec<String>,
+}
+
+/// 파일 하이라이트 규칙 집합
+///
+/// 속성 색상이 None이면 테마의 기본 색상(실행 파일/심볼릭 링크/일반 파일)을 사용합니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HighlightRules {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executable: Option<ColorDef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symlink: Option<ColorDef>,
+    pub broken_symlink: ColorDef,
+    /// 최근 수정 파일 색상 (None이면 비활성)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recent: Option<ColorDef>,
+    pub recent_minutes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<ColorDef>,
+    pub groups: Vec<HighlightGroup>,
+}
+
+fn group(name: &str, color: &str, extensions: &[&str]) -> HighlightGroup {
+    HighlightGroup {
+        name: name.to_string(),
+        color: color.into(),
+        extensions: extensions.iter().map(|ext| ext.to_string()).collect(),
+    }
+}
+
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "rs", "c", "h", "cc", "cpp", "hpp", "cs", "go", "java", "kt", "swift", "py", "rb", "js", "jsx",
+    "ts", "tsx", "php", "lua", "sh", "bash", "zsh", "ps1", "sql", "html", "css", "scss", "vue",
+    "toml", "yaml", "yml", "json", "xml",
+];
+const ARCHIVE_EXTENSIONS: &[&str] = &[
+    "zip", "tar", "gz", "tgz", "bz2", "tbz2", "xz", "txz", "zst", "tzst", "7z", "rar", "jar",
+    "deb", "rpm", "iso", "dmg",
+];
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "bmp", "svg", "webp", "ico", "tif", "tiff", "heic", "psd",
+];
+const DOCUMENT_EXTENSIONS: &[&str] = &[
+    "md", "txt", "pdf", "doc", "docx", "xls", "xlsx", "ppt", "pptx", "odt", "ods", "odp", "hwp",
+    "hwpx", "rtf", "csv", "epub",
+];
+
+impl HighlightRules {
+    fn with_palette(
+        broken: &str,
+        recent: &str,
+        source: &str,
+        archive: &str,
+        image: &str,
+        document: &str,
+    ) -> Self {
+        Self {
+            executable: None,
+            symlink: None,
+            broken_symlink: broken.into(),
+            recent: Some(recent.into()),
+            recent_minutes: DEFAULT_RECENT_MINUTES,
+            hidden: None,
+            groups: vec![
+                group("source", source, SOURCE_EXTENSIONS),
+                group("archive", archive, ARCHIVE_EXTENSIONS),
+                group("image", image, IMAGE_EXTENSIONS),
+                group("document", document, DOCUMENT_EXTENSIONS),
+            ],
+        }
+    }
+
+    /// Dark 테마 기본 규칙
+    pub fn dark() -> Self {
+        Self::with_palette(
+            "#f44747", "#dcdcaa", "#9cdcfe", "#d16969", "#c586c0", "#b5cea8",
+        )
+    }
+
+    /// Light 테마 기본 규칙
+    pub fn light() -> Self {
+        Self::with_palette(
+            "#e51400", "#795e26", "#001080", "#a31515", "#af00db", "#098658",
+        )
+    }
+
+    /// High Contrast 테마 기본 규칙
+    pub fn high_contrast() -> Self {
+        Self::with_palette(
+            "#ff0000", "#ffff00", "#00ffff", "#ff00ff", "#ff8000", "#ffffff",
+        )
+    }
+
+    /// TOML 파일에서 규칙 로드
+    pub fn from_file(path: &Path) -> Result<Self, anyhow::Error> {
+        let content = fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// 확장자 그룹 색상 (대소문자 무시)
+    fn group_color(&self, name: &str) -> Option<Color> {
+        let (_, ext) = name.rsplit_once('.')?;
+        if ext.is_empty() {
+            return None;
+        }
+        self.groups
+            .iter()
+            .find(|group| {
+                group
+                    .extensions
+                    .iter()
+                    .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(ext))
+            })
+            .map(|group| group.color.to_color())
+    }
+
+    fn is_recent(&self, entry: &FileEntry, now: SystemTime) -> bool {
+        now.duration_since(entry.modified)
+            .is_ok_and(|age| age <= Duration::from_secs(self.recent_minutes * 60))
+    }
+
+    /// 엔트리 색상
+    ///
+    /// 우선순위: 깨진 링크 > 링크/실행 파일 > 최근 수정 > 확장자 그룹 > 숨김.
+    /// 디렉토리와 규칙이 없는 경우 None (테마 기본 색상 사용)
+    pub fn color_for(&self, entry: &FileEntry, now: SystemTime) -> Option<Color> {
+        match entry.file_type {
+            FileType::Directory => None,
+            FileType::Symlink if entry.is_broken_symlink => Some(self.broken_symlink.to_color()),
+            FileType::Symlink => self.symlink.as_ref().map(ColorDef::to_color),
+            FileType::Executable => self.executable.as_ref().map(ColorDef::to_color),
+            FileType::File => self
+                .recent
+                .as_ref()
+                .filter(|_| self.is_recent(entry, now))
+                .map(ColorDef::to_color)
+                .or_else(|| self.group_color(&entry.name))
+                .or_else(|| {
+                    self.hidden
+                        .as_ref()
+                        .filter(|_| entry.is_hidden)
+                        .map(ColorDef::to_color)
+                }),
+        }
+    }
+}
+
+impl Default for HighlightRules {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn entry(name: &str, file_type: FileType, age_secs: u64) -> FileEntry {
+        let modified = SystemTime::now() - Duration::from_secs(age_secs);
+        FileEntry::new(
+            name.to_string(),
+            PathBuf::from("/tmp").join(name),
+            file_type,
+            0,
+            modified,
+            modified,
+            None,
+            name.starts_with('.'),
+        )
+    }
+
+    const OLD: u64 = 7 * 24 * 3600;
+
+    #[test]
+    fn test_extension_groups_case_insensitive() {
+        let rules = HighlightRules::dark();
+        let now = SystemTime::now();
+        assert_eq!(
+            rules.color_for(&entry("main.RS", FileType::File, OLD), now),
+            Some(Color::Rgb(0x9c, 0xdc, 0xfe))
+        );
+        assert_eq!(
+            rules.color_for(&entry("a.tar.gz", FileType::File, OLD), now),
+            Some(Color::Rgb(0xd1, 0x69, 0x69))
+        );
+        assert_eq!(
+            rules.color_for(&entry("Makefile", FileType::File, OLD), now),
+            None
+        );
+        assert_eq!(
+            rules.color_for(&entry("photos", FileType::Directory, OLD), now),
+            None
+        );
+    }
+
+    #[test]
+    fn test_attribute_priority() {
+        let mut rules = HighlightRules::dark();
+        let now = SystemTime::now();
+
+        // 최근 수정은 확장자 그룹보다 우선
+        assert_eq!(
+            rules.color_for(&entry("main.rs", FileType::File, 10), now),
+            Some(Color::Rgb(0xdc, 0xdc, 0xaa))
+        );
+
+        let mut broken = entry("link", FileType::Symlink, OLD);
+        assert_eq!(rules.color_for(&broken, now), None);
+        broken.is_broken_symlink = true;
+        assert_eq!(
+            rules.color_for(&broken, now),
+            Some(Color::Rgb(0xf4, 0x47, 0x47))
+        );
+
+        rules.executable = Some("red".into());
+        rules.hidden = Some("darkgray".into());
+        rules.recent = None;
+        assert_eq!(
+            rules.color_for(&entry("run.sh", FileType::Executable, 10), now),
+            Some(Color::Red)
+        );
+        assert_eq!(
+            rules.color_for(&entry(".env", FileType::File, 10), now),
+            Some(Color::DarkGray)
+        );
+    }
+
+    #[test]
+    fn test_partial_toml_keeps_defaults() {
+        let rules: HighlightRules = toml::from_str(
+            r##"
+recent_minutes = 5
+
+[[groups]]
+name = "logs"
+color = "#808080"
+extensions = ["log"]
+"##,
+        )
+        .unwrap();
+        assert_eq!(rules.recent_minutes, 5);
+        assert_eq!(rules.groups.len(), 1);
+        assert_eq!(rules.broken_symlink, HighlightRules::dark().broken_symlink);
+    }
+}
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+use crate::utils::name_filter::{split_regex_prefix, FilterMode, NameFilter};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Language {
     #[default]
@@ -34,7 +36,10 @@ impl Language {
 pub enum TextKey {
     Ok,
     Cancel,
+    Preview,
     Hidden,
+    SyncBrowsing,
+    FollowFocus,
     LayoutDual,
     LayoutSingle,
     LayoutWarn,
@@ -42,9 +47,18 @@ pub enum TextKey {
     SortSize,
     SortDate,
     SortExt,
+    SortMixedFlag,
+    SortNaturalFlag,
+    SortLocaleFlag,
     FilterPrefix,
+    FilterModeText,
+    FilterModeGlob,
+    FilterModeRegex,
+    FilterExcludeFlag,
+    FilterInvalidFlag,
     MenuFile,
     MenuEdit,
+    MenuCompare,
     MenuView,
     MenuSettings,
     MenuHelp,
@@ -60,31 +74,72 @@ pub enum TextKey {
     PanelHeaderCreated,
     PanelHeaderPermissions,
     PanelHeaderType,
+    PanelLoading,
     PanelHeaderOwner,
     DialogSuggestions,
     DialogSuggestionHint,
+    DialogCopyVerify,
+    DialogCopyUpdateOnly,
+    DialogScopeFilter,
+    DialogScopeFilterAll,
+    DialogCopyPreserve,
+    DialogCopyPreserveTimestamps,
+    DialogCopyPreservePermissions,
+    DialogCopyPreserveXattrs,
+    DialogCopySymlinks,
+    DialogMkdirCreates,
+    DialogMkdirEnter,
+    DialogCopySymlinksLinks,
+    DialogCopySymlinksFollow,
+    DialogCopySymlinksSkip,
     DialogTitleFileExists,
+    DialogTitleDirectoryExists,
     DialogSource,
     DialogUnknown,
     DialogTargetExists,
+    DialogTargetDirectoryExists,
+    DialogMerge,
+    DialogReplace,
     DialogOverwrite,
     DialogSkip,
     DialogOverwriteAll,
     DialogSkipAll,
+    DialogTitleFileInUse,
+    DialogFileInUse,
+    DialogRetry,
     DialogPressEscToCancel,
+    DialogProgressHintPause,
+    DialogProgressHintResume,
+    ProgressFile,
+    ProgressTotal,
+    ProgressPaused,
+    ProgressPausedBadge,
     DialogTitleDelete,
     DialogTrash,
     DialogDelete,
+    DialogTitleQuit,
+    DialogQuitRunning,
+    DialogWaitAndQuit,
+    DialogCancelJobsAndQuit,
     DialogName,
     DialogPath,
     DialogType,
     DialogSize,
     DialogModified,
     DialogPermissions,
+    DialogDetectedType,
+    DialogAttributes,
+    DialogAttrReadOnly,
+    DialogAttrHidden,
+    DialogAttrSystem,
+    DialogXattrs,
+    DialogHintXattrs,
+    DialogHintXattrsQuarantine,
     DialogContents,
     DialogSearch,
     DialogSearchActive,
     DialogNoShortcutMatches,
+    DialogNoFuzzyMatches,
     DialogHelpHint,
     DialogNewDirectory,
     DialogDirectoryName,
@@ -92,8 +147,16 @@ pub enum TextKey {
     DialogNewName,
     DialogBookmarkRename,
     DialogNewBookmarkName,
+    DialogBookmarkEditPath,
+    DialogNewBookmarkPath,
+    DialogBookmarkGroup,
+    DialogNewBookmarkGroup,
+    DialogTitleExportBookmarks,
+    DialogTitleImportBookmarks,
+    DialogPromptBookmarkFile,
     DialogFilter,
     DialogFilterPattern,
+    DialogFilterRegex,
     DialogTitleProperties,
     DialogTitleMountPoints,
     DialogTitleTabs,
@@ -101,11 +164,32 @@ pub enum TextKey {
     DialogHistoryCurrentMarker,
     DialogTitleBookmarks,
     DialogHintMoveGoClose,
-    DialogHintMoveGoClearClose,
-    DialogHintMoveGoRenameDeleteClose,
+    DialogHintTabList,
+    DialogHintProperties,
+    DialogHintPropertiesWindows,
+    DialogHintHistoryList,
+    DialogHintHistorySearch,
+    DialogHintBookmarkList,
+    DialogTitleSessions,
+    DialogTitleJobs,
+    DialogHintJobList,
+    SyncCopyToRight,
+    SyncCopyToLeft,
+    SyncDeleteLeft,
+    SyncDeleteRight,
+    DialogTitleThemes,
+    DialogHintMoveApplyReloadClose,
+    ThemeBuiltIn,
+    DialogHintMoveRestoreDeleteClose,
+    DialogTitleRemoteHosts,
+    DialogHintMoveConnectNewDeleteClose,
+    DialogTitleConnectRemote,
+    DialogPromptRemoteUrl,
     DialogTitleCreateArchive,
     DialogArchivePath,
     DialogUsePassword,
+    DialogCompression,
+    DialogArchivePresets,
     DialogPassword,
     DialogConfirmPassword,
     DialogHintArchiveCreate,
@@ -118,6 +202,20 @@ pub enum TextKey {
     DialogPromptArchivePassword,
     DialogTitleRunCommand,
     DialogPromptCommand,
+    DialogTitleSaveSession,
+    DialogPromptSessionName,
+    DialogTitleRenameTab,
+    DialogTitleScopeFilter,
+    DialogTitleNewFile,
+    DialogPromptNewFile,
+    DialogTitleRunExecutable,
+    DialogPromptRunArgs,
+    DialogPromptTabName,
+    DialogTitleAutoRefresh,
+    DialogTitleHidePatterns,
+    DialogPromptHidePatterns,
+    DialogPromptAutoRefresh,
+    DialogPromptScopeFilter,
     DialogEta,
     DialogKeyboardShortcutsTitle,
     AboutTitle,
@@ -140,12 +238,39 @@ pub enum MessageKey {
     CannotCloseLastTab,
     TabIndex,
     NoTabIndex,
+    TabRenamed,
+    TabNameCleared,
+    TabMoved,
+    TabCannotMove,
+    TabCopiedToOtherPanel,
+    TabSentToOtherPanel,
+    TabOpenedInOtherPanel,
+    AttributeTargets,
+    AttributesUpdated,
+    QuarantineRemoved,
     StatusLeftLong,
     StatusSelectedLong,
+    StatusDiskFree,
+    StatusJobs,
     LayoutDualToast,
     LayoutSingleToast,
     DialogArchivePreviewTitle,
     DialogArchivePreviewHint,
+    DialogTextViewerHint,
+    DialogFuzzyListHint,
+    DialogFuzzyListLoadingHint,
+    DialogDiskUsageTitle,
+    DialogDiskUsageHint,
+    DialogDiskUsageScanning,
+    DialogDiskUsageConfirmTrash,
+    DialogDiskUsageConfirmDelete,
+    DialogPanelSyncTitle,
+    DialogPanelSyncHint,
+    DialogPanelSyncComparing,
+    DialogPanelSyncRunning,
+    DialogFreeSpace,
+    DialogSpaceNeeded,
+    ElevationTerminalPrompt,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -168,8 +293,14 @@ impl I18n {
             (Language::Korean, TextKey::Ok) => "확인",
             (Language::English, TextKey::Cancel) => "Cancel",
             (Language::Korean, TextKey::Cancel) => "취소",
+            (Language::English, TextKey::Preview) => "Preview",
+            (Language::Korean, TextKey::Preview) => "미리보기",
             (Language::English, TextKey::Hidden) => "Hidden",
             (Language::Korean, TextKey::Hidden) => "숨김",
+            (Language::English, TextKey::SyncBrowsing) => "SYNC",
+            (Language::Korean, TextKey::SyncBrowsing) => "동기",
+            (Language::English, TextKey::FollowFocus) => "FOLLOW",
+            (Language::Korean, TextKey::FollowFocus) => "따라가기",
             (Language::English, TextKey::LayoutDual) => "DUAL",
             (Language::Korean, TextKey::LayoutDual) => "듀얼",
             (Language::English, TextKey::LayoutSingle) => "SINGLE",
@@ -184,12 +315,30 @@ impl I18n {
             (Language::Korean, TextKey::SortDate) => "날짜",
             (Language::English, TextKey::SortExt) => "Ext",
             (Language::Korean, TextKey::SortExt) => "확장자",
+            (Language::English, TextKey::SortMixedFlag) => "Mix",
+            (Language::Korean, TextKey::SortMixedFlag) => "혼합",
+            (Language::English, TextKey::SortNaturalFlag) => "Nat",
+            (Language::Korean, TextKey::SortNaturalFlag) => "자연",
+            (Language::English, TextKey::SortLocaleFlag) => "Loc",
+            (Language::Korean, TextKey::SortLocaleFlag) => "로캘",
             (Language::English, TextKey::FilterPrefix) => "Filter",
             (Language::Korean, TextKey::FilterPrefix) => "필터",
+            (Language::English, TextKey::FilterModeText) => "Text",
+            (Language::Korean, TextKey::FilterModeText) => "텍스트",
+            (Language::English, TextKey::FilterModeGlob) => "Glob",
+            (Language::Korean, TextKey::FilterModeGlob) => "글롭",
+            (Language::English, TextKey::FilterModeRegex) => "Regex",
+            (Language::Korean, TextKey::FilterModeRegex) => "정규식",
+            (Language::English, TextKey::FilterExcludeFlag) => "Excl",
+            (Language::Korean, TextKey::FilterExcludeFlag) => "제외",
+            (Language::English, TextKey::FilterInvalidFlag) => "Invalid",
+            (Language::Korean, TextKey::FilterInvalidFlag) => "오류",
             (Language::English, TextKey::MenuFile) => "File(F)",
             (Language::Korean, TextKey::MenuFile) => "파일(F)",
             (Language::English, TextKey::MenuEdit) => "Edit(E)",
             (Language::Korean, TextKey::MenuEdit) => "편집(E)",
+            (Language::English, TextKey::MenuCompare) => "Compare(C)",
+            (Language::Korean, TextKey::MenuCompare) => "비교(C)",
             (Language::English, TextKey::MenuView) => "View(V)",
             (Language::Korean, TextKey::MenuView) => "보기(V)",
             (Language::English, TextKey::MenuSettings) => "Settings(S)",
@@ -220,10 +369,40 @@ impl I18n {
             (Language::Korean, TextKey::PanelHeaderPermissions) => "권한",
             (Language::English, TextKey::PanelHeaderType) => "Type",
             (Language::Korean, TextKey::PanelHeaderType) => "종류",
+            (Language::English, TextKey::PanelLoading) => "Loading…",
+            (Language::Korean, TextKey::PanelLoading) => "읽는 중…",
             (Language::English, TextKey::PanelHeaderOwner) => "Owner",
             (Language::Korean, TextKey::PanelHeaderOwner) => "소유",
             (Language::English, TextKey::DialogSuggestions) => "Suggestions",
             (Language::Korean, TextKey::DialogSuggestions) => "추천",
+            (Language::English, TextKey::DialogCopyVerify) => "Verify after copy (Ctrl+R)",
+            (Language::Korean, TextKey::DialogCopyVerify) => "복사 후 검증 (Ctrl+R)",
+            (Language::English, TextKey::DialogCopyUpdateOnly) => "Update only newer (Ctrl+U)",
+            (Language::Korean, TextKey::DialogCopyUpdateOnly) => "새로운 파일만 갱신 (Ctrl+U)",
+            (Language::English, TextKey::DialogScopeFilter) => "Filter (Ctrl+F):",
+            (Language::Korean, TextKey::DialogScopeFilter) => "범위 필터 (Ctrl+F):",
+            (Language::English, TextKey::DialogScopeFilterAll) => "all items",
+            (Language::Korean, TextKey::DialogScopeFilterAll) => "모든 항목",
+            (Language::English, TextKey::DialogCopyPreserve) => "Preserve (Ctrl+T/P/X):",
+            (Language::Korean, TextKey::DialogCopyPreserve) => "유지 (Ctrl+T/P/X):",
+            (Language::English, TextKey::DialogCopyPreserveTimestamps) => "time",
+            (Language::Korean, TextKey::DialogCopyPreserveTimestamps) => "시각",
+            (Language::English, TextKey::DialogCopyPreservePermissions) => "mode",
+            (Language::Korean, TextKey::DialogCopyPreservePermissions) => "권한",
+            (Language::English, TextKey::DialogCopyPreserveXattrs) => "xattr",
+            (Language::Korean, TextKey::DialogCopyPreserveXattrs) => "확장 속성",
+            (Language::English, TextKey::DialogMkdirCreates) => "Creates:",
+            (Language::Korean, TextKey::DialogMkdirCreates) => "만들 경로:",
+            (Language::English, TextKey::DialogMkdirEnter) => "Enter after creating (Ctrl+E)",
+            (Language::Korean, TextKey::DialogMkdirEnter) => "만든 뒤 들어가기 (Ctrl+E)",
+            (Language::English, TextKey::DialogCopySymlinks) => "Symlinks (Ctrl+L):",
+            (Language::Korean, TextKey::DialogCopySymlinks) => "심볼릭 링크 (Ctrl+L):",
+            (Language::English, TextKey::DialogCopySymlinksLinks) => "copy as links",
+            (Language::Korean, TextKey::DialogCopySymlinksLinks) => "링크로 복사",
+            (Language::English, TextKey::DialogCopySymlinksFollow) => "follow and copy targets",
+            (Language::Korean, TextKey::DialogCopySymlinksFollow) => "따라가서 대상 복사",
+            (Language::English, TextKey::DialogCopySymlinksSkip) => "skip",
+            (Language::Korean, TextKey::DialogCopySymlinksSkip) => "건너뛰기",
             (Language::English, TextKey::DialogSuggestionHint) => {
                 "Tab:Apply suggestion  Shift+Tab/Up/Down:Select"
             }
@@ -232,12 +411,24 @@ impl I18n {
             }
             (Language::English, TextKey::DialogTitleFileExists) => " File Exists ",
             (Language::Korean, TextKey::DialogTitleFileExists) => " 파일 충돌 ",
+            (Language::English, TextKey::DialogTitleDirectoryExists) => " Directory Exists ",
+            (Language::Korean, TextKey::DialogTitleDirectoryExists) => " 디렉토리 충돌 ",
             (Language::English, TextKey::DialogSource) => "Source:",
             (Language::Korean, TextKey::DialogSource) => "원본:",
             (Language::English, TextKey::DialogUnknown) => "unknown",
             (Language::Korean, TextKey::DialogUnknown) => "알 수 없음",
             (Language::English, TextKey::DialogTargetExists) => "Target already exists:",
             (Language::Korean, TextKey::DialogTargetExists) => "대상 경로가 이미 존재합니다:",
+            (Language::English, TextKey::DialogTargetDirectoryExists) => {
+                "Target directory already exists:"
+            }
+            (Language::Korean, TextKey::DialogTargetDirectoryExists) => {
+                "대상 디렉토리가 이미 있습니다:"
+            }
+            (Language::English, TextKey::DialogMerge) => "Merge",
+            (Language::Korean, TextKey::DialogMerge) => "합치기",
+            (Language::English, TextKey::DialogReplace) => "Replace",
+            (Language::Korean, TextKey::DialogReplace) => "통째로 바꾸기",
             (Language::English, TextKey::DialogOverwrite) => "Overwrite",
             (Language::Korean, TextKey::DialogOverwrite) => "덮어쓰기",
             (Language::English, TextKey::DialogSkip) => "Skip",
@@ -246,14 +437,44 @@ impl I18n {
             (Language::Korean, TextKey::DialogOverwriteAll) => "모두 덮어쓰기",
             (Language::English, TextKey::DialogSkipAll) => "Skip All",
             (Language::Korean, TextKey::DialogSkipAll) => "모두 건너뛰기",
+            (Language::English, TextKey::DialogTitleFileInUse) => " File In Use ",
+            (Language::Korean, TextKey::DialogTitleFileInUse) => " 사용 중인 파일 ",
+            (Language::English, TextKey::DialogFileInUse) => {
+                "Another program is still using this file:"
+            }
+            (Language::Korean, TextKey::DialogFileInUse) => {
+                "다른 프로그램이 아직 이 파일을 쓰고 있습니다:"
+            }
+            (Language::English, TextKey::DialogRetry) => "Retry",
+            (Language::Korean, TextKey::DialogRetry) => "다시 시도",
             (Language::English, TextKey::DialogPressEscToCancel) => "Press Esc to cancel",
             (Language::Korean, TextKey::DialogPressEscToCancel) => "Esc로 취소",
+            (Language::English, TextKey::DialogProgressHintPause) => "Esc:Cancel  p:Pause",
+            (Language::Korean, TextKey::DialogProgressHintPause) => "Esc:취소  p:일시정지",
+            (Language::English, TextKey::DialogProgressHintResume) => "Esc:Cancel  p:Resume",
+            (Language::Korean, TextKey::DialogProgressHintResume) => "Esc:취소  p:재개",
+            (Language::English, TextKey::ProgressFile) => "File",
+            (Language::Korean, TextKey::ProgressFile) => "파일",
+            (Language::English, TextKey::ProgressTotal) => "Total",
+            (Language::Korean, TextKey::ProgressTotal) => "전체",
+            (Language::English, TextKey::ProgressPaused) => "Paused",
+            (Language::Korean, TextKey::ProgressPaused) => "일시정지됨",
+            (Language::English, TextKey::ProgressPausedBadge) => "PAUSED",
+            (Language::Korean, TextKey::ProgressPausedBadge) => "일시정지",
             (Language::English, TextKey::DialogTitleDelete) => " Delete ",
             (Language::Korean, TextKey::DialogTitleDelete) => " 삭제 ",
             (Language::English, TextKey::DialogTrash) => "Trash",
             (Language::Korean, TextKey::DialogTrash) => "휴지통",
             (Language::English, TextKey::DialogDelete) => "Delete",
             (Language::Korean, TextKey::DialogDelete) => "삭제",
+            (Language::English, TextKey::DialogTitleQuit) => " Quit ",
+            (Language::Korean, TextKey::DialogTitleQuit) => " 종료 ",
+            (Language::English, TextKey::DialogQuitRunning) => "Jobs still running:",
+            (Language::Korean, TextKey::DialogQuitRunning) => "진행 중인 작업:",
+            (Language::English, TextKey::DialogWaitAndQuit) => "Wait",
+            (Language::Korean, TextKey::DialogWaitAndQuit) => "끝나면 종료",
+            (Language::English, TextKey::DialogCancelJobsAndQuit) => "Cancel jobs & quit",
+            (Language::Korean, TextKey::DialogCancelJobsAndQuit) => "취소하고 종료",
             (Language::English, TextKey::DialogName) => "Name:",
             (Language::Korean, TextKey::DialogName) => "이름:",
             (Language::English, TextKey::DialogPath) => "Path:",
@@ -266,6 +487,24 @@ impl I18n {
             (Language::Korean, TextKey::DialogModified) => "수정일:",
             (Language::English, TextKey::DialogPermissions) => "Permissions:",
             (Language::Korean, TextKey::DialogPermissions) => "권한:",
+            (Language::English, TextKey::DialogDetectedType) => "Format:",
+            (Language::Korean, TextKey::DialogDetectedType) => "형식:",
+            (Language::English, TextKey::DialogAttributes) => "Attributes:",
+            (Language::Korean, TextKey::DialogAttributes) => "속성:",
+            (Language::English, TextKey::DialogAttrReadOnly) => "Read-only",
+            (Language::Korean, TextKey::DialogAttrReadOnly) => "읽기 전용",
+            (Language::English, TextKey::DialogAttrHidden) => "Hidden",
+            (Language::Korean, TextKey::DialogAttrHidden) => "숨김",
+            (Language::English, TextKey::DialogAttrSystem) => "System",
+            (Language::Korean, TextKey::DialogAttrSystem) => "시스템",
+            (Language::English, TextKey::DialogXattrs) => "Xattrs:",
+            (Language::Korean, TextKey::DialogXattrs) => "확장 속성:",
+            (Language::English, TextKey::DialogHintXattrs) => "j/k:Move",
+            (Language::Korean, TextKey::DialogHintXattrs) => "j/k:이동",
+            (Language::English, TextKey::DialogHintXattrsQuarantine) => {
+                "j/k:Move  q:Remove quarantine"
+            }
+            (Language::Korean, TextKey::DialogHintXattrsQuarantine) => "j/k:이동  q:격리 해제",
             (Language::English, TextKey::DialogContents) => "Contents:",
             (Language::Korean, TextKey::DialogContents) => "내용:",
             (Language::English, TextKey::DialogSearch) => "Search:",
@@ -276,6 +515,8 @@ impl I18n {
                 "No shortcuts match your search"
             }
             (Language::Korean, TextKey::DialogNoShortcutMatches) => "검색 결과가 없습니다",
+            (Language::English, TextKey::DialogNoFuzzyMatches) => "No matching items",
+            (Language::Korean, TextKey::DialogNoFuzzyMatches) => "일치하는 항목이 없습니다",
             (Language::English, TextKey::DialogHelpHint) => "Esc:Clear/Close  /:Search  j/k:Scroll",
             (Language::Korean, TextKey::DialogHelpHint) => "Esc:닫기  /:검색  j/k:스크롤",
             (Language::English, TextKey::DialogNewDirectory) => "New Directory",
@@ -290,10 +531,26 @@ impl I18n {
             (Language::Korean, TextKey::DialogBookmarkRename) => "북마크 이름 변경",
             (Language::English, TextKey::DialogNewBookmarkName) => "New bookmark name:",
             (Language::Korean, TextKey::DialogNewBookmarkName) => "새 북마크 이름:",
+            (Language::English, TextKey::DialogBookmarkEditPath) => "Bookmark Path",
+            (Language::Korean, TextKey::DialogBookmarkEditPath) => "북마크 경로 변경",
+            (Language::English, TextKey::DialogNewBookmarkPath) => "Target directory:",
+            (Language::Korean, TextKey::DialogNewBookmarkPath) => "대상 디렉토리:",
+            (Language::English, TextKey::DialogBookmarkGroup) => "Bookmark Group",
+            (Language::Korean, TextKey::DialogBookmarkGroup) => "북마크 그룹",
+            (Language::English, TextKey::DialogNewBookmarkGroup) => "Group (empty: none):",
+            (Language::Korean, TextKey::DialogNewBookmarkGroup) => "그룹 (비우면 해제):",
+            (Language::English, TextKey::DialogTitleExportBookmarks) => "Export Bookmarks",
+            (Language::Korean, TextKey::DialogTitleExportBookmarks) => "북마크 내보내기",
+            (Language::English, TextKey::DialogTitleImportBookmarks) => "Import Bookmarks",
+            (Language::Korean, TextKey::DialogTitleImportBookmarks) => "북마크 가져오기",
+            (Language::English, TextKey::DialogPromptBookmarkFile) => "Bookmark file (TOML):",
+            (Language::Korean, TextKey::DialogPromptBookmarkFile) => "북마크 파일 (TOML):",
             (Language::English, TextKey::DialogFilter) => "Filter",
             (Language::Korean, TextKey::DialogFilter) => "필터",
-            (Language::English, TextKey::DialogFilterPattern) => "Pattern (supports * ?):",
-            (Language::Korean, TextKey::DialogFilterPattern) => "패턴 (* ? 지원):",
+            (Language::English, TextKey::DialogFilterPattern) => "Pattern (* ? !, space = OR):",
+            (Language::Korean, TextKey::DialogFilterPattern) => "패턴 (* ? !, 공백 = OR):",
+            (Language::English, TextKey::DialogFilterRegex) => "Regex (Ctrl+R)",
+            (Language::Korean, TextKey::DialogFilterRegex) => "정규식 (Ctrl+R)",
             (Language::English, TextKey::DialogTitleProperties) => " Properties ",
             (Language::Korean, TextKey::DialogTitleProperties) => " 파일 속성 ",
             (Language::English, TextKey::DialogTitleMountPoints) => " Mount Points ",
@@ -312,33 +569,107 @@ impl I18n {
             (Language::Korean, TextKey::DialogHintMoveGoClose) => {
                 " j/k:이동  Enter:열기  Esc:닫기 "
             }
-            (Language::English, TextKey::DialogHintMoveGoClearClose) => {
-                " j/k:Move  Enter:Go  D:Clear  Esc:Close "
+            (Language::English, TextKey::DialogHintTabList) => {
+                " j/k:Move  Enter:Go  r:Rename  J/K:Reorder  c:Copy  m:Send  Esc:Close "
+            }
+            (Language::Korean, TextKey::DialogHintTabList) => {
+                " j/k:이동  Enter:열기  r:이름  J/K:순서  c:복제  m:보내기  Esc:닫기 "
+            }
+            (Language::English, TextKey::DialogHintProperties) => {
+                " w:Read-only  h:Hidden  Enter:Close "
+            }
+            (Language::Korean, TextKey::DialogHintProperties) => {
+                " w:읽기 전용  h:숨김  Enter:닫기 "
+            }
+            (Language::English, TextKey::DialogHintPropertiesWindows) => {
+                " w:Read-only  h:Hidden  s:System  Enter:Close "
+            }
+            (Language::Korean, TextKey::DialogHintPropertiesWindows) => {
+                " w:읽기 전용  h:숨김  s:시스템  Enter:닫기 "
+            }
+            (Language::English, TextKey::DialogHintHistoryList) => {
+                " j/k:Move  Enter:Go  /:Search  p:Pin  D:Clear  Esc:Close "
+            }
+            (Language::Korean, TextKey::DialogHintHistoryList) => {
+                " j/k:이동  Enter:열기  /:검색  p:고정  D:비우기  Esc:닫기 "
+            }
+            (Language::English, TextKey::DialogHintHistorySearch) => {
+                " Type to filter  Enter:Done  Esc:Clear "
+            }
+            (Language::Korean, TextKey::DialogHintHistorySearch) => {
+                " 입력해 좁히기  Enter:완료  Esc:지우기 "
+            }
+            (Language::English, TextKey::DialogHintBookmarkList) => {
+                " Enter:Go/Fold  r:Name  p:Path  g:Group  0-9:Hotkey  d:Delete  Esc:Close "
+            }
+            (Language::Korean, TextKey::DialogHintBookmarkList) => {
+                " Enter:열기/접기  r:이름  p:경로  g:그룹  0-9:단축키  d:삭제  Esc:닫기 "
+            }
+            (Language::English, TextKey::DialogTitleSessions) => " Sessions ",
+            (Language::Korean, TextKey::DialogTitleSessions) => " 세션 ",
+            (Language::English, TextKey::DialogTitleJobs) => " Jobs ",
+            (Language::Korean, TextKey::DialogTitleJobs) => " 작업 ",
+            (Language::English, TextKey::DialogHintJobList) => {
+                " j/k:Move  Enter:Details  D:Clear  Esc:Close "
+            }
+            (Language::Korean, TextKey::DialogHintJobList) => {
+                " j/k:이동  Enter:자세히  D:비우기  Esc:닫기 "
+            }
+            (Language::English, TextKey::SyncCopyToRight) => "copy →",
+            (Language::Korean, TextKey::SyncCopyToRight) => "복사 →",
+            (Language::English, TextKey::SyncCopyToLeft) => "← copy",
+            (Language::Korean, TextKey::SyncCopyToLeft) => "← 복사",
+            (Language::English, TextKey::SyncDeleteLeft) => "delete left",
+            (Language::Korean, TextKey::SyncDeleteLeft) => "왼쪽 삭제",
+            (Language::English, TextKey::SyncDeleteRight) => "delete right",
+            (Language::Korean, TextKey::SyncDeleteRight) => "오른쪽 삭제",
+            (Language::English, TextKey::DialogTitleThemes) => " Themes ",
+            (Language::Korean, TextKey::DialogTitleThemes) => " 테마 ",
+            (Language::English, TextKey::DialogHintMoveApplyReloadClose) => {
+                " j/k:Move  Enter:Apply  r:Reload  Esc:Close "
+            }
+            (Language::Korean, TextKey::DialogHintMoveApplyReloadClose) => {
+                " j/k:이동  Enter:적용  r:다시 읽기  Esc:닫기 "
             }
-            (Language::Korean, TextKey::DialogHintMoveGoClearClose) => {
-                " j/k:이동  Enter:열기  D:비우기  Esc:닫기 "
+            (Language::English, TextKey::ThemeBuiltIn) => "Built-in",
+            (Language::Korean, TextKey::ThemeBuiltIn) => "내장",
+            (Language::English, TextKey::DialogHintMoveRestoreDeleteClose) => {
+                " j/k:Move  Enter:Restore  d:Delete  Esc:Close "
             }
-            (Language::English, TextKey::DialogHintMoveGoRenameDeleteClose) => {
-                " j/k:Move  Enter:Go  r:Rename  d:Delete  Esc:Close "
+            (Language::Korean, TextKey::DialogHintMoveRestoreDeleteClose) => {
+                " j/k:이동  Enter:복원  d:삭제  Esc:닫기 "
             }
-            (Language::Korean, TextKey::DialogHintMoveGoRenameDeleteClose) => {
-                " j/k:이동  Enter:열기  r:이름변경  d:삭제  Esc:닫기 "
+            (Language::English, TextKey::DialogTitleRemoteHosts) => " Remote Hosts ",
+            (Language::Korean, TextKey::DialogTitleRemoteHosts) => " 원격 호스트 ",
+            (Language::English, TextKey::DialogHintMoveConnectNewDeleteClose) => {
+                " j/k:Move  Enter:Connect  n:New  d:Delete  Esc:Close "
             }
+            (Language::Korean, TextKey::DialogHintMoveConnectNewDeleteClose) => {
+                " j/k:이동  Enter:접속  n:새 접속  d:삭제  Esc:닫기 "
+            }
+            (Language::English, TextKey::DialogTitleConnectRemote) => "Connect to Remote",
+            (Language::Korean, TextKey::DialogTitleConnectRemote) => "원격 접속",
+            (Language::English, TextKey::DialogPromptRemoteUrl) => "URL (sftp|ftp|ftps://user@host:port/path):",
+            (Language::Korean, TextKey::DialogPromptRemoteUrl) => "URL (sftp|ftp|ftps://사용자@호스트:포트/경로):",
             (Language::English, TextKey::DialogTitleCreateArchive) => " Create Archive ",
             (Language::Korean, TextKey::DialogTitleCreateArchive) => " 압축 생성 ",
             (Language::English, TextKey::DialogArchivePath) => "Archive path:",
             (Language::Korean, TextKey::DialogArchivePath) => "압축 경로:",
             (Language::English, TextKey::DialogUsePassword) => "Use password",
             (Language::Korean, TextKey::DialogUsePassword) => "비밀번호 사용",
+            (Language::English, TextKey::DialogCompression) => "Compression:",
+            (Language::Korean, TextKey::DialogCompression) => "압축 수준:",
+            (Language::English, TextKey::DialogArchivePresets) => "Presets:",
+            (Language::Korean, TextKey::DialogArchivePresets) => "프리셋:",
             (Language::English, TextKey::DialogPassword) => "Password:",
             (Language::Korean, TextKey::DialogPassword) => "비밀번호:",
             (Language::English, TextKey::DialogConfirmPassword) => "Confirm password:",
             (Language::Korean, TextKey::DialogConfirmPassword) => "비밀번호 확인:",
             (Language::English, TextKey::DialogHintArchiveCreate) => {
-                "Tab/Shift+Tab:Move  Space:Toggle password  Enter:OK  Esc:Cancel  (zip/7z only)"
+                "Tab:Move  Space:Password (zip/7z)  ←/→:Level  Alt+1-9:Preset  Enter:OK  Esc:Cancel"
             }
             (Language::Korean, TextKey::DialogHintArchiveCreate) => {
-                "Tab/Shift+Tab:이동  Space:비밀번호 토글  Enter:확인  Esc:취소  (zip/7z 전용)"
+                "Tab:이동  Space:비밀번호(zip/7z)  ←/→:수준  Alt+1-9:프리셋  Enter:확인  Esc:취소"
             }
             (Language::English, TextKey::DialogArchivePreviewTruncated) => "[showing first 5000]",
             (Language::Korean, TextKey::DialogArchivePreviewTruncated) => "[최대 5000개 표시]",
@@ -358,6 +689,52 @@ impl I18n {
             (Language::Korean, TextKey::DialogTitleRunCommand) => "명령 실행",
             (Language::English, TextKey::DialogPromptCommand) => "Command:",
             (Language::Korean, TextKey::DialogPromptCommand) => "명령:",
+            (Language::English, TextKey::DialogTitleSaveSession) => "Save Session",
+            (Language::Korean, TextKey::DialogTitleSaveSession) => "세션 저장",
+            (Language::English, TextKey::DialogPromptSessionName) => "Session name:",
+            (Language::Korean, TextKey::DialogPromptSessionName) => "세션 이름:",
+            (Language::English, TextKey::DialogTitleRenameTab) => "Rename Tab",
+            (Language::English, TextKey::DialogTitleNewFile) => "New File",
+            (Language::Korean, TextKey::DialogTitleNewFile) => "새 파일",
+            (Language::English, TextKey::DialogPromptNewFile) => {
+                "File name (Ctrl+E: create and edit):"
+            }
+            (Language::Korean, TextKey::DialogPromptNewFile) => "파일 이름 (Ctrl+E: 만들고 편집):",
+            (Language::English, TextKey::DialogTitleRunExecutable) => "Run",
+            (Language::Korean, TextKey::DialogTitleRunExecutable) => "실행",
+            (Language::English, TextKey::DialogPromptRunArgs) => {
+                "Arguments (leading NAME=value sets env, Ctrl+O: capture output):"
+            },
+            (Language::Korean, TextKey::DialogPromptRunArgs) => {
+                "인자 (앞의 NAME=값은 환경 변수, Ctrl+O: 출력 모아 보기):"
+            },
+            (Language::Korean, TextKey::DialogTitleRenameTab) => "탭 이름 변경",
+            (Language::English, TextKey::DialogPromptTabName) => "Tab name (empty = path):",
+            (Language::Korean, TextKey::DialogPromptTabName) => "탭 이름 (비우면 경로):",
+            (Language::English, TextKey::DialogTitleAutoRefresh) => "Auto Refresh",
+            (Language::Korean, TextKey::DialogTitleAutoRefresh) => "자동 새로고침",
+            (Language::English, TextKey::DialogPromptAutoRefresh) => {
+                "Refresh this panel every N seconds (empty or 0 = off):"
+            }
+            (Language::Korean, TextKey::DialogPromptAutoRefresh) => {
+                "이 패널을 N초마다 새로고침 (비우거나 0이면 끔):"
+            }
+            (Language::English, TextKey::DialogTitleHidePatterns) => "Hide Patterns",
+            (Language::Korean, TextKey::DialogTitleHidePatterns) => "숨김 패턴",
+            (Language::English, TextKey::DialogPromptHidePatterns) => {
+                "Globs always hidden from listings, dir/ for directories (e.g. *.pyc __pycache__/):"
+            }
+            (Language::Korean, TextKey::DialogPromptHidePatterns) => {
+                "목록에서 늘 숨길 글로브, 디렉토리는 dir/ (예: *.pyc __pycache__/):"
+            }
+            (Language::English, TextKey::DialogTitleScopeFilter) => "Operation Filter",
+            (Language::Korean, TextKey::DialogTitleScopeFilter) => "작업 범위 필터",
+            (Language::English, TextKey::DialogPromptScopeFilter) => {
+                "Globs to include, !glob to exclude (e.g. *.jpg !node_modules/):"
+            }
+            (Language::Korean, TextKey::DialogPromptScopeFilter) => {
+                "포함할 글로브, 제외는 !글로브 (예: *.jpg !node_modules/):"
+            }
             (Language::English, TextKey::DialogEta) => "ETA",
             (Language::Korean, TextKey::DialogEta) => "예상",
             (Language::English, TextKey::DialogKeyboardShortcutsTitle) => " Keyboard Shortcuts ",
@@ -395,8 +772,8 @@ impl I18n {
             (Language::Korean, MessageKey::HelpTotal) => "전체: {count}",
             (Language::English, MessageKey::HelpResults) => "Results: {count}",
             (Language::Korean, MessageKey::HelpResults) => "결과: {count}",
-            (Language::English, MessageKey::MaxTabsPerPanel) => "Max 5 tabs per panel",
-            (Language::Korean, MessageKey::MaxTabsPerPanel) => "패널당 탭은 최대 5개입니다",
+            (Language::English, MessageKey::MaxTabsPerPanel) => "Max {count} tabs per panel",
+            (Language::Korean, MessageKey::MaxTabsPerPanel) => "패널당 탭은 최대 {count}개입니다",
             (Language::English, MessageKey::TabCreated) => "Tab created ({index})",
             (Language::Korean, MessageKey::TabCreated) => "탭 생성 ({index})",
             (Language::English, MessageKey::TabClosed) => "Tab closed",
@@ -407,6 +784,40 @@ impl I18n {
             (Language::Korean, MessageKey::TabIndex) => "탭 {index}",
             (Language::English, MessageKey::NoTabIndex) => "No tab {index}",
             (Language::Korean, MessageKey::NoTabIndex) => "탭 없음 {index}",
+            (Language::English, MessageKey::TabRenamed) => "Tab renamed",
+            (Language::Korean, MessageKey::TabRenamed) => "탭 이름을 변경했습니다",
+            (Language::English, MessageKey::TabNameCleared) => "Tab name reset to path",
+            (Language::Korean, MessageKey::TabNameCleared) => {
+                "탭 이름을 경로 이름으로 되돌렸습니다"
+            }
+            (Language::English, MessageKey::TabMoved) => "Tab moved ({index})",
+            (Language::Korean, MessageKey::TabMoved) => "탭 이동 ({index})",
+            (Language::English, MessageKey::TabCannotMove) => "Tab is already at the end",
+            (Language::Korean, MessageKey::TabCannotMove) => "탭이 이미 끝에 있습니다",
+            (Language::English, MessageKey::AttributeTargets) => "({count} items)",
+            (Language::Korean, MessageKey::AttributeTargets) => "({count}개 항목)",
+            (Language::English, MessageKey::AttributesUpdated) => "Attributes updated: {count}",
+            (Language::Korean, MessageKey::AttributesUpdated) => "속성 변경: {count}개",
+            (Language::English, MessageKey::QuarantineRemoved) => {
+                "Quarantine attribute removed: {count}"
+            }
+            (Language::Korean, MessageKey::QuarantineRemoved) => "격리 속성 제거: {count}개",
+            (Language::English, MessageKey::TabOpenedInOtherPanel) => {
+                "Opened in other panel tab {index}"
+            }
+            (Language::Korean, MessageKey::TabOpenedInOtherPanel) => {
+                "반대 패널 탭 {index}에서 열었습니다"
+            }
+            (Language::English, MessageKey::TabCopiedToOtherPanel) => {
+                "Tab copied to other panel ({index})"
+            }
+            (Language::Korean, MessageKey::TabCopiedToOtherPanel) => {
+                "반대 패널에 탭 복제 ({index})"
+            }
+            (Language::English, MessageKey::TabSentToOtherPanel) => {
+                "Tab moved to other panel ({index})"
+            }
+            (Language::Korean, MessageKey::TabSentToOtherPanel) => "반대 패널로 탭 이동 ({index})",
             (Language::English, MessageKey::StatusLeftLong) => {
                 " {files} files, {dirs} dirs | {total}"
             }
@@ -415,6 +826,10 @@ impl I18n {
             }
             (Language::English, MessageKey::StatusSelectedLong) => " | {count} selected ({size})",
             (Language::Korean, MessageKey::StatusSelectedLong) => " | 선택 {count}개 ({size})",
+            (Language::English, MessageKey::StatusDiskFree) => "{free} free of {total}",
+            (Language::Korean, MessageKey::StatusDiskFree) => "여유 {free} / {total}",
+            (Language::English, MessageKey::StatusJobs) => "Jobs: {count}",
+            (Language::Korean, MessageKey::StatusJobs) => "작업: {count}",
             (Language::English, MessageKey::LayoutDualToast) => "Layout: Dual panel",
             (Language::Korean, MessageKey::LayoutDualToast) => "레이아웃: 듀얼 패널",
             (Language::English, MessageKey::LayoutSingleToast) => "Layout: Single panel",
@@ -429,6 +844,84 @@ impl I18n {
             (Language::Korean, MessageKey::DialogArchivePreviewHint) => {
                 " j/k:이동  PgUp/PgDn:스크롤  Home/End  Esc:닫기  [{count}개 항목] "
             }
+            (Language::English, MessageKey::DialogTextViewerHint) => {
+                " j/k:Scroll  PgUp/PgDn  Home/End  Esc:Close  [{line}/{count}] "
+            }
+            (Language::Korean, MessageKey::DialogTextViewerHint) => {
+                " j/k:스크롤  PgUp/PgDn  Home/End  Esc:닫기  [{line}/{count}] "
+            }
+            (Language::English, MessageKey::DialogFuzzyListHint) => {
+                " Type:Filter  ↑/↓:Move  Enter:Select  Esc:Close  [{count}/{total}] "
+            }
+            (Language::Korean, MessageKey::DialogFuzzyListHint) => {
+                " 입력:좁히기  ↑/↓:이동  Enter:선택  Esc:닫기  [{count}/{total}] "
+            }
+            (Language::English, MessageKey::DialogFuzzyListLoadingHint) => {
+                " Indexing...  ↑/↓:Move  Enter:Select  Esc:Close  [{count}/{total}] "
+            }
+            (Language::Korean, MessageKey::DialogFuzzyListLoadingHint) => {
+                " 색인 중...  ↑/↓:이동  Enter:선택  Esc:닫기  [{count}/{total}] "
+            }
+            (Language::English, MessageKey::DialogDiskUsageTitle) => {
+                " Disk Usage: {path} ({size}) "
+            }
+            (Language::Korean, MessageKey::DialogDiskUsageTitle) => {
+                " 디스크 사용량: {path} ({size}) "
+            }
+            (Language::English, MessageKey::DialogDiskUsageHint) => {
+                " Enter:Open  h:Up  d:Trash  D:Delete  g:Go to  Esc:Close  [{count} items] "
+            }
+            (Language::Korean, MessageKey::DialogDiskUsageHint) => {
+                " Enter:열기  h:상위  d:휴지통  D:삭제  g:패널 이동  Esc:닫기  [{count}개 항목] "
+            }
+            (Language::English, MessageKey::DialogDiskUsageScanning) => {
+                "Scanning... {files} items, {size}  (Esc: Cancel)"
+            }
+            (Language::Korean, MessageKey::DialogDiskUsageScanning) => {
+                "분석 중... 항목 {files}개, {size}  (Esc: 취소)"
+            }
+            (Language::English, MessageKey::DialogDiskUsageConfirmTrash) => {
+                " Move '{name}' ({size}) to trash? y:Yes  n:No "
+            }
+            (Language::Korean, MessageKey::DialogDiskUsageConfirmTrash) => {
+                " '{name}' ({size})을(를) 휴지통으로 이동할까요? y:예  n:아니오 "
+            }
+            (Language::English, MessageKey::DialogDiskUsageConfirmDelete) => {
+                " Permanently delete '{name}' ({size})? y:Yes  n:No "
+            }
+            (Language::Korean, MessageKey::DialogDiskUsageConfirmDelete) => {
+                " '{name}' ({size})을(를) 영구 삭제할까요? y:예  n:아니오 "
+            }
+            (Language::English, MessageKey::DialogPanelSyncTitle) => " Synchronize: {left} ⇄ {right} ",
+            (Language::Korean, MessageKey::DialogPanelSyncTitle) => " 동기화: {left} ⇄ {right} ",
+            (Language::English, MessageKey::DialogPanelSyncHint) => {
+                " Space:Check  ←/→:Direction  a:All  Enter:Run  Esc:Close  [{checked}/{count}, {size}] "
+            }
+            (Language::Korean, MessageKey::DialogPanelSyncHint) => {
+                " Space:체크  ←/→:방향  a:전체  Enter:실행  Esc:닫기  [{checked}/{count}, {size}] "
+            }
+            (Language::English, MessageKey::DialogPanelSyncComparing) => {
+                "Comparing... {count} items  (Esc: Cancel)"
+            }
+            (Language::Korean, MessageKey::DialogPanelSyncComparing) => {
+                "비교 중... 항목 {count}개  (Esc: 취소)"
+            }
+            (Language::English, MessageKey::DialogPanelSyncRunning) => {
+                " Synchronizing {done}/{total}...  Esc:Cancel "
+            }
+            (Language::Korean, MessageKey::DialogPanelSyncRunning) => {
+                " 동기화 중 {done}/{total}...  Esc:취소 "
+            }
+            (Language::English, MessageKey::DialogFreeSpace) => "Need {need}, free {free}",
+            (Language::Korean, MessageKey::DialogFreeSpace) => "필요 {need}, 여유 {free}",
+            (Language::English, MessageKey::DialogSpaceNeeded) => "Need {need}",
+            (Language::Korean, MessageKey::DialogSpaceNeeded) => "필요 {need}",
+            (Language::English, MessageKey::ElevationTerminalPrompt) => {
+                "BokslDir: {operation} needs administrator privileges (sudo)."
+            }
+            (Language::Korean, MessageKey::ElevationTerminalPrompt) => {
+                "복슬Dir: {operation} 작업에 관리자 권한(sudo)이 필요합니다."
+            }
         }
     }
 
@@ -446,16 +939,47 @@ impl I18n {
         format!("{} {}", self.tr(sort_name_key), arrow)
     }
 
+    /// 필터 표시 문자열 (모드/제외/오류 플래그 포함, `r:` 접두사는 모드로 표시)
     pub fn filter_indicator(self, pattern: &str) -> String {
-        format!("{}: {}", self.tr(TextKey::FilterPrefix), pattern)
+        let name_filter = NameFilter::parse(pattern);
+        let mut flags = vec![self.tr(match name_filter.mode() {
+            FilterMode::Text => TextKey::FilterModeText,
+            FilterMode::Glob => TextKey::FilterModeGlob,
+            FilterMode::Regex => TextKey::FilterModeRegex,
+        })];
+        if name_filter.has_exclusions() {
+            flags.push(self.tr(TextKey::FilterExcludeFlag));
+        }
+        if name_filter.is_invalid() {
+            flags.push(self.tr(TextKey::FilterInvalidFlag));
+        }
+        let (_, body) = split_regex_prefix(pattern.trim());
+        format!(
+            "{} [{}]: {}",
+            self.tr(TextKey::FilterPrefix),
+            flags.join(","),
+            body
+        )
     }
 
     pub fn menu_item(self, id: &str) -> &'static str {
         match (self.language, id) {
             (Language::English, "new_dir") => "New Directory",
             (Language::Korean, "new_dir") => "새 폴더",
+            (Language::English, "new_file") => "New File",
+            (Language::Korean, "new_file") => "새 파일",
+            (Language::English, "enter") => "Open",
+            (Language::Korean, "enter") => "열기",
             (Language::English, "open_default") => "Open with default app",
             (Language::Korean, "open_default") => "기본 프로그램으로 열기",
+            (Language::English, "reveal_in_file_manager") => "Reveal in file manager",
+            (Language::Korean, "reveal_in_file_manager") => "파일 관리자에서 보기",
+            (Language::English, "run_executable") => "Run executable...",
+            (Language::Korean, "run_executable") => "실행 파일 실행...",
+            (Language::English, "git_diff") => "Show git diff",
+            (Language::Korean, "git_diff") => "git 변경 내용 보기",
+            (Language::English, "git_discard") => "Discard git changes",
+            (Language::Korean, "git_discard") => "git 변경 되돌리기",
             (Language::English, "open_terminal_editor") => "Open in terminal editor",
             (Language::Korean, "open_terminal_editor") => "터미널 에디터로 열기",
             (Language::English, "run_shell_command") => "Run command",
@@ -468,8 +992,14 @@ impl I18n {
             (Language::Korean, "archive_extract_auto") => "알아서 풀기",
             (Language::English, "archive_preview") => "Archive preview",
             (Language::Korean, "archive_preview") => "압축 미리보기",
+            (Language::English, "archive_name_encoding") => "Archive name encoding",
+            (Language::Korean, "archive_name_encoding") => "압축 파일 이름 인코딩",
+            (Language::English, "forget_archive_passwords") => "Forget archive passwords",
+            (Language::Korean, "forget_archive_passwords") => "압축 비밀번호 잊기",
             (Language::English, "rename") => "Rename",
             (Language::Korean, "rename") => "이름 변경",
+            (Language::English, "rename_via_editor") => "Rename via editor",
+            (Language::Korean, "rename_via_editor") => "편집기로 이름 바꾸기",
             (Language::English, "delete") => "Delete",
             (Language::Korean, "delete") => "삭제",
             (Language::English, "perm_delete") => "Permanent delete",
@@ -490,6 +1020,8 @@ impl I18n {
             (Language::Korean, "refresh") => "새로고침",
             (Language::English, "file_info") => "File info",
             (Language::Korean, "file_info") => "파일 정보",
+            (Language::English, "selection_stats") => "Selection statistics",
+            (Language::Korean, "selection_stats") => "선택 통계",
             (Language::English, "sort_name") => "Name",
             (Language::Korean, "sort_name") => "이름",
             (Language::English, "sort_size") => "Size",
@@ -502,30 +1034,108 @@ impl I18n {
             (Language::Korean, "sort_asc") => "오름차순",
             (Language::English, "sort_desc") => "Descending",
             (Language::Korean, "sort_desc") => "내림차순",
+            (Language::English, "sort_natural") => "Natural order (file2 < file10)",
+            (Language::Korean, "sort_natural") => "자연 정렬 (file2 < file10)",
+            (Language::English, "sort_locale") => "Locale-aware order",
+            (Language::Korean, "sort_locale") => "로캘 인식 정렬",
+            (Language::English, "sort_dirs_first") => "Directories first",
+            (Language::Korean, "sort_dirs_first") => "디렉토리 우선",
             (Language::English, "filter_start") => "Filter",
             (Language::Korean, "filter_start") => "필터링",
             (Language::English, "filter_clear") => "Clear filter",
             (Language::Korean, "filter_clear") => "필터 해제",
             (Language::English, "toggle_hidden") => "Show hidden files",
             (Language::Korean, "toggle_hidden") => "숨김 파일 표시",
+            (Language::English, "toggle_hidden_both") => "Show hidden files in both panels",
+            (Language::Korean, "toggle_hidden_both") => "양쪽 패널 숨김 파일 표시",
+            (Language::English, "toggle_hide_patterns") => "Show pattern-hidden files",
+            (Language::Korean, "toggle_hide_patterns") => "패턴으로 숨긴 파일 표시",
+            (Language::English, "edit_hide_patterns") => "Hide patterns...",
+            (Language::Korean, "edit_hide_patterns") => "숨김 패턴...",
+            (Language::English, "toggle_sync_browsing") => "Synchronized browsing",
+            (Language::Korean, "toggle_sync_browsing") => "동기 탐색",
+            (Language::English, "toggle_follow_focus") => "Follow focused directory",
+            (Language::Korean, "toggle_follow_focus") => "포커스 디렉토리 따라가기",
             (Language::English, "toggle_layout") => "Toggle single/dual panel",
             (Language::Korean, "toggle_layout") => "싱글/듀얼 패널 전환",
+            (Language::English, "swap_panels") => "Swap panels",
+            (Language::Korean, "swap_panels") => "좌우 패널 바꾸기",
+            (Language::English, "sync_other_panel") => "Other panel = current directory",
+            (Language::Korean, "sync_other_panel") => "반대 패널을 현재 경로로",
             (Language::English, "mount_points") => "Mount points",
             (Language::Korean, "mount_points") => "마운트 포인트",
+            (Language::English, "disk_usage") => "Analyze disk usage...",
+            (Language::Korean, "disk_usage") => "디스크 사용량 분석...",
+            (Language::English, "compare_files") => "Compare files by content",
+            (Language::Korean, "compare_files") => "파일 내용 비교",
+            (Language::English, "sync_panels") => "Synchronize panels...",
+            (Language::Korean, "sync_panels") => "패널 동기화...",
             (Language::English, "goto_path") => "Go to path",
             (Language::Korean, "goto_path") => "경로로 이동",
+            (Language::English, "breadcrumbs") => "Pick path segment",
+            (Language::Korean, "breadcrumbs") => "경로 조각 고르기",
+            (Language::English, "remote_connect") => "Connect to remote (SFTP/FTP)...",
+            (Language::Korean, "remote_connect") => "원격 접속 (SFTP/FTP)...",
             (Language::English, "history_list") => "Directory history",
             (Language::Korean, "history_list") => "디렉토리 히스토리",
+            (Language::English, "jump_list") => "Jump to frequent directory",
+            (Language::Korean, "jump_list") => "자주 가는 디렉토리로 이동",
+            (Language::English, "sibling_list") => "Switch to sibling directory",
+            (Language::Korean, "sibling_list") => "형제 디렉토리로 이동",
+            (Language::English, "find_file") => "Find file",
+            (Language::Korean, "find_file") => "파일 찾기",
             (Language::English, "bookmark_list") => "Bookmarks",
             (Language::Korean, "bookmark_list") => "북마크",
+            (Language::English, "bookmark_export") => "Export bookmarks...",
+            (Language::Korean, "bookmark_export") => "북마크 내보내기...",
+            (Language::English, "bookmark_import") => "Import bookmarks...",
+            (Language::Korean, "bookmark_import") => "북마크 가져오기...",
+            (Language::English, "session_save") => "Save session as...",
+            (Language::Korean, "session_save") => "세션 저장...",
+            (Language::English, "session_list") => "Sessions",
+            (Language::Korean, "session_list") => "세션 목록",
+            (Language::English, "job_list") => "Jobs",
+            (Language::Korean, "job_list") => "작업 목록",
+            (Language::English, "theme_dark") => "Dark (default)",
+            (Language::Korean, "theme_dark") => "다크 (기본)",
+            (Language::English, "theme_light") => "Light",
+            (Language::Korean, "theme_light") => "라이트",
+            (Language::English, "theme_contrast") => "High Contrast",
+            (Language::Korean, "theme_contrast") => "고대비",
+            (Language::English, "theme_list") => "Theme list...",
+            (Language::Korean, "theme_list") => "테마 목록...",
             (Language::English, "size_auto") => "Auto (KB/MB/GB)",
             (Language::Korean, "size_auto") => "자동 (KB/MB/GB)",
             (Language::English, "size_bytes") => "Bytes",
             (Language::Korean, "size_bytes") => "바이트",
             (Language::English, "toggle_icons") => "Toggle icons",
             (Language::Korean, "toggle_icons") => "아이콘 전환",
+            (Language::English, "toggle_git_status") => "Toggle git status",
+            (Language::Korean, "toggle_git_status") => "git 상태 표시 전환",
+            (Language::English, "auto_refresh") => "Auto refresh interval...",
+            (Language::Korean, "auto_refresh") => "자동 새로고침 주기...",
+            (Language::English, "toggle_info_line") => "Toggle info line",
+            (Language::Korean, "toggle_info_line") => "정보 줄 표시 전환",
+            (Language::English, "toggle_path_line") => "Toggle full path line",
+            (Language::Korean, "toggle_path_line") => "전체 경로 줄 표시 전환",
+            (Language::English, "toggle_type_column") => "Toggle type column",
+            (Language::Korean, "toggle_type_column") => "형식 컬럼 표시 전환",
+            (Language::English, "copy_verify") => "Verify after copy",
+            (Language::Korean, "copy_verify") => "복사 후 검증",
+            (Language::English, "copy_preserve_times") => "Preserve timestamps",
+            (Language::Korean, "copy_preserve_times") => "시각 유지",
+            (Language::English, "copy_preserve_permissions") => "Preserve permissions",
+            (Language::Korean, "copy_preserve_permissions") => "권한 유지",
+            (Language::English, "copy_preserve_xattrs") => "Preserve extended attributes",
+            (Language::Korean, "copy_preserve_xattrs") => "확장 속성 유지",
+            (Language::English, "copy_update_only") => "Update only newer",
+            (Language::Korean, "copy_update_only") => "새로운 파일만 갱신",
             (Language::English, "help_keys") => "Keyboard help",
             (Language::Korean, "help_keys") => "단축키 도움말",
+            (Language::English, "command_palette") => "Command palette",
+            (Language::Korean, "command_palette") => "명령 팔레트",
+            (Language::English, "plugin_actions") => "Plugin actions",
+            (Language::Korean, "plugin_actions") => "플러그인 동작",
             (Language::English, "about") => "About BokslDir",
             (Language::Korean, "about") => "복슬Dir 정보",
             _ => "",
@@ -542,6 +1152,8 @@ impl I18n {
             (Language::Korean, "size_format") => "크기 표시 형식",
             (Language::English, "theme") => "Theme",
             (Language::Korean, "theme") => "테마",
+            (Language::English, "copy_options") => "Copy options",
+            (Language::Korean, "copy_options") => "복사 옵션",
             (Language::English, "default_editor") => "Default editor",
             (Language::Korean, "default_editor") => "기본 에디터",
             _ => "",
@@ -579,22 +1191,39 @@ impl I18n {
             (Language::Korean, "page_down") => "아래 페이지 이동",
             (Language::Korean, "toggle_panel") => "패널 전환",
             (Language::Korean, "toggle_layout") => "레이아웃 전환",
+            (Language::Korean, "swap_panels") => "좌우 패널 바꾸기",
+            (Language::Korean, "sync_other_panel") => "반대 패널을 현재 경로로 이동",
             (Language::Korean, "tab_new") => "새 탭",
             (Language::Korean, "tab_close") => "탭 닫기",
+            (Language::Korean, "tab_rename") => "탭 이름 변경",
+            (Language::Korean, "tab_move_left") => "탭 왼쪽으로 이동",
+            (Language::Korean, "tab_move_right") => "탭 오른쪽으로 이동",
+            (Language::Korean, "tab_copy_other") => "탭을 반대 패널에 복제",
+            (Language::Korean, "tab_send_other") => "탭을 반대 패널로 보내기",
+            (Language::Korean, "tab_open_other") => "포커스 디렉토리를 반대 패널 새 탭으로 열기",
             (Language::Korean, "copy") => "복사",
             (Language::Korean, "move") => "이동",
             (Language::Korean, "open_default") => "기본 프로그램으로 열기",
+            (Language::Korean, "reveal_in_file_manager") => "파일 관리자에서 보기",
+            (Language::Korean, "run_executable") => "실행 파일 실행",
+            (Language::Korean, "git_diff") => "git 변경 내용 보기",
+            (Language::Korean, "git_discard") => "git 변경 되돌리기",
             (Language::Korean, "open_terminal_editor") => "터미널 에디터로 열기",
             (Language::Korean, "run_shell_command") => "명령 실행",
             (Language::Korean, "delete") => "삭제",
             (Language::Korean, "perm_delete") => "영구삭제",
             (Language::Korean, "new_dir") => "새폴더",
+            (Language::Korean, "new_file") => "새 파일 만들기",
             (Language::Korean, "rename") => "이름변경",
+            (Language::Korean, "rename_via_editor") => "편집기로 이름 바꾸기",
             (Language::Korean, "file_info") => "정보",
+            (Language::Korean, "selection_stats") => "선택 통계",
             (Language::Korean, "archive_compress") => "압축",
             (Language::Korean, "archive_extract") => "압축 해제",
             (Language::Korean, "archive_extract_auto") => "알아서 풀기",
             (Language::Korean, "archive_preview") => "압축 미리보기",
+            (Language::Korean, "archive_name_encoding") => "압축 파일 이름 인코딩",
+            (Language::Korean, "forget_archive_passwords") => "압축 비밀번호 잊기",
             (Language::Korean, "toggle_sel") => "선택 토글",
             (Language::Korean, "toggle_select") => "선택 토글",
             (Language::Korean, "invert_selection") => "선택 반전",
@@ -603,13 +1232,26 @@ impl I18n {
             (Language::Korean, "help_keys") => "단축키",
             (Language::Korean, "refresh") => "새로고침",
             (Language::Korean, "open_menu") => "메뉴 열기",
+            (Language::Korean, "context_menu") => "항목 메뉴",
+            (Language::Korean, "command_palette") => "명령 팔레트",
             (Language::Korean, "quit") => "종료",
             (Language::Korean, "theme_dark") => "다크 테마",
             (Language::Korean, "theme_light") => "라이트 테마",
             (Language::Korean, "theme_contrast") => "고대비 테마",
+            (Language::Korean, "theme_list") => "테마 목록 보기",
             (Language::Korean, "language_en") => "언어: 영어",
             (Language::Korean, "language_ko") => "언어: 한국어",
             (Language::Korean, "toggle_icons") => "아이콘 전환",
+            (Language::Korean, "toggle_git_status") => "git 상태 표시 전환",
+            (Language::Korean, "toggle_info_line") => "정보 줄 표시 전환",
+            (Language::Korean, "auto_refresh") => "자동 새로고침 주기",
+            (Language::Korean, "toggle_path_line") => "전체 경로 줄 표시 전환",
+            (Language::Korean, "toggle_type_column") => "형식 컬럼 표시 전환",
+            (Language::Korean, "copy_verify") => "복사 후 검증 전환",
+            (Language::Korean, "copy_preserve_times") => "복사 시 시각 유지 전환",
+            (Language::Korean, "copy_preserve_permissions") => "복사 시 권한 유지 전환",
+            (Language::Korean, "copy_preserve_xattrs") => "복사 시 확장 속성 유지 전환",
+            (Language::Korean, "copy_update_only") => "새로운 파일만 갱신 전환",
             (Language::Korean, "editor_preset_vi") => "기본 에디터: vi",
             (Language::Korean, "editor_preset_vim") => "기본 에디터: vim",
             (Language::Korean, "editor_preset_nano") => "기본 에디터: nano",
@@ -620,17 +1262,40 @@ impl I18n {
             (Language::Korean, "sort_ext") => "확장자순 정렬",
             (Language::Korean, "sort_asc") => "정렬 순서 반전",
             (Language::Korean, "sort_desc") => "내림차순",
+            (Language::Korean, "sort_natural") => "자연 정렬 전환",
+            (Language::Korean, "sort_locale") => "로캘 인식 정렬 전환",
+            (Language::Korean, "sort_dirs_first") => "디렉토리 우선 정렬 전환",
             (Language::Korean, "filter_start") => "필터",
             (Language::Korean, "filter_clear") => "필터 해제",
             (Language::Korean, "toggle_hidden") => "숨김 파일 표시 전환",
+            (Language::Korean, "toggle_hidden_both") => "양쪽 패널 숨김 파일 표시 전환",
+            (Language::Korean, "toggle_hide_patterns") => "패턴으로 숨긴 파일 표시 전환",
+            (Language::Korean, "edit_hide_patterns") => "숨김 패턴 편집",
+            (Language::Korean, "toggle_sync_browsing") => "동기 탐색 전환",
+            (Language::Korean, "toggle_follow_focus") => "반대 패널에 포커스 디렉토리 표시",
             (Language::Korean, "mount_points") => "마운트 포인트",
+            (Language::Korean, "disk_usage") => "디스크 사용량 분석",
+            (Language::Korean, "compare_files") => "파일 내용 비교",
+            (Language::Korean, "sync_panels") => "패널 동기화",
             (Language::Korean, "goto_path") => "경로로 이동",
+            (Language::Korean, "remote_connect") => "원격 호스트 접속",
             (Language::Korean, "tab_list") => "탭 목록 보기",
             (Language::Korean, "history_back") => "히스토리 뒤로",
             (Language::Korean, "history_forward") => "히스토리 앞으로",
+            (Language::Korean, "scroll_name_left") => "이름 왼쪽으로 스크롤",
+            (Language::Korean, "scroll_name_right") => "이름 오른쪽으로 스크롤",
+            (Language::Korean, "breadcrumbs") => "경로 조각 고르기",
             (Language::Korean, "history_list") => "히스토리 목록 보기",
+            (Language::Korean, "jump_list") => "자주 가는 디렉토리",
+            (Language::Korean, "sibling_list") => "형제 디렉토리로 이동",
+            (Language::Korean, "find_file") => "파일 찾기",
             (Language::Korean, "bookmark_add") => "북마크 추가",
             (Language::Korean, "bookmark_list") => "북마크 목록 보기",
+            (Language::Korean, "bookmark_export") => "북마크 내보내기",
+            (Language::Korean, "bookmark_import") => "북마크 가져오기",
+            (Language::Korean, "session_save") => "세션 저장",
+            (Language::Korean, "session_list") => "세션 목록 보기",
+            (Language::Korean, "job_list") => "최근 백그라운드 작업 보기",
             (Language::Korean, "size_auto") => "크기: 자동",
             (Language::Korean, "size_bytes") => "크기: 바이트",
             (Language::Korean, "about") => "정보",
@@ -650,18 +1315,65 @@ fn localize_runtime_action(input: &str) -> Option<&'static str> {
         "Auto extract archive" => Some("자동 압축 해제"),
         "Preview archive" => Some("압축 미리보기"),
         "Copy from archive" => Some("압축에서 복사"),
+        "Download" => Some("다운로드"),
+        "Upload" => Some("업로드"),
+        "Connect" => Some("원격 접속"),
+        "Open remote directory" => Some("원격 디렉토리 열기"),
         "Move to trash" => Some("휴지통으로 이동"),
+        "Rename via editor" => Some("편집기로 이름 바꾸기"),
         "Create directory" => Some("디렉토리 생성"),
+        "Create file" => Some("파일 생성"),
+        "Read template" => Some("템플릿 읽기"),
         "Open with default app" => Some("기본 프로그램으로 열기"),
         "Open in terminal editor" => Some("터미널 에디터로 열기"),
         "Run command" => Some("명령 실행"),
         "Rename" => Some("이름 변경"),
         "Archive create" => Some("압축 생성"),
         "Archive extract" => Some("압축 해제"),
+        "Archive delete" => Some("압축 항목 삭제"),
+        "Delete from archive" => Some("압축 파일에서 삭제"),
+        "Save session" => Some("세션 저장"),
+        "Delete session" => Some("세션 삭제"),
+        "Analyze disk usage" => Some("디스크 사용량 분석"),
+        "Compare files" => Some("파일 내용 비교"),
+        "Synchronize panels" => Some("패널 동기화"),
+        "Jump to directory" => Some("디렉토리로 이동"),
+        "Sibling directories" => Some("형제 디렉토리"),
+        "Find file" => Some("파일 찾기"),
+        "Export bookmarks" => Some("북마크 내보내기"),
+        "Import bookmarks" => Some("북마크 가져오기"),
+        "Reveal in file manager" => Some("파일 관리자에서 보기"),
+        "Show git diff" => Some("git 변경 내용 보기"),
+        "Discard changes" => Some("변경 되돌리기"),
         _ => None,
     }
 }
 
+/// `pluralize()` 결과("3 items")를 한국어 개수 표기("3개 항목")로 변환
+fn localize_item_count(count: &str) -> String {
+    match count
+        .strip_suffix(" items")
+        .or_else(|| count.strip_suffix(" item"))
+    {
+        Some(n) => format!("{}개 항목", n),
+        None => count.to_string(),
+    }
+}
+
+/// 이동 완료 토스트의 방식 요약("rename)") 변환
+fn localize_move_strategy(strategy: &str) -> String {
+    if strategy == "rename)" {
+        return " (이름 변경)".to_string();
+    }
+    if let Some(count) = strategy.strip_prefix("unchanged skipped: ") {
+        return format!(" (변경 없어 건너뜀: {}", count);
+    }
+    match strategy.strip_prefix("copy + delete across filesystems: ") {
+        Some(count) => format!(" (파일시스템 간 복사 후 삭제: {}", count),
+        None => format!(" ({}", strategy),
+    }
+}
+
 pub fn localize_runtime_text(language: Language, input: &str) -> String {
     if matches!(language, Language::English) {
         return input.to_string();
@@ -681,7 +1393,17 @@ pub fn localize_runtime_text(language: Language, input: &str) -> String {
 
     let exact = match input {
         "Error" => Some("오류"),
+        "Trash failed" => Some("휴지통 이동 실패"),
+        "No items to rename" => Some("이름을 바꿀 항목이 없습니다"),
+        "No names changed" => Some("바뀐 이름이 없습니다"),
+        "Permanently delete them instead? (No leaves them in place)" => {
+            Some("대신 영구 삭제할까요? (아니오: 그대로 둠)")
+        }
         "Information" => Some("정보"),
+        "Run as administrator" => Some("관리자 권한으로 실행"),
+        "Retry with administrator privileges (sudo)?" => {
+            Some("관리자 권한(sudo)으로 다시 시도할까요?")
+        }
         "Mount Points" => Some("마운트 포인트"),
         "History" => Some("히스토리"),
         "Bookmarks" => Some("북마크"),
@@ -698,18 +1420,64 @@ pub fn localize_runtime_text(language: Language, input: &str) -> String {
         "Unsupported archive format:" => Some("지원하지 않는 압축 형식:"),
         "No files selected for archive." => Some("압축할 파일이 선택되지 않았습니다."),
         "No archive entries selected for copy." => Some("복사할 압축 항목이 선택되지 않았습니다."),
+        "No archive entries selected for extract." => Some("풀 압축 항목이 선택되지 않았습니다."),
+        "No archive entries selected for deletion." => {
+            Some("삭제할 압축 항목이 선택되지 않았습니다.")
+        }
+        "Deleting entries is not supported for this archive format" => {
+            Some("이 압축 형식은 항목 삭제를 지원하지 않습니다")
+        }
+        "Only zip and 7z archives can be updated in place." => {
+            Some("zip과 7z 압축 파일만 항목을 지울 수 있습니다.")
+        }
         "No files selected for operation." => Some("작업할 파일이 선택되지 않았습니다."),
         "No files selected for deletion." => Some("삭제할 파일이 선택되지 않았습니다."),
         "No mount points found." => Some("마운트 포인트가 없습니다."),
         "No history entries." => Some("히스토리 항목이 없습니다."),
         "No bookmarks." => Some("북마크가 없습니다."),
+        "Jobs" => Some("작업"),
+        "No background jobs yet." => Some("아직 끝난 백그라운드 작업이 없습니다."),
+        "Statistics" => Some("통계"),
+        "Not enough space" => Some("공간 부족"),
+        "Retry failed" => Some("실패 항목 다시 시도"),
+        "Errors" => Some("오류"),
+        "v: view all errors, s: save report" => Some("v: 전체 오류 보기, s: 보고서 저장"),
+        "No items to summarize." => Some("요약할 항목이 없습니다."),
         "Bookmark deleted" => Some("북마크를 삭제했습니다"),
+        "Sessions" => Some("세션"),
+        "No saved sessions." => Some("저장된 세션이 없습니다."),
+        "Themes reloaded" => Some("테마를 다시 읽었습니다"),
+        "Sessions are disabled (--no-persist)." => {
+            Some("세션을 사용할 수 없습니다 (--no-persist).")
+        }
+        "Session deleted" => Some("세션을 삭제했습니다"),
+        "Session name cannot be empty" => Some("세션 이름은 비울 수 없습니다"),
+        "Session paths not found" => Some("세션 경로를 찾을 수 없습니다"),
         "Bookmark renamed" => Some("북마크 이름을 변경했습니다"),
         "Bookmark name cannot be empty" => Some("북마크 이름은 비울 수 없습니다"),
         "Bookmark already exists" => Some("이미 존재하는 북마크입니다"),
         "Failed to open bookmark path" => Some("북마크 경로를 열지 못했습니다"),
+        "Bookmark path changed" => Some("북마크 경로를 변경했습니다"),
+        "Bookmark path cannot be empty" => Some("북마크 경로는 비울 수 없습니다"),
+        "Bookmark path must be an existing directory" => {
+            Some("북마크 경로는 존재하는 디렉토리여야 합니다")
+        }
+        "Bookmark hotkey cleared" => Some("북마크 단축키를 해제했습니다"),
+        "Not available while browsing an archive or remote panel" => {
+            Some("압축/원격 패널을 보는 중에는 사용할 수 없습니다")
+        }
+        "Bookmark group changed" => Some("북마크 그룹을 변경했습니다"),
+        "Bookmark removed from group" => Some("북마크를 그룹에서 뺐습니다"),
+        "Check the file path and write permission." => {
+            Some("파일 경로와 쓰기 권한을 확인하세요.")
+        }
+        "Check that the file is a bookmark export (TOML)." => {
+            Some("북마크 내보내기 파일(TOML)인지 확인하세요.")
+        }
         "Failed to open history path" => Some("히스토리 경로를 열지 못했습니다"),
         "History cleared" => Some("히스토리를 비웠습니다"),
+        "History entry pinned" => Some("히스토리 항목을 고정했습니다"),
+        "History entry unpinned" => Some("히스토리 항목 고정을 해제했습니다"),
         "History back failed" => Some("뒤로 이동에 실패했습니다"),
         "No back history" => Some("뒤로 갈 히스토리가 없습니다"),
         "History forward failed" => Some("앞으로 이동에 실패했습니다"),
@@ -717,6 +1485,12 @@ pub fn localize_runtime_text(language: Language, input: &str) -> String {
         "Filter cleared" => Some("필터를 해제했습니다"),
         "Hidden files shown" => Some("숨김 파일 표시"),
         "Hidden files hidden" => Some("숨김 파일 숨김"),
+        "Hidden files shown in both panels" => Some("양쪽 패널 숨김 파일 표시"),
+        "Hidden files hidden in both panels" => Some("양쪽 패널 숨김 파일 숨김"),
+        "No hide patterns set" => Some("설정한 숨김 패턴이 없습니다"),
+        "Pattern-hidden files shown" => Some("패턴으로 숨긴 파일 표시"),
+        "Pattern-hidden files hidden" => Some("패턴으로 숨긴 파일 숨김"),
+        "Hide patterns cleared" => Some("숨김 패턴 해제"),
         "Rename completed" => Some("이름 변경 완료"),
         "Directory symlink is not supported for copy/move" => {
             Some("복사/이동에서 디렉토리 심볼릭 링크는 지원하지 않습니다")
@@ -742,7 +1516,24 @@ pub fn localize_runtime_text(language: Language, input: &str) -> String {
             Some("중복 이름과 쓰기 권한을 확인하세요.")
         }
         "Create directory failed." => Some("디렉토리 생성 실패."),
+        "Create file failed." => Some("파일 생성 실패."),
+        "Use a new name and check write permission." => {
+            Some("새 이름을 사용하고 쓰기 권한을 확인하세요.")
+        }
+        "Check the templates directory." => Some("템플릿 디렉토리를 확인하세요."),
+        "Not available in archive panel" => Some("압축 패널에서는 사용할 수 없습니다"),
+        "Quarantine attribute is only available on macOS" => {
+            Some("격리 속성은 macOS에서만 사용할 수 있습니다")
+        }
+        "Editor is only available for local files" => {
+            Some("편집기는 로컬 파일에서만 사용할 수 있습니다")
+        }
         "Rename failed." => Some("이름 변경 실패."),
+        "Name check failed." => Some("이름 검사 실패."),
+        "Invalid name" => Some("사용할 수 없는 이름"),
+        "Use a shorter name or a shallower directory." => {
+            Some("더 짧은 이름이나 더 얕은 디렉토리를 사용하세요.")
+        }
         "Name cannot be empty." => Some("이름은 비울 수 없습니다."),
         "Enter at least one character." => Some("한 글자 이상 입력하세요."),
         "Cannot open parent entry ('..')." => Some("상위 항목('..')은 열 수 없습니다."),
@@ -757,12 +1548,262 @@ pub fn localize_runtime_text(language: Language, input: &str) -> String {
         "Unknown" => Some("알 수 없음"),
         "Password is empty." => Some("비밀번호가 비어 있습니다."),
         "Password and confirmation do not match." => Some("비밀번호와 확인 값이 다릅니다."),
+        "Failed to open path:" => Some("경로를 열 수 없습니다:"),
+        "Calculating..." => Some("계산 중..."),
+        "Command finished" => Some("명령 실행 완료"),
+        "Archive cancel requested..." => Some("압축 작업 취소 요청됨..."),
+        "Transfer cancel requested..." => Some("전송 취소 요청됨..."),
+        "Not available on remote panel" => Some("원격 패널에서는 사용할 수 없습니다"),
+        "Not available in archive or remote panel" => {
+            Some("압축/원격 패널에서는 사용할 수 없습니다")
+        }
+        "Remote host removed" => Some("원격 호스트 삭제됨"),
+        "Copy remote files to a local panel to open them" => {
+            Some("원격 파일은 로컬 패널로 복사한 뒤 여세요")
+        }
+        "Trash is not available on remote panels." => {
+            Some("원격 패널에서는 휴지통을 사용할 수 없습니다.")
+        }
+        "Compare" => Some("비교"),
+        "Synchronize" => Some("동기화"),
+        "Panels are already in sync." => Some("두 패널의 내용이 이미 같습니다."),
+        "Both panels show the same directory." => Some("두 패널이 같은 디렉토리입니다."),
+        "One panel is inside the other." => Some("한 패널이 다른 패널 안의 경로입니다."),
+        "Synchronize cancelled" => Some("동기화를 취소했습니다"),
+        "Nothing checked to synchronize" => Some("동기화할 항목을 체크하지 않았습니다"),
+        "Check that a desktop file manager is available." => {
+            Some("데스크톱 파일 관리자를 사용할 수 있는지 확인하세요.")
+        }
+        "Compare cancelled" => Some("비교를 취소했습니다"),
+        "Git diff" => Some("git 변경 내용"),
+        "No changes to show." => Some("표시할 변경 내용이 없습니다."),
+        "Jump list" => Some("점프 목록"),
+        "Jump to directory" => Some("디렉토리로 이동"),
+        "Switch to sibling directory" => Some("형제 디렉토리로 이동"),
+        "No parent directory" => Some("상위 디렉토리가 없습니다"),
+        "No visited directories yet." => Some("아직 방문한 디렉토리가 없습니다."),
+        "Directory no longer exists." => Some("디렉토리가 더 이상 없습니다."),
+        "It has been removed from the jump list." => Some("점프 목록에서 삭제했습니다."),
+        "Find file" => Some("파일 찾기"),
+        "Too many files; showing only part of the tree" => {
+            Some("파일이 너무 많아 일부만 표시합니다")
+        }
+        "File no longer exists." => Some("파일이 더 이상 없습니다."),
+        "Search again to refresh the file list." => Some("다시 찾아 파일 목록을 갱신하세요."),
+        "Command palette" => Some("명령 팔레트"),
+        "Plugin actions" => Some("플러그인 동작"),
+        "No plugin actions. Enable plugins with `plugins` in the settings file." => {
+            Some("플러그인 동작이 없습니다. 설정 파일의 `plugins`로 플러그인을 켜세요.")
+        }
+        "No audio/video files" => Some("오디오/동영상 파일이 없습니다"),
+        "Discard changes" => Some("변경 되돌리기"),
+        "No unstaged changes to discard." => Some("되돌릴 작업 트리 변경이 없습니다."),
+        "Untracked files have no committed version to restore." => {
+            Some("추적되지 않는 파일은 되돌릴 커밋 버전이 없습니다.")
+        }
+        "Check that git is installed and the item is inside a repository." => {
+            Some("git이 설치되어 있고 항목이 저장소 안에 있는지 확인하세요.")
+        }
+        "Resolve conflicts first and check the repository state." => {
+            Some("충돌을 먼저 해결하고 저장소 상태를 확인하세요.")
+        }
+        "Both panels point to the same file." => Some("두 패널이 같은 파일을 가리킵니다."),
+        "Directories cannot be compared by content." => {
+            Some("디렉토리는 내용으로 비교할 수 없습니다.")
+        }
+        "Check the file path and read permission." => {
+            Some("파일 경로와 읽기 권한을 확인하세요.")
+        }
+        "Use permanent delete (D)." => Some("영구 삭제(D)를 사용하세요."),
+        "Check the remote path." => Some("원격 경로를 확인하세요."),
+        "Check the directory and read permission." => {
+            Some("디렉토리와 읽기 권한을 확인하세요.")
+        }
+        "Check the address and your ssh keys/agent (password login is not supported)." => {
+            Some("주소와 ssh 키/에이전트를 확인하세요 (비밀번호 로그인은 지원하지 않음).")
+        }
+        "Check the address, user and password (URL or ~/.netrc)." => {
+            Some("주소, 사용자, 비밀번호(URL 또는 ~/.netrc)를 확인하세요.")
+        }
+        "Verify after copy: on" => Some("복사 후 검증: 켜짐"),
+        "Verify after copy: off" => Some("복사 후 검증: 꺼짐"),
+        "Preserve timestamps: on" => Some("시각 유지: 켜짐"),
+        "Preserve timestamps: off" => Some("시각 유지: 꺼짐"),
+        "Preserve permissions: on" => Some("권한 유지: 켜짐"),
+        "Preserve permissions: off" => Some("권한 유지: 꺼짐"),
+        "Preserve extended attributes: on" => Some("확장 속성 유지: 켜짐"),
+        "Preserve extended attributes: off" => Some("확장 속성 유지: 꺼짐"),
+        "Update only newer: on" => Some("새로운 파일만 갱신: 켜짐"),
+        "Update only newer: off" => Some("새로운 파일만 갱신: 꺼짐"),
+        "Git status: on" => Some("git 상태 표시: 켜짐"),
+        "Info line: on" => Some("정보 줄: 켜짐"),
+        "Full path line: on" => Some("전체 경로 줄: 켜짐"),
+        "Full path line: off" => Some("전체 경로 줄: 꺼짐"),
+        "No sibling directories" => Some("형제 디렉토리가 없습니다"),
+        "No archive passwords remembered" => Some("기억한 압축 비밀번호가 없습니다"),
+        "Archive name encoding" => Some("압축 파일 이름 인코딩"),
+        "Copy Preview" => Some("복사 미리보기"),
+        "Move Preview" => Some("이동 미리보기"),
+        "Name encoding can be changed in the archive view" => {
+            Some("이름 인코딩은 압축 보기에서 바꿀 수 있습니다")
+        }
+        "Only zip archives need a name encoding" => {
+            Some("이름 인코딩은 zip 압축 파일에만 필요합니다")
+        }
+        "Job history cleared" => Some("작업 기록을 비웠습니다"),
+        "Not an executable file" => Some("실행 파일이 아닙니다"),
+        "Info line: off" => Some("정보 줄: 꺼짐"),
+        "Auto refresh: off" => Some("자동 새로고침: 꺼짐"),
+        "Enter the interval in seconds" => Some("주기를 초 단위로 입력하세요"),
+        "Type column: on" => Some("형식 컬럼: 켜짐"),
+        "Type column: off" => Some("형식 컬럼: 꺼짐"),
+        "Quitting when running jobs finish" => Some("진행 중인 작업이 끝나면 종료합니다"),
+        "Macro stopped: a dialog is open" => Some("매크로 중지: 창이 열려 있습니다"),
+        "No macro played yet" => Some("아직 재생한 매크로가 없습니다"),
+        "Sync browsing: on" => Some("동기 탐색: 켜짐"),
+        "Follow focused directory: on" => Some("포커스 디렉토리 따라가기: 켜짐"),
+        "Follow focused directory: off" => Some("포커스 디렉토리 따라가기: 꺼짐"),
+        "Panels swapped" => Some("좌우 패널을 바꿨습니다"),
+        "Focus a directory to open in the other panel" => {
+            Some("반대 패널에서 열 디렉토리에 커서를 두세요")
+        }
+        "Other panel set to current directory" => Some("반대 패널을 현재 경로로 이동했습니다"),
+        "Other panel is already in this directory" => Some("반대 패널이 이미 같은 경로입니다"),
+        "Sync browsing: off" => Some("동기 탐색: 꺼짐"),
+        "Sync browsing: no matching directory in other panel" => {
+            Some("동기 탐색: 반대 패널에 같은 이름의 디렉토리가 없습니다")
+        }
+        "Git status: off" => Some("git 상태 표시: 꺼짐"),
         _ => None,
     };
     if let Some(msg) = exact {
         return msg.to_string();
     }
 
+    if let Some(action) = input.strip_suffix(" completed with errors.") {
+        return format!(
+            "{} 완료 (오류 있음).",
+            localize_runtime_text(language, action)
+        );
+    }
+    if let Some((action, count)) = input.split_once(" completed: ") {
+        let (count, strategy) = match count.split_once(" (") {
+            Some((count, strategy)) => (count, localize_move_strategy(strategy)),
+            None => (count, String::new()),
+        };
+        return format!(
+            "{} 완료: {}{}",
+            localize_runtime_text(language, action),
+            localize_item_count(count),
+            strategy
+        );
+    }
+    if let Some((action, progress)) = input.split_once(" cancelled (") {
+        return format!(
+            "{} 취소됨 ({}",
+            localize_runtime_text(language, action),
+            progress
+        );
+    }
+    if let Some(value) = input.strip_prefix("Moved ") {
+        if let Some(count) = value.strip_suffix(" to trash.") {
+            return format!("{}을 휴지통으로 이동했습니다.", localize_item_count(count));
+        }
+    }
+    if let Some(count) = input
+        .strip_prefix("Could not move ")
+        .and_then(|rest| rest.strip_suffix(" to trash:"))
+    {
+        return format!("휴지통으로 옮기지 못한 {}:", localize_item_count(count));
+    }
+    if let Some(count) = input
+        .strip_prefix("  ... and ")
+        .and_then(|rest| rest.strip_suffix(" more"))
+    {
+        return format!("  ... 외 {}개", count);
+    }
+    if let Some(count) = input.strip_prefix("Renamed ") {
+        return format!("{} 이름을 바꿨습니다", localize_item_count(count));
+    }
+    if let Some(path) = input.strip_prefix("Error report saved: ") {
+        return format!("오류 보고서를 저장했습니다: {}", path);
+    }
+    if let Some(reason) = input.strip_prefix("Failed to save error report: ") {
+        return format!("오류 보고서를 저장하지 못했습니다: {}", reason);
+    }
+    if let Some(count) = input
+        .strip_prefix("Retry ")
+        .and_then(|rest| rest.strip_suffix(" with the same destination and options?"))
+    {
+        return format!(
+            "실패한 {}을 같은 대상과 옵션으로 다시 시도할까요?",
+            localize_item_count(count)
+        );
+    }
+    if let Some(value) = input.strip_prefix("... and ") {
+        if let Some(count) = value.strip_suffix(" more errors") {
+            return format!("... 외 {}개 오류", count);
+        }
+        if let Some(count) = value.strip_suffix(" more") {
+            return format!("... 외 {}개", count);
+        }
+    }
+    if let Some((operation, count)) = input
+        .strip_suffix('.')
+        .and_then(|rest| rest.split_once(": permission denied for "))
+    {
+        return format!(
+            "{}: {}에 대한 권한이 없습니다.",
+            localize_runtime_text(language, operation),
+            localize_item_count(count)
+        );
+    }
+    if let Some((need, free)) = input
+        .strip_prefix("Not enough free space: need ")
+        .and_then(|rest| rest.strip_suffix(".\nStart anyway?"))
+        .and_then(|rest| rest.split_once(", free "))
+    {
+        return format!(
+            "여유 공간이 부족합니다: 필요 {}, 여유 {}.\n그래도 시작할까요?",
+            need, free
+        );
+    }
+    if let Some(count) = input.strip_prefix("Hide patterns: ") {
+        return format!("숨김 패턴: {}개", count);
+    }
+    if let Some(secs) = input
+        .strip_prefix("Auto refresh: every ")
+        .and_then(|value| value.strip_suffix('s'))
+    {
+        return format!("자동 새로고침: {}초마다", secs);
+    }
+    if let Some(counts) = input.strip_prefix("Synchronized: ") {
+        let counts: Vec<String> = counts
+            .split(", ")
+            .map(|part| match part.split_once(' ') {
+                Some((n, "copied")) => format!("복사 {}", n),
+                Some((n, "deleted")) => format!("삭제 {}", n),
+                Some((n, "failed")) => format!("실패 {}", n),
+                _ => part.to_string(),
+            })
+            .collect();
+        return format!("동기화 완료: {}", counts.join(", "));
+    }
+    if let Some(value) = input.strip_prefix("Succeeded: ") {
+        return format!("성공: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Failed: ") {
+        return format!("실패: {}", value);
+    }
+    if let Some(count) = input
+        .strip_prefix("Filter skipped ")
+        .and_then(|rest| rest.strip_suffix(" items"))
+    {
+        return format!("범위 필터로 {}개 항목 제외", count);
+    }
+    if let Some(value) = input.strip_prefix("Failed to scan files: ") {
+        return format!("파일 검사 실패: {}", value);
+    }
     if let Some(action) = input.strip_suffix(" failed.") {
         return format!("{} 실패.", localize_runtime_text(language, action));
     }
@@ -784,12 +1825,124 @@ pub fn localize_runtime_text(language: Language, input: &str) -> String {
     if let Some(value) = input.strip_prefix("Opened: ") {
         return format!("열기 완료: {}", value);
     }
+    if let Some(value) = input.strip_prefix("Revealed: ") {
+        return format!("파일 관리자에서 표시: {}", value);
+    }
     if let Some(value) = input.strip_prefix("Edited: ") {
         return format!("편집 완료: {}", value);
     }
     if let Some(value) = input.strip_prefix("Bookmark added: ") {
         return format!("북마크 추가: {}", value);
     }
+    if let Some(value) = input.strip_prefix("Bookmark hotkey: ") {
+        return format!("북마크 단축키: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Bookmarks exported: ") {
+        return format!("북마크 내보내기: {}개", value);
+    }
+    if let Some(value) = input.strip_prefix("Bookmarks imported: ") {
+        return format!("북마크 가져오기: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("No bookmark on ") {
+        return format!("{}에 지정된 북마크가 없습니다", value);
+    }
+    if let Some(value) = input.strip_prefix("Connected: ") {
+        return format!("접속됨: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Freed ") {
+        return format!("공간 확보 {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Moved to trash: ") {
+        return format!("휴지통으로 이동: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Delete failed: ") {
+        return format!("삭제 실패: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Identical: ") {
+        return format!("동일: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Different: ") {
+        return format!("다름: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Size: ") {
+        return format!("크기: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("First difference at byte ") {
+        return format!("첫 차이 위치: {}바이트", value);
+    }
+    if let Some(value) = input.strip_prefix("Method: ") {
+        let method = match value {
+            "byte-by-byte" => "바이트 비교",
+            "size only" => "크기 비교",
+            other => other,
+        };
+        return format!("방법: {}", method);
+    }
+    if let Some(value) = input.strip_prefix("Time: ") {
+        return format!("소요 시간: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Git diff: ") {
+        return format!("git 변경 내용: {}", value);
+    }
+    if let Some((count, archive)) = input
+        .strip_prefix("Delete ")
+        .and_then(|value| value.strip_suffix('?'))
+        .and_then(|value| value.split_once(" from "))
+    {
+        if let Some(count) = count
+            .strip_suffix(" entries")
+            .or_else(|| count.strip_suffix(" entry"))
+        {
+            return format!("{}에서 항목 {}개를 삭제할까요?", archive, count);
+        }
+    }
+    if let Some(value) = input.strip_prefix("Forgot ") {
+        if let Some(count) = value
+            .strip_suffix(" archive passwords")
+            .or_else(|| value.strip_suffix(" archive password"))
+        {
+            return format!("압축 비밀번호 {}개를 잊었습니다", count);
+        }
+    }
+    if let Some(value) = input.strip_prefix("Name encoding: ") {
+        return format!("이름 인코딩: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Preset: ") {
+        return format!("프리셋: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Discarded changes: ") {
+        return format!("변경 되돌림: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Discard unstaged changes to '") {
+        if let Some(name) = value.strip_suffix("'? This cannot be undone.") {
+            return format!(
+                "'{}'의 작업 트리 변경을 되돌릴까요? 되돌린 내용은 복구할 수 없습니다.",
+                name
+            );
+        }
+    }
+    if let Some(value) = input.strip_prefix("No file named '") {
+        if let Some(name) = value.strip_suffix("' in the other panel.") {
+            return format!("반대쪽 패널에 '{}' 파일이 없습니다.", name);
+        }
+    }
+    if let Some(value) = input.strip_prefix("Disconnected: ") {
+        return format!("접속 해제: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Deleted ") {
+        if let Some(count) = value.strip_suffix('.') {
+            return format!("{}을 삭제했습니다.", localize_item_count(count));
+        }
+    }
+    if let Some(value) = input.strip_prefix("Session saved: ") {
+        return format!("세션 저장: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Theme reloaded: ") {
+        return format!("테마 다시 불러옴: {}", value);
+    }
+    if let Some(value) = input.strip_prefix("Session restored: ") {
+        return format!("세션 복원: {}", value);
+    }
     if let Some(value) = input.strip_prefix("Filter: ") {
         return format!("필터: {}", value);
     }
@@ -807,6 +1960,17 @@ pub fn localize_runtime_text(language: Language, input: &str) -> String {
             return format!("디렉토리 '{}' 생성 완료.", name);
         }
     }
+    if let Some(value) = input.strip_prefix("File '") {
+        if let Some((name, template)) = value
+            .strip_suffix("'.")
+            .and_then(|rest| rest.split_once("' created from template '"))
+        {
+            return format!("파일 '{}' 생성 완료 (템플릿 '{}').", name, template);
+        }
+        if let Some(name) = value.strip_suffix("' created.") {
+            return format!("파일 '{}' 생성 완료.", name);
+        }
+    }
     if let Some(value) = input.strip_prefix("Format '") {
         if let Some(fmt) = value.strip_suffix("' does not support password (zip/7z only).") {
             return format!(
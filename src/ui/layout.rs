@@ -3,14 +3,17 @@
 //
 // 터미널 크기에 따른 레이아웃 모드:
 // - 80+ cols: 듀얼/싱글 패널 모드 (사용자 전환)
-// - <80 cols: 경고 메시지 표시 (최소 요구사항 미충족)
+// - 40~79 cols: 싱글 패널 모드 (자동)
+// - 24 rows 미만: 하단 커맨드 바 숨김
+// - 40x10 미만: 경고 메시지 표시 (최소 요구사항 미충족)
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 /// 최소 터미널 크기 상수
-pub const MIN_WIDTH: u16 = 80;
-pub const MIN_HEIGHT: u16 = 24;
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 10;
 pub const DUAL_PANEL_MIN_WIDTH: u16 = 80;
+/// 이 높이 미만이면 커맨드 바를 숨겨 패널에 한 줄 더 씀
 pub const STANDARD_HEIGHT: u16 = 24;
 
 /// 레이아웃 모드
@@ -18,7 +21,7 @@ pub const STANDARD_HEIGHT: u16 = 24;
 pub enum LayoutMode {
     /// 듀얼 패널 모드 (80+ cols)
     DualPanel,
-    /// 싱글 패널 모드 (80+ cols, 사용자 선택)
+    /// 싱글 패널 모드 (사용자 선택 또는 80 cols 미만)
     SinglePanel,
     /// 경고 모드 (터미널이 너무 작음)
     TooSmall,
@@ -91,7 +94,7 @@ pub struct LayoutAreas {
     pub right_panel: Rect,
     /// 상태바 영역
     pub status_bar: Rect,
-    /// 하단 커맨드 바 영역
+    /// 하단 커맨드 바 영역 (낮은 터미널에서는 빈 영역)
     pub command_bar: Rect,
     /// 경고 메시지 영역 (TooSmall 모드에서 사용)
     pub warning: Rect,
@@ -150,21 +153,38 @@ impl LayoutManager {
     fn determine_mode(width: u16, height: u16, single_panel_preferred: bool) -> LayoutMode {
         if width < MIN_WIDTH || height < MIN_HEIGHT {
             LayoutMode::TooSmall
-        } else if single_panel_preferred {
+        } else if single_panel_preferred || width < DUAL_PANEL_MIN_WIDTH {
             LayoutMode::SinglePanel
         } else {
             LayoutMode::DualPanel
         }
     }
 
-    /// 터미널 크기 업데이트 및 레이아웃 재계산
-    pub fn update(&mut self, area: Rect) {
+    /// 터미널 크기 업데이트 및 레이아웃 재계산 (크기가 바뀌었으면 true)
+    pub fn update(&mut self, area: Rect) -> bool {
         let width = area.width;
         let height = area.height;
 
+        let resized = self.state.terminal_size != (width, height);
         self.state.terminal_size = (width, height);
         self.state.mode = Self::determine_mode(width, height, self.state.single_panel_preferred);
         self.state.areas = self.calculate_areas(area);
+        resized
+    }
+
+    /// 메인 수직 분할: 메뉴바 | 패널 | 상태바 | 커맨드바 (낮으면 커맨드바 생략)
+    fn split_vertical(area: Rect) -> [Rect; 4] {
+        let command_bar_height = u16::from(area.height >= STANDARD_HEIGHT);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),                  // 메뉴바
+                Constraint::Min(3),                     // 패널 영역
+                Constraint::Length(1),                  // 상태바
+                Constraint::Length(command_bar_height), // 커맨드바
+            ])
+            .split(area);
+        [chunks[0], chunks[1], chunks[2], chunks[3]]
     }
 
     /// 레이아웃 영역 계산
@@ -181,16 +201,7 @@ impl LayoutManager {
 
     /// 듀얼 패널 레이아웃 계산
     fn calculate_dual_panel_areas(&self, area: Rect) -> LayoutAreas {
-        // 메인 수직 레이아웃: 메뉴바 | 패널 | 상태바 | 커맨드바
-        let vertical_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(1), // 메뉴바
-                Constraint::Min(3),    // 패널 영역
-                Constraint::Length(1), // 상태바
-                Constraint::Length(1), // 커맨드바
-            ])
-            .split(area);
+        let vertical_chunks = Self::split_vertical(area);
 
         // 패널 영역을 좌우로 분할
         let panel_chunks = Layout::default()
@@ -213,16 +224,7 @@ impl LayoutManager {
 
     /// 싱글 패널 레이아웃 계산
     fn calculate_single_panel_areas(&self, area: Rect) -> LayoutAreas {
-        // 메인 수직 레이아웃
-        let vertical_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(1), // 메뉴바
-                Constraint::Min(3),    // 패널 영역
-                Constraint::Length(1), // 상태바
-                Constraint::Length(1), // 커맨드바
-            ])
-            .split(area);
+        let vertical_chunks = Self::split_vertical(area);
 
         // 싱글 패널 모드에서는 활성 패널만 전체 너비 사용
         let panel_area = vertical_chunks[1];
@@ -299,6 +301,22 @@ impl LayoutManager {
         matches!(self.state.mode, LayoutMode::SinglePanel)
     }
 
+    /// 커맨드 바 표시 여부
+    pub fn command_bar_visible(&self) -> bool {
+        self.state.areas.command_bar.height > 0
+    }
+
+    /// 패널 영역 높이 (테두리 포함; 레이아웃 계산 전이면 표준 바 3줄을 뺀 높이)
+    pub fn panel_height(&self) -> u16 {
+        let areas = &self.state.areas;
+        let height = areas.left_panel.height.max(areas.right_panel.height);
+        if height > 0 {
+            height
+        } else {
+            self.state.terminal_size.1.saturating_sub(3)
+        }
+    }
+
     /// 터미널이 너무 작은지 확인
     pub fn is_too_small(&self) -> bool {
         matches!(self.state.mode, LayoutMode::TooSmall)
@@ -341,17 +359,23 @@ mod tests {
             LayoutMode::SinglePanel
         );
 
-        // TooSmall 모드 (80 미만 또는 24 미만)
+        // 80 미만은 자동 싱글 패널, 24 미만도 패널은 유지
         assert_eq!(
             LayoutManager::determine_mode(79, 24, false),
-            LayoutMode::TooSmall
+            LayoutMode::SinglePanel
         );
         assert_eq!(
-            LayoutManager::determine_mode(40, 24, true),
+            LayoutManager::determine_mode(80, 23, false),
+            LayoutMode::DualPanel
+        );
+
+        // TooSmall 모드 (40 미만 또는 10 미만)
+        assert_eq!(
+            LayoutManager::determine_mode(39, 24, true),
             LayoutMode::TooSmall
         );
         assert_eq!(
-            LayoutManager::determine_mode(80, 23, false),
+            LayoutManager::determine_mode(80, 9, false),
             LayoutMode::TooSmall
         );
     }
@@ -379,6 +403,24 @@ mod tests {
         assert_eq!(wide_left.right, 30);
     }
 
+    #[test]
+    fn test_short_terminal_hides_command_bar_and_reports_resize() {
+        let mut manager = LayoutManager::new();
+        assert!(manager.update(Rect::new(0, 0, 120, 40)));
+        assert!(manager.command_bar_visible());
+        assert_eq!(manager.panel_height(), 37);
+        assert!(!manager.update(Rect::new(0, 0, 120, 40)));
+
+        assert!(manager.update(Rect::new(0, 0, 120, 20)));
+        assert!(!manager.command_bar_visible());
+        assert_eq!(manager.areas().status_bar.y, 19);
+        assert_eq!(manager.panel_height(), 18);
+
+        assert!(manager.update(Rect::new(0, 0, 60, 20)));
+        assert!(manager.is_single_panel());
+        assert_eq!(manager.areas().left_panel.width, 60);
+    }
+
     #[test]
     fn test_toggle_layout_mode() {
         let mut manager = LayoutManager::new();
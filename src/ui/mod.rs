@@ -1,5 +1,6 @@
 // UI Layer
 pub mod components;
+pub mod highlight;
 pub mod i18n;
 pub mod layout;
 pub mod theme;
@@ -9,8 +10,10 @@ pub use layout::{ActivePanel, LayoutManager, LayoutMode};
 
 // Re-export components
 pub use components::{
-    create_default_menus, CommandBar, Dialog, DialogKind, DropdownMenu, InputPurpose, Menu,
-    MenuBar, MenuState, Panel, PanelStatus, StatusBar, WarningScreen,
+    create_context_menu, create_default_menus, BookmarkEditField, BookmarkListItem,
+    BookmarkListRow, CommandBar, Dialog, DialogKind, DiskUsageItem, DropdownMenu, FuzzyListItem,
+    FuzzyListPurpose, HistoryListItem, InputPurpose, Menu, MenuBar, MenuState, MountPointItem,
+    Panel, PanelStatus, StatusBar, WarningScreen,
 };
 pub use i18n::{localize_runtime_text, I18n, Language, MessageKey, TextKey};
 
@@ -1,10 +1,13 @@
 // TODO: 추후 UI 컴포넌트에서 사용 예정 - Phase 1.3 완료 시 제거
 #![allow(dead_code)]
 
+use crate::ui::highlight::HighlightRules;
+use ratatui::buffer::Buffer;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 /// 색상 테마 시스템
 ///
@@ -44,12 +47,16 @@ pub struct Theme {
     pub warning: ColorDef,
     pub error: ColorDef,
     pub success: ColorDef,
+
+    // 파일 하이라이트 규칙 (없으면 Dark 기본 규칙)
+    #[serde(default)]
+    pub highlight: HighlightRules,
 }
 
 /// 색상 정의 (TOML 직렬화/역직렬화 지원)
 ///
 /// Hex 문자열("#1e1e1e") 또는 색상 이름("Red")을 지원합니다.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ColorDef {
     Hex(String),
@@ -150,6 +157,9 @@ impl Theme {
             warning: "#ffa500".into(),
             error: "#f44747".into(),
             success: "#4ec9b0".into(),
+
+            // 파일 하이라이트
+            highlight: HighlightRules::dark(),
         }
     }
 
@@ -188,6 +198,9 @@ impl Theme {
             warning: "#ff8c00".into(),
             error: "#e51400".into(),
             success: "#107c10".into(),
+
+            // 파일 하이라이트
+            highlight: HighlightRules::light(),
         }
     }
 
@@ -226,6 +239,9 @@ impl Theme {
             warning: "#ffff00".into(),
             error: "#ff0000".into(),
             success: "#00ff00".into(),
+
+            // 파일 하이라이트
+            highlight: HighlightRules::high_contrast(),
         }
     }
 
@@ -244,13 +260,223 @@ impl Theme {
     }
 }
 
+/// 색상 출력 모드 설정 (`settings.toml`의 `color_mode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// 터미널 환경변수로 자동 감지
+    #[default]
+    Auto,
+    #[serde(alias = "24bit")]
+    Truecolor,
+    #[serde(rename = "256")]
+    Ansi256,
+    #[serde(rename = "16")]
+    Ansi16,
+}
+
+/// 실제 출력 색상 깊이
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorMode {
+    /// 설정값을 실제 색상 깊이로 변환 (Auto는 환경변수로 감지)
+    pub fn resolve(self) -> ColorDepth {
+        match self {
+            ColorMode::Auto => detect_color_depth(),
+            ColorMode::Truecolor => ColorDepth::TrueColor,
+            ColorMode::Ansi256 => ColorDepth::Ansi256,
+            ColorMode::Ansi16 => ColorDepth::Ansi16,
+        }
+    }
+}
+
+/// 현재 터미널의 색상 깊이 감지 (`COLORTERM`, `TERM`, Windows Terminal)
+pub fn detect_color_depth() -> ColorDepth {
+    if std::env::var_os("WT_SESSION").is_some() {
+        return ColorDepth::TrueColor;
+    }
+    detect_color_depth_from(
+        std::env::var("COLORTERM").ok().as_deref(),
+        std::env::var("TERM").ok().as_deref(),
+    )
+}
+
+/// 환경변수 값으로 색상 깊이 판단
+///
+/// - `COLORTERM=truecolor|24bit` 또는 `TERM=*-direct` → TrueColor
+/// - `TERM=*256color*` → 256색
+/// - `TERM=linux|dumb|vt*|*-16color|*-color` → 16색
+/// - `TERM` 없음(Windows 콘솔) → TrueColor, 그 외 → 256색
+pub fn detect_color_depth_from(colorterm: Option<&str>, term: Option<&str>) -> ColorDepth {
+    if let Some(colorterm) = colorterm {
+        let colorterm = colorterm.to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorDepth::TrueColor;
+        }
+    }
+    let Some(term) = term.map(str::to_ascii_lowercase) else {
+        return ColorDepth::TrueColor;
+    };
+    if term.ends_with("-direct") || term.contains("truecolor") {
+        ColorDepth::TrueColor
+    } else if term.contains("256") {
+        ColorDepth::Ansi256
+    } else if term == "linux"
+        || term == "dumb"
+        || term.starts_with("vt")
+        || term.ends_with("-16color")
+        || term.ends_with("-color")
+    {
+        ColorDepth::Ansi16
+    } else {
+        ColorDepth::Ansi256
+    }
+}
+
+/// 16색 팔레트 (xterm 기본값 기준 RGB)
+const ANSI16_PALETTE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// 256색 큐브(16~231) 단계 값
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// 사람 눈 민감도를 반영한 색상 거리 (녹색 가중)
+fn color_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (2 * dr * dr + 4 * dg * dg + 3 * db * db) as u32
+}
+
+fn nearest_cube_index(value: u8) -> usize {
+    CUBE_LEVELS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, level)| (**level as i32 - value as i32).abs())
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// RGB → 가장 가까운 256색 인덱스 (6x6x6 큐브 또는 회색조 중 선택)
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let (ri, gi, bi) = (
+        nearest_cube_index(r),
+        nearest_cube_index(g),
+        nearest_cube_index(b),
+    );
+    let cube_rgb = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    let avg = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (avg.saturating_sub(3) / 10).min(23) as u8;
+    let gray_value = 8 + gray_step * 10;
+    let gray_index = 232 + gray_step as usize;
+
+    let target = (r, g, b);
+    if color_distance(target, (gray_value, gray_value, gray_value))
+        < color_distance(target, cube_rgb)
+    {
+        gray_index as u8
+    } else {
+        cube_index as u8
+    }
+}
+
+/// 256색 인덱스 → RGB
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16_PALETTE[index as usize].1,
+        16..=231 => {
+            let i = (index - 16) as usize;
+            (
+                CUBE_LEVELS[i / 36],
+                CUBE_LEVELS[(i / 6) % 6],
+                CUBE_LEVELS[i % 6],
+            )
+        }
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+/// RGB → 가장 가까운 16색
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16_PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| color_distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// 색상 깊이에 맞게 색상 변환 (이름 색상/Reset은 그대로)
+pub fn downgrade_color(color: Color, depth: ColorDepth) -> Color {
+    match (depth, color) {
+        (ColorDepth::TrueColor, _) => color,
+        (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => rgb_to_ansi16(r, g, b),
+        (ColorDepth::Ansi16, Color::Indexed(index)) => {
+            let (r, g, b) = ansi256_to_rgb(index);
+            rgb_to_ansi16(r, g, b)
+        }
+        _ => color,
+    }
+}
+
+/// 렌더링된 버퍼 전체 셀의 전경/배경색을 색상 깊이에 맞게 변환
+pub fn downgrade_buffer(buf: &mut Buffer, depth: ColorDepth) {
+    if depth == ColorDepth::TrueColor {
+        return;
+    }
+    for cell in buf.content.iter_mut() {
+        cell.fg = downgrade_color(cell.fg, depth);
+        cell.bg = downgrade_color(cell.bg, depth);
+    }
+}
+
+/// 내장 테마 이름
+pub const BUILTIN_THEME_NAMES: [&str; 3] = ["dark", "light", "high_contrast"];
+
+/// 사용자 테마 파일 (핫 리로드 변경 감지용)
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CustomThemeFile {
+    name: String,
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
 /// 테마 관리자
 ///
 /// 현재 활성 테마를 관리하고 런타임에 테마를 전환합니다.
+/// 사용자 테마 디렉토리(`*.toml`)를 등록하면 파일 변경 시 다시 불러옵니다.
 pub struct ThemeManager {
     current_theme: Theme,
     current_theme_name: String,
     available_themes: Vec<(String, Theme)>,
+    custom_dir: Option<PathBuf>,
+    custom_files: Vec<CustomThemeFile>,
 }
 
 impl ThemeManager {
@@ -276,24 +502,28 @@ impl ThemeManager {
         Self {
             current_theme: Theme::dark(),
             current_theme_name: "dark".to_string(),
-            available_themes: vec![
-                ("dark".to_string(), Theme::dark()),
-                ("light".to_string(), Theme::light()),
-                ("high_contrast".to_string(), Theme::high_contrast()),
-            ],
+            available_themes: Self::builtin_themes(),
+            custom_dir: None,
+            custom_files: Vec::new(),
         }
     }
 
+    fn builtin_themes() -> Vec<(String, Theme)> {
+        vec![
+            ("dark".to_string(), Theme::dark()),
+            ("light".to_string(), Theme::light()),
+            ("high_contrast".to_string(), Theme::high_contrast()),
+        ]
+    }
+
     /// 특정 테마로 초기화
     pub fn with_theme(theme: Theme) -> Self {
         Self {
             current_theme_name: Self::infer_theme_name(&theme),
             current_theme: theme,
-            available_themes: vec![
-                ("dark".to_string(), Theme::dark()),
-                ("light".to_string(), Theme::light()),
-                ("high_contrast".to_string(), Theme::high_contrast()),
-            ],
+            available_themes: Self::builtin_themes(),
+            custom_dir: None,
+            custom_files: Vec::new(),
         }
     }
 
@@ -344,32 +574,105 @@ impl ThemeManager {
         self.available_themes.push((name, theme));
     }
 
-    /// 설정 디렉토리에서 테마 파일 로드
-    pub fn load_themes_from_config_dir(&mut self) -> Result<(), anyhow::Error> {
-        if let Some(config_dir) = dirs::config_dir() {
-            let themes_dir = config_dir.join("boksldir").join("themes");
+    /// 사용자 테마 여부
+    pub fn is_custom(&self, name: &str) -> bool {
+        self.custom_theme_path(name).is_some()
+    }
+
+    /// 사용자 테마 파일 경로
+    pub fn custom_theme_path(&self, name: &str) -> Option<&Path> {
+        self.custom_files
+            .iter()
+            .find(|file| file.name == name && self.is_loaded(name))
+            .map(|file| file.path.as_path())
+    }
+
+    /// 사용자 테마 디렉토리 등록 및 로드
+    ///
+    /// 모든 색상 필드를 정의한 `*.toml`만 추가되며, 내장 테마와 같은 이름은 무시합니다.
+    pub fn load_custom_themes(&mut self, dir: &Path) {
+        self.custom_dir = Some(dir.to_path_buf());
+        let files = Self::scan_theme_dir(dir);
+        self.apply_custom_files(files);
+    }
+
+    /// 사용자 테마 파일이 추가/삭제/수정되었으면 다시 로드
+    ///
+    /// 현재 테마가 다시 로드(또는 삭제되어 dark로 복귀)되었으면 true
+    pub fn reload_if_changed(&mut self) -> bool {
+        let Some(dir) = self.custom_dir.clone() else {
+            return false;
+        };
+        let files = Self::scan_theme_dir(&dir);
+        if files == self.custom_files {
+            return false;
+        }
 
-            if themes_dir.exists() {
-                for entry in fs::read_dir(themes_dir)? {
-                    let entry = entry?;
-                    let path = entry.path();
+        let current = self.current_theme_name.clone();
+        let before = self
+            .custom_files
+            .iter()
+            .find(|file| file.name == current)
+            .cloned();
+        self.apply_custom_files(files);
+        if before.is_none() {
+            return false;
+        }
 
-                    if path.extension().and_then(|s| s.to_str()) == Some("toml") {
-                        if let Ok(theme) = Theme::from_file(path.clone()) {
-                            let name = path
-                                .file_stem()
-                                .and_then(|s| s.to_str())
-                                .unwrap_or("custom")
-                                .to_string();
+        let after = self
+            .custom_files
+            .iter()
+            .find(|file| file.name == current)
+            .cloned();
+        // 파싱에 실패하면 마지막으로 읽은 색상을 유지
+        match after {
+            Some(file) if Some(&file) == before.as_ref() => false,
+            Some(_) => self.switch_theme(&current).is_ok(),
+            None => {
+                let _ = self.switch_theme("dark");
+                true
+            }
+        }
+    }
 
-                            self.add_theme(name, theme);
-                        }
-                    }
+    fn scan_theme_dir(dir: &Path) -> Vec<CustomThemeFile> {
+        let Ok(read_dir) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut files: Vec<CustomThemeFile> = read_dir
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("toml"))
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_str()?.to_string();
+                if BUILTIN_THEME_NAMES.contains(&name.as_str()) {
+                    return None;
                 }
+                let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+                Some(CustomThemeFile {
+                    name,
+                    path,
+                    modified,
+                })
+            })
+            .collect();
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        files
+    }
+
+    /// 스캔 결과로 사용자 테마 목록 교체 (파싱 실패 파일은 테마 목록에서 제외)
+    fn apply_custom_files(&mut self, files: Vec<CustomThemeFile>) {
+        self.available_themes = Self::builtin_themes();
+        for file in &files {
+            if let Ok(theme) = Theme::from_file(file.path.clone()) {
+                self.add_theme(file.name.clone(), theme);
             }
         }
+        self.custom_files = files;
+    }
 
-        Ok(())
+    fn is_loaded(&self, name: &str) -> bool {
+        self.available_themes.iter().any(|(n, _)| n == name)
     }
 }
 
@@ -440,4 +743,172 @@ mod tests {
         assert_eq!(manager.current_name(), "light");
         assert_eq!(manager.available_themes().len(), 3);
     }
+
+    fn write_theme(dir: &Path, name: &str, bg: &str, modified: SystemTime) {
+        let mut theme = Theme::dark();
+        theme.bg_primary = bg.into();
+        let path = dir.join(format!("{}.toml", name));
+        theme.save_to_file(path.clone()).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_load_custom_themes_skips_invalid_and_builtin_names() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let now = SystemTime::now();
+        write_theme(temp.path(), "ocean", "#001122", now);
+        write_theme(temp.path(), "dark", "#ffffff", now);
+        fs::write(temp.path().join("partial.toml"), "bg_primary = \"#000000\"").unwrap();
+
+        let mut manager = ThemeManager::new();
+        manager.load_custom_themes(temp.path());
+        assert_eq!(
+            manager.available_themes(),
+            vec!["dark", "light", "high_contrast", "ocean"]
+        );
+        assert!(manager.is_custom("ocean"));
+        assert!(!manager.is_custom("partial"));
+        assert!(!manager.is_custom("dark"));
+        assert_eq!(
+            manager.current().bg_primary.to_color(),
+            Color::Rgb(30, 30, 30)
+        );
+    }
+
+    #[test]
+    fn test_reload_if_changed_updates_current_custom_theme() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let t0 = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        write_theme(temp.path(), "ocean", "#001122", t0);
+
+        let mut manager = ThemeManager::new();
+        manager.load_custom_themes(temp.path());
+        manager.switch_theme("ocean").unwrap();
+        assert!(!manager.reload_if_changed());
+
+        write_theme(
+            temp.path(),
+            "ocean",
+            "#334455",
+            t0 + std::time::Duration::from_secs(5),
+        );
+        assert!(manager.reload_if_changed());
+        assert_eq!(
+            manager.current().bg_primary.to_color(),
+            Color::Rgb(0x33, 0x44, 0x55)
+        );
+
+        // 다른 테마 파일 추가는 현재 테마에 영향 없음
+        write_theme(temp.path(), "forest", "#112200", t0);
+        assert!(!manager.reload_if_changed());
+        assert!(manager.is_custom("forest"));
+
+        fs::remove_file(temp.path().join("ocean.toml")).unwrap();
+        assert!(manager.reload_if_changed());
+        assert_eq!(manager.current_name(), "dark");
+    }
+
+    #[test]
+    fn test_detect_color_depth_from_env() {
+        assert_eq!(
+            detect_color_depth_from(Some("truecolor"), Some("xterm-256color")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect_color_depth_from(None, Some("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect_color_depth_from(None, Some("xterm-direct")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect_color_depth_from(None, Some("linux")),
+            ColorDepth::Ansi16
+        );
+        assert_eq!(
+            detect_color_depth_from(None, Some("xterm")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(detect_color_depth_from(None, None), ColorDepth::TrueColor);
+    }
+
+    #[test]
+    fn test_rgb_to_ansi256() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        // 회색은 회색조 구간 우선 (#1e1e1e ≈ 234)
+        assert_eq!(rgb_to_ansi256(30, 30, 30), 234);
+        // 큐브 값과 정확히 일치하면 큐브 사용 (#0087d7 = 32)
+        assert_eq!(rgb_to_ansi256(0, 135, 215), 32);
+    }
+
+    #[test]
+    fn test_downgrade_color() {
+        let rgb = Color::Rgb(0, 120, 212);
+        assert_eq!(downgrade_color(rgb, ColorDepth::TrueColor), rgb);
+        assert_eq!(
+            downgrade_color(rgb, ColorDepth::Ansi256),
+            Color::Indexed(32)
+        );
+        assert_eq!(downgrade_color(rgb, ColorDepth::Ansi16), Color::LightBlue);
+        assert_eq!(
+            downgrade_color(Color::Rgb(250, 250, 250), ColorDepth::Ansi16),
+            Color::White
+        );
+        assert_eq!(
+            downgrade_color(Color::Indexed(196), ColorDepth::Ansi16),
+            Color::LightRed
+        );
+        assert_eq!(
+            downgrade_color(Color::Cyan, ColorDepth::Ansi256),
+            Color::Cyan
+        );
+        assert_eq!(
+            downgrade_color(Color::Reset, ColorDepth::Ansi16),
+            Color::Reset
+        );
+    }
+
+    #[test]
+    fn test_downgrade_buffer() {
+        use ratatui::layout::Rect;
+        use ratatui::style::Style;
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buf.set_string(
+            0,
+            0,
+            "ab",
+            Style::default()
+                .fg(Color::Rgb(255, 0, 0))
+                .bg(Color::Rgb(0, 0, 0)),
+        );
+        downgrade_buffer(&mut buf, ColorDepth::Ansi16);
+        let cell = &buf.content[0];
+        assert_eq!(cell.fg, Color::LightRed);
+        assert_eq!(cell.bg, Color::Black);
+    }
+
+    #[test]
+    fn test_color_mode_serde() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            mode: ColorMode,
+        }
+        let parse =
+            |s: &str| toml::from_str::<Wrapper>(&format!("mode = \"{}\"", s)).map(|w| w.mode);
+        assert_eq!(parse("auto").unwrap(), ColorMode::Auto);
+        assert_eq!(parse("truecolor").unwrap(), ColorMode::Truecolor);
+        assert_eq!(parse("24bit").unwrap(), ColorMode::Truecolor);
+        assert_eq!(parse("256").unwrap(), ColorMode::Ansi256);
+        assert_eq!(parse("16").unwrap(), ColorMode::Ansi16);
+        assert!(parse("8").is_err());
+    }
 }
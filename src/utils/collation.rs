@@ -0,0 +1,285 @@
+//! 파일 이름 비교 (외부 크레이트 없이 구현)
+//!
+//! - 자연 정렬: 숫자 구간을 수치로 비교 (`file2` < `file10`)
+//! - 로캘 인식 정렬: 라틴 악센트/전각 문자 접기, 한글 자모 단위 비교
+//!   (호환 자모 `ㄱ`이 `가` 앞에 오도록 정렬)
+
+use std::cmp::Ordering;
+
+/// 이름 비교 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NameCollation {
+    /// 숫자 구간을 수치로 비교
+    pub natural: bool,
+    /// 로캘 인식 비교 (악센트/전각/한글 자모 접기)
+    pub locale: bool,
+}
+
+/// 대소문자 무시 이름 비교
+pub fn compare_names(a: &str, b: &str, collation: NameCollation) -> Ordering {
+    let plain_a: Vec<char> = a.to_lowercase().chars().collect();
+    let plain_b: Vec<char> = b.to_lowercase().chars().collect();
+
+    if !collation.locale {
+        return compare_chars(&plain_a, &plain_b, collation.natural);
+    }
+
+    let folded_a = fold_for_locale(&plain_a);
+    let folded_b = fold_for_locale(&plain_b);
+    compare_chars(&folded_a, &folded_b, collation.natural)
+        .then_with(|| compare_chars(&plain_a, &plain_b, collation.natural))
+}
+
+fn compare_chars(a: &[char], b: &[char], natural: bool) -> Ordering {
+    if natural {
+        natural_cmp(a, b)
+    } else {
+        a.cmp(b)
+    }
+}
+
+/// 숫자 구간은 수치로, 나머지는 문자 단위로 비교
+///
+/// 수치가 같으면 선행 0이 적은 쪽이 앞 (`1` < `01`)
+fn natural_cmp(a: &[char], b: &[char]) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+    let mut zero_tiebreak = Ordering::Equal;
+
+    while i < a.len() && j < b.len() {
+        if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+            let start_a = i;
+            while i < a.len() && a[i].is_ascii_digit() {
+                i += 1;
+            }
+            let start_b = j;
+            while j < b.len() && b[j].is_ascii_digit() {
+                j += 1;
+            }
+
+            let run_a = &a[start_a..i];
+            let run_b = &b[start_b..j];
+            let zeros_a = run_a.iter().take_while(|c| **c == '0').count();
+            let zeros_b = run_b.iter().take_while(|c| **c == '0').count();
+            let digits_a = &run_a[zeros_a..];
+            let digits_b = &run_b[zeros_b..];
+
+            let cmp = digits_a
+                .len()
+                .cmp(&digits_b.len())
+                .then_with(|| digits_a.cmp(digits_b));
+            if cmp != Ordering::Equal {
+                return cmp;
+            }
+            if zero_tiebreak == Ordering::Equal {
+                zero_tiebreak = zeros_a.cmp(&zeros_b);
+            }
+            continue;
+        }
+
+        let cmp = a[i].cmp(&b[j]);
+        if cmp != Ordering::Equal {
+            return cmp;
+        }
+        i += 1;
+        j += 1;
+    }
+
+    (a.len() - i).cmp(&(b.len() - j)).then(zero_tiebreak)
+}
+
+const HANGUL_SYLLABLE_BASE: u32 = 0xAC00;
+const HANGUL_SYLLABLE_LAST: u32 = 0xD7A3;
+const HANGUL_CHOSEONG_BASE: u32 = 0x1100;
+const HANGUL_JUNGSEONG_BASE: u32 = 0x1161;
+const HANGUL_JONGSEONG_BASE: u32 = 0x11A7;
+const HANGUL_JUNGSEONG_COUNT: u32 = 21;
+const HANGUL_JONGSEONG_COUNT: u32 = 28;
+
+/// 호환 자모 자음(ㄱ~ㅎ) → 초성 인덱스 (겹받침 전용 자모는 None)
+fn compat_consonant_to_choseong(c: char) -> Option<u32> {
+    let idx = match c {
+        'ㄱ' => 0,
+        'ㄲ' => 1,
+        'ㄴ' => 2,
+        'ㄷ' => 3,
+        'ㄸ' => 4,
+        'ㄹ' => 5,
+        'ㅁ' => 6,
+        'ㅂ' => 7,
+        'ㅃ' => 8,
+        'ㅅ' => 9,
+        'ㅆ' => 10,
+        'ㅇ' => 11,
+        'ㅈ' => 12,
+        'ㅉ' => 13,
+        'ㅊ' => 14,
+        'ㅋ' => 15,
+        'ㅌ' => 16,
+        'ㅍ' => 17,
+        'ㅎ' => 18,
+        _ => return None,
+    };
+    Some(idx)
+}
+
+fn fold_latin(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è'..='ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ì'..='ï' | 'ī' | 'į' => "i",
+        'ł' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò'..='ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ß' => "ss",
+        'ś' | 'š' | 'ş' => "s",
+        'ť' | 'ţ' => "t",
+        'ù'..='ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+/// 로캘 인식 비교용 문자열 접기
+fn fold_for_locale(chars: &[char]) -> Vec<char> {
+    let mut out = Vec::with_capacity(chars.len());
+    for &c in chars {
+        let code = c as u32;
+
+        // 한글 음절 → 초성/중성/종성 자모
+        if (HANGUL_SYLLABLE_BASE..=HANGUL_SYLLABLE_LAST).contains(&code) {
+            let offset = code - HANGUL_SYLLABLE_BASE;
+            let l = offset / (HANGUL_JUNGSEONG_COUNT * HANGUL_JONGSEONG_COUNT);
+            let v = (offset % (HANGUL_JUNGSEONG_COUNT * HANGUL_JONGSEONG_COUNT))
+                / HANGUL_JONGSEONG_COUNT;
+            let t = offset % HANGUL_JONGSEONG_COUNT;
+            out.extend(char::from_u32(HANGUL_CHOSEONG_BASE + l));
+            out.extend(char::from_u32(HANGUL_JUNGSEONG_BASE + v));
+            if t > 0 {
+                out.extend(char::from_u32(HANGUL_JONGSEONG_BASE + t));
+            }
+            continue;
+        }
+
+        // 호환 자모 (ㄱ, ㅏ 등) → 조합형 자모
+        if let Some(idx) = compat_consonant_to_choseong(c) {
+            out.extend(char::from_u32(HANGUL_CHOSEONG_BASE + idx));
+            continue;
+        }
+        if ('ㅏ'..='ㅣ').contains(&c) {
+            out.extend(char::from_u32(HANGUL_JUNGSEONG_BASE + (code - 'ㅏ' as u32)));
+            continue;
+        }
+
+        // 전각 ASCII → 반각
+        if ('\u{FF01}'..='\u{FF5E}').contains(&c) {
+            out.extend(char::from_u32(code - 0xFEE0).map(|ch| ch.to_ascii_lowercase()));
+            continue;
+        }
+
+        if let Some(folded) = fold_latin(c) {
+            out.extend(folded.chars());
+            continue;
+        }
+
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAIN: NameCollation = NameCollation {
+        natural: false,
+        locale: false,
+    };
+    const NATURAL: NameCollation = NameCollation {
+        natural: true,
+        locale: false,
+    };
+    const LOCALE: NameCollation = NameCollation {
+        natural: false,
+        locale: true,
+    };
+
+    fn sorted(names: &[&str], collation: NameCollation) -> Vec<String> {
+        let mut v: Vec<String> = names.iter().map(|s| s.to_string()).collect();
+        v.sort_by(|a, b| compare_names(a, b, collation));
+        v
+    }
+
+    #[test]
+    fn test_plain_is_case_insensitive_lexicographic() {
+        assert_eq!(
+            sorted(&["file10.txt", "File2.txt", "file1.txt"], PLAIN),
+            vec!["file1.txt", "file10.txt", "File2.txt"]
+        );
+    }
+
+    #[test]
+    fn test_natural_orders_numbers_numerically() {
+        assert_eq!(
+            sorted(&["file10.txt", "file2.txt", "file1.txt", "file"], NATURAL),
+            vec!["file", "file1.txt", "file2.txt", "file10.txt"]
+        );
+        assert_eq!(
+            sorted(&["v1.10.0", "v1.9.2", "v1.2.10"], NATURAL),
+            vec!["v1.2.10", "v1.9.2", "v1.10.0"]
+        );
+    }
+
+    #[test]
+    fn test_natural_leading_zero_tiebreak() {
+        assert_eq!(compare_names("a1", "a01", NATURAL), Ordering::Less);
+        assert_eq!(compare_names("a01", "a2", NATURAL), Ordering::Less);
+        assert_eq!(
+            compare_names(
+                "a99999999999999999999999",
+                "a100000000000000000000000",
+                NATURAL
+            ),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_locale_folds_accents_and_fullwidth() {
+        assert_eq!(
+            sorted(&["zebra", "éclair", "apple"], LOCALE),
+            vec!["apple", "éclair", "zebra"]
+        );
+        // 악센트만 다르면 원문 기준으로 안정적 정렬
+        assert_eq!(compare_names("e", "é", LOCALE), Ordering::Less);
+        assert_eq!(compare_names("ＡＢＣ", "abd", LOCALE), Ordering::Less);
+    }
+
+    #[test]
+    fn test_locale_hangul_compat_jamo_sorts_with_syllables() {
+        // 코드포인트 순서로는 ㄴ(U+3134)가 가(U+AC00)보다 앞
+        assert_eq!(compare_names("ㄴ", "가", PLAIN), Ordering::Less);
+        assert_eq!(
+            sorted(&["나무", "ㄴ", "가방", "ㄱ", "각"], LOCALE),
+            vec!["ㄱ", "가방", "각", "ㄴ", "나무"]
+        );
+    }
+
+    #[test]
+    fn test_locale_and_natural_combined() {
+        let both = NameCollation {
+            natural: true,
+            locale: true,
+        };
+        assert_eq!(
+            sorted(&["사진10.jpg", "사진2.jpg", "사진1.jpg"], both),
+            vec!["사진1.jpg", "사진2.jpg", "사진10.jpg"]
+        );
+    }
+}
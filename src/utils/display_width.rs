@@ -0,0 +1,130 @@
+//! 터미널 표시 폭(display cell) 유틸리티
+//!
+//! 한글/한자/이모지 등 전각 문자는 2칸을 차지하므로, 화면 정렬과 생략은
+//! 문자 수(`chars().count()`)가 아니라 이 모듈의 폭 기준으로 계산합니다.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// 말줄임 문자 (1칸)
+pub const ELLIPSIS: &str = "…";
+
+/// 문자열 표시 폭
+pub fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+/// 문자 표시 폭 (폭을 알 수 없는 제어 문자는 1칸으로 취급)
+pub fn char_width(ch: char) -> usize {
+    ch.width().unwrap_or(1)
+}
+
+/// 앞에서부터 max_width 칸 이내의 부분 문자열 (전각 문자는 쪼개지 않음)
+pub fn take_prefix(text: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (i, ch) in text.char_indices() {
+        let ch_width = char_width(ch);
+        if width + ch_width > max_width {
+            return &text[..i];
+        }
+        width += ch_width;
+    }
+    text
+}
+
+/// 뒤에서부터 max_width 칸 이내의 부분 문자열 (전각 문자는 쪼개지 않음)
+pub fn take_suffix(text: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (i, ch) in text.char_indices().rev() {
+        let ch_width = char_width(ch);
+        if width + ch_width > max_width {
+            return &text[i + ch.len_utf8()..];
+        }
+        width += ch_width;
+    }
+    text
+}
+
+/// max_width 칸을 넘으면 뒤를 잘라 `ellipsis`를 붙인다.
+///
+/// 말줄임 문자조차 들어가지 않는 폭이면 말줄임 없이 자른다.
+pub fn truncate_end(text: &str, max_width: usize, ellipsis: &str) -> String {
+    if display_width(text) <= max_width {
+        return text.to_string();
+    }
+    let ellipsis_width = display_width(ellipsis);
+    if max_width <= ellipsis_width {
+        return take_prefix(text, max_width).to_string();
+    }
+    format!(
+        "{}{}",
+        take_prefix(text, max_width - ellipsis_width),
+        ellipsis
+    )
+}
+
+/// 우측 공백 패딩 (target_width보다 넓으면 그대로 반환)
+pub fn pad_right(text: &str, target_width: usize) -> String {
+    let current_width = display_width(text);
+    if current_width >= target_width {
+        return text.to_string();
+    }
+    format!("{}{}", text, " ".repeat(target_width - current_width))
+}
+
+/// 좌측 공백 패딩 (우측 정렬, target_width보다 넓으면 그대로 반환)
+pub fn pad_left(text: &str, target_width: usize) -> String {
+    let current_width = display_width(text);
+    if current_width >= target_width {
+        return text.to_string();
+    }
+    format!("{}{}", " ".repeat(target_width - current_width), text)
+}
+
+/// 정확히 width 칸으로 맞춤 (넘치면 자르고, 모자라면 공백 패딩)
+///
+/// 전각 문자가 경계에 걸려 1칸이 남으면 공백으로 채운다.
+pub fn fit_width(text: &str, width: usize) -> String {
+    pad_right(take_prefix(text, width), width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_width_counts_wide_chars() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("한글"), 4);
+        assert_eq!(display_width("📁a"), 3);
+    }
+
+    #[test]
+    fn test_take_prefix_and_suffix_do_not_split_wide_chars() {
+        assert_eq!(take_prefix("가나다", 5), "가나");
+        assert_eq!(take_prefix("ab", 5), "ab");
+        assert_eq!(take_suffix("가나다", 5), "나다");
+        assert_eq!(take_suffix("a가", 1), "");
+    }
+
+    #[test]
+    fn test_truncate_end() {
+        assert_eq!(truncate_end("short", 10, ELLIPSIS), "short");
+        assert_eq!(truncate_end("가나다라", 5, ELLIPSIS), "가나…");
+        assert_eq!(truncate_end("abcdef", 5, "..."), "ab...");
+        assert_eq!(truncate_end("abcdef", 2, "..."), "ab");
+    }
+
+    #[test]
+    fn test_pad_right_ascii_and_korean() {
+        assert_eq!(pad_right("abc", 5), "abc  ");
+        assert_eq!(pad_right("가a", 4), "가a ");
+        assert_eq!(pad_right("가나다", 2), "가나다");
+    }
+
+    #[test]
+    fn test_pad_left_and_fit_width() {
+        assert_eq!(pad_left("1KB", 5), "  1KB");
+        assert_eq!(fit_width("가나다", 5), "가나 ");
+        assert_eq!(display_width(&fit_width("😀abc", 4)), 4);
+    }
+}
@@ -23,6 +23,9 @@ pub enum BokslDirError {
     #[error("Not a directory: {path}")]
     NotADirectory { path: PathBuf },
 
+    #[error("Not a file: {path}")]
+    NotAFile { path: PathBuf },
+
     // Phase 3.2: 파일 복사/이동 에러
     #[error("File already exists: {path}")]
     FileExists { path: PathBuf },
@@ -37,6 +40,9 @@ pub enum BokslDirError {
         reason: String,
     },
 
+    #[error("Verification failed: {src} -> {dest}: content mismatch")]
+    VerifyFailed { src: PathBuf, dest: PathBuf },
+
     #[error("Move failed: {src} -> {dest}: {reason}")]
     MoveFailed {
         src: PathBuf,
@@ -66,6 +72,9 @@ pub enum BokslDirError {
     #[error("Archive extract failed: {path}: {reason}")]
     ArchiveExtractFailed { path: PathBuf, reason: String },
 
+    #[error("Archive update failed: {path}: {reason}")]
+    ArchiveUpdateFailed { path: PathBuf, reason: String },
+
     #[error("Archive list failed: {path}: {reason}")]
     ArchiveListFailed { path: PathBuf, reason: String },
 
@@ -77,6 +86,64 @@ pub enum BokslDirError {
 
     #[error("Operation cancelled")]
     OperationCancelled,
+
+    #[error("Git command failed: {path}: {reason}")]
+    GitFailed { path: PathBuf, reason: String },
+
+    #[error("Remote connection failed: {host}: {reason}")]
+    RemoteFailed { host: String, reason: String },
+}
+
+impl BokslDirError {
+    /// 권한 부족으로 실패함 (관리자 권한 재시도 제안 대상)
+    pub fn is_permission_denied(&self) -> bool {
+        match self {
+            BokslDirError::PermissionDenied { .. } => true,
+            BokslDirError::Io(e) => e.kind() == std::io::ErrorKind::PermissionDenied,
+            BokslDirError::CopyFailed { reason, .. }
+            | BokslDirError::MoveFailed { reason, .. }
+            | BokslDirError::DeleteFailed { reason, .. }
+            | BokslDirError::RenameFailed { reason, .. } => {
+                reason.contains("Permission denied") || reason.contains("Access is denied")
+            }
+            _ => false,
+        }
+    }
+
+    /// 다른 프로세스가 파일을 쓰고 있어 실패함 (공유/잠금 위반, 잠시 뒤 다시 시도하면 풀릴 수 있음)
+    pub fn is_file_busy(&self) -> bool {
+        match self {
+            BokslDirError::Io(e) => e.raw_os_error().is_some_and(is_busy_os_error),
+            BokslDirError::CopyFailed { reason, .. }
+            | BokslDirError::MoveFailed { reason, .. }
+            | BokslDirError::DeleteFailed { reason, .. }
+            | BokslDirError::RenameFailed { reason, .. } => {
+                reason.contains("being used by another process")
+                    || reason.contains("another process has locked")
+                    || reason.contains("Text file busy")
+                    || reason.contains("Device or resource busy")
+                    || reason.contains("Resource busy")
+            }
+            _ => false,
+        }
+    }
+}
+
+/// 공유 위반/잠금 위반 (Windows ERROR_SHARING_VIOLATION, ERROR_LOCK_VIOLATION)
+#[cfg(windows)]
+fn is_busy_os_error(code: i32) -> bool {
+    matches!(code, 32 | 33)
+}
+
+/// 실행 중인 파일/사용 중인 장치 (ETXTBSY, EBUSY)
+#[cfg(unix)]
+fn is_busy_os_error(code: i32) -> bool {
+    code == libc::ETXTBSY || code == libc::EBUSY
+}
+
+#[cfg(not(any(unix, windows)))]
+fn is_busy_os_error(_code: i32) -> bool {
+    false
 }
 
 pub type Result<T> = std::result::Result<T, BokslDirError>;
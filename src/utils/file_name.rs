@@ -0,0 +1,273 @@
+//! 파일 이름 검사와 자동 수정 (새 파일/새 디렉토리/이름 변경)
+//!
+//! 대상 파일시스템이 Windows 규칙(Windows, FAT/exFAT/NTFS/SMB 마운트)을 따르면 예약 이름
+//! (`CON`, `NUL`, `COM1` 등), 끝의 `.`/공백, `<>:"|?*\` 문자를 막습니다. 이름 길이는 255
+//! (Unix는 UTF-8 바이트, Windows는 UTF-16 단위), 전체 경로는 Unix 4095바이트 / Windows 32767자를
+//! 넘을 수 없습니다.
+
+use chrono::{Datelike, NaiveDateTime, Timelike};
+use std::fmt;
+use std::path::Path;
+
+/// 이름 하나의 최대 길이
+pub const MAX_NAME_LEN: usize = 255;
+const MAX_UNIX_PATH_LEN: usize = 4095;
+const MAX_WINDOWS_PATH_LEN: usize = 32_767;
+
+const WINDOWS_ILLEGAL_CHARS: &[char] = &['<', '>', ':', '"', '|', '?', '*', '\\'];
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Windows 규칙을 따르는 파일시스템 종류 (Unix에서 마운트한 경우)
+const WINDOWS_FS_TYPES: &[&str] = &[
+    "vfat", "msdos", "msdosfs", "exfat", "ntfs", "ntfs3", "fuseblk", "cifs", "smb3", "smbfs",
+];
+
+/// 이름 검사 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameRules {
+    /// `/`와 NUL만 금지
+    Unix,
+    /// 예약 이름, 끝의 `.`/공백, `<>:"|?*\` 금지
+    Windows,
+}
+
+impl NameRules {
+    /// 플랫폼과 대상 파일시스템 종류에 맞는 규칙
+    pub fn for_filesystem(fs_type: Option<&str>) -> Self {
+        if cfg!(windows)
+            || fs_type
+                .is_some_and(|fs_type| WINDOWS_FS_TYPES.contains(&fs_type.to_lowercase().as_str()))
+        {
+            NameRules::Windows
+        } else {
+            NameRules::Unix
+        }
+    }
+}
+
+/// 사용할 수 없는 이름의 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameProblem {
+    /// `.` 또는 `..`
+    DotName,
+    /// 경로 구분자나 사용할 수 없는 문자
+    IllegalChar(char),
+    /// Windows 예약 이름 (확장자를 붙여도 예약됨)
+    Reserved(String),
+    /// Windows에서 끝의 `.`/공백은 조용히 잘림
+    TrailingDotOrSpace,
+    /// 이름이 255자보다 김
+    NameTooLong,
+    /// 전체 경로가 너무 김
+    PathTooLong,
+}
+
+impl fmt::Display for NameProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameProblem::DotName => write!(f, "'.' and '..' are reserved"),
+            NameProblem::IllegalChar(c) if c.is_control() => {
+                write!(f, "control character U+{:04X} is not allowed", *c as u32)
+            }
+            NameProblem::IllegalChar(c) => write!(f, "character '{}' is not allowed", c),
+            NameProblem::Reserved(name) => write!(f, "'{}' is a reserved device name", name),
+            NameProblem::TrailingDotOrSpace => write!(f, "name cannot end with '.' or a space"),
+            NameProblem::NameTooLong => {
+                write!(f, "name is longer than {} characters", MAX_NAME_LEN)
+            }
+            NameProblem::PathTooLong => write!(f, "full path is too long"),
+        }
+    }
+}
+
+fn is_illegal_char(c: char, rules: NameRules) -> bool {
+    c == '/'
+        || c == '\0'
+        || (rules == NameRules::Windows && (c.is_control() || WINDOWS_ILLEGAL_CHARS.contains(&c)))
+}
+
+/// 확장자를 뗀 이름이 Windows 예약 이름이면 그 이름 (`con.txt` → `CON`)
+fn reserved_stem(name: &str) -> Option<&'static str> {
+    let stem = name.split('.').next().unwrap_or(name).trim_end();
+    WINDOWS_RESERVED_NAMES
+        .iter()
+        .copied()
+        .find(|reserved| reserved.eq_ignore_ascii_case(stem))
+}
+
+fn name_len(name: &str, rules: NameRules) -> usize {
+    match rules {
+        NameRules::Unix => name.len(),
+        NameRules::Windows => name.encode_utf16().count(),
+    }
+}
+
+/// `parent` 아래에 만들 이름 검사 (빈 이름은 호출하는 쪽에서 먼저 거름)
+pub fn validate(name: &str, parent: &Path, rules: NameRules) -> Result<(), NameProblem> {
+    if name == "." || name == ".." {
+        return Err(NameProblem::DotName);
+    }
+    if let Some(c) = name.chars().find(|&c| is_illegal_char(c, rules)) {
+        return Err(NameProblem::IllegalChar(c));
+    }
+    if rules == NameRules::Windows {
+        if let Some(reserved) = reserved_stem(name) {
+            return Err(NameProblem::Reserved(reserved.to_string()));
+        }
+        if name.ends_with(['.', ' ']) {
+            return Err(NameProblem::TrailingDotOrSpace);
+        }
+    }
+    if name_len(name, rules) > MAX_NAME_LEN {
+        return Err(NameProblem::NameTooLong);
+    }
+    let parent_len = parent.as_os_str().len();
+    let max_path = match rules {
+        NameRules::Unix => MAX_UNIX_PATH_LEN,
+        NameRules::Windows => MAX_WINDOWS_PATH_LEN,
+    };
+    if parent_len + 1 + name_len(name, rules) > max_path {
+        return Err(NameProblem::PathTooLong);
+    }
+    Ok(())
+}
+
+/// 규칙에 맞게 고친 이름 (사용할 수 없는 문자는 `_`, 예약 이름은 뒤에 `_`, 길면 확장자를 살려 자름)
+pub fn sanitize(name: &str, rules: NameRules) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| if is_illegal_char(c, rules) { '_' } else { c })
+        .collect();
+    if rules == NameRules::Windows {
+        sanitized = sanitized.trim_end_matches(['.', ' ']).to_string();
+        if let Some(reserved) = reserved_stem(&sanitized) {
+            sanitized.replace_range(
+                ..reserved.len(),
+                &format!("{}_", &sanitized[..reserved.len()]),
+            );
+        }
+    }
+    if name_len(&sanitized, rules) > MAX_NAME_LEN {
+        let ext = Path::new(&sanitized)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .filter(|ext| name_len(ext, rules) < MAX_NAME_LEN / 2)
+            .map(|ext| format!(".{}", ext))
+            .unwrap_or_default();
+        let mut stem: String = sanitized[..sanitized.len() - ext.len()].to_string();
+        while name_len(&stem, rules) + name_len(&ext, rules) > MAX_NAME_LEN {
+            stem.pop();
+        }
+        sanitized = format!("{}{}", stem, ext);
+    }
+    if sanitized.is_empty() || sanitized == "." || sanitized == ".." {
+        sanitized = format!("{}_", sanitized);
+    }
+    sanitized
+}
+
+/// 이름에 쓴 날짜/시각 자리표시자 치환 (`{yyyy}`, `{yy}`, `{mm}`, `{dd}`, `{hh}`, `{min}`, `{ss}`)
+///
+/// 모르는 `{...}`는 그대로 둡니다.
+pub fn expand_date_template(template: &str, now: &NaiveDateTime) -> String {
+    let mut expanded = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let Some(end) = after.find('}') else {
+            expanded.push_str(after);
+            return expanded;
+        };
+        let value = match &after[1..end] {
+            "yyyy" => format!("{:04}", now.year()),
+            "yy" => format!("{:02}", now.year() % 100),
+            "mm" => format!("{:02}", now.month()),
+            "dd" => format!("{:02}", now.day()),
+            "hh" => format!("{:02}", now.hour()),
+            "min" => format!("{:02}", now.minute()),
+            "ss" => format!("{:02}", now.second()),
+            _ => after[..=end].to_string(),
+        };
+        expanded.push_str(&value);
+        rest = &after[end + 1..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_date_template_replaces_known_placeholders() {
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 3, 7)
+            .unwrap()
+            .and_hms_opt(9, 5, 2)
+            .unwrap();
+        assert_eq!(
+            expand_date_template("{yyyy}-{mm}-{dd}/notes", &now),
+            "2026-03-07/notes"
+        );
+        assert_eq!(
+            expand_date_template("log_{yy}{mm}{dd}_{hh}{min}{ss}", &now),
+            "log_260307_090502"
+        );
+        assert_eq!(expand_date_template("{name} {dd", &now), "{name} {dd");
+        assert_eq!(expand_date_template("plain", &now), "plain");
+    }
+
+    #[test]
+    fn test_windows_rules_reject_reserved_names_and_trailing_dots() {
+        let parent = Path::new("/mnt/usb");
+        let rules = NameRules::for_filesystem(Some("vfat"));
+        assert_eq!(rules, NameRules::Windows);
+        assert_eq!(
+            validate("con.txt", parent, rules),
+            Err(NameProblem::Reserved("CON".to_string()))
+        );
+        assert_eq!(
+            validate("notes. ", parent, rules),
+            Err(NameProblem::TrailingDotOrSpace)
+        );
+        assert_eq!(
+            validate("a:b?.txt", parent, rules),
+            Err(NameProblem::IllegalChar(':'))
+        );
+        assert!(validate("console.txt", parent, rules).is_ok());
+
+        assert_eq!(sanitize("con.txt", rules), "con_.txt");
+        assert_eq!(sanitize("a:b?.txt", rules), "a_b_.txt");
+        assert_eq!(sanitize("report. ", rules), "report");
+        assert_eq!(sanitize("...", rules), "_");
+    }
+
+    #[test]
+    fn test_unix_rules_allow_windows_names_and_limit_length() {
+        let parent = Path::new("/home/user");
+        if !cfg!(windows) {
+            assert_eq!(NameRules::for_filesystem(Some("ext4")), NameRules::Unix);
+        }
+        let rules = NameRules::Unix;
+        assert!(validate("CON", parent, rules).is_ok());
+        assert!(validate("a:b?. ", parent, rules).is_ok());
+        assert_eq!(
+            validate("a/b", parent, rules),
+            Err(NameProblem::IllegalChar('/'))
+        );
+        assert_eq!(validate("..", parent, rules), Err(NameProblem::DotName));
+
+        let long = format!("{}.txt", "가".repeat(100));
+        assert_eq!(
+            validate(&long, parent, rules),
+            Err(NameProblem::NameTooLong)
+        );
+        let fixed = sanitize(&long, rules);
+        assert!(fixed.len() <= MAX_NAME_LEN && fixed.ends_with(".txt"));
+        assert!(validate(&fixed, parent, rules).is_ok());
+    }
+}
@@ -0,0 +1,144 @@
+//! 퍼지 매칭 (목록 다이얼로그의 입력 즉시 좁히기)
+//!
+//! - 질의의 각 글자가 순서대로 나타나면 일치 (`dwn` → `Downloads`)
+//! - 공백으로 구분된 여러 단어는 모두 일치해야 함 (AND)
+//! - 대문자가 있는 단어만 대소문자를 구분 (smart case)
+//! - 연속 일치, 단어 시작(`/`, `_`, `-`, `.`, 공백 뒤) 일치에 가산점
+
+/// 매칭 결과 (`positions`는 후보 문자열의 글자 인덱스, 오름차순)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    pub positions: Vec<usize>,
+}
+
+const SCORE_MATCH: i64 = 16;
+const BONUS_CONSECUTIVE: i64 = 24;
+const BONUS_WORD_START: i64 = 20;
+const PENALTY_GAP: i64 = 1;
+
+/// 질의와 후보 매칭 (일치하지 않으면 None, 빈 질의는 항상 일치)
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let chars: Vec<char> = candidate.chars().collect();
+    let mut result = FuzzyMatch::default();
+    for token in query.split_whitespace() {
+        let token_match = match_token(token, &chars)?;
+        result.score += token_match.score;
+        result.positions.extend(token_match.positions);
+    }
+    result.positions.sort_unstable();
+    result.positions.dedup();
+    Some(result)
+}
+
+/// 후보 목록 필터링 (점수 내림차순, 같으면 원래 순서)
+///
+/// 반환: (후보 인덱스, 매칭 결과) 목록
+pub fn filter<'a, I>(query: &str, candidates: I) -> Vec<(usize, FuzzyMatch)>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut matches: Vec<(usize, FuzzyMatch)> = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| fuzzy_match(query, candidate).map(|m| (index, m)))
+        .collect();
+    matches.sort_by(|a, b| b.1.score.cmp(&a.1.score).then(a.0.cmp(&b.0)));
+    matches
+}
+
+/// 단어 하나 매칭: 첫 글자가 일치하는 모든 시작 위치에서 탐욕 매칭 후 최고점 선택
+fn match_token(token: &str, chars: &[char]) -> Option<FuzzyMatch> {
+    let case_sensitive = token.chars().any(char::is_uppercase);
+    let fold = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_lowercase().next().unwrap_or(c)
+        }
+    };
+    let needle: Vec<char> = token.chars().map(fold).collect();
+    let haystack: Vec<char> = chars.iter().copied().map(fold).collect();
+    let first = *needle.first()?;
+
+    let mut best: Option<FuzzyMatch> = None;
+    for start in (0..haystack.len()).filter(|&i| haystack[i] == first) {
+        let Some(positions) = greedy_positions(&needle, &haystack, start) else {
+            // 이 위치에서 실패하면 이후 시작 위치도 실패
+            break;
+        };
+        let score = score_positions(&positions, chars);
+        if best.as_ref().is_none_or(|b| score > b.score) {
+            best = Some(FuzzyMatch { score, positions });
+        }
+    }
+    best
+}
+
+fn greedy_positions(needle: &[char], haystack: &[char], start: usize) -> Option<Vec<usize>> {
+    let mut positions = Vec::with_capacity(needle.len());
+    let mut cursor = start;
+    for &c in needle {
+        let offset = haystack[cursor..].iter().position(|&h| h == c)?;
+        positions.push(cursor + offset);
+        cursor += offset + 1;
+    }
+    Some(positions)
+}
+
+fn score_positions(positions: &[usize], chars: &[char]) -> i64 {
+    let mut score = 0;
+    for (i, &pos) in positions.iter().enumerate() {
+        score += SCORE_MATCH;
+        if pos == 0 || matches!(chars[pos - 1], '/' | '\\' | '_' | '-' | '.' | ' ') {
+            score += BONUS_WORD_START;
+        }
+        if i > 0 {
+            let gap = pos - positions[i - 1] - 1;
+            if gap == 0 {
+                score += BONUS_CONSECUTIVE;
+            } else {
+                score -= gap as i64 * PENALTY_GAP;
+            }
+        }
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_subsequence_and_smart_case() {
+        let m = fuzzy_match("dwn", "Downloads").unwrap();
+        assert_eq!(m.positions, vec![0, 2, 3]);
+        assert!(fuzzy_match("nwd", "Downloads").is_none());
+        assert!(fuzzy_match("Dl", "downloads").is_none());
+        assert!(fuzzy_match("DL", "Downloads").is_none());
+        assert!(fuzzy_match("Dl", "Downloads").is_some());
+        assert_eq!(fuzzy_match("", "anything"), Some(FuzzyMatch::default()));
+    }
+
+    #[test]
+    fn test_fuzzy_match_requires_every_word() {
+        let m = fuzzy_match("src app", "/home/me/src/app").unwrap();
+        assert_eq!(m.positions, vec![9, 10, 11, 13, 14, 15]);
+        assert!(fuzzy_match("src lib", "/home/me/src/app").is_none());
+    }
+
+    #[test]
+    fn test_filter_prefers_word_start_and_consecutive_matches() {
+        let candidates = ["/tmp/a_xb_yc", "/work/abc", "/home/abc-old"];
+        let ranked: Vec<usize> = filter("abc", candidates)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(ranked, vec![1, 2, 0]);
+        // 연속 일치 위치를 고름 (첫 'a'가 아닌 뒤쪽 "abc")
+        let m = fuzzy_match("abc", "a/x/abc").unwrap();
+        assert_eq!(m.positions, vec![4, 5, 6]);
+        assert_eq!(filter("zzz", candidates), Vec::new());
+        assert_eq!(filter("", candidates).len(), 3);
+    }
+}
@@ -0,0 +1,176 @@
+//! 파일 로그 (`log` 크레이트 백엔드)
+//!
+//! TUI 화면을 깨뜨리지 않도록 터미널이 아닌 파일에만 기록합니다. 파일이 `MAX_LOG_BYTES`를
+//! 넘으면 `boksldir.log.1` … `boksldir.log.N`으로 밀어내고 새 파일을 엽니다.
+//! 수준은 `--log-level` 또는 설정 파일의 `log_level`로 정합니다 (기본 `warn`).
+
+use chrono::Local;
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// 로그 파일 이름 (설정 파일과 같은 디렉토리)
+pub const LOG_FILE_NAME: &str = "boksldir.log";
+/// 회전 기준 크기
+pub const MAX_LOG_BYTES: u64 = 1024 * 1024;
+/// 보관할 이전 로그 파일 수
+pub const KEEP_ROTATED: usize = 3;
+/// 설정이 없을 때의 수준
+pub const DEFAULT_LEVEL: LevelFilter = LevelFilter::Warn;
+
+/// 수준 이름 파싱 (off, error, warn, info, debug, trace; 대소문자 무시)
+pub fn parse_level(name: &str) -> Option<LevelFilter> {
+    name.trim().parse().ok()
+}
+
+/// 크기 기준으로 회전하는 로그 파일
+#[derive(Debug)]
+pub struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl RotatingFile {
+    pub fn open(path: &Path, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            size,
+            max_bytes,
+            keep,
+        })
+    }
+
+    /// 한 줄 기록 (기준 크기를 넘으면 먼저 회전)
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.size > 0 && self.size + len > self.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", line)?;
+        self.size += len;
+        Ok(())
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_os_string();
+        name.push(format!(".{}", index));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.keep == 0 {
+            self.file = File::create(&self.path)?;
+        } else {
+            let _ = fs::remove_file(self.rotated_path(self.keep));
+            for index in (1..self.keep).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+            self.file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+        }
+        self.size = 0;
+        Ok(())
+    }
+}
+
+struct FileLogger {
+    file: Mutex<RotatingFile>,
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "{} {:<5} {}: {}",
+            Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = file.write_line(&line);
+    }
+
+    fn flush(&self) {
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = file.file.flush();
+    }
+}
+
+static LOGGER: OnceLock<FileLogger> = OnceLock::new();
+
+/// 파일 로그 시작 (프로세스당 한 번, 이후 호출은 수준만 바꿈)
+pub fn init(path: &Path, level: LevelFilter) -> io::Result<()> {
+    if LOGGER.get().is_none() {
+        let file = RotatingFile::open(path, MAX_LOG_BYTES, KEEP_ROTATED)?;
+        let logger = LOGGER.get_or_init(|| FileLogger {
+            file: Mutex::new(file),
+        });
+        log::set_logger(logger).map_err(|e| io::Error::other(e.to_string()))?;
+    }
+    set_level(level);
+    Ok(())
+}
+
+/// 기록 수준 변경
+pub fn set_level(level: LevelFilter) {
+    log::set_max_level(level);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_level_accepts_names_case_insensitively() {
+        assert_eq!(parse_level("debug"), Some(LevelFilter::Debug));
+        assert_eq!(parse_level(" WARN "), Some(LevelFilter::Warn));
+        assert_eq!(parse_level("off"), Some(LevelFilter::Off));
+        assert_eq!(parse_level("loud"), None);
+    }
+
+    #[test]
+    fn test_rotating_file_rolls_over_and_keeps_limited_history() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("logs").join(LOG_FILE_NAME);
+        let mut file = RotatingFile::open(&path, 16, 2).unwrap();
+        for line in ["aaaaaaaaaa", "bbbbbbbbbb", "cccccccccc", "dddddddddd"] {
+            file.write_line(line).unwrap();
+        }
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert_eq!(read(path.clone()), "dddddddddd\n");
+        assert_eq!(read(dir.path().join("logs/boksldir.log.1")), "cccccccccc\n");
+        assert_eq!(read(dir.path().join("logs/boksldir.log.2")), "bbbbbbbbbb\n");
+        assert!(!dir.path().join("logs/boksldir.log.3").exists());
+
+        // 다시 열면 기존 크기부터 이어서 셈
+        let mut reopened = RotatingFile::open(&path, 16, 2).unwrap();
+        reopened.write_line("eeeeeeeeee").unwrap();
+        assert_eq!(read(path), "eeeeeeeeee\n");
+    }
+}
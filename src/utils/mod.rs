@@ -1,5 +1,14 @@
 // Utilities
+pub mod collation;
+pub mod display_width;
 pub mod error;
+pub mod file_name;
 pub mod formatter;
+pub mod fuzzy;
 pub mod glob;
+pub mod logging;
+pub mod name_filter;
 pub mod path_display;
+pub mod scope_filter;
+pub mod temp_name;
+pub mod win_path;
@@ -0,0 +1,219 @@
+//! 패널 파일 이름 필터
+//!
+//! - 공백으로 구분된 여러 패턴은 OR 결합 (`*.rs *.toml`)
+//! - `!`로 시작하는 패턴은 제외 (`!*.o`), 포함 패턴이 없으면 제외만 적용
+//! - 기본은 글로브(`*`, `?`) 또는 부분 문자열 매칭
+//! - `r:` 접두사를 붙이면 각 패턴을 정규식으로 해석 (`r:^test_ !_bak$`)
+//!
+//! 모든 매칭은 대소문자를 무시합니다.
+
+use crate::utils::glob;
+use regex::{Regex, RegexBuilder};
+
+/// 정규식 모드 접두사
+pub const REGEX_PREFIX: &str = "r:";
+
+/// 필터 모드 (상태바 표시용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    /// 부분 문자열 매칭만 사용
+    Text,
+    /// 글로브 와일드카드 포함
+    Glob,
+    /// 정규식 모드
+    Regex,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Text(String),
+    Glob(String),
+    Regex(Regex),
+}
+
+impl Matcher {
+    fn is_match(&self, name: &str) -> bool {
+        match self {
+            Matcher::Text(text) => name.to_lowercase().contains(text),
+            Matcher::Glob(pattern) => glob::glob_match(pattern, name),
+            Matcher::Regex(re) => re.is_match(name),
+        }
+    }
+}
+
+/// 파싱된 필터
+#[derive(Debug, Clone)]
+pub struct NameFilter {
+    mode: FilterMode,
+    include: Vec<Matcher>,
+    exclude: Vec<Matcher>,
+    invalid: bool,
+}
+
+/// 정규식 모드 여부와 접두사를 뗀 패턴 본문
+pub fn split_regex_prefix(filter: &str) -> (bool, &str) {
+    match filter.strip_prefix(REGEX_PREFIX) {
+        Some(rest) => (true, rest),
+        None => (false, filter),
+    }
+}
+
+impl NameFilter {
+    /// 필터 문자열 파싱
+    ///
+    /// 잘못된 정규식 패턴은 무시하고 `is_invalid()`로 표시합니다.
+    pub fn parse(filter: &str) -> Self {
+        let (regex_mode, body) = split_regex_prefix(filter.trim());
+        let mut mode = if regex_mode {
+            FilterMode::Regex
+        } else {
+            FilterMode::Text
+        };
+        let mut include = Vec::new();
+        let mut exclude = Vec::new();
+        let mut invalid = false;
+
+        for term in body.split_whitespace() {
+            let (negated, pattern) = match term.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, term),
+            };
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let matcher = if regex_mode {
+                match RegexBuilder::new(pattern).case_insensitive(true).build() {
+                    Ok(re) => Matcher::Regex(re),
+                    Err(_) => {
+                        invalid = true;
+                        continue;
+                    }
+                }
+            } else if glob::is_glob_pattern(pattern) {
+                mode = FilterMode::Glob;
+                Matcher::Glob(pattern.to_string())
+            } else {
+                Matcher::Text(pattern.to_lowercase())
+            };
+
+            if negated {
+                exclude.push(matcher);
+            } else {
+                include.push(matcher);
+            }
+        }
+
+        Self {
+            mode,
+            include,
+            exclude,
+            invalid,
+        }
+    }
+
+    pub fn mode(&self) -> FilterMode {
+        self.mode
+    }
+
+    /// 정규식 컴파일에 실패한 패턴이 있는지
+    pub fn is_invalid(&self) -> bool {
+        self.invalid
+    }
+
+    /// 적용할 패턴이 하나도 없는지 (빈 필터 또는 전부 잘못된 정규식)
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// 제외 패턴 포함 여부
+    pub fn has_exclusions(&self) -> bool {
+        !self.exclude.is_empty()
+    }
+
+    /// 이름이 필터를 통과하는지
+    pub fn matches(&self, name: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|m| m.is_match(name));
+        included && !self.exclude.iter().any(|m| m.is_match(name))
+    }
+
+    /// 부분 문자열 하이라이트용 단일 텍스트 패턴 (소문자)
+    ///
+    /// 포함 패턴이 부분 문자열 1개이고 제외 패턴이 없을 때만 Some
+    pub fn single_text_term(&self) -> Option<&str> {
+        match (self.include.as_slice(), self.exclude.is_empty()) {
+            ([Matcher::Text(text)], true) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_and_glob_terms_or_combined() {
+        let filter = NameFilter::parse("*.rs toml");
+        assert_eq!(filter.mode(), FilterMode::Glob);
+        assert!(filter.matches("main.RS"));
+        assert!(filter.matches("Cargo.toml"));
+        assert!(!filter.matches("README.md"));
+
+        let text = NameFilter::parse("read");
+        assert_eq!(text.mode(), FilterMode::Text);
+        assert!(text.matches("README.md"));
+        assert_eq!(text.single_text_term(), Some("read"));
+    }
+
+    #[test]
+    fn test_exclusion_terms() {
+        let only_exclude = NameFilter::parse("!*.o");
+        assert!(only_exclude.matches("main.c"));
+        assert!(!only_exclude.matches("main.o"));
+        assert!(only_exclude.has_exclusions());
+        assert!(only_exclude.single_text_term().is_none());
+
+        let mixed = NameFilter::parse("main* !*.o");
+        assert!(mixed.matches("main.c"));
+        assert!(!mixed.matches("main.o"));
+        assert!(!mixed.matches("util.c"));
+    }
+
+    #[test]
+    fn test_regex_mode() {
+        let filter = NameFilter::parse(r"r:^test_\d+ !\.bak$");
+        assert_eq!(filter.mode(), FilterMode::Regex);
+        assert!(filter.matches("TEST_01.rs"));
+        assert!(!filter.matches("test_01.bak"));
+        assert!(!filter.matches("my_test_01.rs"));
+        assert!(!filter.is_invalid());
+        assert!(filter.single_text_term().is_none());
+    }
+
+    #[test]
+    fn test_invalid_regex_is_ignored() {
+        let filter = NameFilter::parse("r:(abc");
+        assert!(filter.is_invalid());
+        assert!(filter.is_empty());
+        assert!(filter.matches("anything"));
+
+        let partial = NameFilter::parse("r:(abc ^x");
+        assert!(partial.is_invalid());
+        assert!(partial.matches("xyz"));
+        assert!(!partial.matches("abc"));
+    }
+
+    #[test]
+    fn test_empty_and_bare_bang() {
+        assert!(NameFilter::parse("").is_empty());
+        assert!(NameFilter::parse("  ! ").is_empty());
+        assert!(NameFilter::parse("r:").matches("a"));
+    }
+
+    #[test]
+    fn test_split_regex_prefix() {
+        assert_eq!(split_regex_prefix("r:abc"), (true, "abc"));
+        assert_eq!(split_regex_prefix("abc"), (false, "abc"));
+    }
+}
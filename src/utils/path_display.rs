@@ -1,5 +1,7 @@
-use std::path::Path;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use crate::utils::display_width::{self, take_prefix, take_suffix};
+use crate::utils::win_path;
+use std::path::{Component, Path, PathBuf};
+use unicode_width::UnicodeWidthStr;
 
 const ELLIPSIS: &str = "...";
 const PATH_ELLIPSIS: &str = "/...";
@@ -15,12 +17,12 @@ pub fn truncate_middle(text: &str, max_width: usize) -> String {
     }
 
     if max_width < 5 {
-        return take_prefix_by_width(text, max_width);
+        return take_prefix(text, max_width).to_string();
     }
 
     let side_width = (max_width - ELLIPSIS.width()) / 2;
-    let start = take_prefix_by_width(text, side_width);
-    let end = take_suffix_by_width(text, side_width);
+    let start = take_prefix(text, side_width);
+    let end = take_suffix(text, side_width);
     format!("{}{}{}", start, ELLIPSIS, end)
 }
 
@@ -75,17 +77,141 @@ pub fn truncate_path(path: &str, max_width: usize) -> String {
 
 /// Path를 문자열로 변환 후 경로 축약 규칙을 적용한다.
 pub fn truncate_path_buf(path: &Path, max_width: usize) -> String {
-    truncate_path(&path.to_string_lossy(), max_width)
+    truncate_path(
+        &win_path::strip_extended_length(&path.to_string_lossy()),
+        max_width,
+    )
 }
 
-/// 문자열을 Unicode 폭 기준으로 target_width까지 우측 공백 패딩한다.
-pub fn pad_right_to_width(text: &str, target_width: usize) -> String {
-    let current_width = text.width();
-    if current_width >= target_width {
-        return text.to_string();
+/// 경로 표시줄 조각 사이 구분자
+pub const BREADCRUMB_SEPARATOR: &str = " ▸ ";
+
+/// 경로 표시줄 조각 하나 (표시 이름과 그 조각까지의 경로)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crumb {
+    pub label: String,
+    pub path: PathBuf,
+}
+
+/// 루트부터 `path`까지의 조각 (`/home/user` → `/`, `home`, `user`)
+pub fn breadcrumbs(path: &Path) -> Vec<Crumb> {
+    let mut crumbs: Vec<Crumb> = Vec::new();
+    let mut current = PathBuf::new();
+    for component in path.components() {
+        current.push(component.as_os_str());
+        match component {
+            // Windows 드라이브/UNC 공유 접두사(`C:`, `\\srv\share`)와 루트는 한 조각으로 합침
+            Component::RootDir if !crumbs.is_empty() => {
+                if let Some(last) = crumbs.last_mut() {
+                    last.label
+                        .push_str(&component.as_os_str().to_string_lossy());
+                    last.path = current.clone();
+                }
+            }
+            Component::CurDir => {}
+            _ => crumbs.push(Crumb {
+                label: win_path::strip_extended_length(&component.as_os_str().to_string_lossy())
+                    .into_owned(),
+                path: current.clone(),
+            }),
+        }
+    }
+    crumbs
+}
+
+/// 너비에 맞춘 경로 표시줄 (텍스트, 조각 번호 — 구분자/생략 표시는 None)
+///
+/// 홈 디렉토리 아래는 `~`부터 보여주고(초점이 홈 위에 있으면 제외), 그래도 길면 초점 조각이
+/// 보이도록 앞쪽(필요하면 뒤쪽) 조각을 `…`로 줄입니다. 루트 조각 뒤에는 구분자를 넣지 않아
+/// `/home ▸ user`처럼 보입니다.
+pub fn breadcrumb_spans(
+    crumbs: &[Crumb],
+    focus: Option<usize>,
+    max_width: usize,
+) -> Vec<(String, Option<usize>)> {
+    if crumbs.is_empty() {
+        return Vec::new();
+    }
+    let focus_index = focus.unwrap_or(crumbs.len() - 1).min(crumbs.len() - 1);
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let home_index = home
+        .and_then(|home| crumbs.iter().position(|crumb| crumb.path == home))
+        .filter(|&index| index > 0 && index <= focus_index);
+    let label = |index: usize| -> &str {
+        if Some(index) == home_index {
+            "~"
+        } else {
+            &crumbs[index].label
+        }
+    };
+    let is_root = |index: usize| crumbs[index].label.ends_with(['/', '\\']);
+
+    let build = |start: usize, end: usize| {
+        let mut spans = Vec::new();
+        if start > 0 && Some(start) != home_index {
+            spans.push((
+                format!("{}{}", display_width::ELLIPSIS, BREADCRUMB_SEPARATOR),
+                None,
+            ));
+        }
+        for index in start..end {
+            spans.push((label(index).to_string(), Some(index)));
+            if index + 1 < end && !is_root(index) {
+                spans.push((BREADCRUMB_SEPARATOR.to_string(), None));
+            }
+        }
+        if end < crumbs.len() {
+            spans.push((
+                format!("{}{}", BREADCRUMB_SEPARATOR, display_width::ELLIPSIS),
+                None,
+            ));
+        }
+        spans
+    };
+    let width = |spans: &[(String, Option<usize>)]| {
+        spans
+            .iter()
+            .map(|(text, _)| display_width::display_width(text))
+            .sum::<usize>()
+    };
+
+    let (mut start, mut end) = (home_index.unwrap_or(0), crumbs.len());
+    let mut spans = build(start, end);
+    while width(&spans) > max_width && end - start > 1 {
+        if start < focus_index {
+            start += 1;
+        } else {
+            end -= 1;
+        }
+        spans = build(start, end);
+    }
+    if width(&spans) > max_width {
+        // 조각 하나도 넓으면 그 조각 이름을 줄임
+        let others: usize = spans
+            .iter()
+            .filter(|(_, index)| index.is_none())
+            .map(|(text, _)| display_width::display_width(text))
+            .sum();
+        for (text, index) in &mut spans {
+            if index.is_some() {
+                *text = truncate_from_start(text, max_width.saturating_sub(others).max(1));
+            }
+        }
     }
+    spans
+}
 
-    format!("{}{}", text, " ".repeat(target_width - current_width))
+/// 경로 표시줄에서 `offset`칸 위치의 조각 번호
+pub fn breadcrumb_at(spans: &[(String, Option<usize>)], offset: usize) -> Option<usize> {
+    let mut x = 0;
+    for (text, index) in spans {
+        let width = display_width::display_width(text);
+        if offset < x + width {
+            return *index;
+        }
+        x += width;
+    }
+    None
 }
 
 fn shorten_home(path: &str) -> String {
@@ -115,40 +241,11 @@ fn truncate_from_start(text: &str, max_width: usize) -> String {
         return text.to_string();
     }
     if max_width <= ELLIPSIS.width() {
-        return take_prefix_by_width(text, max_width);
+        return take_prefix(text, max_width).to_string();
     }
 
     let suffix_width = max_width - ELLIPSIS.width();
-    format!("{}{}", ELLIPSIS, take_suffix_by_width(text, suffix_width))
-}
-
-fn take_prefix_by_width(text: &str, max_width: usize) -> String {
-    let mut result = String::new();
-    let mut width = 0;
-    for ch in text.chars() {
-        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(1);
-        if width + ch_width > max_width {
-            break;
-        }
-        result.push(ch);
-        width += ch_width;
-    }
-    result
-}
-
-fn take_suffix_by_width(text: &str, max_width: usize) -> String {
-    let mut rev_chars: Vec<char> = Vec::new();
-    let mut width = 0;
-    for ch in text.chars().rev() {
-        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(1);
-        if width + ch_width > max_width {
-            break;
-        }
-        rev_chars.push(ch);
-        width += ch_width;
-    }
-    rev_chars.reverse();
-    rev_chars.into_iter().collect()
+    format!("{}{}", ELLIPSIS, take_suffix(text, suffix_width))
 }
 
 #[cfg(test)]
@@ -185,24 +282,23 @@ mod tests {
     }
 
     #[test]
-    fn test_pad_right_to_width_ascii() {
-        let padded = pad_right_to_width("abc", 5);
-        assert_eq!(padded, "abc  ");
-        assert_eq!(padded.width(), 5);
-    }
+    fn test_breadcrumbs_split_and_fit_width() {
+        let crumbs = breadcrumbs(Path::new("/srv/data/projects"));
+        let labels: Vec<_> = crumbs.iter().map(|crumb| crumb.label.as_str()).collect();
+        assert_eq!(labels, ["/", "srv", "data", "projects"]);
+        assert_eq!(crumbs[2].path, PathBuf::from("/srv/data"));
 
-    #[test]
-    fn test_pad_right_to_width_with_korean() {
-        let padded = pad_right_to_width("가a", 4);
-        assert_eq!(padded, "가a ");
-        assert_eq!(padded.width(), 4);
-    }
+        let spans = breadcrumb_spans(&crumbs, None, 80);
+        let text: String = spans.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(text, "/srv ▸ data ▸ projects");
+        assert_eq!(breadcrumb_at(&spans, 0), Some(0));
+        assert_eq!(breadcrumb_at(&spans, 2), Some(1));
+        assert_eq!(breadcrumb_at(&spans, 8), Some(2));
 
-    #[test]
-    fn test_pad_right_to_width_keeps_original_when_target_is_smaller() {
-        let original = "가나다";
-        let padded = pad_right_to_width(original, 2);
-        assert_eq!(padded, original);
-        assert_eq!(padded.width(), original.width());
+        // 좁으면 앞 조각부터 생략하되 고른 조각은 남김
+        let narrow = breadcrumb_spans(&crumbs, Some(1), 14);
+        let text: String = narrow.iter().map(|(text, _)| text.as_str()).collect();
+        assert!(text.width() <= 14);
+        assert!(narrow.iter().any(|(_, index)| *index == Some(1)));
     }
 }
@@ -0,0 +1,159 @@
+//! 복사/이동 범위 필터 (재귀 작업에서 포함/제외할 항목)
+//!
+//! - 공백으로 구분한 글로브 패턴, `!`로 시작하면 제외 (`*.jpg !node_modules/ !.git/`)
+//! - `/`로 끝나는 패턴은 디렉토리에만 맞고, 제외한 디렉토리는 그 아래를 읽지 않음
+//! - `/`가 들어간 패턴은 고른 항목 기준 상대 경로 전체에, 나머지는 이름에 맞춤
+//! - 포함 패턴은 파일에만 적용하며, 포함된 파일이 없는 디렉토리는 만들지 않음
+//!
+//! 모든 매칭은 대소문자를 무시합니다.
+
+use crate::utils::glob;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pattern {
+    glob: String,
+    dir_only: bool,
+    full_path: bool,
+}
+
+impl Pattern {
+    fn parse(term: &str) -> Option<Self> {
+        let dir_only = term.ends_with('/');
+        let body = term.trim_end_matches('/');
+        if body.is_empty() {
+            return None;
+        }
+        Some(Self {
+            glob: body.to_string(),
+            dir_only,
+            full_path: body.contains('/'),
+        })
+    }
+
+    fn is_match(&self, relative: &str, name: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        glob::glob_match(&self.glob, if self.full_path { relative } else { name })
+    }
+}
+
+/// 파싱된 범위 필터 (빈 필터는 모든 항목 통과)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopeFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl ScopeFilter {
+    /// 필터 문자열 파싱 (`!`나 `/`만 있는 조각은 무시)
+    pub fn parse(filter: &str) -> Self {
+        let mut parsed = Self::default();
+        for term in filter.split_whitespace() {
+            let (negated, body) = match term.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, term),
+            };
+            if let Some(pattern) = Pattern::parse(body) {
+                if negated {
+                    parsed.exclude.push(pattern);
+                } else {
+                    parsed.include.push(pattern);
+                }
+            }
+        }
+        parsed
+    }
+
+    /// 제외 패턴만 있는 필터 (패널 숨김 패턴, 패턴 앞에 `!`를 붙이지 않음)
+    pub fn excluding<'a>(patterns: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: patterns
+                .into_iter()
+                .filter_map(|pattern| Pattern::parse(pattern.trim()))
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// 포함 패턴이 있으면 파일이 하나도 없는 디렉토리를 빼야 함
+    pub fn has_includes(&self) -> bool {
+        !self.include.is_empty()
+    }
+
+    /// 항목을 작업에 넣을지 (`relative`는 고른 항목 이름부터 시작하는 상대 경로)
+    ///
+    /// 디렉토리는 제외 패턴만 보고, 파일은 포함 패턴 중 하나에 맞고 제외 패턴에 맞지 않아야
+    /// 합니다. 포함 패턴 중 디렉토리 전용 패턴(`src/`)에 맞는 디렉토리 아래 파일은 모두
+    /// 포함합니다.
+    pub fn allows(&self, relative: &Path, is_dir: bool) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let (relative_text, name) = slash_path(relative);
+        if self
+            .exclude
+            .iter()
+            .any(|pattern| pattern.is_match(&relative_text, &name, is_dir))
+        {
+            return false;
+        }
+        if is_dir || self.include.is_empty() {
+            return true;
+        }
+        self.include.iter().any(|pattern| {
+            if pattern.dir_only {
+                relative.ancestors().skip(1).any(|dir| {
+                    let (dir_text, dir_name) = slash_path(dir);
+                    !dir_text.is_empty() && pattern.is_match(&dir_text, &dir_name, true)
+                })
+            } else {
+                pattern.is_match(&relative_text, &name, false)
+            }
+        })
+    }
+}
+
+/// (`/`로 이은 상대 경로, 이름)
+fn slash_path(path: &Path) -> (String, String) {
+    let text = path
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    (text, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_filter_includes_and_excludes() {
+        let filter = ScopeFilter::parse("*.jpg !node_modules/ !.git/ !raw/*.jpg");
+        assert!(filter.allows(Path::new("photos/a.JPG"), false));
+        assert!(!filter.allows(Path::new("photos/a.png"), false));
+        assert!(!filter.allows(Path::new("app/node_modules"), true));
+        assert!(filter.allows(Path::new("app/node_modules.jpg"), false));
+        assert!(!filter.allows(Path::new("raw/b.jpg"), false));
+        assert!(filter.allows(Path::new("photos/raw/b.jpg"), false));
+        assert!(filter.allows(Path::new("photos"), true));
+
+        let dirs = ScopeFilter::parse("docs/ !*.tmp");
+        assert!(dirs.allows(Path::new("proj/docs/guide/a.md"), false));
+        assert!(!dirs.allows(Path::new("proj/docs/a.tmp"), false));
+        assert!(!dirs.allows(Path::new("proj/readme.md"), false));
+
+        assert!(ScopeFilter::parse("  ! / ").is_empty());
+        assert!(ScopeFilter::parse("").allows(Path::new("any"), false));
+    }
+}
@@ -0,0 +1,95 @@
+//! 작업용 임시 파일/이름 (reflink 복제, 이름 변경, FTPS 세션 파일)
+//!
+//! 이름은 프로세스 id, 시각, 호출 순번을 섞어 만들며, 이미 있는 경로는 건너뜁니다.
+//! 예측 가능한 고정 이름에 미리 만들어 둔 파일/링크를 덮어쓰거나 지우지 않기 위함입니다.
+
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 이름을 다시 고르는 최대 횟수
+const MAX_ATTEMPTS: usize = 64;
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// 매 호출마다 다른 16자리 16진수 토큰
+fn token() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    // splitmix64로 섞어 순번만 바뀌어도 전혀 다른 값이 되게 함
+    let mut value = nanos ^ u64::from(std::process::id()).rotate_left(32) ^ count;
+    value = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    format!("{:016x}", value ^ (value >> 31))
+}
+
+fn candidate(dir: &Path, prefix: &str, suffix: &str) -> PathBuf {
+    dir.join(format!("{}{}{}", prefix, token(), suffix))
+}
+
+/// `dir` 안에 `{prefix}{토큰}{suffix}` 파일을 새로 만들어 반환 (Unix는 권한 0600)
+///
+/// 이미 있는 이름이면 다른 토큰으로 다시 시도하므로 기존 파일을 열지 않습니다.
+pub fn create_new_file(dir: &Path, prefix: &str, suffix: &str) -> io::Result<(PathBuf, File)> {
+    for _ in 0..MAX_ATTEMPTS {
+        let path = candidate(dir, prefix, suffix);
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        match options.open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::from(io::ErrorKind::AlreadyExists))
+}
+
+/// `dir` 안에서 아직 아무것도 없는 `{prefix}{토큰}{suffix}` 경로 (링크 포함해 확인)
+///
+/// 파일을 만들지 않으므로 확인과 사용 사이에 생긴 항목은 호출자가 다시 확인해야 합니다.
+pub fn unused_path(dir: &Path, prefix: &str, suffix: &str) -> io::Result<PathBuf> {
+    for _ in 0..MAX_ATTEMPTS {
+        let path = candidate(dir, prefix, suffix);
+        match std::fs::symlink_metadata(&path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(path),
+            Err(e) => return Err(e),
+            Ok(_) => continue,
+        }
+    }
+    Err(io::Error::from(io::ErrorKind::AlreadyExists))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_create_new_file_never_reuses_existing_names() {
+        let temp = TempDir::new().unwrap();
+        let (first, _) = create_new_file(temp.path(), ".list-", ".txt").unwrap();
+        let (second, _) = create_new_file(temp.path(), ".list-", ".txt").unwrap();
+        assert_ne!(first, second);
+        assert!(first.starts_with(temp.path()));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let unused = unused_path(temp.path(), ".stage-", "").unwrap();
+        assert!(std::fs::symlink_metadata(&unused).is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Windows 경로 처리 (UNC 경로, 긴 경로 `\\?\` 접두사)
+//!
+//! 문자열 규칙만 다루므로 모든 플랫폼에서 컴파일/테스트되며, 실제로 접두사를 붙이는 [`long_path`]는
+//! Windows에서만 경로를 바꿉니다. Windows API는 `\\?\`가 붙은 경로에 `/` 변환이나 `.`/`..` 해석을
+//! 하지 않으므로, 접두사는 구분자를 `\`로 바꾼 절대 경로에만 붙입니다.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+/// 접두사 없이 다룰 수 있는 최대 경로 길이 (디렉토리 생성은 8.3 파일 이름 여유분을 뺀 248)
+pub const MAX_PATH: usize = 260;
+const MAX_DIR_PATH: usize = MAX_PATH - 12;
+
+const EXTENDED_PREFIX: &str = r"\\?\";
+const EXTENDED_UNC_PREFIX: &str = r"\\?\UNC\";
+
+/// UNC 경로 (`\\server\share\rest`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UncPath<'a> {
+    pub server: &'a str,
+    pub share: &'a str,
+    /// 공유 아래 경로 (구분자 포함 그대로, 없으면 빈 문자열)
+    pub rest: &'a str,
+}
+
+fn is_separator(c: char) -> bool {
+    c == '\\' || c == '/'
+}
+
+/// `\\server\share` 또는 `//server/share`로 시작하는 UNC 경로 해석 (`\\?\`, `\\.\` 장치 경로 제외)
+pub fn parse_unc(input: &str) -> Option<UncPath<'_>> {
+    let mut chars = input.chars();
+    if !(chars.next().is_some_and(is_separator) && chars.next().is_some_and(is_separator)) {
+        return None;
+    }
+    let body = &input[2..];
+    let (server, after_server) = body.split_once(is_separator)?;
+    if server.is_empty() || server == "?" || server == "." {
+        return None;
+    }
+    let (share, rest) = match after_server.find(is_separator) {
+        Some(idx) => (&after_server[..idx], &after_server[idx..]),
+        None => (after_server, ""),
+    };
+    if share.is_empty() {
+        return None;
+    }
+    Some(UncPath {
+        server,
+        share,
+        rest,
+    })
+}
+
+/// 서버 이름까지만 입력된 UNC 경로 (`\\server` 또는 `\\server\`, 공유 목록은 읽을 수 없음)
+pub fn is_unc_server_only(input: &str) -> bool {
+    let mut chars = input.chars();
+    if !(chars.next().is_some_and(is_separator) && chars.next().is_some_and(is_separator)) {
+        return false;
+    }
+    let server = input[2..].trim_end_matches(is_separator);
+    !server.is_empty() && server != "?" && server != "." && !server.contains(is_separator)
+}
+
+/// UNC 경로를 `\\server\share\...` 형식으로 정규화 (`/` 구분자 허용)
+pub fn normalize_unc(input: &str) -> Option<String> {
+    let unc = parse_unc(input)?;
+    Some(format!(
+        r"\\{}\{}{}",
+        unc.server,
+        unc.share,
+        unc.rest.replace('/', "\\")
+    ))
+}
+
+/// 긴 경로 접두사를 붙인 경로 (`C:\a` → `\\?\C:\a`, `\\srv\sh\a` → `\\?\UNC\srv\sh\a`)
+///
+/// 이미 접두사가 있거나, 드라이브/UNC 절대 경로가 아니거나, `.`/`..` 구성 요소가 있으면 None.
+pub fn to_extended_length(path: &str) -> Option<String> {
+    if path.starts_with(EXTENDED_PREFIX) {
+        return None;
+    }
+    let (prefix, body) = if let Some(unc) = parse_unc(path) {
+        (
+            EXTENDED_UNC_PREFIX,
+            format!(r"{}\{}{}", unc.server, unc.share, unc.rest),
+        )
+    } else {
+        let bytes = path.as_bytes();
+        let is_drive_absolute = bytes.len() >= 3
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1] == b':'
+            && is_separator(bytes[2] as char);
+        if !is_drive_absolute {
+            return None;
+        }
+        (EXTENDED_PREFIX, path.to_string())
+    };
+    let body = body.replace('/', "\\");
+    if body.split('\\').any(|part| part == "." || part == "..") {
+        return None;
+    }
+    Some(format!("{}{}", prefix, body))
+}
+
+/// 표시용으로 긴 경로 접두사 제거 (`\\?\UNC\srv\sh` → `\\srv\sh`)
+pub fn strip_extended_length(path: &str) -> Cow<'_, str> {
+    if let Some(rest) = path.strip_prefix(EXTENDED_UNC_PREFIX) {
+        Cow::Owned(format!(r"\\{}", rest))
+    } else if let Some(rest) = path.strip_prefix(EXTENDED_PREFIX) {
+        Cow::Borrowed(rest)
+    } else {
+        Cow::Borrowed(path)
+    }
+}
+
+/// 파일 작업에 넘길 경로 (Windows에서 길이 제한을 넘으면 긴 경로 접두사를 붙임)
+pub fn long_path(path: &Path) -> Cow<'_, Path> {
+    if cfg!(windows) && path.as_os_str().len() >= MAX_DIR_PATH {
+        if let Some(extended) = path.to_str().and_then(to_extended_length) {
+            return Cow::Owned(extended.into());
+        }
+    }
+    Cow::Borrowed(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unc_paths_parse_and_normalize() {
+        assert_eq!(
+            parse_unc(r"\\server\share\dir\file"),
+            Some(UncPath {
+                server: "server",
+                share: "share",
+                rest: r"\dir\file",
+            })
+        );
+        assert_eq!(
+            normalize_unc("//nas/media/films").as_deref(),
+            Some(r"\\nas\media\films")
+        );
+        assert_eq!(
+            normalize_unc(r"\\nas\media").as_deref(),
+            Some(r"\\nas\media")
+        );
+        assert!(parse_unc(r"\\server").is_none());
+        assert!(parse_unc(r"\\?\C:\x").is_none());
+        assert!(parse_unc(r"C:\x").is_none());
+        assert!(is_unc_server_only(r"\\server\"));
+        assert!(!is_unc_server_only(r"\\server\share"));
+    }
+
+    #[test]
+    fn test_extended_length_prefix_roundtrip() {
+        assert_eq!(
+            to_extended_length(r"C:\deep/tree").as_deref(),
+            Some(r"\\?\C:\deep\tree")
+        );
+        assert_eq!(
+            to_extended_length(r"\\srv\share\a").as_deref(),
+            Some(r"\\?\UNC\srv\share\a")
+        );
+        assert!(to_extended_length(r"\\?\C:\a").is_none());
+        assert!(to_extended_length(r"relative\a").is_none());
+        assert!(to_extended_length(r"C:\a\..\b").is_none());
+        assert_eq!(
+            strip_extended_length(r"\\?\UNC\srv\share\a"),
+            r"\\srv\share\a"
+        );
+        assert_eq!(strip_extended_length(r"\\?\C:\a"), r"C:\a");
+        assert_eq!(strip_extended_length("/usr"), "/usr");
+
+        #[cfg(not(windows))]
+        assert!(matches!(
+            long_path(Path::new(&"a/".repeat(200))),
+            Cow::Borrowed(_)
+        ));
+    }
+}